@@ -0,0 +1,303 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Per-pallet weight calibration report for `benchmark block`.
+//!
+//! Re-executes the extrinsics of historical blocks one by one on top of their parent state
+//! and compares the time each one took against the weight it was charged, as reported by its
+//! `ExtrinsicSuccess` or `ExtrinsicFailed` event in the re-executed block. Results are
+//! aggregated per pallet so that over- and under-charging weights can be spotted and
+//! recalibrated with real traffic.
+
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use allfeat_primitives::{Block, BlockNumber, Hash};
+use frame_support::storage::storage_prefix;
+use sc_client_api::BlockBackend;
+use sp_api::{ApiExt, ConstructRuntimeApi, Core, ProvideRuntimeApi};
+use sp_block_builder::BlockBuilder;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    codec::Encode,
+    traits::{Block as BlockT, Header as HeaderT},
+};
+
+use crate::service::{FullClient, RuntimeApiCollection};
+
+/// `ref_time` picoseconds per nanosecond of wall time.
+const REF_TIME_PER_NANOS: u128 = 1_000;
+
+/// Largest distance of the consumed to charged ratio from 1 for which a pallet's weights are
+/// reported as exact: below it, the difference is within the noise of the measurement.
+const EXACT_TOLERANCE: f64 = 0.05;
+
+/// Decodes the extrinsics and events of a runtime.
+pub trait CallPallet {
+    /// Name of the pallet `xt` dispatches to, if it decodes for this runtime.
+    fn pallet_name(xt: &<Block as BlockT>::Extrinsic) -> Option<&'static str>;
+
+    /// `ref_time` charged to each extrinsic of a block, by index, from the encoded
+    /// `System::Events` the block deposited. `None` if they do not decode for this runtime.
+    fn charged_weights(events: &[u8]) -> Option<BTreeMap<u32, u64>>;
+}
+
+#[cfg(feature = "melodie-runtime")]
+impl CallPallet for crate::service::MelodieRuntimeApi {
+    fn pallet_name(xt: &<Block as BlockT>::Extrinsic) -> Option<&'static str> {
+        use frame_support::traits::GetCallMetadata;
+        use sp_runtime::codec::Decode;
+
+        let uxt = melodie_runtime::UncheckedExtrinsic::decode(&mut &xt.encode()[..]).ok()?;
+        Some(uxt.function.get_call_metadata().pallet_name)
+    }
+
+    fn charged_weights(events: &[u8]) -> Option<BTreeMap<u32, u64>> {
+        decode_charged_weights::<melodie_runtime::Runtime>(events)
+    }
+}
+
+#[cfg(feature = "allfeat-runtime")]
+impl CallPallet for crate::service::AllfeatRuntimeApi {
+    fn pallet_name(xt: &<Block as BlockT>::Extrinsic) -> Option<&'static str> {
+        use frame_support::traits::GetCallMetadata;
+        use sp_runtime::codec::Decode;
+
+        let uxt = allfeat_runtime::UncheckedExtrinsic::decode(&mut &xt.encode()[..]).ok()?;
+        Some(uxt.function.get_call_metadata().pallet_name)
+    }
+
+    fn charged_weights(events: &[u8]) -> Option<BTreeMap<u32, u64>> {
+        decode_charged_weights::<allfeat_runtime::Runtime>(events)
+    }
+}
+
+/// Weight of the `ExtrinsicSuccess` and `ExtrinsicFailed` events among `events`, by extrinsic
+/// index: the weight actually charged after dispatch, base extrinsic weight included.
+#[cfg(any(feature = "melodie-runtime", feature = "allfeat-runtime"))]
+fn decode_charged_weights<T>(events: &[u8]) -> Option<BTreeMap<u32, u64>>
+where
+    T: frame_system::Config,
+    <T as frame_system::Config>::RuntimeEvent: TryInto<frame_system::Event<T>>,
+{
+    use frame_system::{Event, EventRecord, Phase};
+    use sp_runtime::codec::Decode;
+
+    let records = Vec::<EventRecord<<T as frame_system::Config>::RuntimeEvent, T::Hash>>::decode(
+        &mut &events[..],
+    )
+    .ok()?;
+    Some(
+        records
+            .into_iter()
+            .filter_map(|record| {
+                let Phase::ApplyExtrinsic(index) = record.phase else {
+                    return None;
+                };
+                match record.event.try_into().ok()? {
+                    Event::ExtrinsicSuccess { dispatch_info }
+                    | Event::ExtrinsicFailed { dispatch_info, .. } => {
+                        Some((index, dispatch_info.weight.ref_time()))
+                    }
+                    _ => None,
+                }
+            })
+            .collect(),
+    )
+}
+
+/// How a pallet's consumed to charged weight `ratio` compares to the ideal of 1.
+fn verdict(ratio: f64) -> &'static str {
+    if (ratio - 1.0).abs() <= EXACT_TOLERANCE {
+        "exact"
+    } else if ratio > 1.0 {
+        "under-charged"
+    } else {
+        "over-charged"
+    }
+}
+
+/// Charged and consumed `ref_time` of a pallet's extrinsics.
+#[derive(Default)]
+struct PalletWeights {
+    extrinsics: u32,
+    charged: u128,
+    consumed: u128,
+}
+
+/// Re-execute blocks `from..=to`, `repeat` times each, and log the per-pallet comparison of
+/// consumed against charged weight.
+pub fn report<RuntimeApi>(
+    client: Arc<FullClient<RuntimeApi>>,
+    from: BlockNumber,
+    to: BlockNumber,
+    repeat: u32,
+) -> sc_cli::Result<()>
+where
+    RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>> + CallPallet,
+    RuntimeApi: Send + Sync + 'static,
+    RuntimeApi::RuntimeApi: RuntimeApiCollection,
+{
+    let repeat = repeat.max(1);
+    let mut pallets = BTreeMap::<&'static str, PalletWeights>::new();
+
+    for number in from..=to {
+        let hash = client
+            .hash(number)?
+            .ok_or_else(|| format!("Block #{number} not found"))?;
+        let block = client
+            .block(hash)?
+            .ok_or_else(|| format!("Block #{number} has no body"))?
+            .block;
+        let (mut header, extrinsics) = block.deconstruct();
+        let parent = *header.parent_hash();
+        // The seal is appended after execution and is not expected by the runtime.
+        header
+            .digest_mut()
+            .logs
+            .retain(|item| item.as_seal().is_none());
+
+        let overhead = call_overhead(&client, parent, repeat)?;
+        let mut consumed = vec![Duration::ZERO; extrinsics.len()];
+        let mut charged = BTreeMap::new();
+        for run in 0..repeat {
+            // One API instance per run: its overlay carries each extrinsic's changes to the
+            // next, as during the original import.
+            let api = client.runtime_api();
+            api.initialize_block(parent, &header)
+                .map_err(|e| format!("Failed to initialize block #{number}: {e}"))?;
+            for (xt, total) in extrinsics.iter().zip(consumed.iter_mut()) {
+                let start = Instant::now();
+                // Failed dispatches are charged too: only the execution time matters here.
+                let _ = api
+                    .apply_extrinsic(parent, xt.clone())
+                    .map_err(|e| format!("Failed to apply extrinsic of block #{number}: {e}"))?;
+                *total += start.elapsed().saturating_sub(overhead);
+            }
+            if run == 0 {
+                charged = charged_weights::<RuntimeApi>(&client, &api, parent, number)?;
+            }
+        }
+
+        for (index, (xt, total)) in extrinsics.iter().zip(consumed).enumerate() {
+            let weight = *charged.get(&(index as u32)).ok_or_else(|| {
+                format!("Extrinsic #{number}-{index} has no ExtrinsicSuccess or ExtrinsicFailed")
+            })?;
+            let pallet = pallets
+                .entry(RuntimeApi::pallet_name(xt).unwrap_or("<undecodable>"))
+                .or_default();
+            pallet.extrinsics += 1;
+            pallet.charged += u128::from(weight);
+            pallet.consumed += total.as_nanos() / u128::from(repeat) * REF_TIME_PER_NANOS;
+        }
+    }
+
+    log::info!("Per-pallet weights over blocks #{from}..=#{to} ({repeat} runs, ref_time in ps):");
+    log::info!(
+        "{:<24} {:>10} {:>18} {:>18} {:>8}",
+        "Pallet",
+        "Extrinsics",
+        "Charged",
+        "Consumed",
+        "Ratio"
+    );
+    for (name, weights) in pallets {
+        let ratio = weights.consumed as f64 / weights.charged.max(1) as f64;
+        log::info!(
+            "{:<24} {:>10} {:>18} {:>18} {:>8.2} {}",
+            name,
+            weights.extrinsics,
+            weights.charged,
+            weights.consumed,
+            ratio,
+            verdict(ratio),
+        );
+    }
+    Ok(())
+}
+
+/// Weights charged to the extrinsics of block `number`, from the events its re-execution on
+/// `api` deposited.
+fn charged_weights<RuntimeApi>(
+    client: &Arc<FullClient<RuntimeApi>>,
+    api: &<RuntimeApi as ConstructRuntimeApi<Block, FullClient<RuntimeApi>>>::RuntimeApi,
+    parent: Hash,
+    number: BlockNumber,
+) -> sc_cli::Result<BTreeMap<u32, u64>>
+where
+    RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>> + CallPallet,
+    RuntimeApi: Send + Sync + 'static,
+    RuntimeApi::RuntimeApi: RuntimeApiCollection,
+{
+    let state = client.state_at(parent)?;
+    let changes = api
+        .into_storage_changes(&state, parent)
+        .map_err(|e| format!("Failed to collect the changes of block #{number}: {e}"))?;
+    let events_key = storage_prefix(b"System", b"Events");
+    let events = changes
+        .main_storage_changes
+        .into_iter()
+        .find_map(|(key, value)| (key == events_key).then_some(value).flatten())
+        .ok_or_else(|| format!("Block #{number} deposited no events"))?;
+    RuntimeApi::charged_weights(&events)
+        .ok_or_else(|| format!("Failed to decode the events of block #{number}").into())
+}
+
+/// Fixed cost of a runtime call (executor instantiation), subtracted from every measurement.
+fn call_overhead<RuntimeApi>(
+    client: &Arc<FullClient<RuntimeApi>>,
+    at: Hash,
+    repeat: u32,
+) -> sc_cli::Result<Duration>
+where
+    RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>>,
+    RuntimeApi: Send + Sync + 'static,
+    RuntimeApi::RuntimeApi: RuntimeApiCollection,
+{
+    let mut fastest = Duration::MAX;
+    for _ in 0..repeat {
+        let api = client.runtime_api();
+        let start = Instant::now();
+        api.version(at)
+            .map_err(|e| format!("Failed to call the runtime: {e}"))?;
+        fastest = fastest.min(start.elapsed());
+    }
+    Ok(fastest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratios_near_one_are_exact() {
+        assert_eq!(verdict(1.0), "exact");
+        assert_eq!(verdict(1.04), "exact");
+        assert_eq!(verdict(0.96), "exact");
+    }
+
+    #[test]
+    fn ratios_beyond_the_tolerance_are_mischarged() {
+        assert_eq!(verdict(1.2), "under-charged");
+        assert_eq!(verdict(0.8), "over-charged");
+        assert_eq!(verdict(0.0), "over-charged");
+    }
+}
@@ -23,6 +23,7 @@
 //! a module with `development_chain_spec()`, `local_chain_spec()`, and
 //! `live_chain_spec()` functions.
 
+use allfeat_primitives::properties::{ALLFEAT, ChainProperties, MELODIE};
 use sc_service::{ChainType, Properties};
 
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
@@ -30,7 +31,6 @@ pub type ChainSpec = sc_service::GenericChainSpec;
 
 const WASM_BINARY_NOT_AVAILABLE: &str =
     "WASM binary not available. Build the runtime with `cargo build --release`.";
-const TOKEN_DECIMALS: u32 = 12;
 
 // ============================================================================
 // Chain Spec Generation Macro
@@ -48,7 +48,7 @@ const TOKEN_DECIMALS: u32 = 12;
 /// - `$feature`: The cargo feature name (e.g., `"allfeat-runtime"`)
 /// - `$mod_name`: The module name to create (e.g., `mainnet`)
 /// - `$runtime`: The runtime crate path (e.g., `allfeat_runtime`)
-/// - `$symbol`: Token symbol (e.g., `"AFT"`)
+/// - `$properties`: Address format and native token (e.g., `ALLFEAT`)
 /// - `$prefix`: Chain ID prefix (e.g., `"allfeat"`)
 /// - `$name`: Human-readable chain name (e.g., `"Allfeat"`)
 macro_rules! define_chain_spec {
@@ -56,7 +56,7 @@ macro_rules! define_chain_spec {
         feature = $feature:literal,
         module = $mod_name:ident,
         runtime = $runtime:ident,
-        symbol = $symbol:literal,
+        properties = $properties:ident,
         prefix = $prefix:literal,
         name = $name:literal
     ) => {
@@ -64,13 +64,13 @@ macro_rules! define_chain_spec {
         pub mod $mod_name {
             //! Chain specifications for the network.
 
-            use super::{ChainSpec, NetworkConfig, build_chain_spec_for};
+            use super::{ChainSpec, NetworkConfig, build_chain_spec_for, $properties};
             use sc_service::ChainType;
 
             /// Returns the network configuration for this runtime.
             pub fn network_config() -> NetworkConfig {
                 NetworkConfig {
-                    token_symbol: $symbol,
+                    properties: $properties,
                     id_prefix: $prefix,
                     wasm_binary: $runtime::WASM_BINARY,
                     chain_name: $name,
@@ -103,7 +103,7 @@ define_chain_spec!(
     feature = "melodie-runtime",
     module = melodie,
     runtime = melodie_runtime,
-    symbol = "MEL",
+    properties = MELODIE,
     prefix = "melodie_3",
     name = "Melodie Testnet V3"
 );
@@ -112,7 +112,7 @@ define_chain_spec!(
     feature = "allfeat-runtime",
     module = mainnet,
     runtime = allfeat_runtime,
-    symbol = "AFT",
+    properties = ALLFEAT,
     prefix = "allfeat",
     name = "Allfeat"
 );
@@ -165,17 +165,26 @@ impl NetworkVariant {
 
     /// Try to determine the variant from chain properties.
     pub fn from_token_symbol(symbol: &str) -> Option<Self> {
-        match symbol.to_ascii_uppercase().as_str() {
-            "MEL" => Some(Self::Melodie),
-            "AFT" => Some(Self::Allfeat),
-            _ => None,
+        [Self::Melodie, Self::Allfeat].into_iter().find(|variant| {
+            variant
+                .properties()
+                .token_symbol
+                .eq_ignore_ascii_case(symbol)
+        })
+    }
+
+    /// Address format and native token of the network.
+    pub fn properties(&self) -> ChainProperties {
+        match self {
+            Self::Melodie => MELODIE,
+            Self::Allfeat => ALLFEAT,
         }
     }
 }
 
 /// Configuration parameters for a network variant's chain specs.
 pub struct NetworkConfig {
-    pub token_symbol: &'static str,
+    pub properties: ChainProperties,
     pub id_prefix: &'static str,
     pub wasm_binary: Option<&'static [u8]>,
     pub chain_name: &'static str,
@@ -185,14 +194,15 @@ pub struct NetworkConfig {
 // Chain Spec Builder
 // ============================================================================
 
-fn token_properties(symbol: &str) -> Result<Properties, String> {
+fn chain_properties(properties: &ChainProperties) -> Result<Properties, String> {
     serde_json::json!({
-        "tokenDecimals": TOKEN_DECIMALS,
-        "tokenSymbol": symbol,
+        "ss58Format": properties.ss58_prefix,
+        "tokenDecimals": properties.token_decimals,
+        "tokenSymbol": properties.token_symbol,
     })
     .as_object()
     .cloned()
-    .ok_or_else(|| "Failed to build token properties map".to_string())
+    .ok_or_else(|| "Failed to build chain properties map".to_string())
 }
 
 /// Build a chain spec from a [`NetworkConfig`] and a [`ChainType`].
@@ -203,7 +213,7 @@ pub fn build_chain_spec_for(
     let wasm = config
         .wasm_binary
         .ok_or_else(|| WASM_BINARY_NOT_AVAILABLE.to_string())?;
-    let properties = token_properties(config.token_symbol)?;
+    let properties = chain_properties(&config.properties)?;
 
     let suffix = match chain_type {
         ChainType::Development => "dev",
@@ -292,3 +302,89 @@ impl IdentifyVariant for Box<dyn sc_service::ChainSpec> {
             .map(ToOwned::to_owned)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use std::{fs, path::PathBuf};
+
+    const VARIANTS: [NetworkVariant; 2] = [NetworkVariant::Melodie, NetworkVariant::Allfeat];
+
+    #[test]
+    fn chain_spec_properties_are_the_network_properties() {
+        for variant in VARIANTS {
+            let properties = variant.properties();
+            let json = chain_properties(&properties).unwrap();
+
+            assert_eq!(json["ss58Format"], properties.ss58_prefix);
+            assert_eq!(json["tokenDecimals"], properties.token_decimals);
+            assert_eq!(json["tokenSymbol"], properties.token_symbol);
+        }
+    }
+
+    #[test]
+    fn shipped_specs_declare_the_network_properties() {
+        let mut paths = vec![PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/specs"))];
+        let mut checked = 0;
+        while let Some(path) = paths.pop() {
+            if path.is_dir() {
+                paths.extend(
+                    fs::read_dir(&path)
+                        .unwrap()
+                        .map(|entry| entry.unwrap().path()),
+                );
+                continue;
+            }
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+            let spec: Value = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+            let variant = NetworkVariant::from_id(spec["id"].as_str().unwrap()).unwrap();
+            let properties = chain_properties(&variant.properties()).unwrap();
+            assert_eq!(
+                spec["properties"],
+                Value::Object(properties),
+                "{}",
+                path.display()
+            );
+            checked += 1;
+        }
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn variants_are_identified_by_their_token_symbol() {
+        for variant in VARIANTS {
+            let symbol = variant.properties().token_symbol;
+            assert_eq!(NetworkVariant::from_token_symbol(symbol), Some(variant));
+            assert_eq!(
+                NetworkVariant::from_token_symbol(&symbol.to_ascii_lowercase()),
+                Some(variant)
+            );
+        }
+        assert_eq!(NetworkVariant::from_token_symbol("DOT"), None);
+    }
+
+    #[cfg(feature = "melodie-runtime")]
+    #[test]
+    fn melodie_specs_use_the_melodie_properties() {
+        let config = melodie::network_config();
+        assert_eq!(config.properties, MELODIE);
+        assert_eq!(
+            NetworkVariant::from_id(config.id_prefix),
+            Some(NetworkVariant::Melodie)
+        );
+    }
+
+    #[cfg(feature = "allfeat-runtime")]
+    #[test]
+    fn mainnet_specs_use_the_allfeat_properties() {
+        let config = mainnet::network_config();
+        assert_eq!(config.properties, ALLFEAT);
+        assert_eq!(
+            NetworkVariant::from_id(config.id_prefix),
+            Some(NetworkVariant::Allfeat)
+        );
+    }
+}
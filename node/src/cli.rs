@@ -18,6 +18,9 @@
 
 use sc_cli::RunCmd;
 use sc_storage_monitor::StorageMonitorParams;
+use std::{net::SocketAddr, time::Duration};
+
+use crate::{peer_sets::PeerSetParams, remote_keystore::RemoteSignerConfig};
 
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
@@ -37,8 +40,83 @@ pub struct Cli {
     #[arg(long)]
     pub no_hardware_benchmarks: bool,
 
+    /// Repair a corrupted database instead of refusing to start.
+    ///
+    /// At startup the headers and justifications of the latest blocks and the GRANDPA state
+    /// are checked. With this flag, corrupted blocks are dropped by truncating the chain to the
+    /// last consistent block, and synced again from peers.
+    #[arg(long)]
+    pub repair: bool,
+
+    /// Let `--repair` drop finalized blocks.
+    ///
+    /// Without this flag, the node refuses to start when the corruption reaches below the last
+    /// finalized block, as dropping finalized blocks rewinds what the node reports as final.
+    /// Only archive nodes (`--state-pruning archive`) keep the state needed to drop them.
+    #[arg(long, requires = "repair")]
+    pub repair_finalized: bool,
+
     #[clap(flatten)]
     pub storage_monitor: StorageMonitorParams,
+
+    #[clap(flatten)]
+    pub remote_keystore: RemoteKeystoreParams,
+
+    #[clap(flatten)]
+    pub peer_sets: PeerSetParams,
+
+    /// Format of the node's logs.
+    ///
+    /// `json` writes one JSON object per event (timestamp, level, target, fields and span
+    /// fields) to stderr, and serves the unsafe `logging_*` RPCs to change the level of each
+    /// log target at runtime. Subcommands always log as text.
+    #[arg(long, value_enum, default_value_t = LogFormat::Text)]
+    pub log_format: LogFormat,
+
+    /// Serve a read-only block explorer on this address, e.g. `127.0.0.1:8080`.
+    ///
+    /// It shows the latest blocks, their decoded extrinsics and the transactions of an account,
+    /// for private devnets without an explorer of their own. Requires a node built with
+    /// `--features explorer`.
+    #[arg(long, value_name = "ADDR")]
+    pub explorer: Option<SocketAddr>,
+}
+
+/// Format of the node's logs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogFormat {
+    /// Substrate's human-readable lines.
+    Text,
+    /// One JSON object per event.
+    Json,
+}
+
+/// Parameters of the remote signer holding the session keys.
+#[derive(Debug, Clone, clap::Args)]
+pub struct RemoteKeystoreParams {
+    /// JSON-RPC endpoint of a remote signer (HSM or Vault gateway, ...) holding the session
+    /// keys.
+    ///
+    /// When set, session keys are listed, generated and used through the signer only: the
+    /// local keystore is not consulted and `author_insertKey` is refused.
+    #[arg(long, value_name = "URL")]
+    pub keystore_remote: Option<String>,
+
+    /// Timeout of a single remote signer request, in milliseconds.
+    ///
+    /// Block authoring needs a signature well within the slot, so keep this short.
+    #[arg(long, value_name = "MS", default_value_t = 1_000)]
+    pub keystore_remote_timeout: u64,
+}
+
+impl RemoteKeystoreParams {
+    /// The remote signer to use, if any.
+    pub fn signer_config(&self) -> Option<RemoteSignerConfig> {
+        self.keystore_remote.clone().map(|url| RemoteSignerConfig {
+            url,
+            timeout: Duration::from_millis(self.keystore_remote_timeout),
+        })
+    }
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -71,4 +149,9 @@ pub enum Subcommand {
     /// Sub-commands concerned with benchmarking.
     #[command(subcommand)]
     Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+    /// Check the host and the flags of a node for common misconfigurations.
+    ///
+    /// Pass the flags the node runs with, e.g. `allfeat doctor --validator --chain melodie`.
+    Doctor(crate::doctor::DoctorCmd),
 }
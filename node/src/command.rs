@@ -19,8 +19,9 @@
 use std::{env, path::PathBuf};
 
 use crate::{
-    chain_specs::{ChainSpec, IdentifyVariant},
-    cli::{Cli, Subcommand},
+    chain_specs::{ChainSpec, IdentifyVariant, NetworkVariant},
+    cli::{Cli, LogFormat, Subcommand},
+    db_check::DbCheck,
 };
 
 #[cfg(feature = "allfeat-runtime")]
@@ -29,7 +30,6 @@ use crate::chain_specs::allfeat_chain_spec;
 use crate::chain_specs::melodie_chain_spec;
 use sc_cli::{ChainSpec as ChainSpecT, SubstrateCli};
 use sc_storage_monitor::StorageMonitorService;
-use sp_core::crypto::Ss58AddressFormatRegistry;
 
 impl SubstrateCli for Cli {
     fn impl_name() -> String {
@@ -139,7 +139,13 @@ pub fn run() -> sc_cli::Result<()> {
                 BenchmarkCmd::Extrinsic(_) => Err("Unsupported benchmarking command".into()),
                 BenchmarkCmd::Block(cmd) => runner.sync_run(|config| {
                     dispatch_benchmark_partials!(config => |partials| {
-                        cmd.run(partials.client)
+                        cmd.run(partials.client.clone())?;
+                        crate::block_weights::report(
+                            partials.client,
+                            cmd.params.from,
+                            cmd.params.to,
+                            cmd.params.repeat,
+                        )
                     })
                 }),
                 BenchmarkCmd::Machine(cmd) => {
@@ -153,12 +159,37 @@ pub fn run() -> sc_cli::Result<()> {
             You can enable it with `--features runtime-benchmarks`."
                 .into())
         }
+        Some(Subcommand::Doctor(cmd)) => {
+            let runner = cli.create_runner(&cmd.run)?;
+            runner.sync_run(|config| crate::doctor::run(&config, cmd))
+        }
         None => {
-            let runner = cli.create_runner(&cli.run)?;
+            let runner = match cli.log_format {
+                LogFormat::Text => cli.create_runner(&cli.run)?,
+                LogFormat::Json => crate::logging::create_json_runner(&cli)?,
+            };
             let no_hardware_benchmarks = cli.no_hardware_benchmarks;
             let storage_monitor = cli.storage_monitor.clone();
+            let remote_signer = cli.remote_keystore.signer_config();
+            let peer_sets = cli.peer_sets.clone();
+            let explorer = cli.explorer;
+            #[cfg(not(feature = "explorer"))]
+            if explorer.is_some() {
+                return Err(
+                    "The block explorer was not enabled when building the node. \
+                    You can enable it with `--features explorer`."
+                        .into(),
+                );
+            }
+            let db_check = if cli.repair {
+                DbCheck::Repair {
+                    revert_finalized: cli.repair_finalized,
+                }
+            } else {
+                DbCheck::Abort
+            };
 
-            runner.run_node_until_exit(move |config| async move {
+            runner.run_node_until_exit(move |mut config| async move {
                 let hwbench = (!no_hardware_benchmarks)
                     .then(|| {
                         config.database.path().map(|database_path| {
@@ -180,6 +211,14 @@ pub fn run() -> sc_cli::Result<()> {
                     }
                 }
 
+                let topology = peer_sets.apply(&mut config.network, &config.role);
+                log::info!(
+                    "Peer topology: {topology:?} ({} preferred peers, {} inbound / {} outbound slots)",
+                    peer_sets.preferred_peers.len(),
+                    config.network.default_peers_set.in_peers,
+                    config.network.default_peers_set.out_peers,
+                );
+
                 let database_source = config.database.clone();
                 let chain_spec = &config.chain_spec;
                 set_default_ss58_version(chain_spec);
@@ -194,7 +233,7 @@ pub fn run() -> sc_cli::Result<()> {
                 );
 
                 let task_manager: sc_service::TaskManager =
-                    dispatch_on_runtime_full!(chain_spec, config)?;
+                    dispatch_on_runtime_full!(chain_spec, config, remote_signer, db_check, explorer)?;
 
                 if let Some(path) = database_source.path() {
                     StorageMonitorService::try_spawn(
@@ -262,12 +301,7 @@ fn get_exec_name() -> Option<String> {
 }
 
 fn set_default_ss58_version(chain_spec: &dyn IdentifyVariant) {
-    let ss58_version = if chain_spec.is_melodie() {
-        Ss58AddressFormatRegistry::SubstrateAccount
-    } else {
-        Ss58AddressFormatRegistry::AllfeatNetworkAccount
-    }
-    .into();
+    let variant = chain_spec.variant().unwrap_or(NetworkVariant::Allfeat);
 
-    sp_core::crypto::set_default_ss58_version(ss58_version);
+    sp_core::crypto::set_default_ss58_version(variant.properties().ss58_format());
 }
@@ -0,0 +1,673 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Integrity check of the block database, run at startup before consensus reads its state, so
+//! that a corrupted database is reported block by block instead of surfacing as an opaque
+//! RocksDB error, and can be repaired by truncating the chain.
+
+use std::fmt;
+
+use allfeat_primitives::{Block, BlockNumber, Hash};
+use sc_client_api::{AuxStore, BlockBackend, backend::Backend};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Header;
+
+/// Canonical blocks below the best one whose headers and justifications are checked.
+const CHECK_DEPTH: BlockNumber = 256;
+
+/// Aux key of the GRANDPA authority set, see `sc_consensus_grandpa::aux_schema`.
+const GRANDPA_AUTHORITY_SET_KEY: &[u8] = b"grandpa_voters";
+
+/// What to do when the check finds a problem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DbCheck {
+    /// Log the problems and carry on, so that subcommands such as `revert` still run.
+    Warn,
+    /// Refuse to start, pointing at `--repair`.
+    Abort,
+    /// Truncate the chain to the last consistent block. Finalized blocks are only dropped when
+    /// `revert_finalized` is set, otherwise the node refuses to start if the corruption reaches
+    /// them.
+    Repair { revert_finalized: bool },
+}
+
+/// A problem found in the database.
+#[derive(Debug)]
+enum Problem {
+    /// No canonical block is recorded at this height.
+    MissingBlock(BlockNumber),
+    /// The header of the canonical block at this height cannot be read.
+    UnreadableHeader(BlockNumber, String),
+    /// The canonical block at this height is not the parent of the one above, or its header
+    /// is for another height.
+    BrokenLink(BlockNumber),
+    /// The justifications of the finalized block at this height cannot be decoded.
+    UnreadableJustifications(BlockNumber, String),
+    /// The GRANDPA authority set is missing or unreadable. Truncating the chain does not fix
+    /// it.
+    GrandpaState(String),
+}
+
+impl Problem {
+    /// Height of the block the problem is at, if it is in the block columns.
+    fn height(&self) -> Option<BlockNumber> {
+        match self {
+            Self::MissingBlock(number)
+            | Self::UnreadableHeader(number, _)
+            | Self::BrokenLink(number)
+            | Self::UnreadableJustifications(number, _) => Some(*number),
+            Self::GrandpaState(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingBlock(number) => write!(f, "no canonical block at #{number}"),
+            Self::UnreadableHeader(number, e) => write!(f, "header of #{number} unreadable: {e}"),
+            Self::BrokenLink(number) => {
+                write!(f, "#{number} does not extend the canonical chain below it")
+            }
+            Self::UnreadableJustifications(number, e) => {
+                write!(f, "justifications of #{number} unreadable: {e}")
+            }
+            Self::GrandpaState(e) => write!(f, "GRANDPA authority set unreadable: {e}"),
+        }
+    }
+}
+
+/// Check the last `CHECK_DEPTH` canonical blocks and the GRANDPA state, then act on the
+/// problems found as `policy` says.
+///
+/// `revert_aux` rewinds the consensus aux data once the chain is truncated, by the number of
+/// blocks actually dropped.
+pub fn run<C, B>(
+    client: &C,
+    backend: &B,
+    policy: DbCheck,
+    revert_aux: impl FnOnce(BlockNumber) -> Result<(), String>,
+) -> Result<(), String>
+where
+    C: HeaderBackend<Block> + BlockBackend<Block> + AuxStore,
+    B: Backend<Block>,
+{
+    let problems = check(client);
+    if problems.is_empty() {
+        return Ok(());
+    }
+    for problem in &problems {
+        log::error!("Database integrity check: {problem}");
+    }
+
+    let info = client.info();
+    let best = info.best_number;
+    let last_consistent = problems
+        .iter()
+        .filter_map(Problem::height)
+        .min()
+        .map(|lowest| lowest.saturating_sub(1));
+    let grandpa_broken = problems
+        .iter()
+        .any(|problem| matches!(problem, Problem::GrandpaState(_)));
+
+    match policy {
+        DbCheck::Warn => Ok(()),
+        DbCheck::Abort | DbCheck::Repair { .. } if grandpa_broken => Err(
+            "The GRANDPA authority set in the database is unreadable and cannot be repaired. \
+             Remove the chain with `purge-chain` and sync again."
+                .into(),
+        ),
+        DbCheck::Abort => Err(format!(
+            "The block database is corrupted above block #{}. Restart with `--repair` to \
+             truncate the chain to that block and sync the rest again, or remove the chain \
+             with `purge-chain`.",
+            last_consistent.unwrap_or(best),
+        )),
+        DbCheck::Repair {
+            revert_finalized: false,
+        } if last_consistent.is_some_and(|block| block < info.finalized_number) => Err(format!(
+            "The block database is corrupted above block #{}, below the last finalized block \
+             #{}. Restart with `--repair --repair-finalized` to also drop finalized blocks, or \
+             remove the chain with `purge-chain`.",
+            last_consistent.unwrap_or(best),
+            info.finalized_number,
+        )),
+        DbCheck::Repair { revert_finalized } => {
+            let blocks = best.saturating_sub(last_consistent.unwrap_or(best));
+            let (reverted, _) = backend.revert(blocks, revert_finalized).map_err(|e| {
+                format!(
+                    "Unable to truncate the chain by {blocks} blocks: {e}. Remove the chain \
+                     with `purge-chain` and sync again."
+                )
+            })?;
+            revert_aux(reverted)?;
+            if reverted < blocks {
+                return Err(format!(
+                    "Only {reverted} of the {blocks} blocks above the corruption could be \
+                     dropped, the chain still ends at #{}. Remove the chain with `purge-chain` \
+                     and sync again.",
+                    client.info().best_number,
+                ));
+            }
+            log::warn!(
+                "Database repaired: {reverted} blocks were dropped and the chain now ends at \
+                 #{}. They are synced again from peers.",
+                client.info().best_number,
+            );
+            Ok(())
+        }
+    }
+}
+
+fn check<C>(client: &C) -> Vec<Problem>
+where
+    C: HeaderBackend<Block> + BlockBackend<Block> + AuxStore,
+{
+    let info = client.info();
+    let mut problems = Vec::new();
+    // Parent hash recorded in the header of the block above, if it was read.
+    let mut expected_hash: Option<Hash> = None;
+    // Blocks a warp-synced node has not downloaded yet, filled in the background.
+    let gap = info.block_gap.map(|gap| gap.start..=gap.end);
+
+    for number in (info.best_number.saturating_sub(CHECK_DEPTH)..=info.best_number).rev() {
+        if gap.as_ref().is_some_and(|gap| gap.contains(&number)) {
+            expected_hash = None;
+            continue;
+        }
+        let hash = match client.hash(number) {
+            Ok(Some(hash)) => hash,
+            Ok(None) => {
+                problems.push(Problem::MissingBlock(number));
+                expected_hash = None;
+                continue;
+            }
+            Err(e) => {
+                problems.push(Problem::UnreadableHeader(number, e.to_string()));
+                expected_hash = None;
+                continue;
+            }
+        };
+        if expected_hash.is_some_and(|expected| expected != hash) {
+            problems.push(Problem::BrokenLink(number + 1));
+        }
+
+        expected_hash = match client.header(hash) {
+            Ok(Some(header)) if *header.number() == number => Some(*header.parent_hash()),
+            Ok(Some(_)) => {
+                problems.push(Problem::BrokenLink(number));
+                None
+            }
+            Ok(None) => {
+                problems.push(Problem::UnreadableHeader(number, "missing".into()));
+                None
+            }
+            Err(e) => {
+                problems.push(Problem::UnreadableHeader(number, e.to_string()));
+                None
+            }
+        };
+
+        let justifications = (number <= info.finalized_number).then(|| client.justifications(hash));
+        if let Some(Err(e)) = justifications {
+            problems.push(Problem::UnreadableJustifications(number, e.to_string()));
+        }
+    }
+
+    // GRANDPA writes its authority set when the first block is imported.
+    if info.best_number > 0 {
+        match client.get_aux(GRANDPA_AUTHORITY_SET_KEY) {
+            Ok(Some(_)) => {}
+            Ok(None) => problems.push(Problem::GrandpaState("missing".into())),
+            Err(e) => problems.push(Problem::GrandpaState(e.to_string())),
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::HashMap, ops::RangeInclusive};
+
+    use allfeat_primitives::Header as BlockHeader;
+    use sc_client_api::{
+        BlockImportOperation, NewBlockState, StateBackend, TrieCacheContext, in_mem,
+    };
+    use sc_client_db::BlocksPruning;
+    use sp_blockchain::{Backend as _, BlockGap, BlockGapType, BlockStatus, Info};
+    use sp_runtime::{Justifications, StateVersion, generic::SignedBlock};
+
+    /// Canonical chain of empty blocks, with the blocks of `gap` not downloaded yet.
+    struct Chain {
+        headers: HashMap<Hash, BlockHeader>,
+        hashes: HashMap<BlockNumber, Hash>,
+        best: (Hash, BlockNumber),
+        finalized: (Hash, BlockNumber),
+        gap: Option<(BlockNumber, BlockNumber)>,
+    }
+
+    impl Chain {
+        fn new(
+            best: BlockNumber,
+            finalized: BlockNumber,
+            gap: Option<(BlockNumber, BlockNumber)>,
+        ) -> Self {
+            let mut chain = Self {
+                headers: HashMap::new(),
+                hashes: HashMap::new(),
+                best: Default::default(),
+                finalized: Default::default(),
+                gap,
+            };
+            let mut parent_hash = Hash::zero();
+            for number in 0..=best {
+                let header = BlockHeader::new(
+                    number,
+                    Default::default(),
+                    Default::default(),
+                    parent_hash,
+                    Default::default(),
+                );
+                parent_hash = header.hash();
+                if gap.is_some_and(|(start, end)| (start..=end).contains(&number)) {
+                    continue;
+                }
+                chain.hashes.insert(number, parent_hash);
+                chain.headers.insert(parent_hash, header);
+            }
+            chain.best = (chain.hashes[&best], best);
+            chain.finalized = (chain.hashes[&finalized], finalized);
+            chain
+        }
+
+        fn lose(mut self, number: BlockNumber) -> Self {
+            self.hashes.remove(&number);
+            self
+        }
+    }
+
+    impl HeaderBackend<Block> for Chain {
+        fn header(&self, hash: Hash) -> sp_blockchain::Result<Option<BlockHeader>> {
+            Ok(self.headers.get(&hash).cloned())
+        }
+
+        fn info(&self) -> Info<Block> {
+            Info {
+                best_hash: self.best.0,
+                best_number: self.best.1,
+                genesis_hash: self.hashes[&0],
+                finalized_hash: self.finalized.0,
+                finalized_number: self.finalized.1,
+                finalized_state: Some(self.finalized),
+                number_leaves: 1,
+                block_gap: self.gap.map(|(start, end)| BlockGap {
+                    start,
+                    end,
+                    gap_type: BlockGapType::MissingHeaderAndBody,
+                }),
+            }
+        }
+
+        fn status(&self, hash: Hash) -> sp_blockchain::Result<BlockStatus> {
+            Ok(match self.headers.contains_key(&hash) {
+                true => BlockStatus::InChain,
+                false => BlockStatus::Unknown,
+            })
+        }
+
+        fn number(&self, hash: Hash) -> sp_blockchain::Result<Option<BlockNumber>> {
+            Ok(self.headers.get(&hash).map(|header| *header.number()))
+        }
+
+        fn hash(&self, number: BlockNumber) -> sp_blockchain::Result<Option<Hash>> {
+            Ok(self.hashes.get(&number).copied())
+        }
+    }
+
+    impl BlockBackend<Block> for Chain {
+        fn block_body(
+            &self,
+            _: Hash,
+        ) -> sp_blockchain::Result<Option<Vec<sp_runtime::OpaqueExtrinsic>>> {
+            Ok(Some(Vec::new()))
+        }
+
+        fn block_indexed_body(&self, _: Hash) -> sp_blockchain::Result<Option<Vec<Vec<u8>>>> {
+            Ok(None)
+        }
+
+        fn block(&self, hash: Hash) -> sp_blockchain::Result<Option<SignedBlock<Block>>> {
+            Ok(self.headers.get(&hash).map(|header| SignedBlock {
+                block: Block {
+                    header: header.clone(),
+                    extrinsics: Vec::new(),
+                },
+                justifications: None,
+            }))
+        }
+
+        fn block_status(&self, _: Hash) -> sp_blockchain::Result<sp_consensus::BlockStatus> {
+            Ok(sp_consensus::BlockStatus::InChainWithState)
+        }
+
+        fn justifications(&self, _: Hash) -> sp_blockchain::Result<Option<Justifications>> {
+            Ok(None)
+        }
+
+        fn block_hash(&self, number: BlockNumber) -> sp_blockchain::Result<Option<Hash>> {
+            self.hash(number)
+        }
+
+        fn indexed_transaction(&self, _: Hash) -> sp_blockchain::Result<Option<Vec<u8>>> {
+            Ok(None)
+        }
+
+        fn requires_full_sync(&self) -> bool {
+            false
+        }
+    }
+
+    impl AuxStore for Chain {
+        fn insert_aux<
+            'a,
+            'b: 'a,
+            'c: 'a,
+            I: IntoIterator<Item = &'a (&'c [u8], &'c [u8])>,
+            D: IntoIterator<Item = &'a &'b [u8]>,
+        >(
+            &self,
+            _: I,
+            _: D,
+        ) -> sp_blockchain::Result<()> {
+            Ok(())
+        }
+
+        fn get_aux(&self, key: &[u8]) -> sp_blockchain::Result<Option<Vec<u8>>> {
+            Ok((key == GRANDPA_AUTHORITY_SET_KEY).then(Vec::new))
+        }
+    }
+
+    /// Block database holding a canonical chain of empty blocks, each with an aux entry as
+    /// consensus would write, and whose block at `lost`, if any, is unreadable.
+    struct Db {
+        backend: sc_client_db::Backend<Block>,
+        lost: Option<BlockNumber>,
+    }
+
+    impl Db {
+        /// The state of finalized blocks is only kept, and so revertible, by archive nodes.
+        fn new(best: BlockNumber, finalized: BlockNumber, pruning: BlocksPruning) -> Self {
+            let backend = sc_client_db::Backend::<Block>::new_test_with_tx_storage(pruning, 1_000);
+            let mut parent_hash = Hash::zero();
+            for number in 0..=best {
+                let mut op = backend.begin_operation().unwrap();
+                backend.begin_state_operation(&mut op, parent_hash).unwrap();
+                let state_root = if number == 0 {
+                    op.reset_storage(Default::default(), StateVersion::V1)
+                        .unwrap()
+                } else {
+                    // Each block writes its parent's hash, so that no two share a state.
+                    let (root, changes) = backend
+                        .state_at(parent_hash, TrieCacheContext::Untrusted)
+                        .unwrap()
+                        .storage_root(
+                            [(parent_hash.as_ref(), Some(parent_hash.as_ref()))].into_iter(),
+                            StateVersion::V1,
+                        );
+                    op.update_db_storage(changes).unwrap();
+                    root
+                };
+                let header = BlockHeader::new(
+                    number,
+                    Default::default(),
+                    state_root,
+                    parent_hash,
+                    Default::default(),
+                );
+                parent_hash = header.hash();
+                op.set_block_data(
+                    header,
+                    Some(Vec::new()),
+                    None,
+                    None,
+                    NewBlockState::Best,
+                    true,
+                )
+                .unwrap();
+                op.insert_aux([(aux_key(number), Some(Vec::new()))])
+                    .unwrap();
+                backend.commit_operation(op).unwrap();
+            }
+            AuxStore::insert_aux(&backend, &[(GRANDPA_AUTHORITY_SET_KEY, &[][..])], &[]).unwrap();
+            for number in 1..=finalized {
+                let hash = backend.blockchain().hash(number).unwrap().unwrap();
+                backend.finalize_block(hash, None).unwrap();
+            }
+            Self {
+                backend,
+                lost: None,
+            }
+        }
+
+        fn lose(mut self, number: BlockNumber) -> Self {
+            self.lost = Some(number);
+            self
+        }
+
+        /// Deletes the aux entries of the `reverted` blocks above the best one.
+        fn revert_aux(&self, reverted: BlockNumber) -> Result<(), String> {
+            let best = self.info().best_number;
+            let keys = (best + 1..=best + reverted)
+                .map(aux_key)
+                .collect::<Vec<_>>();
+            let keys = keys.iter().map(Vec::as_slice).collect::<Vec<_>>();
+            self.insert_aux(&[], &keys).map_err(|e| e.to_string())
+        }
+
+        /// Heights of the blocks whose aux entry is left, assuming they are contiguous.
+        fn aux_heights(&self) -> RangeInclusive<BlockNumber> {
+            let heights = (0..=self.info().best_number + 100)
+                .filter(|number| self.get_aux(&aux_key(*number)).unwrap().is_some())
+                .collect::<Vec<_>>();
+            heights[0]..=heights[heights.len() - 1]
+        }
+    }
+
+    fn aux_key(number: BlockNumber) -> Vec<u8> {
+        [&b"block"[..], &number.to_le_bytes()].concat()
+    }
+
+    impl HeaderBackend<Block> for Db {
+        fn header(&self, hash: Hash) -> sp_blockchain::Result<Option<BlockHeader>> {
+            self.backend.blockchain().header(hash)
+        }
+
+        fn info(&self) -> Info<Block> {
+            self.backend.blockchain().info()
+        }
+
+        fn status(&self, hash: Hash) -> sp_blockchain::Result<BlockStatus> {
+            self.backend.blockchain().status(hash)
+        }
+
+        fn number(&self, hash: Hash) -> sp_blockchain::Result<Option<BlockNumber>> {
+            self.backend.blockchain().number(hash)
+        }
+
+        fn hash(&self, number: BlockNumber) -> sp_blockchain::Result<Option<Hash>> {
+            match self.lost == Some(number) {
+                true => Ok(None),
+                false => self.backend.blockchain().hash(number),
+            }
+        }
+    }
+
+    impl BlockBackend<Block> for Db {
+        fn block_body(
+            &self,
+            hash: Hash,
+        ) -> sp_blockchain::Result<Option<Vec<sp_runtime::OpaqueExtrinsic>>> {
+            self.backend.blockchain().body(hash)
+        }
+
+        fn block_indexed_body(&self, _: Hash) -> sp_blockchain::Result<Option<Vec<Vec<u8>>>> {
+            Ok(None)
+        }
+
+        fn block(&self, hash: Hash) -> sp_blockchain::Result<Option<SignedBlock<Block>>> {
+            let (Some(header), Some(extrinsics)) = (self.header(hash)?, self.block_body(hash)?)
+            else {
+                return Ok(None);
+            };
+            Ok(Some(SignedBlock {
+                block: Block { header, extrinsics },
+                justifications: self.justifications(hash)?,
+            }))
+        }
+
+        fn block_status(&self, _: Hash) -> sp_blockchain::Result<sp_consensus::BlockStatus> {
+            Ok(sp_consensus::BlockStatus::InChainWithState)
+        }
+
+        fn justifications(&self, hash: Hash) -> sp_blockchain::Result<Option<Justifications>> {
+            self.backend.blockchain().justifications(hash)
+        }
+
+        fn block_hash(&self, number: BlockNumber) -> sp_blockchain::Result<Option<Hash>> {
+            self.hash(number)
+        }
+
+        fn indexed_transaction(&self, _: Hash) -> sp_blockchain::Result<Option<Vec<u8>>> {
+            Ok(None)
+        }
+
+        fn requires_full_sync(&self) -> bool {
+            false
+        }
+    }
+
+    impl AuxStore for Db {
+        fn insert_aux<
+            'a,
+            'b: 'a,
+            'c: 'a,
+            I: IntoIterator<Item = &'a (&'c [u8], &'c [u8])>,
+            D: IntoIterator<Item = &'a &'b [u8]>,
+        >(
+            &self,
+            insert: I,
+            delete: D,
+        ) -> sp_blockchain::Result<()> {
+            AuxStore::insert_aux(&self.backend, insert, delete)
+        }
+
+        fn get_aux(&self, key: &[u8]) -> sp_blockchain::Result<Option<Vec<u8>>> {
+            AuxStore::get_aux(&self.backend, key)
+        }
+    }
+
+    fn heights(problems: &[Problem]) -> Vec<Option<BlockNumber>> {
+        problems.iter().map(Problem::height).collect()
+    }
+
+    #[test]
+    fn blocks_in_the_warp_sync_gap_are_not_missing() {
+        let chain = Chain::new(300, 290, Some((1, 200)));
+        let backend = in_mem::Backend::<Block>::new();
+
+        assert!(check(&chain).is_empty());
+        assert_eq!(
+            run(&chain, &backend, DbCheck::Abort, |_| unreachable!()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn missing_blocks_outside_the_gap_are_reported() {
+        let chain = Chain::new(300, 290, Some((1, 200))).lose(250);
+
+        assert_eq!(heights(&check(&chain)), [Some(250)]);
+    }
+
+    #[test]
+    fn finalized_blocks_are_only_reverted_on_request() {
+        let db = Db::new(300, 290, BlocksPruning::KeepAll).lose(250);
+
+        let refused = run(
+            &db,
+            &db.backend,
+            DbCheck::Repair {
+                revert_finalized: false,
+            },
+            |_| unreachable!(),
+        );
+        assert!(refused.is_err_and(|e| e.contains("--repair-finalized")));
+        assert_eq!(db.info().best_number, 300);
+
+        let repaired = run(
+            &db,
+            &db.backend,
+            DbCheck::Repair {
+                revert_finalized: true,
+            },
+            |reverted| db.revert_aux(reverted),
+        );
+        assert_eq!(repaired, Ok(()));
+        assert_eq!(db.info().best_number, 249);
+        assert_eq!(db.info().finalized_number, 249);
+        assert_eq!(db.aux_heights(), 0..=249);
+    }
+
+    #[test]
+    fn unfinalized_blocks_are_reverted_without_the_flag() {
+        let db = Db::new(300, 200, BlocksPruning::Some(1_000)).lose(250);
+
+        let repaired = run(
+            &db,
+            &db.backend,
+            DbCheck::Repair {
+                revert_finalized: false,
+            },
+            |reverted| db.revert_aux(reverted),
+        );
+        assert_eq!(repaired, Ok(()));
+        assert_eq!(db.info().best_number, 249);
+        assert_eq!(db.info().finalized_number, 200);
+        assert_eq!(db.aux_heights(), 0..=249);
+    }
+
+    #[test]
+    fn repair_fails_when_finalized_state_is_pruned() {
+        let db = Db::new(300, 290, BlocksPruning::Some(1_000)).lose(250);
+
+        let repaired = run(
+            &db,
+            &db.backend,
+            DbCheck::Repair {
+                revert_finalized: true,
+            },
+            |reverted| db.revert_aux(reverted),
+        );
+        assert!(repaired.is_err_and(|e| e.contains("Only 10 of the 51 blocks")));
+        assert_eq!(db.info().best_number, 290);
+        assert_eq!(db.aux_heights(), 0..=290);
+    }
+}
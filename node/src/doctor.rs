@@ -0,0 +1,599 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! `allfeat doctor`: checks the host and the flags of a node for the misconfigurations new
+//! operators run into most, and says how to fix each of them.
+//!
+//! It takes the flags the node runs with, e.g. `allfeat doctor --validator --chain melodie
+//! --base-path /data`, and starts nothing: ports are bound then released, and the keystore is
+//! only read.
+
+use std::{
+    fmt, fs, io,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener, ToSocketAddrs, UdpSocket},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use sc_cli::RunCmd;
+use sc_network::{Multiaddr, multiaddr::Protocol};
+use sc_service::{Configuration, PruningMode, config::KeystoreConfig};
+use sp_core::crypto::KeyTypeId;
+use sp_keystore::Keystore;
+
+use crate::{chain_specs::IdentifyVariant, cli::RemoteKeystoreParams};
+
+/// Open files below which the node warns at startup, as `sc-cli` does.
+const RECOMMENDED_OPEN_FILES: u64 = 10_000;
+
+/// Clock offsets, in seconds, from which the node misses Aura slots (6 seconds) often enough
+/// to matter, then consistently.
+const CLOCK_SKEW_WARNING: f64 = 0.5;
+const CLOCK_SKEW_ERROR: f64 = 2.0;
+
+/// How long to wait for the NTP server.
+const NTP_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Seconds from the NTP era (1900) to the Unix epoch.
+const NTP_UNIX_OFFSET: f64 = 2_208_988_800.0;
+
+/// Session keys validators need in their keystore, Melodie's `imon` aside.
+const SESSION_KEYS: [KeyTypeId; 2] = [KeyTypeId(*b"aura"), KeyTypeId(*b"gran")];
+const IM_ONLINE: KeyTypeId = KeyTypeId(*b"imon");
+
+const ROTATE_KEYS: &str =
+    "generate session keys with `author_rotateKeysWithOwner`, see the validator guide, step 3";
+
+/// Check the environment and the flags of a node for common misconfigurations.
+#[derive(Debug, Clone, clap::Args)]
+pub struct DoctorCmd {
+    #[clap(flatten)]
+    pub run: RunCmd,
+
+    #[clap(flatten)]
+    pub remote_keystore: RemoteKeystoreParams,
+
+    /// NTP server the local clock is compared with.
+    #[arg(long, value_name = "HOST:PORT", default_value = "pool.ntp.org:123")]
+    pub ntp_server: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Ok,
+    /// Worth knowing, not necessarily wrong.
+    Note,
+    /// The node runs, but not as well as it should.
+    Warning,
+    /// The node fails to start, or to do its job.
+    Error,
+}
+
+/// The outcome of one check.
+#[derive(Debug)]
+struct Finding {
+    severity: Severity,
+    message: String,
+    /// What to do about it.
+    hint: Option<String>,
+}
+
+impl Finding {
+    fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            hint: None,
+        }
+    }
+
+    fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self.severity {
+            Severity::Ok => "ok",
+            Severity::Note => "note",
+            Severity::Warning => "warn",
+            Severity::Error => "error",
+        };
+        write!(f, "  {label:<5} {}", self.message)?;
+        if let Some(hint) = &self.hint {
+            write!(f, "\n        -> {hint}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Run every check against `config`, print the findings, and fail if any is an error.
+#[allow(clippy::result_large_err)]
+pub fn run(config: &Configuration, cmd: &DoctorCmd) -> sc_cli::Result<()> {
+    let authority = config.role.is_authority();
+    let mut findings = Vec::new();
+
+    for (name, addr) in listen_addresses(config) {
+        findings.push(check_port(name, addr));
+        if authority && name == "RPC" && !addr.ip().is_loopback() {
+            findings.push(
+                Finding::new(
+                    Severity::Warning,
+                    format!("RPC of a validator listens on {addr}"),
+                )
+                .hint("keep validator RPC on localhost or behind a VPN: drop --rpc-external"),
+            );
+        }
+    }
+    if let Some(path) = config.database.path() {
+        findings.push(check_writable("Database", path));
+    }
+    findings.push(check_clock(&cmd.ntp_server));
+    findings.push(check_open_files(
+        fs::read_to_string("/proc/self/limits").ok(),
+    ));
+    findings.push(check_pruning(
+        authority,
+        config.state_pruning.as_ref(),
+        public_rpc(config),
+    ));
+    if authority {
+        let mut key_types = SESSION_KEYS.to_vec();
+        if config.chain_spec.is_melodie() {
+            key_types.push(IM_ONLINE);
+        }
+        match cmd.remote_keystore.signer_config() {
+            Some(signer) => findings.push(Finding::new(
+                Severity::Note,
+                format!(
+                    "Session keys are held by the remote signer at {}, not checked",
+                    signer.url
+                ),
+            )),
+            None => findings.extend(check_keystore(&config.keystore, &key_types)),
+        }
+    }
+
+    println!(
+        "Checked {} ({}):",
+        config.chain_spec.name(),
+        if authority { "validator" } else { "full node" }
+    );
+    for finding in &findings {
+        println!("{finding}");
+    }
+
+    let count = |severity| findings.iter().filter(|f| f.severity == severity).count();
+    let (errors, warnings) = (count(Severity::Error), count(Severity::Warning));
+    println!("{errors} error(s), {warnings} warning(s).");
+    if errors > 0 {
+        return Err(format!("{errors} problem(s) to fix before starting the node").into());
+    }
+    Ok(())
+}
+
+/// The TCP addresses the node would listen on: P2P, RPC and Prometheus.
+fn listen_addresses(config: &Configuration) -> Vec<(&'static str, SocketAddr)> {
+    let mut addresses = Vec::new();
+    addresses.extend(
+        config
+            .network
+            .listen_addresses
+            .iter()
+            .filter_map(tcp_address)
+            .map(|addr| ("P2P", addr)),
+    );
+    match &config.rpc.addr {
+        Some(endpoints) => addresses.extend(endpoints.iter().map(|e| ("RPC", e.listen_addr))),
+        None => addresses.push((
+            "RPC",
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), config.rpc.port),
+        )),
+    }
+    if let Some(prometheus) = &config.prometheus_config {
+        addresses.push(("Prometheus", prometheus.port));
+    }
+    addresses
+}
+
+/// The TCP address of a P2P listen address, if it has one.
+fn tcp_address(multiaddr: &Multiaddr) -> Option<SocketAddr> {
+    let mut ip = None;
+    let mut port = None;
+    for protocol in multiaddr.iter() {
+        match protocol {
+            Protocol::Ip4(v4) => ip = Some(IpAddr::V4(v4)),
+            Protocol::Ip6(v6) => ip = Some(IpAddr::V6(v6)),
+            Protocol::Tcp(p) => port = Some(p),
+            _ => {}
+        }
+    }
+    Some(SocketAddr::new(ip?, port?))
+}
+
+/// Whether the RPC server listens beyond localhost.
+fn public_rpc(config: &Configuration) -> bool {
+    config
+        .rpc
+        .addr
+        .iter()
+        .flatten()
+        .any(|e| !e.listen_addr.ip().is_loopback())
+}
+
+fn check_port(name: &str, addr: SocketAddr) -> Finding {
+    match TcpListener::bind(addr) {
+        Ok(_) => Finding::new(Severity::Ok, format!("{name} port {addr} is free")),
+        Err(e) if e.kind() == io::ErrorKind::AddrInUse => Finding::new(
+            Severity::Error,
+            format!("{name} port {addr} is already in use"),
+        )
+        .hint(
+            "another node, or an earlier run of this one, holds it: stop it or pick another port",
+        ),
+        Err(e) => Finding::new(
+            Severity::Error,
+            format!("{name} port {addr} can't be bound: {e}"),
+        ),
+    }
+}
+
+/// Whether the node can write to `path`, or create it.
+fn check_writable(name: &str, path: &Path) -> Finding {
+    let Some(existing) = path.ancestors().find(|p| p.exists()) else {
+        return Finding::new(
+            Severity::Error,
+            format!("{name} path {} has no existing parent", path.display()),
+        );
+    };
+    if !existing.is_dir() {
+        return Finding::new(
+            Severity::Error,
+            format!("{name} path {} is not a directory", existing.display()),
+        );
+    }
+    let probe = existing.join(".allfeat-doctor");
+    match fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe);
+            Finding::new(
+                Severity::Ok,
+                format!("{name} path {} is writable", path.display()),
+            )
+        }
+        Err(e) => Finding::new(
+            Severity::Error,
+            format!("{name} path {} is not writable: {e}", existing.display()),
+        )
+        .hint("run the node as the owner of its base path, or change it with --base-path"),
+    }
+}
+
+fn check_clock(server: &str) -> Finding {
+    let offset = match clock_offset(server) {
+        Ok(offset) => offset,
+        Err(e) => {
+            return Finding::new(
+                Severity::Warning,
+                format!("Clock not checked, {server}: {e}"),
+            )
+            .hint("make sure NTP is running, or pick a reachable server with --ntp-server");
+        }
+    };
+    let message = format!("Clock is {offset:+.3}s off {server}");
+    let severity = match offset.abs() {
+        skew if skew >= CLOCK_SKEW_ERROR => Severity::Error,
+        skew if skew >= CLOCK_SKEW_WARNING => Severity::Warning,
+        _ => return Finding::new(Severity::Ok, message),
+    };
+    Finding::new(severity, message)
+        .hint("enable NTP (chrony or systemd-timesyncd): a skewed validator misses its slots")
+}
+
+/// Offset of the local clock from `server`'s, in seconds, by a single SNTP exchange.
+fn clock_offset(server: &str) -> io::Result<f64> {
+    let addr = server
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no address"))?;
+    let local: IpAddr = if addr.is_ipv4() {
+        Ipv4Addr::UNSPECIFIED.into()
+    } else {
+        Ipv6Addr::UNSPECIFIED.into()
+    };
+    let socket = UdpSocket::bind((local, 0))?;
+    socket.set_read_timeout(Some(NTP_TIMEOUT))?;
+
+    // Leap indicator 0, version 4, client mode.
+    let mut packet = [0u8; 48];
+    packet[0] = 0x23;
+    let sent = unix_now();
+    socket.send_to(&packet, addr)?;
+    let (len, _) = socket.recv_from(&mut packet)?;
+    let received = unix_now();
+
+    // A server answers in mode 4, from a stratum other than 0 ("kiss-of-death").
+    if len < 48 || packet[0] & 0x07 != 4 || packet[1] == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid NTP response",
+        ));
+    }
+    Ok(ntp_offset(
+        sent,
+        ntp_timestamp(&packet[32..40]),
+        ntp_timestamp(&packet[40..48]),
+        received,
+    ))
+}
+
+/// The clock offset from the client's send and receive times and the server's receive and
+/// transmit times (RFC 5905).
+fn ntp_offset(sent: f64, server_received: f64, server_sent: f64, received: f64) -> f64 {
+    ((server_received - sent) + (server_sent - received)) / 2.0
+}
+
+/// Seconds since the Unix epoch of an NTP timestamp.
+fn ntp_timestamp(bytes: &[u8]) -> f64 {
+    let seconds = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let fraction = u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+    seconds as f64 - NTP_UNIX_OFFSET + fraction as f64 / (1u64 << 32) as f64
+}
+
+fn unix_now() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
+}
+
+/// Checks the hard limit of open files: the node raises its soft limit to it at startup.
+fn check_open_files(limits: Option<String>) -> Finding {
+    let Some(limit) = limits.as_deref().and_then(open_files_limit) else {
+        return Finding::new(Severity::Note, "Open files limit not checked");
+    };
+    if limit < RECOMMENDED_OPEN_FILES {
+        Finding::new(
+            Severity::Warning,
+            format!("Open files limited to {limit}, {RECOMMENDED_OPEN_FILES} recommended"),
+        )
+        .hint("raise it with `LimitNOFILE=` in the systemd unit, or `nofile` in limits.conf")
+    } else {
+        Finding::new(Severity::Ok, format!("Open files limited to {limit}"))
+    }
+}
+
+/// The hard limit of open files in the contents of `/proc/<pid>/limits`.
+fn open_files_limit(limits: &str) -> Option<u64> {
+    let line = limits.lines().find(|l| l.starts_with("Max open files"))?;
+    match line.split_whitespace().nth(4)? {
+        "unlimited" => Some(u64::MAX),
+        hard => hard.parse().ok(),
+    }
+}
+
+fn check_pruning(
+    authority: bool,
+    state_pruning: Option<&PruningMode>,
+    public_rpc: bool,
+) -> Finding {
+    let archive = matches!(
+        state_pruning,
+        Some(PruningMode::ArchiveAll | PruningMode::ArchiveCanonical)
+    );
+    match (authority, archive) {
+        (true, true) => Finding::new(
+            Severity::Warning,
+            "Validator keeps the state of every block",
+        )
+        .hint("validating only needs recent state: drop --state-pruning archive to save disk"),
+        (false, false) if public_rpc => Finding::new(
+            Severity::Note,
+            "Public RPC node only keeps the state of recent blocks",
+        )
+        .hint("queries about older blocks fail: use --state-pruning archive if it serves them"),
+        (_, true) => Finding::new(Severity::Ok, "State of every block kept (archive)"),
+        (_, false) => Finding::new(Severity::Ok, "State of recent blocks kept (pruned)"),
+    }
+}
+
+/// Checks the keystore holds one key of each of `key_types`, and only its owner can read it.
+fn check_keystore(keystore: &KeystoreConfig, key_types: &[KeyTypeId]) -> Vec<Finding> {
+    let KeystoreConfig::Path { path, password } = keystore else {
+        return vec![Finding::new(
+            Severity::Error,
+            "Keystore is in memory: session keys are lost on restart",
+        )];
+    };
+    // Opening the keystore creates its directory.
+    if !path.is_dir() {
+        return vec![
+            Finding::new(
+                Severity::Error,
+                format!("No keystore at {}", path.display()),
+            )
+            .hint(
+                "generate session keys with `author_rotateKeysWithOwner` (validator guide, step 3)",
+            ),
+        ];
+    }
+    let keystore = match sc_keystore::LocalKeystore::open(path.clone(), password.clone()) {
+        Ok(keystore) => keystore,
+        Err(e) => {
+            return vec![Finding::new(
+                Severity::Error,
+                format!("Keystore at {} can't be opened: {e}", path.display()),
+            )];
+        }
+    };
+
+    let mut findings: Vec<_> = key_types
+        .iter()
+        .map(|&key_type| {
+            let name = String::from_utf8_lossy(&key_type.0).into_owned();
+            match keystore.keys(key_type).map(|keys| keys.len()) {
+                Ok(0) => Finding::new(Severity::Error, format!("No `{name}` session key"))
+                    .hint(ROTATE_KEYS),
+                Ok(1) => Finding::new(Severity::Ok, format!("`{name}` session key present")),
+                Ok(n) => Finding::new(Severity::Note, format!("{n} `{name}` session keys"))
+                    .hint("only the one registered with `session.setKeys` is used"),
+                Err(e) => Finding::new(
+                    Severity::Error,
+                    format!("`{name}` session keys unreadable: {e}"),
+                ),
+            }
+        })
+        .collect();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        if let Ok(metadata) = fs::metadata(path) {
+            let mode = metadata.permissions().mode();
+            if mode & 0o077 != 0 {
+                findings.push(
+                    Finding::new(
+                        Severity::Warning,
+                        format!(
+                            "Keystore {} is open to other users ({:o})",
+                            path.display(),
+                            mode & 0o777
+                        ),
+                    )
+                    .hint(format!("chmod 700 {}", path.display())),
+                );
+            }
+        }
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMITS: &str = "\
+Limit                     Soft Limit           Hard Limit           Units
+Max cpu time              unlimited            unlimited            seconds
+Max open files            1024                 524288               files
+Max locked memory         8388608              8388608              bytes
+";
+
+    #[test]
+    fn hard_open_files_limit_is_read() {
+        assert_eq!(open_files_limit(LIMITS), Some(524_288));
+        assert_eq!(
+            open_files_limit(&LIMITS.replace("524288", "unlimited")),
+            Some(u64::MAX)
+        );
+        assert_eq!(
+            open_files_limit("Max cpu time  unlimited  unlimited  seconds"),
+            None
+        );
+
+        let low = check_open_files(Some(LIMITS.replace("524288", "4096")));
+        assert_eq!(low.severity, Severity::Warning);
+        assert_eq!(check_open_files(None).severity, Severity::Note);
+    }
+
+    #[test]
+    fn clock_offset_is_halfway_through_the_round_trip() {
+        // Sent at 100, received at 102; the server, 10s ahead, answered in between.
+        assert_eq!(ntp_offset(100.0, 110.5, 111.5, 102.0), 10.0);
+
+        let mut timestamp = (NTP_UNIX_OFFSET as u32 + 60).to_be_bytes().to_vec();
+        timestamp.extend((1u32 << 31).to_be_bytes());
+        assert_eq!(ntp_timestamp(&timestamp), 60.5);
+    }
+
+    #[test]
+    fn ports_in_use_are_reported() {
+        let taken = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let addr = taken.local_addr().unwrap();
+        assert_eq!(check_port("P2P", addr).severity, Severity::Error);
+
+        drop(taken);
+        assert_eq!(check_port("P2P", addr).severity, Severity::Ok);
+    }
+
+    #[test]
+    fn p2p_ports_are_read_from_multiaddrs() {
+        let tcp = |multiaddr: &str| tcp_address(&multiaddr.parse().unwrap());
+
+        assert_eq!(
+            tcp("/ip6/::/tcp/30333"),
+            Some((Ipv6Addr::UNSPECIFIED, 30333).into())
+        );
+        assert_eq!(
+            tcp("/ip4/0.0.0.0/tcp/30333/ws"),
+            Some((Ipv4Addr::UNSPECIFIED, 30333).into())
+        );
+        assert_eq!(tcp("/ip4/0.0.0.0/udp/30333/quic-v1"), None);
+    }
+
+    #[test]
+    fn pruning_is_checked_against_the_role() {
+        let archive = Some(&PruningMode::ArchiveAll);
+        let pruned = PruningMode::blocks_pruning(256);
+
+        assert_eq!(
+            check_pruning(true, archive, false).severity,
+            Severity::Warning
+        );
+        assert_eq!(check_pruning(true, None, false).severity, Severity::Ok);
+        assert_eq!(
+            check_pruning(false, Some(&pruned), true).severity,
+            Severity::Note
+        );
+        assert_eq!(check_pruning(false, archive, true).severity, Severity::Ok);
+    }
+
+    #[test]
+    fn missing_session_keys_are_reported() {
+        let path = std::env::temp_dir().join(format!("allfeat-doctor-{}", std::process::id()));
+        let config = KeystoreConfig::Path {
+            path: path.clone(),
+            password: None,
+        };
+        assert_eq!(
+            check_keystore(&config, &SESSION_KEYS)[0].severity,
+            Severity::Error
+        );
+
+        let keystore = sc_keystore::LocalKeystore::open(path.clone(), None).unwrap();
+        keystore
+            .sr25519_generate_new(SESSION_KEYS[0], None)
+            .unwrap();
+        let findings = check_keystore(&config, &SESSION_KEYS);
+        assert_eq!(findings[0].severity, Severity::Ok);
+        assert_eq!(findings[1].severity, Severity::Error);
+
+        for _ in 0..2 {
+            keystore
+                .ed25519_generate_new(SESSION_KEYS[1], None)
+                .unwrap();
+        }
+        let findings = check_keystore(&config, &SESSION_KEYS);
+        assert_eq!(findings[1].severity, Severity::Note);
+
+        fs::remove_dir_all(path).unwrap();
+    }
+}
@@ -0,0 +1,502 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Read-only block explorer served by the node, for private devnets where running a full
+//! explorer stack is overkill. Built with `--features explorer` and enabled with
+//! `--explorer <ADDR>`.
+//!
+//! It serves three kinds of pages, read from the node's own database:
+//! - `/`: the latest blocks;
+//! - `/block/<number or hash>`: a block's header and its decoded extrinsics;
+//! - `/account/<SS58 address>`: an account's nonce and its transactions in the latest blocks.
+
+use std::{fmt::Debug, net::TcpListener, sync::Arc, time::Duration};
+
+use allfeat_primitives::{AccountId, Address, Block, BlockNumber, Hash, Nonce, Signature};
+use frame_support::traits::{CallMetadata, GetCallMetadata};
+use sc_client_api::BlockBackend;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::crypto::Ss58Codec;
+use sp_runtime::{
+    MultiAddress,
+    generic::Preamble,
+    traits::{Block as BlockT, Header as HeaderT},
+};
+use substrate_frame_rpc_system::AccountNonceApi;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+
+use crate::chain_specs::IdentifyVariant;
+
+/// Blocks listed on the home page.
+const LATEST_BLOCKS: u32 = 20;
+/// Blocks searched for the transactions of an account.
+const ACCOUNT_HISTORY: u32 = 1_000;
+/// Longest request, headers included, read from a client.
+const MAX_REQUEST_SIZE: u64 = 8 * 1024;
+/// Time a client has to send its request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Characters of a call's arguments shown before they are cut, e.g. for `set_code`.
+const MAX_ARGS_LEN: usize = 512;
+
+const STYLE: &str = "body{font-family:sans-serif;margin:2em}table{border-collapse:collapse}\
+    td,th{border:1px solid #ccc;padding:.3em .6em;text-align:left;vertical-align:top}\
+    code{word-break:break-all}";
+
+/// An extrinsic decoded with the runtime's types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extrinsic {
+    /// Account that signed the transaction, `None` for inherents.
+    pub signer: Option<AccountId>,
+    pub pallet: &'static str,
+    pub call: &'static str,
+    /// The call and its arguments, as the runtime debug-prints them.
+    pub args: String,
+}
+
+impl Extrinsic {
+    fn new<Call: GetCallMetadata + Debug, Extension>(
+        preamble: Preamble<Address, Signature, Extension>,
+        function: Call,
+    ) -> Self {
+        let CallMetadata {
+            pallet_name,
+            function_name,
+        } = function.get_call_metadata();
+        let signer = match preamble {
+            Preamble::Signed(MultiAddress::Id(who), ..) => Some(who),
+            _ => None,
+        };
+        Self {
+            signer,
+            pallet: pallet_name,
+            call: function_name,
+            args: format!("{function:?}"),
+        }
+    }
+}
+
+/// Decodes an opaque extrinsic of the chain the node runs, `None` if it doesn't decode.
+pub type Decoder = fn(&<Block as BlockT>::Extrinsic) -> Option<Extrinsic>;
+
+#[cfg(feature = "melodie-runtime")]
+fn decode_melodie(xt: &<Block as BlockT>::Extrinsic) -> Option<Extrinsic> {
+    use sp_runtime::codec::{Decode, Encode};
+
+    let uxt = melodie_runtime::UncheckedExtrinsic::decode(&mut &xt.encode()[..]).ok()?;
+    Some(Extrinsic::new(uxt.preamble, uxt.function))
+}
+
+#[cfg(feature = "allfeat-runtime")]
+fn decode_allfeat(xt: &<Block as BlockT>::Extrinsic) -> Option<Extrinsic> {
+    use sp_runtime::codec::{Decode, Encode};
+
+    let uxt = allfeat_runtime::UncheckedExtrinsic::decode(&mut &xt.encode()[..]).ok()?;
+    Some(Extrinsic::new(uxt.preamble, uxt.function))
+}
+
+/// The decoder of the runtime the node picks for `chain_spec`.
+pub fn decoder(chain_spec: &dyn IdentifyVariant) -> Decoder {
+    #[cfg(feature = "melodie-runtime")]
+    if chain_spec.is_melodie() || !cfg!(feature = "allfeat-runtime") {
+        return decode_melodie;
+    }
+    #[cfg(feature = "allfeat-runtime")]
+    if chain_spec.is_allfeat() || !cfg!(feature = "melodie-runtime") {
+        return decode_allfeat;
+    }
+    let _ = chain_spec;
+    |_| None
+}
+
+/// A block, by number or by hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlockRef {
+    Number(BlockNumber),
+    Hash(Hash),
+}
+
+impl BlockRef {
+    fn parse(id: &str) -> Option<Self> {
+        match id.strip_prefix("0x") {
+            Some(hex) if hex.len() == 64 => hex.parse().ok().map(Self::Hash),
+            Some(_) => None,
+            None => id.parse().ok().map(Self::Number),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Page {
+    Latest,
+    Block(BlockRef),
+    Account(AccountId),
+    NotFound,
+}
+
+/// The page a request target asks for. The query string is ignored.
+fn route(target: &str) -> Page {
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+    match (segments.next(), segments.next(), segments.next()) {
+        (None, ..) => Page::Latest,
+        (Some("block"), Some(id), None) => BlockRef::parse(id).map_or(Page::NotFound, Page::Block),
+        (Some("account"), Some(address), None) => {
+            AccountId::from_ss58check(address).map_or(Page::NotFound, Page::Account)
+        }
+        _ => Page::NotFound,
+    }
+}
+
+struct Response {
+    status: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn ok(title: &str, content: &str) -> Self {
+        Self {
+            status: "200 OK",
+            body: html(title, content),
+        }
+    }
+
+    fn error(status: &'static str, message: &str) -> Self {
+        Self {
+            status,
+            body: html(status, &format!("<p>{}</p>", escape(message))),
+        }
+    }
+
+    fn not_found() -> Self {
+        Self::error("404 Not Found", "No such block or account.")
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        format!(
+            "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            self.status,
+            self.body.len(),
+            self.body,
+        )
+        .into_bytes()
+    }
+}
+
+fn html(title: &str, content: &str) -> String {
+    let title = escape(title);
+    format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{title} · Allfeat explorer\
+         </title><style>{STYLE}</style></head><body><nav><a href=\"/\">Latest blocks</a></nav>\
+         <h1>{title}</h1>{content}</body></html>"
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// `args` cut to [`MAX_ARGS_LEN`] characters.
+fn truncate(args: &str) -> String {
+    match args.char_indices().nth(MAX_ARGS_LEN) {
+        Some((end, _)) => format!("{}…", &args[..end]),
+        None => args.to_owned(),
+    }
+}
+
+fn account_link(who: &AccountId) -> String {
+    let address = who.to_ss58check();
+    format!("<a href=\"/account/{address}\">{address}</a>")
+}
+
+/// Table row of the `index`th extrinsic of block `number`.
+fn extrinsic_row(number: BlockNumber, index: usize, xt: Option<&Extrinsic>) -> String {
+    let block = format!("<a href=\"/block/{number}\">#{number}</a>-{index}");
+    match xt {
+        Some(xt) => format!(
+            "<tr><td>{block}</td><td>{}</td><td>{}.{}</td><td><code>{}</code></td></tr>",
+            xt.signer.as_ref().map(account_link).unwrap_or_default(),
+            xt.pallet,
+            xt.call,
+            escape(&truncate(&xt.args)),
+        ),
+        None => format!("<tr><td>{block}</td><td></td><td colspan=\"2\">undecodable</td></tr>"),
+    }
+}
+
+const EXTRINSICS_HEADER: &str =
+    "<tr><th>Extrinsic</th><th>Signer</th><th>Call</th><th>Arguments</th></tr>";
+
+fn latest<C>(client: &C, decode: Decoder) -> sp_blockchain::Result<Response>
+where
+    C: HeaderBackend<Block> + BlockBackend<Block>,
+{
+    let info = client.info();
+    let mut rows = String::new();
+    for number in (0..=info.best_number).rev().take(LATEST_BLOCKS as usize) {
+        let Some(hash) = client.hash(number)? else {
+            continue;
+        };
+        let extrinsics = client.block_body(hash)?.unwrap_or_default();
+        let transactions = extrinsics
+            .iter()
+            .filter_map(decode)
+            .filter(|xt| xt.signer.is_some())
+            .map(|xt| format!("{}.{}", xt.pallet, xt.call))
+            .collect::<Vec<_>>()
+            .join(", ");
+        rows.push_str(&format!(
+            "<tr><td><a href=\"/block/{number}\">#{number}</a></td><td><code>{hash:?}</code></td>\
+             <td>{}</td><td>{transactions}</td></tr>",
+            extrinsics.len(),
+        ));
+    }
+
+    Ok(Response::ok(
+        "Latest blocks",
+        &format!(
+            "<p>Best block #{}, finalized #{}.</p><table><tr><th>Block</th><th>Hash</th>\
+             <th>Extrinsics</th><th>Transactions</th></tr>{rows}</table>",
+            info.best_number, info.finalized_number,
+        ),
+    ))
+}
+
+fn block<C>(client: &C, decode: Decoder, block: BlockRef) -> sp_blockchain::Result<Response>
+where
+    C: HeaderBackend<Block> + BlockBackend<Block>,
+{
+    let hash = match block {
+        BlockRef::Hash(hash) => hash,
+        BlockRef::Number(number) => match client.hash(number)? {
+            Some(hash) => hash,
+            None => return Ok(Response::not_found()),
+        },
+    };
+    let Some(header) = client.header(hash)? else {
+        return Ok(Response::not_found());
+    };
+    let number = *header.number();
+    let rows = client
+        .block_body(hash)?
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+        .map(|(index, xt)| extrinsic_row(number, index, decode(xt).as_ref()))
+        .collect::<String>();
+
+    Ok(Response::ok(
+        &format!("Block #{number}"),
+        &format!(
+            "<table><tr><th>Hash</th><td><code>{hash:?}</code></td></tr>\
+             <tr><th>Parent</th><td><a href=\"/block/{parent:?}\"><code>{parent:?}</code></a></td></tr>\
+             <tr><th>State root</th><td><code>{:?}</code></td></tr>\
+             <tr><th>Extrinsics root</th><td><code>{:?}</code></td></tr></table>\
+             <h2>Extrinsics</h2><table>{EXTRINSICS_HEADER}{rows}</table>",
+            header.state_root(),
+            header.extrinsics_root(),
+            parent = header.parent_hash(),
+        ),
+    ))
+}
+
+fn account<C>(client: &C, decode: Decoder, who: AccountId) -> sp_blockchain::Result<Response>
+where
+    C: HeaderBackend<Block> + BlockBackend<Block> + ProvideRuntimeApi<Block>,
+    C::Api: AccountNonceApi<Block, AccountId, Nonce>,
+{
+    let info = client.info();
+    let nonce = client
+        .runtime_api()
+        .account_nonce(info.best_hash, who.clone())?;
+
+    let mut rows = String::new();
+    for number in (0..=info.best_number).rev().take(ACCOUNT_HISTORY as usize) {
+        let Some(hash) = client.hash(number)? else {
+            continue;
+        };
+        for (index, xt) in client
+            .block_body(hash)?
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+        {
+            if let Some(xt) = decode(xt).filter(|xt| xt.signer.as_ref() == Some(&who)) {
+                rows.push_str(&extrinsic_row(number, index, Some(&xt)));
+            }
+        }
+    }
+
+    Ok(Response::ok(
+        &format!("Account {}", who.to_ss58check()),
+        &format!(
+            "<p>Nonce {nonce}.</p><h2>Transactions in the last {ACCOUNT_HISTORY} blocks</h2>\
+             <table>{EXTRINSICS_HEADER}{rows}</table>"
+        ),
+    ))
+}
+
+fn render<C>(client: &C, decode: Decoder, page: Page) -> Response
+where
+    C: HeaderBackend<Block> + BlockBackend<Block> + ProvideRuntimeApi<Block>,
+    C::Api: AccountNonceApi<Block, AccountId, Nonce>,
+{
+    let response = match page {
+        Page::Latest => latest(client, decode),
+        Page::Block(id) => block(client, decode, id),
+        Page::Account(who) => account(client, decode, who),
+        Page::NotFound => Ok(Response::not_found()),
+    };
+    response.unwrap_or_else(|e| {
+        log::debug!("Block explorer failed to read the chain: {e}");
+        Response::error("500 Internal Server Error", &e.to_string())
+    })
+}
+
+/// Answer the request on `stream`, then close it.
+async fn respond<C>(
+    mut stream: tokio::net::TcpStream,
+    client: &C,
+    decode: Decoder,
+) -> std::io::Result<()>
+where
+    C: HeaderBackend<Block> + BlockBackend<Block> + ProvideRuntimeApi<Block>,
+    C::Api: AccountNonceApi<Block, AccountId, Nonce>,
+{
+    let (reader, mut writer) = stream.split();
+    let mut lines = BufReader::new(reader.take(MAX_REQUEST_SIZE)).lines();
+    let request_line = lines.next_line().await?.unwrap_or_default();
+    // Headers are read to the end so that closing the connection doesn't reset it.
+    while let Some(header) = lines.next_line().await? {
+        if header.is_empty() {
+            break;
+        }
+    }
+
+    let mut request = request_line.split_whitespace();
+    let response = match (request.next(), request.next()) {
+        (Some("GET"), Some(target)) => render(client, decode, route(target)),
+        (Some(_), Some(_)) => {
+            Response::error("405 Method Not Allowed", "The explorer is read-only.")
+        }
+        _ => Response::error("400 Bad Request", "Malformed request."),
+    };
+    writer.write_all(&response.into_bytes()).await?;
+    writer.shutdown().await
+}
+
+/// Serve the explorer on `listener` until the node stops.
+pub async fn serve<C>(listener: TcpListener, client: Arc<C>, decode: Decoder)
+where
+    C: HeaderBackend<Block> + BlockBackend<Block> + ProvideRuntimeApi<Block> + Send + Sync,
+    C: 'static,
+    C::Api: AccountNonceApi<Block, AccountId, Nonce>,
+{
+    let listener = match listener
+        .set_nonblocking(true)
+        .and_then(|()| tokio::net::TcpListener::from_std(listener))
+    {
+        Ok(listener) => listener,
+        Err(e) => {
+            log::error!("Unable to serve the block explorer: {e}");
+            return;
+        }
+    };
+    if let Ok(addr) = listener.local_addr() {
+        log::info!("Block explorer listening on http://{addr}");
+    }
+
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                log::debug!("Block explorer failed to accept a connection: {e}");
+                continue;
+            }
+        };
+        let client = client.clone();
+        tokio::spawn(async move {
+            match tokio::time::timeout(REQUEST_TIMEOUT, respond(stream, &*client, decode)).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => log::debug!("Block explorer request failed: {e}"),
+                Err(_) => log::debug!("Block explorer request timed out"),
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_are_routed_to_pages() {
+        let alice = AccountId::new([1; 32]);
+        let hash = Hash::repeat_byte(0xab);
+
+        assert_eq!(route("/"), Page::Latest);
+        assert_eq!(route("/?refresh=1"), Page::Latest);
+        assert_eq!(route("/block/42"), Page::Block(BlockRef::Number(42)));
+        assert_eq!(
+            route(&format!("/block/{hash:?}/")),
+            Page::Block(BlockRef::Hash(hash))
+        );
+        assert_eq!(
+            route(&format!("/account/{}", alice.to_ss58check())),
+            Page::Account(alice)
+        );
+    }
+
+    #[test]
+    fn unknown_targets_are_not_found() {
+        for target in [
+            "/blocks",
+            "/block",
+            "/block/-1",
+            "/block/0xab",
+            "/block/42/extrinsics",
+            "/account/not-an-address",
+            "/../etc/passwd",
+        ] {
+            assert_eq!(route(target), Page::NotFound, "{target}");
+        }
+    }
+
+    #[test]
+    fn arguments_are_escaped_and_cut() {
+        assert_eq!(
+            escape("remark { remark: \"<b>\" & 'x' }"),
+            "remark { remark: &quot;&lt;b&gt;&quot; &amp; &#39;x&#39; }"
+        );
+
+        let code = "é".repeat(MAX_ARGS_LEN + 1);
+        assert_eq!(truncate(&code), format!("{}…", "é".repeat(MAX_ARGS_LEN)));
+        assert_eq!(truncate("short"), "short");
+    }
+}
@@ -0,0 +1,325 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Structured JSON logging (`--log-format json`).
+//!
+//! Every event is written to stderr as one JSON object per line: timestamp, level, target, the
+//! event's fields and the fields of the spans it was emitted in. Log pipelines can parse node
+//! output without regexes.
+//!
+//! The level of each subsystem (log target) can be changed while the node runs through the
+//! `logging_*` RPCs, which are unsafe and only served with `--rpc-methods unsafe`. Levels set
+//! this way are persisted in `log-levels.json` under the base path and applied again on
+//! restart, on top of the `--log` directives.
+//!
+//! The text format keeps Substrate's own logger, whose filters are reloaded with
+//! `--enable-log-reloading` and `system_addLogFilter` instead.
+
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Mutex, OnceLock},
+};
+
+use sc_cli::{Runner, SubstrateCli};
+use serde::{Deserialize, Serialize};
+use tracing_subscriber::{
+    EnvFilter, Registry,
+    filter::LevelFilter,
+    fmt,
+    prelude::*,
+    reload::{self, Handle},
+};
+
+use crate::cli::Cli;
+
+/// File the levels set at runtime are persisted in, under the base path.
+const LEVELS_FILE: &str = "log-levels.json";
+
+/// Level of the targets no directive applies to, as with Substrate's logger.
+const DEFAULT_DIRECTIVE: &str = "info";
+
+/// Max number of targets with a level set at runtime.
+pub const MAX_OVERRIDES: usize = 64;
+
+static LOG_LEVELS: OnceLock<LogLevels> = OnceLock::new();
+
+/// Why the levels couldn't be changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LevelError {
+    InvalidTarget(String),
+    InvalidLevel(String),
+    /// No level was set for the target.
+    NotSet(String),
+    /// [`MAX_OVERRIDES`] targets already have a level set.
+    TooManyTargets,
+    /// The levels couldn't be persisted or applied.
+    Failed(String),
+}
+
+/// Levels set at runtime, keyed by log target.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LevelOverrides(BTreeMap<String, String>);
+
+impl LevelOverrides {
+    /// Read the overrides persisted at `path`. A missing or unreadable file yields none.
+    fn load(path: &Path) -> Self {
+        fs::read(path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, serde_json::to_vec_pretty(self)?)
+    }
+
+    /// The filter directives: the default level, the `--log` directives, then the overrides,
+    /// which take precedence over directives for the same target.
+    fn directives(&self, base: &[String]) -> String {
+        std::iter::once(DEFAULT_DIRECTIVE.to_string())
+            .chain(base.iter().cloned())
+            .chain(
+                self.0
+                    .iter()
+                    .map(|(target, level)| format!("{target}={level}")),
+            )
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn set(&mut self, target: &str, level: &str) -> Result<(), LevelError> {
+        let valid_target = !target.is_empty()
+            && target
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | ':'));
+        if !valid_target {
+            return Err(LevelError::InvalidTarget(target.to_string()));
+        }
+        let level = LevelFilter::from_str(level)
+            .map_err(|_| LevelError::InvalidLevel(level.to_string()))?;
+        if !self.0.contains_key(target) && self.0.len() >= MAX_OVERRIDES {
+            return Err(LevelError::TooManyTargets);
+        }
+        self.0.insert(target.to_string(), level.to_string());
+        Ok(())
+    }
+}
+
+/// Handle on the levels of the JSON logger.
+pub struct LogLevels {
+    /// The `--log` directives.
+    base: Vec<String>,
+    path: PathBuf,
+    overrides: Mutex<LevelOverrides>,
+    handle: Handle<EnvFilter, Registry>,
+}
+
+impl LogLevels {
+    /// Levels set at runtime, keyed by target.
+    pub fn overrides(&self) -> BTreeMap<String, String> {
+        self.lock().0.clone()
+    }
+
+    /// Set the level of `target`, one of `trace`, `debug`, `info`, `warn`, `error` or `off`.
+    pub fn set(&self, target: &str, level: &str) -> Result<(), LevelError> {
+        self.update(|overrides| overrides.set(target, level))
+    }
+
+    /// Drop the level set for `target`, falling back to the `--log` directives.
+    pub fn reset(&self, target: &str) -> Result<(), LevelError> {
+        self.update(|overrides| {
+            overrides
+                .0
+                .remove(target)
+                .map(drop)
+                .ok_or_else(|| LevelError::NotSet(target.to_string()))
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LevelOverrides> {
+        self.overrides
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Apply `change` to the overrides, persist them and reload the filter.
+    fn update(
+        &self,
+        change: impl FnOnce(&mut LevelOverrides) -> Result<(), LevelError>,
+    ) -> Result<(), LevelError> {
+        let mut overrides = self.lock();
+        let mut next = overrides.clone();
+        change(&mut next)?;
+
+        let filter = EnvFilter::try_new(next.directives(&self.base))
+            .map_err(|e| LevelError::Failed(e.to_string()))?;
+        next.save(&self.path)
+            .map_err(|e| LevelError::Failed(format!("failed to persist log levels: {e}")))?;
+        self.handle
+            .reload(filter)
+            .map_err(|e| LevelError::Failed(e.to_string()))?;
+        sync_log_max_level();
+
+        *overrides = next;
+        Ok(())
+    }
+}
+
+/// The JSON logger's levels, if it is the node's logger.
+pub fn log_levels() -> Option<&'static LogLevels> {
+    LOG_LEVELS.get()
+}
+
+/// Build the runner of the node with the JSON logger instead of Substrate's.
+#[allow(clippy::result_large_err)]
+pub fn create_json_runner(cli: &Cli) -> sc_cli::Result<Runner<Cli>> {
+    let tokio_runtime = sc_cli::build_runtime()?;
+    // `capture` needs to be called in a tokio context.
+    let signals = tokio_runtime.block_on(async { sc_cli::Signals::capture() })?;
+    let config = cli.create_configuration(&cli.run, tokio_runtime.handle().clone())?;
+
+    init(cli.run.shared_params.log.clone(), config.base_path.path())
+        .map_err(|e| sc_cli::Error::Input(format!("failed to initialize the JSON logger: {e}")))?;
+
+    Runner::new(config, tokio_runtime, signals)
+}
+
+/// Install the JSON logger as the global subscriber.
+fn init(base: Vec<String>, base_path: &Path) -> Result<(), String> {
+    let path = base_path.join(LEVELS_FILE);
+    let mut overrides = LevelOverrides::load(&path);
+    let filter = EnvFilter::try_new(overrides.directives(&base)).or_else(|_| {
+        // Persisted levels that no longer parse are dropped rather than blocking startup.
+        overrides = LevelOverrides::default();
+        EnvFilter::try_new(overrides.directives(&base)).map_err(|e| e.to_string())
+    })?;
+    let (filter, handle) = reload::Layer::new(filter);
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(
+            fmt::layer()
+                .json()
+                .with_writer(io::stderr)
+                .with_current_span(true)
+                .with_span_list(true),
+        )
+        .try_init()
+        .map_err(|e| e.to_string())?;
+    sync_log_max_level();
+
+    LOG_LEVELS
+        .set(LogLevels {
+            base,
+            path,
+            overrides: Mutex::new(overrides),
+            handle,
+        })
+        .map_err(|_| "the JSON logger is already initialized".to_string())
+}
+
+/// Let `log` records through up to the most verbose level the filter enables: they are
+/// filtered once turned into `tracing` events, but dropped by `log` itself above its max level.
+fn sync_log_max_level() {
+    let current = LevelFilter::current();
+    let max = [
+        (LevelFilter::TRACE, log::LevelFilter::Trace),
+        (LevelFilter::DEBUG, log::LevelFilter::Debug),
+        (LevelFilter::INFO, log::LevelFilter::Info),
+        (LevelFilter::WARN, log::LevelFilter::Warn),
+        (LevelFilter::ERROR, log::LevelFilter::Error),
+    ]
+    .into_iter()
+    .find(|(level, _)| current >= *level)
+    .map_or(log::LevelFilter::Off, |(_, max)| max);
+    log::set_max_level(max);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_come_after_cli_directives() {
+        let mut overrides = LevelOverrides::default();
+        assert_eq!(
+            overrides.directives(&["sync=debug".into()]),
+            "info,sync=debug"
+        );
+
+        overrides.set("sync", "TRACE").unwrap();
+        overrides.set("afg", "warn").unwrap();
+        assert_eq!(
+            overrides.directives(&["sync=debug".into()]),
+            "info,sync=debug,afg=warn,sync=trace"
+        );
+        EnvFilter::try_new(overrides.directives(&[])).unwrap();
+    }
+
+    #[test]
+    fn invalid_targets_and_levels_are_refused() {
+        let mut overrides = LevelOverrides::default();
+        assert_eq!(
+            overrides.set("", "info"),
+            Err(LevelError::InvalidTarget("".into()))
+        );
+        assert_eq!(
+            overrides.set("sync=trace,afg", "info"),
+            Err(LevelError::InvalidTarget("sync=trace,afg".into()))
+        );
+        assert_eq!(
+            overrides.set("sync", "verbose"),
+            Err(LevelError::InvalidLevel("verbose".into()))
+        );
+        assert_eq!(overrides, LevelOverrides::default());
+    }
+
+    #[test]
+    fn overridden_targets_are_capped() {
+        let mut overrides = LevelOverrides::default();
+        for i in 0..MAX_OVERRIDES {
+            overrides.set(&format!("target{i}"), "debug").unwrap();
+        }
+        assert_eq!(
+            overrides.set("sync", "debug"),
+            Err(LevelError::TooManyTargets)
+        );
+        // Targets that already have a level can still be changed.
+        overrides.set("target0", "trace").unwrap();
+    }
+
+    #[test]
+    fn overrides_round_trip_through_the_levels_file() {
+        let path =
+            std::env::temp_dir().join(format!("allfeat-{}-{LEVELS_FILE}", std::process::id()));
+        assert_eq!(LevelOverrides::load(&path), LevelOverrides::default());
+
+        let mut overrides = LevelOverrides::default();
+        overrides.set("txpool", "debug").unwrap();
+        overrides.save(&path).unwrap();
+        assert_eq!(LevelOverrides::load(&path), overrides);
+
+        fs::write(&path, b"not json").unwrap();
+        assert_eq!(LevelOverrides::load(&path), LevelOverrides::default());
+        fs::remove_file(&path).unwrap();
+    }
+}
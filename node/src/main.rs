@@ -34,8 +34,19 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
 mod chain_specs;
 mod cli;
+mod db_check;
+mod doctor;
+mod logging;
+mod peer_sets;
+mod remote_keystore;
 mod rpc;
 mod service;
+mod upgrade_notice;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod block_weights;
+#[cfg(feature = "explorer")]
+mod explorer;
 
 // runtime must be declared after service (uses service types)
 // but before command (command uses runtime macros)
@@ -0,0 +1,212 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Peer-set budgets by node role, so that validators spend their connections on the validator
+//! backbone and RPC nodes on the full nodes feeding them.
+//!
+//! The default peer set of `sc-network` has no notion of peer role: slots are filled by whoever
+//! is discovered first. Preference is expressed with reserved peers instead, which are always
+//! dialed, are never evicted and don't count against the slots:
+//!
+//! | Topology    | Preferred peers             | Light-client slots | Non-reserved inbound     |
+//! |-------------|-----------------------------|--------------------|--------------------------|
+//! | `validator` | other validators, sentries  | none               | capped at [`VALIDATOR_MAX_IN_PEERS`] |
+//! | `rpc`       | full nodes                  | none               | unchanged                |
+//! | `full`      | any                         | unchanged          | unchanged                |
+//!
+//! Explicit `--in-peers` / `--out-peers` values below these caps are kept.
+
+use sc_network::config::{MultiaddrWithPeerId, NetworkConfiguration, Role};
+
+/// Non-reserved inbound peers a validator accepts: enough to stay discoverable and to reach
+/// the rest of the network, few enough not to delay block announcements and votes to the
+/// backbone.
+pub const VALIDATOR_MAX_IN_PEERS: u32 = 16;
+
+/// Which peers the node gives its connections to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PeerTopology {
+    /// `validator` when running with `--validator`, `full` otherwise.
+    Auto,
+    /// Part of the validator backbone: keeps to the preferred validators and sentries.
+    Validator,
+    /// Serves RPC: syncs from the preferred full nodes, leaving validators alone.
+    Rpc,
+    /// No preference beyond the preferred peers.
+    Full,
+}
+
+/// Peer-set parameters of the node.
+#[derive(Debug, Clone, clap::Args)]
+pub struct PeerSetParams {
+    /// Which peers the node gives its connections to.
+    #[arg(long, value_enum, default_value_t = PeerTopology::Auto)]
+    pub peer_topology: PeerTopology,
+
+    /// Peers to stay connected to whatever the slots, e.g. the other validators and sentries
+    /// of the backbone for a validator, or the full nodes an RPC node syncs from.
+    ///
+    /// They are added to the reserved nodes: unlike `--reserved-only`, other peers are still
+    /// accepted within the topology's budget.
+    #[arg(long, value_name = "ADDR", num_args = 1..)]
+    pub preferred_peers: Vec<MultiaddrWithPeerId>,
+}
+
+impl PeerSetParams {
+    /// The topology of a node running as `role`.
+    pub fn topology(&self, role: &Role) -> PeerTopology {
+        match self.peer_topology {
+            PeerTopology::Auto if role.is_authority() => PeerTopology::Validator,
+            PeerTopology::Auto => PeerTopology::Full,
+            topology => topology,
+        }
+    }
+
+    /// Fit the default peer set of `network` to the topology of a node running as `role`.
+    pub fn apply(&self, network: &mut NetworkConfiguration, role: &Role) -> PeerTopology {
+        let topology = self.topology(role);
+        let set = &mut network.default_peers_set;
+
+        for peer in &self.preferred_peers {
+            if !set.reserved_nodes.contains(peer) {
+                set.reserved_nodes.push(peer.clone());
+            }
+        }
+
+        if matches!(topology, PeerTopology::Validator | PeerTopology::Rpc) {
+            // Slots beyond `default_peers_set_num_full` are the light clients'.
+            let full_in_peers = network
+                .default_peers_set_num_full
+                .saturating_sub(set.out_peers)
+                .min(set.in_peers);
+            set.in_peers = match topology {
+                PeerTopology::Validator => full_in_peers.min(VALIDATOR_MAX_IN_PEERS),
+                _ => full_in_peers,
+            };
+            network.default_peers_set_num_full = set.in_peers + set.out_peers;
+        }
+
+        topology
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The network configuration `sc-cli` builds with its default peer counts: 32 full and 100
+    /// light inbound peers, 8 outbound.
+    fn cli_defaults() -> NetworkConfiguration {
+        let mut network = NetworkConfiguration::new_local();
+        network.default_peers_set.in_peers = 32 + 100;
+        network.default_peers_set.out_peers = 8;
+        network.default_peers_set_num_full = 32 + 8;
+        network
+    }
+
+    fn peer(i: u8) -> MultiaddrWithPeerId {
+        let id = sc_network::Keypair::ed25519_from_bytes([i; 32])
+            .unwrap()
+            .public()
+            .to_peer_id();
+        format!("/ip4/10.0.0.{i}/tcp/30333/p2p/{id}")
+            .parse()
+            .unwrap()
+    }
+
+    fn params(
+        peer_topology: PeerTopology,
+        preferred_peers: Vec<MultiaddrWithPeerId>,
+    ) -> PeerSetParams {
+        PeerSetParams {
+            peer_topology,
+            preferred_peers,
+        }
+    }
+
+    /// `(in_peers, out_peers, num_full)` of the default set.
+    fn slots(network: &NetworkConfiguration) -> (u32, u32, u32) {
+        (
+            network.default_peers_set.in_peers,
+            network.default_peers_set.out_peers,
+            network.default_peers_set_num_full,
+        )
+    }
+
+    #[test]
+    fn auto_follows_the_node_role() {
+        let auto = params(PeerTopology::Auto, vec![]);
+        assert_eq!(auto.topology(&Role::Authority), PeerTopology::Validator);
+        assert_eq!(auto.topology(&Role::Full), PeerTopology::Full);
+
+        let rpc = params(PeerTopology::Rpc, vec![]);
+        assert_eq!(rpc.topology(&Role::Authority), PeerTopology::Rpc);
+    }
+
+    #[test]
+    fn validators_keep_to_the_backbone() {
+        let mut network = cli_defaults();
+        let backbone = vec![peer(1), peer(2)];
+
+        let topology =
+            params(PeerTopology::Auto, backbone.clone()).apply(&mut network, &Role::Authority);
+
+        assert_eq!(topology, PeerTopology::Validator);
+        assert_eq!(network.default_peers_set.reserved_nodes, backbone);
+        assert_eq!(
+            slots(&network),
+            (VALIDATOR_MAX_IN_PEERS, 8, VALIDATOR_MAX_IN_PEERS + 8)
+        );
+    }
+
+    #[test]
+    fn rpc_nodes_drop_light_client_slots() {
+        let mut network = cli_defaults();
+
+        params(PeerTopology::Rpc, vec![peer(3)]).apply(&mut network, &Role::Full);
+
+        assert_eq!(network.default_peers_set.reserved_nodes, vec![peer(3)]);
+        assert_eq!(slots(&network), (32, 8, 40));
+    }
+
+    #[test]
+    fn full_nodes_only_add_their_preferred_peers() {
+        let mut network = cli_defaults();
+        network.default_peers_set.reserved_nodes = vec![peer(1)];
+
+        params(PeerTopology::Full, vec![peer(1), peer(2)]).apply(&mut network, &Role::Full);
+
+        // Peers given through `--reserved-nodes` as well are kept once.
+        assert_eq!(
+            network.default_peers_set.reserved_nodes,
+            vec![peer(1), peer(2)]
+        );
+        assert_eq!(slots(&network), (132, 8, 40));
+    }
+
+    #[test]
+    fn lower_explicit_peer_counts_are_kept() {
+        let mut network = cli_defaults();
+        network.default_peers_set.in_peers = 4 + 100;
+        network.default_peers_set_num_full = 4 + 8;
+
+        params(PeerTopology::Validator, vec![]).apply(&mut network, &Role::Authority);
+
+        assert_eq!(slots(&network), (4, 8, 12));
+    }
+}
@@ -0,0 +1,611 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keystore backed by a remote signer, so that validators can keep their session keys in an
+//! HSM or a secrets manager (Vault transit engine, cloud KMS gateway, ...) rather than on the
+//! node's disk.
+//!
+//! The node never sees secret material: it asks the signer for public keys and signatures over
+//! JSON-RPC. Keys, messages and signatures are `0x`-prefixed hex, schemes are named `sr25519`,
+//! `ed25519` and `ecdsa`, and key types are their four-character ids (`aura`, `gran`, ...).
+//!
+//! | Method              | Params                                | Result              |
+//! |---------------------|---------------------------------------|---------------------|
+//! | `signer_publicKeys` | `[scheme, keyType]`                   | `[public]`          |
+//! | `signer_generate`   | `[scheme, keyType, seed \| null]`     | `public`            |
+//! | `signer_sign`       | `[scheme, keyType, public, message]`  | `signature \| null` |
+//! | `signer_hasKeys`    | `[[[public, keyType], ...]]`          | `bool`              |
+//!
+//! Failures are closed: an unreachable signer yields no keys and no signatures, so the node
+//! skips its authoring slots and votes instead of acting on a partial view. Signatures are
+//! checked against the requested public key before use. Importing secret keys
+//! (`author_insertKey`), VRF signing and pre-hashed ECDSA signing are not supported.
+
+use std::{fmt, sync::mpsc, thread, time::Duration};
+
+use jsonrpsee::core::{
+    client::{ClientT, Error as ClientError},
+    params::ArrayParams,
+};
+use serde_json::{Value, json};
+use sp_core::{
+    Bytes, Pair,
+    crypto::{ByteArray, KeyTypeId},
+    ecdsa, ed25519, sr25519,
+};
+use sp_keystore::{Error, Keystore};
+
+/// How to reach the remote signer.
+#[derive(Debug, Clone)]
+pub struct RemoteSignerConfig {
+    /// JSON-RPC endpoint of the signer.
+    pub url: String,
+    /// Timeout of a single request.
+    pub timeout: Duration,
+}
+
+/// Why a request to the remote signer failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignerError {
+    /// The signer could not be reached or did not answer in time.
+    Unavailable(String),
+    /// The signer answered with a JSON-RPC error.
+    Rejected(String),
+}
+
+impl fmt::Display for SignerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unavailable(reason) => write!(f, "remote signer unavailable: {reason}"),
+            Self::Rejected(reason) => write!(f, "remote signer rejected the request: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SignerError {}
+
+/// A JSON-RPC connection to the remote signer.
+pub trait SignerTransport: Send + Sync {
+    /// Call `method` with positional `params`.
+    fn request(&self, method: &str, params: Vec<Value>) -> Result<Value, SignerError>;
+}
+
+/// [`SignerTransport`] over HTTP.
+///
+/// Keystore calls are synchronous and made from within the node's async tasks, so requests are
+/// served by a dedicated thread running its own single-threaded runtime instead of blocking on
+/// the node's.
+pub struct HttpTransport {
+    requests: mpsc::Sender<Request>,
+    timeout: Duration,
+}
+
+type Request = (
+    String,
+    Vec<Value>,
+    mpsc::SyncSender<Result<Value, SignerError>>,
+);
+
+impl HttpTransport {
+    /// Spawn the thread serving requests to `config.url`.
+    pub fn spawn(config: &RemoteSignerConfig) -> Result<Self, SignerError> {
+        let (requests, queue) = mpsc::channel::<Request>();
+        let (ready, started) = mpsc::sync_channel(1);
+        let RemoteSignerConfig { url, timeout } = config.clone();
+
+        thread::Builder::new()
+            .name("remote-signer".into())
+            .spawn(move || {
+                let client = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map_err(|e| SignerError::Unavailable(e.to_string()))
+                    .and_then(|runtime| {
+                        let client = runtime.block_on(async {
+                            jsonrpsee::http_client::HttpClientBuilder::default()
+                                .request_timeout(timeout)
+                                .build(&url)
+                                .map_err(|e| SignerError::Unavailable(e.to_string()))
+                        })?;
+                        Ok((runtime, client))
+                    });
+                let (runtime, client) = match client {
+                    Ok(client) => {
+                        let _ = ready.send(Ok(()));
+                        client
+                    }
+                    Err(e) => {
+                        let _ = ready.send(Err(e));
+                        return;
+                    }
+                };
+
+                for (method, params, reply) in queue {
+                    let _ = reply.send(runtime.block_on(call(&client, &method, params)));
+                }
+            })
+            .map_err(|e| SignerError::Unavailable(e.to_string()))?;
+
+        started
+            .recv()
+            .map_err(|_| SignerError::Unavailable("signer thread exited".into()))??;
+        Ok(Self { requests, timeout })
+    }
+}
+
+async fn call<C: ClientT>(
+    client: &C,
+    method: &str,
+    params: Vec<Value>,
+) -> Result<Value, SignerError> {
+    let mut rpc_params = ArrayParams::new();
+    for param in params {
+        rpc_params
+            .insert(param)
+            .map_err(|e| SignerError::Rejected(e.to_string()))?;
+    }
+    client
+        .request(method, rpc_params)
+        .await
+        .map_err(|e| match e {
+            ClientError::Call(err) => SignerError::Rejected(err.message().to_owned()),
+            other => SignerError::Unavailable(other.to_string()),
+        })
+}
+
+impl SignerTransport for HttpTransport {
+    fn request(&self, method: &str, params: Vec<Value>) -> Result<Value, SignerError> {
+        let (reply, response) = mpsc::sync_channel(1);
+        self.requests
+            .send((method.to_owned(), params, reply))
+            .map_err(|_| SignerError::Unavailable("signer thread exited".into()))?;
+        // The client enforces the timeout; this one only guards against requests queued
+        // behind a stuck one.
+        response
+            .recv_timeout(self.timeout * 2)
+            .map_err(|_| SignerError::Unavailable("request timed out".into()))?
+    }
+}
+
+/// Signature schemes served by the remote signer.
+trait RemoteScheme: Pair {
+    const NAME: &'static str;
+}
+
+impl RemoteScheme for sr25519::Pair {
+    const NAME: &'static str = "sr25519";
+}
+
+impl RemoteScheme for ed25519::Pair {
+    const NAME: &'static str = "ed25519";
+}
+
+impl RemoteScheme for ecdsa::Pair {
+    const NAME: &'static str = "ecdsa";
+}
+
+fn hex(bytes: &[u8]) -> Value {
+    Value::String(sp_core::bytes::to_hex(bytes, false))
+}
+
+fn key_type_name(key_type: KeyTypeId) -> Value {
+    Value::String(String::from_utf8_lossy(&key_type.0).into_owned())
+}
+
+fn decode<R: serde::de::DeserializeOwned>(value: Value) -> Result<R, Error> {
+    serde_json::from_value(value)
+        .map_err(|e| Error::ValidationError(format!("malformed signer response: {e}")))
+}
+
+fn unsupported(what: &str) -> Error {
+    Error::Other(format!("{what} is not supported by the remote signer"))
+}
+
+/// [`Keystore`] delegating to a remote signer.
+pub struct RemoteKeystore<T = HttpTransport> {
+    transport: T,
+}
+
+impl RemoteKeystore {
+    /// Connect to the signer at `config.url` and check that it speaks the signer protocol.
+    pub fn connect(config: &RemoteSignerConfig) -> Result<Self, SignerError> {
+        let keystore = Self::new(HttpTransport::spawn(config)?);
+        let probe = keystore
+            .transport
+            .request("signer_hasKeys", vec![json!([])])?;
+        if !probe.is_boolean() {
+            return Err(SignerError::Rejected(format!(
+                "unexpected `signer_hasKeys` response: {probe}"
+            )));
+        }
+        Ok(keystore)
+    }
+}
+
+impl<T: SignerTransport> RemoteKeystore<T> {
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    fn call(&self, method: &str, params: Vec<Value>) -> Result<Value, Error> {
+        self.transport.request(method, params).map_err(|e| match e {
+            SignerError::Unavailable(reason) => {
+                log::warn!("Remote signer unavailable for `{method}`: {reason}");
+                Error::Unavailable
+            }
+            SignerError::Rejected(reason) => Error::Other(reason),
+        })
+    }
+
+    fn public_keys<P: RemoteScheme>(&self, key_type: KeyTypeId) -> Result<Vec<P::Public>, Error> {
+        let keys: Vec<Bytes> = decode(self.call(
+            "signer_publicKeys",
+            vec![json!(P::NAME), key_type_name(key_type)],
+        )?)?;
+        keys.iter()
+            .map(|key| {
+                P::Public::try_from(&key.0[..]).map_err(|()| {
+                    Error::ValidationError(format!("malformed {} public key", P::NAME))
+                })
+            })
+            .collect()
+    }
+
+    /// Public keys of `key_type`, or none if the signer can't be trusted to list them right now.
+    fn public_keys_or_none<P: RemoteScheme>(&self, key_type: KeyTypeId) -> Vec<P::Public> {
+        self.public_keys::<P>(key_type).unwrap_or_else(|e| {
+            log::warn!(
+                "Listing {} keys from the remote signer failed: {e}",
+                P::NAME
+            );
+            Vec::new()
+        })
+    }
+
+    fn generate<P: RemoteScheme>(
+        &self,
+        key_type: KeyTypeId,
+        seed: Option<&str>,
+    ) -> Result<P::Public, Error> {
+        let key: Bytes = decode(self.call(
+            "signer_generate",
+            vec![json!(P::NAME), key_type_name(key_type), json!(seed)],
+        )?)?;
+        P::Public::try_from(&key.0[..])
+            .map_err(|()| Error::ValidationError(format!("malformed {} public key", P::NAME)))
+    }
+
+    fn sign<P: RemoteScheme>(
+        &self,
+        key_type: KeyTypeId,
+        public: &P::Public,
+        msg: &[u8],
+    ) -> Result<Option<P::Signature>, Error> {
+        let signature: Option<Bytes> = decode(self.call(
+            "signer_sign",
+            vec![
+                json!(P::NAME),
+                key_type_name(key_type),
+                hex(public.as_slice()),
+                hex(msg),
+            ],
+        )?)?;
+        let Some(signature) = signature else {
+            return Ok(None);
+        };
+
+        let signature = P::Signature::try_from(&signature.0[..])
+            .map_err(|()| Error::ValidationError(format!("malformed {} signature", P::NAME)))?;
+        if !P::verify(&signature, msg, public) {
+            return Err(Error::ValidationError(format!(
+                "{} signature does not match the requested key",
+                P::NAME
+            )));
+        }
+        Ok(Some(signature))
+    }
+}
+
+impl<T: SignerTransport> Keystore for RemoteKeystore<T> {
+    fn sr25519_public_keys(&self, key_type: KeyTypeId) -> Vec<sr25519::Public> {
+        self.public_keys_or_none::<sr25519::Pair>(key_type)
+    }
+
+    fn sr25519_generate_new(
+        &self,
+        key_type: KeyTypeId,
+        seed: Option<&str>,
+    ) -> Result<sr25519::Public, Error> {
+        self.generate::<sr25519::Pair>(key_type, seed)
+    }
+
+    fn sr25519_sign(
+        &self,
+        key_type: KeyTypeId,
+        public: &sr25519::Public,
+        msg: &[u8],
+    ) -> Result<Option<sr25519::Signature>, Error> {
+        self.sign::<sr25519::Pair>(key_type, public, msg)
+    }
+
+    fn sr25519_vrf_sign(
+        &self,
+        _key_type: KeyTypeId,
+        _public: &sr25519::Public,
+        _data: &sr25519::vrf::VrfSignData,
+    ) -> Result<Option<sr25519::vrf::VrfSignature>, Error> {
+        Err(unsupported("VRF signing"))
+    }
+
+    fn sr25519_vrf_pre_output(
+        &self,
+        _key_type: KeyTypeId,
+        _public: &sr25519::Public,
+        _input: &sr25519::vrf::VrfInput,
+    ) -> Result<Option<sr25519::vrf::VrfPreOutput>, Error> {
+        Err(unsupported("VRF signing"))
+    }
+
+    fn ed25519_public_keys(&self, key_type: KeyTypeId) -> Vec<ed25519::Public> {
+        self.public_keys_or_none::<ed25519::Pair>(key_type)
+    }
+
+    fn ed25519_generate_new(
+        &self,
+        key_type: KeyTypeId,
+        seed: Option<&str>,
+    ) -> Result<ed25519::Public, Error> {
+        self.generate::<ed25519::Pair>(key_type, seed)
+    }
+
+    fn ed25519_sign(
+        &self,
+        key_type: KeyTypeId,
+        public: &ed25519::Public,
+        msg: &[u8],
+    ) -> Result<Option<ed25519::Signature>, Error> {
+        self.sign::<ed25519::Pair>(key_type, public, msg)
+    }
+
+    fn ecdsa_public_keys(&self, key_type: KeyTypeId) -> Vec<ecdsa::Public> {
+        self.public_keys_or_none::<ecdsa::Pair>(key_type)
+    }
+
+    fn ecdsa_generate_new(
+        &self,
+        key_type: KeyTypeId,
+        seed: Option<&str>,
+    ) -> Result<ecdsa::Public, Error> {
+        self.generate::<ecdsa::Pair>(key_type, seed)
+    }
+
+    fn ecdsa_sign(
+        &self,
+        key_type: KeyTypeId,
+        public: &ecdsa::Public,
+        msg: &[u8],
+    ) -> Result<Option<ecdsa::Signature>, Error> {
+        self.sign::<ecdsa::Pair>(key_type, public, msg)
+    }
+
+    fn ecdsa_sign_prehashed(
+        &self,
+        _key_type: KeyTypeId,
+        _public: &ecdsa::Public,
+        _msg: &[u8; 32],
+    ) -> Result<Option<ecdsa::Signature>, Error> {
+        Err(unsupported("pre-hashed ECDSA signing"))
+    }
+
+    fn insert(&self, key_type: KeyTypeId, _suri: &str, _public: &[u8]) -> Result<(), ()> {
+        log::warn!(
+            "Refusing to import a `{}` secret key: keys of a remote signer are managed on the \
+             signer itself",
+            String::from_utf8_lossy(&key_type.0)
+        );
+        Err(())
+    }
+
+    fn keys(&self, key_type: KeyTypeId) -> Result<Vec<Vec<u8>>, Error> {
+        let mut keys: Vec<Vec<u8>> = Vec::new();
+        keys.extend(
+            self.public_keys::<sr25519::Pair>(key_type)?
+                .iter()
+                .map(|key| key.to_raw_vec()),
+        );
+        keys.extend(
+            self.public_keys::<ed25519::Pair>(key_type)?
+                .iter()
+                .map(|key| key.to_raw_vec()),
+        );
+        keys.extend(
+            self.public_keys::<ecdsa::Pair>(key_type)?
+                .iter()
+                .map(|key| key.to_raw_vec()),
+        );
+        Ok(keys)
+    }
+
+    fn has_keys(&self, public_keys: &[(Vec<u8>, KeyTypeId)]) -> bool {
+        let keys: Vec<Value> = public_keys
+            .iter()
+            .map(|(public, key_type)| json!([hex(public), key_type_name(*key_type)]))
+            .collect();
+        self.call("signer_hasKeys", vec![Value::Array(keys)])
+            .and_then(decode::<bool>)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AURA: KeyTypeId = KeyTypeId(*b"aura");
+
+    /// Answers every request with `respond`.
+    struct MockSigner<F>(F);
+
+    impl<F> SignerTransport for MockSigner<F>
+    where
+        F: Fn(&str, &[Value]) -> Result<Value, SignerError> + Send + Sync,
+    {
+        fn request(&self, method: &str, params: Vec<Value>) -> Result<Value, SignerError> {
+            (self.0)(method, &params)
+        }
+    }
+
+    fn keystore<F>(respond: F) -> RemoteKeystore<MockSigner<F>>
+    where
+        F: Fn(&str, &[Value]) -> Result<Value, SignerError> + Send + Sync,
+    {
+        RemoteKeystore::new(MockSigner(respond))
+    }
+
+    fn alice() -> sr25519::Pair {
+        sr25519::Pair::from_string("//Alice", None).unwrap()
+    }
+
+    /// Decodes the message of a `signer_sign` request.
+    fn message(params: &[Value]) -> Vec<u8> {
+        serde_json::from_value::<Bytes>(params[3].clone())
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn unreachable_signer_fails_closed() {
+        let keystore = keystore(|_, _| Err(SignerError::Unavailable("connection refused".into())));
+        let public = alice().public();
+
+        assert!(matches!(
+            keystore.sr25519_sign(AURA, &public, b"block"),
+            Err(Error::Unavailable)
+        ));
+        assert!(keystore.sr25519_public_keys(AURA).is_empty());
+        assert!(!keystore.has_keys(&[(public.to_raw_vec(), AURA)]));
+        assert!(matches!(keystore.keys(AURA), Err(Error::Unavailable)));
+        assert!(matches!(
+            keystore.sr25519_generate_new(AURA, None),
+            Err(Error::Unavailable)
+        ));
+    }
+
+    #[test]
+    fn rejected_request_is_reported() {
+        let keystore = keystore(|_, _| Err(SignerError::Rejected("key is disabled".into())));
+
+        assert!(matches!(
+            keystore.ed25519_sign(AURA, &ed25519::Public::from_raw([1; 32]), b"vote"),
+            Err(Error::Other(reason)) if reason == "key is disabled"
+        ));
+    }
+
+    #[test]
+    fn signs_with_signer_key() {
+        let keystore = keystore(|method, params| {
+            assert_eq!(method, "signer_sign");
+            assert_eq!(params[0], "sr25519");
+            assert_eq!(params[1], "aura");
+            Ok(hex(alice().sign(&message(params)).as_ref()))
+        });
+        let public = alice().public();
+
+        let signature = keystore.sr25519_sign(AURA, &public, b"block").unwrap();
+        assert!(sr25519::Pair::verify(
+            &signature.unwrap(),
+            b"block",
+            &public
+        ));
+    }
+
+    #[test]
+    fn unknown_key_is_not_signed() {
+        let keystore = keystore(|_, _| Ok(Value::Null));
+
+        assert!(matches!(
+            keystore.sr25519_sign(AURA, &alice().public(), b"block"),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn signature_of_another_key_is_refused() {
+        let keystore = keystore(|_, params| {
+            let bob = sr25519::Pair::from_string("//Bob", None).unwrap();
+            Ok(hex(bob.sign(&message(params)).as_ref()))
+        });
+
+        assert!(matches!(
+            keystore.sr25519_sign(AURA, &alice().public(), b"block"),
+            Err(Error::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn malformed_responses_are_refused() {
+        let keystore = keystore(|method, _| match method {
+            "signer_sign" => Ok(json!("0x1234")),
+            "signer_publicKeys" => Ok(json!(["not hex"])),
+            _ => Ok(json!("0x00")),
+        });
+
+        assert!(matches!(
+            keystore.sr25519_sign(AURA, &alice().public(), b"block"),
+            Err(Error::ValidationError(_))
+        ));
+        assert!(keystore.sr25519_public_keys(AURA).is_empty());
+        assert!(matches!(
+            keystore.ed25519_generate_new(AURA, None),
+            Err(Error::ValidationError(_))
+        ));
+        assert!(!keystore.has_keys(&[]));
+    }
+
+    #[test]
+    fn lists_keys_of_every_scheme() {
+        let keystore = keystore(|_, params| {
+            Ok(match params[0].as_str() {
+                Some("sr25519") => json!([hex(alice().public().as_slice())]),
+                _ => json!([]),
+            })
+        });
+
+        assert_eq!(keystore.sr25519_public_keys(AURA), vec![alice().public()]);
+        assert_eq!(
+            keystore.keys(AURA).unwrap(),
+            vec![alice().public().to_raw_vec()]
+        );
+    }
+
+    #[test]
+    fn secret_keys_are_not_imported() {
+        let keystore = keystore(|_, _| panic!("nothing should reach the signer"));
+
+        assert_eq!(
+            keystore.insert(AURA, "//Alice", alice().public().as_slice()),
+            Err(())
+        );
+        let input = sr25519::vrf::VrfTranscript::new(b"test", &[]);
+        assert!(
+            keystore
+                .sr25519_vrf_sign(AURA, &alice().public(), &input.into_sign_data())
+                .is_err()
+        );
+    }
+}
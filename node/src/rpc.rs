@@ -22,6 +22,14 @@ use std::sync::Arc;
 use allfeat_primitives::*;
 use jsonrpsee::RpcModule;
 
+mod artist_proof;
+mod contracts;
+mod deferred_work;
+pub mod errors;
+mod logging;
+mod signing;
+mod upgrade_notice;
+
 /// Extra dependencies for GRANDPA
 pub struct GrandpaDeps<BE> {
     /// Voting round info.
@@ -68,13 +76,18 @@ where
         + sp_blockchain::HeaderMetadata<Block, Error = sp_blockchain::Error>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
         + sp_block_builder::BlockBuilder<Block>
-        + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+        + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+        + pallet_upgrade_notice_runtime_api::UpgradeNoticeApi<Block, BlockNumber>
+        + allfeat_primitives::signing::SigningApi<Block>,
     P: 'static + Sync + Send + sc_transaction_pool_api::TransactionPool<Block = Block>,
 {
     // polkadot-sdk
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
+    use logging::{Logging, LoggingApiServer};
+    use signing::{Signing, SigningApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
+    use upgrade_notice::{UpgradeNotice, UpgradeNoticeApiServer};
 
     let mut module = RpcModule::new(());
 
@@ -93,6 +106,8 @@ where
 
     module.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(UpgradeNotice::new(client.clone()).into_rpc())?;
+    module.merge(Signing::new(client.clone(), pool.clone()).into_rpc())?;
     module.merge(
         Grandpa::new(
             subscription_executor,
@@ -103,12 +118,17 @@ where
         )
         .into_rpc(),
     )?;
+    // Only the JSON logger can be reconfigured at runtime.
+    if let Some(levels) = crate::logging::log_levels() {
+        module.merge(Logging::new(levels).into_rpc())?;
+    }
 
     Ok(module)
 }
 
-/// Register the MIDDS RPC handlers (MusicalWorks + Recordings + Releases) on
-/// top of [`create_full`].
+/// Register the MIDDS RPC handlers (MusicalWorks + Recordings + Releases), the
+/// deferred work queue status, the artist verification proofs and the smart
+/// contracts dry runs on top of [`create_full`].
 ///
 /// Only runtimes hosting `pallet-midds` (e.g. Melodie) satisfy the bound; the
 /// mainnet runtime keeps using the bare [`create_full`].
@@ -129,10 +149,13 @@ where
         + sp_api::CallApiAt<Block>
         + sp_api::ProvideRuntimeApi<Block>
         + sp_blockchain::HeaderBackend<Block>
-        + sp_blockchain::HeaderMetadata<Block, Error = sp_blockchain::Error>,
+        + sp_blockchain::HeaderMetadata<Block, Error = sp_blockchain::Error>
+        + sc_client_api::ProofProvider<Block>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
         + sp_block_builder::BlockBuilder<Block>
         + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+        + pallet_upgrade_notice_runtime_api::UpgradeNoticeApi<Block, BlockNumber>
+        + allfeat_primitives::signing::SigningApi<Block>
         + midds_runtime_api::MusicalWorkApi<
             Block,
             midds_traits::Iswc,
@@ -151,9 +174,21 @@ where
             midds_types::Release,
             AccountId,
             Balance,
+        > + pallet_deferred_work_runtime_api::DeferredWorkApi<Block>
+        + pallet_contracts::ContractsApi<
+            Block,
+            AccountId,
+            Balance,
+            BlockNumber,
+            Hash,
+            OpaqueEventRecord,
         >,
     P: 'static + Sync + Send + sc_transaction_pool_api::TransactionPool<Block = Block>,
 {
+    use artist_proof::{ArtistProofApiServer, ArtistProofs};
+    use contracts::{Contracts, ContractsApiServer};
+    use deferred_work::{DeferredWork, DeferredWorkApiServer};
+
     // One handler per MIDDS instance. The methods are namespaced
     // (`midds_musicalWorks_*` / `midds_recordings_*` / `midds_releases_*`)
     // inside `midds-rpc`, so merging the modules into the same RPC surface
@@ -180,10 +215,13 @@ where
     )?;
     module.merge(
         ReleaseRpc::<C, Block, midds_traits::Upc, midds_types::Release, AccountId, Balance>::new(
-            client,
+            client.clone(),
         )
         .into_rpc(),
     )?;
+    module.merge(DeferredWork::new(client.clone()).into_rpc())?;
+    module.merge(Contracts::new(client.clone()).into_rpc())?;
+    module.merge(ArtistProofs::<C, BE>::new(client).into_rpc())?;
 
     Ok(module)
 }
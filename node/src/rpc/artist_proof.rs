@@ -0,0 +1,143 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC serving proofs of the verification status of artists, for third parties to check with
+//! [`ArtistProof::verify`] instead of trusting the node.
+
+use std::sync::Arc;
+
+use allfeat_primitives::{
+    AccountId, Block, Hash, Hashing,
+    artist_proof::{self, ArtistProof},
+};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sc_client_api::{ProofProvider, StorageProvider, backend::Backend};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, storage::StorageKey};
+use sp_runtime::codec::Encode;
+
+use super::errors::Error;
+
+/// The verification status of an artist at a block, and its proof.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistVerification {
+    /// Block the status was read at.
+    pub at: Hash,
+    /// Current certifiers attesting the artist, none if they aren't verified.
+    pub certifiers: Vec<AccountId>,
+    /// The SCALE-encoded `ArtistProof` of the status.
+    pub proof: Bytes,
+}
+
+#[rpc(server)]
+pub trait ArtistProofApi {
+    /// The verification status of `artist` at `at`, or at the finalized block, with the header
+    /// and storage proof to check it against.
+    #[method(name = "certification_artistProof")]
+    fn artist_proof(&self, artist: AccountId, at: Option<Hash>) -> RpcResult<ArtistVerification>;
+}
+
+pub struct ArtistProofs<C, BE> {
+    client: Arc<C>,
+    _backend: std::marker::PhantomData<BE>,
+}
+
+impl<C, BE> ArtistProofs<C, BE> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _backend: Default::default(),
+        }
+    }
+}
+
+/// Map a failed state access at `at`: a block without state has been pruned.
+fn state_error(at: Hash, e: sp_blockchain::Error) -> Error {
+    match e {
+        sp_blockchain::Error::UnknownBlock(_) => Error::PrunedState { block: at },
+        e => Error::Internal {
+            reason: e.to_string(),
+        },
+    }
+}
+
+impl<C, BE> ArtistProofApiServer for ArtistProofs<C, BE>
+where
+    BE: Backend<Block> + 'static,
+    C: StorageProvider<Block, BE>
+        + ProofProvider<Block>
+        + HeaderBackend<Block>
+        + Send
+        + Sync
+        + 'static,
+{
+    fn artist_proof(&self, artist: AccountId, at: Option<Hash>) -> RpcResult<ArtistVerification> {
+        let at = at.unwrap_or_else(|| self.client.info().finalized_hash);
+        let header = self
+            .client
+            .header(at)
+            .ok()
+            .flatten()
+            .ok_or_else(|| Error::unknown_block(at))?;
+
+        let attestations_key = artist_proof::attestations_key(&artist);
+        let attesting = self
+            .client
+            .storage(at, &StorageKey(attestations_key.clone()))
+            .map_err(|e| state_error(at, e))?
+            .map(|value| {
+                artist_proof::attesting_certifiers(&value.0).ok_or_else(|| Error::Internal {
+                    reason: "undecodable attestations".into(),
+                })
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        let mut keys = vec![attestations_key];
+        let mut certifiers = Vec::new();
+        for certifier in attesting {
+            let key = artist_proof::certifier_key(&certifier);
+            if self
+                .client
+                .storage(at, &StorageKey(key.clone()))
+                .map_err(|e| state_error(at, e))?
+                .is_some()
+            {
+                certifiers.push(certifier);
+            }
+            keys.push(key);
+        }
+
+        let proof = self
+            .client
+            .read_proof(at, &mut keys.iter().map(Vec::as_slice))
+            .map_err(|e| state_error(at, e))?
+            .into_compact_proof::<Hashing>(header.state_root)
+            .map_err(|e| Error::Internal {
+                reason: format!("{e:?}"),
+            })?;
+
+        Ok(ArtistVerification {
+            at,
+            certifiers,
+            proof: ArtistProof { header, proof }.encode().into(),
+        })
+    }
+}
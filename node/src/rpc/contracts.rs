@@ -0,0 +1,221 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC dry-running calls to the smart contracts of `pallet-contracts`, so that clients can
+//! estimate the gas and storage deposit limits of a transaction before signing it.
+
+use std::sync::Arc;
+
+use allfeat_primitives::{AccountId, Balance, Block, BlockNumber, Hash, OpaqueEventRecord};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_contracts::{ContractAccessError, ContractsApi as ContractsRuntimeApi};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, bytes::to_hex};
+use sp_runtime::Weight;
+
+use super::errors::Error;
+
+/// A call to dry-run.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallRequest {
+    pub origin: AccountId,
+    pub dest: AccountId,
+    #[serde(default)]
+    pub value: Balance,
+    /// Defaults to the weight of a whole block.
+    pub gas_limit: Option<Gas>,
+    /// Defaults to the runtime's default deposit limit.
+    pub storage_deposit_limit: Option<Balance>,
+    pub input_data: Bytes,
+}
+
+/// Weight paid as gas by a contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Gas {
+    pub ref_time: u64,
+    pub proof_size: u64,
+}
+
+impl From<Weight> for Gas {
+    fn from(weight: Weight) -> Self {
+        Self {
+            ref_time: weight.ref_time(),
+            proof_size: weight.proof_size(),
+        }
+    }
+}
+
+impl From<Gas> for Weight {
+    fn from(gas: Gas) -> Self {
+        Weight::from_parts(gas.ref_time, gas.proof_size)
+    }
+}
+
+/// Storage deposit charged to the origin, or refunded to it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StorageDeposit {
+    Charge(Balance),
+    Refund(Balance),
+}
+
+/// How the contract returned.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum CallOutcome {
+    /// The contract returned `data`, and reverted its changes if `reverted`.
+    Ok {
+        flags: u32,
+        reverted: bool,
+        data: Bytes,
+    },
+    /// The call trapped or failed to dispatch.
+    Err { reason: String },
+}
+
+/// Result of a dry-run call.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallResult {
+    pub gas_consumed: Gas,
+    /// Gas limit to set for the call to succeed on-chain.
+    pub gas_required: Gas,
+    pub storage_deposit: StorageDeposit,
+    /// What the contract printed through `seal_debug_message`.
+    pub debug_message: String,
+    pub result: CallOutcome,
+    /// SCALE-encoded `frame_system::EventRecord`s deposited during the call.
+    pub events: Vec<Bytes>,
+}
+
+#[rpc(server)]
+pub trait ContractsApi {
+    /// Dry-run `request` on the state of `at`, or of the best block. Nothing is committed.
+    #[method(name = "contracts_call")]
+    fn call(&self, request: CallRequest, at: Option<Hash>) -> RpcResult<CallResult>;
+
+    /// Value stored by the contract at `address` under `key`, on the state of `at` or of the
+    /// best block.
+    #[method(name = "contracts_getStorage")]
+    fn get_storage(
+        &self,
+        address: AccountId,
+        key: Bytes,
+        at: Option<Hash>,
+    ) -> RpcResult<Option<Bytes>>;
+}
+
+pub struct Contracts<C> {
+    client: Arc<C>,
+}
+
+impl<C> Contracts<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> ContractsApiServer for Contracts<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash, OpaqueEventRecord>,
+{
+    fn call(&self, request: CallRequest, at: Option<Hash>) -> RpcResult<CallResult> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let CallRequest {
+            origin,
+            dest,
+            value,
+            gas_limit,
+            storage_deposit_limit,
+            input_data,
+        } = request;
+        let result = self
+            .client
+            .runtime_api()
+            .call(
+                at,
+                origin,
+                dest,
+                value,
+                gas_limit.map(Into::into),
+                storage_deposit_limit,
+                input_data.to_vec(),
+            )
+            .map_err(|e| Error::runtime_call(at, e))?;
+
+        Ok(CallResult {
+            gas_consumed: result.gas_consumed.into(),
+            gas_required: result.gas_required.into(),
+            storage_deposit: match result.storage_deposit {
+                pallet_contracts::StorageDeposit::Charge(amount) => StorageDeposit::Charge(amount),
+                pallet_contracts::StorageDeposit::Refund(amount) => StorageDeposit::Refund(amount),
+            },
+            debug_message: String::from_utf8_lossy(&result.debug_message).into_owned(),
+            result: match result.result {
+                Ok(value) => CallOutcome::Ok {
+                    flags: value.flags.bits(),
+                    reverted: value.did_revert(),
+                    data: value.data.into(),
+                },
+                Err(e) => CallOutcome::Err {
+                    reason: format!("{e:?}"),
+                },
+            },
+            events: result
+                .events
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+
+    fn get_storage(
+        &self,
+        address: AccountId,
+        key: Bytes,
+        at: Option<Hash>,
+    ) -> RpcResult<Option<Bytes>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let value = self
+            .client
+            .runtime_api()
+            .get_storage(at, address.clone(), key.to_vec())
+            .map_err(|e| Error::runtime_call(at, e))?
+            .map_err(|e| match e {
+                ContractAccessError::DoesntExist => Error::EntityNotFound {
+                    entity: "contract",
+                    id: address.to_string(),
+                },
+                ContractAccessError::KeyDecodingFailed => Error::InvalidIdentifier {
+                    identifier: "storageKey",
+                    value: to_hex(&key, false),
+                },
+                ContractAccessError::MigrationInProgress => Error::RuntimeCall {
+                    reason: "contracts storage is being migrated".into(),
+                },
+            })?;
+
+        Ok(value.map(Into::into))
+    }
+}
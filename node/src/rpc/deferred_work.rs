@@ -0,0 +1,88 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC reporting the depth of the `pallet-deferred-work` queue, for monitoring.
+
+use std::sync::Arc;
+
+use allfeat_primitives::{Block, EntityId, EntityKind, Hash};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_deferred_work_runtime_api::DeferredWorkApi as DeferredWorkRuntimeApi;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+
+use super::errors::Error;
+
+/// State of the deferred work queue at a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueStatus {
+    /// Number of pending tasks.
+    pub depth: u32,
+    /// Max number of pending tasks.
+    pub capacity: u32,
+    /// Depth from which pallets hold off queueing more work.
+    pub congestion_threshold: u32,
+    /// Whether the queue is congested.
+    pub congested: bool,
+    /// Canonical id of the task being worked on, if any, e.g. `AFQ-000012-GN`.
+    pub head: Option<String>,
+}
+
+#[rpc(server)]
+pub trait DeferredWorkApi {
+    /// Status of the deferred work queue at `at`, or at the best block.
+    #[method(name = "deferredWork_queueStatus")]
+    fn queue_status(&self, at: Option<Hash>) -> RpcResult<QueueStatus>;
+}
+
+pub struct DeferredWork<C> {
+    client: Arc<C>,
+}
+
+impl<C> DeferredWork<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> DeferredWorkApiServer for DeferredWork<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DeferredWorkRuntimeApi<Block>,
+{
+    fn queue_status(&self, at: Option<Hash>) -> RpcResult<QueueStatus> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let status = self
+            .client
+            .runtime_api()
+            .queue_status(at)
+            .map_err(|e| Error::runtime_call(at, e))?;
+
+        Ok(QueueStatus {
+            depth: status.depth,
+            capacity: status.capacity,
+            congestion_threshold: status.congestion_threshold,
+            congested: status.depth >= status.congestion_threshold,
+            head: status
+                .head
+                .map(|task| EntityId::new(EntityKind::DeferredTask, task).to_string()),
+        })
+    }
+}
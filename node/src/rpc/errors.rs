@@ -0,0 +1,186 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Error codes and payloads shared by the Allfeat RPC methods.
+//!
+//! Every Allfeat method fails with a code of the `20000..=20099` range and a JSON `data` object
+//! tagged by `kind`, so that clients can branch on the code or the payload instead of parsing
+//! messages. Codes are never reused nor renumbered.
+
+use allfeat_primitives::Hash;
+use jsonrpsee::types::ErrorObjectOwned;
+use serde::Serialize;
+
+/// First code of the range reserved for the Allfeat RPC methods.
+pub const BASE_ERROR: i32 = 20000;
+
+/// A block, record or setting the request refers to doesn't exist.
+pub const ENTITY_NOT_FOUND: i32 = BASE_ERROR + 1;
+/// The state of the requested block has been pruned.
+pub const PRUNED_STATE: i32 = BASE_ERROR + 2;
+/// An identifier in the request is malformed.
+pub const INVALID_IDENTIFIER: i32 = BASE_ERROR + 3;
+/// The request would go over a limit of the node.
+pub const QUOTA_EXCEEDED: i32 = BASE_ERROR + 4;
+/// The runtime failed to answer the request.
+pub const RUNTIME_CALL: i32 = BASE_ERROR + 5;
+/// The node failed to carry out the request.
+pub const INTERNAL: i32 = BASE_ERROR + 6;
+/// A transaction couldn't be built or was rejected by the transaction pool.
+pub const INVALID_TRANSACTION: i32 = BASE_ERROR + 7;
+
+/// An Allfeat RPC error, serialized as the `data` of the JSON-RPC error object.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum Error {
+    /// No `entity` is identified by `id`, e.g. a `block` by its hash.
+    EntityNotFound { entity: &'static str, id: String },
+    /// The state of `block` isn't available anymore on this node.
+    PrunedState { block: Hash },
+    /// `value` isn't a valid `identifier`, e.g. a `logTarget`.
+    InvalidIdentifier {
+        identifier: &'static str,
+        value: String,
+    },
+    /// No more than `limit` of `quota` can be held by the node.
+    QuotaExceeded { quota: &'static str, limit: u32 },
+    /// The runtime API call failed.
+    RuntimeCall { reason: String },
+    /// The node failed for a reason of its own, e.g. an I/O error.
+    Internal { reason: String },
+    /// The transaction is invalid, e.g. its signature doesn't match.
+    InvalidTransaction { reason: String },
+}
+
+impl Error {
+    /// The unknown `block`.
+    pub fn unknown_block(block: Hash) -> Self {
+        Self::EntityNotFound {
+            entity: "block",
+            id: format!("{block:?}"),
+        }
+    }
+
+    /// Map a failed runtime API call at `at`: a block without state has been pruned.
+    pub fn runtime_call(at: Hash, e: sp_api::ApiError) -> Self {
+        match e {
+            sp_api::ApiError::UnknownBlock(_) => Self::PrunedState { block: at },
+            e => Self::RuntimeCall {
+                reason: e.to_string(),
+            },
+        }
+    }
+
+    pub fn code(&self) -> i32 {
+        match self {
+            Self::EntityNotFound { .. } => ENTITY_NOT_FOUND,
+            Self::PrunedState { .. } => PRUNED_STATE,
+            Self::InvalidIdentifier { .. } => INVALID_IDENTIFIER,
+            Self::QuotaExceeded { .. } => QUOTA_EXCEEDED,
+            Self::RuntimeCall { .. } => RUNTIME_CALL,
+            Self::Internal { .. } => INTERNAL,
+            Self::InvalidTransaction { .. } => INVALID_TRANSACTION,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            Self::EntityNotFound { entity, id } => format!("Unknown {entity} `{id}`"),
+            Self::PrunedState { block } => format!("State of block {block:?} has been pruned"),
+            Self::InvalidIdentifier { identifier, value } => {
+                format!("Invalid {identifier} `{value}`")
+            }
+            Self::QuotaExceeded { quota, limit } => {
+                format!("Quota of {limit} {quota} exceeded")
+            }
+            Self::RuntimeCall { reason } => format!("Runtime call failed: {reason}"),
+            Self::Internal { reason } => format!("Internal error: {reason}"),
+            Self::InvalidTransaction { reason } => format!("Invalid transaction: {reason}"),
+        }
+    }
+}
+
+impl From<Error> for ErrorObjectOwned {
+    fn from(e: Error) -> Self {
+        ErrorObjectOwned::owned(e.code(), e.message(), Some(e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_stay_in_the_allfeat_range() {
+        let errors = [
+            Error::unknown_block(Hash::zero()),
+            Error::PrunedState {
+                block: Hash::zero(),
+            },
+            Error::InvalidIdentifier {
+                identifier: "logTarget",
+                value: String::new(),
+            },
+            Error::QuotaExceeded {
+                quota: "log levels",
+                limit: 1,
+            },
+            Error::RuntimeCall {
+                reason: String::new(),
+            },
+            Error::Internal {
+                reason: String::new(),
+            },
+            Error::InvalidTransaction {
+                reason: String::new(),
+            },
+        ];
+        let codes: Vec<_> = errors.iter().map(Error::code).collect();
+        assert_eq!(codes, (BASE_ERROR + 1..=BASE_ERROR + 7).collect::<Vec<_>>());
+        assert!(
+            codes
+                .iter()
+                .all(|code| (BASE_ERROR..BASE_ERROR + 100).contains(code))
+        );
+    }
+
+    #[test]
+    fn data_is_tagged_by_kind() {
+        let object = ErrorObjectOwned::from(Error::InvalidIdentifier {
+            identifier: "logLevel",
+            value: "verbose".into(),
+        });
+        assert_eq!(object.code(), INVALID_IDENTIFIER);
+        assert_eq!(object.message(), "Invalid logLevel `verbose`");
+        assert_eq!(
+            object.data().map(|data| data.get()),
+            Some(r#"{"kind":"invalidIdentifier","identifier":"logLevel","value":"verbose"}"#)
+        );
+
+        let object = ErrorObjectOwned::from(Error::PrunedState {
+            block: Hash::repeat_byte(1),
+        });
+        let data: serde_json::Value = serde_json::from_str(object.data().unwrap().get()).unwrap();
+        assert_eq!(data["kind"], "prunedState");
+        assert_eq!(data["block"], format!("{:?}", Hash::repeat_byte(1)));
+    }
+}
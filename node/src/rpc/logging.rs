@@ -0,0 +1,98 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Operator RPC changing the level of each log target of the JSON logger at runtime.
+
+use std::collections::BTreeMap;
+
+use jsonrpsee::{Extensions, core::RpcResult, proc_macros::rpc};
+use sc_rpc_api::check_if_safe;
+
+use super::errors::Error;
+use crate::logging::{LevelError, LogLevels, MAX_OVERRIDES};
+
+#[rpc(server)]
+pub trait LoggingApi {
+    /// Levels set at runtime, keyed by target, on top of the `--log` directives.
+    #[method(name = "logging_levels", with_extensions)]
+    fn levels(&self) -> RpcResult<BTreeMap<String, String>>;
+
+    /// Set the level of `target`: `trace`, `debug`, `info`, `warn`, `error` or `off`.
+    ///
+    /// The level is persisted and applied again on restart. Up to 64 targets can have a level
+    /// set at once.
+    #[method(name = "logging_setLevel", with_extensions)]
+    fn set_level(&self, target: String, level: String) -> RpcResult<()>;
+
+    /// Drop the level set for `target`, falling back to the `--log` directives.
+    #[method(name = "logging_resetLevel", with_extensions)]
+    fn reset_level(&self, target: String) -> RpcResult<()>;
+}
+
+pub struct Logging {
+    levels: &'static LogLevels,
+}
+
+impl Logging {
+    pub fn new(levels: &'static LogLevels) -> Self {
+        Self { levels }
+    }
+}
+
+fn level_error(e: LevelError) -> Error {
+    match e {
+        LevelError::InvalidTarget(value) => Error::InvalidIdentifier {
+            identifier: "logTarget",
+            value,
+        },
+        LevelError::InvalidLevel(value) => Error::InvalidIdentifier {
+            identifier: "logLevel",
+            value,
+        },
+        LevelError::NotSet(id) => Error::EntityNotFound {
+            entity: "logLevel",
+            id,
+        },
+        LevelError::TooManyTargets => Error::QuotaExceeded {
+            quota: "logTargets",
+            limit: MAX_OVERRIDES as u32,
+        },
+        LevelError::Failed(reason) => Error::Internal { reason },
+    }
+}
+
+impl LoggingApiServer for Logging {
+    fn levels(&self, ext: &Extensions) -> RpcResult<BTreeMap<String, String>> {
+        check_if_safe(ext)?;
+        Ok(self.levels.overrides())
+    }
+
+    fn set_level(&self, ext: &Extensions, target: String, level: String) -> RpcResult<()> {
+        check_if_safe(ext)?;
+        self.levels
+            .set(&target, &level)
+            .map_err(|e| level_error(e).into())
+    }
+
+    fn reset_level(&self, ext: &Extensions, target: String) -> RpcResult<()> {
+        check_if_safe(ext)?;
+        self.levels
+            .reset(&target)
+            .map_err(|e| level_error(e).into())
+    }
+}
@@ -0,0 +1,225 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC building signed transactions for external signers, backed by the `SigningApi` runtime API.
+//!
+//! `signing_payload` returns the exact bytes to sign along with the parameters the runtime
+//! resolved; `signing_submit` takes the signature back with those same parameters and submits
+//! the extrinsic.
+
+use std::sync::Arc;
+
+use allfeat_primitives::{
+    AccountId, Balance, Block, Hash, Nonce, Signature,
+    signing::{self, SigningApi as SigningRuntimeApi, SigningError},
+};
+use jsonrpsee::{
+    core::{RpcResult, async_trait},
+    proc_macros::rpc,
+};
+use sc_transaction_pool_api::{TransactionPool, TransactionSource, TxHash};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, bytes::to_hex};
+use sp_runtime::{
+    OpaqueExtrinsic,
+    codec::{Decode, Encode},
+    generic::Era,
+};
+
+use super::errors::Error;
+
+/// What the transaction should look like. Anything left out is resolved by the runtime.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SigningParams {
+    /// Defaults to the signer's next nonce.
+    pub nonce: Option<Nonce>,
+    /// Blocks the transaction stays valid for, rounded to a power of two between 4 and 65536.
+    /// Immortal when left out.
+    pub mortality: Option<u64>,
+    pub tip: Balance,
+}
+
+/// Parameters the runtime resolved, to be submitted untouched along with the signature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionParams {
+    pub nonce: Nonce,
+    /// SCALE-encoded era.
+    pub era: Bytes,
+    pub tip: Balance,
+}
+
+/// What to sign, and what was signed over.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SigningPayload {
+    /// The bytes to sign, already hashed when longer than 256 bytes.
+    pub payload: Bytes,
+    pub params: TransactionParams,
+    pub genesis_hash: Hash,
+    /// Hash of the block the era starts at, the genesis hash for an immortal transaction.
+    pub checkpoint: Hash,
+    pub spec_version: u32,
+    pub transaction_version: u32,
+}
+
+#[rpc(server)]
+pub trait SigningApi<TxHash> {
+    /// The payload `signer` has to sign to dispatch the SCALE-encoded `call`, resolved at `at`
+    /// or at the best block.
+    #[method(name = "signing_payload")]
+    fn payload(
+        &self,
+        call: Bytes,
+        signer: AccountId,
+        params: Option<SigningParams>,
+        at: Option<Hash>,
+    ) -> RpcResult<SigningPayload>;
+
+    /// Submit `call` signed by `signer`, with the parameters returned by `signing_payload`.
+    ///
+    /// `signature` is a SCALE-encoded `MultiSignature`: the signature scheme byte followed by
+    /// the signature itself.
+    #[method(name = "signing_submit")]
+    async fn submit(
+        &self,
+        call: Bytes,
+        signer: AccountId,
+        signature: Bytes,
+        params: TransactionParams,
+    ) -> RpcResult<TxHash>;
+}
+
+pub struct Signing<C, P> {
+    client: Arc<C>,
+    pool: Arc<P>,
+}
+
+impl<C, P> Signing<C, P> {
+    pub fn new(client: Arc<C>, pool: Arc<P>) -> Self {
+        Self { client, pool }
+    }
+}
+
+fn signing_error(e: SigningError) -> Error {
+    let reason = match e {
+        SigningError::UndecodableCall => "call can't be decoded",
+        SigningError::Unresolved => "signed data can't be resolved",
+        SigningError::BadSignature => "signature doesn't match the payload",
+    };
+    Error::InvalidTransaction {
+        reason: reason.into(),
+    }
+}
+
+#[async_trait]
+impl<C, P> SigningApiServer<TxHash<P>> for Signing<C, P>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: SigningRuntimeApi<Block>,
+    P: TransactionPool<Block = Block> + 'static,
+{
+    fn payload(
+        &self,
+        call: Bytes,
+        signer: AccountId,
+        params: Option<SigningParams>,
+        at: Option<Hash>,
+    ) -> RpcResult<SigningPayload> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let params = params.unwrap_or_default();
+        let payload = self
+            .client
+            .runtime_api()
+            .signing_payload(
+                at,
+                call.to_vec(),
+                signer,
+                signing::SigningParams {
+                    nonce: params.nonce,
+                    mortality: params.mortality,
+                    tip: params.tip,
+                },
+            )
+            .map_err(|e| Error::runtime_call(at, e))?
+            .map_err(signing_error)?;
+
+        Ok(SigningPayload {
+            payload: payload.payload.into(),
+            params: TransactionParams {
+                nonce: payload.params.nonce,
+                era: payload.params.era.encode().into(),
+                tip: payload.params.tip,
+            },
+            genesis_hash: payload.genesis_hash,
+            checkpoint: payload.checkpoint,
+            spec_version: payload.spec_version,
+            transaction_version: payload.transaction_version,
+        })
+    }
+
+    async fn submit(
+        &self,
+        call: Bytes,
+        signer: AccountId,
+        signature: Bytes,
+        params: TransactionParams,
+    ) -> RpcResult<TxHash<P>> {
+        let signature =
+            Signature::decode(&mut &signature[..]).map_err(|_| Error::InvalidIdentifier {
+                identifier: "signature",
+                value: to_hex(&signature, false),
+            })?;
+        let era = Era::decode(&mut &params.era[..]).map_err(|_| Error::InvalidIdentifier {
+            identifier: "era",
+            value: to_hex(&params.era, false),
+        })?;
+
+        let at = self.client.info().best_hash;
+        let xt = self
+            .client
+            .runtime_api()
+            .signed_extrinsic(
+                at,
+                call.to_vec(),
+                signer,
+                signature,
+                signing::TransactionParams {
+                    nonce: params.nonce,
+                    era,
+                    tip: params.tip,
+                },
+            )
+            .map_err(|e| Error::runtime_call(at, e))?
+            .map_err(signing_error)?;
+        let xt = OpaqueExtrinsic::decode(&mut &xt[..]).map_err(|e| Error::Internal {
+            reason: e.to_string(),
+        })?;
+
+        Ok(self
+            .pool
+            .submit_one(at, TransactionSource::External, xt)
+            .await
+            .map_err(|e| Error::InvalidTransaction {
+                reason: e.to_string(),
+            })?)
+    }
+}
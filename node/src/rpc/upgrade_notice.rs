@@ -0,0 +1,89 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC reporting the runtime upgrade announced with `pallet-upgrade-notice`.
+
+use std::sync::Arc;
+
+use allfeat_primitives::{Block, BlockNumber, Hash};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_upgrade_notice_runtime_api::UpgradeNoticeApi as UpgradeNoticeRuntimeApi;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+
+use super::errors::Error;
+
+/// A pending runtime upgrade, as seen from a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingUpgrade {
+    /// Hash of the new runtime code.
+    pub code_hash: Hash,
+    /// Block at which the upgrade activates.
+    pub activation: BlockNumber,
+    /// Block at which the upgrade was announced.
+    pub announced_at: BlockNumber,
+    /// Blocks left until the activation.
+    pub blocks_remaining: BlockNumber,
+}
+
+#[rpc(server)]
+pub trait UpgradeNoticeApi {
+    /// The upgrade pending at `at`, or at the best block.
+    #[method(name = "upgradeNotice_pending")]
+    fn pending(&self, at: Option<Hash>) -> RpcResult<Option<PendingUpgrade>>;
+}
+
+pub struct UpgradeNotice<C> {
+    client: Arc<C>,
+}
+
+impl<C> UpgradeNotice<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> UpgradeNoticeApiServer for UpgradeNotice<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: UpgradeNoticeRuntimeApi<Block, BlockNumber>,
+{
+    fn pending(&self, at: Option<Hash>) -> RpcResult<Option<PendingUpgrade>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let number = self
+            .client
+            .number(at)
+            .ok()
+            .flatten()
+            .ok_or_else(|| Error::unknown_block(at))?;
+        let notice = self
+            .client
+            .runtime_api()
+            .pending_upgrade(at)
+            .map_err(|e| Error::runtime_call(at, e))?;
+
+        Ok(notice.map(|notice| PendingUpgrade {
+            code_hash: notice.code_hash,
+            activation: notice.activation,
+            announced_at: notice.announced_at,
+            blocks_remaining: notice.activation.saturating_sub(number),
+        }))
+    }
+}
@@ -108,8 +108,12 @@ macro_rules! dispatch_async_run {
         if $chain_spec.is_melodie() {
             return $runner.async_run(|$config| {
                 let $components =
-                    $crate::service::new_partial::<$crate::service::MelodieRuntimeApi>(&$config)
-                        .map_err(|e| sc_cli::Error::from(*e))?;
+                    $crate::service::new_partial::<$crate::service::MelodieRuntimeApi>(
+                        &$config,
+                        None,
+                        $crate::db_check::DbCheck::Warn,
+                    )
+                    .map_err(|e| sc_cli::Error::from(*e))?;
                 let task_manager = $components.task_manager;
                 { $body }.map(|v| (v, task_manager))
             });
@@ -119,8 +123,12 @@ macro_rules! dispatch_async_run {
         if $chain_spec.is_allfeat() {
             return $runner.async_run(|$config| {
                 let $components =
-                    $crate::service::new_partial::<$crate::service::AllfeatRuntimeApi>(&$config)
-                        .map_err(|e| sc_cli::Error::from(*e))?;
+                    $crate::service::new_partial::<$crate::service::AllfeatRuntimeApi>(
+                        &$config,
+                        None,
+                        $crate::db_check::DbCheck::Warn,
+                    )
+                    .map_err(|e| sc_cli::Error::from(*e))?;
                 let task_manager = $components.task_manager;
                 { $body }.map(|v| (v, task_manager))
             });
@@ -131,8 +139,12 @@ macro_rules! dispatch_async_run {
         {
             return $runner.async_run(|$config| {
                 let $components =
-                    $crate::service::new_partial::<$crate::service::MelodieRuntimeApi>(&$config)
-                        .map_err(|e| sc_cli::Error::from(*e))?;
+                    $crate::service::new_partial::<$crate::service::MelodieRuntimeApi>(
+                        &$config,
+                        None,
+                        $crate::db_check::DbCheck::Warn,
+                    )
+                    .map_err(|e| sc_cli::Error::from(*e))?;
                 let task_manager = $components.task_manager;
                 { $body }.map(|v| (v, task_manager))
             });
@@ -142,8 +154,12 @@ macro_rules! dispatch_async_run {
         {
             return $runner.async_run(|$config| {
                 let $components =
-                    $crate::service::new_partial::<$crate::service::AllfeatRuntimeApi>(&$config)
-                        .map_err(|e| sc_cli::Error::from(*e))?;
+                    $crate::service::new_partial::<$crate::service::AllfeatRuntimeApi>(
+                        &$config,
+                        None,
+                        $crate::db_check::DbCheck::Warn,
+                    )
+                    .map_err(|e| sc_cli::Error::from(*e))?;
                 let task_manager = $components.task_manager;
                 { $body }.map(|v| (v, task_manager))
             });
@@ -161,14 +177,14 @@ macro_rules! dispatch_async_run {
 #[macro_export]
 #[rustfmt::skip]
 macro_rules! dispatch_on_runtime_full {
-    ($chain_spec:expr, $config:expr) => {{
+    ($chain_spec:expr, $config:expr, $remote_signer:expr, $db_check:expr, $explorer:expr) => {{
         use $crate::chain_specs::IdentifyVariant;
 
         #[cfg(feature = "melodie-runtime")]
         if $chain_spec.is_melodie() {
             return $crate::service::new_full_from_network_cfg_with_midds::<
                 $crate::service::MelodieRuntimeApi,
-            >($config)
+            >($config, $remote_signer, $db_check, $explorer)
             .map_err(|e| sc_cli::Error::from(*e));
         }
 
@@ -176,7 +192,7 @@ macro_rules! dispatch_on_runtime_full {
         if $chain_spec.is_allfeat() {
             return $crate::service::new_full_from_network_cfg::<
                 $crate::service::AllfeatRuntimeApi,
-            >($config)
+            >($config, $remote_signer, $db_check, $explorer)
             .map_err(|e| sc_cli::Error::from(*e));
         }
 
@@ -185,7 +201,7 @@ macro_rules! dispatch_on_runtime_full {
         {
             return $crate::service::new_full_from_network_cfg_with_midds::<
                 $crate::service::MelodieRuntimeApi,
-            >($config)
+            >($config, $remote_signer, $db_check, $explorer)
             .map_err(|e| sc_cli::Error::from(*e));
         }
 
@@ -193,7 +209,7 @@ macro_rules! dispatch_on_runtime_full {
         {
             return $crate::service::new_full_from_network_cfg::<
                 $crate::service::AllfeatRuntimeApi,
-            >($config)
+            >($config, $remote_signer, $db_check, $explorer)
             .map_err(|e| sc_cli::Error::from(*e));
         }
 
@@ -220,34 +236,46 @@ macro_rules! dispatch_benchmark_partials {
 
         #[cfg(feature = "melodie-runtime")]
         if $config.chain_spec.is_melodie() {
-            let $partials =
-                $crate::service::new_partial::<$crate::service::MelodieRuntimeApi>(&$config)
-                    .map_err(|e| sc_cli::Error::from(*e))?;
+            let $partials = $crate::service::new_partial::<$crate::service::MelodieRuntimeApi>(
+                &$config,
+                None,
+                $crate::db_check::DbCheck::Warn,
+            )
+            .map_err(|e| sc_cli::Error::from(*e))?;
             return $body;
         }
 
         #[cfg(feature = "allfeat-runtime")]
         if $config.chain_spec.is_allfeat() {
-            let $partials =
-                $crate::service::new_partial::<$crate::service::AllfeatRuntimeApi>(&$config)
-                    .map_err(|e| sc_cli::Error::from(*e))?;
+            let $partials = $crate::service::new_partial::<$crate::service::AllfeatRuntimeApi>(
+                &$config,
+                None,
+                $crate::db_check::DbCheck::Warn,
+            )
+            .map_err(|e| sc_cli::Error::from(*e))?;
             return $body;
         }
 
         // If a single runtime feature is enabled, use it as a safe fallback for custom specs.
         #[cfg(all(feature = "melodie-runtime", not(feature = "allfeat-runtime")))]
         {
-            let $partials =
-                $crate::service::new_partial::<$crate::service::MelodieRuntimeApi>(&$config)
-                    .map_err(|e| sc_cli::Error::from(*e))?;
+            let $partials = $crate::service::new_partial::<$crate::service::MelodieRuntimeApi>(
+                &$config,
+                None,
+                $crate::db_check::DbCheck::Warn,
+            )
+            .map_err(|e| sc_cli::Error::from(*e))?;
             return $body;
         }
 
         #[cfg(all(feature = "allfeat-runtime", not(feature = "melodie-runtime")))]
         {
-            let $partials =
-                $crate::service::new_partial::<$crate::service::AllfeatRuntimeApi>(&$config)
-                    .map_err(|e| sc_cli::Error::from(*e))?;
+            let $partials = $crate::service::new_partial::<$crate::service::AllfeatRuntimeApi>(
+                &$config,
+                None,
+                $crate::db_check::DbCheck::Warn,
+            )
+            .map_err(|e| sc_cli::Error::from(*e))?;
             return $body;
         }
 
@@ -23,31 +23,34 @@ pub use allfeat_runtime::RuntimeApi as AllfeatRuntimeApi;
 #[cfg(feature = "melodie-runtime")]
 pub use melodie_runtime::RuntimeApi as MelodieRuntimeApi;
 
+mod consensus;
+
 // std
-use sc_consensus_aura::{ImportQueueParams, StartAuraParams};
 use sc_consensus_grandpa::GrandpaPruningFilter;
-use std::{sync::Arc, time::Duration};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 // crates.io
 use futures::FutureExt;
 // allfeat
 use allfeat_primitives::*;
 // polkadot-sdk
 use sc_client_api::{BlockBackend, backend::Backend};
-use sc_consensus_slots::SlotProportion;
 use sc_rpc_spec_v2::SubscriptionTaskExecutor;
 use sc_service::{Configuration, TaskManager, WarpSyncConfig, error::Error as ServiceError};
 use sc_telemetry::TelemetryWorker;
 use sc_transaction_pool_api::OffchainTransactionPoolFactory;
 use sp_api::ConstructRuntimeApi;
-use sp_consensus_aura::sr25519::{AuthorityId as AuraId, AuthorityPair as AuraPair};
+use sp_keystore::KeystorePtr;
+
+use crate::{
+    db_check::DbCheck,
+    remote_keystore::{RemoteKeystore, RemoteSignerConfig},
+};
 
 /// The minimum period of blocks on which justifications will be
 /// imported and generated.
 const GRANDPA_JUSTIFICATION_PERIOD: u32 = 512;
 /// Telemetry worker buffer size.
 const TELEMETRY_BUFFER_SIZE: usize = 16;
-/// Proportion of slot duration used for block proposal.
-const BLOCK_PROPOSAL_SLOT_PORTION: f32 = 2.0 / 3.0;
 /// Duration between GRANDPA gossip rounds in milliseconds.
 const GRANDPA_GOSSIP_DURATION_MS: u64 = 333;
 
@@ -70,6 +73,9 @@ pub(crate) struct ConsensusParts<RuntimeApi> {
 pub(crate) struct ExtraParts<RuntimeApi> {
     pub consensus_parts: ConsensusParts<RuntimeApi>,
     pub telemetry: Option<sc_telemetry::Telemetry>,
+    /// Keystore holding the session keys: the remote signer if one is configured, the local
+    /// keystore of `keystore_container` otherwise.
+    pub keystore: KeystorePtr,
 }
 
 type Service<RuntimeApi> = sc_service::PartialComponents<
@@ -88,11 +94,13 @@ pub trait RuntimeApiCollection:
     + sp_api::Metadata<Block>
     + sp_block_builder::BlockBuilder<Block>
     + sp_consensus_grandpa::GrandpaApi<Block>
-    + sp_consensus_aura::AuraApi<Block, AuraId>
+    + consensus::ConsensusApi
     + sp_offchain::OffchainWorkerApi<Block>
     + sp_session::SessionKeys<Block>
     + sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
     + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+    + pallet_upgrade_notice_runtime_api::UpgradeNoticeApi<Block, BlockNumber>
+    + allfeat_primitives::signing::SigningApi<Block>
 {
 }
 impl<Api> RuntimeApiCollection for Api where
@@ -101,15 +109,18 @@ impl<Api> RuntimeApiCollection for Api where
         + sp_api::Metadata<Block>
         + sp_block_builder::BlockBuilder<Block>
         + sp_consensus_grandpa::GrandpaApi<Block>
-        + sp_consensus_aura::AuraApi<Block, AuraId>
+        + consensus::ConsensusApi
         + sp_offchain::OffchainWorkerApi<Block>
         + sp_session::SessionKeys<Block>
         + sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
         + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+        + pallet_upgrade_notice_runtime_api::UpgradeNoticeApi<Block, BlockNumber>
+        + allfeat_primitives::signing::SigningApi<Block>
 {
 }
 
-/// Optional MIDDS APIs implemented only by runtimes that host `pallet-midds`.
+/// Optional MIDDS and smart contracts APIs implemented only by runtimes that host
+/// `pallet-midds` and `pallet-contracts`.
 ///
 /// Kept distinct from [`RuntimeApiCollection`] so runtimes without MIDDS
 /// (e.g. the mainnet runtime today) can still satisfy the shared bounds.
@@ -132,7 +143,7 @@ pub trait MiddsRuntimeApiCollection:
         midds_types::Release,
         AccountId,
         Balance,
-    >
+    > + pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, OpaqueEventRecord>
 {
 }
 impl<Api> MiddsRuntimeApiCollection for Api where
@@ -154,27 +165,21 @@ impl<Api> MiddsRuntimeApiCollection for Api where
             midds_types::Release,
             AccountId,
             Balance,
+        > + pallet_contracts::ContractsApi<
+            Block,
+            AccountId,
+            Balance,
+            BlockNumber,
+            Hash,
+            OpaqueEventRecord,
         >
 {
 }
 
-/// Creates the inherent data providers used by both import queue and block authoring.
-fn create_inherent_data_providers(
-    slot_duration: sp_consensus_aura::SlotDuration,
-) -> (
-    sp_consensus_aura::inherents::InherentDataProvider,
-    sp_timestamp::InherentDataProvider,
-) {
-    let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
-    let slot = sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
-        *timestamp,
-        slot_duration,
-    );
-    (slot, timestamp)
-}
-
 pub fn new_partial<RuntimeApi>(
     config: &Configuration,
+    remote_signer: Option<&RemoteSignerConfig>,
+    db_check: DbCheck,
 ) -> Result<Service<RuntimeApi>, Box<ServiceError>>
 where
     RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>>,
@@ -203,6 +208,23 @@ where
         )?;
     let client = Arc::new(client);
 
+    // Before GRANDPA loads its authority set below.
+    crate::db_check::run(&*client, &*backend, db_check, |blocks| {
+        sc_consensus_grandpa::revert(client.clone(), blocks).map_err(|e| e.to_string())
+    })
+    .map_err(|e| Box::new(sc_service::Error::Other(e)))?;
+
+    let keystore: KeystorePtr = match remote_signer {
+        Some(signer) => {
+            log::info!("Using remote signer at {} for session keys", signer.url);
+            Arc::new(
+                RemoteKeystore::connect(signer)
+                    .map_err(|e| Box::new(sc_service::Error::Application(e.into())))?,
+            )
+        }
+        None => keystore_container.keystore(),
+    };
+
     let telemetry = telemetry.map(|(worker, telemetry)| {
         task_manager
             .spawn_handle()
@@ -232,30 +254,13 @@ where
     )
     .map_err(|e| Box::new(sc_service::Error::Application(e.into())))?;
 
-    let cidp_client = client.clone();
-    let import_queue =
-        sc_consensus_aura::import_queue::<AuraPair, _, _, _, _, _>(ImportQueueParams {
-            block_import: grandpa_block_import.clone(),
-            justification_import: Some(Box::new(grandpa_block_import.clone())),
-            client: client.clone(),
-            create_inherent_data_providers: move |parent_hash, _| {
-                let cidp_client = cidp_client.clone();
-                async move {
-                    let slot_duration =
-                        sc_consensus_aura::standalone::slot_duration_at(&*cidp_client, parent_hash)
-                            .map_err(|e| {
-                                Box::new(sp_consensus::error::Error::ClientImport(format!("{e:?}")))
-                            })?;
-                    Ok(create_inherent_data_providers(slot_duration))
-                }
-            },
-            spawner: &task_manager.spawn_essential_handle(),
-            registry: config.prometheus_registry(),
-            check_for_equivocation: Default::default(),
-            telemetry: telemetry.as_ref().map(|x| x.handle()),
-            compatibility_mode: Default::default(),
-        })
-        .map_err(|e| Box::new(sc_service::Error::Application(e.into())))?;
+    let import_queue = consensus::import_queue(
+        config,
+        client.clone(),
+        grandpa_block_import.clone(),
+        &task_manager,
+        telemetry.as_ref().map(|x| x.handle()),
+    )?;
     let consensus_parts = ConsensusParts {
         grandpa_block_import,
         grandpa_link,
@@ -272,6 +277,7 @@ where
         other: ExtraParts::<RuntimeApi> {
             consensus_parts,
             telemetry,
+            keystore,
         },
     })
 }
@@ -332,6 +338,9 @@ where
 /// Builds a new service for a full client.
 fn new_full<RuntimeApi, N, CreateRpc>(
     config: Configuration,
+    remote_signer: Option<RemoteSignerConfig>,
+    db_check: DbCheck,
+    explorer: Option<SocketAddr>,
     create_rpc: CreateRpc,
 ) -> Result<TaskManager, Box<ServiceError>>
 where
@@ -355,11 +364,12 @@ where
         backend,
         mut task_manager,
         import_queue,
-        keystore_container,
+        keystore_container: _,
         select_chain,
         transaction_pool,
         other: mut extra_parts,
-    } = new_partial::<RuntimeApi>(&config)?;
+    } = new_partial::<RuntimeApi>(&config, remote_signer.as_ref(), db_check)?;
+    let keystore = extra_parts.keystore.clone();
 
     let mut net_config = sc_network::config::FullNetworkConfiguration::<
         Block,
@@ -418,7 +428,7 @@ where
             sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
                 runtime_api_provider: client.clone(),
                 is_validator: config.role.is_authority(),
-                keystore: Some(keystore_container.keystore()),
+                keystore: Some(keystore.clone()),
                 offchain_db: backend.offchain_storage(),
                 transaction_pool: Some(OffchainTransactionPoolFactory::new(
                     transaction_pool.clone(),
@@ -433,6 +443,27 @@ where
         );
     }
 
+    #[cfg(feature = "explorer")]
+    if let Some(addr) = explorer {
+        // Bound here so that a taken address stops the node from starting.
+        let listener = std::net::TcpListener::bind(addr).map_err(|e| {
+            Box::new(ServiceError::Other(format!(
+                "Unable to serve the block explorer on {addr}: {e}"
+            )))
+        })?;
+        task_manager.spawn_handle().spawn(
+            "block-explorer",
+            None,
+            crate::explorer::serve(
+                listener,
+                client.clone(),
+                crate::explorer::decoder(&config.chain_spec),
+            ),
+        );
+    }
+    #[cfg(not(feature = "explorer"))]
+    let _ = explorer;
+
     let role = config.role;
     let force_authoring = config.force_authoring;
     let name = config.network.node_name.clone();
@@ -450,7 +481,7 @@ where
     sc_service::spawn_tasks(sc_service::SpawnTasksParams {
         network: Arc::new(network.clone()),
         client: client.clone(),
-        keystore: keystore_container.keystore(),
+        keystore: keystore.clone(),
         task_manager: &mut task_manager,
         transaction_pool: transaction_pool.clone(),
         rpc_builder: rpc_extensions_builder,
@@ -463,7 +494,16 @@ where
         tracing_execute_block: None,
     })?;
 
-    // Start consensus (Aura + GRANDPA)
+    task_manager.spawn_handle().spawn(
+        "upgrade-notice",
+        None,
+        crate::upgrade_notice::watch(
+            client.clone(),
+            extra_parts.telemetry.as_ref().map(|x| x.handle()),
+        ),
+    );
+
+    // Start consensus (block production + GRANDPA)
     if role.is_authority() {
         let proposer_factory = sc_basic_authorship::ProposerFactory::new(
             task_manager.spawn_handle(),
@@ -473,44 +513,23 @@ where
             extra_parts.telemetry.as_ref().map(|x| x.handle()),
         );
 
-        let slot_duration = sc_consensus_aura::slot_duration(&*client)
-            .map_err(|e| Box::new(sc_service::Error::Application(e.into())))?;
-
-        let aura = sc_consensus_aura::start_aura::<AuraPair, _, _, _, _, _, _, _, _, _, _>(
-            StartAuraParams {
-                slot_duration,
+        consensus::start_authoring(
+            consensus::AuthoringParams {
                 client,
                 select_chain,
                 block_import: extra_parts.consensus_parts.grandpa_block_import,
                 proposer_factory,
-                create_inherent_data_providers: move |_, ()| async move {
-                    Ok(create_inherent_data_providers(slot_duration))
-                },
+                keystore: keystore.clone(),
+                sync_service: sync_service.clone(),
                 force_authoring,
-                // Backoff authoring is disabled; all validators author at every opportunity.
-                backoff_authoring_blocks: None::<()>,
-                keystore: keystore_container.keystore(),
-                sync_oracle: sync_service.clone(),
-                justification_sync_link: sync_service.clone(),
-                block_proposal_slot_portion: SlotProportion::new(BLOCK_PROPOSAL_SLOT_PORTION),
-                max_block_proposal_slot_portion: None,
                 telemetry: extra_parts.telemetry.as_ref().map(|x| x.handle()),
-                compatibility_mode: Default::default(),
             },
-        )
-        .map_err(|e| Box::new(sc_service::Error::Application(e.into())))?;
-
-        task_manager
-            .spawn_essential_handle()
-            .spawn_blocking("aura", Some("block-authoring"), aura);
+            &task_manager,
+        )?;
     }
 
     if enable_grandpa {
-        let keystore = if role.is_authority() {
-            Some(keystore_container.keystore())
-        } else {
-            None
-        };
+        let keystore = role.is_authority().then_some(keystore);
 
         let grandpa_config = sc_consensus_grandpa::Config {
             gossip_duration: Duration::from_millis(GRANDPA_GOSSIP_DURATION_MS),
@@ -549,6 +568,9 @@ where
 
 pub fn new_full_from_network_cfg<RuntimeApi>(
     config: Configuration,
+    remote_signer: Option<RemoteSignerConfig>,
+    db_check: DbCheck,
+    explorer: Option<SocketAddr>,
 ) -> Result<TaskManager, Box<ServiceError>>
 where
     RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>>,
@@ -560,10 +582,19 @@ where
             RuntimeApi,
             sc_network::NetworkWorker<Block, <Block as sp_runtime::traits::Block>::Hash>,
             _,
-        >(config, crate::rpc::create_full),
+        >(
+            config,
+            remote_signer,
+            db_check,
+            explorer,
+            crate::rpc::create_full,
+        ),
         sc_network::config::NetworkBackendType::Litep2p => {
             new_full::<RuntimeApi, sc_network::Litep2pNetworkBackend, _>(
                 config,
+                remote_signer,
+                db_check,
+                explorer,
                 crate::rpc::create_full,
             )
         }
@@ -575,6 +606,9 @@ where
 /// (`midds_runtime_api::MusicalWorkApi` + `midds_runtime_api::RecordingApi`).
 pub fn new_full_from_network_cfg_with_midds<RuntimeApi>(
     config: Configuration,
+    remote_signer: Option<RemoteSignerConfig>,
+    db_check: DbCheck,
+    explorer: Option<SocketAddr>,
 ) -> Result<TaskManager, Box<ServiceError>>
 where
     RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>>,
@@ -582,16 +616,23 @@ where
     RuntimeApi::RuntimeApi: RuntimeApiCollection + MiddsRuntimeApiCollection,
 {
     match config.network.network_backend {
-        sc_network::config::NetworkBackendType::Libp2p => {
-            new_full::<
-                RuntimeApi,
-                sc_network::NetworkWorker<Block, <Block as sp_runtime::traits::Block>::Hash>,
-                _,
-            >(config, crate::rpc::create_full_with_midds)
-        }
+        sc_network::config::NetworkBackendType::Libp2p => new_full::<
+            RuntimeApi,
+            sc_network::NetworkWorker<Block, <Block as sp_runtime::traits::Block>::Hash>,
+            _,
+        >(
+            config,
+            remote_signer,
+            db_check,
+            explorer,
+            crate::rpc::create_full_with_midds,
+        ),
         sc_network::config::NetworkBackendType::Litep2p => {
             new_full::<RuntimeApi, sc_network::Litep2pNetworkBackend, _>(
                 config,
+                remote_signer,
+                db_check,
+                explorer,
                 crate::rpc::create_full_with_midds,
             )
         }
@@ -0,0 +1,172 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Block production (Aura).
+//!
+//! The service builds the import queue and starts authoring only through this module, and
+//! runtimes only implement [`ConsensusApi`] for it, so the slot-based engine can be replaced
+//! without touching the rest of the service. GRANDPA finality, networking and the RPCs are set
+//! up by the service independently of the engine.
+
+use std::sync::Arc;
+
+use allfeat_primitives::Block;
+use sc_consensus::DefaultImportQueue;
+use sc_consensus_aura::{ImportQueueParams, StartAuraParams};
+use sc_consensus_slots::SlotProportion;
+use sc_service::{Configuration, TaskManager, error::Error as ServiceError};
+use sc_telemetry::TelemetryHandle;
+use sp_api::ConstructRuntimeApi;
+use sp_consensus_aura::sr25519::{AuthorityId as AuraId, AuthorityPair as AuraPair};
+use sp_keystore::KeystorePtr;
+
+use super::{FullClient, FullGrandpaBlockImport, FullSelectChain, RuntimeApiCollection};
+
+/// Proportion of slot duration used for block proposal.
+const BLOCK_PROPOSAL_SLOT_PORTION: f32 = 2.0 / 3.0;
+
+/// Runtime API the engine needs.
+pub trait ConsensusApi: sp_consensus_aura::AuraApi<Block, AuraId> {}
+impl<Api> ConsensusApi for Api where Api: sp_consensus_aura::AuraApi<Block, AuraId> {}
+
+/// Parameters of [`start_authoring`].
+pub(super) struct AuthoringParams<RuntimeApi, PF, SO> {
+    pub client: Arc<FullClient<RuntimeApi>>,
+    pub select_chain: FullSelectChain,
+    pub block_import: FullGrandpaBlockImport<RuntimeApi>,
+    pub proposer_factory: PF,
+    pub keystore: KeystorePtr,
+    pub sync_service: SO,
+    pub force_authoring: bool,
+    pub telemetry: Option<TelemetryHandle>,
+}
+
+/// Creates the inherent data providers used by both import queue and block authoring.
+fn create_inherent_data_providers(
+    slot_duration: sp_consensus_aura::SlotDuration,
+) -> (
+    sp_consensus_aura::inherents::InherentDataProvider,
+    sp_timestamp::InherentDataProvider,
+) {
+    let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+    let slot = sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+        *timestamp,
+        slot_duration,
+    );
+    (slot, timestamp)
+}
+
+/// The queue importing blocks through `block_import`, which also imports GRANDPA
+/// justifications.
+pub(super) fn import_queue<RuntimeApi>(
+    config: &Configuration,
+    client: Arc<FullClient<RuntimeApi>>,
+    block_import: FullGrandpaBlockImport<RuntimeApi>,
+    task_manager: &TaskManager,
+    telemetry: Option<TelemetryHandle>,
+) -> Result<DefaultImportQueue<Block>, Box<ServiceError>>
+where
+    RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>>,
+    RuntimeApi: Send + Sync + 'static,
+    RuntimeApi::RuntimeApi: RuntimeApiCollection,
+{
+    let cidp_client = client.clone();
+    sc_consensus_aura::import_queue::<AuraPair, _, _, _, _, _>(ImportQueueParams {
+        block_import: block_import.clone(),
+        justification_import: Some(Box::new(block_import)),
+        client,
+        create_inherent_data_providers: move |parent_hash, _| {
+            let cidp_client = cidp_client.clone();
+            async move {
+                let slot_duration =
+                    sc_consensus_aura::standalone::slot_duration_at(&*cidp_client, parent_hash)
+                        .map_err(|e| {
+                            Box::new(sp_consensus::error::Error::ClientImport(format!("{e:?}")))
+                        })?;
+                Ok(create_inherent_data_providers(slot_duration))
+            }
+        },
+        spawner: &task_manager.spawn_essential_handle(),
+        registry: config.prometheus_registry(),
+        check_for_equivocation: Default::default(),
+        telemetry,
+        compatibility_mode: Default::default(),
+    })
+    .map_err(|e| Box::new(sc_service::Error::Application(e.into())))
+}
+
+/// Author blocks on the slots of the authority keys in the keystore.
+pub(super) fn start_authoring<RuntimeApi, PF, SO>(
+    params: AuthoringParams<RuntimeApi, PF, SO>,
+    task_manager: &TaskManager,
+) -> Result<(), Box<ServiceError>>
+where
+    RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>>,
+    RuntimeApi: Send + Sync + 'static,
+    RuntimeApi::RuntimeApi: RuntimeApiCollection,
+    PF: sp_consensus::Environment<Block, Error = sp_blockchain::Error> + Send + Sync + 'static,
+    PF::Proposer: sp_consensus::Proposer<Block, Error = sp_blockchain::Error>,
+    SO: sp_consensus::SyncOracle
+        + sc_consensus::JustificationSyncLink<Block>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+{
+    let AuthoringParams {
+        client,
+        select_chain,
+        block_import,
+        proposer_factory,
+        keystore,
+        sync_service,
+        force_authoring,
+        telemetry,
+    } = params;
+
+    let slot_duration = sc_consensus_aura::slot_duration(&*client)
+        .map_err(|e| Box::new(sc_service::Error::Application(e.into())))?;
+
+    let aura =
+        sc_consensus_aura::start_aura::<AuraPair, _, _, _, _, _, _, _, _, _, _>(StartAuraParams {
+            slot_duration,
+            client,
+            select_chain,
+            block_import,
+            proposer_factory,
+            create_inherent_data_providers: move |_, ()| async move {
+                Ok(create_inherent_data_providers(slot_duration))
+            },
+            force_authoring,
+            // Backoff authoring is disabled; all validators author at every opportunity.
+            backoff_authoring_blocks: None::<()>,
+            keystore,
+            sync_oracle: sync_service.clone(),
+            justification_sync_link: sync_service,
+            block_proposal_slot_portion: SlotProportion::new(BLOCK_PROPOSAL_SLOT_PORTION),
+            max_block_proposal_slot_portion: None,
+            telemetry,
+            compatibility_mode: Default::default(),
+        })
+        .map_err(|e| Box::new(sc_service::Error::Application(e.into())))?;
+
+    task_manager
+        .spawn_essential_handle()
+        .spawn_blocking("aura", Some("block-authoring"), aura);
+    Ok(())
+}
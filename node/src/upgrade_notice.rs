@@ -0,0 +1,90 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Countdown to the runtime upgrade announced on chain with `pallet-upgrade-notice`, so
+//! operators learn ahead of enactment that they may need a new node release.
+
+use std::sync::Arc;
+
+use allfeat_primitives::{Block, BlockNumber};
+use futures::StreamExt;
+use pallet_upgrade_notice_runtime_api::UpgradeNoticeApi;
+use sc_client_api::BlockchainEvents;
+use sc_telemetry::{SUBSTRATE_INFO, TelemetryHandle, telemetry};
+use sp_api::ProvideRuntimeApi;
+use sp_runtime::traits::Header;
+
+/// Blocks between two reminders while the activation is far off (an hour of 6s blocks).
+const REMINDER_INTERVAL: BlockNumber = 600;
+/// Every block is reported once the activation is this close.
+const FINAL_COUNTDOWN: BlockNumber = 100;
+
+/// Warn in the logs and telemetry about the pending upgrade as new best blocks are imported:
+/// when it is first seen, then every `REMINDER_INTERVAL` blocks, then at every block of the
+/// `FINAL_COUNTDOWN`.
+pub async fn watch<C>(client: Arc<C>, telemetry: Option<TelemetryHandle>)
+where
+    C: ProvideRuntimeApi<Block> + BlockchainEvents<Block>,
+    C::Api: UpgradeNoticeApi<Block, BlockNumber>,
+{
+    let mut imports = client.import_notification_stream();
+    let mut reported = None;
+
+    while let Some(notification) = imports.next().await {
+        if !notification.is_new_best {
+            continue;
+        }
+        let notice = match client.runtime_api().pending_upgrade(notification.hash) {
+            Ok(Some(notice)) => notice,
+            Ok(None) => {
+                reported = None;
+                continue;
+            }
+            Err(e) => {
+                log::debug!("Unable to read the pending runtime upgrade: {e}");
+                continue;
+            }
+        };
+
+        let remaining = notice
+            .activation
+            .saturating_sub(*notification.header.number());
+        if reported == Some(notice.code_hash)
+            && remaining > FINAL_COUNTDOWN
+            && remaining % REMINDER_INTERVAL != 0
+        {
+            continue;
+        }
+        reported = Some(notice.code_hash);
+
+        log::warn!(
+            "Mandatory runtime upgrade {:?} activates at block #{} ({remaining} blocks left). \
+             Check that this node runs a release supporting it.",
+            notice.code_hash,
+            notice.activation,
+        );
+        telemetry!(
+            telemetry;
+            SUBSTRATE_INFO;
+            "upgrade.pending";
+            "code_hash" => ?notice.code_hash,
+            "activation" => notice.activation,
+            "remaining" => remaining,
+        );
+    }
+}
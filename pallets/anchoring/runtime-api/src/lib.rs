@@ -0,0 +1,41 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API looking up the delivery files anchored with `pallet-anchoring` by content hash.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_core::H256;
+
+pub use pallet_anchoring::{Anchor, DeliveryKind, SequenceNumber};
+
+sp_api::decl_runtime_apis! {
+    pub trait AnchoringApi<AccountId, BlockNumber, Moment>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Moment: Codec,
+    {
+        /// Sender, sequence number and anchor of the delivery file digested by `content_hash`.
+        /// `None` if it was never anchored.
+        fn anchor_of_content(
+            content_hash: H256,
+        ) -> Option<(AccountId, SequenceNumber, Anchor<BlockNumber, Moment>)>;
+    }
+}
@@ -0,0 +1,206 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Anchoring
+//!
+//! Anchors the content hashes of metadata delivery files (DDEX ERN messages, CWR
+//! transmissions) so distributors can prove which file they delivered, and when.
+//!
+//! ## Features
+//! - An anchor records the kind of delivery, the digest of the file, and the block and time at
+//!   which it was anchored.
+//! - Anchors are keyed by sender and sequence number. Sequence numbers mirror those of the
+//!   delivery protocol (ERN message ids, CWR transmission sequence numbers) and must strictly
+//!   increase per sender, gaps allowed.
+//! - A digest can only be anchored once: the first anchor is the proof of delivery.
+//!   [`Pallet::anchor_of_content`] looks an anchor up by digest, and the
+//!   `pallet-anchoring-runtime-api` crate exposes it to clients.
+//!
+//! Anchors are permanent and deliberately small; the delivery files themselves stay off chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{pallet_prelude::*, traits::Time};
+use frame_system::pallet_prelude::*;
+use sp_core::H256;
+
+pub type SequenceNumber = u64;
+
+pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
+
+pub type AnchorOf<T> = Anchor<BlockNumberFor<T>, MomentOf<T>>;
+
+/// Format of an anchored delivery file.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum DeliveryKind {
+    /// A DDEX Electronic Release Notification message.
+    DdexErn,
+    /// A CISAC Common Works Registration transmission.
+    Cwr,
+}
+
+/// An anchored delivery file.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Anchor<BlockNumber, Moment> {
+    pub kind: DeliveryKind,
+    /// Digest of the delivery file.
+    pub content_hash: H256,
+    /// Block at which the file was anchored.
+    pub anchored_at: BlockNumber,
+    /// Time of that block.
+    pub timestamp: Moment,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Source of the anchors' timestamps.
+        type Time: Time;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Anchored delivery files, keyed by sender and sequence number.
+    #[pallet::storage]
+    pub type Anchors<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        SequenceNumber,
+        AnchorOf<T>,
+        OptionQuery,
+    >;
+
+    /// Sequence number of each sender's last anchor.
+    #[pallet::storage]
+    pub type LastSequence<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SequenceNumber, OptionQuery>;
+
+    /// Reverse index from content hash to the sender and sequence number that anchored it.
+    #[pallet::storage]
+    pub type ContentIndex<T: Config> =
+        StorageMap<_, Identity, H256, (T::AccountId, SequenceNumber), OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        DeliveryAnchored {
+            sender: T::AccountId,
+            sequence: SequenceNumber,
+            kind: DeliveryKind,
+            content_hash: H256,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The sequence number is not above the sender's last one.
+        SequenceNotIncreasing,
+        ContentAlreadyAnchored,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Anchor the delivery file of `kind` digested by `content_hash`, as the sender's
+        /// `sequence`-th delivery.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::anchor())]
+        pub fn anchor(
+            origin: OriginFor<T>,
+            kind: DeliveryKind,
+            sequence: SequenceNumber,
+            content_hash: H256,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                LastSequence::<T>::get(&who).is_none_or(|last| sequence > last),
+                Error::<T>::SequenceNotIncreasing
+            );
+            ensure!(
+                !ContentIndex::<T>::contains_key(content_hash),
+                Error::<T>::ContentAlreadyAnchored
+            );
+
+            ContentIndex::<T>::insert(content_hash, (&who, sequence));
+            LastSequence::<T>::insert(&who, sequence);
+            Anchors::<T>::insert(
+                &who,
+                sequence,
+                Anchor {
+                    kind,
+                    content_hash,
+                    anchored_at: frame_system::Pallet::<T>::block_number(),
+                    timestamp: T::Time::now(),
+                },
+            );
+
+            Self::deposit_event(Event::DeliveryAnchored {
+                sender: who,
+                sequence,
+                kind,
+                content_hash,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The sender, sequence number and anchor of the delivery file digested by
+        /// `content_hash`, if it was anchored.
+        pub fn anchor_of_content(
+            content_hash: H256,
+        ) -> Option<(T::AccountId, SequenceNumber, AnchorOf<T>)> {
+            let (sender, sequence) = ContentIndex::<T>::get(content_hash)?;
+            let anchor = Anchors::<T>::get(&sender, sequence)?;
+            Some((sender, sequence, anchor))
+        }
+    }
+}
@@ -0,0 +1,174 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Anchor, Anchors, DeliveryKind, Error, Event, LastSequence, mock::*};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+fn file(n: u8) -> H256 {
+    H256::repeat_byte(n)
+}
+
+#[test]
+fn anchor_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        assert_ok!(Anchoring::anchor(
+            RuntimeOrigin::signed(DISTRIBUTOR),
+            DeliveryKind::DdexErn,
+            42,
+            file(1)
+        ));
+
+        assert_eq!(
+            Anchors::<Test>::get(DISTRIBUTOR, 42),
+            Some(Anchor {
+                kind: DeliveryKind::DdexErn,
+                content_hash: file(1),
+                anchored_at: 5,
+                timestamp: 5 * BLOCK_TIME,
+            })
+        );
+        assert_eq!(LastSequence::<Test>::get(DISTRIBUTOR), Some(42));
+        System::assert_last_event(
+            Event::DeliveryAnchored {
+                sender: DISTRIBUTOR,
+                sequence: 42,
+                kind: DeliveryKind::DdexErn,
+                content_hash: file(1),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn anchor_requires_signed_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Anchoring::anchor(RuntimeOrigin::root(), DeliveryKind::Cwr, 1, file(1)),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn sequence_must_increase_per_sender() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Anchoring::anchor(
+            RuntimeOrigin::signed(DISTRIBUTOR),
+            DeliveryKind::Cwr,
+            0,
+            file(1)
+        ));
+        assert_noop!(
+            Anchoring::anchor(
+                RuntimeOrigin::signed(DISTRIBUTOR),
+                DeliveryKind::Cwr,
+                0,
+                file(2)
+            ),
+            Error::<Test>::SequenceNotIncreasing
+        );
+
+        // Gaps are allowed.
+        assert_ok!(Anchoring::anchor(
+            RuntimeOrigin::signed(DISTRIBUTOR),
+            DeliveryKind::Cwr,
+            7,
+            file(2)
+        ));
+        assert_noop!(
+            Anchoring::anchor(
+                RuntimeOrigin::signed(DISTRIBUTOR),
+                DeliveryKind::Cwr,
+                3,
+                file(3)
+            ),
+            Error::<Test>::SequenceNotIncreasing
+        );
+
+        // Sequences are per sender.
+        assert_ok!(Anchoring::anchor(
+            RuntimeOrigin::signed(PUBLISHER),
+            DeliveryKind::Cwr,
+            0,
+            file(3)
+        ));
+    });
+}
+
+#[test]
+fn content_can_only_be_anchored_once() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Anchoring::anchor(
+            RuntimeOrigin::signed(DISTRIBUTOR),
+            DeliveryKind::DdexErn,
+            1,
+            file(1)
+        ));
+
+        assert_noop!(
+            Anchoring::anchor(
+                RuntimeOrigin::signed(DISTRIBUTOR),
+                DeliveryKind::DdexErn,
+                2,
+                file(1)
+            ),
+            Error::<Test>::ContentAlreadyAnchored
+        );
+        assert_noop!(
+            Anchoring::anchor(
+                RuntimeOrigin::signed(PUBLISHER),
+                DeliveryKind::DdexErn,
+                1,
+                file(1)
+            ),
+            Error::<Test>::ContentAlreadyAnchored
+        );
+    });
+}
+
+#[test]
+fn anchor_of_content_looks_up_by_hash() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Anchoring::anchor_of_content(file(1)), None);
+
+        assert_ok!(Anchoring::anchor(
+            RuntimeOrigin::signed(PUBLISHER),
+            DeliveryKind::Cwr,
+            9,
+            file(1)
+        ));
+
+        assert_eq!(
+            Anchoring::anchor_of_content(file(1)),
+            Some((
+                PUBLISHER,
+                9,
+                Anchor {
+                    kind: DeliveryKind::Cwr,
+                    content_hash: file(1),
+                    anchored_at: 1,
+                    timestamp: BLOCK_TIME,
+                }
+            ))
+        );
+    });
+}
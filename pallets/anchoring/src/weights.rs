@@ -0,0 +1,37 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_anchoring.
+pub trait WeightInfo {
+    fn anchor() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Anchoring::LastSequence` (r:1 w:1)
+    /// Storage: `Anchoring::ContentIndex` (r:1 w:1)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `Anchoring::Anchors` (r:0 w:1)
+    fn anchor() -> Weight {
+        Weight::from_parts(20_000_000, 3_600)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+}
@@ -0,0 +1,276 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn member<T: Config>(i: u32) -> T::AccountId {
+    account("member", i, SEED)
+}
+
+/// Creates artist 0 with `m` funded members, all of them needed for every action.
+fn setup_artist<T: Config>(m: u32) -> Result<(), BenchmarkError> {
+    let members: MembersOf<T> = (0..m)
+        .map(member::<T>)
+        .collect::<alloc::vec::Vec<_>>()
+        .try_into()
+        .map_err(|_| BenchmarkError::Weightless)?;
+    let depositor = member::<T>(0);
+    T::Currency::set_balance(
+        &depositor,
+        T::ArtistDeposit::get().saturating_add(T::Currency::minimum_balance()),
+    );
+    let thresholds = Thresholds {
+        metadata: m,
+        payout: m,
+        membership: m,
+    };
+    Pallet::<T>::create_artist(
+        RawOrigin::Signed(depositor).into(),
+        members,
+        thresholds,
+        T::Hash::default(),
+    )?;
+    Ok(())
+}
+
+/// Has member 0 propose `action`, then every other member approve it.
+fn approved_proposal<T: Config>(m: u32, action: ActionOf<T>) -> Result<(), BenchmarkError> {
+    Pallet::<T>::propose(RawOrigin::Signed(member::<T>(0)).into(), 0, action)?;
+    let proposal = Artists::<T>::get(0)
+        .ok_or(BenchmarkError::Weightless)?
+        .next_proposal
+        .saturating_sub(1);
+    for i in 1..m {
+        Pallet::<T>::approve(RawOrigin::Signed(member::<T>(i)).into(), 0, proposal)?;
+    }
+    Ok(())
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_artist(m: Linear<1, { T::MaxMembers::get() }>) -> Result<(), BenchmarkError> {
+        let members: MembersOf<T> = (0..m)
+            .map(member::<T>)
+            .collect::<alloc::vec::Vec<_>>()
+            .try_into()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let caller = member::<T>(0);
+        T::Currency::set_balance(
+            &caller,
+            T::ArtistDeposit::get().saturating_add(T::Currency::minimum_balance()),
+        );
+        let thresholds = Thresholds {
+            metadata: m,
+            payout: m,
+            membership: m,
+        };
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            members,
+            thresholds,
+            T::Hash::default(),
+        );
+
+        assert!(Artists::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn propose() -> Result<(), BenchmarkError> {
+        setup_artist::<T>(T::MaxMembers::get())?;
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(member::<T>(0)),
+            0,
+            Action::SetMetadata(T::Hash::default()),
+        );
+
+        assert!(Proposals::<T>::contains_key(0, 0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn approve() -> Result<(), BenchmarkError> {
+        let m = T::MaxMembers::get();
+        setup_artist::<T>(m)?;
+        approved_proposal::<T>(m - 1, Action::SetMetadata(T::Hash::default()))?;
+        let last = member::<T>(m - 1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(last.clone()), 0, 0);
+
+        assert!(Proposals::<T>::get(0, 0).is_some_and(|p| p.approvals.contains(&last)));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_proposal() -> Result<(), BenchmarkError> {
+        setup_artist::<T>(T::MaxMembers::get())?;
+        approved_proposal::<T>(
+            T::MaxMembers::get(),
+            Action::SetMetadata(T::Hash::default()),
+        )?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(member::<T>(0)), 0, 0);
+
+        assert!(!Proposals::<T>::contains_key(0, 0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn execute_update() -> Result<(), BenchmarkError> {
+        let m = T::MaxMembers::get() - 1;
+        setup_artist::<T>(m)?;
+        let newcomer: T::AccountId = account("newcomer", 0, SEED);
+        approved_proposal::<T>(m, Action::AddMember(newcomer.clone()))?;
+        let caller: T::AccountId = account("caller", 0, SEED);
+
+        #[extrinsic_call]
+        execute(RawOrigin::Signed(caller), 0, 0);
+
+        assert!(Pallet::<T>::is_member(0, &newcomer));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn execute_payout() -> Result<(), BenchmarkError> {
+        let m = T::MaxMembers::get();
+        setup_artist::<T>(m)?;
+        let amount = T::Currency::minimum_balance();
+        T::Currency::set_balance(&Pallet::<T>::artist_account(0), amount);
+        let to: T::AccountId = account("payee", 0, SEED);
+        approved_proposal::<T>(
+            m,
+            Action::Payout {
+                to: to.clone(),
+                amount,
+            },
+        )?;
+        let caller: T::AccountId = account("caller", 0, SEED);
+
+        #[extrinsic_call]
+        execute(RawOrigin::Signed(caller), 0, 0);
+
+        assert_eq!(T::Currency::balance(&to), amount);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn execute_dissolve(
+        p: Linear<0, { T::MaxProposals::get() - 1 }>,
+    ) -> Result<(), BenchmarkError> {
+        let m = T::MaxMembers::get();
+        setup_artist::<T>(m)?;
+        approved_proposal::<T>(m, Action::Dissolve)?;
+        for _ in 0..p {
+            Pallet::<T>::propose(
+                RawOrigin::Signed(member::<T>(0)).into(),
+                0,
+                Action::SetMetadata(T::Hash::default()),
+            )?;
+        }
+        let caller: T::AccountId = account("caller", 0, SEED);
+
+        #[extrinsic_call]
+        execute(RawOrigin::Signed(caller), 0, 0);
+
+        assert!(!Artists::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_artist_with_work(
+        m: Linear<1, { T::MaxMembers::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let members: MembersOf<T> = (0..m)
+            .map(member::<T>)
+            .collect::<alloc::vec::Vec<_>>()
+            .try_into()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let caller = member::<T>(0);
+        let thresholds = Thresholds {
+            metadata: m,
+            payout: m,
+            membership: m,
+        };
+        // Any solution passes: solving is off chain, checking one costs the same.
+        PowDifficulty::<T>::put(0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            members,
+            thresholds,
+            T::Hash::default(),
+            0,
+        );
+
+        assert!(WorkArtists::<T>::contains_key(&caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn adjust_pow_difficulty() {
+        PowRegistrations::<T>::put(T::TargetPowRegistrations::get().saturating_add(1));
+
+        #[block]
+        {
+            Pallet::<T>::adjust_pow_difficulty();
+        }
+
+        assert_eq!(PowRegistrations::<T>::get(), 0);
+    }
+
+    #[benchmark]
+    fn check_artist_work() -> Result<(), BenchmarkError> {
+        let m = T::MaxMembers::get();
+        let members: MembersOf<T> = (0..m)
+            .map(member::<T>)
+            .collect::<alloc::vec::Vec<_>>()
+            .try_into()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let caller = member::<T>(0);
+        let thresholds = Thresholds {
+            metadata: m,
+            payout: m,
+            membership: m,
+        };
+        PowDifficulty::<T>::put(0);
+
+        // What the extension's `validate` and `prepare` each run.
+        #[block]
+        {
+            Pallet::<T>::check_work(&caller, &members, &thresholds, &T::Hash::default(), 0)?;
+        }
+
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,152 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Call, Config, Origin, POW_CONTEXT, Pallet, PowSeed, WeightInfo};
+use alloc::vec;
+use core::marker::PhantomData;
+use frame_support::{
+    DefaultNoBound,
+    pallet_prelude::{Decode, DecodeWithMemTracking, Encode, TransactionSource, TypeInfo},
+    traits::{Get, IsSubType, OriginTrait},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+    SaturatedConversion, Saturating, Weight,
+    traits::{DispatchInfoOf, DispatchOriginOf, Implication, TransactionExtension, ValidateResult},
+    transaction_validity::{InvalidTransaction, TransactionValidityError, ValidTransaction},
+};
+
+/// Takes over the proof-of-work registrations whose solution is valid, so that their caller
+/// needs neither funds nor an account.
+///
+/// The signed origin of such a transaction becomes [`crate::RawOrigin::Solver`], which the
+/// nonce and payment extensions after this one leave alone: nothing is charged and the nonce
+/// is not checked. Transactions with an invalid solution are refused by the pool instead of
+/// paying for a failed call. Must come before `CheckNonce` and the payment extension.
+#[derive(Encode, Decode, DecodeWithMemTracking, DefaultNoBound, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckArtistWork<T>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> core::fmt::Debug for CheckArtistWork<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CheckArtistWork")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config + Send + Sync> CheckArtistWork<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Checks the solution of `call` if it is a proof-of-work registration by `who`: `None`
+    /// for other calls.
+    fn check(
+        who: &T::AccountId,
+        call: &T::RuntimeCall,
+    ) -> Option<Result<(), TransactionValidityError>>
+    where
+        T::RuntimeCall: IsSubType<Call<T>>,
+    {
+        let Some(Call::create_artist_with_work {
+            members,
+            thresholds,
+            metadata,
+            nonce,
+        }) = call.is_sub_type()
+        else {
+            return None;
+        };
+        Some(
+            Pallet::<T>::check_work(who, members, thresholds, metadata, *nonce)
+                .map_err(|_| InvalidTransaction::BadProof.into()),
+        )
+    }
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<T::RuntimeCall> for CheckArtistWork<T>
+where
+    T::RuntimeCall: IsSubType<Call<T>>,
+    T::RuntimeOrigin: From<Origin<T>>,
+{
+    const IDENTIFIER: &'static str = "CheckArtistWork";
+    type Implicit = ();
+    /// The solver of a registration taken over.
+    type Val = Option<T::AccountId>;
+    type Pre = ();
+
+    fn weight(&self, call: &T::RuntimeCall) -> Weight {
+        match call.is_sub_type() {
+            Some(Call::create_artist_with_work { .. }) => T::WeightInfo::check_artist_work(),
+            _ => Weight::zero(),
+        }
+    }
+
+    fn validate(
+        &self,
+        origin: DispatchOriginOf<T::RuntimeCall>,
+        call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+        _self_implicit: Self::Implicit,
+        _inherited_implication: &impl Implication,
+        _source: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        let Some(who) = origin.as_signer().cloned() else {
+            return Ok((Default::default(), None, origin));
+        };
+        match Self::check(&who, call) {
+            None => Ok((Default::default(), None, origin)),
+            Some(Err(e)) => Err(e),
+            Some(Ok(())) => {
+                // Solutions expire with the seed they were found under.
+                let now = frame_system::Pallet::<T>::block_number();
+                let period = T::PowAdjustmentPeriod::get();
+                let left: BlockNumberFor<T> = period.saturating_sub(now % period);
+                let validity = ValidTransaction {
+                    provides: vec![(POW_CONTEXT, PowSeed::<T>::get(), &who).encode()],
+                    longevity: left.saturated_into(),
+                    ..Default::default()
+                };
+                let solver: T::RuntimeOrigin = Origin::<T>::Solver(who.clone()).into();
+                Ok((validity, Some(who), solver))
+            }
+        }
+    }
+
+    fn prepare(
+        self,
+        val: Self::Val,
+        _origin: &DispatchOriginOf<T::RuntimeCall>,
+        call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        // Registrations included earlier may have raised the difficulty since validation: the
+        // call must not fail once included, as nobody pays for it.
+        match val {
+            Some(who) => Self::check(&who, call).unwrap_or(Ok(())),
+            None => Ok(()),
+        }
+    }
+}
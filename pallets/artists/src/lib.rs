@@ -0,0 +1,879 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Artists
+//!
+//! Artist entities, such as bands, controlled by several member accounts.
+//!
+//! ## Features
+//! - An artist has its own account, derived from `Config::PalletId`, which royalties, sales
+//!   and advances can be paid to like to any other account.
+//! - Its members act for it by proposing actions and approving them. Each kind of action has
+//!   its own threshold of approvals ([`Thresholds`]): editing the artist's metadata can take
+//!   fewer members than paying funds out of its account.
+//! - Once enough current members approve a proposal, anyone executes it.
+//! - Members are added and removed through proposals too: a member being removed does not
+//!   count towards the approvals of their own removal, so it takes the consent of the
+//!   remaining members.
+//! - A deposit is held from the creator for the lifetime of the artist and released when
+//!   the members dissolve it.
+//! - Creators without the deposit can solve a proof-of-work puzzle instead
+//!   ([`Pallet::create_artist_with_work`]), one artist per account at a time. The puzzle is
+//!   bound to the caller, to the number of artists it created this way so far and to a seed
+//!   renewed every `Config::PowAdjustmentPeriod`, at the end of which the difficulty moves by
+//!   one bit towards `Config::TargetPowRegistrations` registrations per period. Within a
+//!   period, every `TargetPowRegistrations` registrations make the next ones one bit harder,
+//!   which bounds the storage they take without a deposit.
+//! - The [`CheckArtistWork`] transaction extension checks solutions in the transaction pool
+//!   and takes the transaction over: it pays no fee and needs no nonce, so an account without
+//!   any funds can send it.
+//!
+//! Descriptive metadata (name, biography, ...) lives off chain: the artist only commits to
+//! its hash. Labels and other parties dealing with the artist are out of scope.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod extension;
+pub use extension::CheckArtistWork;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{
+    PalletId,
+    pallet_prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{Precision, Preservation},
+    },
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{
+    Saturating,
+    traits::{AccountIdConversion, Hash, Zero},
+};
+
+pub type ArtistId = u32;
+
+pub type ProposalIndex = u32;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type MembersOf<T> =
+    BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxMembers>;
+
+pub type ArtistOf<T> = Artist<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Config>::Hash,
+    MembersOf<T>,
+>;
+
+pub type ActionOf<T> =
+    Action<<T as frame_system::Config>::AccountId, BalanceOf<T>, <T as frame_system::Config>::Hash>;
+
+pub type ProposalOf<T> =
+    Proposal<<T as frame_system::Config>::AccountId, ActionOf<T>, MembersOf<T>>;
+
+/// Domain separator of the proof-of-work puzzle.
+pub const POW_CONTEXT: &[u8] = b"allfeat/artists/pow";
+
+/// Number of leading zero bits of `hash`.
+pub fn leading_zero_bits(hash: &[u8]) -> u32 {
+    let mut bits = 0;
+    for byte in hash {
+        bits += byte.leading_zeros();
+        if *byte != 0 {
+            break;
+        }
+    }
+    bits
+}
+
+/// Origin of the proof-of-work registrations taken over by [`CheckArtistWork`].
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum RawOrigin<AccountId> {
+    /// The account that solved the puzzle and signed the transaction.
+    Solver(AccountId),
+}
+
+/// Ensures the origin of a proof-of-work registration: [`RawOrigin::Solver`], or a signed
+/// origin for the transactions [`CheckArtistWork`] did not take over.
+pub struct EnsureSolver<AccountId>(PhantomData<AccountId>);
+
+impl<O, AccountId> EnsureOrigin<O> for EnsureSolver<AccountId>
+where
+    O: Into<Result<RawOrigin<AccountId>, O>>
+        + Into<Result<frame_system::RawOrigin<AccountId>, O>>
+        + From<frame_system::RawOrigin<AccountId>>,
+    AccountId: Decode,
+{
+    type Success = AccountId;
+
+    fn try_origin(o: O) -> Result<AccountId, O> {
+        let o = match Into::<Result<RawOrigin<AccountId>, O>>::into(o) {
+            Ok(RawOrigin::Solver(who)) => return Ok(who),
+            Err(o) => o,
+        };
+        match o.into() {
+            Ok(frame_system::RawOrigin::Signed(who)) => Ok(who),
+            Ok(other) => Err(O::from(other)),
+            Err(o) => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        let who = AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
+            .map_err(|_| ())?;
+        Ok(O::from(frame_system::RawOrigin::Signed(who)))
+    }
+}
+
+/// Number of member approvals each kind of action takes.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct Thresholds {
+    /// Replacing the artist's metadata.
+    pub metadata: u32,
+    /// Paying funds out of the artist's account.
+    pub payout: u32,
+    /// Adding or removing members, changing thresholds and dissolving the artist.
+    pub membership: u32,
+}
+
+impl Thresholds {
+    /// Whether every threshold can be met by `members` members.
+    pub fn fit(&self, members: u32) -> bool {
+        [self.metadata, self.payout, self.membership]
+            .iter()
+            .all(|t| (1..=members).contains(t))
+    }
+}
+
+/// An artist entity.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Artist<AccountId, Balance, Hash, Members> {
+    /// Accounts acting for the artist.
+    pub members: Members,
+    pub thresholds: Thresholds,
+    /// Hash of the artist's off-chain metadata.
+    pub metadata: Hash,
+    /// Account the deposit is held from.
+    pub depositor: AccountId,
+    pub deposit: Balance,
+    /// Index of the next proposal.
+    pub next_proposal: ProposalIndex,
+    /// Number of proposals awaiting approval or execution.
+    pub pending_proposals: u32,
+}
+
+/// What a proposal does once executed.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum Action<AccountId, Balance, Hash> {
+    /// Replace the artist's metadata hash.
+    SetMetadata(Hash),
+    /// Transfer `amount` from the artist's account to `to`.
+    Payout {
+        to: AccountId,
+        amount: Balance,
+    },
+    AddMember(AccountId),
+    RemoveMember(AccountId),
+    SetThresholds(Thresholds),
+    /// Remove the artist and release its deposit. Its account must have been emptied.
+    Dissolve,
+}
+
+impl<AccountId, Balance, Hash> Action<AccountId, Balance, Hash> {
+    /// Approvals the action takes under `thresholds`.
+    pub fn threshold(&self, thresholds: &Thresholds) -> u32 {
+        match self {
+            Self::SetMetadata(_) => thresholds.metadata,
+            Self::Payout { .. } => thresholds.payout,
+            Self::AddMember(_)
+            | Self::RemoveMember(_)
+            | Self::SetThresholds(_)
+            | Self::Dissolve => thresholds.membership,
+        }
+    }
+}
+
+/// An action awaiting approval or execution.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Proposal<AccountId, Action, Approvals> {
+    /// Member who proposed the action and may cancel it.
+    pub proposer: AccountId,
+    pub action: Action,
+    /// Members who approved the action, the proposer included.
+    pub approvals: Approvals,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Derives the account of each artist.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Max number of members of an artist.
+        #[pallet::constant]
+        type MaxMembers: Get<u32>;
+
+        /// Max number of proposals of an artist awaiting approval or execution.
+        #[pallet::constant]
+        type MaxProposals: Get<u32>;
+
+        /// Amount held from the creator of each artist.
+        #[pallet::constant]
+        type ArtistDeposit: Get<BalanceOf<Self>>;
+
+        /// Blocks between two adjustments of the proof-of-work difficulty, which also renew
+        /// the puzzle seed.
+        #[pallet::constant]
+        type PowAdjustmentPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Registrations through proof of work aimed for per adjustment period. Each time as
+        /// many were made within a period, the next ones take one more bit.
+        #[pallet::constant]
+        type TargetPowRegistrations: Get<u32>;
+
+        /// Lowest proof-of-work difficulty, in leading zero bits of the solution's hash.
+        #[pallet::constant]
+        type MinPowDifficulty: Get<u32>;
+
+        /// Highest proof-of-work difficulty, in leading zero bits of the solution's hash.
+        #[pallet::constant]
+        type MaxPowDifficulty: Get<u32>;
+
+        /// Origin of proof-of-work registrations, [`EnsureSolver`] for runtimes with the
+        /// [`CheckArtistWork`] transaction extension.
+        type WorkOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        ArtistDeposit,
+    }
+
+    #[pallet::origin]
+    pub type Origin<T> = RawOrigin<<T as frame_system::Config>::AccountId>;
+
+    #[pallet::storage]
+    pub type Artists<T: Config> = StorageMap<_, Twox64Concat, ArtistId, ArtistOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextArtistId<T: Config> = StorageValue<_, ArtistId, ValueQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultPowDifficulty<T: Config>() -> u32 {
+        T::MinPowDifficulty::get()
+    }
+
+    /// Leading zero bits the hash of a proof-of-work solution must have at the start of the
+    /// adjustment period, see [`Pallet::work_difficulty`].
+    #[pallet::storage]
+    pub type PowDifficulty<T: Config> = StorageValue<_, u32, ValueQuery, DefaultPowDifficulty<T>>;
+
+    /// Seed of the proof-of-work puzzle for the current adjustment period.
+    #[pallet::storage]
+    pub type PowSeed<T: Config> = StorageValue<_, T::Hash, ValueQuery>;
+
+    /// Registrations through proof of work in the current adjustment period.
+    #[pallet::storage]
+    pub type PowRegistrations<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Artist each account created through proof of work, until it is dissolved.
+    #[pallet::storage]
+    pub type WorkArtists<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ArtistId, OptionQuery>;
+
+    /// Artists each account created through proof of work so far. Its next solution is bound
+    /// to this count, so that a registration can't be replayed.
+    #[pallet::storage]
+    pub type WorkCounts<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Proposals of each artist awaiting approval or execution.
+    #[pallet::storage]
+    pub type Proposals<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ArtistId,
+        Twox64Concat,
+        ProposalIndex,
+        ProposalOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        ArtistCreated {
+            artist: ArtistId,
+            account: T::AccountId,
+            depositor: T::AccountId,
+        },
+        Proposed {
+            artist: ArtistId,
+            proposal: ProposalIndex,
+            proposer: T::AccountId,
+            action: ActionOf<T>,
+        },
+        Approved {
+            artist: ArtistId,
+            proposal: ProposalIndex,
+            member: T::AccountId,
+        },
+        ProposalCancelled {
+            artist: ArtistId,
+            proposal: ProposalIndex,
+        },
+        /// The proposal's action was carried out.
+        Executed {
+            artist: ArtistId,
+            proposal: ProposalIndex,
+        },
+        MetadataSet {
+            artist: ArtistId,
+            metadata: T::Hash,
+        },
+        PaidOut {
+            artist: ArtistId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        MemberAdded {
+            artist: ArtistId,
+            member: T::AccountId,
+        },
+        MemberRemoved {
+            artist: ArtistId,
+            member: T::AccountId,
+        },
+        ThresholdsSet {
+            artist: ArtistId,
+            thresholds: Thresholds,
+        },
+        ArtistDissolved {
+            artist: ArtistId,
+        },
+        /// A new adjustment period started with a new puzzle seed and `difficulty`.
+        PowDifficultyAdjusted {
+            difficulty: u32,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        ArtistNotFound,
+        NotMember,
+        DuplicateMember,
+        AlreadyMember,
+        TooManyMembers,
+        /// A threshold is zero or above the number of members.
+        InvalidThresholds,
+        TooManyProposals,
+        ProposalNotFound,
+        NotProposer,
+        AlreadyApproved,
+        /// Fewer current members approved the proposal than its action takes.
+        NotApproved,
+        /// The artist's account still holds funds.
+        FundsRemaining,
+        /// The proof-of-work solution's hash has too few leading zero bits.
+        InsufficientWork,
+        /// The caller already created an artist through proof of work.
+        WorkArtistExists,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            if !(now % T::PowAdjustmentPeriod::get()).is_zero() {
+                return Weight::zero();
+            }
+            Self::adjust_pow_difficulty();
+            T::WeightInfo::adjust_pow_difficulty()
+        }
+
+        fn integrity_test() {
+            assert!(
+                !T::PowAdjustmentPeriod::get().is_zero(),
+                "`PowAdjustmentPeriod` must not be zero"
+            );
+            assert!(
+                T::MinPowDifficulty::get() <= T::MaxPowDifficulty::get()
+                    && T::MaxPowDifficulty::get() <= 256,
+                "proof-of-work difficulties must be ordered and at most 256 bits"
+            );
+            assert!(
+                T::TargetPowRegistrations::get() > 0,
+                "`TargetPowRegistrations` must not be zero"
+            );
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create an artist controlled by `members`, the caller among them, with its
+        /// `thresholds` and `metadata` hash.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_artist(members.len() as u32))]
+        pub fn create_artist(
+            origin: OriginFor<T>,
+            members: MembersOf<T>,
+            thresholds: Thresholds,
+            metadata: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_valid_members(&who, &members, &thresholds)?;
+            let deposit = T::ArtistDeposit::get();
+            T::Currency::hold(&HoldReason::ArtistDeposit.into(), &who, deposit)?;
+            Self::do_create_artist(who, members, thresholds, metadata, deposit);
+            Ok(())
+        }
+
+        /// Propose `action` on behalf of `artist`, approving it. Members only.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::propose())]
+        pub fn propose(
+            origin: OriginFor<T>,
+            artist: ArtistId,
+            action: ActionOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut approvals = MembersOf::<T>::default();
+            approvals
+                .try_push(who.clone())
+                .map_err(|_| Error::<T>::TooManyMembers)?;
+            let proposal = Artists::<T>::try_mutate(artist, |maybe_artist| {
+                let info = maybe_artist.as_mut().ok_or(Error::<T>::ArtistNotFound)?;
+                ensure!(info.members.contains(&who), Error::<T>::NotMember);
+                ensure!(
+                    info.pending_proposals < T::MaxProposals::get(),
+                    Error::<T>::TooManyProposals
+                );
+
+                let proposal = info.next_proposal;
+                info.next_proposal.saturating_inc();
+                info.pending_proposals.saturating_inc();
+                Ok::<_, DispatchError>(proposal)
+            })?;
+
+            Proposals::<T>::insert(
+                artist,
+                proposal,
+                Proposal {
+                    proposer: who.clone(),
+                    action: action.clone(),
+                    approvals,
+                },
+            );
+
+            Self::deposit_event(Event::Proposed {
+                artist,
+                proposal,
+                proposer: who,
+                action,
+            });
+            Ok(())
+        }
+
+        /// Approve `proposal` of `artist`. Members only.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::approve())]
+        pub fn approve(
+            origin: OriginFor<T>,
+            artist: ArtistId,
+            proposal: ProposalIndex,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let info = Artists::<T>::get(artist).ok_or(Error::<T>::ArtistNotFound)?;
+            ensure!(info.members.contains(&who), Error::<T>::NotMember);
+            Proposals::<T>::try_mutate(artist, proposal, |maybe_proposal| {
+                let pending = maybe_proposal
+                    .as_mut()
+                    .ok_or(Error::<T>::ProposalNotFound)?;
+                ensure!(
+                    !pending.approvals.contains(&who),
+                    Error::<T>::AlreadyApproved
+                );
+                // Approvals of former members no longer count: make room for current ones.
+                pending.approvals.retain(|a| info.members.contains(a));
+                pending
+                    .approvals
+                    .try_push(who.clone())
+                    .map_err(|_| Error::<T>::TooManyMembers)?;
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::Approved {
+                artist,
+                proposal,
+                member: who,
+            });
+            Ok(())
+        }
+
+        /// Withdraw `proposal` of `artist`. Proposer only.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::cancel_proposal())]
+        pub fn cancel_proposal(
+            origin: OriginFor<T>,
+            artist: ArtistId,
+            proposal: ProposalIndex,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let pending =
+                Proposals::<T>::get(artist, proposal).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(pending.proposer == who, Error::<T>::NotProposer);
+            Proposals::<T>::remove(artist, proposal);
+            Artists::<T>::mutate(artist, |maybe_artist| {
+                if let Some(info) = maybe_artist {
+                    info.pending_proposals.saturating_dec();
+                }
+            });
+
+            Self::deposit_event(Event::ProposalCancelled { artist, proposal });
+            Ok(())
+        }
+
+        /// Carry out `proposal` of `artist` once enough current members approved it.
+        ///
+        /// Fails, leaving the proposal in place, if the action can't be carried out yet, e.g.
+        /// a payout larger than the artist's funds.
+        #[pallet::call_index(4)]
+        #[pallet::weight(
+            T::WeightInfo::execute_update()
+                .max(T::WeightInfo::execute_payout())
+                .max(T::WeightInfo::execute_dissolve(T::MaxProposals::get()))
+        )]
+        pub fn execute(
+            origin: OriginFor<T>,
+            artist: ArtistId,
+            proposal: ProposalIndex,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let mut info = Artists::<T>::get(artist).ok_or(Error::<T>::ArtistNotFound)?;
+            let pending =
+                Proposals::<T>::get(artist, proposal).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(
+                Self::approvals(&info, &pending) >= pending.action.threshold(&info.thresholds),
+                Error::<T>::NotApproved
+            );
+
+            Proposals::<T>::remove(artist, proposal);
+            info.pending_proposals.saturating_dec();
+
+            let weight = match pending.action {
+                Action::SetMetadata(metadata) => {
+                    info.metadata = metadata;
+                    Self::deposit_event(Event::MetadataSet { artist, metadata });
+                    T::WeightInfo::execute_update()
+                }
+                Action::Payout { to, amount } => {
+                    T::Currency::transfer(
+                        &Self::artist_account(artist),
+                        &to,
+                        amount,
+                        Preservation::Expendable,
+                    )?;
+                    Self::deposit_event(Event::PaidOut { artist, to, amount });
+                    T::WeightInfo::execute_payout()
+                }
+                Action::AddMember(member) => {
+                    ensure!(!info.members.contains(&member), Error::<T>::AlreadyMember);
+                    info.members
+                        .try_push(member.clone())
+                        .map_err(|_| Error::<T>::TooManyMembers)?;
+                    Self::deposit_event(Event::MemberAdded { artist, member });
+                    T::WeightInfo::execute_update()
+                }
+                Action::RemoveMember(member) => {
+                    let len = info.members.len();
+                    info.members.retain(|m| m != &member);
+                    ensure!(info.members.len() < len, Error::<T>::NotMember);
+                    ensure!(
+                        info.thresholds.fit(info.members.len() as u32),
+                        Error::<T>::InvalidThresholds
+                    );
+                    Self::deposit_event(Event::MemberRemoved { artist, member });
+                    T::WeightInfo::execute_update()
+                }
+                Action::SetThresholds(thresholds) => {
+                    ensure!(
+                        thresholds.fit(info.members.len() as u32),
+                        Error::<T>::InvalidThresholds
+                    );
+                    info.thresholds = thresholds;
+                    Self::deposit_event(Event::ThresholdsSet { artist, thresholds });
+                    T::WeightInfo::execute_update()
+                }
+                Action::Dissolve => {
+                    let account = Self::artist_account(artist);
+                    ensure!(
+                        T::Currency::total_balance(&account).is_zero(),
+                        Error::<T>::FundsRemaining
+                    );
+                    T::Currency::release(
+                        &HoldReason::ArtistDeposit.into(),
+                        &info.depositor,
+                        info.deposit,
+                        Precision::BestEffort,
+                    )?;
+                    let removed = Proposals::<T>::clear_prefix(artist, u32::MAX, None).unique;
+                    Artists::<T>::remove(artist);
+                    if WorkArtists::<T>::get(&info.depositor) == Some(artist) {
+                        WorkArtists::<T>::remove(&info.depositor);
+                    }
+
+                    Self::deposit_event(Event::Executed { artist, proposal });
+                    Self::deposit_event(Event::ArtistDissolved { artist });
+                    return Ok(Some(T::WeightInfo::execute_dissolve(removed)).into());
+                }
+            };
+            Artists::<T>::insert(artist, info);
+
+            Self::deposit_event(Event::Executed { artist, proposal });
+            Ok(Some(weight).into())
+        }
+
+        /// Create an artist like [`Pallet::create_artist`], without a deposit, by solving the
+        /// proof-of-work puzzle: the hash returned by [`Pallet::work_hash`] for `nonce` must
+        /// have at least [`Pallet::work_difficulty`] leading zero bits.
+        ///
+        /// Each account can have a single such artist at a time. Solutions are only valid until
+        /// the end of the period they were found in, and as long as the difficulty didn't rise.
+        ///
+        /// No fee is paid when the artist is created. Under [`CheckArtistWork`], the caller
+        /// doesn't pay one upfront either, nor need an account.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::create_artist_with_work(members.len() as u32))]
+        pub fn create_artist_with_work(
+            origin: OriginFor<T>,
+            members: MembersOf<T>,
+            thresholds: Thresholds,
+            metadata: T::Hash,
+            nonce: u64,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::WorkOrigin::ensure_origin(origin)?;
+
+            Self::check_work(&who, &members, &thresholds, &metadata, nonce)?;
+
+            let artist =
+                Self::do_create_artist(who.clone(), members, thresholds, metadata, Zero::zero());
+            WorkCounts::<T>::mutate(&who, |n| n.saturating_inc());
+            WorkArtists::<T>::insert(who, artist);
+            PowRegistrations::<T>::mutate(|n| n.saturating_inc());
+            Ok(Pays::No.into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Hash of the proof-of-work solution `nonce` of `who` for an artist with `members`,
+        /// `thresholds` and `metadata`, under the current [`PowSeed`] and `who`'s
+        /// [`WorkCounts`].
+        pub fn work_hash(
+            who: &T::AccountId,
+            members: &MembersOf<T>,
+            thresholds: &Thresholds,
+            metadata: &T::Hash,
+            nonce: u64,
+        ) -> T::Hash {
+            T::Hashing::hash_of(&(
+                POW_CONTEXT,
+                PowSeed::<T>::get(),
+                who,
+                WorkCounts::<T>::get(who),
+                members,
+                thresholds,
+                metadata,
+                nonce,
+            ))
+        }
+
+        /// Leading zero bits a proof-of-work solution needs right now: [`PowDifficulty`], plus
+        /// one for every `Config::TargetPowRegistrations` registrations already made in the
+        /// period.
+        pub fn work_difficulty() -> u32 {
+            let surge = PowRegistrations::<T>::get() / T::TargetPowRegistrations::get().max(1);
+            PowDifficulty::<T>::get()
+                .saturating_add(surge)
+                .min(T::MaxPowDifficulty::get())
+        }
+
+        /// Whether `who` can create an artist with `members`, `thresholds` and `metadata`
+        /// through the proof-of-work solution `nonce`.
+        pub fn check_work(
+            who: &T::AccountId,
+            members: &MembersOf<T>,
+            thresholds: &Thresholds,
+            metadata: &T::Hash,
+            nonce: u64,
+        ) -> DispatchResult {
+            Self::ensure_valid_members(who, members, thresholds)?;
+            ensure!(
+                !WorkArtists::<T>::contains_key(who),
+                Error::<T>::WorkArtistExists
+            );
+            let hash = Self::work_hash(who, members, thresholds, metadata, nonce);
+            ensure!(
+                leading_zero_bits(hash.as_ref()) >= Self::work_difficulty(),
+                Error::<T>::InsufficientWork
+            );
+            Ok(())
+        }
+
+        /// Start a new adjustment period: renew the puzzle seed and move the difficulty one
+        /// bit up if the period that ended saw more registrations than targeted, or one bit
+        /// down if it saw fewer than half of them.
+        pub(crate) fn adjust_pow_difficulty() {
+            let registrations = PowRegistrations::<T>::take();
+            let target = T::TargetPowRegistrations::get();
+            let mut difficulty = PowDifficulty::<T>::get();
+            if registrations > target {
+                difficulty = difficulty.saturating_add(1);
+            } else if registrations < target / 2 {
+                difficulty = difficulty.saturating_sub(1);
+            }
+            let difficulty =
+                difficulty.clamp(T::MinPowDifficulty::get(), T::MaxPowDifficulty::get());
+
+            PowDifficulty::<T>::put(difficulty);
+            PowSeed::<T>::put(frame_system::Pallet::<T>::parent_hash());
+            Self::deposit_event(Event::PowDifficultyAdjusted { difficulty });
+        }
+
+        fn ensure_valid_members(
+            who: &T::AccountId,
+            members: &MembersOf<T>,
+            thresholds: &Thresholds,
+        ) -> DispatchResult {
+            ensure!(members.contains(who), Error::<T>::NotMember);
+            for (i, member) in members.iter().enumerate() {
+                ensure!(!members[..i].contains(member), Error::<T>::DuplicateMember);
+            }
+            ensure!(
+                thresholds.fit(members.len() as u32),
+                Error::<T>::InvalidThresholds
+            );
+            Ok(())
+        }
+
+        fn do_create_artist(
+            who: T::AccountId,
+            members: MembersOf<T>,
+            thresholds: Thresholds,
+            metadata: T::Hash,
+            deposit: BalanceOf<T>,
+        ) -> ArtistId {
+            let artist = NextArtistId::<T>::get();
+            Artists::<T>::insert(
+                artist,
+                Artist {
+                    members,
+                    thresholds,
+                    metadata,
+                    depositor: who.clone(),
+                    deposit,
+                    next_proposal: 0,
+                    pending_proposals: 0,
+                },
+            );
+            NextArtistId::<T>::put(artist.saturating_add(1));
+
+            Self::deposit_event(Event::ArtistCreated {
+                artist,
+                account: Self::artist_account(artist),
+                depositor: who,
+            });
+            artist
+        }
+
+        /// Account of `artist`, holding the funds its members pay out.
+        pub fn artist_account(artist: ArtistId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(artist)
+        }
+
+        /// Whether `who` is a member of `artist`.
+        pub fn is_member(artist: ArtistId, who: &T::AccountId) -> bool {
+            Artists::<T>::get(artist).is_some_and(|info| info.members.contains(who))
+        }
+
+        /// Approvals of `proposal` that count towards its threshold: those of current members,
+        /// except the member it removes.
+        pub fn approvals(info: &ArtistOf<T>, proposal: &ProposalOf<T>) -> u32 {
+            let removed = match &proposal.action {
+                Action::RemoveMember(member) => Some(member),
+                _ => None,
+            };
+            proposal
+                .approvals
+                .iter()
+                .filter(|a| info.members.contains(a) && Some(*a) != removed)
+                .count() as u32
+        }
+    }
+}
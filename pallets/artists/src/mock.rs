@@ -0,0 +1,127 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_artists;
+use frame_support::{PalletId, derive_impl, parameter_types, sp_runtime::BuildStorage};
+use sp_runtime::{
+    testing::H256,
+    traits::{BlakeTwo256, Hash},
+};
+
+pub type Balance = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type Artists = pallet_artists;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+}
+
+pub const ARTIST_DEPOSIT: Balance = 50;
+pub const POW_PERIOD: u64 = 10;
+pub const MIN_POW_DIFFICULTY: u32 = 4;
+pub const TARGET_POW_REGISTRATIONS: u32 = 2;
+
+parameter_types! {
+    pub const ArtistsPalletId: PalletId = PalletId(*b"py/artst");
+    pub const MaxMembers: u32 = 4;
+    pub const MaxProposals: u32 = 3;
+    pub const ArtistDeposit: Balance = ARTIST_DEPOSIT;
+    pub const PowAdjustmentPeriod: u64 = POW_PERIOD;
+    pub const TargetPowRegistrations: u32 = TARGET_POW_REGISTRATIONS;
+    pub const MinPowDifficulty: u32 = MIN_POW_DIFFICULTY;
+    pub const MaxPowDifficulty: u32 = 6;
+}
+
+impl pallet_artists::Config for Test {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type PalletId = ArtistsPalletId;
+    type MaxMembers = MaxMembers;
+    type MaxProposals = MaxProposals;
+    type ArtistDeposit = ArtistDeposit;
+    type PowAdjustmentPeriod = PowAdjustmentPeriod;
+    type TargetPowRegistrations = TargetPowRegistrations;
+    type MinPowDifficulty = MinPowDifficulty;
+    type MaxPowDifficulty = MaxPowDifficulty;
+    type WorkOrigin = pallet_artists::EnsureSolver<u64>;
+    type WeightInfo = ();
+}
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+pub const DAVE: u64 = 4;
+pub const EVE: u64 = 5;
+
+/// Hash of the metadata document `doc`.
+pub fn metadata(doc: &str) -> H256 {
+    BlakeTwo256::hash(doc.as_bytes())
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ALICE, 1_000),
+            (BOB, 1_000),
+            (CHARLIE, 1_000),
+            (DAVE, 1_000),
+            (EVE, 1_000),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
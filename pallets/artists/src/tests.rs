@@ -0,0 +1,739 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Action, ActionOf, Artists as ArtistsStorage, CheckArtistWork, Error, Event, HoldReason,
+    MembersOf, Pallet, PowDifficulty, PowRegistrations, PowSeed, Proposals, Thresholds,
+    WorkArtists, WorkCounts, leading_zero_bits, mock::*,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{GetDispatchInfo, Pays, PostDispatchInfo},
+    traits::{
+        Hooks,
+        fungible::{Inspect, InspectHold, Mutate},
+    },
+};
+use sp_runtime::{
+    traits::DispatchTransaction,
+    transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+};
+
+/// Metadata edits take one approval, payouts two, membership changes three.
+const THRESHOLDS: Thresholds = Thresholds {
+    metadata: 1,
+    payout: 2,
+    membership: 3,
+};
+
+/// Creates artist 0, controlled by ALICE, BOB, CHARLIE and DAVE.
+fn create_band() {
+    assert_ok!(Artists::create_artist(
+        RuntimeOrigin::signed(ALICE),
+        vec![ALICE, BOB, CHARLIE, DAVE].try_into().unwrap(),
+        THRESHOLDS,
+        metadata("band")
+    ));
+}
+
+fn propose(who: u64, action: ActionOf<Test>) -> sp_runtime::DispatchResult {
+    Artists::propose(RuntimeOrigin::signed(who), 0, action)
+}
+
+fn approve(who: u64, proposal: u32) -> sp_runtime::DispatchResult {
+    Artists::approve(RuntimeOrigin::signed(who), 0, proposal)
+}
+
+fn execute(proposal: u32) -> sp_runtime::DispatchResult {
+    Artists::execute(RuntimeOrigin::signed(EVE), 0, proposal)
+        .map(|_| ())
+        .map_err(|e| e.error)
+}
+
+/// First nonce whose work hash for `who`'s band has enough leading zero bits, or too few
+/// if `enough` is false.
+fn nonce(who: u64, band: &MembersOf<Test>, enough: bool) -> u64 {
+    let difficulty = Pallet::<Test>::work_difficulty();
+    (0..)
+        .find(|nonce| {
+            let hash =
+                Pallet::<Test>::work_hash(&who, band, &THRESHOLDS, &metadata("band"), *nonce);
+            (leading_zero_bits(hash.as_ref()) >= difficulty) == enough
+        })
+        .unwrap()
+}
+
+fn create_with_work(who: u64, nonce: u64) -> sp_runtime::DispatchResult {
+    Artists::create_artist_with_work(
+        RuntimeOrigin::signed(who),
+        band(who),
+        THRESHOLDS,
+        metadata("band"),
+        nonce,
+    )
+    .map(|_| ())
+    .map_err(|e| e.error)
+}
+
+/// `who` with BOB, CHARLIE and DAVE.
+fn band(who: u64) -> MembersOf<Test> {
+    vec![who, BOB, CHARLIE, DAVE].try_into().unwrap()
+}
+
+/// Runs the hooks of the first block of the next adjustment period.
+fn next_period() {
+    let now = System::block_number();
+    let next = (now / POW_PERIOD + 1) * POW_PERIOD;
+    System::set_block_number(next);
+    Artists::on_initialize(next);
+}
+
+fn members() -> Vec<u64> {
+    ArtistsStorage::<Test>::get(0).unwrap().members.into_inner()
+}
+
+#[test]
+fn create_artist_validates_members_and_thresholds() {
+    new_test_ext().execute_with(|| {
+        let create = |members: Vec<u64>, thresholds| {
+            Artists::create_artist(
+                RuntimeOrigin::signed(ALICE),
+                members.try_into().unwrap(),
+                thresholds,
+                metadata("band"),
+            )
+        };
+
+        assert_noop!(
+            create(vec![BOB, CHARLIE], THRESHOLDS),
+            Error::<Test>::NotMember
+        );
+        assert_noop!(
+            create(vec![ALICE, BOB, ALICE], THRESHOLDS),
+            Error::<Test>::DuplicateMember
+        );
+        assert_noop!(
+            create(vec![ALICE, BOB], THRESHOLDS),
+            Error::<Test>::InvalidThresholds
+        );
+        assert_noop!(
+            create(
+                vec![ALICE, BOB, CHARLIE],
+                Thresholds {
+                    metadata: 0,
+                    ..THRESHOLDS
+                }
+            ),
+            Error::<Test>::InvalidThresholds
+        );
+
+        assert_ok!(create(vec![ALICE, BOB, CHARLIE], THRESHOLDS));
+    });
+}
+
+#[test]
+fn create_artist_holds_deposit() {
+    new_test_ext().execute_with(|| {
+        create_band();
+
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ArtistDeposit.into(), &ALICE),
+            ARTIST_DEPOSIT
+        );
+        let artist = ArtistsStorage::<Test>::get(0).unwrap();
+        assert_eq!(artist.depositor, ALICE);
+        assert_eq!(artist.metadata, metadata("band"));
+        System::assert_last_event(
+            Event::ArtistCreated {
+                artist: 0,
+                account: Pallet::<Test>::artist_account(0),
+                depositor: ALICE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn only_members_propose_and_approve() {
+    new_test_ext().execute_with(|| {
+        create_band();
+
+        assert_noop!(
+            propose(EVE, Action::SetMetadata(metadata("spam"))),
+            Error::<Test>::NotMember
+        );
+        assert_ok!(propose(ALICE, Action::AddMember(EVE)));
+        assert_noop!(approve(EVE, 0), Error::<Test>::NotMember);
+        assert_noop!(approve(ALICE, 0), Error::<Test>::AlreadyApproved);
+        assert_noop!(approve(BOB, 1), Error::<Test>::ProposalNotFound);
+    });
+}
+
+#[test]
+fn pending_proposals_are_capped() {
+    new_test_ext().execute_with(|| {
+        create_band();
+
+        for _ in 0..3 {
+            assert_ok!(propose(ALICE, Action::SetMetadata(metadata("v2"))));
+        }
+        assert_noop!(
+            propose(BOB, Action::SetMetadata(metadata("v2"))),
+            Error::<Test>::TooManyProposals
+        );
+
+        // Executing or cancelling a proposal makes room for a new one.
+        assert_ok!(execute(0));
+        assert_ok!(propose(BOB, Action::SetMetadata(metadata("v3"))));
+        assert_ok!(Artists::cancel_proposal(RuntimeOrigin::signed(ALICE), 0, 1));
+        assert_ok!(propose(BOB, Action::SetMetadata(metadata("v4"))));
+    });
+}
+
+#[test]
+fn each_action_takes_its_own_threshold() {
+    new_test_ext().execute_with(|| {
+        create_band();
+        let account = Pallet::<Test>::artist_account(0);
+        Balances::set_balance(&account, 500);
+
+        // A single member edits the metadata.
+        assert_ok!(propose(BOB, Action::SetMetadata(metadata("v2"))));
+        assert_ok!(execute(0));
+        assert_eq!(
+            ArtistsStorage::<Test>::get(0).unwrap().metadata,
+            metadata("v2")
+        );
+        System::assert_has_event(
+            Event::MetadataSet {
+                artist: 0,
+                metadata: metadata("v2"),
+            }
+            .into(),
+        );
+
+        // A payout takes a second member.
+        assert_ok!(propose(
+            BOB,
+            Action::Payout {
+                to: EVE,
+                amount: 200
+            }
+        ));
+        assert_noop!(execute(1), Error::<Test>::NotApproved);
+        assert_ok!(approve(CHARLIE, 1));
+        assert_ok!(execute(1));
+        assert_eq!(Balances::balance(&account), 300);
+        assert_eq!(Balances::balance(&EVE), 1_200);
+        assert!(!Proposals::<Test>::contains_key(0, 1));
+        System::assert_last_event(
+            Event::Executed {
+                artist: 0,
+                proposal: 1,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn failed_execution_keeps_the_proposal() {
+    new_test_ext().execute_with(|| {
+        create_band();
+        let account = Pallet::<Test>::artist_account(0);
+
+        assert_ok!(propose(
+            BOB,
+            Action::Payout {
+                to: EVE,
+                amount: 200
+            }
+        ));
+        assert_ok!(approve(CHARLIE, 0));
+        assert!(execute(0).is_err());
+        assert!(Proposals::<Test>::contains_key(0, 0));
+
+        Balances::set_balance(&account, 200);
+        assert_ok!(execute(0));
+        assert_eq!(Balances::balance(&EVE), 1_200);
+    });
+}
+
+#[test]
+fn removal_takes_the_consent_of_remaining_members() {
+    new_test_ext().execute_with(|| {
+        create_band();
+
+        // DAVE's approval of their own removal does not count.
+        assert_ok!(propose(DAVE, Action::RemoveMember(DAVE)));
+        assert_ok!(approve(ALICE, 0));
+        assert_ok!(approve(BOB, 0));
+        assert_noop!(execute(0), Error::<Test>::NotApproved);
+
+        assert_ok!(approve(CHARLIE, 0));
+        assert_ok!(execute(0));
+        assert_eq!(members(), vec![ALICE, BOB, CHARLIE]);
+        System::assert_has_event(
+            Event::MemberRemoved {
+                artist: 0,
+                member: DAVE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn approvals_of_former_members_no_longer_count() {
+    new_test_ext().execute_with(|| {
+        create_band();
+
+        assert_ok!(propose(
+            DAVE,
+            Action::Payout {
+                to: DAVE,
+                amount: 10
+            }
+        ));
+        assert_ok!(propose(ALICE, Action::RemoveMember(DAVE)));
+        assert_ok!(approve(BOB, 1));
+        assert_ok!(approve(CHARLIE, 1));
+        assert_ok!(execute(1));
+
+        assert_ok!(approve(ALICE, 0));
+        Balances::set_balance(&Pallet::<Test>::artist_account(0), 100);
+        assert_noop!(execute(0), Error::<Test>::NotApproved);
+        assert_noop!(approve(DAVE, 0), Error::<Test>::NotMember);
+    });
+}
+
+#[test]
+fn membership_keeps_thresholds_reachable() {
+    new_test_ext().execute_with(|| {
+        create_band();
+        let approve_all = |proposal| {
+            for who in [BOB, CHARLIE] {
+                assert_ok!(approve(who, proposal));
+            }
+        };
+
+        assert_ok!(propose(ALICE, Action::RemoveMember(DAVE)));
+        approve_all(0);
+        assert_ok!(execute(0));
+
+        // Three members left, all needed for membership changes: no one can be removed.
+        assert_ok!(propose(ALICE, Action::RemoveMember(CHARLIE)));
+        assert_ok!(approve(BOB, 1));
+        assert_ok!(propose(
+            ALICE,
+            Action::SetThresholds(Thresholds {
+                membership: 4,
+                ..THRESHOLDS
+            })
+        ));
+        approve_all(2);
+        assert_noop!(execute(2), Error::<Test>::InvalidThresholds);
+
+        assert_ok!(propose(
+            ALICE,
+            Action::SetThresholds(Thresholds {
+                membership: 2,
+                ..THRESHOLDS
+            })
+        ));
+        approve_all(3);
+        assert_ok!(execute(3));
+        assert_ok!(execute(1));
+        assert_eq!(members(), vec![ALICE, BOB]);
+
+        assert_ok!(propose(ALICE, Action::AddMember(EVE)));
+        assert_ok!(approve(BOB, 4));
+        assert_ok!(execute(4));
+        assert_eq!(members(), vec![ALICE, BOB, EVE]);
+    });
+}
+
+#[test]
+fn add_member_rejects_members_and_full_bands() {
+    new_test_ext().execute_with(|| {
+        create_band();
+
+        assert_ok!(propose(ALICE, Action::AddMember(BOB)));
+        assert_ok!(propose(ALICE, Action::AddMember(EVE)));
+        for who in [BOB, CHARLIE] {
+            assert_ok!(approve(who, 0));
+            assert_ok!(approve(who, 1));
+        }
+        assert_noop!(execute(0), Error::<Test>::AlreadyMember);
+        assert_noop!(execute(1), Error::<Test>::TooManyMembers);
+    });
+}
+
+#[test]
+fn cancel_proposal_is_proposer_only() {
+    new_test_ext().execute_with(|| {
+        create_band();
+
+        assert_ok!(propose(ALICE, Action::AddMember(EVE)));
+        assert_noop!(
+            Artists::cancel_proposal(RuntimeOrigin::signed(BOB), 0, 0),
+            Error::<Test>::NotProposer
+        );
+        assert_ok!(Artists::cancel_proposal(RuntimeOrigin::signed(ALICE), 0, 0));
+        assert!(!Proposals::<Test>::contains_key(0, 0));
+        assert_eq!(ArtistsStorage::<Test>::get(0).unwrap().pending_proposals, 0);
+    });
+}
+
+#[test]
+fn dissolve_requires_an_empty_account() {
+    new_test_ext().execute_with(|| {
+        create_band();
+        let account = Pallet::<Test>::artist_account(0);
+        Balances::set_balance(&account, 100);
+
+        assert_ok!(propose(BOB, Action::AddMember(EVE)));
+        assert_ok!(propose(ALICE, Action::Dissolve));
+        for who in [BOB, CHARLIE] {
+            assert_ok!(approve(who, 1));
+        }
+        assert_noop!(execute(1), Error::<Test>::FundsRemaining);
+
+        assert_ok!(propose(
+            ALICE,
+            Action::Payout {
+                to: ALICE,
+                amount: 100
+            }
+        ));
+        assert_ok!(approve(BOB, 2));
+        assert_ok!(execute(2));
+        assert_ok!(execute(1));
+
+        assert!(!ArtistsStorage::<Test>::contains_key(0));
+        assert!(!Proposals::<Test>::contains_key(0, 0));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ArtistDeposit.into(), &ALICE),
+            0
+        );
+        System::assert_last_event(Event::ArtistDissolved { artist: 0 }.into());
+    });
+}
+
+#[test]
+fn leading_zero_bits_spans_bytes() {
+    assert_eq!(leading_zero_bits(&[0xff, 0]), 0);
+    assert_eq!(leading_zero_bits(&[0, 0x10, 0]), 11);
+    assert_eq!(leading_zero_bits(&[0, 0]), 16);
+}
+
+#[test]
+fn create_artist_with_work_holds_no_deposit() {
+    new_test_ext().execute_with(|| {
+        let weak = nonce(ALICE, &band(ALICE), false);
+        assert_noop!(
+            create_with_work(ALICE, weak),
+            Error::<Test>::InsufficientWork
+        );
+
+        assert_ok!(create_with_work(ALICE, nonce(ALICE, &band(ALICE), true)));
+
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ArtistDeposit.into(), &ALICE),
+            0
+        );
+        let artist = ArtistsStorage::<Test>::get(0).unwrap();
+        assert_eq!(artist.depositor, ALICE);
+        assert_eq!(artist.deposit, 0);
+        assert_eq!(WorkArtists::<Test>::get(ALICE), Some(0));
+        assert_eq!(PowRegistrations::<Test>::get(), 1);
+
+        // One artist per account: the same solution can't be used twice.
+        assert_noop!(
+            create_with_work(ALICE, nonce(ALICE, &band(ALICE), true)),
+            Error::<Test>::WorkArtistExists
+        );
+        // The deposit path stays open.
+        create_band();
+    });
+}
+
+#[test]
+fn work_is_bound_to_the_caller_and_the_period() {
+    new_test_ext().execute_with(|| {
+        let band = band(EVE);
+        let solves = |who, nonce| {
+            let hash =
+                Pallet::<Test>::work_hash(&who, &band, &THRESHOLDS, &metadata("band"), nonce);
+            leading_zero_bits(hash.as_ref()) >= Pallet::<Test>::work_difficulty()
+        };
+        let submit = |who, nonce| {
+            Artists::create_artist_with_work(
+                RuntimeOrigin::signed(who),
+                band.clone(),
+                THRESHOLDS,
+                metadata("band"),
+                nonce,
+            )
+            .map(|_| ())
+            .map_err(|e| e.error)
+        };
+
+        // Solved by EVE, not by BOB submitting the same band.
+        let stolen = (0..).find(|n| solves(EVE, *n) && !solves(BOB, *n)).unwrap();
+        assert_noop!(submit(BOB, stolen), Error::<Test>::InsufficientWork);
+
+        // Solved in the previous period only.
+        let seed = PowSeed::<Test>::get();
+        let solved_before = |nonce| {
+            let current = PowSeed::<Test>::get();
+            PowSeed::<Test>::put(seed);
+            let solved = solves(EVE, nonce);
+            PowSeed::<Test>::put(current);
+            solved
+        };
+        System::set_parent_hash(metadata("parent"));
+        next_period();
+        assert_ne!(PowSeed::<Test>::get(), seed);
+        let stale = (0..)
+            .find(|n| solved_before(*n) && !solves(EVE, *n))
+            .unwrap();
+        assert_noop!(submit(EVE, stale), Error::<Test>::InsufficientWork);
+        assert_ok!(submit(EVE, nonce(EVE, &band, true)));
+    });
+}
+
+#[test]
+fn valid_work_pays_no_fee() {
+    new_test_ext().execute_with(|| {
+        let create = |nonce| {
+            Artists::create_artist_with_work(
+                RuntimeOrigin::signed(ALICE),
+                band(ALICE),
+                THRESHOLDS,
+                metadata("band"),
+                nonce,
+            )
+        };
+
+        let weak = create(nonce(ALICE, &band(ALICE), false)).unwrap_err();
+        assert_eq!(weak.post_info.pays_fee, Pays::Yes);
+
+        let solved = create(nonce(ALICE, &band(ALICE), true)).unwrap();
+        assert_eq!(solved.pays_fee, Pays::No);
+    });
+}
+
+#[test]
+fn difficulty_rises_within_the_period() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Pallet::<Test>::work_difficulty(), MIN_POW_DIFFICULTY);
+
+        // A target's worth of registrations: one more bit for the next ones.
+        PowRegistrations::<Test>::put(TARGET_POW_REGISTRATIONS);
+        assert_eq!(Pallet::<Test>::work_difficulty(), MIN_POW_DIFFICULTY + 1);
+        let easy = (0..)
+            .find(|n| {
+                let hash = Pallet::<Test>::work_hash(
+                    &ALICE,
+                    &band(ALICE),
+                    &THRESHOLDS,
+                    &metadata("band"),
+                    *n,
+                );
+                leading_zero_bits(hash.as_ref()) == MIN_POW_DIFFICULTY
+            })
+            .unwrap();
+        assert_noop!(
+            create_with_work(ALICE, easy),
+            Error::<Test>::InsufficientWork
+        );
+        assert_ok!(create_with_work(ALICE, nonce(ALICE, &band(ALICE), true)));
+
+        // Never above the maximum.
+        PowRegistrations::<Test>::put(10 * TARGET_POW_REGISTRATIONS);
+        assert_eq!(Pallet::<Test>::work_difficulty(), 6);
+
+        // The surge ends with the period.
+        next_period();
+        assert_eq!(Pallet::<Test>::work_difficulty(), MIN_POW_DIFFICULTY + 1);
+    });
+}
+
+#[test]
+fn work_is_bound_to_previous_registrations() {
+    new_test_ext().execute_with(|| {
+        let solves = |nonce| {
+            let hash = Pallet::<Test>::work_hash(
+                &ALICE,
+                &band(ALICE),
+                &THRESHOLDS,
+                &metadata("band"),
+                nonce,
+            );
+            leading_zero_bits(hash.as_ref()) >= Pallet::<Test>::work_difficulty()
+        };
+        // Solves the first registration only.
+        let solution = (0..)
+            .find(|n| {
+                let first = solves(*n);
+                WorkCounts::<Test>::insert(ALICE, 1);
+                let second = solves(*n);
+                WorkCounts::<Test>::remove(ALICE);
+                first && !second
+            })
+            .unwrap();
+        assert_ok!(create_with_work(ALICE, solution));
+        assert_eq!(WorkCounts::<Test>::get(ALICE), 1);
+
+        assert_ok!(propose(ALICE, Action::Dissolve));
+        for who in [BOB, CHARLIE] {
+            assert_ok!(approve(who, 0));
+        }
+        assert_ok!(execute(0));
+
+        // The dissolved artist's registration can't be replayed.
+        assert_noop!(
+            create_with_work(ALICE, solution),
+            Error::<Test>::InsufficientWork
+        );
+        assert_ok!(create_with_work(ALICE, nonce(ALICE, &band(ALICE), true)));
+        assert_eq!(WorkCounts::<Test>::get(ALICE), 2);
+    });
+}
+
+/// Account without any funds, unknown to the chain.
+const NOBODY: u64 = 42;
+
+/// `create_artist_with_work` of `who` as a signed transaction with [`CheckArtistWork`] ahead of
+/// `CheckNonce`, as in the runtimes.
+fn submit_with_work(
+    who: u64,
+    nonce: u64,
+) -> Result<sp_runtime::DispatchResultWithInfo<PostDispatchInfo>, TransactionValidityError> {
+    let call = RuntimeCall::Artists(crate::Call::create_artist_with_work {
+        members: band(who),
+        thresholds: THRESHOLDS,
+        metadata: metadata("band"),
+        nonce,
+    });
+    let info = call.get_dispatch_info();
+    (
+        CheckArtistWork::<Test>::new(),
+        frame_system::CheckNonce::<Test>::from(0),
+    )
+        .dispatch_transaction(RuntimeOrigin::signed(who), call, &info, 0, 0)
+}
+
+#[test]
+fn account_without_funds_registers_with_work() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Balances::total_balance(&NOBODY), 0);
+        assert!(!System::account_exists(&NOBODY));
+
+        // Anything else is refused: the account can't pay for its nonce.
+        let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        let info = remark.get_dispatch_info();
+        assert_eq!(
+            (
+                CheckArtistWork::<Test>::new(),
+                frame_system::CheckNonce::<Test>::from(0),
+            )
+                .validate_only(
+                    RuntimeOrigin::signed(NOBODY),
+                    &remark,
+                    &info,
+                    0,
+                    TransactionSource::External,
+                    0,
+                )
+                .map(|_| ())
+                .unwrap_err(),
+            InvalidTransaction::Payment.into()
+        );
+
+        // An invalid solution is refused by the pool rather than dispatched for free.
+        assert_eq!(
+            submit_with_work(NOBODY, nonce(NOBODY, &band(NOBODY), false)).unwrap_err(),
+            InvalidTransaction::BadProof.into()
+        );
+
+        assert_ok!(submit_with_work(NOBODY, nonce(NOBODY, &band(NOBODY), true)).unwrap());
+        assert_eq!(WorkArtists::<Test>::get(NOBODY), Some(0));
+        assert_eq!(ArtistsStorage::<Test>::get(0).unwrap().deposit, 0);
+        assert_eq!(Balances::total_balance(&NOBODY), 0);
+        assert_eq!(System::account_nonce(NOBODY), 0);
+
+        // Once registered, the same transaction is refused.
+        assert_eq!(
+            submit_with_work(NOBODY, nonce(NOBODY, &band(NOBODY), true)).unwrap_err(),
+            InvalidTransaction::BadProof.into()
+        );
+    });
+}
+
+#[test]
+fn difficulty_follows_the_registration_rate() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(PowDifficulty::<Test>::get(), MIN_POW_DIFFICULTY);
+
+        // Three registrations against a target of two: one bit harder.
+        for who in [ALICE, EVE, 6] {
+            assert_ok!(create_with_work(who, nonce(who, &band(who), true)));
+        }
+        next_period();
+        assert_eq!(PowDifficulty::<Test>::get(), MIN_POW_DIFFICULTY + 1);
+        assert_eq!(PowRegistrations::<Test>::get(), 0);
+        System::assert_last_event(
+            Event::PowDifficultyAdjusted {
+                difficulty: MIN_POW_DIFFICULTY + 1,
+            }
+            .into(),
+        );
+
+        // Fewer than half the target: one bit easier, never below the minimum.
+        next_period();
+        assert_eq!(PowDifficulty::<Test>::get(), MIN_POW_DIFFICULTY);
+        next_period();
+        assert_eq!(PowDifficulty::<Test>::get(), MIN_POW_DIFFICULTY);
+
+        // Blocks within a period leave it alone.
+        PowRegistrations::<Test>::put(5);
+        System::set_block_number(System::block_number() + 1);
+        Artists::on_initialize(System::block_number());
+        assert_eq!(PowDifficulty::<Test>::get(), MIN_POW_DIFFICULTY);
+    });
+}
+
+#[test]
+fn dissolving_a_work_artist_frees_its_creator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(create_with_work(ALICE, nonce(ALICE, &band(ALICE), true)));
+
+        assert_ok!(propose(ALICE, Action::Dissolve));
+        for who in [BOB, CHARLIE] {
+            assert_ok!(approve(who, 0));
+        }
+        assert_ok!(execute(0));
+
+        assert!(!WorkArtists::<Test>::contains_key(ALICE));
+        assert_ok!(create_with_work(ALICE, nonce(ALICE, &band(ALICE), true)));
+        assert_eq!(WorkArtists::<Test>::get(ALICE), Some(1));
+    });
+}
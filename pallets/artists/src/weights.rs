@@ -0,0 +1,127 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_artists.
+pub trait WeightInfo {
+    fn create_artist(m: u32) -> Weight;
+    fn propose() -> Weight;
+    fn approve() -> Weight;
+    fn cancel_proposal() -> Weight;
+    fn execute_update() -> Weight;
+    fn execute_payout() -> Weight;
+    fn execute_dissolve(p: u32) -> Weight;
+    fn create_artist_with_work(m: u32) -> Weight;
+    fn adjust_pow_difficulty() -> Weight;
+    fn check_artist_work() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::NextArtistId` (r:1 w:1)
+    /// Storage: `Artists::Artists` (r:0 w:1)
+    /// The range of component `m` is `[1, 32]`.
+    fn create_artist(m: u32) -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(Weight::from_parts(400_000, 32).saturating_mul(m.into()))
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Artists::Artists` (r:1 w:1)
+    /// Storage: `Artists::Proposals` (r:0 w:1)
+    fn propose() -> Weight {
+        Weight::from_parts(25_000_000, 2_600)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Artists::Artists` (r:1 w:0)
+    /// Storage: `Artists::Proposals` (r:1 w:1)
+    fn approve() -> Weight {
+        Weight::from_parts(25_000_000, 3_700)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Artists::Proposals` (r:1 w:1)
+    /// Storage: `Artists::Artists` (r:1 w:1)
+    fn cancel_proposal() -> Weight {
+        Weight::from_parts(20_000_000, 3_700)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Artists::Artists` (r:1 w:1)
+    /// Storage: `Artists::Proposals` (r:1 w:1)
+    fn execute_update() -> Weight {
+        Weight::from_parts(25_000_000, 3_700)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Artists::Artists` (r:1 w:1)
+    /// Storage: `Artists::Proposals` (r:1 w:1)
+    /// Storage: `System::Account` (r:2 w:2)
+    fn execute_payout() -> Weight {
+        Weight::from_parts(50_000_000, 6_200)
+            .saturating_add(ParityDbWeight::get().reads(4_u64))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Artists::Artists` (r:1 w:1)
+    /// Storage: `Artists::Proposals` (r:p+1 w:p+1)
+    /// Storage: `System::Account` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::WorkArtists` (r:1 w:1)
+    /// The range of component `p` is `[0, 15]`.
+    fn execute_dissolve(p: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(Weight::from_parts(5_000_000, 2_600).saturating_mul(p.into()))
+            .saturating_add(ParityDbWeight::get().reads(5_u64))
+            .saturating_add(ParityDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+            .saturating_add(ParityDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+    }
+    /// Storage: `Artists::WorkArtists` (r:1 w:1)
+    /// Storage: `Artists::PowSeed` (r:1 w:0)
+    /// Storage: `Artists::WorkCounts` (r:1 w:1)
+    /// Storage: `Artists::PowDifficulty` (r:1 w:0)
+    /// Storage: `Artists::NextArtistId` (r:1 w:1)
+    /// Storage: `Artists::PowRegistrations` (r:1 w:1)
+    /// Storage: `Artists::Artists` (r:0 w:1)
+    /// The range of component `m` is `[1, 32]`.
+    fn create_artist_with_work(m: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(Weight::from_parts(600_000, 32).saturating_mul(m.into()))
+            .saturating_add(ParityDbWeight::get().reads(6_u64))
+            .saturating_add(ParityDbWeight::get().writes(5_u64))
+    }
+    /// Storage: `Artists::PowRegistrations` (r:1 w:1)
+    /// Storage: `Artists::PowDifficulty` (r:1 w:1)
+    /// Storage: `Artists::PowSeed` (r:0 w:1)
+    fn adjust_pow_difficulty() -> Weight {
+        Weight::from_parts(10_000_000, 1_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Artists::WorkArtists` (r:1 w:0)
+    /// Storage: `Artists::PowSeed` (r:1 w:0)
+    /// Storage: `Artists::WorkCounts` (r:1 w:0)
+    /// Storage: `Artists::PowRegistrations` (r:1 w:0)
+    /// Storage: `Artists::PowDifficulty` (r:1 w:0)
+    fn check_artist_work() -> Weight {
+        Weight::from_parts(30_000_000, 3_500).saturating_add(ParityDbWeight::get().reads(5_u64))
+    }
+}
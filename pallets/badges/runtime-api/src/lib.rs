@@ -0,0 +1,43 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API listing the badges awarded with `pallet-badges`, for profile pages.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::Codec;
+
+pub use pallet_badges::{BadgeClass, ClassId};
+
+sp_api::decl_runtime_apis! {
+    pub trait BadgesApi<AccountId, BlockNumber, Metadata>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Metadata: Codec,
+    {
+        /// Badges held by `who`: class and block they were awarded at, by class id.
+        fn badges_of(who: AccountId) -> Vec<(ClassId, BlockNumber)>;
+
+        /// Issuer, metadata and number of holders of `class`. `None` if it isn't defined.
+        fn class(class: ClassId) -> Option<BadgeClass<AccountId, Metadata>>;
+    }
+}
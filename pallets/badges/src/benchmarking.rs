@@ -0,0 +1,115 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// An issuer with room for one more class, and a class of theirs with full-length metadata.
+fn issuer_with_class<T: Config>() -> (T::AccountId, ClassId) {
+    let issuer: T::AccountId = account("issuer", 0, SEED);
+    Issuers::<T>::insert(&issuer, T::MaxClassesPerIssuer::get().saturating_sub(2));
+    let class = NextClassId::<T>::get();
+    Pallet::<T>::create_class(RawOrigin::Signed(issuer.clone()).into(), metadata::<T>())
+        .expect("the issuer has room for a class");
+    (issuer, class)
+}
+
+fn metadata<T: Config>() -> MetadataOf<T> {
+    alloc::vec![0u8; T::MaxMetadataLength::get() as usize]
+        .try_into()
+        .expect("MaxMetadataLength bytes")
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn add_issuer() {
+        let who: T::AccountId = account("issuer", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, who.clone());
+
+        assert!(Issuers::<T>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn remove_issuer() {
+        let who: T::AccountId = account("issuer", 0, SEED);
+        Issuers::<T>::insert(&who, T::MaxClassesPerIssuer::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, who.clone());
+
+        assert!(!Issuers::<T>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn create_class() {
+        let (issuer, _) = issuer_with_class::<T>();
+        let class = NextClassId::<T>::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(issuer), metadata::<T>());
+
+        assert!(Classes::<T>::contains_key(class));
+    }
+
+    #[benchmark]
+    fn award() {
+        let (issuer, class) = issuer_with_class::<T>();
+        let who: T::AccountId = account("artist", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(issuer), class, who.clone());
+
+        assert!(Pallet::<T>::has_badge(&who, class));
+    }
+
+    #[benchmark]
+    fn revoke() {
+        let (issuer, class) = issuer_with_class::<T>();
+        let who: T::AccountId = account("artist", 0, SEED);
+        Pallet::<T>::award(RawOrigin::Signed(issuer.clone()).into(), class, who.clone())
+            .expect("the issuer awards their own class");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(issuer), class, who.clone());
+
+        assert!(!Pallet::<T>::has_badge(&who, class));
+    }
+
+    #[benchmark]
+    fn renounce() {
+        let (issuer, class) = issuer_with_class::<T>();
+        let who: T::AccountId = account("artist", 0, SEED);
+        Pallet::<T>::award(RawOrigin::Signed(issuer).into(), class, who.clone())
+            .expect("the issuer awards their own class");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who.clone()), class);
+
+        assert!(!Pallet::<T>::has_badge(&who, class));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
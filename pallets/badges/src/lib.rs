@@ -0,0 +1,305 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Badges
+//!
+//! Non-transferable badges (achievements) awarded to artist accounts by approved issuers such
+//! as festivals or certification bodies, for profiles to display.
+//!
+//! ## Features
+//! - `AdminOrigin` (governance) manages the issuer set.
+//! - An issuer defines badge classes on chain, each with its own metadata (e.g. the CID of the
+//!   artwork and description), up to `Config::MaxClassesPerIssuer` classes.
+//! - The issuer of a class awards its badge to accounts and may revoke it, even after losing
+//!   their approval. Removed issuers can no longer award badges.
+//! - Badges can't be transferred: a holder can only renounce theirs.
+//! - Badges are queried through [`Pallet::badges_of`], also exposed by
+//!   `pallet-badges-runtime-api`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use alloc::vec::Vec;
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_runtime::Saturating;
+
+pub type ClassId = u32;
+
+pub type MetadataOf<T> = BoundedVec<u8, <T as Config>::MaxMetadataLength>;
+
+pub type BadgeClassOf<T> = BadgeClass<<T as frame_system::Config>::AccountId, MetadataOf<T>>;
+
+/// A kind of badge, defined by its issuer.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BadgeClass<AccountId, Metadata> {
+    /// Account awarding the badges of this class.
+    pub issuer: AccountId,
+    pub metadata: Metadata,
+    /// Number of accounts holding the badge.
+    pub holders: u32,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Origin allowed to manage the issuer set.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Max length of the metadata of a badge class.
+        #[pallet::constant]
+        type MaxMetadataLength: Get<u32>;
+
+        /// Max number of badge classes an issuer can define.
+        #[pallet::constant]
+        type MaxClassesPerIssuer: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Approved issuers, with the number of classes they defined.
+    #[pallet::storage]
+    pub type Issuers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
+
+    #[pallet::storage]
+    pub type Classes<T: Config> =
+        StorageMap<_, Twox64Concat, ClassId, BadgeClassOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextClassId<T: Config> = StorageValue<_, ClassId, ValueQuery>;
+
+    /// Badges held by each account, with the block they were awarded at.
+    #[pallet::storage]
+    pub type Badges<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        ClassId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        IssuerAdded {
+            who: T::AccountId,
+        },
+        IssuerRemoved {
+            who: T::AccountId,
+        },
+        ClassCreated {
+            class: ClassId,
+            issuer: T::AccountId,
+        },
+        BadgeAwarded {
+            class: ClassId,
+            who: T::AccountId,
+        },
+        BadgeRevoked {
+            class: ClassId,
+            who: T::AccountId,
+        },
+        BadgeRenounced {
+            class: ClassId,
+            who: T::AccountId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        IssuerAlreadyPresent,
+        IssuerNotFound,
+        NotIssuer,
+        TooManyClasses,
+        UnknownClass,
+        /// The caller isn't the issuer of the class.
+        NotClassIssuer,
+        AlreadyAwarded,
+        BadgeNotFound,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Approve `who` as an issuer.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_issuer())]
+        pub fn add_issuer(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !Issuers::<T>::contains_key(&who),
+                Error::<T>::IssuerAlreadyPresent
+            );
+            Issuers::<T>::insert(&who, 0);
+            Self::deposit_event(Event::IssuerAdded { who });
+            Ok(())
+        }
+
+        /// Revoke the issuer approval of `who`.
+        ///
+        /// Their classes and the badges awarded so far are kept.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_issuer())]
+        pub fn remove_issuer(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                Issuers::<T>::take(&who).is_some(),
+                Error::<T>::IssuerNotFound
+            );
+            Self::deposit_event(Event::IssuerRemoved { who });
+            Ok(())
+        }
+
+        /// Define a badge class described by `metadata`. Issuers only.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::create_class())]
+        pub fn create_class(origin: OriginFor<T>, metadata: MetadataOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Issuers::<T>::try_mutate(&who, |maybe_classes| -> DispatchResult {
+                let classes = maybe_classes.as_mut().ok_or(Error::<T>::NotIssuer)?;
+                ensure!(
+                    *classes < T::MaxClassesPerIssuer::get(),
+                    Error::<T>::TooManyClasses
+                );
+                classes.saturating_inc();
+                Ok(())
+            })?;
+
+            let class = NextClassId::<T>::get();
+            Classes::<T>::insert(
+                class,
+                BadgeClass {
+                    issuer: who.clone(),
+                    metadata,
+                    holders: 0,
+                },
+            );
+            NextClassId::<T>::put(class.saturating_add(1));
+
+            Self::deposit_event(Event::ClassCreated { class, issuer: who });
+            Ok(())
+        }
+
+        /// Award the badge of `class` to `who`. Current issuer of the class only.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::award())]
+        pub fn award(origin: OriginFor<T>, class: ClassId, who: T::AccountId) -> DispatchResult {
+            let issuer = ensure_signed(origin)?;
+
+            ensure!(Issuers::<T>::contains_key(&issuer), Error::<T>::NotIssuer);
+            ensure!(
+                !Badges::<T>::contains_key(&who, class),
+                Error::<T>::AlreadyAwarded
+            );
+            Classes::<T>::try_mutate(class, |maybe_class| -> DispatchResult {
+                let badge_class = maybe_class.as_mut().ok_or(Error::<T>::UnknownClass)?;
+                ensure!(badge_class.issuer == issuer, Error::<T>::NotClassIssuer);
+                badge_class.holders.saturating_inc();
+                Ok(())
+            })?;
+            Badges::<T>::insert(&who, class, frame_system::Pallet::<T>::block_number());
+
+            Self::deposit_event(Event::BadgeAwarded { class, who });
+            Ok(())
+        }
+
+        /// Take the badge of `class` back from `who`. Issuer of the class only, approved or not.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::revoke())]
+        pub fn revoke(origin: OriginFor<T>, class: ClassId, who: T::AccountId) -> DispatchResult {
+            let issuer = ensure_signed(origin)?;
+
+            let badge_class = Classes::<T>::get(class).ok_or(Error::<T>::UnknownClass)?;
+            ensure!(badge_class.issuer == issuer, Error::<T>::NotClassIssuer);
+            Self::take_badge(class, &who)?;
+
+            Self::deposit_event(Event::BadgeRevoked { class, who });
+            Ok(())
+        }
+
+        /// Give up the caller's badge of `class`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::renounce())]
+        pub fn renounce(origin: OriginFor<T>, class: ClassId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::take_badge(class, &who)?;
+
+            Self::deposit_event(Event::BadgeRenounced { class, who });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn take_badge(class: ClassId, who: &T::AccountId) -> DispatchResult {
+            ensure!(
+                Badges::<T>::take(who, class).is_some(),
+                Error::<T>::BadgeNotFound
+            );
+            Classes::<T>::mutate(class, |maybe_class| {
+                if let Some(badge_class) = maybe_class {
+                    badge_class.holders.saturating_dec();
+                }
+            });
+            Ok(())
+        }
+
+        /// Whether `who` holds the badge of `class`.
+        pub fn has_badge(who: &T::AccountId, class: ClassId) -> bool {
+            Badges::<T>::contains_key(who, class)
+        }
+
+        /// Badges held by `who`, with the block they were awarded at, by class id.
+        ///
+        /// Iterates over every badge of `who`: meant for off-chain queries.
+        pub fn badges_of(who: &T::AccountId) -> Vec<(ClassId, BlockNumberFor<T>)> {
+            let mut badges: Vec<_> = Badges::<T>::iter_prefix(who).collect();
+            badges.sort_unstable_by_key(|(class, _)| *class);
+            badges
+        }
+
+        pub fn class(class: ClassId) -> Option<BadgeClassOf<T>> {
+            Classes::<T>::get(class)
+        }
+    }
+}
@@ -0,0 +1,224 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BadgeClass, Error, Event, Issuers, MetadataOf, mock::*};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+fn metadata(bytes: &[u8]) -> MetadataOf<Test> {
+    bytes.to_vec().try_into().unwrap()
+}
+
+fn add_issuer(who: u64) {
+    assert_ok!(Badges::add_issuer(RuntimeOrigin::root(), who));
+}
+
+fn create_class(issuer: u64) -> u32 {
+    let class = crate::NextClassId::<Test>::get();
+    assert_ok!(Badges::create_class(
+        RuntimeOrigin::signed(issuer),
+        metadata(b"headline")
+    ));
+    class
+}
+
+#[test]
+fn issuers_are_managed_by_admin_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Badges::add_issuer(RuntimeOrigin::signed(ARTIST), FESTIVAL),
+            DispatchError::BadOrigin
+        );
+
+        add_issuer(FESTIVAL);
+        System::assert_last_event(Event::IssuerAdded { who: FESTIVAL }.into());
+        assert_noop!(
+            Badges::add_issuer(RuntimeOrigin::root(), FESTIVAL),
+            Error::<Test>::IssuerAlreadyPresent
+        );
+
+        assert_noop!(
+            Badges::remove_issuer(RuntimeOrigin::signed(FESTIVAL), FESTIVAL),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Badges::remove_issuer(RuntimeOrigin::root(), FESTIVAL));
+        System::assert_last_event(Event::IssuerRemoved { who: FESTIVAL }.into());
+        assert!(!Issuers::<Test>::contains_key(FESTIVAL));
+        assert_noop!(
+            Badges::remove_issuer(RuntimeOrigin::root(), FESTIVAL),
+            Error::<Test>::IssuerNotFound
+        );
+    });
+}
+
+#[test]
+fn issuers_define_a_bounded_number_of_classes() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Badges::create_class(RuntimeOrigin::signed(FESTIVAL), metadata(b"headline")),
+            Error::<Test>::NotIssuer
+        );
+
+        add_issuer(FESTIVAL);
+        let headline = create_class(FESTIVAL);
+        System::assert_last_event(
+            Event::ClassCreated {
+                class: headline,
+                issuer: FESTIVAL,
+            }
+            .into(),
+        );
+        assert_eq!(
+            Badges::class(headline),
+            Some(BadgeClass {
+                issuer: FESTIVAL,
+                metadata: metadata(b"headline"),
+                holders: 0,
+            })
+        );
+
+        // `MaxClassesPerIssuer` is 2.
+        create_class(FESTIVAL);
+        assert_noop!(
+            Badges::create_class(RuntimeOrigin::signed(FESTIVAL), metadata(b"opening")),
+            Error::<Test>::TooManyClasses
+        );
+        add_issuer(LABEL);
+        create_class(LABEL);
+    });
+}
+
+#[test]
+fn badges_are_awarded_by_the_class_issuer() {
+    new_test_ext().execute_with(|| {
+        add_issuer(FESTIVAL);
+        add_issuer(LABEL);
+        let headline = create_class(FESTIVAL);
+        let opening = create_class(FESTIVAL);
+
+        assert_noop!(
+            Badges::award(RuntimeOrigin::signed(LABEL), headline, ARTIST),
+            Error::<Test>::NotClassIssuer
+        );
+        assert_noop!(
+            Badges::award(RuntimeOrigin::signed(FESTIVAL), 7, ARTIST),
+            Error::<Test>::UnknownClass
+        );
+
+        assert_ok!(Badges::award(
+            RuntimeOrigin::signed(FESTIVAL),
+            opening,
+            ARTIST
+        ));
+        System::set_block_number(5);
+        assert_ok!(Badges::award(
+            RuntimeOrigin::signed(FESTIVAL),
+            headline,
+            ARTIST
+        ));
+        System::assert_last_event(
+            Event::BadgeAwarded {
+                class: headline,
+                who: ARTIST,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Badges::award(RuntimeOrigin::signed(FESTIVAL), headline, ARTIST),
+            Error::<Test>::AlreadyAwarded
+        );
+        assert_ok!(Badges::award(
+            RuntimeOrigin::signed(FESTIVAL),
+            headline,
+            OTHER_ARTIST
+        ));
+
+        assert_eq!(
+            Badges::badges_of(&ARTIST),
+            vec![(headline, 5), (opening, 1)]
+        );
+        assert!(Badges::has_badge(&OTHER_ARTIST, headline));
+        assert!(!Badges::has_badge(&OTHER_ARTIST, opening));
+        assert_eq!(Badges::class(headline).unwrap().holders, 2);
+
+        // Removed issuers can no longer award badges.
+        assert_ok!(Badges::remove_issuer(RuntimeOrigin::root(), FESTIVAL));
+        assert_noop!(
+            Badges::award(RuntimeOrigin::signed(FESTIVAL), opening, OTHER_ARTIST),
+            Error::<Test>::NotIssuer
+        );
+    });
+}
+
+#[test]
+fn badges_are_revoked_by_their_issuer_or_renounced() {
+    new_test_ext().execute_with(|| {
+        add_issuer(FESTIVAL);
+        add_issuer(LABEL);
+        let headline = create_class(FESTIVAL);
+        for who in [ARTIST, OTHER_ARTIST] {
+            assert_ok!(Badges::award(
+                RuntimeOrigin::signed(FESTIVAL),
+                headline,
+                who
+            ));
+        }
+
+        assert_noop!(
+            Badges::revoke(RuntimeOrigin::signed(LABEL), headline, ARTIST),
+            Error::<Test>::NotClassIssuer
+        );
+        // Issuers keep control of the badges they awarded after losing their approval.
+        assert_ok!(Badges::remove_issuer(RuntimeOrigin::root(), FESTIVAL));
+        assert_ok!(Badges::revoke(
+            RuntimeOrigin::signed(FESTIVAL),
+            headline,
+            ARTIST
+        ));
+        System::assert_last_event(
+            Event::BadgeRevoked {
+                class: headline,
+                who: ARTIST,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Badges::revoke(RuntimeOrigin::signed(FESTIVAL), headline, ARTIST),
+            Error::<Test>::BadgeNotFound
+        );
+
+        assert_ok!(Badges::renounce(
+            RuntimeOrigin::signed(OTHER_ARTIST),
+            headline
+        ));
+        System::assert_last_event(
+            Event::BadgeRenounced {
+                class: headline,
+                who: OTHER_ARTIST,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Badges::renounce(RuntimeOrigin::signed(OTHER_ARTIST), headline),
+            Error::<Test>::BadgeNotFound
+        );
+
+        assert!(Badges::badges_of(&ARTIST).is_empty());
+        assert_eq!(Badges::class(headline).unwrap().holders, 0);
+    });
+}
@@ -0,0 +1,74 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_badges.
+pub trait WeightInfo {
+    fn add_issuer() -> Weight;
+    fn remove_issuer() -> Weight;
+    fn create_class() -> Weight;
+    fn award() -> Weight;
+    fn revoke() -> Weight;
+    fn renounce() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Badges::Issuers` (r:1 w:1)
+    fn add_issuer() -> Weight {
+        Weight::from_parts(13_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Badges::Issuers` (r:1 w:1)
+    fn remove_issuer() -> Weight {
+        Weight::from_parts(13_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Badges::Issuers` (r:1 w:1)
+    /// Storage: `Badges::NextClassId` (r:1 w:1)
+    /// Storage: `Badges::Classes` (r:0 w:1)
+    fn create_class() -> Weight {
+        Weight::from_parts(18_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Badges::Issuers` (r:1 w:0)
+    /// Storage: `Badges::Badges` (r:1 w:1)
+    /// Storage: `Badges::Classes` (r:1 w:1)
+    fn award() -> Weight {
+        Weight::from_parts(22_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Badges::Classes` (r:2 w:1)
+    /// Storage: `Badges::Badges` (r:1 w:1)
+    fn revoke() -> Weight {
+        Weight::from_parts(22_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Badges::Badges` (r:1 w:1)
+    /// Storage: `Badges::Classes` (r:1 w:1)
+    fn renounce() -> Weight {
+        Weight::from_parts(18_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+}
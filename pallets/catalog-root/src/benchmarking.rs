@@ -0,0 +1,64 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn on_finalize() {
+        for i in 0..100u32 {
+            Pallet::<T>::commit(*b"bnch", &i, &[i; 8]);
+        }
+
+        #[block]
+        {
+            Pallet::<T>::on_finalize(1u32.into());
+        }
+
+        assert!(
+            catalog_root::<T::Hash>(&frame_system::Pallet::<T>::digest())
+                .is_some_and(|root| root == Pallet::<T>::root())
+        );
+    }
+
+    /// Statistics over a handful of namespaces, one per catalog pallet.
+    #[benchmark]
+    fn deposit_statistics() {
+        for namespace in 0..8u8 {
+            Pallet::<T>::commit([namespace; 4], &0u32, &[namespace; 8]);
+        }
+        let n = T::StatisticsPeriod::get();
+
+        #[block]
+        {
+            Pallet::<T>::deposit_statistics(n);
+        }
+
+        assert_eq!(
+            catalog_statistics(&frame_system::Pallet::<T>::digest())
+                .map(|statistics| statistics.entities.len()),
+            Some(8)
+        );
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,250 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Catalog Root
+//!
+//! Commits to the state of the catalog in every block header, so that off-chain systems can
+//! check catalog entities at any block against a single hash.
+//!
+//! ## Features
+//! - Catalog pallets report their entities through [`CatalogCommitment`]. An entity is keyed
+//!   by its pallet's namespace and its id, and committed as the hash of its SCALE encoding.
+//! - The hashes live in a dedicated child trie, [`CATALOG_TRIE`], so the commitment is kept up
+//!   to date entry by entry rather than recomputed from the whole catalog.
+//! - The root of that trie is deposited in the digest of every block, read back with
+//!   [`catalog_root`].
+//!
+//! An entity is verified with a child read proof of its [`entity_key`] against the root in the
+//! header, comparing the proven hash with the hash of the entity held off chain.
+//!
+//! ## Statistics
+//! - The number of entities committed in each namespace is kept in [`EntityCounts`].
+//! - Every `Config::StatisticsPeriod` blocks, [`CatalogStatistics`] (the entity counts and the
+//!   total paid out by royalty distributions) are deposited in the digest, read back with
+//!   [`catalog_statistics`]. Light clients trust them as far as they trust the header:
+//!   once it is finalized, the validator set signed them through its GRANDPA justification.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+pub mod migrations;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{
+    pallet_prelude::*,
+    storage::child::{self, ChildInfo},
+};
+use frame_system::pallet_prelude::*;
+use parity_scale_codec::DecodeAll;
+use sp_runtime::{
+    Digest, DigestItem, Saturating, Vec,
+    traits::{Hash, UniqueSaturatedInto, Zero},
+};
+
+/// Storage key of the child trie holding the catalog commitment.
+pub const CATALOG_TRIE: &[u8] = b"allfeat/catalog";
+
+/// Tag of the digest item carrying the catalog root.
+pub const CATALOG_ROOT_TAG: [u8; 4] = *b"ctlg";
+
+/// Tag of the digest item carrying the catalog statistics.
+pub const CATALOG_STATISTICS_TAG: [u8; 4] = *b"ctst";
+
+/// Statistics of the catalog at the end of a period, deposited in the digest.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct CatalogStatistics {
+    /// Index of the period, counted from genesis.
+    pub period: u32,
+    /// Number of entities committed in each namespace, in namespace order.
+    pub entities: Vec<([u8; 4], u32)>,
+    /// Everything royalty distributions paid out since genesis.
+    pub distributed: u128,
+}
+
+/// Key of the entity `id` of `namespace` in the catalog trie.
+pub fn entity_key(namespace: [u8; 4], id: &impl Encode) -> Vec<u8> {
+    (namespace, id).encode()
+}
+
+/// The catalog root deposited in `digest`, if any.
+pub fn catalog_root<H: Decode>(digest: &Digest) -> Option<H> {
+    digest.logs().iter().find_map(|item| match item {
+        DigestItem::Other(data) => <([u8; 4], H)>::decode_all(&mut &data[..])
+            .ok()
+            .filter(|(tag, _)| *tag == CATALOG_ROOT_TAG)
+            .map(|(_, root)| root),
+        _ => None,
+    })
+}
+
+/// The catalog statistics deposited in `digest`, if any.
+pub fn catalog_statistics(digest: &Digest) -> Option<CatalogStatistics> {
+    digest.logs().iter().find_map(|item| match item {
+        DigestItem::Other(data) => <([u8; 4], CatalogStatistics)>::decode_all(&mut &data[..])
+            .ok()
+            .filter(|(tag, _)| *tag == CATALOG_STATISTICS_TAG)
+            .map(|(_, statistics)| statistics),
+        _ => None,
+    })
+}
+
+fn catalog_trie() -> ChildInfo {
+    ChildInfo::new_default(CATALOG_TRIE)
+}
+
+/// Keeps the catalog commitment up to date with the entities of a catalog pallet.
+pub trait CatalogCommitment {
+    /// Commit to `entity` as the current state of `id` in `namespace`.
+    fn commit(namespace: [u8; 4], id: &impl Encode, entity: &impl Encode);
+
+    /// Drop `id` in `namespace` from the commitment.
+    fn withdraw(namespace: [u8; 4], id: &impl Encode);
+}
+
+impl CatalogCommitment for () {
+    fn commit(_: [u8; 4], _: &impl Encode, _: &impl Encode) {}
+
+    fn withdraw(_: [u8; 4], _: &impl Encode) {}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Number of blocks between two deposits of the catalog statistics.
+        #[pallet::constant]
+        type StatisticsPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Everything royalty distributions paid out so far, reported in the statistics.
+        type TotalDistributed: Get<u128>;
+
+        /// A type representing the weights required by the hooks of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    /// Number of entities committed in each namespace.
+    #[pallet::storage]
+    pub type EntityCounts<T: Config> = StorageMap<_, Twox64Concat, [u8; 4], u32, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let weight = T::WeightInfo::on_finalize();
+            if Self::ends_period(n) {
+                weight.saturating_add(T::WeightInfo::deposit_statistics())
+            } else {
+                weight
+            }
+        }
+
+        fn on_finalize(n: BlockNumberFor<T>) {
+            frame_system::Pallet::<T>::deposit_log(DigestItem::Other(
+                (CATALOG_ROOT_TAG, Self::root()).encode(),
+            ));
+            if Self::ends_period(n) {
+                Self::deposit_statistics(n);
+            }
+        }
+
+        fn integrity_test() {
+            assert!(
+                !T::StatisticsPeriod::get().is_zero(),
+                "`StatisticsPeriod` must not be zero"
+            );
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Root of the catalog trie in the current state.
+        pub fn root() -> T::Hash {
+            let root = child::root(&catalog_trie(), T::Version::get().state_version());
+            T::Hash::decode(&mut &root[..])
+                .expect("child roots are hashed with the runtime hasher; qed")
+        }
+
+        /// Hash committed for the entity `id` of `namespace`.
+        pub fn entity_hash(namespace: [u8; 4], id: &impl Encode) -> Option<T::Hash> {
+            child::get(&catalog_trie(), &entity_key(namespace, id))
+        }
+
+        /// Whether the statistics are deposited at block `n`, the last of a period.
+        fn ends_period(n: BlockNumberFor<T>) -> bool {
+            (n % T::StatisticsPeriod::get()).is_zero()
+        }
+
+        /// Deposit the statistics of the period ending at block `n` in the digest.
+        pub(crate) fn deposit_statistics(n: BlockNumberFor<T>) {
+            frame_system::Pallet::<T>::deposit_log(DigestItem::Other(
+                (CATALOG_STATISTICS_TAG, Self::statistics(n)).encode(),
+            ));
+        }
+
+        /// Statistics of the catalog in the current state, for the period ending at block `n`.
+        pub fn statistics(n: BlockNumberFor<T>) -> CatalogStatistics {
+            let mut entities: Vec<_> = EntityCounts::<T>::iter().collect();
+            entities.sort_unstable();
+            CatalogStatistics {
+                period: (n / T::StatisticsPeriod::get()).unique_saturated_into(),
+                entities,
+                distributed: T::TotalDistributed::get(),
+            }
+        }
+    }
+
+    impl<T: Config> CatalogCommitment for Pallet<T> {
+        fn commit(namespace: [u8; 4], id: &impl Encode, entity: &impl Encode) {
+            let key = entity_key(namespace, id);
+            if !child::exists(&catalog_trie(), &key) {
+                EntityCounts::<T>::mutate(namespace, |count| count.saturating_inc());
+            }
+            child::put(&catalog_trie(), &key, &T::Hashing::hash_of(entity));
+        }
+
+        fn withdraw(namespace: [u8; 4], id: &impl Encode) {
+            let key = entity_key(namespace, id);
+            if child::exists(&catalog_trie(), &key) {
+                EntityCounts::<T>::mutate_exists(namespace, |count| {
+                    *count = count
+                        .map(|count| count.saturating_sub(1))
+                        .filter(|count| *count > 0);
+                });
+                child::kill(&catalog_trie(), &key);
+            }
+        }
+    }
+}
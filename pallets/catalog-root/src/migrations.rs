@@ -0,0 +1,74 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Storage migrations of the pallet.
+
+pub mod v1 {
+    use crate::{Config, EntityCounts, Pallet, catalog_trie};
+    use alloc::collections::BTreeMap;
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration, pallet_prelude::*, storage::ChildTriePrefixIterator,
+        traits::UncheckedOnRuntimeUpgrade,
+    };
+    use sp_runtime::{Saturating, Vec};
+
+    /// Counts the entities committed before [`EntityCounts`] existed.
+    pub type CountEntities<T> = VersionedMigration<
+        0,
+        1,
+        UncheckedCountEntities<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// [`CountEntities`] without the storage version check.
+    pub struct UncheckedCountEntities<T>(PhantomData<T>);
+
+    /// Namespaces of the entities in the catalog trie.
+    fn namespaces<T: Config>() -> impl Iterator<Item = [u8; 4]> {
+        ChildTriePrefixIterator::<(Vec<u8>, T::Hash)>::with_prefix(&catalog_trie(), &[])
+            .filter_map(|(key, _)| key.get(..4)?.try_into().ok())
+    }
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedCountEntities<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut counts = BTreeMap::<[u8; 4], u32>::new();
+            let mut entities = 0u64;
+            for namespace in namespaces::<T>() {
+                counts.entry(namespace).or_default().saturating_inc();
+                entities.saturating_inc();
+            }
+
+            let namespaces = counts.len() as u64;
+            for (namespace, count) in counts {
+                EntityCounts::<T>::insert(namespace, count);
+            }
+            T::DbWeight::get().reads_writes(entities, namespaces)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let counted: u64 = EntityCounts::<T>::iter_values().map(u64::from).sum();
+            ensure!(
+                counted == namespaces::<T>().count() as u64,
+                "every entity of the catalog trie is counted"
+            );
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,194 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    CatalogCommitment, CatalogStatistics, EntityCounts, catalog_root, catalog_statistics,
+    catalog_trie, entity_key, migrations, mock::*,
+};
+use frame_support::{
+    storage::child,
+    traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+};
+use parity_scale_codec::Encode;
+use sp_runtime::{
+    DigestItem,
+    testing::H256,
+    traits::{BlakeTwo256, Hash},
+};
+
+const WORKS: [u8; 4] = *b"work";
+const RELEASES: [u8; 4] = *b"rlse";
+
+#[test]
+fn entities_are_committed_by_hash() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(CatalogRoot::entity_hash(WORKS, &1u32), None);
+
+        CatalogRoot::commit(WORKS, &1u32, &"first version");
+        assert_eq!(
+            CatalogRoot::entity_hash(WORKS, &1u32),
+            Some(BlakeTwo256::hash_of(&"first version"))
+        );
+        // Namespaces keep ids apart.
+        assert_eq!(CatalogRoot::entity_hash(RELEASES, &1u32), None);
+
+        CatalogRoot::withdraw(WORKS, &1u32);
+        assert_eq!(CatalogRoot::entity_hash(WORKS, &1u32), None);
+    });
+}
+
+#[test]
+fn root_tracks_the_catalog() {
+    new_test_ext().execute_with(|| {
+        let empty = CatalogRoot::root();
+
+        CatalogRoot::commit(WORKS, &1u32, &"first version");
+        let one_work = CatalogRoot::root();
+        assert_ne!(one_work, empty);
+
+        CatalogRoot::commit(RELEASES, &7u64, &"release");
+        let with_release = CatalogRoot::root();
+        assert_ne!(with_release, one_work);
+
+        CatalogRoot::commit(WORKS, &1u32, &"second version");
+        assert_ne!(CatalogRoot::root(), with_release);
+
+        CatalogRoot::commit(WORKS, &1u32, &"first version");
+        assert_eq!(CatalogRoot::root(), with_release);
+
+        CatalogRoot::withdraw(RELEASES, &7u64);
+        assert_eq!(CatalogRoot::root(), one_work);
+        CatalogRoot::withdraw(WORKS, &1u32);
+        assert_eq!(CatalogRoot::root(), empty);
+    });
+}
+
+#[test]
+fn every_block_carries_the_root() {
+    new_test_ext().execute_with(|| {
+        CatalogRoot::on_finalize(1);
+        let digest = System::digest();
+        assert_eq!(catalog_root(&digest), Some(CatalogRoot::root()));
+
+        System::reset_events();
+        System::initialize(&2, &System::parent_hash(), &Default::default());
+        CatalogRoot::commit(WORKS, &1u32, &"first version");
+        CatalogRoot::on_finalize(2);
+        let root = catalog_root::<H256>(&System::digest()).unwrap();
+        assert_eq!(root, CatalogRoot::root());
+        assert_ne!(Some(root), catalog_root(&digest));
+    });
+}
+
+#[test]
+fn catalog_root_ignores_other_digest_items() {
+    let root = BlakeTwo256::hash_of(&"root");
+    let mut digest = sp_runtime::Digest::default();
+    digest.push(DigestItem::Other(b"unrelated".to_vec()));
+    digest.push(DigestItem::Other((*b"othr", root).encode()));
+    assert_eq!(catalog_root::<H256>(&digest), None);
+
+    digest.push(DigestItem::Other((*b"ctlg", root).encode()));
+    assert_eq!(catalog_root(&digest), Some(root));
+}
+
+#[test]
+fn entity_keys_are_namespaced_ids() {
+    assert_eq!(
+        entity_key(WORKS, &1u32),
+        [&b"work"[..], &[1, 0, 0, 0]].concat()
+    );
+}
+
+#[test]
+fn entities_are_counted_per_namespace() {
+    new_test_ext().execute_with(|| {
+        CatalogRoot::commit(WORKS, &1u32, &"first version");
+        CatalogRoot::commit(WORKS, &2u32, &"other work");
+        CatalogRoot::commit(RELEASES, &7u64, &"release");
+        // Updates don't count as new entities.
+        CatalogRoot::commit(WORKS, &1u32, &"second version");
+        assert_eq!(EntityCounts::<Test>::get(WORKS), 2);
+        assert_eq!(EntityCounts::<Test>::get(RELEASES), 1);
+
+        CatalogRoot::withdraw(WORKS, &1u32);
+        // Nothing to withdraw.
+        CatalogRoot::withdraw(WORKS, &1u32);
+        CatalogRoot::withdraw(RELEASES, &8u64);
+        assert_eq!(EntityCounts::<Test>::get(WORKS), 1);
+        assert_eq!(EntityCounts::<Test>::get(RELEASES), 1);
+
+        CatalogRoot::withdraw(RELEASES, &7u64);
+        assert!(!EntityCounts::<Test>::contains_key(RELEASES));
+    });
+}
+
+#[test]
+fn statistics_are_deposited_once_per_period() {
+    new_test_ext().execute_with(|| {
+        CatalogRoot::commit(WORKS, &1u32, &"work");
+        CatalogRoot::commit(WORKS, &2u32, &"other work");
+        CatalogRoot::commit(RELEASES, &7u64, &"release");
+        TotalDistributed::set(1_000);
+
+        CatalogRoot::on_finalize(1);
+        assert_eq!(catalog_statistics(&System::digest()), None);
+
+        for n in [STATISTICS_PERIOD, 3 * STATISTICS_PERIOD] {
+            System::set_block_number(n - 1);
+            System::initialize(&n, &System::parent_hash(), &Default::default());
+            assert_ne!(
+                CatalogRoot::on_initialize(n),
+                CatalogRoot::on_initialize(n + 1)
+            );
+            CatalogRoot::on_finalize(n);
+
+            let digest = System::digest();
+            assert_eq!(
+                catalog_statistics(&digest),
+                Some(CatalogStatistics {
+                    period: (n / STATISTICS_PERIOD) as u32,
+                    entities: vec![(RELEASES, 1), (WORKS, 2)],
+                    distributed: 1_000,
+                })
+            );
+            // Both items live side by side.
+            assert_eq!(catalog_root(&digest), Some(CatalogRoot::root()));
+        }
+    });
+}
+
+#[test]
+fn migration_counts_committed_entities() {
+    new_test_ext().execute_with(|| {
+        for (namespace, id) in [(WORKS, 1u32), (WORKS, 2), (RELEASES, 7)] {
+            child::put(
+                &catalog_trie(),
+                &entity_key(namespace, &id),
+                &BlakeTwo256::hash_of(&id),
+            );
+        }
+        StorageVersion::new(0).put::<CatalogRoot>();
+
+        migrations::v1::CountEntities::<Test>::on_runtime_upgrade();
+
+        assert_eq!(EntityCounts::<Test>::get(WORKS), 2);
+        assert_eq!(EntityCounts::<Test>::get(RELEASES), 1);
+        assert_eq!(CatalogRoot::on_chain_storage_version(), 1);
+    });
+}
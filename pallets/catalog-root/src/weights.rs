@@ -0,0 +1,44 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_catalog_root.
+pub trait WeightInfo {
+    fn on_finalize() -> Weight;
+    fn deposit_statistics() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `CatalogRoot` child trie root (r:1 w:0)
+    /// Storage: `System::Digest` (r:1 w:1)
+    fn on_finalize() -> Weight {
+        Weight::from_parts(15_000_000, 1_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `CatalogRoot::EntityCounts` (r:8 w:0)
+    /// Storage: `Royalties::TotalDistributed` (r:1 w:0)
+    /// Storage: `System::Digest` (r:1 w:1)
+    fn deposit_statistics() -> Weight {
+        Weight::from_parts(12_000_000, 1_800)
+            .saturating_add(ParityDbWeight::get().reads(10_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+}
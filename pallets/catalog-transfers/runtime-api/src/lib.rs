@@ -0,0 +1,40 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API serving the chain of title of works and recordings recorded by
+//! `pallet-catalog-transfers`, for provenance and due-diligence queries.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+
+pub use pallet_catalog_transfers::{ChainOfTitle, ProvenanceRecord, TransferId};
+
+sp_api::decl_runtime_apis! {
+    pub trait CatalogTransfersApi<AccountId, AssetId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        AssetId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Current owner and ordered transfer history of `asset`. Links archived out of state
+        /// are counted in `archived` and must be rebuilt from `TransferCompleted` events.
+        fn chain_of_title(asset: AssetId) -> ChainOfTitle<AccountId, Balance, BlockNumber>;
+    }
+}
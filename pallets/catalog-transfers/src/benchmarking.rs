@@ -0,0 +1,103 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+
+fn funded<T: Config>(name: &'static str) -> T::AccountId {
+    let who: T::AccountId = account(name, 0, SEED);
+    T::Currency::set_balance(&who, BalanceOf::<T>::max_value() / 4u32.into());
+    who
+}
+
+fn bench_price<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().max(1u32.into()) * 1_000u32.into()
+}
+
+/// A batch of `a` assets owned by `owner`.
+fn catalog<T: Config>(a: u32, owner: &T::AccountId) -> BatchOf<T> {
+    (0..a)
+        .map(|i| T::BenchmarkHelper::create_asset(i, owner))
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("at most MaxBatchSize assets")
+}
+
+/// A pending offer of `a` assets from a funded seller to a funded buyer.
+fn pending_offer<T: Config>(a: u32) -> (T::AccountId, T::AccountId, TransferId) {
+    let seller = funded::<T>("seller");
+    let buyer = funded::<T>("buyer");
+    Pallet::<T>::offer_transfer(
+        RawOrigin::Signed(seller.clone()).into(),
+        buyer.clone(),
+        catalog::<T>(a, &seller),
+        bench_price::<T>(),
+    )
+    .expect("owner offers a valid batch");
+    (seller, buyer, NextTransferId::<T>::get() - 1)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn offer_transfer(a: Linear<1, { T::MaxBatchSize::get() }>) {
+        let seller = funded::<T>("seller");
+        let buyer = funded::<T>("buyer");
+        let assets = catalog::<T>(a, &seller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(seller), buyer, assets, bench_price::<T>());
+
+        assert!(Offers::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn accept_transfer(a: Linear<1, { T::MaxBatchSize::get() }>) {
+        let (_, buyer, transfer_id) = pending_offer::<T>(a);
+        // Full provenance chains, so that every transfer archives a link.
+        for asset in Offers::<T>::get(transfer_id)
+            .expect("offer is pending")
+            .assets
+        {
+            ProvenanceLength::<T>::insert(asset, T::MaxProvenanceRecords::get());
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer), transfer_id);
+
+        assert!(!Offers::<T>::contains_key(transfer_id));
+    }
+
+    #[benchmark]
+    fn cancel_offer(a: Linear<1, { T::MaxBatchSize::get() }>) {
+        let (seller, _, transfer_id) = pending_offer::<T>(a);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(seller), transfer_id);
+
+        assert!(!Offers::<T>::contains_key(transfer_id));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
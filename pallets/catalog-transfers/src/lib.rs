@@ -0,0 +1,409 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Catalog Transfers
+//!
+//! Transfers of works and recordings between accounts, one at a time or as a whole catalog.
+//!
+//! ## Features
+//! - The owner of a batch of assets (`Config::Assets`) offers it to a buyer, optionally for a
+//!   price. A deposit is held while the offer is pending, and an asset can be in one pending
+//!   offer at a time.
+//! - The buyer accepts the offer: the price is paid to the seller and every asset of the batch
+//!   changes hands atomically. Either party can cancel a pending offer instead.
+//! - Each settled transfer appends a record to the provenance chain of its assets, so the
+//!   chain of title of an asset can be audited from its first sale.
+//! - Only the latest `Config::MaxProvenanceRecords` links of a chain are kept in state. Older
+//!   links are archived: they are rebuilt from the `TransferCompleted` events of their blocks.
+//!   [`Pallet::chain_of_title`] tells how many links are archived.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use alloc::vec::Vec;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{Precision, Preservation},
+    },
+};
+use frame_system::pallet_prelude::*;
+use pallet_royalties::AssetOwnership;
+
+pub type TransferId = u32;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type BatchOf<T> = BoundedVec<<T as Config>::AssetId, <T as Config>::MaxBatchSize>;
+
+pub type TransferOfferOf<T> =
+    TransferOffer<<T as frame_system::Config>::AccountId, BatchOf<T>, BalanceOf<T>>;
+
+pub type ProvenanceRecordOf<T> =
+    ProvenanceRecord<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+/// A registry whose assets can change hands.
+pub trait AssetTransfer<AssetId, AccountId>: AssetOwnership<AssetId, AccountId> {
+    /// Make `to` the owner of `asset`.
+    fn transfer(asset: &AssetId, to: &AccountId) -> DispatchResult;
+}
+
+/// A pending offer to transfer a batch of assets.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct TransferOffer<AccountId, Batch, Balance> {
+    pub seller: AccountId,
+    pub buyer: AccountId,
+    pub assets: Batch,
+    /// Paid by the buyer to the seller on acceptance; zero for a gift or an internal move.
+    pub price: Balance,
+    /// Amount held from `seller` while the offer is pending.
+    pub deposit: Balance,
+}
+
+/// One link of an asset's provenance chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ProvenanceRecord<AccountId, Balance, BlockNumber> {
+    pub from: AccountId,
+    pub to: AccountId,
+    /// The settled transfer, shared by every asset of a catalog sale.
+    pub transfer: TransferId,
+    /// Price of the whole transfer.
+    pub price: Balance,
+    pub at: BlockNumber,
+}
+
+/// The chain of title of an asset, as served to due-diligence queries.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct ChainOfTitle<AccountId, Balance, BlockNumber> {
+    /// Current owner of the asset, `None` if it does not exist (anymore).
+    pub owner: Option<AccountId>,
+    /// Number of oldest links pruned from state. They precede `records` and are rebuilt from
+    /// the `TransferCompleted` events emitted up to the block of the first record.
+    pub archived: u32,
+    /// Links kept in state, oldest transfer first.
+    pub records: Vec<ProvenanceRecord<AccountId, Balance, BlockNumber>>,
+}
+
+pub type ChainOfTitleOf<T> =
+    ChainOfTitle<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, AccountId> {
+    /// Create the `i`-th asset, owned by `owner`, and return its id.
+    fn create_asset(i: u32, owner: &AccountId) -> AssetId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Identifier of a transferable work or recording.
+        type AssetId: Parameter + Member + MaxEncodedLen;
+
+        /// Resolves and changes the owners of assets.
+        type Assets: AssetTransfer<Self::AssetId, Self::AccountId>;
+
+        /// Max number of assets transferred by a single offer.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Amount held from the seller for each pending offer.
+        #[pallet::constant]
+        type OfferDeposit: Get<BalanceOf<Self>>;
+
+        /// Max number of links of a provenance chain kept in state, the latest ones.
+        #[pallet::constant]
+        type MaxProvenanceRecords: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Self::AccountId>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        OfferDeposit,
+    }
+
+    #[pallet::storage]
+    pub type Offers<T: Config> =
+        StorageMap<_, Twox64Concat, TransferId, TransferOfferOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextTransferId<T: Config> = StorageValue<_, TransferId, ValueQuery>;
+
+    /// The pending offer each asset is part of.
+    #[pallet::storage]
+    pub type PendingAssets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, TransferId, OptionQuery>;
+
+    /// Provenance chains, oldest transfer first. Only the latest `MaxProvenanceRecords` links
+    /// of each chain are kept.
+    #[pallet::storage]
+    pub type Provenance<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        Twox64Concat,
+        u32,
+        ProvenanceRecordOf<T>,
+        OptionQuery,
+    >;
+
+    /// Number of links of each provenance chain, archived ones included.
+    #[pallet::storage]
+    pub type ProvenanceLength<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, u32, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        TransferOffered {
+            transfer_id: TransferId,
+            seller: T::AccountId,
+            buyer: T::AccountId,
+            assets: BatchOf<T>,
+            price: BalanceOf<T>,
+        },
+        TransferCancelled {
+            transfer_id: TransferId,
+        },
+        TransferCompleted {
+            transfer_id: TransferId,
+            seller: T::AccountId,
+            buyer: T::AccountId,
+            assets: BatchOf<T>,
+            price: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        EmptyBatch,
+        DuplicateAsset,
+        NotAssetOwner,
+        /// An asset of the batch is already part of a pending offer.
+        AssetAlreadyOffered,
+        SelfTransfer,
+        OfferNotFound,
+        NotBuyer,
+        /// Only the seller and the buyer can cancel an offer.
+        NotParty,
+        /// The seller no longer owns every asset of the batch.
+        OfferStale,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Offer `assets` to `buyer` for `price`. Owner of every asset only.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::offer_transfer(assets.len() as u32))]
+        pub fn offer_transfer(
+            origin: OriginFor<T>,
+            buyer: T::AccountId,
+            assets: BatchOf<T>,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(who != buyer, Error::<T>::SelfTransfer);
+            ensure!(!assets.is_empty(), Error::<T>::EmptyBatch);
+            for (i, asset) in assets.iter().enumerate() {
+                ensure!(!assets[..i].contains(asset), Error::<T>::DuplicateAsset);
+                ensure!(
+                    T::Assets::owner_of(asset).as_ref() == Some(&who),
+                    Error::<T>::NotAssetOwner
+                );
+                ensure!(
+                    !PendingAssets::<T>::contains_key(asset),
+                    Error::<T>::AssetAlreadyOffered
+                );
+            }
+
+            let deposit = T::OfferDeposit::get();
+            T::Currency::hold(&HoldReason::OfferDeposit.into(), &who, deposit)?;
+
+            let transfer_id = NextTransferId::<T>::get();
+            for asset in assets.iter() {
+                PendingAssets::<T>::insert(asset, transfer_id);
+            }
+            Offers::<T>::insert(
+                transfer_id,
+                TransferOffer {
+                    seller: who.clone(),
+                    buyer: buyer.clone(),
+                    assets: assets.clone(),
+                    price,
+                    deposit,
+                },
+            );
+            NextTransferId::<T>::put(transfer_id.saturating_add(1));
+
+            Self::deposit_event(Event::TransferOffered {
+                transfer_id,
+                seller: who,
+                buyer,
+                assets,
+                price,
+            });
+            Ok(())
+        }
+
+        /// Accept an offer: pay its price to the seller and take over its assets. Buyer only.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::accept_transfer(T::MaxBatchSize::get()))]
+        pub fn accept_transfer(
+            origin: OriginFor<T>,
+            transfer_id: TransferId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let offer = Offers::<T>::get(transfer_id).ok_or(Error::<T>::OfferNotFound)?;
+            ensure!(offer.buyer == who, Error::<T>::NotBuyer);
+            ensure!(
+                offer
+                    .assets
+                    .iter()
+                    .all(|asset| T::Assets::owner_of(asset).as_ref() == Some(&offer.seller)),
+                Error::<T>::OfferStale
+            );
+
+            T::Currency::transfer(&who, &offer.seller, offer.price, Preservation::Preserve)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            for asset in offer.assets.iter() {
+                T::Assets::transfer(asset, &who)?;
+                let index = ProvenanceLength::<T>::get(asset);
+                Provenance::<T>::insert(
+                    asset,
+                    index,
+                    ProvenanceRecord {
+                        from: offer.seller.clone(),
+                        to: who.clone(),
+                        transfer: transfer_id,
+                        price: offer.price,
+                        at: now,
+                    },
+                );
+                ProvenanceLength::<T>::insert(asset, index.saturating_add(1));
+                if let Some(archived) = index.checked_sub(T::MaxProvenanceRecords::get()) {
+                    Provenance::<T>::remove(asset, archived);
+                }
+            }
+            let assets = offer.assets.len() as u32;
+            Self::close_offer(transfer_id, &offer)?;
+
+            Self::deposit_event(Event::TransferCompleted {
+                transfer_id,
+                seller: offer.seller,
+                buyer: who,
+                assets: offer.assets,
+                price: offer.price,
+            });
+            Ok(Some(T::WeightInfo::accept_transfer(assets)).into())
+        }
+
+        /// Cancel a pending offer and release its deposit. Seller or buyer only.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::cancel_offer(T::MaxBatchSize::get()))]
+        pub fn cancel_offer(
+            origin: OriginFor<T>,
+            transfer_id: TransferId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let offer = Offers::<T>::get(transfer_id).ok_or(Error::<T>::OfferNotFound)?;
+            ensure!(
+                who == offer.seller || who == offer.buyer,
+                Error::<T>::NotParty
+            );
+            Self::close_offer(transfer_id, &offer)?;
+
+            Self::deposit_event(Event::TransferCancelled { transfer_id });
+            Ok(Some(T::WeightInfo::cancel_offer(offer.assets.len() as u32)).into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Drop a pending offer, unlocking its assets and releasing its deposit.
+        fn close_offer(transfer_id: TransferId, offer: &TransferOfferOf<T>) -> DispatchResult {
+            T::Currency::release(
+                &HoldReason::OfferDeposit.into(),
+                &offer.seller,
+                offer.deposit,
+                Precision::BestEffort,
+            )?;
+            for asset in offer.assets.iter() {
+                PendingAssets::<T>::remove(asset);
+            }
+            Offers::<T>::remove(transfer_id);
+            Ok(())
+        }
+
+        /// The provenance links of `asset` kept in state, oldest transfer first.
+        pub fn provenance(asset: &T::AssetId) -> Vec<ProvenanceRecordOf<T>> {
+            let length = ProvenanceLength::<T>::get(asset);
+            (Self::archived_links(length)..length)
+                .filter_map(|index| Provenance::<T>::get(asset, index))
+                .collect()
+        }
+
+        /// The chain of title of `asset`: its owner and its provenance chain.
+        pub fn chain_of_title(asset: &T::AssetId) -> ChainOfTitleOf<T> {
+            ChainOfTitle {
+                owner: T::Assets::owner_of(asset),
+                archived: Self::archived_links(ProvenanceLength::<T>::get(asset)),
+                records: Self::provenance(asset),
+            }
+        }
+
+        /// Number of links of a chain of `length` pruned from state.
+        fn archived_links(length: u32) -> u32 {
+            length.saturating_sub(T::MaxProvenanceRecords::get())
+        }
+    }
+}
@@ -0,0 +1,279 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    BatchOf, ChainOfTitle, Error, Event, HoldReason, Offers, PendingAssets, Provenance,
+    ProvenanceRecord, mock::*,
+};
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use pallet_royalties::AssetOwnership;
+
+const PRICE: Balance = 200;
+
+fn batch(assets: &[u32]) -> BatchOf<Test> {
+    assets.to_vec().try_into().unwrap()
+}
+
+fn offer(seller: u64, buyer: u64, assets: &[u32], price: Balance) -> sp_runtime::DispatchResult {
+    CatalogTransfers::offer_transfer(RuntimeOrigin::signed(seller), buyer, batch(assets), price)
+}
+
+fn deposit_held(who: u64) -> Balance {
+    Balances::balance_on_hold(&HoldReason::OfferDeposit.into(), &who)
+}
+
+#[test]
+fn offer_transfer_locks_assets_and_holds_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(offer(ALICE, BOB, &[WORK, RECORDING], PRICE));
+
+        let pending = Offers::<Test>::get(0).unwrap();
+        assert_eq!(pending.seller, ALICE);
+        assert_eq!(pending.buyer, BOB);
+        assert_eq!(pending.deposit, OFFER_DEPOSIT);
+        assert_eq!(deposit_held(ALICE), OFFER_DEPOSIT);
+        assert_eq!(PendingAssets::<Test>::get(WORK), Some(0));
+        assert_eq!(PendingAssets::<Test>::get(RECORDING), Some(0));
+        System::assert_last_event(
+            Event::TransferOffered {
+                transfer_id: 0,
+                seller: ALICE,
+                buyer: BOB,
+                assets: batch(&[WORK, RECORDING]),
+                price: PRICE,
+            }
+            .into(),
+        );
+
+        // Offering doesn't change ownership yet.
+        assert_eq!(MockAssets::owner_of(&WORK), Some(ALICE));
+    });
+}
+
+#[test]
+fn offer_transfer_validates_batch() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            offer(ALICE, ALICE, &[WORK], PRICE),
+            Error::<Test>::SelfTransfer
+        );
+        assert_noop!(offer(ALICE, BOB, &[], PRICE), Error::<Test>::EmptyBatch);
+        assert_noop!(
+            offer(ALICE, BOB, &[WORK, WORK], PRICE),
+            Error::<Test>::DuplicateAsset
+        );
+        assert_noop!(
+            offer(ALICE, BOB, &[WORK, OTHER_WORK], PRICE),
+            Error::<Test>::NotAssetOwner
+        );
+
+        assert_ok!(offer(ALICE, BOB, &[WORK], PRICE));
+        assert_noop!(
+            offer(ALICE, CHARLIE, &[RECORDING, WORK], PRICE),
+            Error::<Test>::AssetAlreadyOffered
+        );
+    });
+}
+
+#[test]
+fn accept_transfer_settles_catalog_sale() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(offer(ALICE, BOB, &[WORK, RECORDING], PRICE));
+
+        assert_ok!(CatalogTransfers::accept_transfer(
+            RuntimeOrigin::signed(BOB),
+            0
+        ));
+
+        assert_eq!(MockAssets::owner_of(&WORK), Some(BOB));
+        assert_eq!(MockAssets::owner_of(&RECORDING), Some(BOB));
+        assert_eq!(Balances::free_balance(ALICE), 100 + PRICE);
+        assert_eq!(Balances::free_balance(BOB), 1_000 - PRICE);
+        assert_eq!(deposit_held(ALICE), 0);
+        assert!(Offers::<Test>::get(0).is_none());
+        assert!(PendingAssets::<Test>::get(WORK).is_none());
+
+        let record = ProvenanceRecord {
+            from: ALICE,
+            to: BOB,
+            transfer: 0,
+            price: PRICE,
+            at: 1,
+        };
+        assert_eq!(CatalogTransfers::provenance(&WORK), vec![record.clone()]);
+        assert_eq!(CatalogTransfers::provenance(&RECORDING), vec![record]);
+        System::assert_last_event(
+            Event::TransferCompleted {
+                transfer_id: 0,
+                seller: ALICE,
+                buyer: BOB,
+                assets: batch(&[WORK, RECORDING]),
+                price: PRICE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn accept_transfer_checks_offer() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CatalogTransfers::accept_transfer(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::OfferNotFound
+        );
+
+        assert_ok!(offer(ALICE, POOR, &[WORK], PRICE));
+        assert_noop!(
+            CatalogTransfers::accept_transfer(RuntimeOrigin::signed(CHARLIE), 0),
+            Error::<Test>::NotBuyer
+        );
+        // The buyer can't pay the price: nothing changes hands.
+        assert!(CatalogTransfers::accept_transfer(RuntimeOrigin::signed(POOR), 0).is_err());
+        assert_eq!(MockAssets::owner_of(&WORK), Some(ALICE));
+
+        assert_ok!(offer(ALICE, BOB, &[RECORDING], PRICE));
+        MockAssets::set_owner(RECORDING, CHARLIE);
+        assert_noop!(
+            CatalogTransfers::accept_transfer(RuntimeOrigin::signed(BOB), 1),
+            Error::<Test>::OfferStale
+        );
+    });
+}
+
+#[test]
+fn either_party_cancels_offer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(offer(ALICE, BOB, &[WORK], PRICE));
+        assert_noop!(
+            CatalogTransfers::cancel_offer(RuntimeOrigin::signed(CHARLIE), 0),
+            Error::<Test>::NotParty
+        );
+
+        assert_ok!(CatalogTransfers::cancel_offer(
+            RuntimeOrigin::signed(BOB),
+            0
+        ));
+        System::assert_last_event(Event::TransferCancelled { transfer_id: 0 }.into());
+        assert!(Offers::<Test>::get(0).is_none());
+        assert!(PendingAssets::<Test>::get(WORK).is_none());
+        assert_eq!(deposit_held(ALICE), 0);
+
+        // The asset can be offered again, and the seller may cancel too.
+        assert_ok!(offer(ALICE, CHARLIE, &[WORK], PRICE));
+        assert_ok!(CatalogTransfers::cancel_offer(
+            RuntimeOrigin::signed(ALICE),
+            1
+        ));
+        assert_noop!(
+            CatalogTransfers::cancel_offer(RuntimeOrigin::signed(ALICE), 1),
+            Error::<Test>::OfferNotFound
+        );
+    });
+}
+
+#[test]
+fn provenance_chains_across_resales() {
+    new_test_ext().execute_with(|| {
+        // A gift first, then a sale.
+        assert_ok!(offer(ALICE, BOB, &[WORK], 0));
+        assert_ok!(CatalogTransfers::accept_transfer(
+            RuntimeOrigin::signed(BOB),
+            0
+        ));
+        System::set_block_number(5);
+        assert_ok!(offer(BOB, CHARLIE, &[WORK], 50));
+        assert_ok!(CatalogTransfers::accept_transfer(
+            RuntimeOrigin::signed(CHARLIE),
+            1
+        ));
+
+        assert_eq!(MockAssets::owner_of(&WORK), Some(CHARLIE));
+        assert_eq!(
+            CatalogTransfers::provenance(&WORK),
+            vec![
+                ProvenanceRecord {
+                    from: ALICE,
+                    to: BOB,
+                    transfer: 0,
+                    price: 0,
+                    at: 1,
+                },
+                ProvenanceRecord {
+                    from: BOB,
+                    to: CHARLIE,
+                    transfer: 1,
+                    price: 50,
+                    at: 5,
+                },
+            ]
+        );
+        assert!(CatalogTransfers::provenance(&RECORDING).is_empty());
+    });
+}
+
+#[test]
+fn chain_of_title_archives_oldest_links() {
+    new_test_ext().execute_with(|| {
+        // ALICE -> BOB -> CHARLIE -> BOB, two links kept in state.
+        for (transfer, (seller, buyer)) in [(ALICE, BOB), (BOB, CHARLIE), (CHARLIE, BOB)]
+            .into_iter()
+            .enumerate()
+        {
+            System::set_block_number(transfer as u64 + 1);
+            assert_ok!(offer(seller, buyer, &[WORK], 0));
+            assert_ok!(CatalogTransfers::accept_transfer(
+                RuntimeOrigin::signed(buyer),
+                transfer as u32
+            ));
+        }
+
+        assert!(!Provenance::<Test>::contains_key(WORK, 0));
+        assert_eq!(
+            CatalogTransfers::chain_of_title(&WORK),
+            ChainOfTitle {
+                owner: Some(BOB),
+                archived: 1,
+                records: vec![
+                    ProvenanceRecord {
+                        from: BOB,
+                        to: CHARLIE,
+                        transfer: 1,
+                        price: 0,
+                        at: 2,
+                    },
+                    ProvenanceRecord {
+                        from: CHARLIE,
+                        to: BOB,
+                        transfer: 2,
+                        price: 0,
+                        at: 3,
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            CatalogTransfers::chain_of_title(&RECORDING),
+            ChainOfTitle {
+                owner: Some(ALICE),
+                archived: 0,
+                records: vec![],
+            }
+        );
+    });
+}
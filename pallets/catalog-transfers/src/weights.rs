@@ -0,0 +1,71 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_catalog_transfers.
+pub trait WeightInfo {
+    fn offer_transfer(a: u32) -> Weight;
+    fn accept_transfer(a: u32) -> Weight;
+    fn cancel_offer(a: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Assets` registry (r:a w:0)
+    /// Storage: `CatalogTransfers::PendingAssets` (r:a w:a)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `CatalogTransfers::NextTransferId` (r:1 w:1)
+    /// Storage: `CatalogTransfers::Offers` (r:0 w:1)
+    /// The range of component `a` is `[1, 100]`.
+    fn offer_transfer(a: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_500)
+            .saturating_add(Weight::from_parts(8_000_000, 2_600).saturating_mul(a.into()))
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().reads((2_u64).saturating_mul(a.into())))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+            .saturating_add(ParityDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+    }
+    /// Storage: `CatalogTransfers::Offers` (r:1 w:1)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Assets` registry (r:2a w:a)
+    /// Storage: `CatalogTransfers::ProvenanceLength` (r:a w:a)
+    /// Storage: `CatalogTransfers::Provenance` (r:0 w:2a)
+    /// Storage: `CatalogTransfers::PendingAssets` (r:0 w:a)
+    /// The range of component `a` is `[1, 100]`.
+    fn accept_transfer(a: u32) -> Weight {
+        Weight::from_parts(70_000_000, 6_000)
+            .saturating_add(Weight::from_parts(33_000_000, 5_200).saturating_mul(a.into()))
+            .saturating_add(ParityDbWeight::get().reads(4_u64))
+            .saturating_add(ParityDbWeight::get().reads((3_u64).saturating_mul(a.into())))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+            .saturating_add(ParityDbWeight::get().writes((5_u64).saturating_mul(a.into())))
+    }
+    /// Storage: `CatalogTransfers::Offers` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `CatalogTransfers::PendingAssets` (r:0 w:a)
+    /// The range of component `a` is `[1, 100]`.
+    fn cancel_offer(a: u32) -> Weight {
+        Weight::from_parts(35_000_000, 4_500)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(a.into()))
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+            .saturating_add(ParityDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+    }
+}
@@ -0,0 +1,98 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn fill_certifiers<T: Config>(n: u32) {
+    for i in 0..n {
+        let who: T::AccountId = account("certifier", i, SEED);
+        Certifiers::<T>::insert(&who, OrganizationKind::Pro);
+    }
+    CertifierCount::<T>::put(n);
+}
+
+/// `record` attested by `n` certifiers.
+fn fill_attestations<T: Config>(record: &T::Record, n: u32) {
+    let attestations: Vec<_> = (0..n)
+        .map(|i| (account("certifier", i, SEED), 0u32.into()))
+        .collect();
+    Attestations::<T>::insert(
+        record,
+        AttestationsOf::<T>::try_from(attestations).expect("n <= MaxAttestations"),
+    );
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn add_certifier() {
+        fill_certifiers::<T>(T::MaxCertifiers::get() - 1);
+        let who: T::AccountId = account("new", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, who.clone(), OrganizationKind::Pro);
+
+        assert!(Certifiers::<T>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn remove_certifier() {
+        fill_certifiers::<T>(T::MaxCertifiers::get());
+        let who: T::AccountId = account("certifier", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, who.clone());
+
+        assert!(!Certifiers::<T>::contains_key(&who));
+    }
+
+    /// Full attestations of former certifiers, all dropped to make room.
+    #[benchmark]
+    fn attest() {
+        let max = T::MaxAttestations::get();
+        let record = T::BenchmarkHelper::create_record(0);
+        fill_attestations::<T>(&record, max);
+        let who: T::AccountId = account("new", 0, SEED);
+        Certifiers::<T>::insert(&who, OrganizationKind::Registrar);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who.clone()), record.clone());
+
+        assert_eq!(Attestations::<T>::get(&record).len(), 1);
+    }
+
+    #[benchmark]
+    fn revoke_attestation() {
+        let max = T::MaxAttestations::get();
+        let record = T::BenchmarkHelper::create_record(0);
+        fill_attestations::<T>(&record, max);
+        let who: T::AccountId = account("certifier", max - 1, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who), record.clone());
+
+        assert_eq!(Attestations::<T>::get(&record).len() as u32, max - 1);
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
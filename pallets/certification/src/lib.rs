@@ -0,0 +1,330 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! # Pallet Certification
+//!
+//! Attestations of artist and work records by trusted organizations (PROs, distributors,
+//! registrars), upgrading the records they vouch for to a verified tier.
+//!
+//! ## Features
+//! - `AdminOrigin` (governance) manages the certifier set.
+//! - A certifier attests an existing record (`Config::Records`) and may revoke their own
+//!   attestation.
+//! - A record is verified while at least one current certifier attests it: removing a
+//!   certifier withdraws the tier from the records only they vouched for, without touching
+//!   their attestations.
+//! - Other pallets query the tier through [`Certification`] (fee discounts, dispute priority,
+//!   ...).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{pallet_prelude::*, traits::Contains};
+use frame_system::pallet_prelude::*;
+use sp_runtime::Saturating;
+
+/// The kind of organization a certifier is.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum OrganizationKind {
+    /// Performing rights organization.
+    Pro,
+    Distributor,
+    Registrar,
+    /// Recording industry body awarding sales certifications (gold, platinum, ...).
+    CertificationBody,
+}
+
+/// Trust tier of a record.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum Tier {
+    Unverified,
+    /// Attested by at least one current certifier.
+    Verified,
+}
+
+/// Trust tier lookup for other pallets.
+pub trait Certification<Record> {
+    fn tier_of(record: &Record) -> Tier;
+
+    fn is_verified(record: &Record) -> bool {
+        Self::tier_of(record) == Tier::Verified
+    }
+}
+
+/// No certification: every record is unverified.
+impl<Record> Certification<Record> for () {
+    fn tier_of(_: &Record) -> Tier {
+        Tier::Unverified
+    }
+}
+
+pub type AttestationsOf<T> = BoundedVec<
+    (<T as frame_system::Config>::AccountId, BlockNumberFor<T>),
+    <T as Config>::MaxAttestations,
+>;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Record> {
+    /// Create the `i`-th record and return its id.
+    fn create_record(i: u32) -> Record;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Origin allowed to manage the certifier set.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Identifier of an artist or work record.
+        type Record: Parameter + Member + MaxEncodedLen;
+
+        /// The records that can be attested.
+        type Records: Contains<Self::Record>;
+
+        /// Max number of certifiers.
+        #[pallet::constant]
+        type MaxCertifiers: Get<u32>;
+
+        /// Max number of attestations on a single record.
+        #[pallet::constant]
+        type MaxAttestations: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::Record>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Organizations allowed to attest records.
+    #[pallet::storage]
+    pub type Certifiers<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, OrganizationKind, OptionQuery>;
+
+    #[pallet::storage]
+    pub type CertifierCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Attestations of each record: the certifier and the block they attested at.
+    #[pallet::storage]
+    pub type Attestations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Record, AttestationsOf<T>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        CertifierAdded {
+            who: T::AccountId,
+            kind: OrganizationKind,
+        },
+        CertifierRemoved {
+            who: T::AccountId,
+        },
+        RecordAttested {
+            record: T::Record,
+            certifier: T::AccountId,
+        },
+        AttestationRevoked {
+            record: T::Record,
+            certifier: T::AccountId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        CertifierAlreadyPresent,
+        CertifierNotFound,
+        TooManyCertifiers,
+        NotCertifier,
+        UnknownRecord,
+        AlreadyAttested,
+        AttestationNotFound,
+        TooManyAttestations,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Approve `who` as a certifier of kind `kind`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_certifier())]
+        pub fn add_certifier(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            kind: OrganizationKind,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !Certifiers::<T>::contains_key(&who),
+                Error::<T>::CertifierAlreadyPresent
+            );
+            let count = CertifierCount::<T>::get();
+            ensure!(
+                count < T::MaxCertifiers::get(),
+                Error::<T>::TooManyCertifiers
+            );
+
+            Certifiers::<T>::insert(&who, kind);
+            CertifierCount::<T>::put(count.saturating_add(1));
+            Self::deposit_event(Event::CertifierAdded { who, kind });
+            Ok(())
+        }
+
+        /// Revoke the certifier approval of `who`.
+        ///
+        /// Their attestations are kept but no longer count towards the verified tier.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_certifier())]
+        pub fn remove_certifier(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                Certifiers::<T>::take(&who).is_some(),
+                Error::<T>::CertifierNotFound
+            );
+            CertifierCount::<T>::mutate(|count| count.saturating_dec());
+            Self::deposit_event(Event::CertifierRemoved { who });
+            Ok(())
+        }
+
+        /// Attest `record`. Certifiers only.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::attest())]
+        pub fn attest(origin: OriginFor<T>, record: T::Record) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                Certifiers::<T>::contains_key(&who),
+                Error::<T>::NotCertifier
+            );
+            ensure!(T::Records::contains(&record), Error::<T>::UnknownRecord);
+
+            Attestations::<T>::try_mutate(&record, |attestations| -> DispatchResult {
+                ensure!(
+                    !attestations.iter().any(|(certifier, _)| certifier == &who),
+                    Error::<T>::AlreadyAttested
+                );
+                // Attestations of former certifiers no longer count: make room by dropping them.
+                if attestations.is_full() {
+                    attestations.retain(|(certifier, _)| Certifiers::<T>::contains_key(certifier));
+                }
+                attestations
+                    .try_push((who.clone(), frame_system::Pallet::<T>::block_number()))
+                    .map_err(|_| Error::<T>::TooManyAttestations)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::RecordAttested {
+                record,
+                certifier: who,
+            });
+            Ok(())
+        }
+
+        /// Revoke the caller's attestation of `record`.
+        ///
+        /// Former certifiers may revoke their attestations too.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::revoke_attestation())]
+        pub fn revoke_attestation(origin: OriginFor<T>, record: T::Record) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Attestations::<T>::try_mutate_exists(
+                &record,
+                |maybe_attestations| -> DispatchResult {
+                    let attestations = maybe_attestations
+                        .as_mut()
+                        .ok_or(Error::<T>::AttestationNotFound)?;
+                    let pos = attestations
+                        .iter()
+                        .position(|(certifier, _)| certifier == &who)
+                        .ok_or(Error::<T>::AttestationNotFound)?;
+                    attestations.remove(pos);
+                    if attestations.is_empty() {
+                        *maybe_attestations = None;
+                    }
+                    Ok(())
+                },
+            )?;
+
+            Self::deposit_event(Event::AttestationRevoked {
+                record,
+                certifier: who,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Current certifiers attesting `record`.
+        pub fn certifiers_of(record: &T::Record) -> impl Iterator<Item = T::AccountId> {
+            Attestations::<T>::get(record)
+                .into_iter()
+                .map(|(certifier, _)| certifier)
+                .filter(|certifier| Certifiers::<T>::contains_key(certifier))
+        }
+    }
+}
+
+impl<T: Config> Certification<T::Record> for Pallet<T> {
+    fn tier_of(record: &T::Record) -> Tier {
+        if Self::certifiers_of(record).next().is_some() {
+            Tier::Verified
+        } else {
+            Tier::Unverified
+        }
+    }
+}
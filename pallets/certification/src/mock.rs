@@ -0,0 +1,98 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate as pallet_certification;
+use frame_support::{derive_impl, parameter_types, sp_runtime::BuildStorage, traits::Contains};
+use frame_system::EnsureRoot;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Certification = pallet_certification;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+parameter_types! {
+    pub const MaxCertifiers: u32 = 3;
+    pub const MaxAttestations: u32 = 2;
+}
+
+/// Records `1..=MAX_RECORD` exist.
+pub struct MockRecords;
+impl Contains<u32> for MockRecords {
+    fn contains(record: &u32) -> bool {
+        (1..=MAX_RECORD).contains(record)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u32> for MockRecords {
+    fn create_record(i: u32) -> u32 {
+        i % MAX_RECORD + 1
+    }
+}
+
+impl pallet_certification::Config for Test {
+    type AdminOrigin = EnsureRoot<Self::AccountId>;
+    type Record = u32;
+    type Records = MockRecords;
+    type MaxCertifiers = MaxCertifiers;
+    type MaxAttestations = MaxAttestations;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockRecords;
+}
+
+pub const MAX_RECORD: u32 = 10;
+pub const WORK: u32 = 1;
+
+/// A performing rights organization.
+pub const PRO: u64 = 1;
+pub const DISTRIBUTOR: u64 = 2;
+pub const REGISTRAR: u64 = 3;
+pub const OUTSIDER: u64 = 4;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,211 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{Attestations, CertifierCount, Error, Event, OrganizationKind, Tier, mock::*};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+fn add(who: u64, kind: OrganizationKind) {
+    assert_ok!(Certification::add_certifier(
+        RuntimeOrigin::root(),
+        who,
+        kind
+    ));
+}
+
+fn attest(who: u64, record: u32) -> sp_runtime::DispatchResult {
+    Certification::attest(RuntimeOrigin::signed(who), record)
+}
+
+fn tier(record: u32) -> Tier {
+    <Certification as crate::Certification<u32>>::tier_of(&record)
+}
+
+#[test]
+fn certifiers_are_managed_by_admin_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Certification::add_certifier(
+                RuntimeOrigin::signed(OUTSIDER),
+                PRO,
+                OrganizationKind::Pro
+            ),
+            DispatchError::BadOrigin
+        );
+
+        add(PRO, OrganizationKind::Pro);
+        System::assert_last_event(
+            Event::CertifierAdded {
+                who: PRO,
+                kind: OrganizationKind::Pro,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Certification::add_certifier(RuntimeOrigin::root(), PRO, OrganizationKind::Registrar),
+            Error::<Test>::CertifierAlreadyPresent
+        );
+        add(DISTRIBUTOR, OrganizationKind::Distributor);
+        add(REGISTRAR, OrganizationKind::Registrar);
+        assert_noop!(
+            Certification::add_certifier(RuntimeOrigin::root(), OUTSIDER, OrganizationKind::Pro),
+            Error::<Test>::TooManyCertifiers
+        );
+
+        assert_noop!(
+            Certification::remove_certifier(RuntimeOrigin::signed(PRO), PRO),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Certification::remove_certifier(RuntimeOrigin::root(), PRO));
+        System::assert_last_event(Event::CertifierRemoved { who: PRO }.into());
+        assert_eq!(CertifierCount::<Test>::get(), 2);
+        assert_noop!(
+            Certification::remove_certifier(RuntimeOrigin::root(), PRO),
+            Error::<Test>::CertifierNotFound
+        );
+    });
+}
+
+#[test]
+fn attestation_verifies_a_record() {
+    new_test_ext().execute_with(|| {
+        add(PRO, OrganizationKind::Pro);
+        assert_eq!(tier(WORK), Tier::Unverified);
+
+        assert_ok!(attest(PRO, WORK));
+        System::assert_last_event(
+            Event::RecordAttested {
+                record: WORK,
+                certifier: PRO,
+            }
+            .into(),
+        );
+        assert_eq!(tier(WORK), Tier::Verified);
+        assert!(<Certification as crate::Certification<u32>>::is_verified(
+            &WORK
+        ));
+        assert_eq!(Attestations::<Test>::get(WORK).to_vec(), vec![(PRO, 1)]);
+    });
+}
+
+#[test]
+fn attest_checks_certifier_and_record() {
+    new_test_ext().execute_with(|| {
+        add(PRO, OrganizationKind::Pro);
+
+        assert_noop!(attest(OUTSIDER, WORK), Error::<Test>::NotCertifier);
+        assert_noop!(attest(PRO, MAX_RECORD + 1), Error::<Test>::UnknownRecord);
+        assert_ok!(attest(PRO, WORK));
+        assert_noop!(attest(PRO, WORK), Error::<Test>::AlreadyAttested);
+    });
+}
+
+#[test]
+fn removed_certifier_no_longer_counts() {
+    new_test_ext().execute_with(|| {
+        add(PRO, OrganizationKind::Pro);
+        add(DISTRIBUTOR, OrganizationKind::Distributor);
+        assert_ok!(attest(PRO, WORK));
+        assert_ok!(attest(DISTRIBUTOR, WORK));
+
+        assert_ok!(Certification::remove_certifier(RuntimeOrigin::root(), PRO));
+        assert_eq!(tier(WORK), Tier::Verified);
+        assert_eq!(
+            Certification::certifiers_of(&WORK).collect::<Vec<_>>(),
+            vec![DISTRIBUTOR]
+        );
+
+        assert_ok!(Certification::remove_certifier(
+            RuntimeOrigin::root(),
+            DISTRIBUTOR
+        ));
+        assert_eq!(tier(WORK), Tier::Unverified);
+        // Their attestations are kept, and count again if they are re-approved.
+        add(PRO, OrganizationKind::Pro);
+        assert_eq!(tier(WORK), Tier::Verified);
+    });
+}
+
+#[test]
+fn full_attestations_make_room_from_former_certifiers() {
+    new_test_ext().execute_with(|| {
+        add(PRO, OrganizationKind::Pro);
+        add(DISTRIBUTOR, OrganizationKind::Distributor);
+        add(REGISTRAR, OrganizationKind::Registrar);
+        assert_ok!(attest(PRO, WORK));
+        assert_ok!(attest(DISTRIBUTOR, WORK));
+        assert_noop!(attest(REGISTRAR, WORK), Error::<Test>::TooManyAttestations);
+
+        assert_ok!(Certification::remove_certifier(RuntimeOrigin::root(), PRO));
+        assert_ok!(attest(REGISTRAR, WORK));
+        assert_eq!(
+            Attestations::<Test>::get(WORK).to_vec(),
+            vec![(DISTRIBUTOR, 1), (REGISTRAR, 1)]
+        );
+    });
+}
+
+#[test]
+fn certifier_revokes_own_attestation() {
+    new_test_ext().execute_with(|| {
+        add(PRO, OrganizationKind::Pro);
+        add(DISTRIBUTOR, OrganizationKind::Distributor);
+        assert_ok!(attest(PRO, WORK));
+
+        assert_noop!(
+            Certification::revoke_attestation(RuntimeOrigin::signed(DISTRIBUTOR), WORK),
+            Error::<Test>::AttestationNotFound
+        );
+        assert_ok!(Certification::revoke_attestation(
+            RuntimeOrigin::signed(PRO),
+            WORK
+        ));
+        System::assert_last_event(
+            Event::AttestationRevoked {
+                record: WORK,
+                certifier: PRO,
+            }
+            .into(),
+        );
+        assert_eq!(tier(WORK), Tier::Unverified);
+        assert!(!Attestations::<Test>::contains_key(WORK));
+    });
+}
+
+#[test]
+fn former_certifier_revokes_attestation() {
+    new_test_ext().execute_with(|| {
+        add(PRO, OrganizationKind::Pro);
+        assert_ok!(attest(PRO, WORK));
+        assert_ok!(Certification::remove_certifier(RuntimeOrigin::root(), PRO));
+
+        assert_ok!(Certification::revoke_attestation(
+            RuntimeOrigin::signed(PRO),
+            WORK
+        ));
+        add(PRO, OrganizationKind::Pro);
+        assert_eq!(tier(WORK), Tier::Unverified);
+    });
+}
+
+#[test]
+fn unit_certification_verifies_nothing() {
+    assert_eq!(
+        <() as crate::Certification<u32>>::tier_of(&WORK),
+        Tier::Unverified
+    );
+}
@@ -0,0 +1,58 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_certification.
+pub trait WeightInfo {
+    fn add_certifier() -> Weight;
+    fn remove_certifier() -> Weight;
+    fn attest() -> Weight;
+    fn revoke_attestation() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Certification::Certifiers` (r:1 w:1)
+    /// Storage: `Certification::CertifierCount` (r:1 w:1)
+    fn add_certifier() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Certification::Certifiers` (r:1 w:1)
+    /// Storage: `Certification::CertifierCount` (r:1 w:1)
+    fn remove_certifier() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Certification::Certifiers` (r:`MaxAttestations` w:0)
+    /// Storage: `Records` registry (r:1 w:0)
+    /// Storage: `Certification::Attestations` (r:1 w:1)
+    fn attest() -> Weight {
+        Weight::from_parts(30_000_000, 6_000)
+            .saturating_add(ParityDbWeight::get().reads(18_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Certification::Attestations` (r:1 w:1)
+    fn revoke_attestation() -> Weight {
+        Weight::from_parts(18_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,145 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn contribution<T: Config>() -> BalanceOf<T> {
+    T::MinContribution::get().max(T::Currency::minimum_balance()) * 10u32.into()
+}
+
+/// Opens round 0 with a `target`, returning its artist.
+fn setup_round<T: Config>(target: BalanceOf<T>) -> Result<T::AccountId, BenchmarkError> {
+    let origin = T::OpenOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    let artist =
+        T::OpenOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+    T::Currency::set_balance(&artist, T::Currency::minimum_balance());
+    let asset = T::BenchmarkHelper::create_asset(&artist);
+    let deadline = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+    Pallet::<T>::open_round(origin, asset, target, deadline, Perbill::from_percent(10))?;
+    Ok(artist)
+}
+
+/// Has `b` funded backers contribute to round 0, returning them.
+fn back_round<T: Config>(b: u32) -> Result<Vec<T::AccountId>, BenchmarkError> {
+    (0..b)
+        .map(|i| {
+            let backer: T::AccountId = account("backer", i, SEED);
+            T::Currency::set_balance(&backer, contribution::<T>() * 2u32.into());
+            Pallet::<T>::contribute(
+                RawOrigin::Signed(backer.clone()).into(),
+                0,
+                contribution::<T>(),
+            )?;
+            Ok(backer)
+        })
+        .collect()
+}
+
+fn close_round<T: Config>() {
+    let now = frame_system::Pallet::<T>::block_number();
+    frame_system::Pallet::<T>::set_block_number(now.saturating_add(1u32.into()));
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn open_round() -> Result<(), BenchmarkError> {
+        let origin =
+            T::OpenOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let artist =
+            T::OpenOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+        let asset = T::BenchmarkHelper::create_asset(&artist);
+        let deadline = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            asset,
+            contribution::<T>(),
+            deadline,
+            Perbill::from_percent(10),
+        );
+
+        assert!(Rounds::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn contribute() -> Result<(), BenchmarkError> {
+        setup_round::<T>(contribution::<T>())?;
+        let backer: T::AccountId = account("backer", 0, SEED);
+        T::Currency::set_balance(&backer, contribution::<T>() * 2u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(backer.clone()), 0, contribution::<T>());
+
+        assert_eq!(Contributions::<T>::get(0, &backer), contribution::<T>());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn settle_funded(b: Linear<1, { T::MaxBackers::get() }>) -> Result<(), BenchmarkError> {
+        setup_round::<T>(contribution::<T>())?;
+        back_round::<T>(b)?;
+        close_round::<T>();
+        let caller: T::AccountId = account("caller", 0, SEED);
+
+        #[extrinsic_call]
+        settle(RawOrigin::Signed(caller), 0);
+
+        assert!(Rounds::<T>::get(0).is_some_and(|r| r.status == RoundStatus::Funded));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn settle_failed(b: Linear<1, { T::MaxBackers::get() }>) -> Result<(), BenchmarkError> {
+        setup_round::<T>(contribution::<T>() * (b + 1).into())?;
+        back_round::<T>(b)?;
+        close_round::<T>();
+        let caller: T::AccountId = account("caller", 0, SEED);
+
+        #[extrinsic_call]
+        settle(RawOrigin::Signed(caller), 0);
+
+        assert!(!Rounds::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim() -> Result<(), BenchmarkError> {
+        setup_round::<T>(contribution::<T>())?;
+        let backer = back_round::<T>(1)?.remove(0);
+        close_round::<T>();
+        Pallet::<T>::settle(RawOrigin::Signed(backer.clone()).into(), 0)?;
+        T::Currency::set_balance(&Pallet::<T>::round_account(0), contribution::<T>());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(backer.clone()), 0);
+
+        assert_eq!(Claimed::<T>::get(0, &backer), contribution::<T>());
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,503 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Crowdfunding
+//!
+//! Funding rounds for albums and recordings, paid back out of the resulting royalty stream.
+//!
+//! ## Features
+//! - An artist (`Config::OpenOrigin`) opens a round for one of their royalty assets, with a
+//!   target amount, a deadline, and the share of the asset's royalties offered to backers. The
+//!   artist must hold at least that share in the asset's split table (`Config::RoyaltyShares`).
+//! - Until the deadline, backers contribute; their contributions are held.
+//! - Once the deadline has passed, anyone settles the round:
+//!   - if the target was met, the contributions are paid to the artist and the offered share
+//!     is moved from the artist to the round's account in the asset's split table;
+//!   - otherwise, or if the share can no longer be moved, every contribution is released.
+//! - Royalties paid to the round's account are claimed by backers pro rata to their
+//!   contribution, as they accrue.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{
+    PalletId,
+    pallet_prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{Fortitude, Precision, Preservation, Restriction},
+    },
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{
+    Perbill, Rounding, SaturatedConversion, Saturating, helpers_128bit,
+    traits::{AccountIdConversion, Zero},
+};
+
+pub type RoundId = u32;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type RoundOf<T> = Round<
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::AssetId,
+    BalanceOf<T>,
+    BlockNumberFor<T>,
+>;
+
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum RoundStatus {
+    /// Accepting contributions until the deadline, or awaiting settlement.
+    Open,
+    /// The target was met: backers hold a share of the asset's royalties.
+    Funded,
+}
+
+/// A funding round.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Round<AccountId, AssetId, Balance, BlockNumber> {
+    /// Artist the contributions are paid to.
+    pub artist: AccountId,
+    /// Royalty asset backers get a share of.
+    pub asset: AssetId,
+    pub target: Balance,
+    pub raised: Balance,
+    /// First block at which contributions are no longer accepted.
+    pub deadline: BlockNumber,
+    /// Share of the asset's royalties moved from the artist to the backers once funded.
+    pub backers_share: Perbill,
+    pub backer_count: u32,
+    pub status: RoundStatus,
+}
+
+/// The royalty split tables backers' shares are carved out of.
+pub trait RoyaltyShares<AssetId, AccountId> {
+    /// Share of `asset`'s royalties currently paid to `who`.
+    fn share_of(asset: &AssetId, who: &AccountId) -> Perbill;
+
+    /// Move `share` of `asset`'s royalties from `from` to `to`.
+    fn assign_share(
+        asset: &AssetId,
+        from: &AccountId,
+        to: &AccountId,
+        share: Perbill,
+    ) -> DispatchResult;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, AccountId> {
+    /// Create an asset whose royalties are all paid to `owner`, and return its id.
+    fn create_asset(owner: &AccountId) -> AssetId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Derives the account of each round, royalties owed to its backers are paid to.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Identifier of a royalty asset.
+        type AssetId: Parameter + Member + MaxEncodedLen;
+
+        /// The royalty split tables.
+        type RoyaltyShares: RoyaltyShares<Self::AssetId, Self::AccountId>;
+
+        /// Origin allowed to open rounds, resolving to the artist.
+        type OpenOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// Max number of backers of a round.
+        #[pallet::constant]
+        type MaxBackers: Get<u32>;
+
+        /// Smallest contribution accepted.
+        #[pallet::constant]
+        type MinContribution: Get<BalanceOf<Self>>;
+
+        /// Max number of blocks between a round's opening and its deadline.
+        #[pallet::constant]
+        type MaxFundingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Self::AccountId>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// A contribution to a round awaiting settlement.
+        Contribution,
+    }
+
+    /// Open and funded rounds. Failed rounds are removed when settled.
+    #[pallet::storage]
+    pub type Rounds<T: Config> = StorageMap<_, Twox64Concat, RoundId, RoundOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextRoundId<T: Config> = StorageValue<_, RoundId, ValueQuery>;
+
+    /// Total contribution of each backer to a round.
+    #[pallet::storage]
+    pub type Contributions<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        RoundId,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Royalties each backer of a funded round has claimed so far.
+    #[pallet::storage]
+    pub type Claimed<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        RoundId,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Royalties claimed from each funded round so far, by all its backers.
+    #[pallet::storage]
+    pub type TotalClaimed<T: Config> =
+        StorageMap<_, Twox64Concat, RoundId, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        RoundOpened {
+            round: RoundId,
+            artist: T::AccountId,
+            asset: T::AssetId,
+            target: BalanceOf<T>,
+            deadline: BlockNumberFor<T>,
+            backers_share: Perbill,
+        },
+        Contributed {
+            round: RoundId,
+            backer: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// `raised` was paid to the artist and the backers' share assigned to the round.
+        RoundFunded {
+            round: RoundId,
+            raised: BalanceOf<T>,
+        },
+        /// Every contribution was released.
+        RoundFailed { round: RoundId },
+        RoyaltiesClaimed {
+            round: RoundId,
+            backer: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        ZeroTarget,
+        /// The deadline is in the past or further than `MaxFundingPeriod`.
+        InvalidDeadline,
+        ZeroShare,
+        /// The artist's share of the asset's royalties is smaller than the offered share.
+        InsufficientShare,
+        RoundNotFound,
+        /// The round no longer accepts contributions.
+        RoundClosed,
+        ContributionTooLow,
+        TooManyBackers,
+        /// The round's deadline has not passed yet.
+        DeadlineNotReached,
+        AlreadySettled,
+        RoundNotFunded,
+        NotBacker,
+        NothingToClaim,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Open a round raising `target` until `deadline`, offering `backers_share` of
+        /// `asset`'s royalties to its backers.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::open_round())]
+        pub fn open_round(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            target: BalanceOf<T>,
+            deadline: BlockNumberFor<T>,
+            backers_share: Perbill,
+        ) -> DispatchResult {
+            let artist = T::OpenOrigin::ensure_origin(origin)?;
+
+            ensure!(!target.is_zero(), Error::<T>::ZeroTarget);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                deadline > now && deadline <= now.saturating_add(T::MaxFundingPeriod::get()),
+                Error::<T>::InvalidDeadline
+            );
+            ensure!(!backers_share.is_zero(), Error::<T>::ZeroShare);
+            ensure!(
+                T::RoyaltyShares::share_of(&asset, &artist) >= backers_share,
+                Error::<T>::InsufficientShare
+            );
+
+            let round = NextRoundId::<T>::get();
+            Rounds::<T>::insert(
+                round,
+                Round {
+                    artist: artist.clone(),
+                    asset: asset.clone(),
+                    target,
+                    raised: Zero::zero(),
+                    deadline,
+                    backers_share,
+                    backer_count: 0,
+                    status: RoundStatus::Open,
+                },
+            );
+            NextRoundId::<T>::put(round.saturating_add(1));
+
+            Self::deposit_event(Event::RoundOpened {
+                round,
+                artist,
+                asset,
+                target,
+                deadline,
+                backers_share,
+            });
+            Ok(())
+        }
+
+        /// Back `round` with `amount`, held until the round is settled.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::contribute())]
+        pub fn contribute(
+            origin: OriginFor<T>,
+            round: RoundId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                amount >= T::MinContribution::get(),
+                Error::<T>::ContributionTooLow
+            );
+            Rounds::<T>::try_mutate(round, |maybe_round| -> DispatchResult {
+                let info = maybe_round.as_mut().ok_or(Error::<T>::RoundNotFound)?;
+                ensure!(
+                    info.status == RoundStatus::Open
+                        && frame_system::Pallet::<T>::block_number() < info.deadline,
+                    Error::<T>::RoundClosed
+                );
+
+                let contribution = Contributions::<T>::get(round, &who);
+                if contribution.is_zero() {
+                    ensure!(
+                        info.backer_count < T::MaxBackers::get(),
+                        Error::<T>::TooManyBackers
+                    );
+                    info.backer_count.saturating_inc();
+                }
+
+                T::Currency::hold(&HoldReason::Contribution.into(), &who, amount)?;
+                Contributions::<T>::insert(round, &who, contribution.saturating_add(amount));
+                info.raised.saturating_accrue(amount);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::Contributed {
+                round,
+                backer: who,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Settle `round` once its deadline has passed: fund the artist if the target was met,
+        /// refund every backer otherwise.
+        #[pallet::call_index(2)]
+        #[pallet::weight(
+            T::WeightInfo::settle_funded(T::MaxBackers::get())
+                .max(T::WeightInfo::settle_failed(T::MaxBackers::get()))
+        )]
+        pub fn settle(origin: OriginFor<T>, round: RoundId) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let mut info = Rounds::<T>::get(round).ok_or(Error::<T>::RoundNotFound)?;
+            ensure!(info.status == RoundStatus::Open, Error::<T>::AlreadySettled);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= info.deadline,
+                Error::<T>::DeadlineNotReached
+            );
+
+            // The artist may have given up part of their share since the round opened.
+            let funded = info.raised >= info.target
+                && frame_support::storage::with_storage_layer(|| {
+                    T::RoyaltyShares::assign_share(
+                        &info.asset,
+                        &info.artist,
+                        &Self::round_account(round),
+                        info.backers_share,
+                    )
+                })
+                .is_ok();
+
+            if !funded {
+                for (backer, amount) in Contributions::<T>::drain_prefix(round) {
+                    T::Currency::release(
+                        &HoldReason::Contribution.into(),
+                        &backer,
+                        amount,
+                        Precision::BestEffort,
+                    )?;
+                }
+                Rounds::<T>::remove(round);
+
+                Self::deposit_event(Event::RoundFailed { round });
+                return Ok(Some(T::WeightInfo::settle_failed(info.backer_count)).into());
+            }
+
+            for (backer, amount) in Contributions::<T>::iter_prefix(round) {
+                T::Currency::transfer_on_hold(
+                    &HoldReason::Contribution.into(),
+                    &backer,
+                    &info.artist,
+                    amount,
+                    Precision::Exact,
+                    Restriction::Free,
+                    Fortitude::Polite,
+                )?;
+            }
+            info.status = RoundStatus::Funded;
+            Rounds::<T>::insert(round, &info);
+
+            Self::deposit_event(Event::RoundFunded {
+                round,
+                raised: info.raised,
+            });
+            Ok(Some(T::WeightInfo::settle_funded(info.backer_count)).into())
+        }
+
+        /// Claim the caller's part of the royalties paid to funded `round` so far.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::claim())]
+        pub fn claim(origin: OriginFor<T>, round: RoundId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let info = Rounds::<T>::get(round).ok_or(Error::<T>::RoundNotFound)?;
+            ensure!(
+                info.status == RoundStatus::Funded,
+                Error::<T>::RoundNotFunded
+            );
+            ensure!(
+                Contributions::<T>::contains_key(round, &who),
+                Error::<T>::NotBacker
+            );
+
+            let amount = Self::claimable(round, &who);
+            ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+            T::Currency::transfer(
+                &Self::round_account(round),
+                &who,
+                amount,
+                Preservation::Expendable,
+            )?;
+            Claimed::<T>::mutate(round, &who, |claimed| claimed.saturating_accrue(amount));
+            TotalClaimed::<T>::mutate(round, |total| total.saturating_accrue(amount));
+
+            Self::deposit_event(Event::RoyaltiesClaimed {
+                round,
+                backer: who,
+                amount,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Account of `round`, holding the royalties owed to its backers until they claim them.
+        pub fn round_account(round: RoundId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(round)
+        }
+
+        /// Royalties `backer` can claim from `round` right now.
+        ///
+        /// Each backer is entitled to their pro-rata part of every royalty the round received,
+        /// less what they already claimed.
+        pub fn claimable(round: RoundId, backer: &T::AccountId) -> BalanceOf<T> {
+            let Some(info) = Rounds::<T>::get(round) else {
+                return Zero::zero();
+            };
+            if info.status != RoundStatus::Funded {
+                return Zero::zero();
+            }
+
+            let received = T::Currency::balance(&Self::round_account(round))
+                .saturating_add(TotalClaimed::<T>::get(round));
+            let entitled = helpers_128bit::multiply_by_rational_with_rounding(
+                received.saturated_into(),
+                Contributions::<T>::get(round, backer).saturated_into(),
+                info.raised.saturated_into(),
+                Rounding::Down,
+            )
+            .map(BalanceOf::<T>::saturated_from)
+            .unwrap_or_default();
+            entitled.saturating_sub(Claimed::<T>::get(round, backer))
+        }
+    }
+}
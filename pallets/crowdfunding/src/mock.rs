@@ -0,0 +1,165 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_crowdfunding;
+use frame_support::{
+    PalletId, derive_impl, pallet_prelude::DispatchResult, parameter_types,
+    sp_runtime::BuildStorage, storage::unhashed,
+};
+use frame_system::EnsureSigned;
+use parity_scale_codec::Encode;
+use sp_runtime::{DispatchError, Perbill, Saturating};
+
+pub type Balance = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type Crowdfunding = pallet_crowdfunding;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+}
+
+pub const MIN_CONTRIBUTION: Balance = 10;
+pub const MAX_FUNDING_PERIOD: u64 = 100;
+
+parameter_types! {
+    pub const CrowdfundingPalletId: PalletId = PalletId(*b"py/crwdf");
+    pub const MaxBackers: u32 = 3;
+    pub const MinContribution: Balance = MIN_CONTRIBUTION;
+    pub const MaxFundingPeriod: u64 = MAX_FUNDING_PERIOD;
+}
+
+/// Royalty shares, kept in the test externalities so that each test starts from genesis.
+pub struct MockShares;
+
+impl MockShares {
+    fn key(asset: u32, who: u64) -> Vec<u8> {
+        (b"mock/royalty-share", asset, who).encode()
+    }
+
+    pub fn set_share(asset: u32, who: u64, percent: u32) {
+        unhashed::put(&Self::key(asset, who), &Perbill::from_percent(percent));
+    }
+}
+
+impl crate::RoyaltyShares<u32, u64> for MockShares {
+    fn share_of(asset: &u32, who: &u64) -> Perbill {
+        unhashed::get(&Self::key(*asset, *who)).unwrap_or_default()
+    }
+
+    fn assign_share(asset: &u32, from: &u64, to: &u64, share: Perbill) -> DispatchResult {
+        let remaining = Self::share_of(asset, from)
+            .deconstruct()
+            .checked_sub(share.deconstruct())
+            .ok_or(DispatchError::Unavailable)?;
+        unhashed::put(&Self::key(*asset, *from), &Perbill::from_parts(remaining));
+        let assigned = Self::share_of(asset, to).saturating_add(share);
+        unhashed::put(&Self::key(*asset, *to), &assigned);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u32, u64> for MockShares {
+    fn create_asset(owner: &u64) -> u32 {
+        Self::set_share(0, *owner, 100);
+        0
+    }
+}
+
+impl pallet_crowdfunding::Config for Test {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type PalletId = CrowdfundingPalletId;
+    type AssetId = u32;
+    type RoyaltyShares = MockShares;
+    type OpenOrigin = EnsureSigned<u64>;
+    type MaxBackers = MaxBackers;
+    type MinContribution = MinContribution;
+    type MaxFundingPeriod = MaxFundingPeriod;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockShares;
+}
+
+pub const ARTIST: u64 = 1;
+pub const ALICE: u64 = 2;
+pub const BOB: u64 = 3;
+pub const CHARLIE: u64 = 4;
+pub const DAVE: u64 = 5;
+/// Pays royalties to the rounds' accounts.
+pub const PAYER: u64 = 6;
+
+/// `ARTIST` receives 80% of its royalties.
+pub const ALBUM: u32 = 1;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ARTIST, 100),
+            (ALICE, 1_000),
+            (BOB, 1_000),
+            (CHARLIE, 1_000),
+            (DAVE, 1_000),
+            (PAYER, 10_000),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        MockShares::set_share(ALBUM, ARTIST, 80);
+    });
+    ext
+}
@@ -0,0 +1,276 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Claimed, Contributions, Error, Event, HoldReason, Pallet, RoundStatus, Rounds, RoyaltyShares,
+    TotalClaimed, mock::*,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{
+        fungible::{Inspect, InspectHold, Mutate},
+        tokens::Preservation,
+    },
+};
+use sp_runtime::Perbill;
+
+const TARGET: Balance = 400;
+const DEADLINE: u64 = 50;
+
+fn open_round() {
+    assert_ok!(Crowdfunding::open_round(
+        RuntimeOrigin::signed(ARTIST),
+        ALBUM,
+        TARGET,
+        DEADLINE,
+        Perbill::from_percent(30)
+    ));
+}
+
+fn contribute(who: u64, amount: Balance) -> sp_runtime::DispatchResult {
+    Crowdfunding::contribute(RuntimeOrigin::signed(who), 0, amount)
+}
+
+fn on_hold(who: u64) -> Balance {
+    Balances::balance_on_hold(&HoldReason::Contribution.into(), &who)
+}
+
+fn settle() -> sp_runtime::DispatchResult {
+    Crowdfunding::settle(RuntimeOrigin::signed(CHARLIE), 0)
+        .map(|_| ())
+        .map_err(|e| e.error)
+}
+
+fn claim(who: u64) -> sp_runtime::DispatchResult {
+    Crowdfunding::claim(RuntimeOrigin::signed(who), 0)
+}
+
+#[test]
+fn open_round_validates_terms() {
+    new_test_ext().execute_with(|| {
+        let open = |target, deadline, percent| {
+            Crowdfunding::open_round(
+                RuntimeOrigin::signed(ARTIST),
+                ALBUM,
+                target,
+                deadline,
+                Perbill::from_percent(percent),
+            )
+        };
+        assert_noop!(open(0, DEADLINE, 30), Error::<Test>::ZeroTarget);
+        assert_noop!(open(TARGET, 1, 30), Error::<Test>::InvalidDeadline);
+        assert_noop!(
+            open(TARGET, 2 + MAX_FUNDING_PERIOD, 30),
+            Error::<Test>::InvalidDeadline
+        );
+        assert_noop!(open(TARGET, DEADLINE, 0), Error::<Test>::ZeroShare);
+        // The artist only holds 80% of the album's royalties.
+        assert_noop!(open(TARGET, DEADLINE, 81), Error::<Test>::InsufficientShare);
+
+        assert_ok!(open(TARGET, 1 + MAX_FUNDING_PERIOD, 80));
+        System::assert_last_event(
+            Event::RoundOpened {
+                round: 0,
+                artist: ARTIST,
+                asset: ALBUM,
+                target: TARGET,
+                deadline: 1 + MAX_FUNDING_PERIOD,
+                backers_share: Perbill::from_percent(80),
+            }
+            .into(),
+        );
+        let round = Rounds::<Test>::get(0).unwrap();
+        assert_eq!(round.raised, 0);
+        assert_eq!(round.status, RoundStatus::Open);
+
+        open_round();
+        assert!(Rounds::<Test>::contains_key(1));
+    });
+}
+
+#[test]
+fn contributions_are_held_until_the_deadline() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(contribute(ALICE, 100), Error::<Test>::RoundNotFound);
+        open_round();
+        assert_noop!(
+            contribute(ALICE, MIN_CONTRIBUTION - 1),
+            Error::<Test>::ContributionTooLow
+        );
+
+        assert_ok!(contribute(ALICE, 100));
+        System::assert_last_event(
+            Event::Contributed {
+                round: 0,
+                backer: ALICE,
+                amount: 100,
+            }
+            .into(),
+        );
+        assert_ok!(contribute(ALICE, 50));
+        assert_eq!(on_hold(ALICE), 150);
+        assert_eq!(Contributions::<Test>::get(0, ALICE), 150);
+
+        assert_ok!(contribute(BOB, 10));
+        assert_ok!(contribute(CHARLIE, 10));
+        assert_noop!(contribute(DAVE, 10), Error::<Test>::TooManyBackers);
+        // Existing backers can still top up.
+        assert_ok!(contribute(BOB, 10));
+
+        let round = Rounds::<Test>::get(0).unwrap();
+        assert_eq!(round.raised, 180);
+        assert_eq!(round.backer_count, 3);
+
+        System::set_block_number(DEADLINE);
+        assert_noop!(contribute(ALICE, 100), Error::<Test>::RoundClosed);
+    });
+}
+
+#[test]
+fn settle_waits_for_the_deadline() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(settle(), Error::<Test>::RoundNotFound);
+        open_round();
+        System::set_block_number(DEADLINE - 1);
+        assert_noop!(settle(), Error::<Test>::DeadlineNotReached);
+    });
+}
+
+#[test]
+fn funded_round_pays_the_artist_and_assigns_the_backers_share() {
+    new_test_ext().execute_with(|| {
+        open_round();
+        assert_ok!(contribute(ALICE, 300));
+        assert_ok!(contribute(BOB, 150));
+        System::set_block_number(DEADLINE);
+
+        assert_ok!(settle());
+        System::assert_last_event(
+            Event::RoundFunded {
+                round: 0,
+                raised: 450,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::balance(&ARTIST), 100 + 450);
+        assert_eq!(Balances::total_balance(&ALICE), 700);
+        assert_eq!(Balances::total_balance(&BOB), 850);
+        assert_eq!(on_hold(ALICE), 0);
+
+        let account = Pallet::<Test>::round_account(0);
+        assert_eq!(
+            MockShares::share_of(&ALBUM, &ARTIST),
+            Perbill::from_percent(50)
+        );
+        assert_eq!(
+            MockShares::share_of(&ALBUM, &account),
+            Perbill::from_percent(30)
+        );
+        assert_eq!(Rounds::<Test>::get(0).unwrap().status, RoundStatus::Funded);
+
+        assert_noop!(settle(), Error::<Test>::AlreadySettled);
+        assert_noop!(contribute(ALICE, 100), Error::<Test>::RoundClosed);
+    });
+}
+
+#[test]
+fn missed_target_refunds_every_backer() {
+    new_test_ext().execute_with(|| {
+        open_round();
+        assert_ok!(contribute(ALICE, 300));
+        assert_ok!(contribute(BOB, 99));
+        System::set_block_number(DEADLINE);
+
+        assert_ok!(settle());
+        System::assert_last_event(Event::RoundFailed { round: 0 }.into());
+        assert_eq!(Balances::balance(&ALICE), 1_000);
+        assert_eq!(Balances::balance(&BOB), 1_000);
+        assert_eq!(Balances::balance(&ARTIST), 100);
+        assert!(!Rounds::<Test>::contains_key(0));
+        assert!(!Contributions::<Test>::contains_key(0, ALICE));
+        assert_eq!(
+            MockShares::share_of(&ALBUM, &ARTIST),
+            Perbill::from_percent(80)
+        );
+    });
+}
+
+#[test]
+fn round_fails_if_the_artist_no_longer_holds_the_share() {
+    new_test_ext().execute_with(|| {
+        open_round();
+        assert_ok!(contribute(ALICE, TARGET));
+        MockShares::set_share(ALBUM, ARTIST, 20);
+        System::set_block_number(DEADLINE);
+
+        assert_ok!(settle());
+        System::assert_last_event(Event::RoundFailed { round: 0 }.into());
+        assert_eq!(Balances::balance(&ALICE), 1_000);
+        assert_eq!(
+            MockShares::share_of(&ALBUM, &Pallet::<Test>::round_account(0)),
+            Perbill::zero()
+        );
+    });
+}
+
+#[test]
+fn backers_claim_royalties_pro_rata() {
+    new_test_ext().execute_with(|| {
+        open_round();
+        assert_ok!(contribute(ALICE, 300));
+        assert_ok!(contribute(BOB, 100));
+        assert_noop!(claim(ALICE), Error::<Test>::RoundNotFunded);
+        System::set_block_number(DEADLINE);
+        assert_ok!(settle());
+
+        let account = Pallet::<Test>::round_account(0);
+        let pay_royalties = |amount| {
+            assert_ok!(Balances::transfer(
+                &PAYER,
+                &account,
+                amount,
+                Preservation::Expendable
+            ));
+        };
+        assert_noop!(claim(ALICE), Error::<Test>::NothingToClaim);
+        assert_noop!(claim(CHARLIE), Error::<Test>::NotBacker);
+
+        pay_royalties(100);
+        assert_eq!(Pallet::<Test>::claimable(0, &ALICE), 75);
+        assert_ok!(claim(ALICE));
+        System::assert_last_event(
+            Event::RoyaltiesClaimed {
+                round: 0,
+                backer: ALICE,
+                amount: 75,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::balance(&ALICE), 700 + 75);
+        assert_noop!(claim(ALICE), Error::<Test>::NothingToClaim);
+
+        // Later royalties add up with what was left unclaimed.
+        pay_royalties(40);
+        assert_ok!(claim(ALICE));
+        assert_eq!(Claimed::<Test>::get(0, ALICE), 105);
+        assert_ok!(claim(BOB));
+        assert_eq!(Claimed::<Test>::get(0, BOB), 35);
+        assert_eq!(TotalClaimed::<Test>::get(0), 140);
+        assert_eq!(Balances::balance(&account), 0);
+    });
+}
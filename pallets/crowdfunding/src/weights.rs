@@ -0,0 +1,85 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_crowdfunding.
+pub trait WeightInfo {
+    fn open_round() -> Weight;
+    fn contribute() -> Weight;
+    fn settle_funded(b: u32) -> Weight;
+    fn settle_failed(b: u32) -> Weight;
+    fn claim() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `RoyaltyShares` split table (r:1 w:0)
+    /// Storage: `Crowdfunding::NextRoundId` (r:1 w:1)
+    /// Storage: `Crowdfunding::Rounds` (r:0 w:1)
+    fn open_round() -> Weight {
+        Weight::from_parts(25_000_000, 3_600)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Crowdfunding::Rounds` (r:1 w:1)
+    /// Storage: `Crowdfunding::Contributions` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn contribute() -> Weight {
+        Weight::from_parts(45_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Crowdfunding::Rounds` (r:1 w:1)
+    /// Storage: `RoyaltyShares` split table (r:1 w:1)
+    /// Storage: `Crowdfunding::Contributions` (r:b w:0)
+    /// Storage: `Balances::Holds` (r:b w:b)
+    /// Storage: `System::Account` (r:b+1 w:b+1)
+    /// The range of component `b` is `[1, 500]`.
+    fn settle_funded(b: u32) -> Weight {
+        Weight::from_parts(45_000_000, 6_000)
+            .saturating_add(Weight::from_parts(40_000_000, 2_600).saturating_mul(b.into()))
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().reads((3_u64).saturating_mul(b.into())))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+            .saturating_add(ParityDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+    }
+    /// Storage: `Crowdfunding::Rounds` (r:1 w:1)
+    /// Storage: `Crowdfunding::Contributions` (r:b w:b)
+    /// Storage: `Balances::Holds` (r:b w:b)
+    /// Storage: `System::Account` (r:b w:b)
+    /// The range of component `b` is `[1, 500]`.
+    fn settle_failed(b: u32) -> Weight {
+        Weight::from_parts(25_000_000, 3_600)
+            .saturating_add(Weight::from_parts(30_000_000, 2_600).saturating_mul(b.into()))
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().reads((3_u64).saturating_mul(b.into())))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+            .saturating_add(ParityDbWeight::get().writes((3_u64).saturating_mul(b.into())))
+    }
+    /// Storage: `Crowdfunding::Rounds` (r:1 w:0)
+    /// Storage: `Crowdfunding::Contributions` (r:1 w:0)
+    /// Storage: `Crowdfunding::Claimed` (r:1 w:1)
+    /// Storage: `Crowdfunding::TotalClaimed` (r:1 w:1)
+    /// Storage: `System::Account` (r:2 w:2)
+    fn claim() -> Weight {
+        Weight::from_parts(50_000_000, 6_200)
+            .saturating_add(ParityDbWeight::get().reads(6_u64))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+    }
+}
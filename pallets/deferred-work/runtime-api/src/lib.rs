@@ -0,0 +1,31 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API reporting the state of the `pallet-deferred-work` queue, e.g. for monitoring
+//! its depth.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_deferred_work::QueueStatus;
+
+sp_api::decl_runtime_apis! {
+    pub trait DeferredWorkApi {
+        /// Depth and bounds of the deferred work queue.
+        fn queue_status() -> QueueStatus;
+    }
+}
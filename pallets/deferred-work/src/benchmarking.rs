@@ -0,0 +1,67 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+fn fill_queue<T: Config>(n: u32) {
+    for _ in 0..n {
+        Pallet::<T>::push(T::BenchmarkHelper::noop_task()).expect("queue has room");
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn enqueue() {
+        fill_queue::<T>(T::MaxQueued::get() - 1);
+        let task = Box::new(T::BenchmarkHelper::noop_task());
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, task);
+
+        assert_eq!(QueueDepth::<T>::get(), T::MaxQueued::get());
+    }
+
+    #[benchmark]
+    fn cancel() {
+        fill_queue::<T>(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, 0);
+
+        assert!(!Tasks::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn run_step() {
+        fill_queue::<T>(1);
+
+        #[block]
+        {
+            Pallet::<T>::process_queue(T::BlockBudget::get());
+        }
+
+        assert_eq!(QueueDepth::<T>::get(), 0);
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,305 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Deferred Work
+//!
+//! A queue of heavy operations (catalog merges, bulk reassignments, ...) carried out in steps
+//! across blocks, in the weight left over by regular transactions.
+//!
+//! ## Features
+//! - Work is described by the runtime's `Config::Task`, usually an enum aggregating the tasks
+//!   of the pallets sharing the queue. A task runs one bounded step at a time and hands back
+//!   the work left to do until it is done.
+//! - Pallets queue tasks through [`DeferWork`]; `EnqueueOrigin` may also queue and cancel
+//!   tasks directly.
+//! - Tasks are processed in `on_idle`, first in first out, within at most
+//!   `Config::BlockBudget` per block. Each step is atomic: a failing step is reverted and its
+//!   task dropped.
+//! - Backpressure: the queue holds at most `Config::MaxQueued` tasks, and
+//!   [`DeferWork::is_congested`] tells callers to hold off new heavy work once
+//!   `Config::CongestionThreshold` tasks are pending.
+//! - [`Pallet::queue_status`] reports the queue depth, and the
+//!   `pallet-deferred-work-runtime-api` crate exposes it to clients.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use alloc::boxed::Box;
+use frame_support::{pallet_prelude::*, storage::with_storage_layer};
+use frame_system::pallet_prelude::*;
+use sp_runtime::Saturating;
+
+pub type TaskId = u32;
+
+/// A heavy operation, carried out in bounded steps.
+pub trait DeferredTask: Parameter + Member + MaxEncodedLen {
+    /// Upper bound on the weight of the next [`DeferredTask::step`].
+    fn step_weight(&self) -> Weight;
+
+    /// Carry out the next step, returning the work left to do, or `None` once done.
+    ///
+    /// On error the storage changes of the step are reverted and the task is dropped.
+    fn step(self) -> Result<Option<Self>, DispatchError>;
+}
+
+/// Queue of deferred work, as seen by the pallets sharing it.
+pub trait DeferWork<Task> {
+    /// Queue `task` behind the pending ones.
+    fn defer(task: Task) -> Result<TaskId, DispatchError>;
+
+    /// Whether enough work is pending that callers should hold off queueing more.
+    fn is_congested() -> bool;
+}
+
+impl<Task> DeferWork<Task> for () {
+    fn defer(_task: Task) -> Result<TaskId, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn is_congested() -> bool {
+        false
+    }
+}
+
+/// Snapshot of the queue, for clients.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct QueueStatus {
+    /// Number of pending tasks.
+    pub depth: u32,
+    /// Max number of pending tasks.
+    pub capacity: u32,
+    /// Depth from which the queue reports congestion.
+    pub congestion_threshold: u32,
+    /// Id `on_idle` resumes from, if any task is pending.
+    pub head: Option<TaskId>,
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Task> {
+    /// A task completing in a single step of negligible weight.
+    fn noop_task() -> Task;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Deferred work of the pallets sharing the queue.
+        type Task: DeferredTask;
+
+        /// Origin allowed to queue and cancel tasks directly.
+        type EnqueueOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Max number of pending tasks.
+        #[pallet::constant]
+        type MaxQueued: Get<u32>;
+
+        /// Number of pending tasks from which the queue reports congestion.
+        #[pallet::constant]
+        type CongestionThreshold: Get<u32>;
+
+        /// Max weight spent on deferred work per block.
+        #[pallet::constant]
+        type BlockBudget: Get<Weight>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::Task>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Pending tasks, keyed by id.
+    #[pallet::storage]
+    pub type Tasks<T: Config> = StorageMap<_, Twox64Concat, TaskId, T::Task, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextTaskId<T: Config> = StorageValue<_, TaskId, ValueQuery>;
+
+    /// The task `on_idle` is working on. Tasks are processed in id order, so every pending
+    /// task has an id in `[TaskHead, NextTaskId)`; ids in that range without a task were
+    /// cancelled.
+    #[pallet::storage]
+    pub type TaskHead<T: Config> = StorageValue<_, TaskId, ValueQuery>;
+
+    /// Number of pending tasks.
+    #[pallet::storage]
+    pub type QueueDepth<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        TaskQueued {
+            id: TaskId,
+        },
+        TaskCompleted {
+            id: TaskId,
+        },
+        /// A step of the task failed; the task was dropped.
+        TaskFailed {
+            id: TaskId,
+            error: DispatchError,
+        },
+        TaskCancelled {
+            id: TaskId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        QueueFull,
+        /// A step of the task does not fit in the per-block budget.
+        TaskTooHeavy,
+        TaskNotFound,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::process_queue(remaining_weight.min(T::BlockBudget::get()))
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Queue `task` behind the pending ones.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::enqueue())]
+        pub fn enqueue(origin: OriginFor<T>, task: Box<T::Task>) -> DispatchResult {
+            T::EnqueueOrigin::ensure_origin(origin)?;
+            Self::push(*task)?;
+            Ok(())
+        }
+
+        /// Drop a pending task. Steps already carried out are not reverted.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::cancel())]
+        pub fn cancel(origin: OriginFor<T>, id: TaskId) -> DispatchResult {
+            T::EnqueueOrigin::ensure_origin(origin)?;
+
+            ensure!(Tasks::<T>::take(id).is_some(), Error::<T>::TaskNotFound);
+            QueueDepth::<T>::mutate(|depth| depth.saturating_dec());
+            Self::deposit_event(Event::TaskCancelled { id });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        pub(crate) fn push(task: T::Task) -> Result<TaskId, DispatchError> {
+            ensure!(
+                T::WeightInfo::run_step()
+                    .saturating_add(task.step_weight())
+                    .all_lte(T::BlockBudget::get()),
+                Error::<T>::TaskTooHeavy
+            );
+            let depth = QueueDepth::<T>::get();
+            ensure!(depth < T::MaxQueued::get(), Error::<T>::QueueFull);
+
+            let id = NextTaskId::<T>::get();
+            Tasks::<T>::insert(id, task);
+            NextTaskId::<T>::put(id.saturating_add(1));
+            QueueDepth::<T>::put(depth.saturating_add(1));
+            Self::deposit_event(Event::TaskQueued { id });
+            Ok(id)
+        }
+
+        /// Work through the queue within `budget`, returning the weight used.
+        pub(crate) fn process_queue(budget: Weight) -> Weight {
+            let skip_weight = T::DbWeight::get().reads(1);
+            let mut used = T::DbWeight::get().reads(2);
+            if budget.any_lt(used) {
+                return Weight::zero();
+            }
+
+            let mut head = TaskHead::<T>::get();
+            let next = NextTaskId::<T>::get();
+            while head < next {
+                let Some(task) = Tasks::<T>::get(head) else {
+                    // Cancelled.
+                    if budget.any_lt(used.saturating_add(skip_weight)) {
+                        break;
+                    }
+                    used.saturating_accrue(skip_weight);
+                    head.saturating_inc();
+                    continue;
+                };
+
+                let step_weight = T::WeightInfo::run_step().saturating_add(task.step_weight());
+                if budget.any_lt(used.saturating_add(step_weight)) {
+                    break;
+                }
+                used.saturating_accrue(step_weight);
+
+                match with_storage_layer(|| task.step()) {
+                    Ok(Some(rest)) => {
+                        Tasks::<T>::insert(head, rest);
+                        continue;
+                    }
+                    Ok(None) => Self::deposit_event(Event::TaskCompleted { id: head }),
+                    Err(error) => Self::deposit_event(Event::TaskFailed { id: head, error }),
+                }
+                Tasks::<T>::remove(head);
+                QueueDepth::<T>::mutate(|depth| depth.saturating_dec());
+                head.saturating_inc();
+            }
+
+            TaskHead::<T>::put(head);
+            used.saturating_add(T::DbWeight::get().writes(1))
+        }
+
+        /// Current depth and bounds of the queue.
+        pub fn queue_status() -> QueueStatus {
+            let depth = QueueDepth::<T>::get();
+            QueueStatus {
+                depth,
+                capacity: T::MaxQueued::get(),
+                congestion_threshold: T::CongestionThreshold::get(),
+                head: (depth > 0).then(TaskHead::<T>::get),
+            }
+        }
+    }
+}
+
+impl<T: Config, Task: Into<T::Task>> DeferWork<Task> for Pallet<T> {
+    fn defer(task: Task) -> Result<TaskId, DispatchError> {
+        Self::push(task.into())
+    }
+
+    fn is_congested() -> bool {
+        QueueDepth::<T>::get() >= T::CongestionThreshold::get()
+    }
+}
@@ -0,0 +1,139 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_deferred_work, DeferredTask, WeightInfo};
+use frame_support::{
+    derive_impl, pallet_prelude::*, parameter_types, sp_runtime::BuildStorage, storage::unhashed,
+};
+use frame_system::EnsureRoot;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type DeferredWork = pallet_deferred_work;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+/// Weight of a step of the mock tasks, `Heavy` aside.
+pub const STEP_WEIGHT: u64 = 100;
+
+const STEPS_KEY: &[u8] = b"steps";
+
+/// Number of steps carried out so far, failed ones aside.
+pub fn steps_run() -> u32 {
+    unhashed::get_or_default(STEPS_KEY)
+}
+
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum MockTask {
+    /// Takes the given number of steps.
+    Steps(u32),
+    /// Fails on its first step, after writing to storage.
+    Fail,
+    /// A step that never fits in a block.
+    Heavy,
+}
+
+impl DeferredTask for MockTask {
+    fn step_weight(&self) -> Weight {
+        match self {
+            MockTask::Heavy => Weight::MAX,
+            _ => Weight::from_parts(STEP_WEIGHT, 0),
+        }
+    }
+
+    fn step(self) -> Result<Option<Self>, DispatchError> {
+        unhashed::put(STEPS_KEY, &(steps_run() + 1));
+        match self {
+            MockTask::Steps(n) if n > 1 => Ok(Some(MockTask::Steps(n - 1))),
+            MockTask::Steps(_) | MockTask::Heavy => Ok(None),
+            MockTask::Fail => Err(DispatchError::Unavailable),
+        }
+    }
+}
+
+/// Weight of one step of a mock task, overhead included.
+pub fn step() -> Weight {
+    <() as WeightInfo>::run_step().saturating_add(Weight::from_parts(STEP_WEIGHT, 0))
+}
+
+parameter_types! {
+    pub const MaxQueued: u32 = 4;
+    pub const CongestionThreshold: u32 = 2;
+    pub BlockBudget: Weight = step().saturating_mul(3);
+}
+
+impl pallet_deferred_work::Config for Test {
+    type Task = MockTask;
+    type EnqueueOrigin = EnsureRoot<Self::AccountId>;
+    type MaxQueued = MaxQueued;
+    type CongestionThreshold = CongestionThreshold;
+    type BlockBudget = BlockBudget;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_deferred_work::BenchmarkHelper<MockTask> for () {
+    fn noop_task() -> MockTask {
+        MockTask::Steps(1)
+    }
+}
+
+pub(crate) fn run_to_block(n: u64) {
+    use frame_support::traits::Hooks;
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        DeferredWork::on_idle(next, Weight::MAX);
+    }
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
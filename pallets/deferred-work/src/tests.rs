@@ -0,0 +1,211 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    DeferWork, Error, Event, NextTaskId, QueueDepth, QueueStatus, TaskHead, Tasks, mock::*,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use sp_runtime::DispatchError;
+
+fn enqueue(task: MockTask) {
+    assert_ok!(DeferredWork::enqueue(RuntimeOrigin::root(), Box::new(task)));
+}
+
+#[test]
+fn enqueue_requires_enqueue_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            DeferredWork::enqueue(RuntimeOrigin::signed(1), Box::new(MockTask::Steps(1))),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn enqueue_queues_task() {
+    new_test_ext().execute_with(|| {
+        enqueue(MockTask::Steps(2));
+
+        assert_eq!(Tasks::<Test>::get(0), Some(MockTask::Steps(2)));
+        assert_eq!(NextTaskId::<Test>::get(), 1);
+        assert_eq!(QueueDepth::<Test>::get(), 1);
+        System::assert_last_event(Event::TaskQueued { id: 0 }.into());
+    });
+}
+
+#[test]
+fn enqueue_fails_when_queue_full() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..MaxQueued::get() {
+            enqueue(MockTask::Steps(1));
+        }
+
+        assert_noop!(
+            DeferredWork::enqueue(RuntimeOrigin::root(), Box::new(MockTask::Steps(1))),
+            Error::<Test>::QueueFull
+        );
+    });
+}
+
+#[test]
+fn enqueue_rejects_steps_over_budget() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            DeferredWork::enqueue(RuntimeOrigin::root(), Box::new(MockTask::Heavy)),
+            Error::<Test>::TaskTooHeavy
+        );
+    });
+}
+
+#[test]
+fn on_idle_runs_steps_within_block_budget() {
+    new_test_ext().execute_with(|| {
+        enqueue(MockTask::Steps(5));
+
+        run_to_block(2);
+        assert_eq!(steps_run(), 3);
+        assert_eq!(Tasks::<Test>::get(0), Some(MockTask::Steps(2)));
+
+        run_to_block(3);
+        assert_eq!(steps_run(), 5);
+        assert_eq!(Tasks::<Test>::get(0), None);
+        assert_eq!(QueueDepth::<Test>::get(), 0);
+        assert_eq!(TaskHead::<Test>::get(), 1);
+        System::assert_last_event(Event::TaskCompleted { id: 0 }.into());
+    });
+}
+
+#[test]
+fn on_idle_stays_within_remaining_weight() {
+    new_test_ext().execute_with(|| {
+        enqueue(MockTask::Steps(5));
+
+        let used = DeferredWork::on_idle(1, step() + Weight::from_parts(1_000, 0));
+        assert_eq!(steps_run(), 1);
+        assert!(used.all_lte(step() + Weight::from_parts(1_000, 0)));
+
+        assert_eq!(DeferredWork::on_idle(1, Weight::zero()), Weight::zero());
+        assert_eq!(steps_run(), 1);
+    });
+}
+
+#[test]
+fn on_idle_processes_tasks_in_order() {
+    new_test_ext().execute_with(|| {
+        enqueue(MockTask::Steps(2));
+        enqueue(MockTask::Steps(2));
+
+        run_to_block(2);
+        assert_eq!(Tasks::<Test>::get(0), None);
+        assert_eq!(Tasks::<Test>::get(1), Some(MockTask::Steps(1)));
+        System::assert_has_event(Event::TaskCompleted { id: 0 }.into());
+    });
+}
+
+#[test]
+fn failed_step_is_reverted_and_task_dropped() {
+    new_test_ext().execute_with(|| {
+        enqueue(MockTask::Fail);
+        enqueue(MockTask::Steps(1));
+
+        run_to_block(2);
+
+        // Only the second task's step is left in storage.
+        assert_eq!(steps_run(), 1);
+        assert_eq!(QueueDepth::<Test>::get(), 0);
+        System::assert_has_event(
+            Event::TaskFailed {
+                id: 0,
+                error: DispatchError::Unavailable,
+            }
+            .into(),
+        );
+        System::assert_last_event(Event::TaskCompleted { id: 1 }.into());
+    });
+}
+
+#[test]
+fn cancel_drops_pending_task() {
+    new_test_ext().execute_with(|| {
+        enqueue(MockTask::Steps(1));
+        enqueue(MockTask::Steps(1));
+
+        assert_noop!(
+            DeferredWork::cancel(RuntimeOrigin::signed(1), 0),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(DeferredWork::cancel(RuntimeOrigin::root(), 0));
+        System::assert_last_event(Event::TaskCancelled { id: 0 }.into());
+        assert_eq!(QueueDepth::<Test>::get(), 1);
+        assert_noop!(
+            DeferredWork::cancel(RuntimeOrigin::root(), 0),
+            Error::<Test>::TaskNotFound
+        );
+
+        run_to_block(2);
+        assert_eq!(steps_run(), 1);
+        assert_eq!(TaskHead::<Test>::get(), 2);
+        System::assert_last_event(Event::TaskCompleted { id: 1 }.into());
+    });
+}
+
+#[test]
+fn defer_applies_backpressure() {
+    new_test_ext().execute_with(|| {
+        assert!(!<DeferredWork as DeferWork<MockTask>>::is_congested());
+
+        assert_eq!(DeferredWork::defer(MockTask::Steps(1)), Ok(0));
+        assert_eq!(DeferredWork::defer(MockTask::Steps(1)), Ok(1));
+        assert!(<DeferredWork as DeferWork<MockTask>>::is_congested());
+
+        assert_ok!(DeferredWork::defer(MockTask::Steps(1)));
+        assert_ok!(DeferredWork::defer(MockTask::Steps(1)));
+        assert_eq!(
+            DeferredWork::defer(MockTask::Steps(1)),
+            Err(Error::<Test>::QueueFull.into())
+        );
+    });
+}
+
+#[test]
+fn queue_status_reports_depth() {
+    new_test_ext().execute_with(|| {
+        let empty = QueueStatus {
+            depth: 0,
+            capacity: MaxQueued::get(),
+            congestion_threshold: CongestionThreshold::get(),
+            head: None,
+        };
+        assert_eq!(DeferredWork::queue_status(), empty);
+
+        enqueue(MockTask::Steps(4));
+        enqueue(MockTask::Steps(1));
+        run_to_block(2);
+        assert_eq!(
+            DeferredWork::queue_status(),
+            QueueStatus {
+                depth: 2,
+                head: Some(0),
+                ..empty.clone()
+            }
+        );
+
+        run_to_block(3);
+        assert_eq!(DeferredWork::queue_status(), empty);
+    });
+}
@@ -0,0 +1,147 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use alloc::vec;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn tranche<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance() * 10u32.into()
+}
+
+/// Has a funded payer open escrow 0 with `m` milestones, returning the payer and the payee.
+fn setup_escrow<T: Config>(m: u32) -> Result<(T::AccountId, T::AccountId), BenchmarkError> {
+    let payer: T::AccountId = account("payer", 0, SEED);
+    let payee: T::AccountId = account("payee", 0, SEED);
+    T::Currency::set_balance(&payer, tranche::<T>() * (m + 1).into());
+    T::Currency::set_balance(&payee, T::Currency::minimum_balance());
+    Pallet::<T>::create(
+        RawOrigin::Signed(payer.clone()).into(),
+        payee.clone(),
+        milestones::<T>(m),
+    )?;
+    Ok((payer, payee))
+}
+
+fn milestones<T: Config>(
+    m: u32,
+) -> BoundedVec<(BalanceOf<T>, BlockNumberFor<T>), T::MaxMilestones> {
+    let deadline = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+    BoundedVec::truncate_from(vec![(tranche::<T>(), deadline); m as usize])
+}
+
+fn state<T: Config>() -> Option<MilestoneState<BlockNumberFor<T>>> {
+    Escrows::<T>::get(0).map(|e| e.milestones[0].state)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create(m: Linear<1, { T::MaxMilestones::get() }>) {
+        let payer: T::AccountId = account("payer", 0, SEED);
+        let payee: T::AccountId = account("payee", 0, SEED);
+        T::Currency::set_balance(&payer, tranche::<T>() * (m + 1).into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(payer), payee, milestones::<T>(m));
+
+        assert!(Escrows::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn submit() -> Result<(), BenchmarkError> {
+        let (_, payee) = setup_escrow::<T>(T::MaxMilestones::get())?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(payee), 0, 0);
+
+        assert!(matches!(state::<T>(), Some(MilestoneState::Submitted(_))));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn approve() -> Result<(), BenchmarkError> {
+        let (payer, payee) = setup_escrow::<T>(T::MaxMilestones::get())?;
+        Pallet::<T>::submit(RawOrigin::Signed(payee).into(), 0, 0)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(payer), 0, 0);
+
+        assert_eq!(state::<T>(), Some(MilestoneState::Approved));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn dispute() -> Result<(), BenchmarkError> {
+        let (payer, payee) = setup_escrow::<T>(T::MaxMilestones::get())?;
+        Pallet::<T>::submit(RawOrigin::Signed(payee).into(), 0, 0)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(payer), 0, 0);
+
+        assert_eq!(state::<T>(), Some(MilestoneState::Disputed));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn resolve() -> Result<(), BenchmarkError> {
+        let origin = T::ArbitrationOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        setup_escrow::<T>(T::MaxMilestones::get())?;
+
+        // Refunding moves funds, approving does not.
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0, 0, false);
+
+        assert_eq!(state::<T>(), Some(MilestoneState::Refunded));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim() -> Result<(), BenchmarkError> {
+        let (payer, payee) = setup_escrow::<T>(T::MaxMilestones::get())?;
+        Pallet::<T>::submit(RawOrigin::Signed(payee.clone()).into(), 0, 0)?;
+        Pallet::<T>::approve(RawOrigin::Signed(payer).into(), 0, 0)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(payee), 0, 0);
+
+        assert_eq!(state::<T>(), Some(MilestoneState::Claimed));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reclaim() -> Result<(), BenchmarkError> {
+        let (payer, _) = setup_escrow::<T>(T::MaxMilestones::get())?;
+        let now = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(now.saturating_add(2u32.into()));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(payer), 0, 0);
+
+        assert_eq!(state::<T>(), Some(MilestoneState::Refunded));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,535 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Escrow
+//!
+//! Milestone-based escrows for advance payments, e.g. from a label to an artist.
+//!
+//! ## Features
+//! - The payer opens an escrow for a payee with a list of milestones, each with an amount and a
+//!   delivery deadline. The total is held from the payer.
+//! - The payee submits each milestone before its deadline. The payer then approves or disputes
+//!   it within `Config::ApprovalTimeout`; a submission left unanswered is approved by timeout.
+//! - The payee claims the tranche of each approved milestone.
+//! - `Config::ArbitrationOrigin` resolves milestones, disputed or not, by approving them or
+//!   refunding their tranche to the payer.
+//! - The payer reclaims the tranche of a milestone not submitted by its deadline.
+//! - The escrow is closed once every tranche has been claimed or refunded.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{Fortitude, Precision, Restriction},
+    },
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{
+    Saturating,
+    traits::{CheckedAdd, Zero},
+};
+
+pub type EscrowId = u32;
+pub type MilestoneIndex = u32;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type MilestoneOf<T> = Milestone<BalanceOf<T>, BlockNumberFor<T>>;
+
+pub type MilestonesOf<T> = BoundedVec<MilestoneOf<T>, <T as Config>::MaxMilestones>;
+
+pub type EscrowOf<T> = Escrow<<T as frame_system::Config>::AccountId, MilestonesOf<T>>;
+
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum MilestoneState<BlockNumber> {
+    /// Awaiting delivery by the payee.
+    Pending,
+    /// Delivered by the payee at the given block, awaiting the payer's answer.
+    Submitted(BlockNumber),
+    /// The payer disputes the delivery; the arbitration origin decides.
+    Disputed,
+    /// The tranche can be claimed by the payee.
+    Approved,
+    /// The tranche was paid to the payee.
+    Claimed,
+    /// The tranche went back to the payer.
+    Refunded,
+}
+
+/// A tranche of an escrow, released once its milestone is met.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Milestone<Balance, BlockNumber> {
+    /// Tranche released when the milestone is met.
+    pub amount: Balance,
+    /// Last block at which the payee can submit the milestone.
+    pub deadline: BlockNumber,
+    pub state: MilestoneState<BlockNumber>,
+}
+
+impl<Balance, BlockNumber> Milestone<Balance, BlockNumber> {
+    /// Whether the tranche has left the escrow.
+    pub fn is_settled(&self) -> bool {
+        matches!(
+            self.state,
+            MilestoneState::Claimed | MilestoneState::Refunded
+        )
+    }
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Escrow<AccountId, Milestones> {
+    /// Account the tranches are held from, e.g. a label.
+    pub payer: AccountId,
+    /// Account the tranches are paid to, e.g. an artist.
+    pub payee: AccountId,
+    pub milestones: Milestones,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Origin allowed to resolve milestones on behalf of both parties.
+        type ArbitrationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Max number of milestones of an escrow.
+        #[pallet::constant]
+        type MaxMilestones: Get<u32>;
+
+        /// Blocks the payer has to approve or dispute a submitted milestone, after which it is
+        /// approved.
+        #[pallet::constant]
+        type ApprovalTimeout: Get<BlockNumberFor<Self>>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Tranches of an escrow not yet claimed or refunded.
+        Escrow,
+    }
+
+    /// Open escrows.
+    #[pallet::storage]
+    pub type Escrows<T: Config> = StorageMap<_, Twox64Concat, EscrowId, EscrowOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextEscrowId<T: Config> = StorageValue<_, EscrowId, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        EscrowCreated {
+            escrow: EscrowId,
+            payer: T::AccountId,
+            payee: T::AccountId,
+            total: BalanceOf<T>,
+        },
+        MilestoneSubmitted {
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+        },
+        MilestoneApproved {
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+        },
+        MilestoneDisputed {
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+        },
+        TrancheClaimed {
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+            amount: BalanceOf<T>,
+        },
+        TrancheRefunded {
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+            amount: BalanceOf<T>,
+        },
+        /// Every tranche was claimed or refunded.
+        EscrowClosed { escrow: EscrowId },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NoMilestones,
+        ZeroAmount,
+        /// A milestone's deadline is in the past.
+        DeadlineInPast,
+        /// The payer and the payee are the same account.
+        SelfEscrow,
+        EscrowNotFound,
+        MilestoneNotFound,
+        NotPayer,
+        NotPayee,
+        /// The milestone is not in a state allowing this action.
+        InvalidMilestoneState,
+        /// The milestone's deadline has passed.
+        DeadlinePassed,
+        /// The milestone's deadline has not passed yet.
+        DeadlineNotReached,
+        /// The milestone is neither approved nor past its approval timeout.
+        NotApproved,
+        /// The milestones add up to more than a balance can hold.
+        Overflow,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Open an escrow paying `payee` a tranche per milestone, given as `(amount, deadline)`.
+        /// The total of the tranches is held from the caller.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create(milestones.len() as u32))]
+        pub fn create(
+            origin: OriginFor<T>,
+            payee: T::AccountId,
+            milestones: BoundedVec<(BalanceOf<T>, BlockNumberFor<T>), T::MaxMilestones>,
+        ) -> DispatchResult {
+            let payer = ensure_signed(origin)?;
+
+            ensure!(payer != payee, Error::<T>::SelfEscrow);
+            ensure!(!milestones.is_empty(), Error::<T>::NoMilestones);
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut total = BalanceOf::<T>::zero();
+            for (amount, deadline) in milestones.iter() {
+                ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+                ensure!(*deadline >= now, Error::<T>::DeadlineInPast);
+                total = total.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+            }
+
+            T::Currency::hold(&HoldReason::Escrow.into(), &payer, total)?;
+
+            let escrow = NextEscrowId::<T>::get();
+            // Same bound as `milestones`, nothing is truncated.
+            let milestones = MilestonesOf::<T>::truncate_from(
+                milestones
+                    .into_iter()
+                    .map(|(amount, deadline)| Milestone {
+                        amount,
+                        deadline,
+                        state: MilestoneState::Pending,
+                    })
+                    .collect(),
+            );
+            Escrows::<T>::insert(
+                escrow,
+                Escrow {
+                    payer: payer.clone(),
+                    payee: payee.clone(),
+                    milestones,
+                },
+            );
+            NextEscrowId::<T>::put(escrow.saturating_add(1));
+
+            Self::deposit_event(Event::EscrowCreated {
+                escrow,
+                payer,
+                payee,
+                total,
+            });
+            Ok(())
+        }
+
+        /// Report a milestone as delivered, before its deadline. Payee only.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::submit())]
+        pub fn submit(
+            origin: OriginFor<T>,
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::mutate_milestone(escrow, milestone, |info, m| {
+                ensure!(info.payee == who, Error::<T>::NotPayee);
+                ensure!(
+                    m.state == MilestoneState::Pending,
+                    Error::<T>::InvalidMilestoneState
+                );
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(now <= m.deadline, Error::<T>::DeadlinePassed);
+                m.state = MilestoneState::Submitted(now);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::MilestoneSubmitted { escrow, milestone });
+            Ok(())
+        }
+
+        /// Approve a submitted milestone, letting the payee claim its tranche. Payer only.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::approve())]
+        pub fn approve(
+            origin: OriginFor<T>,
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::mutate_milestone(escrow, milestone, |info, m| {
+                ensure!(info.payer == who, Error::<T>::NotPayer);
+                ensure!(
+                    matches!(m.state, MilestoneState::Submitted(_)),
+                    Error::<T>::InvalidMilestoneState
+                );
+                m.state = MilestoneState::Approved;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::MilestoneApproved { escrow, milestone });
+            Ok(())
+        }
+
+        /// Dispute a submitted milestone before its approval timeout, leaving it to the
+        /// arbitration origin. Payer only.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::dispute())]
+        pub fn dispute(
+            origin: OriginFor<T>,
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::mutate_milestone(escrow, milestone, |info, m| {
+                ensure!(info.payer == who, Error::<T>::NotPayer);
+                let MilestoneState::Submitted(at) = m.state else {
+                    return Err(Error::<T>::InvalidMilestoneState.into());
+                };
+                ensure!(!Self::is_timed_out(at), Error::<T>::InvalidMilestoneState);
+                m.state = MilestoneState::Disputed;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::MilestoneDisputed { escrow, milestone });
+            Ok(())
+        }
+
+        /// Settle a milestone that is not yet approved or settled: approve it for the payee,
+        /// or refund its tranche to the payer.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::resolve())]
+        pub fn resolve(
+            origin: OriginFor<T>,
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+            approve: bool,
+        ) -> DispatchResult {
+            T::ArbitrationOrigin::ensure_origin(origin)?;
+
+            if approve {
+                Self::mutate_milestone(escrow, milestone, |_, m| {
+                    ensure!(
+                        matches!(
+                            m.state,
+                            MilestoneState::Pending
+                                | MilestoneState::Submitted(_)
+                                | MilestoneState::Disputed
+                        ),
+                        Error::<T>::InvalidMilestoneState
+                    );
+                    m.state = MilestoneState::Approved;
+                    Ok(())
+                })?;
+                Self::deposit_event(Event::MilestoneApproved { escrow, milestone });
+                return Ok(());
+            }
+
+            Self::settle(escrow, milestone, |_, m| {
+                ensure!(
+                    !m.is_settled() && m.state != MilestoneState::Approved,
+                    Error::<T>::InvalidMilestoneState
+                );
+                Ok(false)
+            })
+        }
+
+        /// Claim the tranche of an approved milestone, or of a submitted one past its approval
+        /// timeout. Payee only.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::claim())]
+        pub fn claim(
+            origin: OriginFor<T>,
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::settle(escrow, milestone, |info, m| {
+                ensure!(info.payee == who, Error::<T>::NotPayee);
+                let approved = match m.state {
+                    MilestoneState::Approved => true,
+                    MilestoneState::Submitted(at) => Self::is_timed_out(at),
+                    _ => false,
+                };
+                ensure!(approved, Error::<T>::NotApproved);
+                Ok(true)
+            })
+        }
+
+        /// Take back the tranche of a milestone the payee did not submit by its deadline.
+        /// Payer only.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::reclaim())]
+        pub fn reclaim(
+            origin: OriginFor<T>,
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::settle(escrow, milestone, |info, m| {
+                ensure!(info.payer == who, Error::<T>::NotPayer);
+                ensure!(
+                    m.state == MilestoneState::Pending,
+                    Error::<T>::InvalidMilestoneState
+                );
+                ensure!(
+                    frame_system::Pallet::<T>::block_number() > m.deadline,
+                    Error::<T>::DeadlineNotReached
+                );
+                Ok(false)
+            })
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Whether a milestone submitted at `at` is approved by timeout.
+        fn is_timed_out(at: BlockNumberFor<T>) -> bool {
+            frame_system::Pallet::<T>::block_number()
+                >= at.saturating_add(T::ApprovalTimeout::get())
+        }
+
+        fn mutate_milestone(
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+            f: impl FnOnce(&EscrowOf<T>, &mut MilestoneOf<T>) -> DispatchResult,
+        ) -> DispatchResult {
+            Escrows::<T>::try_mutate(escrow, |maybe_escrow| {
+                let info = maybe_escrow.as_mut().ok_or(Error::<T>::EscrowNotFound)?;
+                let mut m = info
+                    .milestones
+                    .get(milestone as usize)
+                    .cloned()
+                    .ok_or(Error::<T>::MilestoneNotFound)?;
+                f(info, &mut m)?;
+                info.milestones[milestone as usize] = m;
+                Ok(())
+            })
+        }
+
+        /// Pay the tranche of `milestone` out of the escrow once `check` allowed it: to the
+        /// payee if it returns `true`, back to the payer otherwise. The escrow is closed once
+        /// every tranche is settled.
+        fn settle(
+            escrow: EscrowId,
+            milestone: MilestoneIndex,
+            check: impl FnOnce(&EscrowOf<T>, &MilestoneOf<T>) -> Result<bool, DispatchError>,
+        ) -> DispatchResult {
+            let mut info = Escrows::<T>::get(escrow).ok_or(Error::<T>::EscrowNotFound)?;
+            let m = info
+                .milestones
+                .get(milestone as usize)
+                .ok_or(Error::<T>::MilestoneNotFound)?;
+            let to_payee = check(&info, m)?;
+            let amount = m.amount;
+
+            if to_payee {
+                info.milestones[milestone as usize].state = MilestoneState::Claimed;
+                T::Currency::transfer_on_hold(
+                    &HoldReason::Escrow.into(),
+                    &info.payer,
+                    &info.payee,
+                    amount,
+                    Precision::Exact,
+                    Restriction::Free,
+                    Fortitude::Polite,
+                )?;
+                Self::deposit_event(Event::TrancheClaimed {
+                    escrow,
+                    milestone,
+                    amount,
+                });
+            } else {
+                info.milestones[milestone as usize].state = MilestoneState::Refunded;
+                T::Currency::release(
+                    &HoldReason::Escrow.into(),
+                    &info.payer,
+                    amount,
+                    Precision::Exact,
+                )?;
+                Self::deposit_event(Event::TrancheRefunded {
+                    escrow,
+                    milestone,
+                    amount,
+                });
+            }
+
+            if info.milestones.iter().all(Milestone::is_settled) {
+                Escrows::<T>::remove(escrow);
+                Self::deposit_event(Event::EscrowClosed { escrow });
+            } else {
+                Escrows::<T>::insert(escrow, info);
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,250 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error, Escrows, Event, HoldReason, MilestoneState, mock::*};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::fungible::{Inspect, InspectHold},
+};
+use sp_runtime::traits::BadOrigin;
+
+/// Two tranches: 300 due by block 20, 200 due by block 40.
+fn create() {
+    assert_ok!(Escrow::create(
+        RuntimeOrigin::signed(LABEL),
+        ARTIST,
+        vec![(300, 20), (200, 40)].try_into().unwrap()
+    ));
+}
+
+fn on_hold(who: u64) -> Balance {
+    Balances::balance_on_hold(&HoldReason::Escrow.into(), &who)
+}
+
+fn state(milestone: usize) -> MilestoneState<u64> {
+    Escrows::<Test>::get(0).unwrap().milestones[milestone].state
+}
+
+#[test]
+fn create_holds_the_advance() {
+    new_test_ext().execute_with(|| {
+        let open = |payee, milestones: Vec<(Balance, u64)>| {
+            Escrow::create(
+                RuntimeOrigin::signed(LABEL),
+                payee,
+                milestones.try_into().unwrap(),
+            )
+        };
+        assert_noop!(open(LABEL, vec![(300, 20)]), Error::<Test>::SelfEscrow);
+        assert_noop!(open(ARTIST, vec![]), Error::<Test>::NoMilestones);
+        assert_noop!(
+            open(ARTIST, vec![(300, 20), (0, 40)]),
+            Error::<Test>::ZeroAmount
+        );
+        assert_noop!(
+            open(ARTIST, vec![(Balance::MAX, 20), (1, 40)]),
+            Error::<Test>::Overflow
+        );
+        System::set_block_number(5);
+        assert_noop!(open(ARTIST, vec![(300, 4)]), Error::<Test>::DeadlineInPast);
+
+        create();
+
+        assert_eq!(on_hold(LABEL), 500);
+        assert_eq!(state(0), MilestoneState::Pending);
+        System::assert_last_event(
+            Event::EscrowCreated {
+                escrow: 0,
+                payer: LABEL,
+                payee: ARTIST,
+                total: 500,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn approved_milestones_are_claimed_by_the_payee() {
+    new_test_ext().execute_with(|| {
+        create();
+
+        assert_noop!(
+            Escrow::submit(RuntimeOrigin::signed(BOB), 0, 0),
+            Error::<Test>::NotPayee
+        );
+        assert_noop!(
+            Escrow::approve(RuntimeOrigin::signed(LABEL), 0, 0),
+            Error::<Test>::InvalidMilestoneState
+        );
+        assert_ok!(Escrow::submit(RuntimeOrigin::signed(ARTIST), 0, 0));
+        assert_eq!(state(0), MilestoneState::Submitted(1));
+        assert_noop!(
+            Escrow::claim(RuntimeOrigin::signed(ARTIST), 0, 0),
+            Error::<Test>::NotApproved
+        );
+
+        assert_noop!(
+            Escrow::approve(RuntimeOrigin::signed(ARTIST), 0, 0),
+            Error::<Test>::NotPayer
+        );
+        assert_ok!(Escrow::approve(RuntimeOrigin::signed(LABEL), 0, 0));
+        assert_ok!(Escrow::claim(RuntimeOrigin::signed(ARTIST), 0, 0));
+
+        assert_eq!(Balances::balance(&ARTIST), 400);
+        assert_eq!(Balances::balance(&LABEL), 500);
+        assert_eq!(on_hold(LABEL), 200);
+        assert_eq!(state(0), MilestoneState::Claimed);
+        System::assert_last_event(
+            Event::TrancheClaimed {
+                escrow: 0,
+                milestone: 0,
+                amount: 300,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Escrow::claim(RuntimeOrigin::signed(ARTIST), 0, 0),
+            Error::<Test>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn unanswered_submissions_are_approved_by_timeout() {
+    new_test_ext().execute_with(|| {
+        create();
+        assert_ok!(Escrow::submit(RuntimeOrigin::signed(ARTIST), 0, 0));
+
+        System::set_block_number(APPROVAL_TIMEOUT);
+        assert_noop!(
+            Escrow::claim(RuntimeOrigin::signed(ARTIST), 0, 0),
+            Error::<Test>::NotApproved
+        );
+
+        System::set_block_number(1 + APPROVAL_TIMEOUT);
+        assert_noop!(
+            Escrow::dispute(RuntimeOrigin::signed(LABEL), 0, 0),
+            Error::<Test>::InvalidMilestoneState
+        );
+        assert_ok!(Escrow::claim(RuntimeOrigin::signed(ARTIST), 0, 0));
+        assert_eq!(Balances::balance(&ARTIST), 400);
+    });
+}
+
+#[test]
+fn disputes_are_resolved_by_arbitration() {
+    new_test_ext().execute_with(|| {
+        create();
+        assert_ok!(Escrow::submit(RuntimeOrigin::signed(ARTIST), 0, 0));
+        assert_ok!(Escrow::submit(RuntimeOrigin::signed(ARTIST), 0, 1));
+        assert_ok!(Escrow::dispute(RuntimeOrigin::signed(LABEL), 0, 0));
+        assert_ok!(Escrow::dispute(RuntimeOrigin::signed(LABEL), 0, 1));
+        assert_eq!(state(0), MilestoneState::Disputed);
+
+        // A disputed milestone does not time out.
+        System::set_block_number(1 + APPROVAL_TIMEOUT);
+        assert_noop!(
+            Escrow::claim(RuntimeOrigin::signed(ARTIST), 0, 0),
+            Error::<Test>::NotApproved
+        );
+
+        assert_noop!(
+            Escrow::resolve(RuntimeOrigin::signed(LABEL), 0, 0, false),
+            BadOrigin
+        );
+        assert_ok!(Escrow::resolve(RuntimeOrigin::root(), 0, 0, true));
+        assert_ok!(Escrow::claim(RuntimeOrigin::signed(ARTIST), 0, 0));
+
+        assert_ok!(Escrow::resolve(RuntimeOrigin::root(), 0, 1, false));
+        System::assert_has_event(
+            Event::TrancheRefunded {
+                escrow: 0,
+                milestone: 1,
+                amount: 200,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::balance(&ARTIST), 400);
+        assert_eq!(Balances::balance(&LABEL), 700);
+        assert_eq!(on_hold(LABEL), 0);
+    });
+}
+
+#[test]
+fn missed_deadlines_are_reclaimed_by_the_payer() {
+    new_test_ext().execute_with(|| {
+        create();
+
+        assert_noop!(
+            Escrow::reclaim(RuntimeOrigin::signed(LABEL), 0, 0),
+            Error::<Test>::DeadlineNotReached
+        );
+
+        System::set_block_number(21);
+        assert_noop!(
+            Escrow::submit(RuntimeOrigin::signed(ARTIST), 0, 0),
+            Error::<Test>::DeadlinePassed
+        );
+        assert_noop!(
+            Escrow::reclaim(RuntimeOrigin::signed(ARTIST), 0, 0),
+            Error::<Test>::NotPayer
+        );
+        assert_ok!(Escrow::reclaim(RuntimeOrigin::signed(LABEL), 0, 0));
+
+        assert_eq!(state(0), MilestoneState::Refunded);
+        assert_eq!(on_hold(LABEL), 200);
+        assert_eq!(Balances::balance(&LABEL), 800);
+    });
+}
+
+#[test]
+fn escrow_is_closed_once_every_tranche_is_settled() {
+    new_test_ext().execute_with(|| {
+        create();
+        assert_ok!(Escrow::submit(RuntimeOrigin::signed(ARTIST), 0, 1));
+        assert_ok!(Escrow::approve(RuntimeOrigin::signed(LABEL), 0, 1));
+        assert_ok!(Escrow::claim(RuntimeOrigin::signed(ARTIST), 0, 1));
+        assert!(Escrows::<Test>::contains_key(0));
+
+        System::set_block_number(21);
+        assert_ok!(Escrow::reclaim(RuntimeOrigin::signed(LABEL), 0, 0));
+
+        assert!(!Escrows::<Test>::contains_key(0));
+        System::assert_last_event(Event::EscrowClosed { escrow: 0 }.into());
+        assert_noop!(
+            Escrow::claim(RuntimeOrigin::signed(ARTIST), 0, 0),
+            Error::<Test>::EscrowNotFound
+        );
+    });
+}
+
+#[test]
+fn unknown_milestones_are_rejected() {
+    new_test_ext().execute_with(|| {
+        create();
+        assert_noop!(
+            Escrow::submit(RuntimeOrigin::signed(ARTIST), 0, 2),
+            Error::<Test>::MilestoneNotFound
+        );
+        assert_noop!(
+            Escrow::submit(RuntimeOrigin::signed(ARTIST), 1, 0),
+            Error::<Test>::EscrowNotFound
+        );
+    });
+}
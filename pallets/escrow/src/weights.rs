@@ -0,0 +1,84 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_escrow.
+pub trait WeightInfo {
+    fn create(m: u32) -> Weight;
+    fn submit() -> Weight;
+    fn approve() -> Weight;
+    fn dispute() -> Weight;
+    fn resolve() -> Weight;
+    fn claim() -> Weight;
+    fn reclaim() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Escrow::NextEscrowId` (r:1 w:1)
+    /// Storage: `Escrow::Escrows` (r:0 w:1)
+    /// The range of component `m` is `[1, 16]`.
+    fn create(m: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(Weight::from_parts(1_000_000, 40).saturating_mul(m.into()))
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Escrow::Escrows` (r:1 w:1)
+    fn submit() -> Weight {
+        Weight::from_parts(20_000_000, 1_500)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Escrow::Escrows` (r:1 w:1)
+    fn approve() -> Weight {
+        Weight::from_parts(20_000_000, 1_500)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Escrow::Escrows` (r:1 w:1)
+    fn dispute() -> Weight {
+        Weight::from_parts(20_000_000, 1_500)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Escrow::Escrows` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn resolve() -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Escrow::Escrows` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:2 w:2)
+    fn claim() -> Weight {
+        Weight::from_parts(50_000_000, 6_200)
+            .saturating_add(ParityDbWeight::get().reads(4_u64))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Escrow::Escrows` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn reclaim() -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+}
@@ -0,0 +1,94 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Hash;
+
+fn label<T: Config>(i: u32) -> T::Hash {
+    T::Hashing::hash_of(&i)
+}
+
+/// Add a chain of genres `MaxDepth` levels deep and return the deepest one.
+fn deepest_genre<T: Config>() -> GenreId {
+    let mut parent = None;
+    for i in 0..=T::MaxDepth::get() {
+        let id = NextGenreId::<T>::get();
+        Pallet::<T>::add_genre(RawOrigin::Root.into(), label::<T>(i), parent)
+            .expect("the chain is MaxDepth levels deep");
+        parent = Some(id);
+    }
+    parent.expect("MaxDepth + 1 genres added")
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// The deepest sub-genre allowed.
+    #[benchmark]
+    fn add_genre() {
+        let mut parent = None;
+        for i in 0..T::MaxDepth::get() {
+            let id = NextGenreId::<T>::get();
+            Pallet::<T>::add_genre(RawOrigin::Root.into(), label::<T>(i), parent)
+                .expect("the chain is less than MaxDepth levels deep");
+            parent = Some(id);
+        }
+        let id = NextGenreId::<T>::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, label::<T>(u32::MAX), parent);
+
+        assert!(Pallet::<T>::contains(&id));
+    }
+
+    #[benchmark]
+    fn relabel_genre() {
+        let id = deepest_genre::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, id, label::<T>(u32::MAX));
+
+        assert_eq!(Pallet::<T>::genre_of_label(label::<T>(u32::MAX)), Some(id));
+    }
+
+    #[benchmark]
+    fn deprecate_genre() {
+        let id = deepest_genre::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, id);
+
+        assert!(!Pallet::<T>::contains(&id));
+    }
+
+    #[benchmark]
+    fn restore_genre() {
+        let id = deepest_genre::<T>();
+        Pallet::<T>::deprecate_genre(RawOrigin::Root.into(), id).expect("the genre exists");
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, id);
+
+        assert!(Pallet::<T>::contains(&id));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,268 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Genres
+//!
+//! A taxonomy of music genres that governance extends without a runtime upgrade.
+//!
+//! ## Features
+//! - `AdminOrigin` (governance, e.g. a content committee) adds genres, relabels them and
+//!   deprecates them.
+//! - A genre has a sequential id, the hash of its label, unique across the registry, and
+//!   optionally a parent genre, at most `Config::MaxDepth` levels below a root genre.
+//! - Deprecated genres stay in the registry so existing references keep resolving, but they no
+//!   longer validate new references and can't get sub-genres.
+//! - Other pallets validate the genre ids they are given through `Contains<GenreId>`.
+//!
+//! Labels themselves (and their translations) live off chain, keyed by their hash.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{pallet_prelude::*, traits::Contains};
+use frame_system::pallet_prelude::*;
+
+pub type GenreId = u32;
+
+pub type GenreOf<T> = Genre<<T as frame_system::Config>::Hash>;
+
+/// A genre of the taxonomy.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Genre<Hash> {
+    /// Hash of the genre's label.
+    pub label_hash: Hash,
+    /// Genre this one is a sub-genre of, if any.
+    pub parent: Option<GenreId>,
+    /// Number of ancestors of the genre: 0 for a root genre.
+    pub depth: u32,
+    /// Whether the genre may no longer be referenced.
+    pub deprecated: bool,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Origin allowed to manage the taxonomy.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Max depth of a genre: the number of ancestors it can have.
+        #[pallet::constant]
+        type MaxDepth: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::storage]
+    pub type Genres<T: Config> = StorageMap<_, Twox64Concat, GenreId, GenreOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextGenreId<T: Config> = StorageValue<_, GenreId, ValueQuery>;
+
+    /// Genre of each label hash.
+    #[pallet::storage]
+    pub type LabelIndex<T: Config> = StorageMap<_, Identity, T::Hash, GenreId, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        GenreAdded {
+            id: GenreId,
+            label_hash: T::Hash,
+            parent: Option<GenreId>,
+        },
+        GenreRelabeled {
+            id: GenreId,
+            label_hash: T::Hash,
+        },
+        GenreDeprecated {
+            id: GenreId,
+        },
+        GenreRestored {
+            id: GenreId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        UnknownGenre,
+        /// The parent genre is deprecated.
+        ParentDeprecated,
+        LabelAlreadyUsed,
+        /// The parent genre is already `MaxDepth` levels deep.
+        TooDeep,
+        AlreadyDeprecated,
+        NotDeprecated,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Add the genre labeled `label_hash`, as a sub-genre of `parent` or as a root genre.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_genre())]
+        pub fn add_genre(
+            origin: OriginFor<T>,
+            label_hash: T::Hash,
+            parent: Option<GenreId>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !LabelIndex::<T>::contains_key(label_hash),
+                Error::<T>::LabelAlreadyUsed
+            );
+            let depth = match parent {
+                Some(parent) => {
+                    let parent = Genres::<T>::get(parent).ok_or(Error::<T>::UnknownGenre)?;
+                    ensure!(!parent.deprecated, Error::<T>::ParentDeprecated);
+                    ensure!(parent.depth < T::MaxDepth::get(), Error::<T>::TooDeep);
+                    parent.depth.saturating_add(1)
+                }
+                None => 0,
+            };
+
+            let id = NextGenreId::<T>::get();
+            Genres::<T>::insert(
+                id,
+                Genre {
+                    label_hash,
+                    parent,
+                    depth,
+                    deprecated: false,
+                },
+            );
+            LabelIndex::<T>::insert(label_hash, id);
+            NextGenreId::<T>::put(id.saturating_add(1));
+
+            Self::deposit_event(Event::GenreAdded {
+                id,
+                label_hash,
+                parent,
+            });
+            Ok(())
+        }
+
+        /// Replace the label of genre `id`, e.g. to fix a typo.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::relabel_genre())]
+        pub fn relabel_genre(
+            origin: OriginFor<T>,
+            id: GenreId,
+            label_hash: T::Hash,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !LabelIndex::<T>::contains_key(label_hash),
+                Error::<T>::LabelAlreadyUsed
+            );
+            Genres::<T>::try_mutate(id, |maybe_genre| -> DispatchResult {
+                let genre = maybe_genre.as_mut().ok_or(Error::<T>::UnknownGenre)?;
+                LabelIndex::<T>::remove(genre.label_hash);
+                genre.label_hash = label_hash;
+                Ok(())
+            })?;
+            LabelIndex::<T>::insert(label_hash, id);
+
+            Self::deposit_event(Event::GenreRelabeled { id, label_hash });
+            Ok(())
+        }
+
+        /// Stop genre `id` from being referenced. Existing references are left alone.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::deprecate_genre())]
+        pub fn deprecate_genre(origin: OriginFor<T>, id: GenreId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Self::set_deprecated(id, true)?;
+            Self::deposit_event(Event::GenreDeprecated { id });
+            Ok(())
+        }
+
+        /// Let deprecated genre `id` be referenced again.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::restore_genre())]
+        pub fn restore_genre(origin: OriginFor<T>, id: GenreId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Self::set_deprecated(id, false)?;
+            Self::deposit_event(Event::GenreRestored { id });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn set_deprecated(id: GenreId, deprecated: bool) -> DispatchResult {
+            Genres::<T>::try_mutate(id, |maybe_genre| {
+                let genre = maybe_genre.as_mut().ok_or(Error::<T>::UnknownGenre)?;
+                match (genre.deprecated, deprecated) {
+                    (true, true) => Err(Error::<T>::AlreadyDeprecated.into()),
+                    (false, false) => Err(Error::<T>::NotDeprecated.into()),
+                    _ => {
+                        genre.deprecated = deprecated;
+                        Ok(())
+                    }
+                }
+            })
+        }
+
+        /// The genre labeled `label_hash`, if any.
+        pub fn genre_of_label(label_hash: T::Hash) -> Option<GenreId> {
+            LabelIndex::<T>::get(label_hash)
+        }
+
+        /// Whether `genre` is `ancestor` or one of its sub-genres, at any depth.
+        pub fn is_within(genre: GenreId, ancestor: GenreId) -> bool {
+            let mut current = Some(genre);
+            // Parents are set once, when the genre is added, so the walk ends within
+            // `MaxDepth + 1` steps.
+            while let Some(id) = current {
+                if id == ancestor {
+                    return true;
+                }
+                current = Genres::<T>::get(id).and_then(|genre| genre.parent);
+            }
+            false
+        }
+    }
+}
+
+/// Genres that may be referenced: the registered ones that aren't deprecated.
+impl<T: Config> Contains<GenreId> for Pallet<T> {
+    fn contains(id: &GenreId) -> bool {
+        Genres::<T>::get(id).is_some_and(|genre| !genre.deprecated)
+    }
+}
@@ -0,0 +1,177 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error, Event, Genre, Genres as GenresStorage, LabelIndex, NextGenreId, mock::*};
+use frame_support::{assert_noop, assert_ok, traits::Contains};
+use sp_runtime::DispatchError;
+
+fn add(name: &str, parent: Option<u32>) -> u32 {
+    let id = NextGenreId::<Test>::get();
+    assert_ok!(Genres::add_genre(
+        RuntimeOrigin::root(),
+        label(name),
+        parent
+    ));
+    id
+}
+
+#[test]
+fn genres_are_added_by_admin_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Genres::add_genre(RuntimeOrigin::signed(OUTSIDER), label("rock"), None),
+            DispatchError::BadOrigin
+        );
+
+        let rock = add("rock", None);
+        System::assert_last_event(
+            Event::GenreAdded {
+                id: rock,
+                label_hash: label("rock"),
+                parent: None,
+            }
+            .into(),
+        );
+        assert_eq!(
+            GenresStorage::<Test>::get(rock),
+            Some(Genre {
+                label_hash: label("rock"),
+                parent: None,
+                depth: 0,
+                deprecated: false,
+            })
+        );
+        assert_eq!(Genres::genre_of_label(label("rock")), Some(rock));
+        assert!(Genres::contains(&rock));
+
+        // Labels are unique.
+        assert_noop!(
+            Genres::add_genre(RuntimeOrigin::root(), label("rock"), None),
+            Error::<Test>::LabelAlreadyUsed
+        );
+        assert!(!Genres::contains(&NextGenreId::<Test>::get()));
+    });
+}
+
+#[test]
+fn sub_genres_are_bounded_in_depth() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Genres::add_genre(RuntimeOrigin::root(), label("punk"), Some(7)),
+            Error::<Test>::UnknownGenre
+        );
+
+        let rock = add("rock", None);
+        let punk = add("punk", Some(rock));
+        let hardcore = add("hardcore", Some(punk));
+        assert_eq!(GenresStorage::<Test>::get(hardcore).unwrap().depth, 2);
+
+        // `MaxDepth` is 2.
+        assert_noop!(
+            Genres::add_genre(RuntimeOrigin::root(), label("crust"), Some(hardcore)),
+            Error::<Test>::TooDeep
+        );
+
+        let jazz = add("jazz", None);
+        assert!(Genres::is_within(hardcore, rock));
+        assert!(Genres::is_within(hardcore, punk));
+        assert!(Genres::is_within(punk, punk));
+        assert!(!Genres::is_within(rock, punk));
+        assert!(!Genres::is_within(hardcore, jazz));
+    });
+}
+
+#[test]
+fn deprecated_genres_can_no_longer_be_referenced() {
+    new_test_ext().execute_with(|| {
+        let rock = add("rock", None);
+        let punk = add("punk", Some(rock));
+
+        assert_noop!(
+            Genres::deprecate_genre(RuntimeOrigin::signed(OUTSIDER), rock),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Genres::deprecate_genre(RuntimeOrigin::root(), rock));
+        System::assert_last_event(Event::GenreDeprecated { id: rock }.into());
+        assert!(!Genres::contains(&rock));
+        // Sub-genres and existing lookups are left alone.
+        assert!(Genres::contains(&punk));
+        assert!(Genres::is_within(punk, rock));
+
+        assert_noop!(
+            Genres::add_genre(RuntimeOrigin::root(), label("grunge"), Some(rock)),
+            Error::<Test>::ParentDeprecated
+        );
+        assert_noop!(
+            Genres::deprecate_genre(RuntimeOrigin::root(), rock),
+            Error::<Test>::AlreadyDeprecated
+        );
+
+        assert_ok!(Genres::restore_genre(RuntimeOrigin::root(), rock));
+        System::assert_last_event(Event::GenreRestored { id: rock }.into());
+        assert!(Genres::contains(&rock));
+        assert_noop!(
+            Genres::restore_genre(RuntimeOrigin::root(), rock),
+            Error::<Test>::NotDeprecated
+        );
+        assert_noop!(
+            Genres::restore_genre(RuntimeOrigin::root(), 7),
+            Error::<Test>::UnknownGenre
+        );
+    });
+}
+
+#[test]
+fn relabeling_moves_the_label() {
+    new_test_ext().execute_with(|| {
+        let rock = add("rock", None);
+        let jazz = add("jazz", None);
+
+        assert_noop!(
+            Genres::relabel_genre(RuntimeOrigin::root(), rock, label("jazz")),
+            Error::<Test>::LabelAlreadyUsed
+        );
+        assert_noop!(
+            Genres::relabel_genre(RuntimeOrigin::root(), 7, label("blues")),
+            Error::<Test>::UnknownGenre
+        );
+
+        assert_ok!(Genres::relabel_genre(
+            RuntimeOrigin::root(),
+            rock,
+            label("rock & roll")
+        ));
+        System::assert_last_event(
+            Event::GenreRelabeled {
+                id: rock,
+                label_hash: label("rock & roll"),
+            }
+            .into(),
+        );
+        assert_eq!(
+            GenresStorage::<Test>::get(rock).unwrap().label_hash,
+            label("rock & roll")
+        );
+        assert!(!LabelIndex::<Test>::contains_key(label("rock")));
+        assert_eq!(Genres::genre_of_label(label("rock & roll")), Some(rock));
+        assert_eq!(Genres::genre_of_label(label("jazz")), Some(jazz));
+
+        // The old label is free again.
+        add("rock", None);
+    });
+}
@@ -0,0 +1,118 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use alloc::vec::Vec;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+
+/// A funded, approved importer.
+fn importer<T: Config>() -> T::AccountId {
+    let who: T::AccountId = account("importer", 0, SEED);
+    T::Currency::set_balance(&who, BalanceOf::<T>::max_value() / 4u32.into());
+    T::BenchmarkHelper::approve_importer(&who);
+    who
+}
+
+fn batch<T: Config>(e: u32) -> EntriesOf<T> {
+    (0..e)
+        .map(|i| {
+            T::BenchmarkHelper::entry(i)
+                .try_into()
+                .expect("entries fit in MaxEntryLen")
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("at most MaxBatchSize entries")
+}
+
+/// A batch of `e` entries staged by `importer`, validated if `validate`.
+fn staged<T: Config>(importer: &T::AccountId, e: u32, validate: bool) -> BatchId {
+    Pallet::<T>::stage(RawOrigin::Signed(importer.clone()).into(), batch::<T>(e))
+        .expect("approved importer stages valid entries");
+    let id = NextBatchId::<T>::get() - 1;
+    if validate {
+        let mut task = Some(ValidationTask { batch: id, next: 0 });
+        while let Some(next) = task {
+            task = Pallet::<T>::validate_step(next).expect("entries are valid");
+        }
+    }
+    id
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn stage(e: Linear<1, { T::MaxBatchSize::get() }>) {
+        let importer = importer::<T>();
+        let entries = batch::<T>(e);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(importer), entries);
+
+        assert!(Batches::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn commit(e: Linear<1, { T::MaxBatchSize::get() }>) {
+        let importer = importer::<T>();
+        let id = staged::<T>(&importer, e, true);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(importer), id);
+
+        assert!(!Batches::<T>::contains_key(id));
+    }
+
+    #[benchmark]
+    fn discard(e: Linear<1, { T::MaxBatchSize::get() }>) {
+        let importer = importer::<T>();
+        let id = staged::<T>(&importer, e, false);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(importer), id);
+
+        assert!(!Batches::<T>::contains_key(id));
+    }
+
+    #[benchmark]
+    fn validate_step() {
+        let importer = importer::<T>();
+        // The last step, which also settles the status of the batch.
+        let id = staged::<T>(&importer, 1, false);
+        let rest;
+
+        #[block]
+        {
+            rest = Pallet::<T>::validate_step(ValidationTask { batch: id, next: 0 });
+        }
+
+        assert_eq!(rest, Ok(None));
+        assert!(matches!(
+            Batches::<T>::get(id).expect("batch is staged").status,
+            BatchStatus::Valid { .. }
+        ));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,462 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Import Staging
+//!
+//! Two-phase catalog imports: a batch is staged and validated before any of it lands in the
+//! catalog, so that an import never stops half-way.
+//!
+//! ## Features
+//! - An approved importer (`Config::Importers`) stages a batch of encoded catalog entries. A
+//!   deposit, proportional to the size of the batch, is held while it is staged.
+//! - The batch is validated one entry per step through the deferred work queue
+//!   (`Config::Validation`): each entry is imported and reverted, and its failure, if any,
+//!   recorded in the batch's validation report. Entries are checked against the catalog as it
+//!   stands, not against each other.
+//! - Once validated, the importer commits a batch whose entries all passed: every entry is
+//!   imported, or none is if the catalog changed since validation. Either way the importer may
+//!   discard the batch instead.
+//! - The report is kept for `Config::StagingPeriod` blocks after validation; past that, anyone
+//!   can discard the batch. The deposit always goes back to the importer.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{
+    pallet_prelude::*,
+    storage::with_transaction,
+    traits::{
+        Contains,
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::Precision,
+    },
+};
+use frame_system::pallet_prelude::*;
+use pallet_deferred_work::DeferWork;
+use sp_runtime::{Saturating, TransactionOutcome};
+
+pub type BatchId = u32;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type EntryOf<T> = BoundedVec<u8, <T as Config>::MaxEntryLen>;
+
+pub type EntriesOf<T> = BoundedVec<EntryOf<T>, <T as Config>::MaxBatchSize>;
+
+pub type ValidationReportOf<T> =
+    ValidationReport<BoundedVec<EntryFailure, <T as Config>::MaxReportedFailures>>;
+
+pub type StagedBatchOf<T> = StagedBatch<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    BlockNumberFor<T>,
+    ValidationReportOf<T>,
+>;
+
+/// The catalog staged entries are imported into.
+pub trait CatalogImport<AccountId> {
+    /// Import the encoded `entry` on behalf of `importer`.
+    ///
+    /// Also used to validate entries: the changes of a validation are reverted.
+    fn import(importer: &AccountId, entry: &[u8]) -> DispatchResult;
+
+    /// Upper bound on the weight of [`CatalogImport::import`].
+    fn entry_weight() -> Weight;
+}
+
+/// Where a staged batch stands.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum BatchStatus<BlockNumber> {
+    /// Entries are being validated.
+    Validating,
+    /// Every entry passed validation: the batch can be committed.
+    Valid { expires_at: BlockNumber },
+    /// Some entries failed validation: the batch can only be discarded.
+    Rejected { expires_at: BlockNumber },
+}
+
+/// An entry which failed validation.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct EntryFailure {
+    /// Position of the entry in its batch.
+    pub index: u32,
+    pub error: DispatchError,
+}
+
+/// Outcome of the validation of a batch, so far.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ValidationReport<Failures> {
+    /// Number of entries validated.
+    pub checked: u32,
+    /// Number of entries which failed validation.
+    pub failed: u32,
+    /// The first `Config::MaxReportedFailures` failures, by entry.
+    pub failures: Failures,
+}
+
+/// A batch of catalog entries waiting to be committed.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct StagedBatch<AccountId, Balance, BlockNumber, Report> {
+    pub importer: AccountId,
+    /// Number of entries of the batch.
+    pub entries: u32,
+    /// Amount held from `importer` while the batch is staged.
+    pub deposit: Balance,
+    pub status: BatchStatus<BlockNumber>,
+    pub report: Report,
+}
+
+/// Validation of the entries of `batch` from `next` on, queued as deferred work.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ValidationTask {
+    pub batch: BatchId,
+    pub next: u32,
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId> {
+    /// Allow `who` to stage batches.
+    fn approve_importer(who: &AccountId);
+
+    /// The `i`-th entry of a batch, valid and as heavy to import as entries get.
+    fn entry(i: u32) -> alloc::vec::Vec<u8>;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Accounts allowed to stage batches.
+        type Importers: Contains<Self::AccountId>;
+
+        /// The catalog batches are imported into.
+        type Catalog: CatalogImport<Self::AccountId>;
+
+        /// Queue validating staged batches.
+        type Validation: DeferWork<ValidationTask>;
+
+        /// Max number of entries of a batch.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Max length of an encoded entry.
+        #[pallet::constant]
+        type MaxEntryLen: Get<u32>;
+
+        /// Max number of failures detailed by a validation report.
+        #[pallet::constant]
+        type MaxReportedFailures: Get<u32>;
+
+        /// Blocks a batch stays staged once validated, before anyone can discard it.
+        #[pallet::constant]
+        type StagingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Amount held for each staged batch.
+        #[pallet::constant]
+        type DepositBase: Get<BalanceOf<Self>>;
+
+        /// Amount held for each byte of the entries of a staged batch.
+        #[pallet::constant]
+        type DepositPerByte: Get<BalanceOf<Self>>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        StagingDeposit,
+    }
+
+    #[pallet::storage]
+    pub type Batches<T: Config> =
+        StorageMap<_, Twox64Concat, BatchId, StagedBatchOf<T>, OptionQuery>;
+
+    /// Entries of the staged batches, by position.
+    #[pallet::storage]
+    pub type Entries<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BatchId, Twox64Concat, u32, EntryOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextBatchId<T: Config> = StorageValue<_, BatchId, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        BatchStaged {
+            batch: BatchId,
+            importer: T::AccountId,
+            entries: u32,
+            deposit: BalanceOf<T>,
+        },
+        /// Every entry of the batch was validated; `failed` of them did not pass.
+        BatchValidated {
+            batch: BatchId,
+            failed: u32,
+        },
+        BatchCommitted {
+            batch: BatchId,
+            entries: u32,
+        },
+        BatchDiscarded {
+            batch: BatchId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NotImporter,
+        EmptyBatch,
+        /// The validation queue is congested; stage the batch later.
+        ValidationCongested,
+        BatchNotFound,
+        NotBatchImporter,
+        /// The batch is still being validated.
+        NotValidated,
+        /// Some entries of the batch failed validation.
+        BatchRejected,
+        /// Only the importer can discard a batch before it expires.
+        BatchNotExpired,
+        EntryNotFound,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Stage `entries` for import and queue their validation. Approved importers only.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::stage(entries.len() as u32))]
+        pub fn stage(origin: OriginFor<T>, entries: EntriesOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(T::Importers::contains(&who), Error::<T>::NotImporter);
+            ensure!(!entries.is_empty(), Error::<T>::EmptyBatch);
+            ensure!(
+                !T::Validation::is_congested(),
+                Error::<T>::ValidationCongested
+            );
+
+            let bytes = entries.iter().fold(0u32, |bytes, entry| {
+                bytes.saturating_add(entry.len() as u32)
+            });
+            let deposit = T::DepositPerByte::get()
+                .saturating_mul(bytes.into())
+                .saturating_add(T::DepositBase::get());
+            T::Currency::hold(&HoldReason::StagingDeposit.into(), &who, deposit)?;
+
+            let batch = NextBatchId::<T>::get();
+            T::Validation::defer(ValidationTask { batch, next: 0 })?;
+            let count = entries.len() as u32;
+            for (index, entry) in entries.into_iter().enumerate() {
+                Entries::<T>::insert(batch, index as u32, entry);
+            }
+            Batches::<T>::insert(
+                batch,
+                StagedBatch {
+                    importer: who.clone(),
+                    entries: count,
+                    deposit,
+                    status: BatchStatus::Validating,
+                    report: ValidationReport {
+                        checked: 0,
+                        failed: 0,
+                        failures: BoundedVec::new(),
+                    },
+                },
+            );
+            NextBatchId::<T>::put(batch.saturating_add(1));
+
+            Self::deposit_event(Event::BatchStaged {
+                batch,
+                importer: who,
+                entries: count,
+                deposit,
+            });
+            Ok(())
+        }
+
+        /// Import every entry of a validated batch, or none of them, and release its deposit.
+        /// Importer only.
+        #[pallet::call_index(1)]
+        #[pallet::weight(Pallet::<T>::commit_weight(T::MaxBatchSize::get()))]
+        pub fn commit(origin: OriginFor<T>, batch: BatchId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let staged = Batches::<T>::get(batch).ok_or(Error::<T>::BatchNotFound)?;
+            ensure!(staged.importer == who, Error::<T>::NotBatchImporter);
+            ensure!(T::Importers::contains(&who), Error::<T>::NotImporter);
+            match staged.status {
+                BatchStatus::Validating => return Err(Error::<T>::NotValidated.into()),
+                BatchStatus::Rejected { .. } => return Err(Error::<T>::BatchRejected.into()),
+                BatchStatus::Valid { .. } => {}
+            }
+
+            // Calls are transactional: an entry failing here reverts the entries before it.
+            for index in 0..staged.entries {
+                let entry = Entries::<T>::take(batch, index).ok_or(Error::<T>::EntryNotFound)?;
+                T::Catalog::import(&who, &entry)?;
+            }
+            Self::close_batch(batch, &staged)?;
+
+            Self::deposit_event(Event::BatchCommitted {
+                batch,
+                entries: staged.entries,
+            });
+            Ok(Some(Self::commit_weight(staged.entries)).into())
+        }
+
+        /// Drop a staged batch and release its deposit. Importer only, or anyone once the
+        /// batch expired.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::discard(T::MaxBatchSize::get()))]
+        pub fn discard(origin: OriginFor<T>, batch: BatchId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let staged = Batches::<T>::get(batch).ok_or(Error::<T>::BatchNotFound)?;
+            if who != staged.importer {
+                let expired = match staged.status {
+                    BatchStatus::Validating => false,
+                    BatchStatus::Valid { expires_at } | BatchStatus::Rejected { expires_at } => {
+                        frame_system::Pallet::<T>::block_number() >= expires_at
+                    }
+                };
+                ensure!(expired, Error::<T>::BatchNotExpired);
+            }
+            let _ = Entries::<T>::clear_prefix(batch, staged.entries, None);
+            Self::close_batch(batch, &staged)?;
+
+            Self::deposit_event(Event::BatchDiscarded { batch });
+            Ok(Some(T::WeightInfo::discard(staged.entries)).into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Drop a staged batch whose entries are gone and release its deposit.
+        fn close_batch(batch: BatchId, staged: &StagedBatchOf<T>) -> DispatchResult {
+            T::Currency::release(
+                &HoldReason::StagingDeposit.into(),
+                &staged.importer,
+                staged.deposit,
+                Precision::BestEffort,
+            )?;
+            Batches::<T>::remove(batch);
+            Ok(())
+        }
+
+        /// Weight of committing a batch of `entries` entries, imports included.
+        pub fn commit_weight(entries: u32) -> Weight {
+            T::WeightInfo::commit(entries)
+                .saturating_add(T::Catalog::entry_weight().saturating_mul(entries.into()))
+        }
+
+        /// Upper bound on the weight of a [`Pallet::validate_step`].
+        pub fn validation_step_weight() -> Weight {
+            T::WeightInfo::validate_step().saturating_add(T::Catalog::entry_weight())
+        }
+
+        /// Validate the next entry of `task`'s batch, returning the validation left to do, or
+        /// `None` once the batch is validated or gone.
+        pub fn validate_step(
+            task: ValidationTask,
+        ) -> Result<Option<ValidationTask>, DispatchError> {
+            let Some(mut staged) = Batches::<T>::get(task.batch) else {
+                // Discarded while being validated.
+                return Ok(None);
+            };
+            let entry =
+                Entries::<T>::get(task.batch, task.next).ok_or(Error::<T>::EntryNotFound)?;
+
+            let outcome = with_transaction(|| {
+                TransactionOutcome::Rollback(Ok::<_, DispatchError>(T::Catalog::import(
+                    &staged.importer,
+                    &entry,
+                )))
+            })?;
+            let report = &mut staged.report;
+            report.checked.saturating_inc();
+            if let Err(error) = outcome {
+                report.failed.saturating_inc();
+                let _ = report.failures.try_push(EntryFailure {
+                    index: task.next,
+                    error,
+                });
+            }
+
+            let next = task.next.saturating_add(1);
+            let rest = if next < staged.entries {
+                Some(ValidationTask {
+                    batch: task.batch,
+                    next,
+                })
+            } else {
+                let expires_at = frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::StagingPeriod::get());
+                staged.status = if staged.report.failed == 0 {
+                    BatchStatus::Valid { expires_at }
+                } else {
+                    BatchStatus::Rejected { expires_at }
+                };
+                Self::deposit_event(Event::BatchValidated {
+                    batch: task.batch,
+                    failed: staged.report.failed,
+                });
+                None
+            };
+            Batches::<T>::insert(task.batch, staged);
+            Ok(rest)
+        }
+    }
+}
@@ -0,0 +1,236 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_import_staging, CatalogImport, ValidationTask};
+use frame_support::{
+    derive_impl, pallet_prelude::*, parameter_types, sp_runtime::BuildStorage, storage::unhashed,
+    traits::Contains,
+};
+use frame_system::EnsureRoot;
+use parity_scale_codec::DecodeAll;
+
+pub type Balance = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type DeferredWork = pallet_deferred_work;
+
+    #[runtime::pallet_index(3)]
+    pub type ImportStaging = pallet_import_staging;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+}
+
+impl pallet_deferred_work::DeferredTask for ValidationTask {
+    fn step_weight(&self) -> Weight {
+        ImportStaging::validation_step_weight()
+    }
+
+    fn step(self) -> Result<Option<Self>, DispatchError> {
+        ImportStaging::validate_step(self)
+    }
+}
+
+parameter_types! {
+    pub const MaxQueued: u32 = 4;
+    pub const CongestionThreshold: u32 = 2;
+    // Two entries validated per block.
+    pub ValidationBudget: Weight = ImportStaging::validation_step_weight()
+        .saturating_add(<() as pallet_deferred_work::WeightInfo>::run_step())
+        .saturating_mul(2);
+}
+
+impl pallet_deferred_work::Config for Test {
+    type Task = ValidationTask;
+    type EnqueueOrigin = EnsureRoot<Self::AccountId>;
+    type MaxQueued = MaxQueued;
+    type CongestionThreshold = CongestionThreshold;
+    type BlockBudget = ValidationBudget;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_deferred_work::BenchmarkHelper<ValidationTask> for () {
+    fn noop_task() -> ValidationTask {
+        ValidationTask { batch: 0, next: 0 }
+    }
+}
+
+/// Approved importers, kept in the test externalities so that each test starts from genesis.
+pub struct MockImporters;
+
+impl MockImporters {
+    fn key(who: u64) -> Vec<u8> {
+        (b"mock/importer", who).encode()
+    }
+
+    pub fn approve(who: u64) {
+        unhashed::put(&Self::key(who), &true);
+    }
+
+    pub fn revoke(who: u64) {
+        unhashed::kill(&Self::key(who));
+    }
+}
+
+impl Contains<u64> for MockImporters {
+    fn contains(who: &u64) -> bool {
+        unhashed::exists(&Self::key(*who))
+    }
+}
+
+/// A catalog of works, each entry being the encoded id of a work to register. Work 0 is
+/// invalid and works can be registered once.
+pub struct MockCatalog;
+
+impl MockCatalog {
+    fn key(work: u32) -> Vec<u8> {
+        (b"mock/work", work).encode()
+    }
+
+    /// Who registered `work`, if anyone did.
+    pub fn registrant(work: u32) -> Option<u64> {
+        unhashed::get(&Self::key(work))
+    }
+}
+
+impl CatalogImport<u64> for MockCatalog {
+    fn import(importer: &u64, entry: &[u8]) -> DispatchResult {
+        let work = u32::decode_all(&mut &entry[..])
+            .map_err(|_| DispatchError::Other("undecodable work"))?;
+        ensure!(work != 0, DispatchError::Other("invalid work"));
+        ensure!(
+            Self::registrant(work).is_none(),
+            DispatchError::Other("duplicate work")
+        );
+        unhashed::put(&Self::key(work), importer);
+        Ok(())
+    }
+
+    fn entry_weight() -> Weight {
+        Weight::from_parts(1_000, 0)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u64> for MockImporters {
+    fn approve_importer(who: &u64) {
+        Self::approve(*who);
+    }
+
+    fn entry(i: u32) -> Vec<u8> {
+        (i + 1).encode()
+    }
+}
+
+pub const DEPOSIT_BASE: Balance = 10;
+pub const DEPOSIT_PER_BYTE: Balance = 1;
+pub const STAGING_PERIOD: u64 = 10;
+
+parameter_types! {
+    pub const MaxBatchSize: u32 = 4;
+    pub const MaxEntryLen: u32 = 8;
+    pub const MaxReportedFailures: u32 = 2;
+    pub const StagingPeriod: u64 = STAGING_PERIOD;
+    pub const DepositBase: Balance = DEPOSIT_BASE;
+    pub const DepositPerByte: Balance = DEPOSIT_PER_BYTE;
+}
+
+impl pallet_import_staging::Config for Test {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Importers = MockImporters;
+    type Catalog = MockCatalog;
+    type Validation = DeferredWork;
+    type MaxBatchSize = MaxBatchSize;
+    type MaxEntryLen = MaxEntryLen;
+    type MaxReportedFailures = MaxReportedFailures;
+    type StagingPeriod = StagingPeriod;
+    type DepositBase = DepositBase;
+    type DepositPerByte = DepositPerByte;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockImporters;
+}
+
+/// Approved importer.
+pub const ALICE: u64 = 1;
+/// Approved importer.
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+
+pub(crate) fn run_to_block(n: u64) {
+    use frame_support::traits::Hooks;
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        DeferredWork::on_idle(next, Weight::MAX);
+    }
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, 1_000), (BOB, 1_000), (CHARLIE, 1_000)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        MockImporters::approve(ALICE);
+        MockImporters::approve(BOB);
+    });
+    ext
+}
@@ -0,0 +1,288 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    BatchStatus, Batches, Entries, EntriesOf, Error, Event, HoldReason, ValidationReport,
+    ValidationTask, mock::*,
+};
+use frame_support::{
+    assert_noop, assert_ok, pallet_prelude::Encode, traits::fungible::InspectHold,
+};
+use sp_runtime::DispatchError;
+
+fn entries(works: &[u32]) -> EntriesOf<Test> {
+    works
+        .iter()
+        .map(|work| work.encode().try_into().unwrap())
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+fn stage(importer: u64, works: &[u32]) -> sp_runtime::DispatchResult {
+    ImportStaging::stage(RuntimeOrigin::signed(importer), entries(works))
+}
+
+/// Deposit of a batch of `n` works, each encoded on 4 bytes.
+fn deposit(n: u64) -> Balance {
+    DEPOSIT_BASE + 4 * n * DEPOSIT_PER_BYTE
+}
+
+fn deposit_held(who: u64) -> Balance {
+    Balances::balance_on_hold(&HoldReason::StagingDeposit.into(), &who)
+}
+
+fn status(batch: u32) -> BatchStatus<u64> {
+    Batches::<Test>::get(batch).unwrap().status
+}
+
+#[test]
+fn stage_holds_deposit_and_queues_validation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(stage(ALICE, &[1, 2, 3]));
+
+        let staged = Batches::<Test>::get(0).unwrap();
+        assert_eq!(staged.importer, ALICE);
+        assert_eq!(staged.entries, 3);
+        assert_eq!(staged.deposit, deposit(3));
+        assert_eq!(staged.status, BatchStatus::Validating);
+        assert_eq!(deposit_held(ALICE), deposit(3));
+        assert_eq!(Entries::<Test>::get(0, 2).unwrap().to_vec(), 3u32.encode());
+        assert_eq!(
+            pallet_deferred_work::Tasks::<Test>::get(0),
+            Some(ValidationTask { batch: 0, next: 0 })
+        );
+        System::assert_last_event(
+            Event::BatchStaged {
+                batch: 0,
+                importer: ALICE,
+                entries: 3,
+                deposit: deposit(3),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn stage_requires_an_approved_importer_and_entries() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(stage(CHARLIE, &[1]), Error::<Test>::NotImporter);
+        assert_noop!(stage(ALICE, &[]), Error::<Test>::EmptyBatch);
+    });
+}
+
+#[test]
+fn stage_backs_off_when_validation_is_congested() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(stage(ALICE, &[1]));
+        assert_ok!(stage(BOB, &[2]));
+
+        assert_noop!(stage(ALICE, &[3]), Error::<Test>::ValidationCongested);
+
+        run_to_block(2);
+        assert_ok!(stage(ALICE, &[3]));
+    });
+}
+
+#[test]
+fn validation_runs_across_blocks_without_importing() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(stage(ALICE, &[1, 2, 3]));
+
+        run_to_block(2);
+        assert_eq!(status(0), BatchStatus::Validating);
+        assert_eq!(Batches::<Test>::get(0).unwrap().report.checked, 2);
+
+        run_to_block(3);
+        let staged = Batches::<Test>::get(0).unwrap();
+        assert_eq!(
+            staged.status,
+            BatchStatus::Valid {
+                expires_at: 3 + STAGING_PERIOD
+            }
+        );
+        assert_eq!(
+            staged.report,
+            ValidationReport {
+                checked: 3,
+                failed: 0,
+                failures: Default::default(),
+            }
+        );
+        System::assert_has_event(
+            Event::BatchValidated {
+                batch: 0,
+                failed: 0,
+            }
+            .into(),
+        );
+        assert_eq!(MockCatalog::registrant(1), None);
+    });
+}
+
+#[test]
+fn validation_reports_failing_entries() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(stage(BOB, &[2]));
+        run_to_block(2);
+        assert_ok!(ImportStaging::commit(RuntimeOrigin::signed(BOB), 0));
+
+        assert_ok!(stage(ALICE, &[0, 2, 5, 0]));
+        run_to_block(4);
+
+        let staged = Batches::<Test>::get(1).unwrap();
+        assert_eq!(
+            staged.status,
+            BatchStatus::Rejected {
+                expires_at: 4 + STAGING_PERIOD
+            }
+        );
+        // Only the first `MaxReportedFailures` failures are detailed.
+        assert_eq!(staged.report.checked, 4);
+        assert_eq!(staged.report.failed, 3);
+        assert_eq!(
+            staged
+                .report
+                .failures
+                .iter()
+                .map(|failure| failure.index)
+                .collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert_eq!(MockCatalog::registrant(5), None);
+    });
+}
+
+#[test]
+fn commit_imports_every_entry_and_releases_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(stage(ALICE, &[1, 2, 3]));
+        run_to_block(3);
+
+        assert_ok!(ImportStaging::commit(RuntimeOrigin::signed(ALICE), 0));
+
+        for work in [1, 2, 3] {
+            assert_eq!(MockCatalog::registrant(work), Some(ALICE));
+        }
+        assert!(!Batches::<Test>::contains_key(0));
+        assert_eq!(Entries::<Test>::iter_prefix(0).count(), 0);
+        assert_eq!(deposit_held(ALICE), 0);
+        System::assert_last_event(
+            Event::BatchCommitted {
+                batch: 0,
+                entries: 3,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn commit_requires_a_valid_batch() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ImportStaging::commit(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::BatchNotFound
+        );
+
+        assert_ok!(stage(ALICE, &[1]));
+        assert_ok!(stage(BOB, &[0]));
+        assert_noop!(
+            ImportStaging::commit(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::NotValidated
+        );
+
+        run_to_block(2);
+        assert_noop!(
+            ImportStaging::commit(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::NotBatchImporter
+        );
+        assert_noop!(
+            ImportStaging::commit(RuntimeOrigin::signed(BOB), 1),
+            Error::<Test>::BatchRejected
+        );
+
+        MockImporters::revoke(ALICE);
+        assert_noop!(
+            ImportStaging::commit(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::NotImporter
+        );
+    });
+}
+
+#[test]
+fn commit_imports_nothing_if_the_catalog_changed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(stage(ALICE, &[1, 2]));
+        assert_ok!(stage(BOB, &[3, 2]));
+        run_to_block(3);
+        assert!(matches!(status(1), BatchStatus::Valid { .. }));
+
+        assert_ok!(ImportStaging::commit(RuntimeOrigin::signed(ALICE), 0));
+
+        assert_noop!(
+            ImportStaging::commit(RuntimeOrigin::signed(BOB), 1),
+            DispatchError::Other("duplicate work")
+        );
+        assert_eq!(MockCatalog::registrant(3), None);
+        assert_eq!(deposit_held(BOB), deposit(2));
+    });
+}
+
+#[test]
+fn importer_can_discard_any_time() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(stage(ALICE, &[1, 2, 3]));
+        run_to_block(2);
+
+        assert_ok!(ImportStaging::discard(RuntimeOrigin::signed(ALICE), 0));
+
+        assert!(!Batches::<Test>::contains_key(0));
+        assert_eq!(Entries::<Test>::iter_prefix(0).count(), 0);
+        assert_eq!(deposit_held(ALICE), 0);
+        System::assert_last_event(Event::BatchDiscarded { batch: 0 }.into());
+
+        // The rest of the validation is dropped.
+        run_to_block(3);
+        assert_eq!(pallet_deferred_work::QueueDepth::<Test>::get(), 0);
+        System::assert_last_event(pallet_deferred_work::Event::TaskCompleted { id: 0 }.into());
+    });
+}
+
+#[test]
+fn others_can_discard_expired_batches() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(stage(ALICE, &[1]));
+        assert_noop!(
+            ImportStaging::discard(RuntimeOrigin::signed(CHARLIE), 0),
+            Error::<Test>::BatchNotExpired
+        );
+
+        run_to_block(2 + STAGING_PERIOD - 1);
+        assert_noop!(
+            ImportStaging::discard(RuntimeOrigin::signed(CHARLIE), 0),
+            Error::<Test>::BatchNotExpired
+        );
+
+        run_to_block(2 + STAGING_PERIOD);
+        assert_ok!(ImportStaging::discard(RuntimeOrigin::signed(CHARLIE), 0));
+        assert_eq!(deposit_held(ALICE), 0);
+        assert_eq!(deposit_held(CHARLIE), 0);
+    });
+}
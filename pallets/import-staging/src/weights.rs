@@ -0,0 +1,78 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_import_staging.
+pub trait WeightInfo {
+    fn stage(e: u32) -> Weight;
+    fn commit(e: u32) -> Weight;
+    fn discard(e: u32) -> Weight;
+    fn validate_step() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `ImportStaging::NextBatchId` (r:1 w:1)
+    /// Storage: `DeferredWork::QueueDepth` (r:2 w:1)
+    /// Storage: `DeferredWork::NextTaskId` (r:1 w:1)
+    /// Storage: `DeferredWork::Tasks` (r:0 w:1)
+    /// Storage: `ImportStaging::Batches` (r:0 w:1)
+    /// Storage: `ImportStaging::Entries` (r:0 w:e)
+    /// The range of component `e` is `[1, 128]`.
+    fn stage(e: u32) -> Weight {
+        Weight::from_parts(45_000_000, 4_000)
+            .saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(e.into()))
+            .saturating_add(ParityDbWeight::get().reads(5_u64))
+            .saturating_add(ParityDbWeight::get().writes(6_u64))
+            .saturating_add(ParityDbWeight::get().writes((1_u64).saturating_mul(e.into())))
+    }
+    /// Storage: `ImportStaging::Batches` (r:1 w:1)
+    /// Storage: `ImportStaging::Entries` (r:e w:e)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Imports are weighed apart, by `CatalogImport::entry_weight`.
+    /// The range of component `e` is `[1, 128]`.
+    fn commit(e: u32) -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(Weight::from_parts(4_000_000, 2_600).saturating_mul(e.into()))
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().reads((1_u64).saturating_mul(e.into())))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+            .saturating_add(ParityDbWeight::get().writes((1_u64).saturating_mul(e.into())))
+    }
+    /// Storage: `ImportStaging::Batches` (r:1 w:1)
+    /// Storage: `ImportStaging::Entries` (r:0 w:e)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `e` is `[1, 128]`.
+    fn discard(e: u32) -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(e.into()))
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+            .saturating_add(ParityDbWeight::get().writes((1_u64).saturating_mul(e.into())))
+    }
+    /// Storage: `ImportStaging::Batches` (r:1 w:1)
+    /// Storage: `ImportStaging::Entries` (r:1 w:0)
+    /// The dry-run import is weighed apart, by `CatalogImport::entry_weight`.
+    fn validate_step() -> Weight {
+        Weight::from_parts(20_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,157 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn fill_importers<T: Config>(n: u32) {
+    for i in 0..n {
+        let who: T::AccountId = account("importer", i, SEED);
+        Importers::<T>::insert(&who, ());
+    }
+    ImporterCount::<T>::put(n);
+}
+
+/// Schedules `n` back-to-back windows of 10 blocks, the first one opening at `from`.
+fn fill_windows<T: Config>(from: u32, n: u32) {
+    for i in 0..n {
+        let start = from + i * 10;
+        Pallet::<T>::schedule_window(
+            RawOrigin::Root.into(),
+            start.into(),
+            (start + 10).into(),
+            T::MaxPriorityBoost::get(),
+            u32::MAX,
+        )
+        .expect("windows do not overlap");
+    }
+}
+
+fn fill_quota<T: Config>(window: WindowId) {
+    for i in 0..T::MaxImporters::get() {
+        let who: T::AccountId = account("importer", i, SEED);
+        QuotaUsed::<T>::insert(window, who, 1);
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn add_importer() {
+        fill_importers::<T>(T::MaxImporters::get() - 1);
+        let who: T::AccountId = account("new", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, who.clone());
+
+        assert!(Importers::<T>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn remove_importer() {
+        fill_importers::<T>(T::MaxImporters::get());
+        let who: T::AccountId = account("importer", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, who.clone());
+
+        assert!(!Importers::<T>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn schedule_window() {
+        let max = T::MaxScheduledWindows::get();
+        fill_windows::<T>(100, max - 1);
+        // Inserted in front so that the whole schedule shifts.
+        let start: BlockNumberFor<T> = 10u32.into();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            start,
+            start.saturating_add(10u32.into()),
+            T::MaxPriorityBoost::get(),
+            u32::MAX,
+        );
+
+        assert_eq!(Windows::<T>::get().len() as u32, max);
+    }
+
+    #[benchmark]
+    fn cancel_window() {
+        fill_windows::<T>(10, T::MaxScheduledWindows::get());
+        fill_quota::<T>(0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, 0);
+
+        assert_eq!(QuotaUsed::<T>::iter_prefix(0).count(), 0);
+    }
+
+    #[benchmark]
+    fn on_initialize_noop() {
+        fill_windows::<T>(10, T::MaxScheduledWindows::get());
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(1u32.into());
+        }
+    }
+
+    #[benchmark]
+    fn on_initialize_close(w: Linear<1, { T::MaxScheduledWindows::get() }>) {
+        fill_windows::<T>(10, w);
+        for id in 0..w {
+            fill_quota::<T>(id);
+        }
+        let now: BlockNumberFor<T> = (10 + w * 10).into();
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(now);
+        }
+
+        assert!(Windows::<T>::get().is_empty());
+    }
+
+    #[benchmark]
+    fn prioritize_catalog_import() {
+        fill_importers::<T>(1);
+        fill_windows::<T>(1, T::MaxScheduledWindows::get());
+        let who: T::AccountId = account("importer", 0, SEED);
+        frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+        // Same storage accesses as the extension's `validate` + `prepare`; `ImportCalls` is a
+        // pure match on the call.
+        #[block]
+        {
+            let window = Pallet::<T>::open_window(frame_system::Pallet::<T>::block_number())
+                .expect("window open");
+            assert!(Importers::<T>::contains_key(&who));
+            assert!(QuotaUsed::<T>::get(window.id, &who) < window.quota_per_importer);
+            QuotaUsed::<T>::mutate(window.id, &who, |used| *used = used.saturating_add(1));
+        }
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,107 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Config, Pallet, QuotaUsed, WeightInfo, WindowId};
+use core::marker::PhantomData;
+use frame_support::{
+    DefaultNoBound,
+    pallet_prelude::{Decode, DecodeWithMemTracking, Encode, TransactionSource, TypeInfo},
+    traits::OriginTrait,
+};
+use sp_runtime::{
+    Weight,
+    traits::{DispatchInfoOf, DispatchOriginOf, Implication, TransactionExtension, ValidateResult},
+    transaction_validity::{TransactionValidityError, ValidTransaction},
+};
+
+/// Raises the priority of batch registration transactions sent by approved importers while an
+/// import window is open, and counts them against the importer's quota once included.
+#[derive(Encode, Decode, DecodeWithMemTracking, DefaultNoBound, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct PrioritizeCatalogImport<T>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> core::fmt::Debug for PrioritizeCatalogImport<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "PrioritizeCatalogImport")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config + Send + Sync> PrioritizeCatalogImport<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<T::RuntimeCall> for PrioritizeCatalogImport<T> {
+    const IDENTIFIER: &'static str = "PrioritizeCatalogImport";
+    type Implicit = ();
+    /// The window and importer to charge the quota of, for boosted transactions.
+    type Val = Option<(WindowId, T::AccountId)>;
+    type Pre = ();
+
+    fn weight(&self, _: &T::RuntimeCall) -> Weight {
+        T::WeightInfo::prioritize_catalog_import()
+    }
+
+    fn validate(
+        &self,
+        origin: DispatchOriginOf<T::RuntimeCall>,
+        call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+        _self_implicit: Self::Implicit,
+        _inherited_implication: &impl Implication,
+        _source: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        let boost = origin
+            .as_signer()
+            .and_then(|who| Pallet::<T>::boost_for(who, call).map(|b| (who.clone(), b)));
+
+        match boost {
+            Some((who, (window, priority))) => Ok((
+                ValidTransaction {
+                    priority,
+                    ..Default::default()
+                },
+                Some((window, who)),
+                origin,
+            )),
+            None => Ok((Default::default(), None, origin)),
+        }
+    }
+
+    fn prepare(
+        self,
+        val: Self::Val,
+        _origin: &DispatchOriginOf<T::RuntimeCall>,
+        _call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        if let Some((window, who)) = val {
+            QuotaUsed::<T>::mutate(window, who, |used| *used = used.saturating_add(1));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Import Windows
+//!
+//! Governance-scheduled catalog import windows, smoothing large onboarding events without
+//! permanent transaction pool policy changes.
+//!
+//! ## Features
+//! - `AdminOrigin` approves importer accounts and schedules non-overlapping windows.
+//! - While a window is open, batch registration calls (`Config::ImportCalls`) signed by an
+//!   approved importer get the window's priority boost through the
+//!   [`PrioritizeCatalogImport`] transaction extension.
+//! - Each importer gets a per-window quota of boosted transactions; past it, their
+//!   transactions are validated with the regular priority again.
+//! - Expired windows and their quota counters are pruned in `on_initialize`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod extension;
+pub use extension::PrioritizeCatalogImport;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{pallet_prelude::*, traits::Contains};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{Saturating, transaction_validity::TransactionPriority};
+
+pub type WindowId = u32;
+
+pub type ImportWindowOf<T> = ImportWindow<BlockNumberFor<T>>;
+
+/// A scheduled catalog import window, open on `[start, end)`.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ImportWindow<BlockNumber> {
+    pub id: WindowId,
+    pub start: BlockNumber,
+    pub end: BlockNumber,
+    /// Priority added to the importers' batch registration transactions.
+    pub priority_boost: TransactionPriority,
+    /// Boosted transactions each importer may land during the window.
+    pub quota_per_importer: u32,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Origin allowed to manage importers and windows.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Batch registration calls eligible for a boost.
+        type ImportCalls: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Max number of approved importers.
+        #[pallet::constant]
+        type MaxImporters: Get<u32>;
+
+        /// Max number of windows scheduled at once, including the open one.
+        #[pallet::constant]
+        type MaxScheduledWindows: Get<u32>;
+
+        /// Upper bound on a window's `priority_boost`.
+        #[pallet::constant]
+        type MaxPriorityBoost: Get<TransactionPriority>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Accounts approved to use import windows.
+    #[pallet::storage]
+    pub type Importers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    #[pallet::storage]
+    pub type ImporterCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Scheduled windows, sorted by start and non-overlapping.
+    #[pallet::storage]
+    pub type Windows<T: Config> =
+        StorageValue<_, BoundedVec<ImportWindowOf<T>, T::MaxScheduledWindows>, ValueQuery>;
+
+    #[pallet::storage]
+    pub type NextWindowId<T: Config> = StorageValue<_, WindowId, ValueQuery>;
+
+    /// Boosted transactions landed per window and importer.
+    #[pallet::storage]
+    pub type QuotaUsed<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        WindowId,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        ImporterAdded(T::AccountId),
+        ImporterRemoved(T::AccountId),
+        WindowScheduled(ImportWindowOf<T>),
+        WindowCancelled(WindowId),
+        WindowClosed(WindowId),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        ImporterAlreadyPresent,
+        ImporterNotFound,
+        TooManyImporters,
+        /// The window ends before it starts, or starts in the past.
+        InvalidWindow,
+        WindowOverlap,
+        TooManyWindows,
+        WindowNotFound,
+        PriorityBoostTooHigh,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let mut windows = Windows::<T>::get();
+            let expired = windows.iter().take_while(|w| w.end <= now).count() as u32;
+            if expired == 0 {
+                return T::WeightInfo::on_initialize_noop();
+            }
+
+            for window in windows.drain(..expired as usize) {
+                // At most `MaxImporters` counters can exist for a window.
+                let _ = QuotaUsed::<T>::clear_prefix(window.id, T::MaxImporters::get(), None);
+                Self::deposit_event(Event::WindowClosed(window.id));
+            }
+            Windows::<T>::put(windows);
+
+            T::WeightInfo::on_initialize_close(expired)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Approve `who` as a catalog importer.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_importer())]
+        pub fn add_importer(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !Importers::<T>::contains_key(&who),
+                Error::<T>::ImporterAlreadyPresent
+            );
+            let count = ImporterCount::<T>::get();
+            ensure!(count < T::MaxImporters::get(), Error::<T>::TooManyImporters);
+
+            Importers::<T>::insert(&who, ());
+            ImporterCount::<T>::put(count.saturating_add(1));
+            Self::deposit_event(Event::ImporterAdded(who));
+            Ok(())
+        }
+
+        /// Revoke the importer approval of `who`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_importer())]
+        pub fn remove_importer(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                Importers::<T>::take(&who).is_some(),
+                Error::<T>::ImporterNotFound
+            );
+            ImporterCount::<T>::mutate(|count| count.saturating_dec());
+            Self::deposit_event(Event::ImporterRemoved(who));
+            Ok(())
+        }
+
+        /// Schedule an import window open on `[start, end)`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::schedule_window())]
+        pub fn schedule_window(
+            origin: OriginFor<T>,
+            start: BlockNumberFor<T>,
+            end: BlockNumberFor<T>,
+            priority_boost: TransactionPriority,
+            quota_per_importer: u32,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(start >= now && end > start, Error::<T>::InvalidWindow);
+            ensure!(
+                priority_boost <= T::MaxPriorityBoost::get(),
+                Error::<T>::PriorityBoostTooHigh
+            );
+
+            let mut windows = Windows::<T>::get();
+            let pos = windows.partition_point(|w| w.start < start);
+            let overlaps_previous = pos > 0 && windows[pos - 1].end > start;
+            let overlaps_next = windows.get(pos).is_some_and(|w| w.start < end);
+            ensure!(
+                !overlaps_previous && !overlaps_next,
+                Error::<T>::WindowOverlap
+            );
+
+            let id = NextWindowId::<T>::get();
+            let window = ImportWindow {
+                id,
+                start,
+                end,
+                priority_boost,
+                quota_per_importer,
+            };
+            windows
+                .try_insert(pos, window.clone())
+                .map_err(|_| Error::<T>::TooManyWindows)?;
+
+            Windows::<T>::put(windows);
+            NextWindowId::<T>::put(id.saturating_add(1));
+            Self::deposit_event(Event::WindowScheduled(window));
+            Ok(())
+        }
+
+        /// Cancel a scheduled or open window.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::cancel_window())]
+        pub fn cancel_window(origin: OriginFor<T>, id: WindowId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let mut windows = Windows::<T>::get();
+            let pos = windows
+                .iter()
+                .position(|w| w.id == id)
+                .ok_or(Error::<T>::WindowNotFound)?;
+            windows.remove(pos);
+
+            Windows::<T>::put(windows);
+            let _ = QuotaUsed::<T>::clear_prefix(id, T::MaxImporters::get(), None);
+            Self::deposit_event(Event::WindowCancelled(id));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The window open at `now`, if any.
+        pub fn open_window(now: BlockNumberFor<T>) -> Option<ImportWindowOf<T>> {
+            Windows::<T>::get()
+                .into_iter()
+                .find(|w| w.start <= now && now < w.end)
+        }
+
+        /// The window and priority boost `who` is entitled to for `call` right now, if any.
+        pub fn boost_for(
+            who: &T::AccountId,
+            call: &<T as frame_system::Config>::RuntimeCall,
+        ) -> Option<(WindowId, TransactionPriority)> {
+            if !T::ImportCalls::contains(call) || !Importers::<T>::contains_key(who) {
+                return None;
+            }
+            let window = Self::open_window(frame_system::Pallet::<T>::block_number())?;
+            (QuotaUsed::<T>::get(window.id, who) < window.quota_per_importer)
+                .then_some((window.id, window.priority_boost))
+        }
+    }
+}
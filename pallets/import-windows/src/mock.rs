@@ -0,0 +1,94 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_import_windows;
+use frame_support::{derive_impl, parameter_types, sp_runtime::BuildStorage, traits::Contains};
+use frame_system::EnsureRoot;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type ImportWindows = pallet_import_windows;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+parameter_types! {
+    pub const MaxImporters: u32 = 3;
+    pub const MaxScheduledWindows: u32 = 2;
+    pub const MaxPriorityBoost: u64 = 1_000;
+}
+
+/// `System::remark` stands in for a batch registration call.
+pub struct RemarkIsImport;
+impl Contains<RuntimeCall> for RemarkIsImport {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::System(frame_system::Call::remark { .. }))
+    }
+}
+
+impl pallet_import_windows::Config for Test {
+    type AdminOrigin = EnsureRoot<Self::AccountId>;
+    type ImportCalls = RemarkIsImport;
+    type MaxImporters = MaxImporters;
+    type MaxScheduledWindows = MaxScheduledWindows;
+    type MaxPriorityBoost = MaxPriorityBoost;
+    type WeightInfo = ();
+}
+
+pub const IMPORTER: u64 = 1;
+pub const OUTSIDER: u64 = 2;
+
+pub(crate) fn run_to_block(n: u64) {
+    use frame_support::traits::Hooks;
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        ImportWindows::on_initialize(next);
+    }
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
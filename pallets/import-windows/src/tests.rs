@@ -0,0 +1,227 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error, Event, ImporterCount, PrioritizeCatalogImport, QuotaUsed, Windows, mock::*};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchInfo};
+use sp_runtime::{
+    DispatchError,
+    traits::DispatchTransaction,
+    transaction_validity::{TransactionPriority, TransactionSource},
+};
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn non_import_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::set_heap_pages { pages: 1 })
+}
+
+fn priority_of(who: u64, call: &RuntimeCall) -> TransactionPriority {
+    PrioritizeCatalogImport::<Test>::new()
+        .validate_only(
+            Some(who).into(),
+            call,
+            &DispatchInfo::default(),
+            0,
+            TransactionSource::External,
+            0,
+        )
+        .unwrap()
+        .0
+        .priority
+}
+
+fn include(who: u64, call: &RuntimeCall) {
+    assert_ok!(PrioritizeCatalogImport::<Test>::new().validate_and_prepare(
+        Some(who).into(),
+        call,
+        &DispatchInfo::default(),
+        0,
+        0,
+    ));
+}
+
+fn setup_window(start: u64, end: u64, boost: TransactionPriority, quota: u32) {
+    assert_ok!(ImportWindows::add_importer(RuntimeOrigin::root(), IMPORTER));
+    assert_ok!(ImportWindows::schedule_window(
+        RuntimeOrigin::root(),
+        start,
+        end,
+        boost,
+        quota
+    ));
+}
+
+#[test]
+fn importers_are_managed_by_admin_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ImportWindows::add_importer(RuntimeOrigin::signed(OUTSIDER), IMPORTER),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(ImportWindows::add_importer(RuntimeOrigin::root(), IMPORTER));
+        System::assert_last_event(Event::ImporterAdded(IMPORTER).into());
+        assert_noop!(
+            ImportWindows::add_importer(RuntimeOrigin::root(), IMPORTER),
+            Error::<Test>::ImporterAlreadyPresent
+        );
+
+        assert_ok!(ImportWindows::add_importer(RuntimeOrigin::root(), 10));
+        assert_ok!(ImportWindows::add_importer(RuntimeOrigin::root(), 11));
+        assert_noop!(
+            ImportWindows::add_importer(RuntimeOrigin::root(), 12),
+            Error::<Test>::TooManyImporters
+        );
+
+        assert_ok!(ImportWindows::remove_importer(RuntimeOrigin::root(), 10));
+        assert_eq!(ImporterCount::<Test>::get(), 2);
+        assert_noop!(
+            ImportWindows::remove_importer(RuntimeOrigin::root(), 10),
+            Error::<Test>::ImporterNotFound
+        );
+    });
+}
+
+#[test]
+fn windows_are_validated_and_kept_sorted() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ImportWindows::schedule_window(RuntimeOrigin::root(), 10, 10, 1, 1),
+            Error::<Test>::InvalidWindow
+        );
+        assert_noop!(
+            ImportWindows::schedule_window(RuntimeOrigin::root(), 0, 10, 1, 1),
+            Error::<Test>::InvalidWindow
+        );
+        assert_noop!(
+            ImportWindows::schedule_window(RuntimeOrigin::root(), 5, 10, 1_001, 1),
+            Error::<Test>::PriorityBoostTooHigh
+        );
+
+        assert_ok!(ImportWindows::schedule_window(
+            RuntimeOrigin::root(),
+            20,
+            30,
+            1,
+            1
+        ));
+        assert_ok!(ImportWindows::schedule_window(
+            RuntimeOrigin::root(),
+            5,
+            10,
+            1,
+            1
+        ));
+        assert_eq!(
+            Windows::<Test>::get()
+                .iter()
+                .map(|w| (w.id, w.start))
+                .collect::<Vec<_>>(),
+            vec![(1, 5), (0, 20)]
+        );
+
+        assert_noop!(
+            ImportWindows::schedule_window(RuntimeOrigin::root(), 12, 15, 1, 1),
+            Error::<Test>::TooManyWindows
+        );
+        assert_ok!(ImportWindows::cancel_window(RuntimeOrigin::root(), 1));
+        System::assert_last_event(Event::WindowCancelled(1).into());
+
+        assert_noop!(
+            ImportWindows::schedule_window(RuntimeOrigin::root(), 25, 40, 1, 1),
+            Error::<Test>::WindowOverlap
+        );
+        assert_noop!(
+            ImportWindows::schedule_window(RuntimeOrigin::root(), 15, 21, 1, 1),
+            Error::<Test>::WindowOverlap
+        );
+        assert_ok!(ImportWindows::schedule_window(
+            RuntimeOrigin::root(),
+            30,
+            40,
+            1,
+            1
+        ));
+        assert_noop!(
+            ImportWindows::cancel_window(RuntimeOrigin::root(), 7),
+            Error::<Test>::WindowNotFound
+        );
+    });
+}
+
+#[test]
+fn boost_applies_only_inside_open_window() {
+    new_test_ext().execute_with(|| {
+        setup_window(5, 10, 500, 10);
+
+        assert_eq!(priority_of(IMPORTER, &remark()), 0);
+        run_to_block(5);
+        assert_eq!(priority_of(IMPORTER, &remark()), 500);
+        run_to_block(10);
+        assert_eq!(priority_of(IMPORTER, &remark()), 0);
+    });
+}
+
+#[test]
+fn boost_requires_approved_importer_and_import_call() {
+    new_test_ext().execute_with(|| {
+        setup_window(1, 10, 500, 10);
+
+        assert_eq!(priority_of(IMPORTER, &remark()), 500);
+        assert_eq!(priority_of(IMPORTER, &non_import_call()), 0);
+        assert_eq!(priority_of(OUTSIDER, &remark()), 0);
+
+        assert_ok!(ImportWindows::remove_importer(
+            RuntimeOrigin::root(),
+            IMPORTER
+        ));
+        assert_eq!(priority_of(IMPORTER, &remark()), 0);
+    });
+}
+
+#[test]
+fn boost_stops_once_quota_is_used() {
+    new_test_ext().execute_with(|| {
+        setup_window(1, 10, 500, 2);
+
+        include(IMPORTER, &remark());
+        assert_eq!(priority_of(IMPORTER, &remark()), 500);
+        include(IMPORTER, &remark());
+        assert_eq!(QuotaUsed::<Test>::get(0, IMPORTER), 2);
+
+        // Still valid, just no longer boosted.
+        assert_eq!(priority_of(IMPORTER, &remark()), 0);
+        include(IMPORTER, &remark());
+        assert_eq!(QuotaUsed::<Test>::get(0, IMPORTER), 2);
+    });
+}
+
+#[test]
+fn expired_windows_are_pruned_with_their_quota() {
+    new_test_ext().execute_with(|| {
+        setup_window(1, 5, 500, 2);
+        include(IMPORTER, &remark());
+
+        run_to_block(5);
+        assert!(Windows::<Test>::get().is_empty());
+        assert_eq!(QuotaUsed::<Test>::get(0, IMPORTER), 0);
+        System::assert_last_event(Event::WindowClosed(0).into());
+    });
+}
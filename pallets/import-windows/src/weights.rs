@@ -0,0 +1,84 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_import_windows.
+pub trait WeightInfo {
+    fn add_importer() -> Weight;
+    fn remove_importer() -> Weight;
+    fn schedule_window() -> Weight;
+    fn cancel_window() -> Weight;
+    fn on_initialize_noop() -> Weight;
+    fn on_initialize_close(w: u32) -> Weight;
+    fn prioritize_catalog_import() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `ImportWindows::Importers` (r:1 w:1)
+    /// Storage: `ImportWindows::ImporterCount` (r:1 w:1)
+    fn add_importer() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `ImportWindows::Importers` (r:1 w:1)
+    /// Storage: `ImportWindows::ImporterCount` (r:1 w:1)
+    fn remove_importer() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `ImportWindows::Windows` (r:1 w:1)
+    /// Storage: `ImportWindows::NextWindowId` (r:1 w:1)
+    fn schedule_window() -> Weight {
+        Weight::from_parts(18_000_000, 2_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `ImportWindows::Windows` (r:1 w:1)
+    /// Storage: `ImportWindows::QuotaUsed` (r:0 w:`MaxImporters`)
+    fn cancel_window() -> Weight {
+        Weight::from_parts(40_000_000, 2_500)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(65_u64))
+    }
+    /// Storage: `ImportWindows::Windows` (r:1 w:0)
+    fn on_initialize_noop() -> Weight {
+        Weight::from_parts(3_000_000, 2_500).saturating_add(ParityDbWeight::get().reads(1_u64))
+    }
+    /// Storage: `ImportWindows::Windows` (r:1 w:1)
+    /// Storage: `ImportWindows::QuotaUsed` (r:0 w:`MaxImporters` per window)
+    /// The range of component `w` is `[1, 16]`.
+    fn on_initialize_close(w: u32) -> Weight {
+        Weight::from_parts(5_000_000, 2_500)
+            .saturating_add(Weight::from_parts(35_000_000, 0).saturating_mul(w.into()))
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(64_u64.saturating_mul(w.into())))
+    }
+    /// Storage: `ImportWindows::Importers` (r:1 w:0)
+    /// Storage: `ImportWindows::Windows` (r:1 w:0)
+    /// Storage: `ImportWindows::QuotaUsed` (r:2 w:1)
+    fn prioritize_catalog_import() -> Weight {
+        Weight::from_parts(12_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(4_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+}
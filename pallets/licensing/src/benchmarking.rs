@@ -0,0 +1,190 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+
+fn funded<T: Config>(name: &'static str) -> T::AccountId {
+    let who: T::AccountId = account(name, 0, SEED);
+    T::Currency::set_balance(&who, BalanceOf::<T>::max_value() / 4u32.into());
+    who
+}
+
+/// The largest territory an offer can list.
+fn max_territory<T: Config>() -> TerritoryOf<T> {
+    let countries: sp_runtime::Vec<CountryCode> = (0..T::MaxTerritories::get())
+        .map(|i| [b'A' + (i / 26 % 26) as u8, b'A' + (i % 26) as u8])
+        .collect();
+    Territory::Countries(countries.try_into().expect("exactly MaxTerritories codes"))
+}
+
+fn bench_price<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().max(1u32.into()) * 1_000u32.into()
+}
+
+fn bench_rights() -> SublicenseRights {
+    SublicenseRights {
+        max_depth: 2,
+        pass_through: Perbill::from_percent(10),
+    }
+}
+
+/// An open offer from a funded licensor, with the largest terms.
+fn open_offer<T: Config>() -> (T::AccountId, OfferId) {
+    let licensor = funded::<T>("licensor");
+    let asset = T::BenchmarkHelper::create_asset(0, &licensor);
+    Pallet::<T>::publish_offer(
+        RawOrigin::Signed(licensor.clone()).into(),
+        asset,
+        UsageType::Sync,
+        max_territory::<T>(),
+        1_000u32.into(),
+        bench_price::<T>(),
+        Some(bench_rights()),
+    )
+    .expect("owner publishes a valid offer");
+    (licensor, NextOfferId::<T>::get() - 1)
+}
+
+/// A license accepted from `open_offer`, with its price in escrow.
+fn escrowed_license<T: Config>() -> (T::AccountId, T::AccountId, LicenseId) {
+    let (licensor, offer_id) = open_offer::<T>();
+    let licensee = funded::<T>("licensee");
+    Pallet::<T>::accept_offer(RawOrigin::Signed(licensee.clone()).into(), offer_id)
+        .expect("funded licensee accepts");
+    (licensor, licensee, NextLicenseId::<T>::get() - 1)
+}
+
+/// An open sublicense offer on a license accepted from `open_offer`.
+fn open_sublicense_offer<T: Config>() -> (T::AccountId, T::AccountId, LicenseId, OfferId) {
+    let (licensor, licensee, license_id) = escrowed_license::<T>();
+    Pallet::<T>::offer_sublicense(
+        RawOrigin::Signed(licensee.clone()).into(),
+        license_id,
+        max_territory::<T>(),
+        1_000u32.into(),
+        bench_price::<T>(),
+    )
+    .expect("licensee sublicenses within the terms");
+    (licensor, licensee, license_id, NextOfferId::<T>::get() - 1)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn publish_offer() {
+        let licensor = funded::<T>("licensor");
+        let asset = T::BenchmarkHelper::create_asset(0, &licensor);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(licensor),
+            asset,
+            UsageType::Sync,
+            max_territory::<T>(),
+            1_000u32.into(),
+            bench_price::<T>(),
+            Some(bench_rights()),
+        );
+
+        assert!(Offers::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn withdraw_offer() {
+        let (licensor, offer_id) = open_offer::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(licensor), offer_id);
+
+        assert!(!Offers::<T>::contains_key(offer_id));
+    }
+
+    // Worst case: a sublicense, checked against its parent.
+    #[benchmark]
+    fn accept_offer() {
+        let (_, _, parent, offer_id) = open_sublicense_offer::<T>();
+        let sublicensee = funded::<T>("sublicensee");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(sublicensee), offer_id);
+
+        assert!(Sublicenses::<T>::contains_key(parent, parent + 1));
+    }
+
+    // Worst case: a sublicense, passing part of its price through to the original licensor.
+    #[benchmark]
+    fn claim_payment() {
+        let (_, licensee, _, offer_id) = open_sublicense_offer::<T>();
+        Pallet::<T>::accept_offer(
+            RawOrigin::Signed(funded::<T>("sublicensee")).into(),
+            offer_id,
+        )
+        .expect("funded sublicensee accepts");
+        let license_id = NextLicenseId::<T>::get() - 1;
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + T::EscrowPeriod::get(),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(licensee), license_id);
+
+        assert!(Licenses::<T>::get(license_id).is_some_and(|license| license.paid));
+    }
+
+    #[benchmark]
+    fn revoke(s: Linear<0, { T::MaxSublicenses::get() }>) {
+        let (licensor, _, license_id, offer_id) = open_sublicense_offer::<T>();
+        for i in 0..s {
+            let sublicensee: T::AccountId = account("sublicensee", i, SEED);
+            T::Currency::set_balance(&sublicensee, bench_price::<T>() * 2u32.into());
+            Pallet::<T>::accept_offer(RawOrigin::Signed(sublicensee).into(), offer_id)
+                .expect("funded sublicensee accepts");
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(licensor), license_id);
+
+        assert!(!Licenses::<T>::contains_key(license_id));
+        assert_eq!(Sublicenses::<T>::iter_prefix(license_id).count(), 0);
+    }
+
+    #[benchmark]
+    fn offer_sublicense() {
+        let (_, licensee, license_id) = escrowed_license::<T>();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(licensee),
+            license_id,
+            max_territory::<T>(),
+            1_000u32.into(),
+            bench_price::<T>(),
+        );
+
+        assert!(Offers::<T>::get(1).is_some_and(|offer| offer.parent == Some(license_id)));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
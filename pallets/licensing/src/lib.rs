@@ -0,0 +1,714 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Licensing
+//!
+//! Sync and mechanical licensing of works and recordings.
+//!
+//! ## Features
+//! - The owner of an asset (`Config::Assets`) publishes license offers: usage type, territory,
+//!   duration and price. A deposit is held for each open offer.
+//! - Anyone but the licensor accepts an offer, which mints a license record valid for the
+//!   offer's duration. The price is held from the licensee in escrow.
+//! - During the escrow period (`Config::EscrowPeriod`) the licensor may revoke the license,
+//!   refunding the licensee; once it elapses, the licensor claims the payment.
+//! - Offers of an asset whose owner changed can no longer be accepted.
+//! - An offer may let its licensees sublicense, down to a maximum depth. A licensee publishes
+//!   sublicense offers on their license, within its territory; sublicenses expire with the
+//!   license they stem from. Revoking a license revokes its sublicenses, refunding their
+//!   licensees, and each sublicense price passes a share through to the original licensor.
+//!
+//! Events carry the full license terms so off-chain contracting systems can mirror licenses
+//! without reading storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{Fortitude, Precision, Restriction},
+    },
+};
+use frame_system::pallet_prelude::*;
+use pallet_royalties::{AssetOwnership, OnPayout};
+use sp_runtime::{Perbill, Saturating, traits::Zero};
+
+pub type OfferId = u32;
+pub type LicenseId = u32;
+
+/// An ISO 3166-1 alpha-2 country code, e.g. `*b"FR"`.
+pub type CountryCode = [u8; 2];
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type TerritoryOf<T> = Territory<BoundedVec<CountryCode, <T as Config>::MaxTerritories>>;
+
+pub type LicenseTermsOf<T> =
+    LicenseTerms<<T as Config>::AssetId, TerritoryOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+
+pub type OfferOf<T> =
+    Offer<<T as frame_system::Config>::AccountId, LicenseTermsOf<T>, BalanceOf<T>>;
+
+pub type LicenseOf<T> =
+    License<<T as frame_system::Config>::AccountId, LicenseTermsOf<T>, BlockNumberFor<T>>;
+
+/// What a license allows the licensee to do with the asset.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum UsageType {
+    /// Synchronization with visual media (film, advertising, games, ...).
+    Sync,
+    /// Reproduction in physical or digital copies.
+    Mechanical,
+}
+
+/// Where a license applies.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum Territory<Countries> {
+    Worldwide,
+    Countries(Countries),
+}
+
+impl<Countries: AsRef<[CountryCode]>> Territory<Countries> {
+    /// Whether every country of `self` is part of `other`.
+    pub fn is_within(&self, other: &Self) -> bool {
+        match (self, other) {
+            (_, Territory::Worldwide) => true,
+            (Territory::Worldwide, Territory::Countries(_)) => false,
+            (Territory::Countries(inner), Territory::Countries(outer)) => inner
+                .as_ref()
+                .iter()
+                .all(|code| outer.as_ref().contains(code)),
+        }
+    }
+}
+
+/// Sublicensing allowed by an offer, carried down to every sublicense of its licenses.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct SublicenseRights {
+    /// Levels of sublicenses below a license minted from the offer: 1 lets its licensee
+    /// sublicense, 2 lets their sublicensees sublicense in turn, and so on.
+    pub max_depth: u8,
+    /// Share of every sublicense price paid to the original licensor.
+    pub pass_through: Perbill,
+}
+
+/// Terms of an offer, copied into every license minted from it.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct LicenseTerms<AssetId, Territory, Balance, BlockNumber> {
+    /// The licensed work or recording.
+    pub asset: AssetId,
+    pub usage: UsageType,
+    pub territory: Territory,
+    /// Validity of a license, counted from its acceptance.
+    pub duration: BlockNumber,
+    /// Paid by the licensee to the licensor.
+    pub price: Balance,
+    /// Whether and how licensees may sublicense, `None` if they may not.
+    pub sublicensing: Option<SublicenseRights>,
+}
+
+/// A published license offer.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Offer<AccountId, Terms, Balance> {
+    /// Owner of the asset at publication.
+    pub licensor: AccountId,
+    pub terms: Terms,
+    /// Amount held from `licensor` while the offer is open.
+    pub deposit: Balance,
+    /// License a sublicense offer is made under, `None` for an offer of the asset owner.
+    pub parent: Option<LicenseId>,
+}
+
+/// A license minted by accepting an offer.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct License<AccountId, Terms, BlockNumber> {
+    pub offer: OfferId,
+    pub licensor: AccountId,
+    pub licensee: AccountId,
+    pub terms: Terms,
+    pub starts_at: BlockNumber,
+    pub expires_at: BlockNumber,
+    /// Until this block the price stays in escrow and the licensor may revoke.
+    pub escrow_until: BlockNumber,
+    /// Whether the licensor claimed the escrowed price.
+    pub paid: bool,
+    /// License this one is a sublicense of, `None` for a license granted by the asset owner.
+    pub parent: Option<LicenseId>,
+    /// License granted by the asset owner this one stems from, itself if `parent` is `None`.
+    pub root: LicenseId,
+    /// Number of sublicensing steps between `root` and this license.
+    pub depth: u8,
+}
+
+impl<AccountId, Terms, BlockNumber: PartialOrd> License<AccountId, Terms, BlockNumber> {
+    /// Whether the license is in force at `now`.
+    pub fn is_active(&self, now: BlockNumber) -> bool {
+        self.starts_at <= now && now < self.expires_at
+    }
+}
+
+/// Checks that `territory` lists distinct, upper-case ISO 3166-1 alpha-2 codes, at least one.
+pub fn is_valid_territory<Countries: AsRef<[CountryCode]>>(
+    territory: &Territory<Countries>,
+) -> bool {
+    match territory {
+        Territory::Worldwide => true,
+        Territory::Countries(countries) => {
+            let countries = countries.as_ref();
+            !countries.is_empty()
+                && countries.iter().enumerate().all(|(i, code)| {
+                    code.iter().all(u8::is_ascii_uppercase) && !countries[..i].contains(code)
+                })
+        }
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, AccountId> {
+    /// Create the `i`-th asset, owned by `owner`, and return its id.
+    fn create_asset(i: u32, owner: &AccountId) -> AssetId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Identifier of a licensable work or recording.
+        type AssetId: Parameter + Member + MaxEncodedLen;
+
+        /// Resolves who may publish offers for an asset.
+        type Assets: AssetOwnership<Self::AssetId, Self::AccountId>;
+
+        /// Notified of each license price, or part of it, paid out of escrow, with its payee.
+        type OnPayout: OnPayout<Self::AccountId, BalanceOf<Self>>;
+
+        /// Max number of countries an offer's territory can list.
+        #[pallet::constant]
+        type MaxTerritories: Get<u32>;
+
+        /// Amount held from the licensor for each open offer.
+        #[pallet::constant]
+        type OfferDeposit: Get<BalanceOf<Self>>;
+
+        /// Blocks during which a license payment stays in escrow.
+        #[pallet::constant]
+        type EscrowPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Max number of sublicenses, at any depth, stemming from a license granted by an asset
+        /// owner. Bounds the work of revoking a license along with its sublicenses.
+        #[pallet::constant]
+        type MaxSublicenses: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Self::AccountId>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        OfferDeposit,
+        /// A license price awaiting the end of its escrow period.
+        LicenseEscrow,
+    }
+
+    #[pallet::storage]
+    pub type Offers<T: Config> = StorageMap<_, Twox64Concat, OfferId, OfferOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextOfferId<T: Config> = StorageValue<_, OfferId, ValueQuery>;
+
+    #[pallet::storage]
+    pub type Licenses<T: Config> =
+        StorageMap<_, Twox64Concat, LicenseId, LicenseOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextLicenseId<T: Config> = StorageValue<_, LicenseId, ValueQuery>;
+
+    /// Sublicenses of each license, walked to revoke them along with it.
+    #[pallet::storage]
+    pub type Sublicenses<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, LicenseId, Twox64Concat, LicenseId, (), OptionQuery>;
+
+    /// Number of sublicenses stemming from each license granted by an asset owner.
+    #[pallet::storage]
+    pub type SublicenseCount<T: Config> = StorageMap<_, Twox64Concat, LicenseId, u32, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        OfferPublished {
+            offer_id: OfferId,
+            licensor: T::AccountId,
+            terms: LicenseTermsOf<T>,
+            parent: Option<LicenseId>,
+        },
+        OfferWithdrawn {
+            offer_id: OfferId,
+        },
+        LicenseIssued {
+            license_id: LicenseId,
+            offer_id: OfferId,
+            licensor: T::AccountId,
+            licensee: T::AccountId,
+            terms: LicenseTermsOf<T>,
+            starts_at: BlockNumberFor<T>,
+            expires_at: BlockNumberFor<T>,
+            escrow_until: BlockNumberFor<T>,
+            parent: Option<LicenseId>,
+        },
+        PaymentReleased {
+            license_id: LicenseId,
+            licensor: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The original licensor's share of a sublicense price was paid to them.
+        PaymentPassedThrough {
+            license_id: LicenseId,
+            rights_holder: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        LicenseRevoked {
+            license_id: LicenseId,
+            licensee: T::AccountId,
+            refunded: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NotAssetOwner,
+        InvalidTerritory,
+        ZeroDuration,
+        OfferNotFound,
+        NotLicensor,
+        /// The licensor no longer owns the offer's asset.
+        OfferStale,
+        /// Licensors cannot accept their own offers.
+        SelfLicense,
+        LicenseNotFound,
+        EscrowNotElapsed,
+        EscrowElapsed,
+        AlreadyPaid,
+        ZeroSublicenseDepth,
+        NotLicensee,
+        /// The license expired or is not in force yet.
+        LicenseInactive,
+        /// The license's terms do not allow sublicensing, or not that deep.
+        SublicensingNotPermitted,
+        /// A sublicense territory must lie within the license's.
+        TerritoryNotCovered,
+        TooManySublicenses,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Publish a license offer for `asset`. Asset owner only.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::publish_offer())]
+        pub fn publish_offer(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            usage: UsageType,
+            territory: TerritoryOf<T>,
+            duration: BlockNumberFor<T>,
+            price: BalanceOf<T>,
+            sublicensing: Option<SublicenseRights>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                T::Assets::owner_of(&asset).as_ref() == Some(&who),
+                Error::<T>::NotAssetOwner
+            );
+            ensure!(is_valid_territory(&territory), Error::<T>::InvalidTerritory);
+            ensure!(!duration.is_zero(), Error::<T>::ZeroDuration);
+            ensure!(
+                sublicensing.is_none_or(|rights| rights.max_depth > 0),
+                Error::<T>::ZeroSublicenseDepth
+            );
+
+            let terms = LicenseTerms {
+                asset,
+                usage,
+                territory,
+                duration,
+                price,
+                sublicensing,
+            };
+            Self::do_publish_offer(who, terms, None)
+        }
+
+        /// Withdraw an offer and release its deposit. Licensor only.
+        ///
+        /// Licenses already minted from the offer are unaffected.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::withdraw_offer())]
+        pub fn withdraw_offer(origin: OriginFor<T>, offer_id: OfferId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let offer = Offers::<T>::get(offer_id).ok_or(Error::<T>::OfferNotFound)?;
+            ensure!(offer.licensor == who, Error::<T>::NotLicensor);
+
+            T::Currency::release(
+                &HoldReason::OfferDeposit.into(),
+                &who,
+                offer.deposit,
+                Precision::BestEffort,
+            )?;
+            Offers::<T>::remove(offer_id);
+
+            Self::deposit_event(Event::OfferWithdrawn { offer_id });
+            Ok(())
+        }
+
+        /// Accept an offer, holding its price in escrow and minting a license valid from now.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::accept_offer())]
+        pub fn accept_offer(origin: OriginFor<T>, offer_id: OfferId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let offer = Offers::<T>::get(offer_id).ok_or(Error::<T>::OfferNotFound)?;
+            ensure!(offer.licensor != who, Error::<T>::SelfLicense);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let license_id = NextLicenseId::<T>::get();
+            let mut expires_at = now.saturating_add(offer.terms.duration);
+            let (root, depth) = match offer.parent {
+                None => {
+                    ensure!(
+                        T::Assets::owner_of(&offer.terms.asset).as_ref() == Some(&offer.licensor),
+                        Error::<T>::OfferStale
+                    );
+                    (license_id, 0)
+                }
+                Some(parent_id) => {
+                    // Revoking the parent revokes its sublicenses, and with them the offers
+                    // made under it.
+                    let parent = Licenses::<T>::get(parent_id).ok_or(Error::<T>::OfferStale)?;
+                    ensure!(parent.is_active(now), Error::<T>::LicenseInactive);
+                    ensure!(
+                        SublicenseCount::<T>::get(parent.root) < T::MaxSublicenses::get(),
+                        Error::<T>::TooManySublicenses
+                    );
+                    expires_at = expires_at.min(parent.expires_at);
+                    (parent.root, parent.depth.saturating_add(1))
+                }
+            };
+
+            T::Currency::hold(&HoldReason::LicenseEscrow.into(), &who, offer.terms.price)?;
+
+            if let Some(parent_id) = offer.parent {
+                SublicenseCount::<T>::mutate(root, |count| count.saturating_inc());
+                Sublicenses::<T>::insert(parent_id, license_id, ());
+            }
+            let escrow_until = now.saturating_add(T::EscrowPeriod::get());
+            Licenses::<T>::insert(
+                license_id,
+                License {
+                    offer: offer_id,
+                    licensor: offer.licensor.clone(),
+                    licensee: who.clone(),
+                    terms: offer.terms.clone(),
+                    starts_at: now,
+                    expires_at,
+                    escrow_until,
+                    paid: false,
+                    parent: offer.parent,
+                    root,
+                    depth,
+                },
+            );
+            NextLicenseId::<T>::put(license_id.saturating_add(1));
+
+            Self::deposit_event(Event::LicenseIssued {
+                license_id,
+                offer_id,
+                licensor: offer.licensor,
+                licensee: who,
+                terms: offer.terms,
+                starts_at: now,
+                expires_at,
+                escrow_until,
+                parent: offer.parent,
+            });
+            Ok(())
+        }
+
+        /// Transfer the escrowed price of a license to its licensor once the escrow period
+        /// elapsed. Licensor only.
+        ///
+        /// For a sublicense, the share of the price its terms pass through goes to the original
+        /// licensor.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::claim_payment())]
+        pub fn claim_payment(origin: OriginFor<T>, license_id: LicenseId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut license = Licenses::<T>::get(license_id).ok_or(Error::<T>::LicenseNotFound)?;
+            ensure!(license.licensor == who, Error::<T>::NotLicensor);
+            ensure!(!license.paid, Error::<T>::AlreadyPaid);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= license.escrow_until,
+                Error::<T>::EscrowNotElapsed
+            );
+
+            let mut amount = license.terms.price;
+            let mut passed_through = None;
+            // The root outlives its sublicenses: revoking it revokes them.
+            if license.parent.is_some()
+                && let Some(rights) = license.terms.sublicensing
+                && !rights.pass_through.is_zero()
+                && let Some(root) = Licenses::<T>::get(license.root)
+            {
+                let share = rights.pass_through.mul_floor(amount);
+                Self::pay_from_escrow(&license.licensee, &root.licensor, share)?;
+                amount.saturating_reduce(share);
+                passed_through = Some((root.licensor, share));
+            }
+            Self::pay_from_escrow(&license.licensee, &who, amount)?;
+            license.paid = true;
+            Licenses::<T>::insert(license_id, license);
+
+            Self::deposit_event(Event::PaymentReleased {
+                license_id,
+                licensor: who,
+                amount,
+            });
+            if let Some((rights_holder, amount)) = passed_through {
+                Self::deposit_event(Event::PaymentPassedThrough {
+                    license_id,
+                    rights_holder,
+                    amount,
+                });
+            }
+            Ok(())
+        }
+
+        /// Revoke a license during its escrow period, refunding the licensee. Licensor only.
+        ///
+        /// Its sublicenses, at any depth, are revoked with it and their licensees refunded.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::revoke(T::MaxSublicenses::get()))]
+        pub fn revoke(origin: OriginFor<T>, license_id: LicenseId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let license = Licenses::<T>::get(license_id).ok_or(Error::<T>::LicenseNotFound)?;
+            ensure!(license.licensor == who, Error::<T>::NotLicensor);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < license.escrow_until,
+                Error::<T>::EscrowElapsed
+            );
+
+            match license.parent {
+                None => SublicenseCount::<T>::remove(license_id),
+                Some(parent) => Sublicenses::<T>::remove(parent, license_id),
+            }
+            let root = license.root;
+            let mut revoked = 0u32;
+            let mut pending = sp_runtime::Vec::from([(license_id, license)]);
+            while let Some((id, license)) = pending.pop() {
+                for (child, ()) in Sublicenses::<T>::drain_prefix(id) {
+                    if let Some(sublicense) = Licenses::<T>::get(child) {
+                        pending.push((child, sublicense));
+                    }
+                }
+                Self::do_revoke(id, license)?;
+                if id != license_id {
+                    revoked.saturating_inc();
+                }
+            }
+            if root != license_id {
+                SublicenseCount::<T>::mutate(root, |count| {
+                    *count = count.saturating_sub(revoked.saturating_add(1))
+                });
+            }
+
+            Ok(Some(T::WeightInfo::revoke(revoked)).into())
+        }
+
+        /// Publish an offer of sublicenses of `license_id`, on its asset and usage and within
+        /// its territory. Licensee only, while the license is in force and its terms allow
+        /// sublicensing at this depth.
+        ///
+        /// Sublicenses expire with the license at the latest.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::offer_sublicense())]
+        pub fn offer_sublicense(
+            origin: OriginFor<T>,
+            license_id: LicenseId,
+            territory: TerritoryOf<T>,
+            duration: BlockNumberFor<T>,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let license = Licenses::<T>::get(license_id).ok_or(Error::<T>::LicenseNotFound)?;
+            ensure!(license.licensee == who, Error::<T>::NotLicensee);
+            ensure!(
+                license.is_active(frame_system::Pallet::<T>::block_number()),
+                Error::<T>::LicenseInactive
+            );
+            ensure!(
+                license
+                    .terms
+                    .sublicensing
+                    .is_some_and(|rights| license.depth < rights.max_depth),
+                Error::<T>::SublicensingNotPermitted
+            );
+            ensure!(is_valid_territory(&territory), Error::<T>::InvalidTerritory);
+            ensure!(
+                territory.is_within(&license.terms.territory),
+                Error::<T>::TerritoryNotCovered
+            );
+            ensure!(!duration.is_zero(), Error::<T>::ZeroDuration);
+
+            let terms = LicenseTerms {
+                territory,
+                duration,
+                price,
+                ..license.terms
+            };
+            Self::do_publish_offer(who, terms, Some(license_id))
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn do_publish_offer(
+            licensor: T::AccountId,
+            terms: LicenseTermsOf<T>,
+            parent: Option<LicenseId>,
+        ) -> DispatchResult {
+            let deposit = T::OfferDeposit::get();
+            T::Currency::hold(&HoldReason::OfferDeposit.into(), &licensor, deposit)?;
+
+            let offer_id = NextOfferId::<T>::get();
+            Offers::<T>::insert(
+                offer_id,
+                Offer {
+                    licensor: licensor.clone(),
+                    terms: terms.clone(),
+                    deposit,
+                    parent,
+                },
+            );
+            NextOfferId::<T>::put(offer_id.saturating_add(1));
+
+            Self::deposit_event(Event::OfferPublished {
+                offer_id,
+                licensor,
+                terms,
+                parent,
+            });
+            Ok(())
+        }
+
+        fn pay_from_escrow(
+            licensee: &T::AccountId,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let paid = T::Currency::transfer_on_hold(
+                &HoldReason::LicenseEscrow.into(),
+                licensee,
+                to,
+                amount,
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Polite,
+            )?;
+            T::OnPayout::on_payout(to, paid);
+            Ok(())
+        }
+
+        /// Remove a license, refunding its licensee if the price is still in escrow.
+        fn do_revoke(license_id: LicenseId, license: LicenseOf<T>) -> DispatchResult {
+            let refunded = if license.paid {
+                Zero::zero()
+            } else {
+                T::Currency::release(
+                    &HoldReason::LicenseEscrow.into(),
+                    &license.licensee,
+                    license.terms.price,
+                    Precision::BestEffort,
+                )?
+            };
+            Licenses::<T>::remove(license_id);
+
+            Self::deposit_event(Event::LicenseRevoked {
+                license_id,
+                licensee: license.licensee,
+                refunded,
+            });
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,146 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_licensing;
+use frame_support::{derive_impl, parameter_types, sp_runtime::BuildStorage, storage::unhashed};
+use parity_scale_codec::Encode;
+
+pub type Balance = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type Licensing = pallet_licensing;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+}
+
+pub const OFFER_DEPOSIT: Balance = 5;
+pub const ESCROW_PERIOD: u64 = 10;
+
+parameter_types! {
+    pub const MaxTerritories: u32 = 3;
+    pub const OfferDeposit: Balance = OFFER_DEPOSIT;
+    pub const EscrowPeriod: u64 = ESCROW_PERIOD;
+    pub const MaxSublicenses: u32 = 3;
+}
+
+/// Asset owners, kept in the test externalities so that each test starts from genesis.
+pub struct MockAssets;
+
+impl MockAssets {
+    fn key(asset: u32) -> Vec<u8> {
+        (b"mock/asset-owner", asset).encode()
+    }
+
+    pub fn set_owner(asset: u32, owner: u64) {
+        unhashed::put(&Self::key(asset), &owner);
+    }
+}
+
+impl pallet_royalties::AssetOwnership<u32, u64> for MockAssets {
+    fn owner_of(asset: &u32) -> Option<u64> {
+        unhashed::get(&Self::key(*asset))
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u32, u64> for MockAssets {
+    fn create_asset(i: u32, owner: &u64) -> u32 {
+        Self::set_owner(i, *owner);
+        i
+    }
+}
+
+impl pallet_licensing::Config for Test {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetId = u32;
+    type Assets = MockAssets;
+    type OnPayout = ();
+    type MaxTerritories = MaxTerritories;
+    type OfferDeposit = OfferDeposit;
+    type EscrowPeriod = EscrowPeriod;
+    type MaxSublicenses = MaxSublicenses;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockAssets;
+}
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+pub const POOR: u64 = 4;
+pub const DAVE: u64 = 5;
+
+/// Owned by `ALICE`.
+pub const WORK: u32 = 1;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ALICE, 100),
+            (BOB, 1_000),
+            (CHARLIE, 1_000),
+            (POOR, 3),
+            (DAVE, 1_000),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        MockAssets::set_owner(WORK, ALICE);
+    });
+    ext
+}
@@ -0,0 +1,592 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Error, Event, HoldReason, LicenseTerms, Licenses, Offers, SublicenseCount, SublicenseRights,
+    Sublicenses, Territory, TerritoryOf, UsageType, is_valid_territory, mock::*,
+};
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use sp_runtime::Perbill;
+
+const PRICE: Balance = 200;
+const DURATION: u64 = 100;
+
+fn countries(codes: &[&[u8; 2]]) -> TerritoryOf<Test> {
+    Territory::Countries(
+        codes
+            .iter()
+            .map(|c| **c)
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+    )
+}
+
+fn publish(territory: TerritoryOf<Test>) -> sp_runtime::DispatchResult {
+    Licensing::publish_offer(
+        RuntimeOrigin::signed(ALICE),
+        WORK,
+        UsageType::Sync,
+        territory,
+        DURATION,
+        PRICE,
+        None,
+    )
+}
+
+fn publish_sublicensable(
+    territory: TerritoryOf<Test>,
+    max_depth: u8,
+) -> sp_runtime::DispatchResult {
+    Licensing::publish_offer(
+        RuntimeOrigin::signed(ALICE),
+        WORK,
+        UsageType::Sync,
+        territory,
+        DURATION,
+        PRICE,
+        Some(SublicenseRights {
+            max_depth,
+            pass_through: Perbill::from_percent(10),
+        }),
+    )
+}
+
+fn escrowed(who: u64) -> Balance {
+    Balances::balance_on_hold(&HoldReason::LicenseEscrow.into(), &who)
+}
+
+#[test]
+fn territories_are_validated() {
+    assert!(is_valid_territory(&Territory::<Vec<[u8; 2]>>::Worldwide));
+    assert!(is_valid_territory(&Territory::Countries(vec![
+        *b"FR", *b"US"
+    ])));
+    assert!(!is_valid_territory(&Territory::<Vec<[u8; 2]>>::Countries(
+        vec![]
+    )));
+    assert!(!is_valid_territory(&Territory::Countries(vec![*b"fr"])));
+    assert!(!is_valid_territory(&Territory::Countries(vec![*b"F1"])));
+    assert!(!is_valid_territory(&Territory::Countries(vec![
+        *b"FR", *b"FR"
+    ])));
+}
+
+#[test]
+fn territories_nest() {
+    let worldwide = Territory::<Vec<[u8; 2]>>::Worldwide;
+    let europe = Territory::Countries(vec![*b"FR", *b"BE", *b"DE"]);
+    let france = Territory::Countries(vec![*b"FR"]);
+
+    assert!(france.is_within(&europe));
+    assert!(europe.is_within(&worldwide));
+    assert!(worldwide.is_within(&worldwide));
+    assert!(!europe.is_within(&france));
+    assert!(!worldwide.is_within(&europe));
+}
+
+#[test]
+fn publish_offer_holds_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(publish(countries(&[b"FR", b"BE"])));
+
+        let offer = Offers::<Test>::get(0).unwrap();
+        assert_eq!(offer.licensor, ALICE);
+        assert_eq!(offer.deposit, OFFER_DEPOSIT);
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::OfferDeposit.into(), &ALICE),
+            OFFER_DEPOSIT
+        );
+        System::assert_last_event(
+            Event::OfferPublished {
+                offer_id: 0,
+                licensor: ALICE,
+                terms: LicenseTerms {
+                    asset: WORK,
+                    usage: UsageType::Sync,
+                    territory: countries(&[b"FR", b"BE"]),
+                    duration: DURATION,
+                    price: PRICE,
+                    sublicensing: None,
+                },
+                parent: None,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn publish_offer_validates_input() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Licensing::publish_offer(
+                RuntimeOrigin::signed(BOB),
+                WORK,
+                UsageType::Mechanical,
+                Territory::Worldwide,
+                DURATION,
+                PRICE,
+                None,
+            ),
+            Error::<Test>::NotAssetOwner
+        );
+        assert_noop!(
+            publish(countries(&[b"FR", b"fr"])),
+            Error::<Test>::InvalidTerritory
+        );
+        assert_noop!(
+            Licensing::publish_offer(
+                RuntimeOrigin::signed(ALICE),
+                WORK,
+                UsageType::Sync,
+                Territory::Worldwide,
+                0,
+                PRICE,
+                None,
+            ),
+            Error::<Test>::ZeroDuration
+        );
+
+        MockAssets::set_owner(2, POOR);
+        assert!(
+            Licensing::publish_offer(
+                RuntimeOrigin::signed(POOR),
+                2,
+                UsageType::Sync,
+                Territory::Worldwide,
+                DURATION,
+                PRICE,
+                None,
+            )
+            .is_err()
+        );
+    });
+}
+
+#[test]
+fn accept_offer_escrows_price_and_mints_license() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(publish(Territory::Worldwide));
+
+        assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(BOB), 0));
+        assert_eq!(escrowed(BOB), PRICE);
+
+        let license = Licenses::<Test>::get(0).unwrap();
+        assert_eq!(license.licensee, BOB);
+        assert_eq!(license.starts_at, 1);
+        assert_eq!(license.expires_at, 1 + DURATION);
+        assert_eq!(license.escrow_until, 1 + ESCROW_PERIOD);
+        assert!(!license.paid);
+        assert!(license.is_active(1));
+        assert!(!license.is_active(1 + DURATION));
+        System::assert_last_event(
+            Event::LicenseIssued {
+                license_id: 0,
+                offer_id: 0,
+                licensor: ALICE,
+                licensee: BOB,
+                terms: Offers::<Test>::get(0).unwrap().terms,
+                starts_at: 1,
+                expires_at: 1 + DURATION,
+                escrow_until: 1 + ESCROW_PERIOD,
+                parent: None,
+            }
+            .into(),
+        );
+
+        // Offers stay open for further licensees.
+        assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(CHARLIE), 0));
+        assert!(Licenses::<Test>::get(1).is_some());
+    });
+}
+
+#[test]
+fn accept_offer_checks_offer() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Licensing::accept_offer(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::OfferNotFound
+        );
+        assert_ok!(publish(Territory::Worldwide));
+        assert_noop!(
+            Licensing::accept_offer(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::SelfLicense
+        );
+        assert!(Licensing::accept_offer(RuntimeOrigin::signed(POOR), 0).is_err());
+
+        MockAssets::set_owner(WORK, CHARLIE);
+        assert_noop!(
+            Licensing::accept_offer(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::OfferStale
+        );
+    });
+}
+
+#[test]
+fn licensor_claims_payment_after_escrow() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(publish(Territory::Worldwide));
+        assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(BOB), 0));
+
+        assert_noop!(
+            Licensing::claim_payment(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::EscrowNotElapsed
+        );
+        System::set_block_number(1 + ESCROW_PERIOD);
+        assert_noop!(
+            Licensing::claim_payment(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::NotLicensor
+        );
+
+        assert_ok!(Licensing::claim_payment(RuntimeOrigin::signed(ALICE), 0));
+        assert_eq!(escrowed(BOB), 0);
+        assert_eq!(Balances::free_balance(BOB), 1_000 - PRICE);
+        assert_eq!(Balances::free_balance(ALICE), 100 - OFFER_DEPOSIT + PRICE);
+        assert!(Licenses::<Test>::get(0).unwrap().paid);
+        System::assert_last_event(
+            Event::PaymentReleased {
+                license_id: 0,
+                licensor: ALICE,
+                amount: PRICE,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Licensing::claim_payment(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::AlreadyPaid
+        );
+        assert_noop!(
+            Licensing::revoke(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::EscrowElapsed
+        );
+    });
+}
+
+#[test]
+fn licensor_revokes_during_escrow() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(publish(Territory::Worldwide));
+        assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(BOB), 0));
+
+        assert_noop!(
+            Licensing::revoke(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::NotLicensor
+        );
+        assert_ok!(Licensing::revoke(RuntimeOrigin::signed(ALICE), 0));
+        assert!(Licenses::<Test>::get(0).is_none());
+        assert_eq!(escrowed(BOB), 0);
+        assert_eq!(Balances::free_balance(BOB), 1_000);
+        System::assert_last_event(
+            Event::LicenseRevoked {
+                license_id: 0,
+                licensee: BOB,
+                refunded: PRICE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn withdrawn_offer_keeps_minted_licenses() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(publish(Territory::Worldwide));
+        assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(BOB), 0));
+
+        assert_noop!(
+            Licensing::withdraw_offer(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::NotLicensor
+        );
+        assert_ok!(Licensing::withdraw_offer(RuntimeOrigin::signed(ALICE), 0));
+        System::assert_last_event(Event::OfferWithdrawn { offer_id: 0 }.into());
+        assert!(Offers::<Test>::get(0).is_none());
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::OfferDeposit.into(), &ALICE),
+            0
+        );
+
+        assert!(Licenses::<Test>::get(0).is_some());
+        assert_noop!(
+            Licensing::accept_offer(RuntimeOrigin::signed(CHARLIE), 0),
+            Error::<Test>::OfferNotFound
+        );
+    });
+}
+
+#[test]
+fn free_offers_need_no_escrow() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Licensing::publish_offer(
+            RuntimeOrigin::signed(ALICE),
+            WORK,
+            UsageType::Mechanical,
+            Territory::Worldwide,
+            DURATION,
+            0,
+            None,
+        ));
+        assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(POOR), 0));
+        assert_eq!(escrowed(POOR), 0);
+    });
+}
+
+#[test]
+fn sublicensing_follows_the_grant() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            publish_sublicensable(Territory::Worldwide, 0),
+            Error::<Test>::ZeroSublicenseDepth
+        );
+
+        // Offer 0 does not allow sublicensing, offer 1 allows one level in FR and BE.
+        assert_ok!(publish(Territory::Worldwide));
+        assert_ok!(publish_sublicensable(countries(&[b"FR", b"BE"]), 1));
+        assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(BOB), 0));
+        assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(BOB), 1));
+        assert_noop!(
+            Licensing::offer_sublicense(
+                RuntimeOrigin::signed(BOB),
+                0,
+                Territory::Worldwide,
+                DURATION,
+                PRICE
+            ),
+            Error::<Test>::SublicensingNotPermitted
+        );
+        assert_noop!(
+            Licensing::offer_sublicense(
+                RuntimeOrigin::signed(CHARLIE),
+                1,
+                countries(&[b"FR"]),
+                DURATION,
+                PRICE
+            ),
+            Error::<Test>::NotLicensee
+        );
+        assert_noop!(
+            Licensing::offer_sublicense(
+                RuntimeOrigin::signed(BOB),
+                1,
+                countries(&[b"FR", b"US"]),
+                DURATION,
+                PRICE
+            ),
+            Error::<Test>::TerritoryNotCovered
+        );
+
+        System::set_block_number(11);
+        assert_ok!(Licensing::offer_sublicense(
+            RuntimeOrigin::signed(BOB),
+            1,
+            countries(&[b"FR"]),
+            DURATION,
+            PRICE
+        ));
+        let offer = Offers::<Test>::get(2).unwrap();
+        assert_eq!(offer.licensor, BOB);
+        assert_eq!(offer.parent, Some(1));
+        assert_eq!(offer.terms.asset, WORK);
+        assert_eq!(offer.terms.territory, countries(&[b"FR"]));
+
+        assert_noop!(
+            Licensing::accept_offer(RuntimeOrigin::signed(BOB), 2),
+            Error::<Test>::SelfLicense
+        );
+        assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(CHARLIE), 2));
+        let sublicense = Licenses::<Test>::get(2).unwrap();
+        assert_eq!(sublicense.licensor, BOB);
+        assert_eq!(
+            (sublicense.parent, sublicense.root, sublicense.depth),
+            (Some(1), 1, 1)
+        );
+        // Capped by the parent license, which expires first.
+        assert_eq!(sublicense.expires_at, 1 + DURATION);
+        assert!(Sublicenses::<Test>::contains_key(1, 2));
+        assert_eq!(SublicenseCount::<Test>::get(1), 1);
+
+        // One level only.
+        assert_noop!(
+            Licensing::offer_sublicense(
+                RuntimeOrigin::signed(CHARLIE),
+                2,
+                countries(&[b"FR"]),
+                DURATION,
+                PRICE
+            ),
+            Error::<Test>::SublicensingNotPermitted
+        );
+
+        System::set_block_number(1 + DURATION);
+        assert_noop!(
+            Licensing::offer_sublicense(
+                RuntimeOrigin::signed(BOB),
+                1,
+                countries(&[b"BE"]),
+                DURATION,
+                PRICE
+            ),
+            Error::<Test>::LicenseInactive
+        );
+        assert_noop!(
+            Licensing::accept_offer(RuntimeOrigin::signed(DAVE), 2),
+            Error::<Test>::LicenseInactive
+        );
+    });
+}
+
+/// ALICE licenses to BOB (0), who sublicenses to CHARLIE (1), who sublicenses to DAVE (2).
+fn sublicense_chain() {
+    assert_ok!(publish_sublicensable(Territory::Worldwide, 2));
+    assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(BOB), 0));
+    for (licensee, license, sublicensee) in [(BOB, 0, CHARLIE), (CHARLIE, 1, DAVE)] {
+        assert_ok!(Licensing::offer_sublicense(
+            RuntimeOrigin::signed(licensee),
+            license,
+            Territory::Worldwide,
+            DURATION,
+            PRICE
+        ));
+        assert_ok!(Licensing::accept_offer(
+            RuntimeOrigin::signed(sublicensee),
+            license + 1
+        ));
+    }
+}
+
+#[test]
+fn revoking_a_license_revokes_its_sublicenses() {
+    new_test_ext().execute_with(|| {
+        sublicense_chain();
+        assert_eq!(SublicenseCount::<Test>::get(0), 2);
+
+        assert_ok!(Licensing::revoke(RuntimeOrigin::signed(BOB), 1));
+        assert!(Licenses::<Test>::get(0).is_some());
+        assert!(Licenses::<Test>::get(1).is_none());
+        assert!(Licenses::<Test>::get(2).is_none());
+        assert_eq!(escrowed(CHARLIE), 0);
+        assert_eq!(escrowed(DAVE), 0);
+        assert_eq!(SublicenseCount::<Test>::get(0), 0);
+        assert!(!Sublicenses::<Test>::contains_key(0, 1));
+        assert!(!Sublicenses::<Test>::contains_key(1, 2));
+        System::assert_has_event(
+            Event::LicenseRevoked {
+                license_id: 2,
+                licensee: DAVE,
+                refunded: PRICE,
+            }
+            .into(),
+        );
+
+        // Offers made under a revoked license are stale.
+        assert_noop!(
+            Licensing::accept_offer(RuntimeOrigin::signed(DAVE), 2),
+            Error::<Test>::OfferStale
+        );
+    });
+}
+
+#[test]
+fn revoking_the_original_license_revokes_the_whole_chain() {
+    new_test_ext().execute_with(|| {
+        sublicense_chain();
+
+        assert_ok!(Licensing::revoke(RuntimeOrigin::signed(ALICE), 0));
+        for license in 0..3 {
+            assert!(Licenses::<Test>::get(license).is_none());
+        }
+        // BOB and CHARLIE still hold the deposit of their sublicense offers.
+        for who in [BOB, CHARLIE, DAVE] {
+            assert_eq!(escrowed(who), 0);
+            assert_eq!(
+                Balances::free_balance(who) + Balances::total_balance_on_hold(&who),
+                1_000
+            );
+        }
+        assert_eq!(SublicenseCount::<Test>::get(0), 0);
+    });
+}
+
+#[test]
+fn sublicense_payments_pass_through_to_the_original_licensor() {
+    new_test_ext().execute_with(|| {
+        sublicense_chain();
+        System::set_block_number(1 + ESCROW_PERIOD);
+
+        assert_ok!(Licensing::claim_payment(RuntimeOrigin::signed(CHARLIE), 2));
+        assert_eq!(escrowed(DAVE), 0);
+        assert_eq!(
+            Balances::free_balance(ALICE),
+            100 - OFFER_DEPOSIT + PRICE / 10
+        );
+        assert_eq!(
+            Balances::free_balance(CHARLIE),
+            1_000 - OFFER_DEPOSIT - PRICE + PRICE * 9 / 10
+        );
+        System::assert_has_event(
+            Event::PaymentReleased {
+                license_id: 2,
+                licensor: CHARLIE,
+                amount: PRICE * 9 / 10,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::PaymentPassedThrough {
+                license_id: 2,
+                rights_holder: ALICE,
+                amount: PRICE / 10,
+            }
+            .into(),
+        );
+
+        // The original licensor's own license pays in full.
+        assert_ok!(Licensing::claim_payment(RuntimeOrigin::signed(ALICE), 0));
+        System::assert_last_event(
+            Event::PaymentReleased {
+                license_id: 0,
+                licensor: ALICE,
+                amount: PRICE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn sublicenses_are_capped() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(publish_sublicensable(Territory::Worldwide, 1));
+        assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(BOB), 0));
+        assert_ok!(Licensing::offer_sublicense(
+            RuntimeOrigin::signed(BOB),
+            0,
+            Territory::Worldwide,
+            DURATION,
+            0
+        ));
+        for who in [CHARLIE, DAVE, POOR] {
+            assert_ok!(Licensing::accept_offer(RuntimeOrigin::signed(who), 1));
+        }
+        assert_noop!(
+            Licensing::accept_offer(RuntimeOrigin::signed(ALICE), 1),
+            Error::<Test>::TooManySublicenses
+        );
+    });
+}
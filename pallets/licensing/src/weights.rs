@@ -0,0 +1,90 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_licensing.
+pub trait WeightInfo {
+    fn publish_offer() -> Weight;
+    fn withdraw_offer() -> Weight;
+    fn accept_offer() -> Weight;
+    fn claim_payment() -> Weight;
+    fn revoke(s: u32) -> Weight;
+    fn offer_sublicense() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Assets` registry (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Licensing::NextOfferId` (r:1 w:1)
+    /// Storage: `Licensing::Offers` (r:0 w:1)
+    fn publish_offer() -> Weight {
+        Weight::from_parts(45_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Licensing::Offers` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn withdraw_offer() -> Weight {
+        Weight::from_parts(35_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Licensing::Offers` (r:1 w:0)
+    /// Storage: `Licensing::NextLicenseId` (r:1 w:1)
+    /// Storage: `Licensing::Licenses` (r:1 w:1)
+    /// Storage: `Licensing::SublicenseCount` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Licensing::Sublicenses` (r:0 w:1)
+    fn accept_offer() -> Weight {
+        Weight::from_parts(55_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(5_u64))
+            .saturating_add(ParityDbWeight::get().writes(5_u64))
+    }
+    /// Storage: `Licensing::Licenses` (r:2 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:3 w:3)
+    fn claim_payment() -> Weight {
+        Weight::from_parts(80_000_000, 8_500)
+            .saturating_add(ParityDbWeight::get().reads(6_u64))
+            .saturating_add(ParityDbWeight::get().writes(5_u64))
+    }
+    /// Storage: `Licensing::Licenses` (r:1+s w:1+s)
+    /// Storage: `Licensing::Sublicenses` (r:1+s w:1+s)
+    /// Storage: `Licensing::SublicenseCount` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1+s w:1+s)
+    /// The range of component `s` is `[0, 64]`.
+    fn revoke(s: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_500)
+            .saturating_add(Weight::from_parts(30_000_000, 4_500).saturating_mul(s.into()))
+            .saturating_add(ParityDbWeight::get().reads(4_u64))
+            .saturating_add(ParityDbWeight::get().reads((3_u64).saturating_mul(s.into())))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+            .saturating_add(ParityDbWeight::get().writes((3_u64).saturating_mul(s.into())))
+    }
+    /// Storage: `Licensing::Licenses` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Licensing::NextOfferId` (r:1 w:1)
+    /// Storage: `Licensing::Offers` (r:0 w:1)
+    fn offer_sublicense() -> Weight {
+        Weight::from_parts(45_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+}
@@ -0,0 +1,83 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+use frame_benchmarking::v2::*;
+
+/// Schedule changes of the first `n` parameters of the benchmark helper.
+fn schedule_changes<T: Config>(n: u32) {
+    let now = frame_system::Pallet::<T>::block_number();
+    let pending = (0..n)
+        .map(|i| PendingChange {
+            key_value: T::BenchmarkHelper::key_value(i),
+            scheduled_at: now,
+            enact_at: now.saturating_add(T::Delay::get()),
+        })
+        .collect::<Vec<_>>();
+    Pending::<T>::put(BoundedVec::truncate_from(pending));
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn schedule() -> Result<(), BenchmarkError> {
+        let max = T::MaxPending::get();
+        schedule_changes::<T>(max - 1);
+        let key_value = T::BenchmarkHelper::key_value(max - 1);
+        let key = key_value.clone().into_parts().0;
+        let origin = T::ScheduleOrigin::try_successful_origin(&key)
+            .map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, key_value);
+
+        assert!(Pallet::<T>::pending_change(&key).is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel() -> Result<(), BenchmarkError> {
+        let max = T::MaxPending::get();
+        schedule_changes::<T>(max);
+        let key = T::BenchmarkHelper::key_value(max - 1).into_parts().0;
+        let origin = T::ScheduleOrigin::try_successful_origin(&key)
+            .map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, key.clone());
+
+        assert!(Pallet::<T>::pending_change(&key).is_none());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_initialize(n: Linear<0, { T::MaxPending::get() }>) {
+        schedule_changes::<T>(n);
+        let enact_at = frame_system::Pallet::<T>::block_number().saturating_add(T::Delay::get());
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(enact_at);
+        }
+
+        assert!(Pending::<T>::get().is_empty());
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
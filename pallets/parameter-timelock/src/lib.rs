@@ -0,0 +1,267 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! # Pallet Parameter Timelock
+//!
+//! Applies changes of runtime parameters (`pallet-parameters`) after a mandatory delay, so every
+//! change is public for a while before it takes effect.
+//!
+//! ## Features
+//! - `ScheduleOrigin` schedules a new value for a parameter; it is applied `Config::Delay`
+//!   blocks later through `pallet_parameters::set_parameter`, which records it in the usual
+//!   `Parameters::Updated` event.
+//! - Values are checked against `Config::Bounds` when scheduled, so hard bounds set at compile
+//!   time can't be crossed.
+//! - At most one change is pending per parameter, and it can be cancelled until applied.
+//! - Changes are applied with this pallet's [`Origin::Timelocked`]. Setting
+//!   [`EnsureTimelocked`] as the `pallet_parameters` admin origin of a parameter makes the
+//!   delay mandatory: `set_parameter` can no longer change it directly.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use alloc::vec::Vec;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Contains, EnsureOriginWithArg, dynamic_params::AggregatedKeyValue},
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::Saturating;
+
+pub type KeyValueOf<T> = <T as pallet_parameters::Config>::RuntimeParameters;
+pub type KeyOf<T> = <KeyValueOf<T> as AggregatedKeyValue>::Key;
+pub type ValueOf<T> = <KeyValueOf<T> as AggregatedKeyValue>::Value;
+
+pub type PendingChangeOf<T> = PendingChange<KeyValueOf<T>, BlockNumberFor<T>>;
+
+/// A scheduled parameter change.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PendingChange<KeyValue, BlockNumber> {
+    /// The parameter and the value it is set to, `None` resetting it to its default.
+    pub key_value: KeyValue,
+    /// Block at which the change was scheduled.
+    pub scheduled_at: BlockNumber,
+    /// Block at which the change is applied.
+    pub enact_at: BlockNumber,
+}
+
+impl<KeyValue: AggregatedKeyValue, BlockNumber> PendingChange<KeyValue, BlockNumber> {
+    /// The parameter changed.
+    pub fn key(&self) -> KeyValue::Key {
+        self.key_value.clone().into_parts().0
+    }
+}
+
+/// Ensures the origin is [`Origin::Timelocked`], i.e. the call is a change this pallet applies
+/// once its delay elapsed.
+pub struct EnsureTimelocked;
+impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for EnsureTimelocked {
+    type Success = ();
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().map(|Origin::Timelocked| ())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(O::from(Origin::Timelocked))
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<KeyValue> {
+    /// A value within bounds for the `i`-th parameter; `i` ranges over `0..Config::MaxPending`
+    /// and each `i` must name a different parameter.
+    fn key_value(i: u32) -> KeyValue;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config:
+        frame_system::Config<RuntimeOrigin: From<Origin>>
+        + pallet_parameters::Config<RuntimeParameters: MaxEncodedLen>
+    {
+        /// Origin allowed to schedule and cancel changes of a parameter.
+        type ScheduleOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, KeyOf<Self>>;
+
+        /// Values parameters may be changed to.
+        type Bounds: Contains<KeyValueOf<Self>>;
+
+        /// Number of blocks between the scheduling of a change and its application.
+        #[pallet::constant]
+        type Delay: Get<BlockNumberFor<Self>>;
+
+        /// Max number of changes pending at once.
+        #[pallet::constant]
+        type MaxPending: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<KeyValueOf<Self>>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Origin of the parameter changes applied by this pallet.
+    #[pallet::origin]
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+    )]
+    pub enum Origin {
+        /// A change whose delay elapsed.
+        Timelocked,
+    }
+
+    /// Changes scheduled and not applied yet, in scheduling order.
+    #[pallet::storage]
+    pub type Pending<T: Config> =
+        StorageValue<_, BoundedVec<PendingChangeOf<T>, T::MaxPending>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        ChangeScheduled {
+            key: KeyOf<T>,
+            value: Option<ValueOf<T>>,
+            enact_at: BlockNumberFor<T>,
+        },
+        ChangeCancelled {
+            key: KeyOf<T>,
+        },
+        /// A change's delay elapsed and it was handed to `pallet_parameters`.
+        ChangeEnacted {
+            key: KeyOf<T>,
+            result: DispatchResult,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The value is outside of `Config::Bounds`.
+        OutOfBounds,
+        ChangeAlreadyPending,
+        TooManyPending,
+        NoPendingChange,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let (due, pending): (Vec<_>, Vec<_>) = Pending::<T>::get()
+                .into_iter()
+                .partition(|change| change.enact_at <= now);
+            if due.is_empty() {
+                return <T as Config>::WeightInfo::on_initialize(0);
+            }
+
+            Pending::<T>::put(BoundedVec::truncate_from(pending));
+            let enacted = due.len() as u32;
+            for change in due {
+                let key = change.key();
+                let result = pallet_parameters::Pallet::<T>::set_parameter(
+                    Origin::Timelocked.into(),
+                    change.key_value,
+                );
+                Self::deposit_event(Event::ChangeEnacted { key, result });
+            }
+            <T as Config>::WeightInfo::on_initialize(enacted)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Schedule `key_value` to be set `Config::Delay` blocks from now.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::schedule())]
+        pub fn schedule(origin: OriginFor<T>, key_value: KeyValueOf<T>) -> DispatchResult {
+            let (key, value) = key_value.clone().into_parts();
+            T::ScheduleOrigin::ensure_origin(origin, &key)?;
+            ensure!(T::Bounds::contains(&key_value), Error::<T>::OutOfBounds);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let enact_at = now.saturating_add(T::Delay::get());
+            Pending::<T>::try_mutate(|pending| {
+                ensure!(
+                    !pending.iter().any(|change| change.key() == key),
+                    Error::<T>::ChangeAlreadyPending
+                );
+                pending
+                    .try_push(PendingChange {
+                        key_value,
+                        scheduled_at: now,
+                        enact_at,
+                    })
+                    .map_err(|_| Error::<T>::TooManyPending)
+            })?;
+
+            Self::deposit_event(Event::ChangeScheduled {
+                key,
+                value,
+                enact_at,
+            });
+            Ok(())
+        }
+
+        /// Withdraw the change pending for `key`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel())]
+        pub fn cancel(origin: OriginFor<T>, key: KeyOf<T>) -> DispatchResult {
+            T::ScheduleOrigin::ensure_origin(origin, &key)?;
+
+            Pending::<T>::try_mutate(|pending| -> DispatchResult {
+                let index = pending
+                    .iter()
+                    .position(|change| change.key() == key)
+                    .ok_or(Error::<T>::NoPendingChange)?;
+                pending.remove(index);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ChangeCancelled { key });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The change pending for `key`, if any.
+        pub fn pending_change(key: &KeyOf<T>) -> Option<PendingChangeOf<T>> {
+            Pending::<T>::get()
+                .into_iter()
+                .find(|change| &change.key() == key)
+        }
+    }
+}
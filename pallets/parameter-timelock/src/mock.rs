@@ -0,0 +1,167 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate as pallet_parameter_timelock;
+use frame_support::{
+    derive_impl,
+    dynamic_params::{dynamic_pallet_params, dynamic_params},
+    parameter_types,
+    sp_runtime::BuildStorage,
+    traits::{AsEnsureOriginWithArg, Contains},
+};
+use frame_system::EnsureRoot;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Parameters = pallet_parameters;
+
+    #[runtime::pallet_index(2)]
+    pub type ParameterTimelock = pallet_parameter_timelock;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Test>)]
+pub mod dynamic_params {
+    use super::*;
+
+    #[dynamic_pallet_params]
+    #[codec(index = 0)]
+    pub mod limits {
+        #[codec(index = 0)]
+        pub static Floor: u32 = 10;
+
+        #[codec(index = 1)]
+        pub static Ceiling: u32 = 100;
+
+        #[codec(index = 2)]
+        pub static Step: u32 = 1;
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+    fn default() -> Self {
+        RuntimeParameters::Limits(dynamic_params::limits::Parameters::Floor(
+            dynamic_params::limits::Floor,
+            Some(10),
+        ))
+    }
+}
+
+pub(crate) fn floor(value: Option<u32>) -> RuntimeParameters {
+    RuntimeParameters::Limits(dynamic_params::limits::Parameters::Floor(
+        dynamic_params::limits::Floor,
+        value,
+    ))
+}
+
+pub(crate) fn ceiling(value: Option<u32>) -> RuntimeParameters {
+    RuntimeParameters::Limits(dynamic_params::limits::Parameters::Ceiling(
+        dynamic_params::limits::Ceiling,
+        value,
+    ))
+}
+
+pub(crate) fn step(value: Option<u32>) -> RuntimeParameters {
+    RuntimeParameters::Limits(dynamic_params::limits::Parameters::Step(
+        dynamic_params::limits::Step,
+        value,
+    ))
+}
+
+impl pallet_parameters::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeParameters = RuntimeParameters;
+    type AdminOrigin = AsEnsureOriginWithArg<crate::EnsureTimelocked>;
+    type WeightInfo = ();
+}
+
+/// `Floor` stays within `1..=50`, the other limits are free.
+pub struct LimitBounds;
+impl Contains<RuntimeParameters> for LimitBounds {
+    fn contains(key_value: &RuntimeParameters) -> bool {
+        match key_value {
+            RuntimeParameters::Limits(dynamic_params::limits::Parameters::Floor(
+                _,
+                Some(floor),
+            )) => (1..=50).contains(floor),
+            _ => true,
+        }
+    }
+}
+
+parameter_types! {
+    pub const Delay: u64 = 10;
+}
+
+impl pallet_parameter_timelock::Config for Test {
+    type ScheduleOrigin = AsEnsureOriginWithArg<EnsureRoot<Self::AccountId>>;
+    type Bounds = LimitBounds;
+    type Delay = Delay;
+    type MaxPending = frame_support::traits::ConstU32<2>;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = Self;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<RuntimeParameters> for Test {
+    fn key_value(i: u32) -> RuntimeParameters {
+        [floor(Some(20)), ceiling(Some(200)), step(Some(2))][i as usize % 3].clone()
+    }
+}
+
+pub(crate) fn run_to_block(n: u64) {
+    use frame_support::traits::Hooks;
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        ParameterTimelock::on_initialize(next);
+    }
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
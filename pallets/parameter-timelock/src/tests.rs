@@ -0,0 +1,189 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{Error, Event, Pending, PendingChange, mock::*};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use sp_runtime::DispatchError;
+
+use dynamic_params::limits::{Ceiling, Floor, ParametersKey};
+
+fn floor_key() -> RuntimeParametersKey {
+    RuntimeParametersKey::Limits(ParametersKey::Floor(Floor))
+}
+
+#[test]
+fn schedule_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ParameterTimelock::schedule(
+            RuntimeOrigin::root(),
+            floor(Some(20))
+        ));
+
+        assert_eq!(
+            ParameterTimelock::pending_change(&floor_key()),
+            Some(PendingChange {
+                key_value: floor(Some(20)),
+                scheduled_at: 1,
+                enact_at: 11,
+            })
+        );
+        System::assert_last_event(
+            Event::ChangeScheduled {
+                key: floor_key(),
+                value: Some(RuntimeParametersValue::Limits(
+                    dynamic_params::limits::ParametersValue::Floor(20),
+                )),
+                enact_at: 11,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn schedule_requires_schedule_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ParameterTimelock::schedule(RuntimeOrigin::signed(1), floor(Some(20))),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn schedule_enforces_bounds() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ParameterTimelock::schedule(RuntimeOrigin::root(), floor(Some(51))),
+            Error::<Test>::OutOfBounds
+        );
+        assert_noop!(
+            ParameterTimelock::schedule(RuntimeOrigin::root(), floor(Some(0))),
+            Error::<Test>::OutOfBounds
+        );
+        // Resetting to the default is always possible.
+        assert_ok!(ParameterTimelock::schedule(
+            RuntimeOrigin::root(),
+            floor(None)
+        ));
+    });
+}
+
+#[test]
+fn one_change_pending_per_parameter() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ParameterTimelock::schedule(
+            RuntimeOrigin::root(),
+            floor(Some(20))
+        ));
+        assert_noop!(
+            ParameterTimelock::schedule(RuntimeOrigin::root(), floor(Some(30))),
+            Error::<Test>::ChangeAlreadyPending
+        );
+
+        assert_ok!(ParameterTimelock::schedule(
+            RuntimeOrigin::root(),
+            ceiling(Some(200))
+        ));
+        assert_noop!(
+            ParameterTimelock::schedule(RuntimeOrigin::root(), step(Some(2))),
+            Error::<Test>::TooManyPending
+        );
+    });
+}
+
+#[test]
+fn change_applied_after_delay() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ParameterTimelock::schedule(
+            RuntimeOrigin::root(),
+            floor(Some(20))
+        ));
+        run_to_block(5);
+        assert_ok!(ParameterTimelock::schedule(
+            RuntimeOrigin::root(),
+            ceiling(Some(200))
+        ));
+
+        run_to_block(10);
+        assert_eq!(Floor::get(), 10);
+
+        run_to_block(11);
+        assert_eq!(Floor::get(), 20);
+        assert_eq!(Ceiling::get(), 100);
+        assert_eq!(Pending::<Test>::get().len(), 1);
+        System::assert_has_event(
+            pallet_parameters::Event::Updated {
+                key: floor_key(),
+                old_value: None,
+                new_value: Some(RuntimeParametersValue::Limits(
+                    dynamic_params::limits::ParametersValue::Floor(20),
+                )),
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::ChangeEnacted {
+                key: floor_key(),
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        run_to_block(15);
+        assert_eq!(Ceiling::get(), 200);
+        assert!(Pending::<Test>::get().is_empty());
+    });
+}
+
+#[test]
+fn timelocked_parameters_cannot_be_set_directly() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Parameters::set_parameter(RuntimeOrigin::root(), floor(Some(20))),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn cancel_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ParameterTimelock::cancel(RuntimeOrigin::root(), floor_key()),
+            Error::<Test>::NoPendingChange
+        );
+        assert_ok!(ParameterTimelock::schedule(
+            RuntimeOrigin::root(),
+            floor(Some(20))
+        ));
+
+        assert_noop!(
+            ParameterTimelock::cancel(RuntimeOrigin::signed(1), floor_key()),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(ParameterTimelock::cancel(
+            RuntimeOrigin::root(),
+            floor_key()
+        ));
+        System::assert_last_event(Event::ChangeCancelled { key: floor_key() }.into());
+
+        run_to_block(11);
+        assert_eq!(Floor::get(), 10);
+        assert!(Pending::<Test>::get().is_empty());
+    });
+}
@@ -0,0 +1,51 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_parameter_timelock.
+pub trait WeightInfo {
+    fn schedule() -> Weight;
+    fn cancel() -> Weight;
+    fn on_initialize(n: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `ParameterTimelock::Pending` (r:1 w:1)
+    fn schedule() -> Weight {
+        Weight::from_parts(16_000_000, 1_700)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `ParameterTimelock::Pending` (r:1 w:1)
+    fn cancel() -> Weight {
+        Weight::from_parts(14_000_000, 1_700)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `ParameterTimelock::Pending` (r:1 w:1)
+    /// Storage: `Parameters::Parameters` (r:n w:n)
+    /// The range of component `n` is `[0, 6]`.
+    fn on_initialize(n: u32) -> Weight {
+        Weight::from_parts(3_000_000, 1_700)
+            .saturating_add(Weight::from_parts(14_000_000, 2_500).saturating_mul(n.into()))
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes((n > 0) as u64))
+            .saturating_add(ParityDbWeight::get().reads_writes(n.into(), n.into()))
+    }
+}
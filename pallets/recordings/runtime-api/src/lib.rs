@@ -0,0 +1,39 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Runtime API serving the certifications of `pallet-recordings` recordings, for profile
+//! displays.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::Codec;
+
+pub use pallet_recordings::{AwardDate, CertificationLevel, CountryCode, Isrc};
+
+sp_api::decl_runtime_apis! {
+    pub trait RecordingsApi<Certification>
+    where
+        Certification: Codec,
+    {
+        /// Certifications of `isrc` awarded by current certification bodies, in the order they
+        /// were recorded. Empty if the recording isn't anchored.
+        fn certifications_of(isrc: Isrc) -> Vec<Certification>;
+    }
+}
@@ -0,0 +1,261 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_support::traits::fungible::Mutate;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+
+fn funded_caller<T: Config>() -> T::AccountId {
+    let caller: T::AccountId = account("caller", 0, SEED);
+    T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+    caller
+}
+
+fn bench_isrc() -> Isrc {
+    BoundedVec::try_from(b"FRZ031800212".to_vec()).expect("12-byte literal fits ISRC bound")
+}
+
+fn bench_works<T: Config>(w: u32) -> WorksOf<T> {
+    (0..w)
+        .map(T::BenchmarkHelper::register_work)
+        .collect::<sp_runtime::Vec<_>>()
+        .try_into()
+        .expect("w <= MaxWorksPerRecording")
+}
+
+fn bench_certification<T: Config>(certifier: T::AccountId) -> CertificationOf<T> {
+    Certification {
+        certifier,
+        territory: *b"US",
+        level: CertificationLevel::Gold,
+        awarded_on: AwardDate {
+            year: 2019,
+            month: 3,
+            day: 8,
+        },
+        evidence: H256::repeat_byte(7),
+        recorded_at: frame_system::Pallet::<T>::block_number(),
+    }
+}
+
+/// Anchor the benchmark recording and fill its certifications up, with awards of `certifier`
+/// for all but the last one.
+fn full_certifications<T: Config>(certifier: T::AccountId, last: T::AccountId) {
+    Pallet::<T>::anchor(
+        RawOrigin::Signed(funded_caller::<T>()).into(),
+        bench_isrc(),
+        H256::repeat_byte(1),
+        bench_works::<T>(1),
+    )
+    .expect("anchor succeeds");
+
+    let max = T::MaxCertificationsPerRecording::get();
+    let certifications: Vec<_> = (0..max)
+        .map(|i| {
+            let mut certification = bench_certification::<T>(if i + 1 < max {
+                certifier.clone()
+            } else {
+                last.clone()
+            });
+            // Levels only tell the awards of a certifier apart.
+            certification.level = CertificationLevel::MultiPlatinum(i.saturating_add(2) as u8);
+            certification
+        })
+        .collect();
+    Certifications::<T>::insert(
+        bench_isrc(),
+        CertificationsOf::<T>::try_from(certifications).expect("max certifications"),
+    );
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn anchor(w: Linear<1, { T::MaxWorksPerRecording::get() }>) {
+        let caller = funded_caller::<T>();
+        let works = bench_works::<T>(w);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            bench_isrc(),
+            H256::repeat_byte(1),
+            works,
+        );
+
+        assert!(Recordings::<T>::contains_key(bench_isrc()));
+    }
+
+    #[benchmark]
+    fn set_works(w: Linear<1, { T::MaxWorksPerRecording::get() }>) {
+        let caller = funded_caller::<T>();
+        let works = bench_works::<T>(w);
+        Pallet::<T>::anchor(
+            RawOrigin::Signed(caller.clone()).into(),
+            bench_isrc(),
+            H256::repeat_byte(1),
+            bench_works::<T>(1),
+        )
+        .expect("anchor succeeds");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), bench_isrc(), works);
+
+        assert_eq!(
+            Recordings::<T>::get(bench_isrc()).unwrap().works.len() as u32,
+            w
+        );
+    }
+
+    #[benchmark]
+    fn remove() {
+        let caller = funded_caller::<T>();
+        Pallet::<T>::anchor(
+            RawOrigin::Signed(caller.clone()).into(),
+            bench_isrc(),
+            H256::repeat_byte(1),
+            bench_works::<T>(T::MaxWorksPerRecording::get()),
+        )
+        .expect("anchor succeeds");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), bench_isrc());
+
+        assert!(!Recordings::<T>::contains_key(bench_isrc()));
+    }
+
+    #[benchmark]
+    fn certify() {
+        let certifier: T::AccountId = account("certifier", 0, SEED);
+        T::BenchmarkHelper::accredit_certifier(&certifier);
+        // Worst case: the list is full and every award must be checked for a former certifier.
+        let former: T::AccountId = account("former", 0, SEED);
+        full_certifications::<T>(former.clone(), former);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(certifier.clone()),
+            bench_isrc(),
+            *b"US",
+            CertificationLevel::Gold,
+            AwardDate {
+                year: 2019,
+                month: 3,
+                day: 8,
+            },
+            H256::repeat_byte(7),
+        );
+
+        assert_eq!(
+            Pallet::<T>::certifications_of(&bench_isrc()),
+            vec![bench_certification::<T>(certifier)]
+        );
+    }
+
+    #[benchmark]
+    fn revoke_certification() {
+        let certifier: T::AccountId = account("certifier", 0, SEED);
+        T::BenchmarkHelper::accredit_certifier(&certifier);
+        // The revoked award is the last one.
+        full_certifications::<T>(account("other", 0, SEED), certifier.clone());
+        let max = T::MaxCertificationsPerRecording::get();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(certifier),
+            bench_isrc(),
+            *b"US",
+            CertificationLevel::MultiPlatinum(max.saturating_add(1) as u8),
+        );
+
+        assert_eq!(Certifications::<T>::get(bench_isrc()).len() as u32, max - 1);
+    }
+
+    #[benchmark]
+    fn commit_anchor() {
+        let caller = funded_caller::<T>();
+        let reveal_by = frame_system::Pallet::<T>::block_number() + T::MaxRevealDelay::get();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            H256::repeat_byte(1),
+            reveal_by,
+        );
+
+        assert!(SealedAnchors::<T>::contains_key(
+            caller,
+            H256::repeat_byte(1)
+        ));
+    }
+
+    #[benchmark]
+    fn reveal_anchor(w: Linear<1, { T::MaxWorksPerRecording::get() }>) {
+        let caller = funded_caller::<T>();
+        let works = bench_works::<T>(w);
+        let salt = [1; 32];
+        let commitment =
+            anchor_commitment(&caller, &bench_isrc(), &H256::repeat_byte(1), &works, &salt);
+        let reveal_by = frame_system::Pallet::<T>::block_number() + T::MaxRevealDelay::get();
+        Pallet::<T>::commit_anchor(
+            RawOrigin::Signed(caller.clone()).into(),
+            commitment,
+            reveal_by,
+        )
+        .expect("commit succeeds");
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            bench_isrc(),
+            H256::repeat_byte(1),
+            works,
+            salt,
+        );
+
+        assert!(Recordings::<T>::contains_key(bench_isrc()));
+    }
+
+    #[benchmark]
+    fn withdraw_commitment() {
+        let caller = funded_caller::<T>();
+        let reveal_by = frame_system::Pallet::<T>::block_number() + T::MaxRevealDelay::get();
+        Pallet::<T>::commit_anchor(
+            RawOrigin::Signed(caller.clone()).into(),
+            H256::repeat_byte(1),
+            reveal_by,
+        )
+        .expect("commit succeeds");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), H256::repeat_byte(1));
+
+        assert!(!SealedAnchors::<T>::contains_key(
+            caller,
+            H256::repeat_byte(1)
+        ));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
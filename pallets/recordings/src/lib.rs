@@ -0,0 +1,789 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Recordings
+//!
+//! Anchors sound recording masters on chain so producers can prove precedence of a master.
+//!
+//! ## Features
+//! - One anchor per ISRC, holding the owner, a 32-byte content hash (audio fingerprint digest,
+//!   IPFS CID multihash digest, ...) and the block at which it was anchored.
+//! - Each recording links to one or more musical works, checked against the runtime's works
+//!   registry (`Config::Works`).
+//! - A content hash can only be anchored once: the first anchor wins and is the proof of
+//!   precedence.
+//! - A deposit is held for the lifetime of the anchor and released on removal.
+//! - Unreleased masters can be anchored sealed: the owner commits to the anchor's hash (see
+//!   [`anchor_commitment`]) and reveals it before a deadline of its choosing, at most
+//!   `Config::MaxRevealDelay` blocks away. The revealed anchor is dated from the commitment,
+//!   proving precedence without disclosing the master's ISRC or content hash until release.
+//! - Anchors are committed to the catalog root (`Config::Catalog`) under
+//!   [`CATALOG_NAMESPACE`], keyed by ISRC.
+//! - Certification bodies (`Config::Certifiers`) attach the awards a recording received (gold,
+//!   platinum, ...) in a territory, with the award date and a hash of the evidence, and may
+//!   revoke them. Awards of bodies that lost their accreditation are kept but no longer
+//!   returned by [`Pallet::certifications_of`].
+//!
+//! Descriptive metadata (title, performers, ...) is out of scope: it lives in the MIDDS
+//! `Recording` registry. This pallet only records who anchored which master, and when.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use alloc::vec::Vec;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        Contains,
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{Fortitude, Precision, Restriction},
+    },
+};
+use frame_system::pallet_prelude::*;
+use pallet_catalog_root::CatalogCommitment;
+use sp_core::H256;
+use sp_runtime::{
+    Saturating,
+    traits::{BlakeTwo256, Hash},
+};
+
+/// Length of an ISRC once stripped of its dashes, e.g. `FRZ031800212`.
+pub const ISRC_LEN: u32 = 12;
+
+/// An International Standard Recording Code, stored without separators.
+pub type Isrc = BoundedVec<u8, ConstU32<ISRC_LEN>>;
+
+/// Namespace of anchored recordings in the catalog commitment.
+pub const CATALOG_NAMESPACE: [u8; 4] = *b"srec";
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type WorksOf<T> = BoundedVec<<T as Config>::WorkId, <T as Config>::MaxWorksPerRecording>;
+
+pub type RecordingOf<T> =
+    Recording<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>, WorksOf<T>>;
+
+/// An ISO 3166-1 alpha-2 country code, upper case.
+pub type CountryCode = [u8; 2];
+
+pub type CertificationOf<T> =
+    Certification<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+pub type CertificationsOf<T> =
+    BoundedVec<CertificationOf<T>, <T as Config>::MaxCertificationsPerRecording>;
+
+/// An anchored master.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Recording<AccountId, Balance, BlockNumber, Works> {
+    /// Account that anchored the master and may update or remove it.
+    pub owner: AccountId,
+    /// Digest of the master's content.
+    pub content_hash: H256,
+    /// Musical works embodied in the recording.
+    pub works: Works,
+    /// Amount held from `owner` for this anchor.
+    pub deposit: Balance,
+    /// Block at which the master was anchored.
+    pub anchored_at: BlockNumber,
+}
+
+pub type SealedAnchorOf<T> = SealedAnchor<BalanceOf<T>, BlockNumberFor<T>>;
+
+/// An anchor committed to, but not revealed yet.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SealedAnchor<Balance, BlockNumber> {
+    /// Amount held from the owner, carried over to the anchor once revealed.
+    pub deposit: Balance,
+    /// Block at which the anchor was committed to, and the revealed anchor is dated from.
+    pub committed_at: BlockNumber,
+    /// Last block at which the anchor can be revealed.
+    pub reveal_by: BlockNumber,
+}
+
+/// The commitment `owner` makes to anchor `isrc`, fingerprinted by `content_hash` and embodying
+/// `works`, blinded by `salt`: the BLAKE2-256 hash of the SCALE-encoded
+/// `(owner, isrc, content_hash, works, salt)`.
+///
+/// `salt` must be kept secret until the reveal, and be random: ISRCs and the works of an artist
+/// can be guessed.
+pub fn anchor_commitment<AccountId: Encode, Works: Encode>(
+    owner: &AccountId,
+    isrc: &Isrc,
+    content_hash: &H256,
+    works: &Works,
+    salt: &[u8; 32],
+) -> H256 {
+    BlakeTwo256::hash_of(&(owner, isrc, content_hash, works, salt))
+}
+
+/// Sales certification level, as awarded by the recording industry body of a territory.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum CertificationLevel {
+    Silver,
+    Gold,
+    Platinum,
+    /// Platinum times the given multiple, at least 2.
+    MultiPlatinum(u8),
+    Diamond,
+}
+
+impl CertificationLevel {
+    pub fn is_valid(&self) -> bool {
+        !matches!(self, Self::MultiPlatinum(multiple) if *multiple < 2)
+    }
+}
+
+/// Calendar date a certification was awarded on.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct AwardDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl AwardDate {
+    pub fn is_valid(&self) -> bool {
+        let days_in_month = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if self.year.is_multiple_of(4)
+                && (!self.year.is_multiple_of(100) || self.year.is_multiple_of(400)) =>
+            {
+                29
+            }
+            2 => 28,
+            _ => return false,
+        };
+        self.year >= 1900 && (1..=days_in_month).contains(&self.day)
+    }
+}
+
+/// A certification awarded to a recording.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Certification<AccountId, BlockNumber> {
+    /// The certification body that awarded it, the only account allowed to revoke it.
+    pub certifier: AccountId,
+    pub territory: CountryCode,
+    pub level: CertificationLevel,
+    pub awarded_on: AwardDate,
+    /// Digest of the evidence backing the award (certificate, audited sales report, ...).
+    pub evidence: H256,
+    /// Block at which the certification was recorded.
+    pub recorded_at: BlockNumber,
+}
+
+/// Checks that `territory` looks like an ISO 3166-1 alpha-2 code: two upper-case letters.
+pub fn is_valid_country_code(territory: &CountryCode) -> bool {
+    territory.iter().all(u8::is_ascii_uppercase)
+}
+
+/// Checks that `isrc` is a well-formed, separator-free ISRC: a two-letter country code, a
+/// three-character alphanumeric registrant code, a two-digit year and a five-digit designation.
+/// Only upper-case letters are accepted so that one recording has exactly one key.
+pub fn is_valid_isrc(isrc: &[u8]) -> bool {
+    isrc.len() == ISRC_LEN as usize
+        && isrc[..2].iter().all(u8::is_ascii_uppercase)
+        && isrc[2..5]
+            .iter()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        && isrc[5..].iter().all(u8::is_ascii_digit)
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, WorkId> {
+    /// Register the `i`-th work in the works registry and return its id.
+    fn register_work(i: u32) -> WorkId;
+
+    /// Accredit `who` as a certification body.
+    fn accredit_certifier(who: &AccountId);
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Identifier of a musical work in the works registry.
+        type WorkId: Parameter + Member + MaxEncodedLen;
+
+        /// The works registry recordings can be linked to.
+        type Works: Contains<Self::WorkId>;
+
+        /// Max number of works a single recording can be linked to.
+        #[pallet::constant]
+        type MaxWorksPerRecording: Get<u32>;
+
+        /// Amount held from the owner for each anchored recording.
+        #[pallet::constant]
+        type AnchorDeposit: Get<BalanceOf<Self>>;
+
+        /// The catalog commitment anchored recordings are kept in.
+        type Catalog: CatalogCommitment;
+
+        /// Max number of blocks between committing to an anchor and its reveal deadline.
+        #[pallet::constant]
+        type MaxRevealDelay: Get<BlockNumberFor<Self>>;
+
+        /// Accounts of the accredited certification bodies.
+        type Certifiers: Contains<Self::AccountId>;
+
+        /// Max number of certifications on a single recording.
+        #[pallet::constant]
+        type MaxCertificationsPerRecording: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::WorkId>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        RecordingAnchor,
+    }
+
+    /// Anchored masters, keyed by ISRC.
+    #[pallet::storage]
+    pub type Recordings<T: Config> =
+        StorageMap<_, Blake2_128Concat, Isrc, RecordingOf<T>, OptionQuery>;
+
+    /// Reverse index from content hash to the ISRC that first anchored it.
+    #[pallet::storage]
+    pub type ContentIndex<T: Config> = StorageMap<_, Identity, H256, Isrc, OptionQuery>;
+
+    /// Anchors committed to and not revealed yet, keyed by owner and commitment.
+    #[pallet::storage]
+    pub type SealedAnchors<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        H256,
+        SealedAnchorOf<T>,
+        OptionQuery,
+    >;
+
+    /// Certifications awarded to anchored recordings, in the order they were recorded.
+    #[pallet::storage]
+    pub type Certifications<T: Config> =
+        StorageMap<_, Blake2_128Concat, Isrc, CertificationsOf<T>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        RecordingAnchored {
+            isrc: Isrc,
+            owner: T::AccountId,
+            content_hash: H256,
+        },
+        WorksUpdated {
+            isrc: Isrc,
+        },
+        RecordingRemoved {
+            isrc: Isrc,
+        },
+        OwnershipTransferred {
+            isrc: Isrc,
+            from: T::AccountId,
+            to: T::AccountId,
+        },
+        RecordingCertified {
+            isrc: Isrc,
+            certifier: T::AccountId,
+            territory: CountryCode,
+            level: CertificationLevel,
+        },
+        CertificationRevoked {
+            isrc: Isrc,
+            certifier: T::AccountId,
+            territory: CountryCode,
+            level: CertificationLevel,
+        },
+        AnchorCommitted {
+            owner: T::AccountId,
+            commitment: H256,
+            reveal_by: BlockNumberFor<T>,
+        },
+        /// The anchor committed to was revealed, and `RecordingAnchored` deposited for it.
+        AnchorRevealed {
+            owner: T::AccountId,
+            commitment: H256,
+            isrc: Isrc,
+        },
+        CommitmentWithdrawn {
+            owner: T::AccountId,
+            commitment: H256,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        InvalidIsrc,
+        RecordingAlreadyAnchored,
+        ContentAlreadyAnchored,
+        RecordingNotFound,
+        NotOwner,
+        NoWorks,
+        DuplicateWork,
+        UnknownWork,
+        NotCertifier,
+        InvalidTerritory,
+        InvalidLevel,
+        InvalidDate,
+        AlreadyCertified,
+        CertificationNotFound,
+        TooManyCertifications,
+        /// The reveal deadline is not in the future, or further than `MaxRevealDelay`.
+        InvalidRevealDeadline,
+        CommitmentAlreadyExists,
+        /// The caller committed to no such anchor: the revealed fields or salt differ.
+        CommitmentNotFound,
+        RevealDeadlinePassed,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Anchor the master identified by `isrc`, fingerprinted by `content_hash` and embodying
+        /// `works`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::anchor(works.len() as u32))]
+        pub fn anchor(
+            origin: OriginFor<T>,
+            isrc: Isrc,
+            content_hash: H256,
+            works: WorksOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_anchorable(&isrc, content_hash, &works)?;
+
+            let deposit = T::AnchorDeposit::get();
+            T::Currency::hold(&HoldReason::RecordingAnchor.into(), &who, deposit)?;
+
+            Self::insert_anchor(
+                isrc,
+                Recording {
+                    owner: who,
+                    content_hash,
+                    works,
+                    deposit,
+                    anchored_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            Ok(())
+        }
+
+        /// Replace the works linked to an anchored recording. Owner only.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_works(works.len() as u32))]
+        pub fn set_works(origin: OriginFor<T>, isrc: Isrc, works: WorksOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Recordings::<T>::try_mutate(&isrc, |maybe_recording| -> DispatchResult {
+                let recording = maybe_recording
+                    .as_mut()
+                    .ok_or(Error::<T>::RecordingNotFound)?;
+                ensure!(recording.owner == who, Error::<T>::NotOwner);
+                Self::ensure_valid_works(&works)?;
+                recording.works = works;
+                T::Catalog::commit(CATALOG_NAMESPACE, &isrc, recording);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::WorksUpdated { isrc });
+            Ok(())
+        }
+
+        /// Remove an anchored recording and release its deposit. Owner only.
+        ///
+        /// The content hash is freed as well, so the master can be anchored again. The
+        /// certifications of the recording are dropped.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::remove())]
+        pub fn remove(origin: OriginFor<T>, isrc: Isrc) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let recording = Recordings::<T>::get(&isrc).ok_or(Error::<T>::RecordingNotFound)?;
+            ensure!(recording.owner == who, Error::<T>::NotOwner);
+
+            T::Currency::release(
+                &HoldReason::RecordingAnchor.into(),
+                &who,
+                recording.deposit,
+                Precision::BestEffort,
+            )?;
+
+            ContentIndex::<T>::remove(recording.content_hash);
+            Recordings::<T>::remove(&isrc);
+            Certifications::<T>::remove(&isrc);
+            T::Catalog::withdraw(CATALOG_NAMESPACE, &isrc);
+
+            Self::deposit_event(Event::RecordingRemoved { isrc });
+            Ok(())
+        }
+
+        /// Record that `isrc` was awarded `level` in `territory` on `awarded_on`, backed by the
+        /// evidence hashed to `evidence`. Certification bodies only.
+        ///
+        /// A body awards a level once per territory.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::certify())]
+        pub fn certify(
+            origin: OriginFor<T>,
+            isrc: Isrc,
+            territory: CountryCode,
+            level: CertificationLevel,
+            awarded_on: AwardDate,
+            evidence: H256,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(T::Certifiers::contains(&who), Error::<T>::NotCertifier);
+            ensure!(
+                Recordings::<T>::contains_key(&isrc),
+                Error::<T>::RecordingNotFound
+            );
+            ensure!(
+                is_valid_country_code(&territory),
+                Error::<T>::InvalidTerritory
+            );
+            ensure!(level.is_valid(), Error::<T>::InvalidLevel);
+            ensure!(awarded_on.is_valid(), Error::<T>::InvalidDate);
+
+            Certifications::<T>::try_mutate(&isrc, |certifications| -> DispatchResult {
+                ensure!(
+                    !certifications.iter().any(|c| c.certifier == who
+                        && c.territory == territory
+                        && c.level == level),
+                    Error::<T>::AlreadyCertified
+                );
+                // Awards of former certifiers are no longer shown: make room by dropping them.
+                if certifications.is_full() {
+                    certifications.retain(|c| T::Certifiers::contains(&c.certifier));
+                }
+                certifications
+                    .try_push(Certification {
+                        certifier: who.clone(),
+                        territory,
+                        level,
+                        awarded_on,
+                        evidence,
+                        recorded_at: frame_system::Pallet::<T>::block_number(),
+                    })
+                    .map_err(|_| Error::<T>::TooManyCertifications)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::RecordingCertified {
+                isrc,
+                certifier: who,
+                territory,
+                level,
+            });
+            Ok(())
+        }
+
+        /// Revoke the caller's `level` certification of `isrc` in `territory`.
+        ///
+        /// Former certification bodies may revoke their awards too.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::revoke_certification())]
+        pub fn revoke_certification(
+            origin: OriginFor<T>,
+            isrc: Isrc,
+            territory: CountryCode,
+            level: CertificationLevel,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Certifications::<T>::try_mutate_exists(
+                &isrc,
+                |maybe_certifications| -> DispatchResult {
+                    let certifications = maybe_certifications
+                        .as_mut()
+                        .ok_or(Error::<T>::CertificationNotFound)?;
+                    let pos = certifications
+                        .iter()
+                        .position(|c| {
+                            c.certifier == who && c.territory == territory && c.level == level
+                        })
+                        .ok_or(Error::<T>::CertificationNotFound)?;
+                    certifications.remove(pos);
+                    if certifications.is_empty() {
+                        *maybe_certifications = None;
+                    }
+                    Ok(())
+                },
+            )?;
+
+            Self::deposit_event(Event::CertificationRevoked {
+                isrc,
+                certifier: who,
+                territory,
+                level,
+            });
+            Ok(())
+        }
+
+        /// Commit to an anchor to reveal by block `reveal_by`, holding `AnchorDeposit` from the
+        /// caller. `commitment` is computed by [`anchor_commitment`].
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::commit_anchor())]
+        pub fn commit_anchor(
+            origin: OriginFor<T>,
+            commitment: H256,
+            reveal_by: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                reveal_by > now && reveal_by <= now.saturating_add(T::MaxRevealDelay::get()),
+                Error::<T>::InvalidRevealDeadline
+            );
+            ensure!(
+                !SealedAnchors::<T>::contains_key(&who, commitment),
+                Error::<T>::CommitmentAlreadyExists
+            );
+
+            let deposit = T::AnchorDeposit::get();
+            T::Currency::hold(&HoldReason::RecordingAnchor.into(), &who, deposit)?;
+            SealedAnchors::<T>::insert(
+                &who,
+                commitment,
+                SealedAnchor {
+                    deposit,
+                    committed_at: now,
+                    reveal_by,
+                },
+            );
+
+            Self::deposit_event(Event::AnchorCommitted {
+                owner: who,
+                commitment,
+                reveal_by,
+            });
+            Ok(())
+        }
+
+        /// Reveal an anchor the caller committed to, anchoring it as of the commitment's block.
+        ///
+        /// Fails like [`Pallet::anchor`] if the ISRC or the content hash were anchored in the
+        /// meantime; the commitment stays as a proof of precedence until withdrawn.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::reveal_anchor(works.len() as u32))]
+        pub fn reveal_anchor(
+            origin: OriginFor<T>,
+            isrc: Isrc,
+            content_hash: H256,
+            works: WorksOf<T>,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let commitment = anchor_commitment(&who, &isrc, &content_hash, &works, &salt);
+            let sealed =
+                SealedAnchors::<T>::get(&who, commitment).ok_or(Error::<T>::CommitmentNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= sealed.reveal_by,
+                Error::<T>::RevealDeadlinePassed
+            );
+            Self::ensure_anchorable(&isrc, content_hash, &works)?;
+
+            SealedAnchors::<T>::remove(&who, commitment);
+            Self::insert_anchor(
+                isrc.clone(),
+                Recording {
+                    owner: who.clone(),
+                    content_hash,
+                    works,
+                    deposit: sealed.deposit,
+                    anchored_at: sealed.committed_at,
+                },
+            );
+
+            Self::deposit_event(Event::AnchorRevealed {
+                owner: who,
+                commitment,
+                isrc,
+            });
+            Ok(())
+        }
+
+        /// Drop an anchor the caller committed to and release its deposit, e.g. once its reveal
+        /// deadline passed.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::withdraw_commitment())]
+        pub fn withdraw_commitment(origin: OriginFor<T>, commitment: H256) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let sealed =
+                SealedAnchors::<T>::take(&who, commitment).ok_or(Error::<T>::CommitmentNotFound)?;
+            T::Currency::release(
+                &HoldReason::RecordingAnchor.into(),
+                &who,
+                sealed.deposit,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::CommitmentWithdrawn {
+                owner: who,
+                commitment,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn ensure_anchorable(
+            isrc: &Isrc,
+            content_hash: H256,
+            works: &WorksOf<T>,
+        ) -> DispatchResult {
+            ensure!(is_valid_isrc(isrc), Error::<T>::InvalidIsrc);
+            ensure!(
+                !Recordings::<T>::contains_key(isrc),
+                Error::<T>::RecordingAlreadyAnchored
+            );
+            ensure!(
+                !ContentIndex::<T>::contains_key(content_hash),
+                Error::<T>::ContentAlreadyAnchored
+            );
+            Self::ensure_valid_works(works)
+        }
+
+        /// Store `recording`, its deposit already held.
+        fn insert_anchor(isrc: Isrc, recording: RecordingOf<T>) {
+            T::Catalog::commit(CATALOG_NAMESPACE, &isrc, &recording);
+            ContentIndex::<T>::insert(recording.content_hash, &isrc);
+            let owner = recording.owner.clone();
+            let content_hash = recording.content_hash;
+            Recordings::<T>::insert(&isrc, recording);
+
+            Self::deposit_event(Event::RecordingAnchored {
+                isrc,
+                owner,
+                content_hash,
+            });
+        }
+
+        fn ensure_valid_works(works: &WorksOf<T>) -> DispatchResult {
+            ensure!(!works.is_empty(), Error::<T>::NoWorks);
+            for (i, work) in works.iter().enumerate() {
+                ensure!(!works[..i].contains(work), Error::<T>::DuplicateWork);
+                ensure!(T::Works::contains(work), Error::<T>::UnknownWork);
+            }
+            Ok(())
+        }
+
+        /// Hand the anchor of `isrc` over to `to`, along with its held deposit.
+        ///
+        /// No origin check: meant for pallets settling a sale the owner agreed to.
+        pub fn transfer_ownership(isrc: &Isrc, to: &T::AccountId) -> DispatchResult {
+            Recordings::<T>::try_mutate(isrc, |maybe_recording| -> DispatchResult {
+                let recording = maybe_recording
+                    .as_mut()
+                    .ok_or(Error::<T>::RecordingNotFound)?;
+                if &recording.owner == to {
+                    return Ok(());
+                }
+
+                T::Currency::transfer_on_hold(
+                    &HoldReason::RecordingAnchor.into(),
+                    &recording.owner,
+                    to,
+                    recording.deposit,
+                    Precision::Exact,
+                    Restriction::OnHold,
+                    Fortitude::Polite,
+                )?;
+
+                let from = core::mem::replace(&mut recording.owner, to.clone());
+                T::Catalog::commit(CATALOG_NAMESPACE, isrc, recording);
+                Self::deposit_event(Event::OwnershipTransferred {
+                    isrc: isrc.clone(),
+                    from,
+                    to: to.clone(),
+                });
+                Ok(())
+            })
+        }
+
+        /// The ISRC that anchored `content_hash`, if any.
+        pub fn recording_of_content(content_hash: H256) -> Option<Isrc> {
+            ContentIndex::<T>::get(content_hash)
+        }
+
+        /// Certifications of `isrc` awarded by current certification bodies, in the order they
+        /// were recorded.
+        pub fn certifications_of(isrc: &Isrc) -> Vec<CertificationOf<T>> {
+            Certifications::<T>::get(isrc)
+                .into_iter()
+                .filter(|c| T::Certifiers::contains(&c.certifier))
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,148 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_recordings;
+use frame_support::{
+    derive_impl, parameter_types,
+    sp_runtime::BuildStorage,
+    traits::{ConstU64, ConstU128, Contains},
+};
+
+pub type Balance = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type Recordings = pallet_recordings;
+
+    #[runtime::pallet_index(3)]
+    pub type CatalogRoot = pallet_catalog_root;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+}
+
+pub const ANCHOR_DEPOSIT: Balance = 10;
+
+parameter_types! {
+    pub const MaxWorksPerRecording: u32 = 3;
+    pub const AnchorDeposit: Balance = ANCHOR_DEPOSIT;
+    pub const MaxCertificationsPerRecording: u32 = 3;
+    pub const MaxRevealDelay: u64 = MAX_REVEAL_DELAY;
+    pub static AccreditedCertifiers: Vec<u64> = vec![RIAA, BPI];
+}
+
+/// Works `0..100` are registered, anything above is unknown.
+pub struct MockWorks;
+impl Contains<u32> for MockWorks {
+    fn contains(work: &u32) -> bool {
+        *work < 100
+    }
+}
+
+pub struct MockCertifiers;
+impl Contains<u64> for MockCertifiers {
+    fn contains(who: &u64) -> bool {
+        AccreditedCertifiers::get().contains(who)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u64, u32> for MockWorks {
+    fn register_work(i: u32) -> u32 {
+        i
+    }
+
+    fn accredit_certifier(who: &u64) {
+        AccreditedCertifiers::mutate(|certifiers| certifiers.push(*who));
+    }
+}
+
+impl pallet_catalog_root::Config for Test {
+    type StatisticsPeriod = ConstU64<10>;
+    type TotalDistributed = ConstU128<0>;
+    type WeightInfo = ();
+}
+
+impl pallet_recordings::Config for Test {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type WorkId = u32;
+    type Works = MockWorks;
+    type MaxWorksPerRecording = MaxWorksPerRecording;
+    type AnchorDeposit = AnchorDeposit;
+    type Catalog = CatalogRoot;
+    type MaxRevealDelay = MaxRevealDelay;
+    type Certifiers = MockCertifiers;
+    type MaxCertificationsPerRecording = MaxCertificationsPerRecording;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockWorks;
+}
+
+pub const MAX_REVEAL_DELAY: u64 = 100;
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const POOR: u64 = 3;
+pub const RIAA: u64 = 10;
+pub const BPI: u64 = 11;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, 100), (BOB, 100), (POOR, 5)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,723 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AwardDate, CATALOG_NAMESPACE, CertificationLevel, Certifications, ContentIndex, CountryCode,
+    Error, Event, HoldReason, Isrc, Recordings as RecordingsStorage, SealedAnchors,
+    anchor_commitment, mock::*,
+};
+use frame_support::{
+    BoundedVec, assert_noop, assert_ok,
+    traits::fungible::{InspectHold, Mutate},
+};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn isrc(code: &[u8]) -> Isrc {
+    code.to_vec().try_into().unwrap()
+}
+
+fn works(ids: &[u32]) -> BoundedVec<u32, MaxWorksPerRecording> {
+    ids.to_vec().try_into().unwrap()
+}
+
+fn held(who: u64) -> Balance {
+    Balances::balance_on_hold(&HoldReason::RecordingAnchor.into(), &who)
+}
+
+#[test]
+fn anchor_stores_recording_and_holds_deposit() {
+    new_test_ext().execute_with(|| {
+        let code = isrc(b"FRZ031800212");
+        let hash = H256::repeat_byte(1);
+
+        assert_ok!(Recordings::anchor(
+            RuntimeOrigin::signed(ALICE),
+            code.clone(),
+            hash,
+            works(&[1, 2])
+        ));
+
+        let recording = RecordingsStorage::<Test>::get(&code).unwrap();
+        assert_eq!(recording.owner, ALICE);
+        assert_eq!(recording.content_hash, hash);
+        assert_eq!(recording.works.to_vec(), vec![1, 2]);
+        assert_eq!(recording.anchored_at, 1);
+        assert_eq!(ContentIndex::<Test>::get(hash), Some(code.clone()));
+        assert_eq!(held(ALICE), ANCHOR_DEPOSIT);
+
+        System::assert_last_event(
+            Event::RecordingAnchored {
+                isrc: code,
+                owner: ALICE,
+                content_hash: hash,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn anchor_rejects_malformed_isrc() {
+    new_test_ext().execute_with(|| {
+        for bad in [
+            &b"FR-Z03-18-00212"[..12],
+            b"FRZ03180021",
+            b"frz031800212",
+            b"F1Z031800212",
+            b"FRZ03180021A",
+        ] {
+            assert_noop!(
+                Recordings::anchor(
+                    RuntimeOrigin::signed(ALICE),
+                    isrc(bad),
+                    H256::repeat_byte(1),
+                    works(&[1])
+                ),
+                Error::<Test>::InvalidIsrc
+            );
+        }
+    });
+}
+
+#[test]
+fn first_anchor_of_a_master_wins() {
+    new_test_ext().execute_with(|| {
+        let hash = H256::repeat_byte(7);
+        assert_ok!(Recordings::anchor(
+            RuntimeOrigin::signed(ALICE),
+            isrc(b"FRZ031800212"),
+            hash,
+            works(&[1])
+        ));
+
+        // Same master under another ISRC.
+        assert_noop!(
+            Recordings::anchor(
+                RuntimeOrigin::signed(BOB),
+                isrc(b"USAAA2500001"),
+                hash,
+                works(&[1])
+            ),
+            Error::<Test>::ContentAlreadyAnchored
+        );
+        // Same ISRC with another master.
+        assert_noop!(
+            Recordings::anchor(
+                RuntimeOrigin::signed(BOB),
+                isrc(b"FRZ031800212"),
+                H256::repeat_byte(8),
+                works(&[1])
+            ),
+            Error::<Test>::RecordingAlreadyAnchored
+        );
+
+        assert_eq!(
+            Recordings::recording_of_content(hash),
+            Some(isrc(b"FRZ031800212"))
+        );
+    });
+}
+
+#[test]
+fn anchor_validates_linked_works() {
+    new_test_ext().execute_with(|| {
+        let anchor = |ids: &[u32]| {
+            Recordings::anchor(
+                RuntimeOrigin::signed(ALICE),
+                isrc(b"FRZ031800212"),
+                H256::repeat_byte(1),
+                works(ids),
+            )
+        };
+
+        assert_noop!(anchor(&[]), Error::<Test>::NoWorks);
+        assert_noop!(anchor(&[1, 1]), Error::<Test>::DuplicateWork);
+        assert_noop!(anchor(&[1, 100]), Error::<Test>::UnknownWork);
+    });
+}
+
+#[test]
+fn anchor_fails_without_funds_for_deposit() {
+    new_test_ext().execute_with(|| {
+        assert!(
+            Recordings::anchor(
+                RuntimeOrigin::signed(POOR),
+                isrc(b"FRZ031800212"),
+                H256::repeat_byte(1),
+                works(&[1])
+            )
+            .is_err()
+        );
+        assert!(RecordingsStorage::<Test>::get(isrc(b"FRZ031800212")).is_none());
+    });
+}
+
+#[test]
+fn owner_can_update_works() {
+    new_test_ext().execute_with(|| {
+        let code = isrc(b"FRZ031800212");
+        assert_ok!(Recordings::anchor(
+            RuntimeOrigin::signed(ALICE),
+            code.clone(),
+            H256::repeat_byte(1),
+            works(&[1])
+        ));
+
+        assert_noop!(
+            Recordings::set_works(RuntimeOrigin::signed(BOB), code.clone(), works(&[2])),
+            Error::<Test>::NotOwner
+        );
+        assert_noop!(
+            Recordings::set_works(RuntimeOrigin::signed(ALICE), code.clone(), works(&[200])),
+            Error::<Test>::UnknownWork
+        );
+
+        assert_ok!(Recordings::set_works(
+            RuntimeOrigin::signed(ALICE),
+            code.clone(),
+            works(&[2, 3])
+        ));
+        assert_eq!(
+            RecordingsStorage::<Test>::get(&code)
+                .unwrap()
+                .works
+                .to_vec(),
+            vec![2, 3]
+        );
+        System::assert_last_event(Event::WorksUpdated { isrc: code }.into());
+    });
+}
+
+#[test]
+fn set_works_on_unknown_recording_fails() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Recordings::set_works(
+                RuntimeOrigin::signed(ALICE),
+                isrc(b"FRZ031800212"),
+                works(&[1])
+            ),
+            Error::<Test>::RecordingNotFound
+        );
+    });
+}
+
+#[test]
+fn remove_releases_deposit_and_frees_content_hash() {
+    new_test_ext().execute_with(|| {
+        let code = isrc(b"FRZ031800212");
+        let hash = H256::repeat_byte(1);
+        assert_ok!(Recordings::anchor(
+            RuntimeOrigin::signed(ALICE),
+            code.clone(),
+            hash,
+            works(&[1])
+        ));
+
+        assert_noop!(
+            Recordings::remove(RuntimeOrigin::signed(BOB), code.clone()),
+            Error::<Test>::NotOwner
+        );
+
+        assert_ok!(Recordings::remove(
+            RuntimeOrigin::signed(ALICE),
+            code.clone()
+        ));
+        assert!(RecordingsStorage::<Test>::get(&code).is_none());
+        assert!(ContentIndex::<Test>::get(hash).is_none());
+        assert_eq!(held(ALICE), 0);
+        System::assert_last_event(Event::RecordingRemoved { isrc: code }.into());
+
+        // The master can be anchored again once freed.
+        assert_ok!(Recordings::anchor(
+            RuntimeOrigin::signed(BOB),
+            isrc(b"USAAA2500001"),
+            hash,
+            works(&[1])
+        ));
+    });
+}
+
+#[test]
+fn remove_refunds_exactly_the_held_deposit() {
+    new_test_ext().execute_with(|| {
+        let code = isrc(b"FRZ031800212");
+        assert_ok!(Recordings::anchor(
+            RuntimeOrigin::signed(ALICE),
+            code.clone(),
+            H256::repeat_byte(1),
+            works(&[1])
+        ));
+        // Unrelated balance movements must not affect the refund.
+        assert_ok!(<Balances as Mutate<_>>::mint_into(&ALICE, 50));
+
+        assert_ok!(Recordings::remove(RuntimeOrigin::signed(ALICE), code));
+        assert_eq!(Balances::free_balance(ALICE), 150);
+    });
+}
+
+#[test]
+fn transfer_ownership_moves_anchor_and_deposit() {
+    new_test_ext().execute_with(|| {
+        let code = isrc(b"FRZ031800212");
+        assert_ok!(Recordings::anchor(
+            RuntimeOrigin::signed(ALICE),
+            code.clone(),
+            H256::repeat_byte(1),
+            works(&[1])
+        ));
+
+        assert_ok!(Recordings::transfer_ownership(&code, &BOB));
+        assert_eq!(RecordingsStorage::<Test>::get(&code).unwrap().owner, BOB);
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(held(BOB), ANCHOR_DEPOSIT);
+        System::assert_last_event(
+            Event::OwnershipTransferred {
+                isrc: code.clone(),
+                from: ALICE,
+                to: BOB,
+            }
+            .into(),
+        );
+
+        // The new owner manages the anchor and gets the deposit back on removal.
+        assert_noop!(
+            Recordings::remove(RuntimeOrigin::signed(ALICE), code.clone()),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Recordings::remove(RuntimeOrigin::signed(BOB), code));
+        assert_eq!(Balances::free_balance(BOB), 100 + ANCHOR_DEPOSIT);
+
+        assert_noop!(
+            Recordings::transfer_ownership(&isrc(b"USAAA2500001"), &BOB),
+            Error::<Test>::RecordingNotFound
+        );
+    });
+}
+
+#[test]
+fn anchors_are_committed_to_the_catalog_root() {
+    new_test_ext().execute_with(|| {
+        let code = isrc(b"FRZ031800212");
+        let committed = || CatalogRoot::entity_hash(CATALOG_NAMESPACE, &code);
+        let stored = || RecordingsStorage::<Test>::get(&code).map(|r| BlakeTwo256::hash_of(&r));
+
+        assert_ok!(Recordings::anchor(
+            RuntimeOrigin::signed(ALICE),
+            code.clone(),
+            H256::repeat_byte(1),
+            works(&[1])
+        ));
+        assert!(committed().is_some());
+        assert_eq!(committed(), stored());
+
+        assert_ok!(Recordings::set_works(
+            RuntimeOrigin::signed(ALICE),
+            code.clone(),
+            works(&[1, 2])
+        ));
+        assert_eq!(committed(), stored());
+
+        assert_ok!(Recordings::transfer_ownership(&code, &BOB));
+        assert_eq!(committed(), stored());
+
+        assert_ok!(Recordings::remove(RuntimeOrigin::signed(BOB), code.clone()));
+        assert_eq!(committed(), None);
+    });
+}
+
+fn anchored(code: &[u8]) -> Isrc {
+    let code = isrc(code);
+    assert_ok!(Recordings::anchor(
+        RuntimeOrigin::signed(ALICE),
+        code.clone(),
+        BlakeTwo256::hash(&code),
+        works(&[1])
+    ));
+    code
+}
+
+const AWARDED_ON: AwardDate = AwardDate {
+    year: 2019,
+    month: 3,
+    day: 8,
+};
+
+fn certify(
+    certifier: u64,
+    code: &Isrc,
+    territory: &CountryCode,
+    level: CertificationLevel,
+) -> sp_runtime::DispatchResult {
+    Recordings::certify(
+        RuntimeOrigin::signed(certifier),
+        code.clone(),
+        *territory,
+        level,
+        AWARDED_ON,
+        H256::repeat_byte(7),
+    )
+}
+
+/// Territory and level of each certification returned for `code`.
+fn awards(code: &Isrc) -> Vec<(u64, CountryCode, CertificationLevel)> {
+    Recordings::certifications_of(code)
+        .into_iter()
+        .map(|c| (c.certifier, c.territory, c.level))
+        .collect()
+}
+
+#[test]
+fn certification_bodies_certify_recordings() {
+    new_test_ext().execute_with(|| {
+        let code = anchored(b"USUM71703861");
+
+        assert_ok!(certify(RIAA, &code, b"US", CertificationLevel::Gold));
+        System::assert_last_event(
+            Event::RecordingCertified {
+                isrc: code.clone(),
+                certifier: RIAA,
+                territory: *b"US",
+                level: CertificationLevel::Gold,
+            }
+            .into(),
+        );
+        // Higher levels and other territories are separate awards.
+        assert_ok!(certify(
+            RIAA,
+            &code,
+            b"US",
+            CertificationLevel::MultiPlatinum(3)
+        ));
+        assert_ok!(certify(BPI, &code, b"GB", CertificationLevel::Silver));
+
+        let stored = Certifications::<Test>::get(&code);
+        assert_eq!(stored[0].awarded_on, AWARDED_ON);
+        assert_eq!(stored[0].evidence, H256::repeat_byte(7));
+        assert_eq!(stored[0].recorded_at, 1);
+        assert_eq!(
+            awards(&code),
+            vec![
+                (RIAA, *b"US", CertificationLevel::Gold),
+                (RIAA, *b"US", CertificationLevel::MultiPlatinum(3)),
+                (BPI, *b"GB", CertificationLevel::Silver),
+            ]
+        );
+    });
+}
+
+#[test]
+fn certify_checks_its_inputs() {
+    new_test_ext().execute_with(|| {
+        let code = anchored(b"USUM71703861");
+        let gold = CertificationLevel::Gold;
+
+        assert_noop!(
+            certify(ALICE, &code, b"US", gold),
+            Error::<Test>::NotCertifier
+        );
+        assert_noop!(
+            certify(RIAA, &isrc(b"GBAYE0000351"), b"US", gold),
+            Error::<Test>::RecordingNotFound
+        );
+        assert_noop!(
+            certify(RIAA, &code, b"us", gold),
+            Error::<Test>::InvalidTerritory
+        );
+        assert_noop!(
+            certify(RIAA, &code, b"U1", gold),
+            Error::<Test>::InvalidTerritory
+        );
+        assert_noop!(
+            certify(RIAA, &code, b"US", CertificationLevel::MultiPlatinum(1)),
+            Error::<Test>::InvalidLevel
+        );
+        for awarded_on in [
+            AwardDate {
+                year: 2019,
+                month: 2,
+                day: 29,
+            },
+            AwardDate {
+                year: 2019,
+                month: 13,
+                day: 1,
+            },
+            AwardDate {
+                year: 1899,
+                month: 12,
+                day: 31,
+            },
+        ] {
+            assert_noop!(
+                Recordings::certify(
+                    RuntimeOrigin::signed(RIAA),
+                    code.clone(),
+                    *b"US",
+                    gold,
+                    awarded_on,
+                    H256::zero(),
+                ),
+                Error::<Test>::InvalidDate
+            );
+        }
+
+        assert_ok!(certify(RIAA, &code, b"US", gold));
+        assert_noop!(
+            certify(RIAA, &code, b"US", gold),
+            Error::<Test>::AlreadyCertified
+        );
+    });
+}
+
+#[test]
+fn certifications_are_bounded_and_stale_ones_make_room() {
+    new_test_ext().execute_with(|| {
+        let code = anchored(b"USUM71703861");
+
+        assert_ok!(certify(BPI, &code, b"GB", CertificationLevel::Silver));
+        assert_ok!(certify(RIAA, &code, b"US", CertificationLevel::Gold));
+        assert_ok!(certify(RIAA, &code, b"US", CertificationLevel::Platinum));
+        assert_noop!(
+            certify(RIAA, &code, b"US", CertificationLevel::Diamond),
+            Error::<Test>::TooManyCertifications
+        );
+
+        // The BPI loses its accreditation: its award is hidden, then dropped for a new one.
+        AccreditedCertifiers::set(vec![RIAA]);
+        assert_eq!(awards(&code).len(), 2);
+        assert_ok!(certify(RIAA, &code, b"US", CertificationLevel::Diamond));
+        assert_eq!(
+            awards(&code),
+            vec![
+                (RIAA, *b"US", CertificationLevel::Gold),
+                (RIAA, *b"US", CertificationLevel::Platinum),
+                (RIAA, *b"US", CertificationLevel::Diamond),
+            ]
+        );
+    });
+}
+
+#[test]
+fn certifiers_revoke_their_own_certifications() {
+    new_test_ext().execute_with(|| {
+        let code = anchored(b"USUM71703861");
+        let gold = CertificationLevel::Gold;
+        assert_ok!(certify(RIAA, &code, b"US", gold));
+        assert_ok!(certify(BPI, &code, b"GB", gold));
+
+        let revoke = |who, territory: &CountryCode| {
+            Recordings::revoke_certification(
+                RuntimeOrigin::signed(who),
+                code.clone(),
+                *territory,
+                gold,
+            )
+        };
+        assert_noop!(revoke(BPI, b"US"), Error::<Test>::CertificationNotFound);
+        assert_noop!(revoke(RIAA, b"GB"), Error::<Test>::CertificationNotFound);
+
+        assert_ok!(revoke(RIAA, b"US"));
+        System::assert_last_event(
+            Event::CertificationRevoked {
+                isrc: code.clone(),
+                certifier: RIAA,
+                territory: *b"US",
+                level: gold,
+            }
+            .into(),
+        );
+        assert_eq!(awards(&code), vec![(BPI, *b"GB", gold)]);
+
+        // A former certification body can still take its awards back.
+        AccreditedCertifiers::set(vec![RIAA]);
+        assert_ok!(revoke(BPI, b"GB"));
+        assert!(!Certifications::<Test>::contains_key(&code));
+    });
+}
+
+#[test]
+fn certifications_follow_the_recording() {
+    new_test_ext().execute_with(|| {
+        let code = anchored(b"USUM71703861");
+        assert_ok!(certify(RIAA, &code, b"US", CertificationLevel::Gold));
+
+        // Awards belong to the master, not to its owner.
+        assert_ok!(Recordings::transfer_ownership(&code, &BOB));
+        assert_eq!(awards(&code).len(), 1);
+
+        assert_ok!(Recordings::remove(RuntimeOrigin::signed(BOB), code.clone()));
+        assert!(!Certifications::<Test>::contains_key(&code));
+    });
+}
+
+const SALT: [u8; 32] = [42; 32];
+
+/// ALICE's commitment to anchor `FRZ031800212` as content `7` embodying work `1`.
+fn commitment() -> H256 {
+    anchor_commitment(
+        &ALICE,
+        &isrc(b"FRZ031800212"),
+        &H256::repeat_byte(7),
+        &works(&[1]),
+        &SALT,
+    )
+}
+
+fn reveal(who: u64, salt: [u8; 32]) -> sp_runtime::DispatchResult {
+    Recordings::reveal_anchor(
+        RuntimeOrigin::signed(who),
+        isrc(b"FRZ031800212"),
+        H256::repeat_byte(7),
+        works(&[1]),
+        salt,
+    )
+}
+
+#[test]
+fn revealed_anchors_are_dated_from_their_commitment() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Recordings::commit_anchor(
+            RuntimeOrigin::signed(ALICE),
+            commitment(),
+            50
+        ));
+        System::assert_last_event(
+            Event::AnchorCommitted {
+                owner: ALICE,
+                commitment: commitment(),
+                reveal_by: 50,
+            }
+            .into(),
+        );
+        assert_eq!(held(ALICE), ANCHOR_DEPOSIT);
+        assert_noop!(
+            Recordings::commit_anchor(RuntimeOrigin::signed(ALICE), commitment(), 50),
+            Error::<Test>::CommitmentAlreadyExists
+        );
+
+        System::set_block_number(50);
+        // Only ALICE knows the salt, and the commitment binds ALICE's account.
+        assert_noop!(reveal(ALICE, [0; 32]), Error::<Test>::CommitmentNotFound);
+        assert_noop!(reveal(BOB, SALT), Error::<Test>::CommitmentNotFound);
+        assert_ok!(reveal(ALICE, SALT));
+
+        let code = isrc(b"FRZ031800212");
+        let recording = RecordingsStorage::<Test>::get(&code).unwrap();
+        assert_eq!(recording.owner, ALICE);
+        assert_eq!(recording.anchored_at, 1);
+        assert_eq!(recording.deposit, ANCHOR_DEPOSIT);
+        assert_eq!(held(ALICE), ANCHOR_DEPOSIT);
+        assert_eq!(
+            ContentIndex::<Test>::get(H256::repeat_byte(7)),
+            Some(code.clone())
+        );
+        assert_eq!(
+            CatalogRoot::entity_hash(CATALOG_NAMESPACE, &code),
+            Some(BlakeTwo256::hash_of(&recording))
+        );
+        System::assert_has_event(
+            Event::RecordingAnchored {
+                isrc: code.clone(),
+                owner: ALICE,
+                content_hash: H256::repeat_byte(7),
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::AnchorRevealed {
+                owner: ALICE,
+                commitment: commitment(),
+                isrc: code,
+            }
+            .into(),
+        );
+        assert_eq!(SealedAnchors::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn reveal_deadline_is_bounded_and_enforced() {
+    new_test_ext().execute_with(|| {
+        let commit = |reveal_by| {
+            Recordings::commit_anchor(RuntimeOrigin::signed(ALICE), commitment(), reveal_by)
+        };
+        assert_noop!(commit(1), Error::<Test>::InvalidRevealDeadline);
+        assert_noop!(
+            commit(2 + MAX_REVEAL_DELAY),
+            Error::<Test>::InvalidRevealDeadline
+        );
+        assert_noop!(
+            Recordings::commit_anchor(RuntimeOrigin::signed(POOR), commitment(), 2),
+            sp_runtime::TokenError::FundsUnavailable
+        );
+        assert_ok!(commit(1 + MAX_REVEAL_DELAY));
+
+        System::set_block_number(2 + MAX_REVEAL_DELAY);
+        assert_noop!(reveal(ALICE, SALT), Error::<Test>::RevealDeadlinePassed);
+
+        assert_noop!(
+            Recordings::withdraw_commitment(RuntimeOrigin::signed(BOB), commitment()),
+            Error::<Test>::CommitmentNotFound
+        );
+        assert_ok!(Recordings::withdraw_commitment(
+            RuntimeOrigin::signed(ALICE),
+            commitment()
+        ));
+        System::assert_last_event(
+            Event::CommitmentWithdrawn {
+                owner: ALICE,
+                commitment: commitment(),
+            }
+            .into(),
+        );
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 100);
+    });
+}
+
+#[test]
+fn commitments_survive_a_failed_reveal() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Recordings::commit_anchor(
+            RuntimeOrigin::signed(ALICE),
+            commitment(),
+            50
+        ));
+        // The master leaked and was anchored publicly before the release.
+        System::set_block_number(10);
+        assert_ok!(Recordings::anchor(
+            RuntimeOrigin::signed(BOB),
+            isrc(b"USAAA2500001"),
+            H256::repeat_byte(7),
+            works(&[1])
+        ));
+
+        assert_noop!(reveal(ALICE, SALT), Error::<Test>::ContentAlreadyAnchored);
+        // ALICE keeps the proof of having had the master first.
+        assert_eq!(
+            SealedAnchors::<Test>::get(ALICE, commitment()).map(|sealed| sealed.committed_at),
+            Some(1)
+        );
+        assert_eq!(
+            RecordingsStorage::<Test>::get(isrc(b"USAAA2500001")).map(|r| r.anchored_at),
+            Some(10)
+        );
+    });
+}
@@ -0,0 +1,110 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_recordings.
+pub trait WeightInfo {
+    fn anchor(w: u32) -> Weight;
+    fn set_works(w: u32) -> Weight;
+    fn remove() -> Weight;
+    fn certify() -> Weight;
+    fn revoke_certification() -> Weight;
+    fn commit_anchor() -> Weight;
+    fn reveal_anchor(w: u32) -> Weight;
+    fn withdraw_commitment() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Recordings::Recordings` (r:1 w:1)
+    /// Storage: `Recordings::ContentIndex` (r:1 w:1)
+    /// Storage: `Works` registry (r:w w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `CatalogRoot` child trie (r:0 w:1)
+    /// The range of component `w` is `[1, 8]`.
+    fn anchor(w: u32) -> Weight {
+        Weight::from_parts(45_000_000, 4_000)
+            .saturating_add(Weight::from_parts(4_000_000, 2_600).saturating_mul(w.into()))
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().reads(w.into()))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Recordings::Recordings` (r:1 w:1)
+    /// Storage: `Works` registry (r:w w:0)
+    /// Storage: `CatalogRoot` child trie (r:0 w:1)
+    /// The range of component `w` is `[1, 8]`.
+    fn set_works(w: u32) -> Weight {
+        Weight::from_parts(20_000_000, 2_000)
+            .saturating_add(Weight::from_parts(4_000_000, 2_600).saturating_mul(w.into()))
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().reads(w.into()))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Recordings::Recordings` (r:1 w:1)
+    /// Storage: `Recordings::ContentIndex` (r:0 w:1)
+    /// Storage: `Recordings::Certifications` (r:0 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `CatalogRoot` child trie (r:0 w:1)
+    fn remove() -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(5_u64))
+    }
+    /// Storage: `Certifiers` set (r:`1 + MaxCertificationsPerRecording` w:0)
+    /// Storage: `Recordings::Recordings` (r:1 w:0)
+    /// Storage: `Recordings::Certifications` (r:1 w:1)
+    fn certify() -> Weight {
+        Weight::from_parts(60_000_000, 6_000)
+            .saturating_add(ParityDbWeight::get().reads(35_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Recordings::Certifications` (r:1 w:1)
+    fn revoke_certification() -> Weight {
+        Weight::from_parts(25_000_000, 6_000)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Recordings::SealedAnchors` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn commit_anchor() -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Recordings::SealedAnchors` (r:1 w:1)
+    /// Storage: `Recordings::Recordings` (r:1 w:1)
+    /// Storage: `Recordings::ContentIndex` (r:1 w:1)
+    /// Storage: `Works` registry (r:w w:0)
+    /// Storage: `CatalogRoot` child trie (r:0 w:1)
+    /// The range of component `w` is `[1, 8]`.
+    fn reveal_anchor(w: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(Weight::from_parts(4_000_000, 2_600).saturating_mul(w.into()))
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().reads(w.into()))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Recordings::SealedAnchors` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn withdraw_commitment() -> Weight {
+        Weight::from_parts(30_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+}
@@ -0,0 +1,93 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// An accredited certifier with a signing key, and a claim about a new entity signed with it.
+fn signed_claim<T: Config>() -> (T::AccountId, ClaimOf<T>, T::Signature) {
+    let certifier: T::AccountId = account("certifier", 0, SEED);
+    T::BenchmarkHelper::accredit(&certifier);
+    let claim = Claim {
+        entity: T::BenchmarkHelper::create_entity(0),
+        reference: BoundedVec::truncate_from(
+            core::iter::repeat_n(b'0', T::MaxReferenceLength::get() as usize).collect(),
+        ),
+        nonce: 0,
+    };
+    let (key, signature) =
+        T::BenchmarkHelper::sign(&Pallet::<T>::signing_payload(&certifier, &claim));
+    SigningKeys::<T>::insert(&certifier, key);
+    (certifier, claim, signature)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_signing_key() {
+        let (certifier, ..) = signed_claim::<T>();
+        let key = SigningKeys::<T>::take(&certifier).expect("key set above");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(certifier.clone()), key);
+
+        assert!(SigningKeys::<T>::contains_key(&certifier));
+    }
+
+    #[benchmark]
+    fn publish() {
+        let (certifier, claim, signature) = signed_claim::<T>();
+        let entity = claim.entity.clone();
+        let relayer: T::AccountId = account("relayer", 0, SEED);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            certifier.clone(),
+            claim,
+            signature,
+        );
+
+        assert!(Attestations::<T>::contains_key(&entity, &certifier));
+    }
+
+    #[benchmark]
+    fn revoke() {
+        let (certifier, claim, signature) = signed_claim::<T>();
+        let entity = claim.entity.clone();
+        Pallet::<T>::publish(
+            RawOrigin::Signed(certifier.clone()).into(),
+            certifier.clone(),
+            claim,
+            signature,
+        )
+        .expect("claim signed above");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(certifier.clone()), entity.clone());
+
+        assert!(!Attestations::<T>::contains_key(&entity, &certifier));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,333 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Registry Attestations
+//!
+//! Signed attestations published by accredited external certifiers (collective management
+//! societies, national registries, ...), bridging the trust of legacy registries onto the chain:
+//! "work W is registered with society X under number Y".
+//!
+//! ## Features
+//! - Accreditation is decided outside this pallet (`Config::Certifiers`).
+//! - Each certifier registers the key its registry signs attestations with, and may rotate it.
+//! - Anyone may relay an attestation signed by a certifier's key: the signature is checked
+//!   against the stored key, over a payload bound to this chain and the certifier, and each
+//!   claim carries the certifier's next nonce so it cannot be replayed.
+//! - One attestation per certifier and entity, holding the reference the entity has in the
+//!   certifier's registry and the key it was signed with. Certifiers may revoke them.
+//! - Attestations are queried per entity; those of certifiers who lost their accreditation are
+//!   kept but no longer returned.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use alloc::vec::Vec;
+use frame_support::{pallet_prelude::*, traits::Contains};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{
+    Saturating,
+    traits::{IdentifyAccount, Verify, Zero},
+};
+
+/// Domain separator of the signed payload.
+pub const ATTESTATION_CONTEXT: &[u8] = b"allfeat/registry-attestation";
+
+/// Identifier an entity has in a certifier's registry, e.g. a work registration number.
+pub type ReferenceOf<T> = BoundedVec<u8, <T as Config>::MaxReferenceLength>;
+
+pub type ClaimOf<T> = Claim<<T as Config>::Entity, ReferenceOf<T>>;
+
+pub type AttestationOf<T> =
+    Attestation<ReferenceOf<T>, <T as Config>::SigningKey, BlockNumberFor<T>>;
+
+/// What a certifier signs.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct Claim<Entity, Reference> {
+    /// The on-chain entity the claim is about.
+    pub entity: Entity,
+    /// Identifier of the entity in the certifier's registry.
+    pub reference: Reference,
+    /// Next nonce of the certifier: claims are accepted once, in signing order.
+    pub nonce: u32,
+}
+
+/// A published attestation.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Attestation<Reference, SigningKey, BlockNumber> {
+    /// Identifier of the entity in the certifier's registry.
+    pub reference: Reference,
+    /// Key the claim was signed with, kept across key rotations.
+    pub key: SigningKey,
+    /// Nonce of the signed claim.
+    pub nonce: u32,
+    /// Block at which the attestation was published.
+    pub published_at: BlockNumber,
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, Entity, SigningKey, Signature> {
+    /// Accredit `who` as a certifier.
+    fn accredit(who: &AccountId);
+
+    /// Create the `i`-th entity and return its id.
+    fn create_entity(i: u32) -> Entity;
+
+    /// Generate a signing key and sign `message` with it.
+    fn sign(message: &[u8]) -> (SigningKey, Signature);
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Accounts of the accredited certifiers.
+        type Certifiers: Contains<Self::AccountId>;
+
+        /// Identifier of an on-chain entity certifiers attest.
+        type Entity: Parameter + Member + MaxEncodedLen;
+
+        /// The entities that can be attested.
+        type Entities: Contains<Self::Entity>;
+
+        /// Signature of a certifier's registry over a claim.
+        type Signature: Verify<Signer = Self::KeyIdentifier> + Parameter;
+
+        /// Signer of `Signature`.
+        type KeyIdentifier: IdentifyAccount<AccountId = Self::SigningKey>;
+
+        /// Public key a certifier signs claims with.
+        type SigningKey: Parameter + Member + MaxEncodedLen;
+
+        /// Max length of a registry reference.
+        #[pallet::constant]
+        type MaxReferenceLength: Get<u32>;
+
+        /// Max number of attestations on a single entity.
+        #[pallet::constant]
+        type MaxAttestations: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::Entity, Self::SigningKey, Self::Signature>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Key each certifier's registry signs claims with.
+    #[pallet::storage]
+    pub type SigningKeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::SigningKey, OptionQuery>;
+
+    /// Nonce the next claim of each certifier must carry.
+    #[pallet::storage]
+    pub type Nonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Attestations of each entity, by certifier.
+    #[pallet::storage]
+    pub type Attestations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::Entity,
+        Blake2_128Concat,
+        T::AccountId,
+        AttestationOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    pub type AttestationCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Entity, u32, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        SigningKeySet {
+            certifier: T::AccountId,
+            key: T::SigningKey,
+        },
+        /// The signature is part of the event so that the claim stays verifiable off chain.
+        AttestationPublished {
+            entity: T::Entity,
+            certifier: T::AccountId,
+            reference: ReferenceOf<T>,
+            signature: T::Signature,
+        },
+        AttestationRevoked {
+            entity: T::Entity,
+            certifier: T::AccountId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NotCertifier,
+        NoSigningKey,
+        UnknownEntity,
+        InvalidNonce,
+        BadSignature,
+        TooManyAttestations,
+        AttestationNotFound,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the key the caller's registry signs claims with. Certifiers only.
+        ///
+        /// Published attestations keep the key they were signed with.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_signing_key())]
+        pub fn set_signing_key(origin: OriginFor<T>, key: T::SigningKey) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(T::Certifiers::contains(&who), Error::<T>::NotCertifier);
+            SigningKeys::<T>::insert(&who, &key);
+
+            Self::deposit_event(Event::SigningKeySet {
+                certifier: who,
+                key,
+            });
+            Ok(())
+        }
+
+        /// Publish `claim`, signed by the registry of `certifier`.
+        ///
+        /// Any account may relay the claim. An earlier attestation of the same entity by the
+        /// same certifier is replaced.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::publish())]
+        pub fn publish(
+            origin: OriginFor<T>,
+            certifier: T::AccountId,
+            claim: ClaimOf<T>,
+            signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            ensure!(
+                T::Certifiers::contains(&certifier),
+                Error::<T>::NotCertifier
+            );
+            let key = SigningKeys::<T>::get(&certifier).ok_or(Error::<T>::NoSigningKey)?;
+            ensure!(
+                claim.nonce == Nonces::<T>::get(&certifier),
+                Error::<T>::InvalidNonce
+            );
+            ensure!(
+                T::Entities::contains(&claim.entity),
+                Error::<T>::UnknownEntity
+            );
+            ensure!(
+                signature.verify(&Self::signing_payload(&certifier, &claim)[..], &key),
+                Error::<T>::BadSignature
+            );
+
+            if !Attestations::<T>::contains_key(&claim.entity, &certifier) {
+                AttestationCount::<T>::try_mutate(&claim.entity, |count| -> DispatchResult {
+                    ensure!(
+                        *count < T::MaxAttestations::get(),
+                        Error::<T>::TooManyAttestations
+                    );
+                    count.saturating_inc();
+                    Ok(())
+                })?;
+            }
+            Nonces::<T>::insert(&certifier, claim.nonce.saturating_add(1));
+            Attestations::<T>::insert(
+                &claim.entity,
+                &certifier,
+                Attestation {
+                    reference: claim.reference.clone(),
+                    key,
+                    nonce: claim.nonce,
+                    published_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::AttestationPublished {
+                entity: claim.entity,
+                certifier,
+                reference: claim.reference,
+                signature,
+            });
+            Ok(())
+        }
+
+        /// Revoke the caller's attestation of `entity`.
+        ///
+        /// Former certifiers may revoke their attestations too.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::revoke())]
+        pub fn revoke(origin: OriginFor<T>, entity: T::Entity) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                Attestations::<T>::take(&entity, &who).is_some(),
+                Error::<T>::AttestationNotFound
+            );
+            AttestationCount::<T>::mutate_exists(&entity, |count| {
+                let remaining = count.unwrap_or_default().saturating_sub(1);
+                *count = (!remaining.is_zero()).then_some(remaining);
+            });
+
+            Self::deposit_event(Event::AttestationRevoked {
+                entity,
+                certifier: who,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The bytes `certifier`'s registry signs for `claim`.
+        ///
+        /// Bound to this chain by its genesis hash, and to `certifier` so that a registry
+        /// sharing its key across accounts cannot have its claims replayed by another.
+        pub fn signing_payload(certifier: &T::AccountId, claim: &ClaimOf<T>) -> Vec<u8> {
+            let genesis = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+            (ATTESTATION_CONTEXT, genesis, certifier, claim).encode()
+        }
+
+        /// Attestations of `entity` by currently accredited certifiers.
+        pub fn attestations_of(entity: &T::Entity) -> Vec<(T::AccountId, AttestationOf<T>)> {
+            Attestations::<T>::iter_prefix(entity)
+                .filter(|(certifier, _)| T::Certifiers::contains(certifier))
+                .collect()
+        }
+    }
+}
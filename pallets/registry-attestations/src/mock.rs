@@ -0,0 +1,125 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_registry_attestations;
+use frame_support::{
+    derive_impl, parameter_types,
+    sp_runtime::{
+        BuildStorage,
+        testing::{TestSignature, UintAuthorityId},
+    },
+    traits::Contains,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type RegistryAttestations = pallet_registry_attestations;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+parameter_types! {
+    pub const MaxReferenceLength: u32 = 16;
+    pub const MaxAttestations: u32 = 2;
+    pub static AccreditedCertifiers: Vec<u64> = vec![SOCIETY, REGISTRY, AGENCY];
+}
+
+pub struct MockCertifiers;
+impl Contains<u64> for MockCertifiers {
+    fn contains(who: &u64) -> bool {
+        AccreditedCertifiers::get().contains(who)
+    }
+}
+
+/// Entities `1..=MAX_ENTITY` exist.
+pub struct MockEntities;
+impl Contains<u32> for MockEntities {
+    fn contains(entity: &u32) -> bool {
+        (1..=MAX_ENTITY).contains(entity)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u64, u32, u64, TestSignature> for MockEntities {
+    fn accredit(who: &u64) {
+        AccreditedCertifiers::mutate(|certifiers| certifiers.push(*who));
+    }
+
+    fn create_entity(i: u32) -> u32 {
+        i % MAX_ENTITY + 1
+    }
+
+    fn sign(message: &[u8]) -> (u64, TestSignature) {
+        (0, TestSignature(0, message.to_vec()))
+    }
+}
+
+impl pallet_registry_attestations::Config for Test {
+    type Certifiers = MockCertifiers;
+    type Entity = u32;
+    type Entities = MockEntities;
+    type Signature = TestSignature;
+    type KeyIdentifier = UintAuthorityId;
+    type SigningKey = u64;
+    type MaxReferenceLength = MaxReferenceLength;
+    type MaxAttestations = MaxAttestations;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockEntities;
+}
+
+pub const MAX_ENTITY: u32 = 10;
+pub const WORK: u32 = 1;
+
+/// A collective management society.
+pub const SOCIETY: u64 = 1;
+pub const REGISTRY: u64 = 2;
+pub const AGENCY: u64 = 3;
+pub const OUTSIDER: u64 = 4;
+pub const RELAYER: u64 = 5;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
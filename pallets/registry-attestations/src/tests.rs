@@ -0,0 +1,243 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AttestationCount, Attestations, ClaimOf, Error, Event, Nonces, mock::*};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchResult, testing::TestSignature};
+
+/// Key `certifier`'s registry signs with.
+fn key(certifier: u64) -> u64 {
+    certifier * 100
+}
+
+fn set_key(certifier: u64) {
+    assert_ok!(RegistryAttestations::set_signing_key(
+        RuntimeOrigin::signed(certifier),
+        key(certifier)
+    ));
+}
+
+fn claim(entity: u32, reference: &[u8], nonce: u32) -> ClaimOf<Test> {
+    ClaimOf::<Test> {
+        entity,
+        reference: reference.to_vec().try_into().unwrap(),
+        nonce,
+    }
+}
+
+fn signed_by(signer: u64, certifier: u64, claim: &ClaimOf<Test>) -> TestSignature {
+    TestSignature(
+        signer,
+        RegistryAttestations::signing_payload(&certifier, claim),
+    )
+}
+
+fn publish(certifier: u64, claim: ClaimOf<Test>) -> DispatchResult {
+    let signature = signed_by(key(certifier), certifier, &claim);
+    RegistryAttestations::publish(RuntimeOrigin::signed(RELAYER), certifier, claim, signature)
+}
+
+#[test]
+fn only_certifiers_set_signing_keys() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            RegistryAttestations::set_signing_key(RuntimeOrigin::signed(OUTSIDER), 1),
+            Error::<Test>::NotCertifier
+        );
+
+        set_key(SOCIETY);
+        System::assert_last_event(
+            Event::SigningKeySet {
+                certifier: SOCIETY,
+                key: key(SOCIETY),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn publish_checks_the_signature_against_the_stored_key() {
+    new_test_ext().execute_with(|| {
+        let work = claim(WORK, b"T-123.456.789-0", 0);
+        assert_noop!(publish(SOCIETY, work.clone()), Error::<Test>::NoSigningKey);
+        set_key(SOCIETY);
+        set_key(REGISTRY);
+
+        // Signed by another registry's key.
+        assert_noop!(
+            RegistryAttestations::publish(
+                RuntimeOrigin::signed(RELAYER),
+                SOCIETY,
+                work.clone(),
+                signed_by(key(REGISTRY), SOCIETY, &work)
+            ),
+            Error::<Test>::BadSignature
+        );
+        // Signed for another certifier.
+        assert_noop!(
+            RegistryAttestations::publish(
+                RuntimeOrigin::signed(RELAYER),
+                SOCIETY,
+                work.clone(),
+                signed_by(key(SOCIETY), REGISTRY, &work)
+            ),
+            Error::<Test>::BadSignature
+        );
+        // Signed over another claim.
+        assert_noop!(
+            RegistryAttestations::publish(
+                RuntimeOrigin::signed(RELAYER),
+                SOCIETY,
+                work.clone(),
+                signed_by(key(SOCIETY), SOCIETY, &claim(WORK, b"T-000.000.000-0", 0))
+            ),
+            Error::<Test>::BadSignature
+        );
+
+        assert_ok!(publish(SOCIETY, work.clone()));
+        System::assert_last_event(
+            Event::AttestationPublished {
+                entity: WORK,
+                certifier: SOCIETY,
+                reference: work.reference.clone(),
+                signature: signed_by(key(SOCIETY), SOCIETY, &work),
+            }
+            .into(),
+        );
+        let attestation = Attestations::<Test>::get(WORK, SOCIETY).unwrap();
+        assert_eq!(attestation.reference, work.reference);
+        assert_eq!(attestation.key, key(SOCIETY));
+        assert_eq!(attestation.published_at, 1);
+        assert_eq!(Nonces::<Test>::get(SOCIETY), 1);
+    });
+}
+
+#[test]
+fn publish_rejects_outsiders_unknown_entities_and_replays() {
+    new_test_ext().execute_with(|| {
+        set_key(SOCIETY);
+        assert_noop!(
+            RegistryAttestations::publish(
+                RuntimeOrigin::signed(RELAYER),
+                OUTSIDER,
+                claim(WORK, b"1", 0),
+                signed_by(key(OUTSIDER), OUTSIDER, &claim(WORK, b"1", 0))
+            ),
+            Error::<Test>::NotCertifier
+        );
+        assert_noop!(
+            publish(SOCIETY, claim(MAX_ENTITY + 1, b"1", 0)),
+            Error::<Test>::UnknownEntity
+        );
+
+        assert_ok!(publish(SOCIETY, claim(WORK, b"1", 0)));
+        assert_ok!(RegistryAttestations::revoke(
+            RuntimeOrigin::signed(SOCIETY),
+            WORK
+        ));
+        // The revoked claim cannot be relayed again, nor can claims be skipped.
+        assert_noop!(
+            publish(SOCIETY, claim(WORK, b"1", 0)),
+            Error::<Test>::InvalidNonce
+        );
+        assert_noop!(
+            publish(SOCIETY, claim(WORK, b"1", 2)),
+            Error::<Test>::InvalidNonce
+        );
+        assert_ok!(publish(SOCIETY, claim(WORK, b"1", 1)));
+    });
+}
+
+#[test]
+fn a_newer_claim_replaces_the_certifiers_attestation() {
+    new_test_ext().execute_with(|| {
+        set_key(SOCIETY);
+        set_key(REGISTRY);
+        set_key(AGENCY);
+        assert_ok!(publish(SOCIETY, claim(WORK, b"old", 0)));
+        assert_ok!(publish(REGISTRY, claim(WORK, b"R-1", 0)));
+
+        assert_ok!(publish(SOCIETY, claim(WORK, b"new", 1)));
+        assert_eq!(
+            Attestations::<Test>::get(WORK, SOCIETY).unwrap().reference,
+            b"new".to_vec()
+        );
+        assert_eq!(AttestationCount::<Test>::get(WORK), 2);
+
+        assert_noop!(
+            publish(AGENCY, claim(WORK, b"A-1", 0)),
+            Error::<Test>::TooManyAttestations
+        );
+    });
+}
+
+#[test]
+fn certifiers_revoke_their_own_attestations() {
+    new_test_ext().execute_with(|| {
+        set_key(SOCIETY);
+        assert_ok!(publish(SOCIETY, claim(WORK, b"1", 0)));
+
+        assert_noop!(
+            RegistryAttestations::revoke(RuntimeOrigin::signed(REGISTRY), WORK),
+            Error::<Test>::AttestationNotFound
+        );
+        assert_ok!(RegistryAttestations::revoke(
+            RuntimeOrigin::signed(SOCIETY),
+            WORK
+        ));
+        System::assert_last_event(
+            Event::AttestationRevoked {
+                entity: WORK,
+                certifier: SOCIETY,
+            }
+            .into(),
+        );
+        assert!(Attestations::<Test>::get(WORK, SOCIETY).is_none());
+        assert!(!AttestationCount::<Test>::contains_key(WORK));
+    });
+}
+
+#[test]
+fn attestations_of_skips_certifiers_no_longer_accredited() {
+    new_test_ext().execute_with(|| {
+        set_key(SOCIETY);
+        set_key(REGISTRY);
+        assert_ok!(publish(SOCIETY, claim(WORK, b"S-1", 0)));
+        assert_ok!(publish(REGISTRY, claim(WORK, b"R-1", 0)));
+
+        // Rotating the key keeps the attestation signed with the old one.
+        assert_ok!(RegistryAttestations::set_signing_key(
+            RuntimeOrigin::signed(SOCIETY),
+            7
+        ));
+        AccreditedCertifiers::set(vec![SOCIETY]);
+
+        let attestations = RegistryAttestations::attestations_of(&WORK);
+        assert_eq!(attestations.len(), 1);
+        assert_eq!(attestations[0].0, SOCIETY);
+        assert_eq!(attestations[0].1.key, key(SOCIETY));
+
+        // A former certifier can still revoke.
+        assert_ok!(RegistryAttestations::revoke(
+            RuntimeOrigin::signed(REGISTRY),
+            WORK
+        ));
+        assert_eq!(AttestationCount::<Test>::get(WORK), 1);
+    });
+}
@@ -0,0 +1,57 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_registry_attestations.
+pub trait WeightInfo {
+    fn set_signing_key() -> Weight;
+    fn publish() -> Weight;
+    fn revoke() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Certifiers` set (r:1 w:0)
+    /// Storage: `RegistryAttestations::SigningKeys` (r:0 w:1)
+    fn set_signing_key() -> Weight {
+        Weight::from_parts(14_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Certifiers` set (r:1 w:0)
+    /// Storage: `RegistryAttestations::SigningKeys` (r:1 w:0)
+    /// Storage: `RegistryAttestations::Nonces` (r:1 w:1)
+    /// Storage: `Entities` registry (r:1 w:0)
+    /// Storage: `System::BlockHash` (r:1 w:0)
+    /// Storage: `RegistryAttestations::Attestations` (r:1 w:1)
+    /// Storage: `RegistryAttestations::AttestationCount` (r:1 w:1)
+    fn publish() -> Weight {
+        // Dominated by the signature check.
+        Weight::from_parts(70_000_000, 6_000)
+            .saturating_add(ParityDbWeight::get().reads(7_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `RegistryAttestations::Attestations` (r:1 w:1)
+    /// Storage: `RegistryAttestations::AttestationCount` (r:1 w:1)
+    fn revoke() -> Weight {
+        Weight::from_parts(18_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+}
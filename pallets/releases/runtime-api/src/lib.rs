@@ -0,0 +1,43 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API serving the localized titles of `pallet-releases` releases, and the published
+//! releases titled in a language for catalog searches filtered by language.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::Codec;
+
+pub use pallet_releases::{Ean, LocalizedTitle};
+
+sp_api::decl_runtime_apis! {
+    pub trait ReleasesApi<Title>
+    where
+        Title: Codec,
+    {
+        /// The title of `ean` in `language`, a BCP 47 tag, falling back to its default language.
+        /// `None` if the release has no titles.
+        fn title_of(ean: Ean, language: Option<Vec<u8>>) -> Option<Title>;
+
+        /// Up to `limit` published releases titled in `language`, following `start_after`.
+        fn releases_in_language(language: Vec<u8>, start_after: Option<Ean>, limit: u32) -> Vec<Ean>;
+    }
+}
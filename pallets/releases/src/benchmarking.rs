@@ -0,0 +1,184 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_support::traits::fungible::Mutate;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+
+fn funded_caller<T: Config>() -> T::AccountId {
+    let caller: T::AccountId = account("caller", 0, SEED);
+    T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+    caller
+}
+
+fn bench_ean() -> Ean {
+    BoundedVec::try_from(b"4006381333931".to_vec()).expect("13-byte literal fits EAN bound")
+}
+
+fn bench_date() -> ReleaseDate {
+    ReleaseDate {
+        year: 2025,
+        month: 1,
+        day: 1,
+    }
+}
+
+fn bench_tracks<T: Config>(t: u32) -> TracksOf<T> {
+    (0..t)
+        .map(T::BenchmarkHelper::register_recording)
+        .collect::<sp_runtime::Vec<_>>()
+        .try_into()
+        .expect("t <= MaxTracks")
+}
+
+/// `l` titles in distinct languages, with the longest titles and aliases.
+fn bench_titles<T: Config>(l: u32) -> LocalizedTitlesOf<T> {
+    let title = || -> TitleOf<T> {
+        BoundedVec::truncate_from(
+            core::iter::repeat_n(b'a', T::MaxTitleLength::get() as usize).collect(),
+        )
+    };
+    (0..l)
+        .map(|i| LocalizedTitle {
+            language: BoundedVec::truncate_from(
+                [b'a' + (i / 26 % 26) as u8, b'a' + (i % 26) as u8].to_vec(),
+            ),
+            title: title(),
+            aliases: BoundedVec::truncate_from(
+                (0..T::MaxAliases::get()).map(|_| title()).collect(),
+            ),
+        })
+        .collect::<sp_runtime::Vec<_>>()
+        .try_into()
+        .expect("l <= MaxLanguages")
+}
+
+fn set_draft_titles<T: Config>(caller: &T::AccountId, l: u32) {
+    if l == 0 {
+        return;
+    }
+    let titles = bench_titles::<T>(l);
+    Pallet::<T>::set_titles(
+        RawOrigin::Signed(caller.clone()).into(),
+        bench_ean(),
+        titles[0].language.clone(),
+        titles,
+    )
+    .expect("set_titles succeeds");
+}
+
+fn create_draft<T: Config>(caller: &T::AccountId, t: u32) {
+    Pallet::<T>::create_release(
+        RawOrigin::Signed(caller.clone()).into(),
+        bench_ean(),
+        bench_tracks::<T>(t),
+        bench_date(),
+        caller.clone(),
+    )
+    .expect("create_release succeeds");
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_release(t: Linear<1, { T::MaxTracks::get() }>) {
+        let caller = funded_caller::<T>();
+        let tracks = bench_tracks::<T>(t);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            bench_ean(),
+            tracks,
+            bench_date(),
+            caller.clone(),
+        );
+
+        assert!(Releases::<T>::contains_key(bench_ean()));
+    }
+
+    #[benchmark]
+    fn set_tracks(t: Linear<1, { T::MaxTracks::get() }>) {
+        let caller = funded_caller::<T>();
+        create_draft::<T>(&caller, 1);
+        let tracks = bench_tracks::<T>(t);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), bench_ean(), tracks);
+
+        assert_eq!(
+            Releases::<T>::get(bench_ean()).unwrap().tracks.len() as u32,
+            t
+        );
+    }
+
+    #[benchmark]
+    fn publish(t: Linear<1, { T::MaxTracks::get() }>, l: Linear<0, { T::MaxLanguages::get() }>) {
+        let caller = funded_caller::<T>();
+        create_draft::<T>(&caller, t);
+        set_draft_titles::<T>(&caller, l);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), bench_ean());
+
+        assert!(matches!(
+            Releases::<T>::get(bench_ean()).unwrap().status,
+            ReleaseStatus::Published { .. }
+        ));
+    }
+
+    #[benchmark]
+    fn remove(l: Linear<0, { T::MaxLanguages::get() }>) {
+        let caller = funded_caller::<T>();
+        create_draft::<T>(&caller, T::MaxTracks::get());
+        set_draft_titles::<T>(&caller, l);
+        Pallet::<T>::publish(RawOrigin::Signed(caller.clone()).into(), bench_ean())
+            .expect("publish succeeds");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), bench_ean());
+
+        assert!(!Releases::<T>::contains_key(bench_ean()));
+    }
+
+    #[benchmark]
+    fn set_titles(l: Linear<1, { T::MaxLanguages::get() }>) {
+        let caller = funded_caller::<T>();
+        create_draft::<T>(&caller, 1);
+        let titles = bench_titles::<T>(l);
+        let default_language = titles[0].language.clone();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            bench_ean(),
+            default_language,
+            titles,
+        );
+
+        assert!(Titles::<T>::contains_key(bench_ean()));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,555 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Releases
+//!
+//! Lets labels group recordings into releases (albums, EPs, singles) identified by UPC/EAN.
+//!
+//! ## Features
+//! - Releases are keyed by their GTIN-13. A 12-digit UPC-A is accepted and stored with a leading
+//!   zero, so the same product has exactly one key. The GS1 check digit is verified.
+//! - An ordered tracklist of recordings, each checked against the runtime's recordings registry
+//!   (`Config::Recordings`), a release date and the distributor account.
+//! - Releases start as drafts the label can edit, and become immutable once published. Publishing
+//!   emits `ReleasePublished`, carrying everything an indexer or RPC needs.
+//! - Titles are localized: a draft holds one title, with aliases, per language (BCP 47 tag)
+//!   and a default language. Published releases are indexed by language, so that catalog
+//!   searches can be filtered by language.
+//! - A deposit is held from the label for the lifetime of the release.
+//! - Published releases, titles included, are committed to the catalog root
+//!   (`Config::Catalog`) under [`CATALOG_NAMESPACE`], keyed by EAN-13.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        Contains,
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::Precision,
+    },
+};
+use frame_system::pallet_prelude::*;
+use pallet_catalog_root::CatalogCommitment;
+
+/// Length of a GTIN-13 (EAN-13) code.
+pub const EAN_LEN: u32 = 13;
+
+/// A release's product code, normalized to 13 ASCII digits.
+pub type Ean = BoundedVec<u8, ConstU32<EAN_LEN>>;
+
+/// Namespace of published releases in the catalog commitment.
+pub const CATALOG_NAMESPACE: [u8; 4] = *b"rels";
+
+/// Max length of a language tag, as recommended by RFC 5646.
+pub const LANGUAGE_TAG_LEN: u32 = 35;
+
+/// A BCP 47 language tag, e.g. `fr` or `pt-br`, normalized to lower case.
+pub type LanguageTag = BoundedVec<u8, ConstU32<LANGUAGE_TAG_LEN>>;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type TracksOf<T> = BoundedVec<<T as Config>::RecordingId, <T as Config>::MaxTracks>;
+
+pub type TitleOf<T> = BoundedVec<u8, <T as Config>::MaxTitleLength>;
+
+pub type LocalizedTitleOf<T> =
+    LocalizedTitle<TitleOf<T>, BoundedVec<TitleOf<T>, <T as Config>::MaxAliases>>;
+
+pub type LocalizedTitlesOf<T> = BoundedVec<LocalizedTitleOf<T>, <T as Config>::MaxLanguages>;
+
+pub type ReleaseTitlesOf<T> = ReleaseTitles<LocalizedTitlesOf<T>>;
+
+pub type ReleaseOf<T> =
+    Release<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>, TracksOf<T>>;
+
+/// Calendar date a release is (or was) made available.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct ReleaseDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl ReleaseDate {
+    pub fn is_valid(&self) -> bool {
+        let days_in_month = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if self.year.is_multiple_of(4)
+                && (!self.year.is_multiple_of(100) || self.year.is_multiple_of(400)) =>
+            {
+                29
+            }
+            2 => 28,
+            _ => return false,
+        };
+        self.year >= 1900 && (1..=days_in_month).contains(&self.day)
+    }
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum ReleaseStatus<BlockNumber> {
+    Draft,
+    Published { at: BlockNumber },
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Release<AccountId, Balance, BlockNumber, Tracks> {
+    /// The label that created the release.
+    pub owner: AccountId,
+    /// Account distributing the release to stores.
+    pub distributor: AccountId,
+    /// Recordings in track order.
+    pub tracks: Tracks,
+    pub release_date: ReleaseDate,
+    pub status: ReleaseStatus<BlockNumber>,
+    /// Amount held from `owner` for this release.
+    pub deposit: Balance,
+}
+
+/// The title of a release in one language.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct LocalizedTitle<Title, Aliases> {
+    pub language: LanguageTag,
+    pub title: Title,
+    /// Alternative titles in the same language, e.g. a transliteration or a former title.
+    pub aliases: Aliases,
+}
+
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ReleaseTitles<Titles> {
+    /// Language of the title shown when none is requested, or the requested one is missing.
+    pub default_language: LanguageTag,
+    /// One title per language, in the order the label gave them.
+    pub titles: Titles,
+}
+
+/// Normalizes a 12-digit UPC-A or a 13-digit EAN-13 to an EAN-13 and verifies its GS1 check
+/// digit.
+pub fn normalize_ean(code: &[u8]) -> Option<Ean> {
+    if !code.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let ean: sp_runtime::Vec<u8> = match code.len() {
+        12 => core::iter::once(b'0').chain(code.iter().copied()).collect(),
+        13 => code.to_vec(),
+        _ => return None,
+    };
+    // Weights alternate 1, 3 from the left of an EAN-13, the last digit being the check digit.
+    let sum: u32 = ean[..12]
+        .iter()
+        .enumerate()
+        .map(|(i, d)| u32::from(d - b'0') * if i % 2 == 0 { 1 } else { 3 })
+        .sum();
+    let check = (10 - sum % 10) % 10;
+    if u32::from(ean[12] - b'0') != check {
+        return None;
+    }
+    ean.try_into().ok()
+}
+
+/// Checks that `tag` is well-formed BCP 47 and lower-cases it, so that one language has exactly
+/// one key. Subtags are 1 to 8 ASCII alphanumerics separated by dashes, the primary language
+/// subtag being 2 to 8 letters.
+pub fn normalize_language_tag(tag: &[u8]) -> Option<LanguageTag> {
+    let mut subtags = tag.split(|c| *c == b'-');
+    let primary = subtags.next()?;
+    let well_formed = (2..=8).contains(&primary.len())
+        && primary.iter().all(u8::is_ascii_alphabetic)
+        && subtags.all(|s| (1..=8).contains(&s.len()) && s.iter().all(u8::is_ascii_alphanumeric));
+    if !well_formed {
+        return None;
+    }
+    tag.to_ascii_lowercase().try_into().ok()
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<RecordingId> {
+    /// Register the `i`-th recording in the recordings registry and return its id.
+    fn register_recording(i: u32) -> RecordingId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Identifier of a recording in the recordings registry.
+        type RecordingId: Parameter + Member + MaxEncodedLen;
+
+        /// The recordings registry tracks are checked against.
+        type Recordings: Contains<Self::RecordingId>;
+
+        /// Max number of tracks on a release.
+        #[pallet::constant]
+        type MaxTracks: Get<u32>;
+
+        /// Amount held from the label for each release.
+        #[pallet::constant]
+        type ReleaseDeposit: Get<BalanceOf<Self>>;
+
+        /// Max number of languages a release can be titled in.
+        #[pallet::constant]
+        type MaxLanguages: Get<u32>;
+
+        /// Max length of a title or alias, in bytes.
+        #[pallet::constant]
+        type MaxTitleLength: Get<u32>;
+
+        /// Max number of aliases of a title.
+        #[pallet::constant]
+        type MaxAliases: Get<u32>;
+
+        /// The catalog commitment published releases are kept in.
+        type Catalog: CatalogCommitment;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::RecordingId>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        ReleaseDeposit,
+    }
+
+    /// Releases, keyed by EAN-13.
+    #[pallet::storage]
+    pub type Releases<T: Config> = StorageMap<_, Blake2_128Concat, Ean, ReleaseOf<T>, OptionQuery>;
+
+    /// Localized titles of releases.
+    #[pallet::storage]
+    pub type Titles<T: Config> =
+        StorageMap<_, Blake2_128Concat, Ean, ReleaseTitlesOf<T>, OptionQuery>;
+
+    /// Published releases titled in each language.
+    #[pallet::storage]
+    pub type LanguageIndex<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, LanguageTag, Blake2_128Concat, Ean, (), OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        ReleaseCreated {
+            ean: Ean,
+            owner: T::AccountId,
+        },
+        TracksUpdated {
+            ean: Ean,
+        },
+        TitlesUpdated {
+            ean: Ean,
+        },
+        ReleasePublished {
+            ean: Ean,
+            owner: T::AccountId,
+            distributor: T::AccountId,
+            release_date: ReleaseDate,
+            tracks: TracksOf<T>,
+            titles: Option<ReleaseTitlesOf<T>>,
+        },
+        ReleaseRemoved {
+            ean: Ean,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Not a 12-digit UPC-A or 13-digit EAN-13 with a valid check digit.
+        InvalidCode,
+        InvalidReleaseDate,
+        ReleaseAlreadyExists,
+        ReleaseNotFound,
+        NotOwner,
+        AlreadyPublished,
+        NoTracks,
+        DuplicateTrack,
+        UnknownRecording,
+        /// A language tag is not well-formed BCP 47.
+        InvalidLanguageTag,
+        NoTitles,
+        /// Two titles share a language.
+        DuplicateLanguage,
+        /// The default language has no title.
+        MissingDefaultTitle,
+        EmptyTitle,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a draft release under `code`, a UPC-A or EAN-13.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_release(tracks.len() as u32))]
+        pub fn create_release(
+            origin: OriginFor<T>,
+            code: Ean,
+            tracks: TracksOf<T>,
+            release_date: ReleaseDate,
+            distributor: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let ean = normalize_ean(&code).ok_or(Error::<T>::InvalidCode)?;
+            ensure!(
+                !Releases::<T>::contains_key(&ean),
+                Error::<T>::ReleaseAlreadyExists
+            );
+            ensure!(release_date.is_valid(), Error::<T>::InvalidReleaseDate);
+            Self::ensure_valid_tracks(&tracks)?;
+
+            let deposit = T::ReleaseDeposit::get();
+            T::Currency::hold(&HoldReason::ReleaseDeposit.into(), &who, deposit)?;
+
+            Releases::<T>::insert(
+                &ean,
+                Release {
+                    owner: who.clone(),
+                    distributor,
+                    tracks,
+                    release_date,
+                    status: ReleaseStatus::Draft,
+                    deposit,
+                },
+            );
+
+            Self::deposit_event(Event::ReleaseCreated { ean, owner: who });
+            Ok(())
+        }
+
+        /// Replace the tracklist of a draft release. Owner only.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_tracks(tracks.len() as u32))]
+        pub fn set_tracks(origin: OriginFor<T>, ean: Ean, tracks: TracksOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Releases::<T>::try_mutate(&ean, |maybe_release| -> DispatchResult {
+                let release = Self::ensure_owned_draft(maybe_release.as_mut(), &who)?;
+                Self::ensure_valid_tracks(&tracks)?;
+                release.tracks = tracks;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::TracksUpdated { ean });
+            Ok(())
+        }
+
+        /// Publish a draft release, freezing it. Owner only.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::publish(T::MaxTracks::get(), T::MaxLanguages::get()))]
+        pub fn publish(origin: OriginFor<T>, ean: Ean) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let release = Releases::<T>::try_mutate(&ean, |maybe_release| {
+                let release = Self::ensure_owned_draft(maybe_release.as_mut(), &who)?;
+                // Recordings may have been removed from the registry since the draft was made.
+                Self::ensure_valid_tracks(&release.tracks)?;
+                release.status = ReleaseStatus::Published {
+                    at: frame_system::Pallet::<T>::block_number(),
+                };
+                Ok::<_, DispatchError>(release.clone())
+            })?;
+
+            let titles = Titles::<T>::get(&ean);
+            for title in titles.iter().flat_map(|t| t.titles.iter()) {
+                LanguageIndex::<T>::insert(&title.language, &ean, ());
+            }
+            T::Catalog::commit(CATALOG_NAMESPACE, &ean, &(&release, &titles));
+
+            Self::deposit_event(Event::ReleasePublished {
+                ean,
+                owner: release.owner,
+                distributor: release.distributor,
+                release_date: release.release_date,
+                tracks: release.tracks,
+                titles,
+            });
+            Ok(())
+        }
+
+        /// Remove a release and release its deposit. Owner only.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::remove(T::MaxLanguages::get()))]
+        pub fn remove(origin: OriginFor<T>, ean: Ean) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let release = Releases::<T>::get(&ean).ok_or(Error::<T>::ReleaseNotFound)?;
+            ensure!(release.owner == who, Error::<T>::NotOwner);
+
+            T::Currency::release(
+                &HoldReason::ReleaseDeposit.into(),
+                &who,
+                release.deposit,
+                Precision::BestEffort,
+            )?;
+            Releases::<T>::remove(&ean);
+            for title in Titles::<T>::take(&ean).iter().flat_map(|t| t.titles.iter()) {
+                LanguageIndex::<T>::remove(&title.language, &ean);
+            }
+            if release.status != ReleaseStatus::Draft {
+                T::Catalog::withdraw(CATALOG_NAMESPACE, &ean);
+            }
+
+            Self::deposit_event(Event::ReleaseRemoved { ean });
+            Ok(())
+        }
+
+        /// Replace the localized titles of a draft release. `default_language` must be one of
+        /// the titles' languages. Language tags are normalized to lower case. Owner only.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_titles(titles.len() as u32))]
+        pub fn set_titles(
+            origin: OriginFor<T>,
+            ean: Ean,
+            default_language: LanguageTag,
+            mut titles: LocalizedTitlesOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_owned_draft(Releases::<T>::get(&ean).as_mut(), &who)?;
+            ensure!(!titles.is_empty(), Error::<T>::NoTitles);
+            let default_language =
+                normalize_language_tag(&default_language).ok_or(Error::<T>::InvalidLanguageTag)?;
+            for i in 0..titles.len() {
+                let title = &mut titles[i];
+                title.language = normalize_language_tag(&title.language)
+                    .ok_or(Error::<T>::InvalidLanguageTag)?;
+                ensure!(
+                    !title.title.is_empty() && title.aliases.iter().all(|a| !a.is_empty()),
+                    Error::<T>::EmptyTitle
+                );
+                ensure!(
+                    !titles[..i].iter().any(|t| t.language == titles[i].language),
+                    Error::<T>::DuplicateLanguage
+                );
+            }
+            ensure!(
+                titles.iter().any(|t| t.language == default_language),
+                Error::<T>::MissingDefaultTitle
+            );
+
+            Titles::<T>::insert(
+                &ean,
+                ReleaseTitles {
+                    default_language,
+                    titles,
+                },
+            );
+
+            Self::deposit_event(Event::TitlesUpdated { ean });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn ensure_owned_draft<'a>(
+            release: Option<&'a mut ReleaseOf<T>>,
+            who: &T::AccountId,
+        ) -> Result<&'a mut ReleaseOf<T>, DispatchError> {
+            let release = release.ok_or(Error::<T>::ReleaseNotFound)?;
+            ensure!(release.owner == *who, Error::<T>::NotOwner);
+            ensure!(
+                release.status == ReleaseStatus::Draft,
+                Error::<T>::AlreadyPublished
+            );
+            Ok(release)
+        }
+
+        /// The title of `ean` in `language`, or in its default language if `language` is
+        /// `None` or the release has no title in it.
+        pub fn title_of(ean: &Ean, language: Option<&[u8]>) -> Option<LocalizedTitleOf<T>> {
+            let titles = Titles::<T>::get(ean)?;
+            let requested = language.and_then(normalize_language_tag);
+            [requested.as_ref(), Some(&titles.default_language)]
+                .into_iter()
+                .flatten()
+                .find_map(|language| titles.titles.iter().find(|t| &t.language == language))
+                .cloned()
+        }
+
+        /// Up to `limit` published releases titled in `language`, following `start_after` in
+        /// index order.
+        pub fn releases_in_language(
+            language: &[u8],
+            start_after: Option<Ean>,
+            limit: u32,
+        ) -> sp_runtime::Vec<Ean> {
+            let Some(language) = normalize_language_tag(language) else {
+                return sp_runtime::Vec::new();
+            };
+            let eans = match start_after {
+                Some(ean) => LanguageIndex::<T>::iter_key_prefix_from(
+                    &language,
+                    LanguageIndex::<T>::hashed_key_for(&language, ean),
+                ),
+                None => LanguageIndex::<T>::iter_key_prefix(&language),
+            };
+            eans.take(limit as usize).collect()
+        }
+
+        fn ensure_valid_tracks(tracks: &TracksOf<T>) -> DispatchResult {
+            ensure!(!tracks.is_empty(), Error::<T>::NoTracks);
+            for (i, track) in tracks.iter().enumerate() {
+                ensure!(!tracks[..i].contains(track), Error::<T>::DuplicateTrack);
+                ensure!(T::Recordings::contains(track), Error::<T>::UnknownRecording);
+            }
+            Ok(())
+        }
+    }
+}
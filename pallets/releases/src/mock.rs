@@ -0,0 +1,134 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_releases;
+use frame_support::{
+    derive_impl, parameter_types,
+    sp_runtime::BuildStorage,
+    traits::{ConstU64, ConstU128, Contains},
+};
+
+pub type Balance = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type Releases = pallet_releases;
+
+    #[runtime::pallet_index(3)]
+    pub type CatalogRoot = pallet_catalog_root;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+}
+
+pub const RELEASE_DEPOSIT: Balance = 10;
+
+parameter_types! {
+    pub const MaxTracks: u32 = 3;
+    pub const ReleaseDeposit: Balance = RELEASE_DEPOSIT;
+    pub const MaxLanguages: u32 = 3;
+    pub const MaxTitleLength: u32 = 32;
+    pub const MaxAliases: u32 = 2;
+}
+
+/// Recordings `0..100` are registered, anything above is unknown.
+pub struct MockRecordings;
+impl Contains<u32> for MockRecordings {
+    fn contains(recording: &u32) -> bool {
+        *recording < 100
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u32> for MockRecordings {
+    fn register_recording(i: u32) -> u32 {
+        i
+    }
+}
+
+impl pallet_catalog_root::Config for Test {
+    type StatisticsPeriod = ConstU64<10>;
+    type TotalDistributed = ConstU128<0>;
+    type WeightInfo = ();
+}
+
+impl pallet_releases::Config for Test {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RecordingId = u32;
+    type Recordings = MockRecordings;
+    type MaxTracks = MaxTracks;
+    type ReleaseDeposit = ReleaseDeposit;
+    type MaxLanguages = MaxLanguages;
+    type MaxTitleLength = MaxTitleLength;
+    type MaxAliases = MaxAliases;
+    type Catalog = CatalogRoot;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockRecordings;
+}
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const POOR: u64 = 3;
+pub const DISTRIBUTOR: u64 = 4;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, 100), (BOB, 100), (POOR, 5)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,453 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    CATALOG_NAMESPACE, Ean, Error, Event, HoldReason, LanguageIndex, LanguageTag, LocalizedTitle,
+    LocalizedTitlesOf, ReleaseDate, ReleaseStatus, ReleaseTitles, Releases as ReleasesStorage,
+    Titles, mock::*, normalize_ean, normalize_language_tag,
+};
+use frame_support::{BoundedVec, assert_noop, assert_ok, traits::fungible::InspectHold};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+const EAN: &[u8] = b"4006381333931";
+
+fn code(code: &[u8]) -> Ean {
+    code.to_vec().try_into().unwrap()
+}
+
+fn tracks(ids: &[u32]) -> BoundedVec<u32, MaxTracks> {
+    ids.to_vec().try_into().unwrap()
+}
+
+fn date() -> ReleaseDate {
+    ReleaseDate {
+        year: 2025,
+        month: 6,
+        day: 20,
+    }
+}
+
+fn create(who: u64, ids: &[u32]) -> sp_runtime::DispatchResult {
+    Releases::create_release(
+        RuntimeOrigin::signed(who),
+        code(EAN),
+        tracks(ids),
+        date(),
+        DISTRIBUTOR,
+    )
+}
+
+#[test]
+fn upc_and_ean_codes_are_normalized_and_checked() {
+    assert_eq!(normalize_ean(EAN), Some(code(EAN)));
+    // UPC-A gets the leading zero of its EAN-13 form.
+    assert_eq!(normalize_ean(b"036000291452"), Some(code(b"0036000291452")));
+    assert_eq!(
+        normalize_ean(b"0036000291452"),
+        normalize_ean(b"036000291452")
+    );
+
+    assert_eq!(normalize_ean(b"4006381333932"), None);
+    assert_eq!(normalize_ean(b"400638133393"), None);
+    assert_eq!(normalize_ean(b"40063813339A1"), None);
+}
+
+#[test]
+fn release_dates_are_validated() {
+    let at = |year, month, day| ReleaseDate { year, month, day }.is_valid();
+    assert!(at(2024, 2, 29));
+    assert!(!at(2025, 2, 29));
+    assert!(!at(1900, 2, 29));
+    assert!(at(2000, 2, 29));
+    assert!(!at(2025, 4, 31));
+    assert!(!at(2025, 13, 1));
+    assert!(!at(2025, 1, 0));
+}
+
+#[test]
+fn create_release_stores_draft_and_holds_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(create(ALICE, &[3, 1, 2]));
+
+        let release = ReleasesStorage::<Test>::get(code(EAN)).unwrap();
+        assert_eq!(release.owner, ALICE);
+        assert_eq!(release.distributor, DISTRIBUTOR);
+        assert_eq!(release.tracks.to_vec(), vec![3, 1, 2]);
+        assert_eq!(release.status, ReleaseStatus::Draft);
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ReleaseDeposit.into(), &ALICE),
+            RELEASE_DEPOSIT
+        );
+        System::assert_last_event(
+            Event::ReleaseCreated {
+                ean: code(EAN),
+                owner: ALICE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn create_release_validates_input() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Releases::create_release(
+                RuntimeOrigin::signed(ALICE),
+                code(b"4006381333932"),
+                tracks(&[1]),
+                date(),
+                DISTRIBUTOR,
+            ),
+            Error::<Test>::InvalidCode
+        );
+        assert_noop!(
+            Releases::create_release(
+                RuntimeOrigin::signed(ALICE),
+                code(EAN),
+                tracks(&[1]),
+                ReleaseDate {
+                    year: 2025,
+                    month: 2,
+                    day: 30
+                },
+                DISTRIBUTOR,
+            ),
+            Error::<Test>::InvalidReleaseDate
+        );
+        assert_noop!(create(ALICE, &[]), Error::<Test>::NoTracks);
+        assert_noop!(create(ALICE, &[1, 2, 1]), Error::<Test>::DuplicateTrack);
+        assert_noop!(create(ALICE, &[1, 100]), Error::<Test>::UnknownRecording);
+        assert!(create(POOR, &[1]).is_err());
+
+        assert_ok!(create(ALICE, &[1]));
+        assert_noop!(create(BOB, &[2]), Error::<Test>::ReleaseAlreadyExists);
+    });
+}
+
+#[test]
+fn upc_and_ean_forms_share_one_release() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Releases::create_release(
+            RuntimeOrigin::signed(ALICE),
+            code(b"036000291452"),
+            tracks(&[1]),
+            date(),
+            DISTRIBUTOR,
+        ));
+        assert_noop!(
+            Releases::create_release(
+                RuntimeOrigin::signed(BOB),
+                code(b"0036000291452"),
+                tracks(&[1]),
+                date(),
+                DISTRIBUTOR,
+            ),
+            Error::<Test>::ReleaseAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn owner_edits_tracks_until_published() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(create(ALICE, &[1]));
+
+        assert_noop!(
+            Releases::set_tracks(RuntimeOrigin::signed(BOB), code(EAN), tracks(&[2])),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Releases::set_tracks(
+            RuntimeOrigin::signed(ALICE),
+            code(EAN),
+            tracks(&[2, 1])
+        ));
+        System::assert_last_event(Event::TracksUpdated { ean: code(EAN) }.into());
+
+        assert_noop!(
+            Releases::publish(RuntimeOrigin::signed(BOB), code(EAN)),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Releases::publish(RuntimeOrigin::signed(ALICE), code(EAN)));
+        System::assert_last_event(
+            Event::ReleasePublished {
+                ean: code(EAN),
+                owner: ALICE,
+                distributor: DISTRIBUTOR,
+                release_date: date(),
+                tracks: tracks(&[2, 1]),
+                titles: None,
+            }
+            .into(),
+        );
+        assert_eq!(
+            ReleasesStorage::<Test>::get(code(EAN)).unwrap().status,
+            ReleaseStatus::Published { at: 1 }
+        );
+
+        assert_noop!(
+            Releases::set_tracks(RuntimeOrigin::signed(ALICE), code(EAN), tracks(&[1])),
+            Error::<Test>::AlreadyPublished
+        );
+        assert_noop!(
+            Releases::publish(RuntimeOrigin::signed(ALICE), code(EAN)),
+            Error::<Test>::AlreadyPublished
+        );
+    });
+}
+
+#[test]
+fn remove_releases_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(create(ALICE, &[1]));
+        assert_noop!(
+            Releases::remove(RuntimeOrigin::signed(BOB), code(EAN)),
+            Error::<Test>::NotOwner
+        );
+
+        assert_ok!(Releases::remove(RuntimeOrigin::signed(ALICE), code(EAN)));
+        assert!(ReleasesStorage::<Test>::get(code(EAN)).is_none());
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ReleaseDeposit.into(), &ALICE),
+            0
+        );
+        assert_eq!(Balances::free_balance(ALICE), 100);
+        System::assert_last_event(Event::ReleaseRemoved { ean: code(EAN) }.into());
+    });
+}
+
+fn titles(entries: &[(&str, &str, &[&str])]) -> LocalizedTitlesOf<Test> {
+    entries
+        .iter()
+        .map(|(language, title, aliases)| LocalizedTitle {
+            language: language.as_bytes().to_vec().try_into().unwrap(),
+            title: title.as_bytes().to_vec().try_into().unwrap(),
+            aliases: aliases
+                .iter()
+                .map(|a| a.as_bytes().to_vec().try_into().unwrap())
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+fn set_titles(
+    default_language: &str,
+    entries: &[(&str, &str, &[&str])],
+) -> sp_runtime::DispatchResult {
+    Releases::set_titles(
+        RuntimeOrigin::signed(ALICE),
+        code(EAN),
+        default_language.as_bytes().to_vec().try_into().unwrap(),
+        titles(entries),
+    )
+}
+
+#[test]
+fn language_tags_are_normalized_and_checked() {
+    assert_eq!(
+        normalize_language_tag(b"pt-BR").unwrap().into_inner(),
+        b"pt-br".to_vec()
+    );
+    assert_eq!(
+        normalize_language_tag(b"zh-Hant-TW").unwrap().into_inner(),
+        b"zh-hant-tw".to_vec()
+    );
+    assert!(normalize_language_tag(b"es-419").is_some());
+    assert!(normalize_language_tag(b"").is_none());
+    assert!(normalize_language_tag(b"e").is_none());
+    assert!(normalize_language_tag(b"42").is_none());
+    assert!(normalize_language_tag(b"en-").is_none());
+    assert!(normalize_language_tag(b"en_US").is_none());
+    assert!(normalize_language_tag(b"en-toolongsub").is_none());
+}
+
+#[test]
+fn set_titles_validates_titles() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(create(ALICE, &[1]));
+
+        assert_noop!(set_titles("en", &[]), Error::<Test>::NoTitles);
+        assert_noop!(
+            set_titles("en", &[("en_GB", "Blue", &[])]),
+            Error::<Test>::InvalidLanguageTag
+        );
+        assert_noop!(
+            set_titles("e", &[("en", "Blue", &[])]),
+            Error::<Test>::InvalidLanguageTag
+        );
+        assert_noop!(
+            set_titles("en", &[("en", "", &[])]),
+            Error::<Test>::EmptyTitle
+        );
+        assert_noop!(
+            set_titles("en", &[("en", "Blue", &[""])]),
+            Error::<Test>::EmptyTitle
+        );
+        assert_noop!(
+            set_titles("en", &[("en", "Blue", &[]), ("EN", "Bleu", &[])]),
+            Error::<Test>::DuplicateLanguage
+        );
+        assert_noop!(
+            set_titles("de", &[("en", "Blue", &[])]),
+            Error::<Test>::MissingDefaultTitle
+        );
+        assert_noop!(
+            Releases::set_titles(
+                RuntimeOrigin::signed(BOB),
+                code(EAN),
+                b"en".to_vec().try_into().unwrap(),
+                titles(&[("en", "Blue", &[])]),
+            ),
+            Error::<Test>::NotOwner
+        );
+
+        assert_ok!(set_titles(
+            "FR",
+            &[("fr", "Bleu", &[]), ("ja-JP", "Ao", &["青"])]
+        ));
+        assert_eq!(
+            Titles::<Test>::get(code(EAN)),
+            Some(ReleaseTitles {
+                default_language: b"fr".to_vec().try_into().unwrap(),
+                titles: titles(&[("fr", "Bleu", &[]), ("ja-jp", "Ao", &["青"])]),
+            })
+        );
+        System::assert_last_event(Event::TitlesUpdated { ean: code(EAN) }.into());
+
+        assert_ok!(Releases::publish(RuntimeOrigin::signed(ALICE), code(EAN)));
+        assert_noop!(
+            set_titles("fr", &[("fr", "Bleu", &[])]),
+            Error::<Test>::AlreadyPublished
+        );
+    });
+}
+
+#[test]
+fn titles_fall_back_to_the_default_language() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(create(ALICE, &[1]));
+        assert_eq!(Releases::title_of(&code(EAN), None), None);
+
+        assert_ok!(set_titles(
+            "fr",
+            &[("fr", "Bleu", &[]), ("en", "Blue", &[])]
+        ));
+        let [bleu, blue]: [_; 2] = titles(&[("fr", "Bleu", &[]), ("en", "Blue", &[])])
+            .into_inner()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(Releases::title_of(&code(EAN), Some(b"EN")), Some(blue));
+        assert_eq!(
+            Releases::title_of(&code(EAN), Some(b"de")),
+            Some(bleu.clone())
+        );
+        assert_eq!(
+            Releases::title_of(&code(EAN), Some(b"not a tag")),
+            Some(bleu.clone())
+        );
+        assert_eq!(Releases::title_of(&code(EAN), None), Some(bleu));
+    });
+}
+
+#[test]
+fn published_releases_are_indexed_by_language() {
+    new_test_ext().execute_with(|| {
+        const OTHER_EAN: &[u8] = b"0036000291452";
+        assert_ok!(create(ALICE, &[1]));
+        assert_ok!(set_titles(
+            "fr",
+            &[("fr", "Bleu", &[]), ("en", "Blue", &[])]
+        ));
+        assert_ok!(Releases::create_release(
+            RuntimeOrigin::signed(ALICE),
+            code(OTHER_EAN),
+            tracks(&[2]),
+            date(),
+            DISTRIBUTOR,
+        ));
+        assert_ok!(Releases::set_titles(
+            RuntimeOrigin::signed(ALICE),
+            code(OTHER_EAN),
+            b"en".to_vec().try_into().unwrap(),
+            titles(&[("en", "Red", &[])]),
+        ));
+
+        // Drafts are not indexed.
+        assert!(Releases::releases_in_language(b"en", None, 10).is_empty());
+
+        assert_ok!(Releases::publish(RuntimeOrigin::signed(ALICE), code(EAN)));
+        assert_ok!(Releases::publish(
+            RuntimeOrigin::signed(ALICE),
+            code(OTHER_EAN)
+        ));
+        assert_eq!(
+            Releases::releases_in_language(b"FR", None, 10),
+            vec![code(EAN)]
+        );
+        let english = Releases::releases_in_language(b"en", None, 10);
+        assert_eq!(english.len(), 2);
+        assert!(english.contains(&code(EAN)) && english.contains(&code(OTHER_EAN)));
+
+        // Paging.
+        let first = Releases::releases_in_language(b"en", None, 1);
+        assert_eq!(first, english[..1]);
+        assert_eq!(
+            Releases::releases_in_language(b"en", Some(first[0].clone()), 10),
+            english[1..]
+        );
+        assert!(Releases::releases_in_language(b"not a tag", None, 10).is_empty());
+
+        assert_ok!(Releases::remove(RuntimeOrigin::signed(ALICE), code(EAN)));
+        assert!(Titles::<Test>::get(code(EAN)).is_none());
+        let french: LanguageTag = b"fr".to_vec().try_into().unwrap();
+        assert!(!LanguageIndex::<Test>::contains_key(french, code(EAN)));
+        assert_eq!(
+            Releases::releases_in_language(b"en", None, 10),
+            vec![code(OTHER_EAN)]
+        );
+    });
+}
+
+#[test]
+fn published_releases_are_committed_to_the_catalog_root() {
+    new_test_ext().execute_with(|| {
+        let ean = code(EAN);
+        let committed = || CatalogRoot::entity_hash(CATALOG_NAMESPACE, &ean);
+
+        assert_ok!(create(ALICE, &[1, 2]));
+        assert_eq!(committed(), None);
+
+        assert_ok!(Releases::publish(RuntimeOrigin::signed(ALICE), ean.clone()));
+        let release = ReleasesStorage::<Test>::get(&ean).unwrap();
+        assert_eq!(
+            committed(),
+            Some(BlakeTwo256::hash_of(&(
+                &release,
+                &Titles::<Test>::get(&ean)
+            )))
+        );
+
+        assert_ok!(Releases::remove(RuntimeOrigin::signed(ALICE), ean.clone()));
+        assert_eq!(committed(), None);
+    });
+}
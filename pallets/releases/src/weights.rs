@@ -0,0 +1,91 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_releases.
+pub trait WeightInfo {
+    fn create_release(t: u32) -> Weight;
+    fn set_tracks(t: u32) -> Weight;
+    fn publish(t: u32, l: u32) -> Weight;
+    fn remove(l: u32) -> Weight;
+    fn set_titles(l: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Releases::Releases` (r:1 w:1)
+    /// Storage: `Recordings` registry (r:t w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `t` is `[1, 100]`.
+    fn create_release(t: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(Weight::from_parts(4_000_000, 2_600).saturating_mul(t.into()))
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().reads(t.into()))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Releases::Releases` (r:1 w:1)
+    /// Storage: `Recordings` registry (r:t w:0)
+    /// The range of component `t` is `[1, 100]`.
+    fn set_tracks(t: u32) -> Weight {
+        Weight::from_parts(20_000_000, 4_000)
+            .saturating_add(Weight::from_parts(4_000_000, 2_600).saturating_mul(t.into()))
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().reads(t.into()))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Releases::Releases` (r:1 w:1)
+    /// Storage: `Recordings` registry (r:t w:0)
+    /// Storage: `Releases::Titles` (r:1 w:0)
+    /// Storage: `Releases::LanguageIndex` (r:0 w:l)
+    /// Storage: `CatalogRoot` child trie (r:0 w:1)
+    /// The range of component `t` is `[1, 100]`.
+    /// The range of component `l` is `[0, 16]`.
+    fn publish(t: u32, l: u32) -> Weight {
+        Weight::from_parts(25_000_000, 9_000)
+            .saturating_add(Weight::from_parts(4_000_000, 2_600).saturating_mul(t.into()))
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(l.into()))
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().reads(t.into()))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(l.into()))
+    }
+    /// Storage: `Releases::Releases` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Releases::Titles` (r:1 w:1)
+    /// Storage: `Releases::LanguageIndex` (r:0 w:l)
+    /// Storage: `CatalogRoot` child trie (r:0 w:1)
+    /// The range of component `l` is `[0, 16]`.
+    fn remove(l: u32) -> Weight {
+        Weight::from_parts(40_000_000, 9_000)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(l.into()))
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+            .saturating_add(ParityDbWeight::get().writes(l.into()))
+    }
+    /// Storage: `Releases::Releases` (r:1 w:0)
+    /// Storage: `Releases::Titles` (r:0 w:1)
+    /// The range of component `l` is `[1, 16]`.
+    fn set_titles(l: u32) -> Weight {
+        Weight::from_parts(20_000_000, 4_000)
+            .saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(l.into()))
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,76 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API exporting the statement ledgers of `pallet-royalties` to accounting
+//! integrations, simulating past distributions under another split, and charting what
+//! accounts earn per era.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::Codec;
+use sp_runtime::{DispatchError, Perbill};
+
+pub use pallet_royalties::{EraEarnings, EraIndex, LedgerLine, SimulatedPayout, StatementNumber};
+
+sp_api::decl_runtime_apis! {
+    #[api_version(3)]
+    pub trait RoyaltiesApi<AccountId, AssetId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        AssetId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Number of the last statement issued to `payer`, 0 if none was.
+        fn last_statement(payer: AccountId) -> StatementNumber;
+
+        /// The ledger of `payer`'s `statement`: statement number, payer, asset, gross, fees and
+        /// net of every payment, in report order. `None` if the statement does not exist or
+        /// was pruned.
+        fn statement_ledger(
+            payer: AccountId,
+            statement: StatementNumber,
+        ) -> Option<Vec<LedgerLine<AccountId, AssetId, Balance>>>;
+
+        /// What the distributions queued between blocks `from` and `to` (inclusive) paid for
+        /// `asset`, next to what they would have paid had `shares` been its split, per
+        /// beneficiary. Computed from the statement ledgers, so pruned statements are left out.
+        ///
+        /// Fails if `shares` is not a valid split table.
+        #[api_version(2)]
+        fn simulate_split(
+            asset: AssetId,
+            shares: Vec<(AccountId, Perbill)>,
+            from: BlockNumber,
+            to: BlockNumber,
+        ) -> Result<Vec<SimulatedPayout<AccountId, Balance>>, DispatchError>;
+
+        /// What `who` earned in each era from `from` to `to` (inclusive), by source, in era
+        /// order. Eras they earned nothing in are left out. Era `n` spans `EarningsEra` blocks
+        /// from block `n * EarningsEra`.
+        #[api_version(3)]
+        fn earnings(
+            who: AccountId,
+            from: EraIndex,
+            to: EraIndex,
+        ) -> Vec<(EraIndex, EraEarnings<Balance>)>;
+    }
+}
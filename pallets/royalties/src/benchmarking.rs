@@ -0,0 +1,307 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_support::traits::fungible::Mutate;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+
+fn funded_payer<T: Config>() -> T::AccountId {
+    let payer: T::AccountId = account("payer", 0, SEED);
+    T::Currency::set_balance(&payer, BalanceOf::<T>::max_value() / 2u32.into());
+    payer
+}
+
+/// Large enough for every part to clear the existential deposit of a new account.
+fn bench_pot<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().max(1u32.into())
+        * T::MaxCollaborators::get().into()
+        * 1_000u32.into()
+}
+
+/// The `i`-th asset, owned by `owner`, with a `c`-collaborator table the owner is not part of.
+fn asset_and_shares<T: Config>(i: u32, owner: &T::AccountId, c: u32) -> (T::AssetId, SharesOf<T>) {
+    let asset = T::BenchmarkHelper::create_asset(i, owner);
+    let mut shares: Vec<_> = (0..c)
+        .map(|i| {
+            (
+                account("collaborator", i, SEED),
+                Perbill::from_rational(1, c),
+            )
+        })
+        .collect();
+    // `from_rational` rounds down, the first collaborator takes the remainder.
+    let total: u32 = shares.iter().map(|(_, share)| share.deconstruct()).sum();
+    shares[0].1 = Perbill::from_parts(shares[0].1.deconstruct() + Perbill::ACCURACY - total);
+    (asset, shares.try_into().expect("c <= MaxCollaborators"))
+}
+
+/// The `i`-th asset with an active `c`-collaborator split.
+fn asset_with_split<T: Config>(i: u32, c: u32) -> T::AssetId {
+    let owner: T::AccountId = account("owner", 0, SEED);
+    let (asset, shares) = asset_and_shares::<T>(i, &owner, c);
+    Pallet::<T>::propose_split(
+        RawOrigin::Signed(owner).into(),
+        asset.clone(),
+        shares.clone(),
+    )
+    .expect("owner proposes a valid split");
+    for (who, _) in shares {
+        Pallet::<T>::co_sign(RawOrigin::Signed(who).into(), asset.clone())
+            .expect("listed collaborator co-signs");
+    }
+    asset
+}
+
+/// A distribution of `bench_pot` over the given report, with the pot held from `payer` and
+/// recorded in their first statement.
+fn held_distribution<T: Config>(
+    payer: &T::AccountId,
+    report: Vec<(T::AssetId, u32)>,
+) -> DistributionOf<T> {
+    let pot = bench_pot::<T>();
+    T::Currency::hold(&HoldReason::DistributionPot.into(), payer, pot).expect("payer is funded");
+    Statements::<T>::insert(
+        payer,
+        1,
+        Statement {
+            distribution: 0,
+            pot,
+            queued_at: Zero::zero(),
+            entries: 0,
+            deposit: 0u32.into(),
+        },
+    );
+    Distribution {
+        payer: payer.clone(),
+        pot,
+        total_plays: report.iter().map(|(_, plays)| u64::from(*plays)).sum(),
+        report: report.try_into().expect("report <= MaxReportEntries"),
+        cursor: 0,
+        distributed: 0u32.into(),
+        statement: 1,
+    }
+}
+
+/// A full list of allowed payers, ending with `payer`.
+fn allowed_payers<T: Config>(payer: &T::AccountId) -> PayersOf<T> {
+    let others = T::MaxAllowedPayers::get().saturating_sub(1);
+    BoundedVec::truncate_from(
+        (0..others)
+            .map(|i| account("aggregator", i, SEED))
+            .chain(core::iter::once(payer.clone()))
+            .collect(),
+    )
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn propose_split(c: Linear<1, { T::MaxCollaborators::get() }>) {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let (asset, shares) = asset_and_shares::<T>(0, &owner, c);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), asset.clone(), shares);
+
+        assert!(Proposals::<T>::contains_key(&asset));
+    }
+
+    /// The last signature, which activates the split.
+    #[benchmark]
+    fn co_sign(c: Linear<1, { T::MaxCollaborators::get() }>) {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let (asset, shares) = asset_and_shares::<T>(0, &owner, c);
+        let last = shares[c as usize - 1].0.clone();
+        Pallet::<T>::propose_split(
+            RawOrigin::Signed(owner).into(),
+            asset.clone(),
+            shares.clone(),
+        )
+        .expect("owner proposes a valid split");
+        for (who, _) in shares.iter().take(c as usize - 1) {
+            Pallet::<T>::co_sign(RawOrigin::Signed(who.clone()).into(), asset.clone())
+                .expect("listed collaborator co-signs");
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(last), asset.clone());
+
+        assert!(Splits::<T>::contains_key(&asset));
+    }
+
+    #[benchmark]
+    fn withdraw_proposal() {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let (asset, shares) = asset_and_shares::<T>(0, &owner, T::MaxCollaborators::get());
+        Pallet::<T>::propose_split(
+            RawOrigin::Signed(owner.clone()).into(),
+            asset.clone(),
+            shares,
+        )
+        .expect("owner proposes a valid split");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), asset.clone());
+
+        assert!(!Proposals::<T>::contains_key(&asset));
+    }
+
+    #[benchmark]
+    fn lock_split() {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let asset = T::BenchmarkHelper::create_asset(0, &owner);
+        let max = T::MaxCollaborators::get();
+        // An owner-only split activates on proposal.
+        Pallet::<T>::propose_split(
+            RawOrigin::Signed(owner.clone()).into(),
+            asset.clone(),
+            BoundedVec::truncate_from(Vec::from([(owner.clone(), Perbill::one())])),
+        )
+        .expect("owner proposes a valid split");
+        // A pending replacement, dropped by the lock.
+        let (_, shares) = asset_and_shares::<T>(0, &owner, max);
+        Pallet::<T>::propose_split(
+            RawOrigin::Signed(owner.clone()).into(),
+            asset.clone(),
+            shares,
+        )
+        .expect("owner proposes a valid split");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), asset.clone());
+
+        assert!(Splits::<T>::get(&asset).is_some_and(|split| split.locked));
+    }
+
+    /// Every asset restricts its payers, with the caller last in a full list.
+    #[benchmark]
+    fn distribute(e: Linear<1, { T::MaxReportEntries::get() }>) {
+        let payer = funded_payer::<T>();
+        let report: UsageReportOf<T> = (0..e)
+            .map(|i| {
+                let asset = asset_with_split::<T>(i, 1);
+                AllowedPayers::<T>::insert(&asset, allowed_payers::<T>(&payer));
+                (asset, 1_000)
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("e <= MaxReportEntries");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(payer), bench_pot::<T>(), report);
+
+        assert!(Distributions::<T>::contains_key(0));
+    }
+
+    /// Every beneficiary is a new account. At `c = MaxCollaborators` this is the entry payment
+    /// `integrity_test` checks against `DistributionWeightCeiling`.
+    #[benchmark]
+    fn pay_entry(c: Linear<1, { T::MaxCollaborators::get() }>) {
+        let payer = funded_payer::<T>();
+        let asset = asset_with_split::<T>(0, c);
+        let mut distribution = held_distribution::<T>(&payer, Vec::from([(asset, 1_000)]));
+
+        #[block]
+        {
+            Pallet::<T>::pay_entry(0, &mut distribution);
+        }
+
+        assert_eq!(distribution.distributed, distribution.pot);
+    }
+
+    #[benchmark]
+    fn complete_distribution() {
+        let payer = funded_payer::<T>();
+        let asset = asset_with_split::<T>(0, 1);
+        let mut distribution = held_distribution::<T>(&payer, Vec::from([(asset, 1_000)]));
+        distribution.cursor = 1;
+        Distributions::<T>::insert(0, &distribution);
+
+        #[block]
+        {
+            Pallet::<T>::complete_distribution(0, distribution);
+        }
+
+        assert!(!Distributions::<T>::contains_key(0));
+    }
+
+    /// A completed statement of `e` entries, each paid to `MaxCollaborators` beneficiaries.
+    #[benchmark]
+    fn prune_statement(e: Linear<1, { T::MaxReportEntries::get() }>) {
+        let payer = funded_payer::<T>();
+        let deposit = T::LedgerDeposit::get().saturating_mul(e.into());
+        T::Currency::hold(&HoldReason::StatementLedger.into(), &payer, deposit)
+            .expect("payer is funded");
+        Statements::<T>::insert(
+            &payer,
+            1,
+            Statement {
+                distribution: 0,
+                pot: bench_pot::<T>(),
+                queued_at: Zero::zero(),
+                entries: e,
+                deposit,
+            },
+        );
+        let asset = T::BenchmarkHelper::create_asset(0, &payer);
+        let payments: Vec<_> = (0..T::MaxCollaborators::get())
+            .map(|i| Payment {
+                beneficiary: account("collaborator", i, SEED),
+                gross: bench_pot::<T>(),
+                fees: 0u32.into(),
+                net: bench_pot::<T>(),
+            })
+            .collect();
+        for index in 0..e {
+            Ledger::<T>::insert(
+                (&payer, 1, index),
+                LedgerEntry {
+                    asset: asset.clone(),
+                    plays: 1_000,
+                    payments: BoundedVec::truncate_from(payments.clone()),
+                },
+            );
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(payer.clone()), 1);
+
+        assert!(!Statements::<T>::contains_key(&payer, 1));
+    }
+
+    #[benchmark]
+    fn set_allowed_payers(p: Linear<0, { T::MaxAllowedPayers::get() }>) {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let asset = T::BenchmarkHelper::create_asset(0, &owner);
+        let payers: PayersOf<T> =
+            BoundedVec::truncate_from((0..p).map(|i| account("aggregator", i, SEED)).collect());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), asset.clone(), Some(payers));
+
+        assert!(AllowedPayers::<T>::contains_key(&asset));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,421 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Pot distribution along split tables, processed in `on_idle`.
+
+use super::*;
+use frame_support::{
+    storage::with_storage_layer,
+    traits::tokens::{Fortitude, Restriction},
+};
+use sp_runtime::{
+    Rounding,
+    helpers_128bit::multiply_by_rational_with_rounding,
+    traits::{UniqueSaturatedInto, Zero},
+};
+
+pub type DistributionId = u32;
+
+pub type UsageReportOf<T> =
+    BoundedVec<(<T as Config>::AssetId, u32), <T as Config>::MaxReportEntries>;
+
+pub type DistributionOf<T> =
+    Distribution<<T as frame_system::Config>::AccountId, BalanceOf<T>, UsageReportOf<T>>;
+
+/// Number of a payer's statement. Each payer's statements are numbered from 1, whatever the
+/// pots are paid in.
+pub type StatementNumber = u32;
+
+pub type StatementOf<T> = Statement<BalanceOf<T>, BlockNumberFor<T>>;
+
+pub type PaymentOf<T> = Payment<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+pub type LedgerEntryOf<T> =
+    LedgerEntry<<T as Config>::AssetId, BoundedVec<PaymentOf<T>, <T as Config>::MaxCollaborators>>;
+
+pub type LedgerLineOf<T> =
+    LedgerLine<<T as frame_system::Config>::AccountId, <T as Config>::AssetId, BalanceOf<T>>;
+
+pub type SimulatedPayoutOf<T> =
+    SimulatedPayout<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+/// A funded payout pot and the usage report it is distributed along.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Distribution<AccountId, Balance, Report> {
+    pub payer: AccountId,
+    pub pot: Balance,
+    /// Sum of the report's play counts.
+    pub total_plays: u64,
+    /// Asset and play count pairs.
+    pub report: Report,
+    /// Index of the next report entry to pay.
+    pub cursor: u32,
+    /// Paid out of the pot so far.
+    pub distributed: Balance,
+    /// Payer's statement the distribution is recorded in.
+    pub statement: StatementNumber,
+}
+
+/// The accounting record of one distribution, kept until its payer prunes it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Statement<Balance, BlockNumber> {
+    pub distribution: DistributionId,
+    pub pot: Balance,
+    /// Block the distribution was queued in.
+    pub queued_at: BlockNumber,
+    /// Ledger entries recorded so far, one per paid report entry.
+    pub entries: u32,
+    /// Amount held from the payer for the ledger.
+    pub deposit: Balance,
+}
+
+/// A payment to one beneficiary. `gross` is the beneficiary's part of the entry, `fees` the
+/// distribution fee taken out of it and `net` what they received.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Payment<AccountId, Balance> {
+    pub beneficiary: AccountId,
+    pub gross: Balance,
+    pub fees: Balance,
+    pub net: Balance,
+}
+
+/// The payments made for one report entry.
+///
+/// If the entry could not be paid, its payments are recorded with zero `fees` and `net`: their
+/// gross went back to the payer.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LedgerEntry<AssetId, Payments> {
+    pub asset: AssetId,
+    pub plays: u32,
+    pub payments: Payments,
+}
+
+/// A line of an exported statement ledger: one payment, with the statement it belongs to.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct LedgerLine<AccountId, AssetId, Balance> {
+    pub statement: StatementNumber,
+    pub payer: AccountId,
+    pub asset: AssetId,
+    pub beneficiary: AccountId,
+    pub gross: Balance,
+    pub fees: Balance,
+    pub net: Balance,
+}
+
+/// What past distributions paid one beneficiary, next to what they would have paid under a
+/// simulated split.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SimulatedPayout<AccountId, Balance> {
+    pub beneficiary: AccountId,
+    /// Received under the splits in force at the time, net of fees.
+    pub paid: Balance,
+    /// Part of the distributed amounts under the simulated split.
+    pub gross: Balance,
+    /// Distribution fees, at the current `Config::DistributionFee`.
+    pub fees: Balance,
+    pub net: Balance,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Weight reserved for paying one report entry, whatever the size of its split table.
+    fn entry_weight() -> Weight {
+        T::WeightInfo::pay_entry(T::MaxCollaborators::get())
+    }
+
+    /// Weight of the largest indivisible step of `on_idle`: reading the queue, paying one entry
+    /// to `MaxCollaborators` beneficiaries, completing its distribution and writing the queue
+    /// back. A block that cannot fit it never makes progress on the queue.
+    pub fn distribution_step_weight() -> Weight {
+        T::DbWeight::get()
+            .reads_writes(2, 2)
+            .saturating_add(Self::entry_weight())
+            .saturating_add(T::WeightInfo::complete_distribution())
+    }
+
+    /// Work through the distribution queue within `remaining_weight`, returning the weight
+    /// used.
+    pub(crate) fn process_distributions(remaining_weight: Weight) -> Weight {
+        let entry_weight = Self::entry_weight();
+        let complete_weight = T::WeightInfo::complete_distribution();
+        let mut used = T::DbWeight::get().reads(2);
+        if remaining_weight.any_lt(used) {
+            return Weight::zero();
+        }
+
+        let mut head = DistributionHead::<T>::get();
+        let next = NextDistributionId::<T>::get();
+        while head < next {
+            let Some(mut distribution) = Distributions::<T>::get(head) else {
+                // Cannot happen, see `DistributionHead`; skip rather than stall the queue.
+                head.saturating_inc();
+                continue;
+            };
+
+            while (distribution.cursor as usize) < distribution.report.len() {
+                if remaining_weight.any_lt(used.saturating_add(entry_weight)) {
+                    Distributions::<T>::insert(head, distribution);
+                    DistributionHead::<T>::put(head);
+                    return used.saturating_add(T::DbWeight::get().writes(2));
+                }
+                Self::pay_entry(head, &mut distribution);
+                used.saturating_accrue(entry_weight);
+            }
+
+            if remaining_weight.any_lt(used.saturating_add(complete_weight)) {
+                Distributions::<T>::insert(head, distribution);
+                DistributionHead::<T>::put(head);
+                return used.saturating_add(T::DbWeight::get().writes(2));
+            }
+            Self::complete_distribution(head, distribution);
+            used.saturating_accrue(complete_weight);
+            head.saturating_inc();
+        }
+
+        DistributionHead::<T>::put(head);
+        used.saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// The share of `distribution`'s pot owed to `plays`, rounded down.
+    fn pro_rata(distribution: &DistributionOf<T>, plays: u32) -> BalanceOf<T> {
+        let pot: u128 = distribution.pot.unique_saturated_into();
+        multiply_by_rational_with_rounding(
+            pot,
+            plays.into(),
+            distribution.total_plays.into(),
+            Rounding::Down,
+        )
+        .map(|amount| amount.unique_saturated_into())
+        .unwrap_or_else(Zero::zero)
+    }
+
+    /// Pay the report entry at `distribution.cursor` to the collaborators of its asset, less
+    /// the distribution fee, record it in the payer's statement and advance the cursor.
+    ///
+    /// The entry is paid all at once: if any payment fails, none of the entry's payments
+    /// happen and its share stays in the pot, to be returned to the payer.
+    pub(crate) fn pay_entry(id: DistributionId, distribution: &mut DistributionOf<T>) {
+        let index = distribution.cursor;
+        let Some((asset, plays)) = distribution.report.get(index as usize).cloned() else {
+            return;
+        };
+        distribution.cursor.saturating_inc();
+
+        let amount = Self::pro_rata(distribution, plays);
+        let mut payments: Vec<PaymentOf<T>> = Self::apportion(&asset, amount)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, part)| !part.is_zero())
+            .map(|(beneficiary, gross)| Self::payment(beneficiary, gross))
+            .collect();
+
+        let payer = distribution.payer.clone();
+        let fee_destination = T::FeeDestination::get();
+        let paid = with_storage_layer(|| -> Result<BalanceOf<T>, DispatchError> {
+            let mut paid = BalanceOf::<T>::zero();
+            for payment in &payments {
+                for (to, value) in [
+                    (&payment.beneficiary, payment.net),
+                    (&fee_destination, payment.fees),
+                ] {
+                    if value.is_zero() {
+                        continue;
+                    }
+                    T::Currency::transfer_on_hold(
+                        &HoldReason::DistributionPot.into(),
+                        &payer,
+                        to,
+                        value,
+                        Precision::Exact,
+                        Restriction::Free,
+                        Fortitude::Polite,
+                    )?;
+                }
+                paid.saturating_accrue(payment.gross);
+                Self::deposit_event(Event::RoyaltyPaid {
+                    id,
+                    asset: asset.clone(),
+                    beneficiary: payment.beneficiary.clone(),
+                    amount: payment.net,
+                    fees: payment.fees,
+                });
+            }
+            Ok(paid)
+        });
+        match paid {
+            Ok(paid) => {
+                distribution.distributed.saturating_accrue(paid);
+                for payment in &payments {
+                    Self::note_earning(
+                        &payment.beneficiary,
+                        EarningSource::Distributions,
+                        payment.net,
+                    );
+                }
+            }
+            Err(_) => payments.iter_mut().for_each(|payment| {
+                payment.fees = Zero::zero();
+                payment.net = Zero::zero();
+            }),
+        }
+
+        Ledger::<T>::insert(
+            (&payer, distribution.statement, index),
+            LedgerEntry {
+                asset,
+                plays,
+                payments: BoundedVec::truncate_from(payments),
+            },
+        );
+        Statements::<T>::mutate(&payer, distribution.statement, |statement| {
+            if let Some(statement) = statement {
+                statement.entries.saturating_inc();
+            }
+        });
+    }
+
+    /// `gross` paid to `beneficiary`, less the distribution fee.
+    fn payment(beneficiary: T::AccountId, gross: BalanceOf<T>) -> PaymentOf<T> {
+        let fees = T::DistributionFee::get().mul_floor(gross);
+        Payment {
+            beneficiary,
+            gross,
+            fees,
+            net: gross.saturating_sub(fees),
+        }
+    }
+
+    /// Return what is left of `distribution`'s pot to the payer and drop it.
+    pub(crate) fn complete_distribution(id: DistributionId, distribution: DistributionOf<T>) {
+        let returned = distribution.pot.saturating_sub(distribution.distributed);
+        let _ = T::Currency::release(
+            &HoldReason::DistributionPot.into(),
+            &distribution.payer,
+            returned,
+            Precision::BestEffort,
+        );
+        Distributions::<T>::remove(id);
+        TotalDistributed::<T>::mutate(|total| total.saturating_accrue(distribution.distributed));
+
+        Self::deposit_event(Event::DistributionCompleted {
+            id,
+            distributed: distribution.distributed,
+            returned,
+        });
+    }
+
+    /// The ledger of `payer`'s `statement`, one line per payment in report order. `None` if
+    /// the statement does not exist or was pruned.
+    pub fn statement_ledger(
+        payer: &T::AccountId,
+        statement: StatementNumber,
+    ) -> Option<Vec<LedgerLineOf<T>>> {
+        let entries = Statements::<T>::get(payer, statement)?.entries;
+        let lines = (0..entries)
+            .filter_map(|index| Ledger::<T>::get((payer, statement, index)))
+            .flat_map(|entry| {
+                entry.payments.into_iter().map(move |payment| LedgerLine {
+                    statement,
+                    payer: payer.clone(),
+                    asset: entry.asset.clone(),
+                    beneficiary: payment.beneficiary,
+                    gross: payment.gross,
+                    fees: payment.fees,
+                    net: payment.net,
+                })
+            })
+            .collect();
+        Some(lines)
+    }
+
+    /// Recompute what the distributions queued between blocks `from` and `to` (inclusive) paid
+    /// for `asset`, as if `shares` had been its split, from their statement ledgers.
+    ///
+    /// Beneficiaries come in the order of `shares`, followed by those the simulated split
+    /// leaves out. Report entries that could not be paid are left out, as are statements their
+    /// payer pruned.
+    pub fn simulate_split(
+        asset: &T::AssetId,
+        shares: Vec<(T::AccountId, Perbill)>,
+        from: BlockNumberFor<T>,
+        to: BlockNumberFor<T>,
+    ) -> Result<Vec<SimulatedPayoutOf<T>>, DispatchError> {
+        let shares =
+            SharesOf::<T>::try_from(shares).map_err(|_| Error::<T>::TooManyCollaborators)?;
+        Self::ensure_valid_shares(&shares)?;
+
+        let mut payouts = Vec::new();
+        for (beneficiary, _) in &shares {
+            payout_of::<T>(&mut payouts, beneficiary);
+        }
+
+        for (payer, number, statement) in Statements::<T>::iter() {
+            if statement.queued_at < from || statement.queued_at > to {
+                continue;
+            }
+            for index in 0..statement.entries {
+                let Some(entry) = Ledger::<T>::get((&payer, number, index)) else {
+                    continue;
+                };
+                let unpaid = entry
+                    .payments
+                    .iter()
+                    .all(|payment| payment.fees.is_zero() && payment.net.is_zero());
+                if &entry.asset != asset || unpaid {
+                    continue;
+                }
+
+                let mut amount = BalanceOf::<T>::zero();
+                for payment in entry.payments {
+                    amount.saturating_accrue(payment.gross);
+                    payout_of::<T>(&mut payouts, &payment.beneficiary)
+                        .paid
+                        .saturating_accrue(payment.net);
+                }
+                for (beneficiary, gross) in Self::apportion_along(&shares, amount) {
+                    let payment = Self::payment(beneficiary, gross);
+                    let payout = payout_of::<T>(&mut payouts, &payment.beneficiary);
+                    payout.gross.saturating_accrue(payment.gross);
+                    payout.fees.saturating_accrue(payment.fees);
+                    payout.net.saturating_accrue(payment.net);
+                }
+            }
+        }
+        Ok(payouts)
+    }
+}
+
+/// The payout of `beneficiary` in `payouts`, added with nothing paid if missing.
+fn payout_of<'a, T: Config>(
+    payouts: &'a mut Vec<SimulatedPayoutOf<T>>,
+    beneficiary: &T::AccountId,
+) -> &'a mut SimulatedPayoutOf<T> {
+    let pos = match payouts.iter().position(|p| &p.beneficiary == beneficiary) {
+        Some(pos) => pos,
+        None => {
+            payouts.push(SimulatedPayout {
+                beneficiary: beneficiary.clone(),
+                paid: Zero::zero(),
+                gross: Zero::zero(),
+                fees: Zero::zero(),
+                net: Zero::zero(),
+            });
+            payouts.len() - 1
+        }
+    };
+    &mut payouts[pos]
+}
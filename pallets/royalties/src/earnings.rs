@@ -0,0 +1,128 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! What each account earns per era, by source, for revenue dashboards.
+
+use super::*;
+use sp_runtime::traits::{UniqueSaturatedInto, Zero};
+
+/// Number of an era of the earnings ledger: era `n` spans blocks `n * EarningsEra` to
+/// `(n + 1) * EarningsEra - 1`.
+pub type EraIndex = u32;
+
+pub type EraEarningsOf<T> = EraEarnings<BalanceOf<T>>;
+
+/// Where earnings come from.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum EarningSource {
+    /// Royalty distributions, net of the distribution fee.
+    Distributions,
+    /// License prices paid out of escrow to licensors.
+    Licenses,
+    /// Sale proceeds, paid to the seller or along the asset's split.
+    Sales,
+    /// Subscription charges.
+    Subscriptions,
+}
+
+/// What an account earned in one era, by source.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct EraEarnings<Balance> {
+    pub distributions: Balance,
+    pub licenses: Balance,
+    pub sales: Balance,
+    pub subscriptions: Balance,
+}
+
+impl<Balance: Saturating + Copy> EraEarnings<Balance> {
+    fn accrue(&mut self, source: EarningSource, amount: Balance) {
+        let earned = match source {
+            EarningSource::Distributions => &mut self.distributions,
+            EarningSource::Licenses => &mut self.licenses,
+            EarningSource::Sales => &mut self.sales,
+            EarningSource::Subscriptions => &mut self.subscriptions,
+        };
+        *earned = earned.saturating_add(amount);
+    }
+}
+
+/// Told of the payouts of another pallet, e.g. license fees or sale proceeds.
+pub trait OnPayout<AccountId, Balance> {
+    /// `payee` was paid `amount`.
+    fn on_payout(payee: &AccountId, amount: Balance);
+}
+
+impl<AccountId, Balance> OnPayout<AccountId, Balance> for () {
+    fn on_payout(_: &AccountId, _: Balance) {}
+}
+
+/// Records the payouts of another pallet as earnings from `Source`.
+pub struct RecordEarning<T, Source>(PhantomData<(T, Source)>);
+
+impl<T: Config, Source: Get<EarningSource>> OnPayout<T::AccountId, BalanceOf<T>>
+    for RecordEarning<T, Source>
+{
+    fn on_payout(payee: &T::AccountId, amount: BalanceOf<T>) {
+        Pallet::<T>::note_earning(payee, Source::get(), amount);
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Era of the earnings ledger block `n` falls in.
+    pub fn earnings_era(n: BlockNumberFor<T>) -> EraIndex {
+        (n / T::EarningsEra::get()).unique_saturated_into()
+    }
+
+    /// Add `amount` to what `who` earned from `source` in the current era.
+    pub fn note_earning(who: &T::AccountId, source: EarningSource, amount: BalanceOf<T>) {
+        if amount.is_zero() {
+            return;
+        }
+        let era = Self::earnings_era(frame_system::Pallet::<T>::block_number());
+        Earnings::<T>::mutate(who, era, |earnings| {
+            earnings
+                .get_or_insert_with(Default::default)
+                .accrue(source, amount)
+        });
+    }
+
+    /// What `who` earned in each era from `from` to `to` (inclusive), by source, in era order.
+    /// Eras they earned nothing in are left out.
+    pub fn earnings(
+        who: &T::AccountId,
+        from: EraIndex,
+        to: EraIndex,
+    ) -> Vec<(EraIndex, EraEarningsOf<T>)> {
+        let mut earnings: Vec<_> = Earnings::<T>::iter_prefix(who)
+            .filter(|(era, _)| (from..=to).contains(era))
+            .collect();
+        earnings.sort_unstable_by_key(|(era, _)| *era);
+        earnings
+    }
+}
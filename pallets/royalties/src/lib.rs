@@ -0,0 +1,884 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Royalties
+//!
+//! Royalty split tables for works and recordings, which payment pallets distribute against.
+//!
+//! ## Features
+//! - The owner of an asset (`Config::Assets`) proposes a split table: a bounded list of
+//!   collaborators and their `Perbill` shares, summing to exactly 100%.
+//! - A proposal becomes the asset's active split once every collaborator listed in it, and
+//!   every collaborator of the split it replaces, has co-signed. Until then, the previous split
+//!   (if any) stays in force, so an owner can never change a split unilaterally.
+//! - The owner can lock the active split, after which it can no longer be replaced.
+//! - [`Pallet::apportion`] divides an amount according to an asset's active split; payment
+//!   pallets must distribute through it.
+//! - [`Pallet::assign_share`] lets other pallets move part of a collaborator's share to another
+//!   account (e.g. crowdfunding backers) without co-signing: only that collaborator's share is
+//!   reduced, and they must have agreed to it in the calling pallet.
+//!
+//! ## Distribution
+//! - A payer funds a payout pot together with a usage report (asset → play count). The pot is
+//!   held from the payer and the distribution is queued.
+//! - Queued distributions are processed in `on_idle`, across as many blocks as needed: each
+//!   report entry receives its pro-rata share of the pot, which is then paid to the asset's
+//!   collaborators along its active split.
+//! - Each entry is paid atomically. Payments that cannot be made (e.g. below the existential
+//!   deposit of a new account) and rounding remainders are returned to the payer once the
+//!   distribution completes.
+//! - [`Pallet::distribute_usage`] takes the report from the finalized usage of a reporting
+//!   period instead (`Config::Usage`), leaving out assets without a split.
+//! - `Config::DistributionFee` is taken out of each payment and sent to
+//!   `Config::FeeDestination`.
+//! - [`TotalDistributed`] adds up what completed distributions paid, fees included.
+//!
+//! ## Statements
+//! - Each distribution is recorded in a statement of its payer. Statements are numbered per
+//!   payer, from 1 and without gaps.
+//! - A statement's ledger lists, for every report entry, the gross, fees and net paid to each
+//!   beneficiary. [`Pallet::statement_ledger`] exports it for accounting integrations, and the
+//!   `pallet-royalties-runtime-api` crate exposes it to clients.
+//! - [`Pallet::simulate_split`] recomputes what the distributions of a block range paid for an
+//!   asset under another split, from the statement ledgers, so that collaborators negotiating a
+//!   new split can compare it with what they were paid.
+//! - A deposit per report entry is held from the payer for the ledger, until they prune the
+//!   statement once the distribution completed.
+//!
+//! ## Earnings
+//! - What each account earns is added up per era of `Config::EarningsEra` blocks, by source:
+//!   royalty distributions, and the license, sale and subscription payments other pallets
+//!   report through [`RecordEarning`]. [`Pallet::earnings`] returns it for revenue dashboards.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod distribution;
+pub use distribution::*;
+
+mod earnings;
+pub use earnings::*;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use alloc::vec::Vec;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::Precision,
+    },
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{PerThing, Perbill, Saturating, traits::AtLeast32BitUnsigned};
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type SharesOf<T> =
+    BoundedVec<(<T as frame_system::Config>::AccountId, Perbill), <T as Config>::MaxCollaborators>;
+
+pub type SplitProposalOf<T> = SplitProposal<
+    <T as frame_system::Config>::AccountId,
+    SharesOf<T>,
+    BoundedVec<<T as frame_system::Config>::AccountId, MaxSignatories<T>>,
+>;
+
+/// Bound on a proposal's signatories: its own collaborators plus those of the split it
+/// replaces.
+pub struct MaxSignatories<T>(PhantomData<T>);
+
+impl<T: Config> Get<u32> for MaxSignatories<T> {
+    fn get() -> u32 {
+        T::MaxCollaborators::get().saturating_mul(2)
+    }
+}
+
+pub type SplitOf<T> = Split<SharesOf<T>, BlockNumberFor<T>>;
+
+pub type PayersOf<T> =
+    BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxAllowedPayers>;
+
+/// A split table awaiting its collaborators' signatures.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SplitProposal<AccountId, Shares, Approvals> {
+    /// Owner of the asset at proposal time.
+    pub proposer: AccountId,
+    pub shares: Shares,
+    /// Signatories who co-signed so far.
+    pub approvals: Approvals,
+}
+
+/// The split table in force for an asset.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Split<Shares, BlockNumber> {
+    pub shares: Shares,
+    /// Block at which the last collaborator co-signed.
+    pub activated_at: BlockNumber,
+    /// A locked split can no longer be replaced.
+    pub locked: bool,
+}
+
+/// Resolves the current owner of a work or recording.
+pub trait AssetOwnership<AssetId, AccountId> {
+    fn owner_of(asset: &AssetId) -> Option<AccountId>;
+}
+
+/// Finalized usage figures of a reporting period, e.g. from a usage oracle.
+pub trait UsageReports<AssetId> {
+    /// Play counts finalized for `period`, `None` until it is finalized.
+    fn finalized_usage(period: u32) -> Option<Vec<(AssetId, u32)>>;
+}
+
+/// No usage source: no period is ever finalized.
+impl<AssetId> UsageReports<AssetId> for () {
+    fn finalized_usage(_: u32) -> Option<Vec<(AssetId, u32)>> {
+        None
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, AccountId> {
+    /// Create the `i`-th asset, owned by `owner`, and return its id.
+    fn create_asset(i: u32, owner: &AccountId) -> AssetId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Identifier of a work or recording splits are attached to.
+        type AssetId: Parameter + Member + MaxEncodedLen;
+
+        /// Resolves who may propose and lock an asset's split.
+        type Assets: AssetOwnership<Self::AssetId, Self::AccountId>;
+
+        /// Finalized usage figures `distribute_usage` distributes along.
+        type Usage: UsageReports<Self::AssetId>;
+
+        /// Max number of collaborators in a split table.
+        #[pallet::constant]
+        type MaxCollaborators: Get<u32>;
+
+        /// Max number of entries in a usage report.
+        #[pallet::constant]
+        type MaxReportEntries: Get<u32>;
+
+        /// Fee taken out of each royalty payment.
+        #[pallet::constant]
+        type DistributionFee: Get<Perbill>;
+
+        /// Account distribution fees are paid to.
+        type FeeDestination: Get<Self::AccountId>;
+
+        /// Amount held from the payer for each report entry recorded in a statement's ledger.
+        #[pallet::constant]
+        type LedgerDeposit: Get<BalanceOf<Self>>;
+
+        /// Max number of accounts an asset owner can allow to distribute the asset's royalties.
+        #[pallet::constant]
+        type MaxAllowedPayers: Get<u32>;
+
+        /// Share of the block weight the worst-case distribution step may take: paying one
+        /// report entry to `MaxCollaborators` beneficiaries and completing its distribution.
+        ///
+        /// Checked by `integrity_test`, so that `MaxCollaborators` cannot be raised past what
+        /// `on_idle` is able to execute.
+        #[pallet::constant]
+        type DistributionWeightCeiling: Get<Perbill>;
+
+        /// Length in blocks of the eras the earnings ledger groups earnings by.
+        #[pallet::constant]
+        type EarningsEra: Get<BlockNumberFor<Self>>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Self::AccountId>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// A payout pot awaiting distribution.
+        DistributionPot,
+        /// The ledger of a statement, until its payer prunes it.
+        StatementLedger,
+    }
+
+    /// Active split tables, keyed by asset.
+    #[pallet::storage]
+    pub type Splits<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, SplitOf<T>, OptionQuery>;
+
+    /// Pending split proposals, at most one per asset.
+    #[pallet::storage]
+    pub type Proposals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, SplitProposalOf<T>, OptionQuery>;
+
+    /// Accounts allowed to distribute the royalties of each asset. Assets without an entry
+    /// accept any payer.
+    #[pallet::storage]
+    pub type AllowedPayers<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, PayersOf<T>, OptionQuery>;
+
+    /// Queued and in-progress distributions.
+    #[pallet::storage]
+    pub type Distributions<T: Config> =
+        StorageMap<_, Twox64Concat, DistributionId, DistributionOf<T>, OptionQuery>;
+
+    /// Id the next queued distribution gets.
+    #[pallet::storage]
+    pub type NextDistributionId<T: Config> = StorageValue<_, DistributionId, ValueQuery>;
+
+    /// The distribution `on_idle` is working on. Distributions are processed in id order, so
+    /// every id in `[DistributionHead, NextDistributionId)` is still pending.
+    #[pallet::storage]
+    pub type DistributionHead<T: Config> = StorageValue<_, DistributionId, ValueQuery>;
+
+    /// Number of the last statement issued to each payer.
+    #[pallet::storage]
+    pub type StatementCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, StatementNumber, ValueQuery>;
+
+    /// Statements, keyed by payer and statement number.
+    #[pallet::storage]
+    pub type Statements<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        StatementNumber,
+        StatementOf<T>,
+        OptionQuery,
+    >;
+
+    /// Statement ledgers, keyed by payer, statement number and report entry index.
+    #[pallet::storage]
+    pub type Ledger<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::AccountId>,
+            NMapKey<Twox64Concat, StatementNumber>,
+            NMapKey<Twox64Concat, u32>,
+        ),
+        LedgerEntryOf<T>,
+        OptionQuery,
+    >;
+
+    /// Everything paid out of completed distribution pots, fees included.
+    #[pallet::storage]
+    pub type TotalDistributed<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// What each account earned per era of the earnings ledger, by source.
+    #[pallet::storage]
+    pub type Earnings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        EraIndex,
+        EraEarningsOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        SplitProposed {
+            asset: T::AssetId,
+            proposer: T::AccountId,
+        },
+        SplitCoSigned {
+            asset: T::AssetId,
+            who: T::AccountId,
+        },
+        SplitActivated {
+            asset: T::AssetId,
+            shares: SharesOf<T>,
+        },
+        ProposalWithdrawn {
+            asset: T::AssetId,
+        },
+        SplitLocked {
+            asset: T::AssetId,
+        },
+        DistributionQueued {
+            id: DistributionId,
+            payer: T::AccountId,
+            pot: BalanceOf<T>,
+            statement: StatementNumber,
+        },
+        /// `amount` was paid to `beneficiary`, net of `fees`.
+        RoyaltyPaid {
+            id: DistributionId,
+            asset: T::AssetId,
+            beneficiary: T::AccountId,
+            amount: BalanceOf<T>,
+            fees: BalanceOf<T>,
+        },
+        /// `distributed` was paid out of the pot, `returned` went back to the payer.
+        DistributionCompleted {
+            id: DistributionId,
+            distributed: BalanceOf<T>,
+            returned: BalanceOf<T>,
+        },
+        StatementPruned {
+            payer: T::AccountId,
+            statement: StatementNumber,
+        },
+        ShareAssigned {
+            asset: T::AssetId,
+            from: T::AccountId,
+            to: T::AccountId,
+            share: Perbill,
+        },
+        /// `None` opens the asset to any payer.
+        AllowedPayersSet {
+            asset: T::AssetId,
+            payers: Option<PayersOf<T>>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NotAssetOwner,
+        NoCollaborators,
+        DuplicateCollaborator,
+        ZeroShare,
+        /// The shares do not add up to exactly 100%.
+        SharesNotWhole,
+        SplitIsLocked,
+        ProposalNotFound,
+        NotCollaborator,
+        AlreadyCoSigned,
+        SplitNotFound,
+        ZeroPot,
+        EmptyReport,
+        ZeroPlays,
+        DuplicateReportEntry,
+        /// A reported asset has no active split to distribute along.
+        AssetWithoutSplit,
+        /// The usage of the period is not finalized.
+        UsageNotFinalized,
+        /// The finalized usage has more assets with a split than a report can hold.
+        TooManyReportEntries,
+        StatementNotFound,
+        /// The statement's distribution has not completed yet.
+        DistributionPending,
+        /// The collaborator's share is smaller than the share to assign.
+        InsufficientShare,
+        TooManyCollaborators,
+        /// A reported asset does not allow the caller to distribute its royalties.
+        PayerNotAllowed,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::process_distributions(remaining_weight)
+        }
+
+        fn integrity_test() {
+            assert!(
+                !T::EarningsEra::get().is_zero(),
+                "`EarningsEra` must not be zero"
+            );
+            let ceiling = T::BlockWeights::get().max_block * T::DistributionWeightCeiling::get();
+            assert!(
+                Self::distribution_step_weight().all_lte(ceiling),
+                "the worst-case royalty distribution step exceeds `DistributionWeightCeiling`; \
+                 lower `MaxCollaborators`",
+            );
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Propose `shares` as the split table of `asset`, replacing any pending proposal.
+        /// Asset owner only.
+        ///
+        /// If the owner is a signatory, their signature is counted right away.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::propose_split(shares.len() as u32))]
+        pub fn propose_split(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            shares: SharesOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_owner(&asset, &who)?;
+            ensure!(
+                !Splits::<T>::get(&asset).is_some_and(|split| split.locked),
+                Error::<T>::SplitIsLocked
+            );
+            Self::ensure_valid_shares(&shares)?;
+
+            let mut approvals = BoundedVec::new();
+            if Self::is_signatory(&asset, &shares, &who) {
+                approvals
+                    .try_push(who.clone())
+                    .expect("shares are non-empty, so the bound is at least 1; qed");
+            }
+            let proposal = SplitProposal {
+                proposer: who.clone(),
+                shares,
+                approvals,
+            };
+
+            Self::deposit_event(Event::SplitProposed {
+                asset: asset.clone(),
+                proposer: who,
+            });
+            Self::store_or_activate(asset, proposal);
+            Ok(())
+        }
+
+        /// Co-sign the pending split proposal of `asset`. Collaborators listed in the proposal
+        /// or in the split it replaces only.
+        ///
+        /// The last signature activates the split.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::co_sign(T::MaxCollaborators::get()))]
+        pub fn co_sign(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut proposal = Proposals::<T>::get(&asset).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(
+                Self::is_signatory(&asset, &proposal.shares, &who),
+                Error::<T>::NotCollaborator
+            );
+            ensure!(
+                !proposal.approvals.contains(&who),
+                Error::<T>::AlreadyCoSigned
+            );
+            proposal
+                .approvals
+                .try_push(who.clone())
+                .expect("approvals are distinct signatories, at most `MaxSignatories`; qed");
+
+            Self::deposit_event(Event::SplitCoSigned {
+                asset: asset.clone(),
+                who,
+            });
+            Self::store_or_activate(asset, proposal);
+            Ok(())
+        }
+
+        /// Withdraw the pending split proposal of `asset`. Asset owner only.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::withdraw_proposal())]
+        pub fn withdraw_proposal(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_owner(&asset, &who)?;
+            ensure!(
+                Proposals::<T>::take(&asset).is_some(),
+                Error::<T>::ProposalNotFound
+            );
+
+            Self::deposit_event(Event::ProposalWithdrawn { asset });
+            Ok(())
+        }
+
+        /// Lock the active split of `asset` so it can no longer be replaced. Asset owner only.
+        ///
+        /// Any pending proposal is dropped.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::lock_split())]
+        pub fn lock_split(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_owner(&asset, &who)?;
+            Splits::<T>::try_mutate(&asset, |maybe_split| -> DispatchResult {
+                let split = maybe_split.as_mut().ok_or(Error::<T>::SplitNotFound)?;
+                ensure!(!split.locked, Error::<T>::SplitIsLocked);
+                split.locked = true;
+                Ok(())
+            })?;
+            Proposals::<T>::remove(&asset);
+
+            Self::deposit_event(Event::SplitLocked { asset });
+            Ok(())
+        }
+
+        /// Queue the distribution of `pot` across the assets of `report`, pro rata to their
+        /// play counts. `pot` is held from the caller until the distribution completes.
+        ///
+        /// Every reported asset must have an active split. The distribution is recorded in the
+        /// caller's next statement, whose ledger deposit is held as well.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::distribute(report.len() as u32))]
+        pub fn distribute(
+            origin: OriginFor<T>,
+            pot: BalanceOf<T>,
+            report: UsageReportOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::queue_distribution(who, pot, report)
+        }
+
+        /// Drop the ledger of one of the caller's statements and release its deposit.
+        ///
+        /// The statement's distribution must have completed. Its number is not reused.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::prune_statement(T::MaxReportEntries::get()))]
+        pub fn prune_statement(
+            origin: OriginFor<T>,
+            statement: StatementNumber,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let info =
+                Statements::<T>::get(&who, statement).ok_or(Error::<T>::StatementNotFound)?;
+            ensure!(
+                !Distributions::<T>::contains_key(info.distribution),
+                Error::<T>::DistributionPending
+            );
+
+            let _ = Ledger::<T>::clear_prefix((who.clone(), statement), info.entries, None);
+            Statements::<T>::remove(&who, statement);
+            T::Currency::release(
+                &HoldReason::StatementLedger.into(),
+                &who,
+                info.deposit,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::StatementPruned {
+                payer: who,
+                statement,
+            });
+            Ok(Some(T::WeightInfo::prune_statement(info.entries)).into())
+        }
+
+        /// Queue the distribution of `pot` along the finalized usage of `period`
+        /// (`Config::Usage`), like [`Pallet::distribute`] does along a usage report.
+        ///
+        /// Assets without an active split, or that do not allow the caller as a payer, are left
+        /// out of the distribution.
+        #[pallet::call_index(6)]
+        #[pallet::weight(
+            T::WeightInfo::distribute(T::MaxReportEntries::get())
+                .saturating_add(T::DbWeight::get().reads(
+                    u64::from(T::MaxReportEntries::get())
+                        .saturating_mul(2)
+                        .saturating_add(1),
+                ))
+        )]
+        pub fn distribute_usage(
+            origin: OriginFor<T>,
+            pot: BalanceOf<T>,
+            period: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let usage = T::Usage::finalized_usage(period).ok_or(Error::<T>::UsageNotFinalized)?;
+            let report: Vec<_> = usage
+                .into_iter()
+                .filter(|(asset, _)| {
+                    Splits::<T>::contains_key(asset) && Self::is_allowed_payer(asset, &who)
+                })
+                .collect();
+            let report = UsageReportOf::<T>::try_from(report)
+                .map_err(|_| Error::<T>::TooManyReportEntries)?;
+            Self::queue_distribution(who, pot, report)
+        }
+
+        /// Restrict the accounts allowed to distribute the royalties of `asset` to `payers`,
+        /// or open it to any payer with `None`. Asset owner only.
+        ///
+        /// Lets the owner keep dust distributions out of their collaborators' statements, e.g.
+        /// by only allowing their DSP aggregator. Queued distributions are not affected.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_allowed_payers(
+            payers.as_ref().map_or(0, |payers| payers.len() as u32)
+        ))]
+        pub fn set_allowed_payers(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            payers: Option<PayersOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_owner(&asset, &who)?;
+            AllowedPayers::<T>::set(&asset, payers.clone());
+
+            Self::deposit_event(Event::AllowedPayersSet { asset, payers });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Validate `report`, hold `pot` and the ledger deposit from `who`, and queue the
+        /// distribution in `who`'s next statement.
+        fn queue_distribution(
+            who: T::AccountId,
+            pot: BalanceOf<T>,
+            report: UsageReportOf<T>,
+        ) -> DispatchResult {
+            ensure!(!pot.is_zero(), Error::<T>::ZeroPot);
+            ensure!(!report.is_empty(), Error::<T>::EmptyReport);
+            let mut total_plays: u64 = 0;
+            for (i, (asset, plays)) in report.iter().enumerate() {
+                ensure!(*plays > 0, Error::<T>::ZeroPlays);
+                ensure!(
+                    !report[..i].iter().any(|(other, _)| other == asset),
+                    Error::<T>::DuplicateReportEntry
+                );
+                ensure!(
+                    Splits::<T>::contains_key(asset),
+                    Error::<T>::AssetWithoutSplit
+                );
+                ensure!(
+                    Self::is_allowed_payer(asset, &who),
+                    Error::<T>::PayerNotAllowed
+                );
+                total_plays = total_plays.saturating_add((*plays).into());
+            }
+
+            T::Currency::hold(&HoldReason::DistributionPot.into(), &who, pot)?;
+            let deposit = T::LedgerDeposit::get().saturating_mul((report.len() as u32).into());
+            T::Currency::hold(&HoldReason::StatementLedger.into(), &who, deposit)?;
+
+            let id = NextDistributionId::<T>::get();
+            let statement = StatementCount::<T>::get(&who).saturating_add(1);
+            StatementCount::<T>::insert(&who, statement);
+            Statements::<T>::insert(
+                &who,
+                statement,
+                Statement {
+                    distribution: id,
+                    pot,
+                    queued_at: frame_system::Pallet::<T>::block_number(),
+                    entries: 0,
+                    deposit,
+                },
+            );
+            Distributions::<T>::insert(
+                id,
+                Distribution {
+                    payer: who.clone(),
+                    pot,
+                    total_plays,
+                    report,
+                    cursor: 0,
+                    distributed: Zero::zero(),
+                    statement,
+                },
+            );
+            NextDistributionId::<T>::put(id.saturating_add(1));
+
+            Self::deposit_event(Event::DistributionQueued {
+                id,
+                payer: who,
+                pot,
+                statement,
+            });
+            Ok(())
+        }
+
+        fn ensure_owner(asset: &T::AssetId, who: &T::AccountId) -> DispatchResult {
+            ensure!(
+                T::Assets::owner_of(asset).as_ref() == Some(who),
+                Error::<T>::NotAssetOwner
+            );
+            Ok(())
+        }
+
+        /// Whether `who` may distribute the royalties of `asset`.
+        pub fn is_allowed_payer(asset: &T::AssetId, who: &T::AccountId) -> bool {
+            AllowedPayers::<T>::get(asset).is_none_or(|payers| payers.contains(who))
+        }
+
+        pub(crate) fn ensure_valid_shares(shares: &SharesOf<T>) -> DispatchResult {
+            ensure!(!shares.is_empty(), Error::<T>::NoCollaborators);
+            let mut total: u64 = 0;
+            for (i, (account, share)) in shares.iter().enumerate() {
+                ensure!(
+                    !shares[..i].iter().any(|(other, _)| other == account),
+                    Error::<T>::DuplicateCollaborator
+                );
+                ensure!(!share.is_zero(), Error::<T>::ZeroShare);
+                total = total.saturating_add(share.deconstruct().into());
+            }
+            ensure!(
+                total == u64::from(Perbill::ACCURACY),
+                Error::<T>::SharesNotWhole
+            );
+            Ok(())
+        }
+
+        /// Whether `who` must co-sign `shares` to become the split of `asset`.
+        fn is_signatory(asset: &T::AssetId, shares: &SharesOf<T>, who: &T::AccountId) -> bool {
+            let listed = |shares: &SharesOf<T>| shares.iter().any(|(account, _)| account == who);
+            listed(shares) || Splits::<T>::get(asset).is_some_and(|split| listed(&split.shares))
+        }
+
+        /// Activate `proposal` if every signatory signed it, store it as pending otherwise.
+        fn store_or_activate(asset: T::AssetId, proposal: SplitProposalOf<T>) {
+            let signed = |shares: &SharesOf<T>| {
+                shares
+                    .iter()
+                    .all(|(account, _)| proposal.approvals.contains(account))
+            };
+            let replaced_signed =
+                Splits::<T>::get(&asset).is_none_or(|split| signed(&split.shares));
+            if !signed(&proposal.shares) || !replaced_signed {
+                Proposals::<T>::insert(asset, proposal);
+                return;
+            }
+
+            Proposals::<T>::remove(&asset);
+            Splits::<T>::insert(
+                &asset,
+                Split {
+                    shares: proposal.shares.clone(),
+                    activated_at: frame_system::Pallet::<T>::block_number(),
+                    locked: false,
+                },
+            );
+            Self::deposit_event(Event::SplitActivated {
+                asset,
+                shares: proposal.shares,
+            });
+        }
+
+        /// Share of `asset`'s royalties `who` receives under its active split, zero if none.
+        pub fn share_of(asset: &T::AssetId, who: &T::AccountId) -> Perbill {
+            Splits::<T>::get(asset)
+                .and_then(|split| {
+                    split
+                        .shares
+                        .into_iter()
+                        .find_map(|(account, share)| (&account == who).then_some(share))
+                })
+                .unwrap_or_default()
+        }
+
+        /// Move `share` of `asset`'s royalties from `from` to `to` in its active split, adding
+        /// `to` as a collaborator if needed. `from` is dropped from the split if their share
+        /// reaches zero.
+        ///
+        /// No origin check and no co-signing: meant for pallets settling an agreement `from`
+        /// entered. Since `to` becomes a collaborator, replacing the split needs their
+        /// signature from then on.
+        pub fn assign_share(
+            asset: &T::AssetId,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            share: Perbill,
+        ) -> DispatchResult {
+            ensure!(!share.is_zero(), Error::<T>::ZeroShare);
+            Splits::<T>::try_mutate(asset, |maybe_split| -> DispatchResult {
+                let split = maybe_split.as_mut().ok_or(Error::<T>::SplitNotFound)?;
+                ensure!(!split.locked, Error::<T>::SplitIsLocked);
+
+                let pos = split
+                    .shares
+                    .iter()
+                    .position(|(account, _)| account == from)
+                    .ok_or(Error::<T>::NotCollaborator)?;
+                let remaining = split.shares[pos]
+                    .1
+                    .deconstruct()
+                    .checked_sub(share.deconstruct())
+                    .ok_or(Error::<T>::InsufficientShare)?;
+                if remaining == 0 {
+                    split.shares.remove(pos);
+                } else {
+                    split.shares[pos].1 = Perbill::from_parts(remaining);
+                }
+
+                match split.shares.iter_mut().find(|(account, _)| account == to) {
+                    Some((_, existing)) => *existing = existing.saturating_add(share),
+                    None => split
+                        .shares
+                        .try_push((to.clone(), share))
+                        .map_err(|_| Error::<T>::TooManyCollaborators)?,
+                }
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ShareAssigned {
+                asset: asset.clone(),
+                from: from.clone(),
+                to: to.clone(),
+                share,
+            });
+            Ok(())
+        }
+
+        /// Divide `amount` among the collaborators of `asset`'s active split.
+        ///
+        /// Each part is rounded down and the rounding remainder goes to the first collaborator,
+        /// so the parts always add up to `amount`. `None` if the asset has no active split.
+        pub fn apportion<Balance: AtLeast32BitUnsigned + Copy>(
+            asset: &T::AssetId,
+            amount: Balance,
+        ) -> Option<Vec<(T::AccountId, Balance)>> {
+            Some(Self::apportion_along(
+                &Splits::<T>::get(asset)?.shares,
+                amount,
+            ))
+        }
+
+        /// Divide `amount` along `shares`, rounding like [`Pallet::apportion`].
+        pub(crate) fn apportion_along<Balance: AtLeast32BitUnsigned + Copy>(
+            shares: &SharesOf<T>,
+            amount: Balance,
+        ) -> Vec<(T::AccountId, Balance)> {
+            let mut parts: Vec<_> = shares
+                .iter()
+                .map(|(account, share)| (account.clone(), share.mul_floor(amount)))
+                .collect();
+            let distributed = parts
+                .iter()
+                .fold(Balance::zero(), |acc, (_, part)| acc.saturating_add(*part));
+            if let Some((_, first)) = parts.first_mut() {
+                first.saturating_accrue(amount.saturating_sub(distributed));
+            }
+            parts
+        }
+    }
+}
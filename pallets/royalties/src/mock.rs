@@ -0,0 +1,174 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_royalties;
+use frame_support::{derive_impl, parameter_types, sp_runtime::BuildStorage, storage::unhashed};
+use parity_scale_codec::Encode;
+use sp_runtime::Perbill;
+
+pub type Balance = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type Royalties = pallet_royalties;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+}
+
+parameter_types! {
+    pub const MaxCollaborators: u32 = 3;
+    pub const MaxReportEntries: u32 = 4;
+    pub static DistributionFee: Perbill = Perbill::zero();
+    pub const FeeDestination: u64 = TREASURY;
+    pub const LedgerDeposit: Balance = 2;
+    pub const MaxAllowedPayers: u32 = 2;
+    pub static DistributionWeightCeiling: Perbill = Perbill::from_percent(50);
+    pub const EarningsEra: u64 = EARNINGS_ERA;
+}
+
+/// Asset owners, kept in the test externalities so that each test starts from genesis.
+pub struct MockAssets;
+
+impl MockAssets {
+    fn key(asset: u32) -> Vec<u8> {
+        (b"mock/asset-owner", asset).encode()
+    }
+
+    pub fn set_owner(asset: u32, owner: u64) {
+        unhashed::put(&Self::key(asset), &owner);
+    }
+}
+
+impl crate::AssetOwnership<u32, u64> for MockAssets {
+    fn owner_of(asset: &u32) -> Option<u64> {
+        unhashed::get(&Self::key(*asset))
+    }
+}
+
+/// Finalized usage, kept in the test externalities.
+pub struct MockUsage;
+
+impl MockUsage {
+    fn key(period: u32) -> Vec<u8> {
+        (b"mock/usage", period).encode()
+    }
+
+    pub fn finalize(period: u32, usage: Vec<(u32, u32)>) {
+        unhashed::put(&Self::key(period), &usage);
+    }
+}
+
+impl crate::UsageReports<u32> for MockUsage {
+    fn finalized_usage(period: u32) -> Option<Vec<(u32, u32)>> {
+        unhashed::get(&Self::key(period))
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u32, u64> for MockAssets {
+    fn create_asset(i: u32, owner: &u64) -> u32 {
+        Self::set_owner(i, *owner);
+        i
+    }
+}
+
+impl pallet_royalties::Config for Test {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetId = u32;
+    type Assets = MockAssets;
+    type Usage = MockUsage;
+    type MaxCollaborators = MaxCollaborators;
+    type MaxReportEntries = MaxReportEntries;
+    type DistributionFee = DistributionFee;
+    type FeeDestination = FeeDestination;
+    type LedgerDeposit = LedgerDeposit;
+    type MaxAllowedPayers = MaxAllowedPayers;
+    type DistributionWeightCeiling = DistributionWeightCeiling;
+    type EarningsEra = EarningsEra;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockAssets;
+}
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+pub const DAVE: u64 = 4;
+/// Funds distributions.
+pub const PAYER: u64 = 5;
+/// Collects distribution fees.
+pub const TREASURY: u64 = 6;
+
+/// Blocks per era of the earnings ledger.
+pub const EARNINGS_ERA: u64 = 10;
+
+/// Owned by `ALICE`.
+pub const WORK: u32 = 1;
+/// Owned by `BOB`.
+pub const RECORDING: u32 = 2;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, 100), (BOB, 100), (CHARLIE, 100), (PAYER, 10_000)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        MockAssets::set_owner(WORK, ALICE);
+        MockAssets::set_owner(RECORDING, BOB);
+    });
+    ext
+}
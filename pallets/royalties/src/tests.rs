@@ -0,0 +1,880 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AllowedPayers, DistributionHead, Distributions, EarningSource, Earnings, EraEarnings, Error,
+    Event, HoldReason, Ledger, LedgerLine, OnPayout, Pallet, Proposals, RecordEarning, SharesOf,
+    SimulatedPayout, Splits, StatementCount, Statements, TotalDistributed, UsageReportOf,
+    WeightInfo, mock::*,
+};
+use frame_support::{
+    assert_noop, assert_ok, parameter_types,
+    traits::{Hooks, fungible::InspectHold},
+    weights::Weight,
+};
+use sp_runtime::Perbill;
+
+fn shares(table: &[(u64, u32)]) -> SharesOf<Test> {
+    table
+        .iter()
+        .map(|&(who, percent)| (who, Perbill::from_percent(percent)))
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+fn propose(table: &[(u64, u32)]) -> sp_runtime::DispatchResult {
+    Royalties::propose_split(RuntimeOrigin::signed(ALICE), WORK, shares(table))
+}
+
+fn co_sign(who: u64) -> sp_runtime::DispatchResult {
+    Royalties::co_sign(RuntimeOrigin::signed(who), WORK)
+}
+
+#[test]
+fn proposal_activates_once_every_collaborator_co_signed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(propose(&[(ALICE, 50), (BOB, 30), (CHARLIE, 20)]));
+        System::assert_last_event(
+            Event::SplitProposed {
+                asset: WORK,
+                proposer: ALICE,
+            }
+            .into(),
+        );
+        // The proposing owner is listed, so their signature is already counted.
+        assert_eq!(
+            Proposals::<Test>::get(WORK).unwrap().approvals.to_vec(),
+            vec![ALICE]
+        );
+
+        assert_ok!(co_sign(BOB));
+        System::assert_last_event(
+            Event::SplitCoSigned {
+                asset: WORK,
+                who: BOB,
+            }
+            .into(),
+        );
+        assert!(Splits::<Test>::get(WORK).is_none());
+
+        assert_ok!(co_sign(CHARLIE));
+        System::assert_last_event(
+            Event::SplitActivated {
+                asset: WORK,
+                shares: shares(&[(ALICE, 50), (BOB, 30), (CHARLIE, 20)]),
+            }
+            .into(),
+        );
+        assert!(Proposals::<Test>::get(WORK).is_none());
+        let split = Splits::<Test>::get(WORK).unwrap();
+        assert_eq!(split.activated_at, 1);
+        assert!(!split.locked);
+    });
+}
+
+#[test]
+fn owner_alone_activates_a_split_only_they_are_in() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(propose(&[(ALICE, 100)]));
+        assert!(Splits::<Test>::get(WORK).is_some());
+        assert!(Proposals::<Test>::get(WORK).is_none());
+    });
+}
+
+#[test]
+fn propose_split_validates_shares() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Royalties::propose_split(RuntimeOrigin::signed(BOB), WORK, shares(&[(BOB, 100)])),
+            Error::<Test>::NotAssetOwner
+        );
+        assert_noop!(
+            Royalties::propose_split(RuntimeOrigin::signed(ALICE), 2, shares(&[(ALICE, 100)])),
+            Error::<Test>::NotAssetOwner
+        );
+        assert_noop!(propose(&[]), Error::<Test>::NoCollaborators);
+        assert_noop!(
+            propose(&[(BOB, 50), (BOB, 50)]),
+            Error::<Test>::DuplicateCollaborator
+        );
+        assert_noop!(
+            propose(&[(BOB, 100), (CHARLIE, 0)]),
+            Error::<Test>::ZeroShare
+        );
+        assert_noop!(
+            propose(&[(BOB, 50), (CHARLIE, 49)]),
+            Error::<Test>::SharesNotWhole
+        );
+        assert_noop!(
+            propose(&[(BOB, 60), (CHARLIE, 60)]),
+            Error::<Test>::SharesNotWhole
+        );
+    });
+}
+
+#[test]
+fn only_listed_collaborators_co_sign_once() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(co_sign(BOB), Error::<Test>::ProposalNotFound);
+
+        assert_ok!(propose(&[(BOB, 50), (CHARLIE, 50)]));
+        assert_noop!(co_sign(DAVE), Error::<Test>::NotCollaborator);
+        // Not listed, so proposing does not count as a signature.
+        assert_noop!(co_sign(ALICE), Error::<Test>::NotCollaborator);
+
+        assert_ok!(co_sign(BOB));
+        assert_noop!(co_sign(BOB), Error::<Test>::AlreadyCoSigned);
+    });
+}
+
+#[test]
+fn active_split_stays_until_replacement_is_co_signed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(propose(&[(ALICE, 100)]));
+
+        assert_ok!(propose(&[(ALICE, 10), (BOB, 90)]));
+        assert_eq!(
+            Splits::<Test>::get(WORK).unwrap().shares,
+            shares(&[(ALICE, 100)])
+        );
+
+        // A new proposal resets the signatures collected so far.
+        assert_ok!(propose(&[(ALICE, 20), (BOB, 80)]));
+        assert_eq!(
+            Proposals::<Test>::get(WORK).unwrap().approvals.to_vec(),
+            vec![ALICE]
+        );
+
+        assert_ok!(co_sign(BOB));
+        assert_eq!(
+            Splits::<Test>::get(WORK).unwrap().shares,
+            shares(&[(ALICE, 20), (BOB, 80)])
+        );
+    });
+}
+
+#[test]
+fn replaced_collaborators_must_co_sign_too() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(propose(&[(ALICE, 50), (BOB, 50)]));
+        assert_ok!(co_sign(BOB));
+
+        // Dropping `BOB` needs their signature, even though they are no longer listed.
+        assert_ok!(propose(&[(ALICE, 100)]));
+        assert_eq!(
+            Splits::<Test>::get(WORK).unwrap().shares,
+            shares(&[(ALICE, 50), (BOB, 50)])
+        );
+        assert_noop!(co_sign(CHARLIE), Error::<Test>::NotCollaborator);
+
+        assert_ok!(co_sign(BOB));
+        assert_eq!(
+            Splits::<Test>::get(WORK).unwrap().shares,
+            shares(&[(ALICE, 100)])
+        );
+    });
+}
+
+#[test]
+fn owner_withdraws_proposal() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Royalties::withdraw_proposal(RuntimeOrigin::signed(ALICE), WORK),
+            Error::<Test>::ProposalNotFound
+        );
+        assert_ok!(propose(&[(BOB, 100)]));
+        assert_noop!(
+            Royalties::withdraw_proposal(RuntimeOrigin::signed(BOB), WORK),
+            Error::<Test>::NotAssetOwner
+        );
+
+        assert_ok!(Royalties::withdraw_proposal(
+            RuntimeOrigin::signed(ALICE),
+            WORK
+        ));
+        assert!(Proposals::<Test>::get(WORK).is_none());
+        System::assert_last_event(Event::ProposalWithdrawn { asset: WORK }.into());
+        assert_noop!(co_sign(BOB), Error::<Test>::ProposalNotFound);
+    });
+}
+
+#[test]
+fn locked_split_can_no_longer_be_replaced() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Royalties::lock_split(RuntimeOrigin::signed(ALICE), WORK),
+            Error::<Test>::SplitNotFound
+        );
+        assert_ok!(propose(&[(ALICE, 100)]));
+        assert_ok!(propose(&[(ALICE, 50), (BOB, 50)]));
+        assert_noop!(
+            Royalties::lock_split(RuntimeOrigin::signed(BOB), WORK),
+            Error::<Test>::NotAssetOwner
+        );
+
+        assert_ok!(Royalties::lock_split(RuntimeOrigin::signed(ALICE), WORK));
+        System::assert_last_event(Event::SplitLocked { asset: WORK }.into());
+        assert!(Splits::<Test>::get(WORK).unwrap().locked);
+        // The pending proposal is dropped with the lock.
+        assert_noop!(co_sign(BOB), Error::<Test>::ProposalNotFound);
+
+        assert_noop!(propose(&[(ALICE, 100)]), Error::<Test>::SplitIsLocked);
+        assert_noop!(
+            Royalties::lock_split(RuntimeOrigin::signed(ALICE), WORK),
+            Error::<Test>::SplitIsLocked
+        );
+    });
+}
+
+#[test]
+fn apportion_follows_the_active_split() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Pallet::<Test>::apportion(&WORK, 100u64), None);
+
+        assert_ok!(Royalties::propose_split(
+            RuntimeOrigin::signed(ALICE),
+            WORK,
+            vec![
+                (ALICE, Perbill::from_rational(1u32, 3)),
+                (BOB, Perbill::from_rational(1u32, 3)),
+                (CHARLIE, Perbill::from_parts(333_333_334)),
+            ]
+            .try_into()
+            .unwrap()
+        ));
+        assert_ok!(co_sign(BOB));
+        assert_ok!(co_sign(CHARLIE));
+
+        // 33 each, the rounding remainder goes to the first collaborator.
+        assert_eq!(
+            Pallet::<Test>::apportion(&WORK, 100u64),
+            Some(vec![(ALICE, 34), (BOB, 33), (CHARLIE, 33)])
+        );
+        assert_eq!(
+            Pallet::<Test>::apportion(&WORK, 0u64),
+            Some(vec![(ALICE, 0), (BOB, 0), (CHARLIE, 0)])
+        );
+    });
+}
+
+#[test]
+fn assign_share_moves_part_of_a_collaborators_share() {
+    new_test_ext().execute_with(|| {
+        let assign = |from, to, percent| {
+            Pallet::<Test>::assign_share(&WORK, &from, &to, Perbill::from_percent(percent))
+        };
+        assert_noop!(assign(ALICE, DAVE, 10), Error::<Test>::SplitNotFound);
+
+        assert_ok!(propose(&[(ALICE, 60), (BOB, 40)]));
+        assert_ok!(co_sign(BOB));
+        assert_noop!(assign(CHARLIE, DAVE, 10), Error::<Test>::NotCollaborator);
+        assert_noop!(assign(ALICE, DAVE, 61), Error::<Test>::InsufficientShare);
+        assert_noop!(assign(ALICE, DAVE, 0), Error::<Test>::ZeroShare);
+
+        assert_ok!(assign(ALICE, DAVE, 20));
+        System::assert_last_event(
+            Event::ShareAssigned {
+                asset: WORK,
+                from: ALICE,
+                to: DAVE,
+                share: Perbill::from_percent(20),
+            }
+            .into(),
+        );
+        assert_eq!(
+            Splits::<Test>::get(WORK).unwrap().shares,
+            shares(&[(ALICE, 40), (BOB, 40), (DAVE, 20)])
+        );
+        assert_eq!(
+            Pallet::<Test>::share_of(&WORK, &DAVE),
+            Perbill::from_percent(20)
+        );
+
+        // Merged into an existing share; a share reaching zero leaves the split.
+        assert_ok!(assign(BOB, DAVE, 40));
+        assert_eq!(
+            Splits::<Test>::get(WORK).unwrap().shares,
+            shares(&[(ALICE, 40), (DAVE, 60)])
+        );
+        assert_eq!(Pallet::<Test>::share_of(&WORK, &BOB), Perbill::zero());
+
+        assert_ok!(assign(ALICE, CHARLIE, 10));
+        assert_noop!(assign(ALICE, BOB, 10), Error::<Test>::TooManyCollaborators);
+
+        assert_ok!(Royalties::lock_split(RuntimeOrigin::signed(ALICE), WORK));
+        assert_noop!(assign(ALICE, CHARLIE, 10), Error::<Test>::SplitIsLocked);
+    });
+}
+
+fn report(entries: &[(u32, u32)]) -> UsageReportOf<Test> {
+    entries.to_vec().try_into().unwrap()
+}
+
+fn pot_on_hold() -> Balance {
+    Balances::balance_on_hold(&HoldReason::DistributionPot.into(), &PAYER)
+}
+
+/// `WORK` split 50/50 between `ALICE` and `BOB`, `RECORDING` entirely `BOB`'s.
+fn setup_splits() {
+    assert_ok!(propose(&[(ALICE, 50), (BOB, 50)]));
+    assert_ok!(co_sign(BOB));
+    assert_ok!(Royalties::propose_split(
+        RuntimeOrigin::signed(BOB),
+        RECORDING,
+        shares(&[(BOB, 100)])
+    ));
+}
+
+#[test]
+fn distribute_validates_report() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        let distribute = |pot, entries: &[(u32, u32)]| {
+            Royalties::distribute(RuntimeOrigin::signed(PAYER), pot, report(entries))
+        };
+
+        assert_noop!(distribute(0, &[(WORK, 1)]), Error::<Test>::ZeroPot);
+        assert_noop!(distribute(100, &[]), Error::<Test>::EmptyReport);
+        assert_noop!(distribute(100, &[(WORK, 0)]), Error::<Test>::ZeroPlays);
+        assert_noop!(
+            distribute(100, &[(WORK, 1), (WORK, 2)]),
+            Error::<Test>::DuplicateReportEntry
+        );
+        assert_noop!(
+            distribute(100, &[(WORK, 1), (3, 2)]),
+            Error::<Test>::AssetWithoutSplit
+        );
+        assert!(distribute(100_000, &[(WORK, 1)]).is_err());
+    });
+}
+
+#[test]
+fn distribution_pays_rightsholders_pro_rata() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(PAYER),
+            1_000,
+            report(&[(WORK, 3), (RECORDING, 1)])
+        ));
+        System::assert_last_event(
+            Event::DistributionQueued {
+                id: 0,
+                payer: PAYER,
+                pot: 1_000,
+                statement: 1,
+            }
+            .into(),
+        );
+        assert_eq!(pot_on_hold(), 1_000);
+
+        Royalties::on_idle(1, Weight::MAX);
+
+        assert_eq!(Balances::free_balance(ALICE), 100 + 375);
+        assert_eq!(Balances::free_balance(BOB), 100 + 375 + 250);
+        // Less the ledger deposit of the two entries.
+        assert_eq!(Balances::free_balance(PAYER), 10_000 - 1_000 - 4);
+        assert_eq!(pot_on_hold(), 0);
+        System::assert_has_event(
+            Event::RoyaltyPaid {
+                id: 0,
+                asset: RECORDING,
+                beneficiary: BOB,
+                amount: 250,
+                fees: 0,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::DistributionCompleted {
+                id: 0,
+                distributed: 1_000,
+                returned: 0,
+            }
+            .into(),
+        );
+        assert!(Distributions::<Test>::get(0).is_none());
+        assert_eq!(DistributionHead::<Test>::get(), 1);
+    });
+}
+
+#[test]
+fn rounding_remainder_returns_to_payer() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+
+        // 10 * 1/3 and 10 * 2/3 round down to 3 and 6.
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(PAYER),
+            10,
+            report(&[(RECORDING, 1), (WORK, 2)])
+        ));
+        Royalties::on_idle(1, Weight::MAX);
+
+        System::assert_last_event(
+            Event::DistributionCompleted {
+                id: 0,
+                distributed: 9,
+                returned: 1,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(PAYER), 10_000 - 9 - 4);
+        assert_eq!(pot_on_hold(), 0);
+    });
+}
+
+#[test]
+fn distribution_spans_blocks_when_weight_is_short() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(PAYER),
+            1_000,
+            report(&[(WORK, 1), (RECORDING, 1)])
+        ));
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(PAYER),
+            100,
+            report(&[(RECORDING, 1)])
+        ));
+        let one_entry = <() as WeightInfo>::pay_entry(MaxCollaborators::get());
+
+        // Room for the first entry only.
+        assert_eq!(Royalties::on_idle(1, one_entry), one_entry);
+        assert_eq!(Distributions::<Test>::get(0).unwrap().cursor, 1);
+        assert_eq!(Balances::free_balance(ALICE), 100 + 250);
+        assert_eq!(Balances::free_balance(BOB), 100 + 250);
+
+        // Not even room for one entry.
+        assert_eq!(Royalties::on_idle(2, Weight::zero()), Weight::zero());
+        assert_eq!(Distributions::<Test>::get(0).unwrap().cursor, 1);
+
+        Royalties::on_idle(3, Weight::MAX);
+        assert!(Distributions::<Test>::get(0).is_none());
+        assert!(Distributions::<Test>::get(1).is_none());
+        assert_eq!(DistributionHead::<Test>::get(), 2);
+        assert_eq!(Balances::free_balance(BOB), 100 + 250 + 500 + 100);
+        assert_eq!(pot_on_hold(), 0);
+    });
+}
+
+#[test]
+fn distribution_step_weight_completes_a_single_entry_distribution() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(PAYER),
+            1_000,
+            report(&[(WORK, 1)])
+        ));
+
+        Royalties::on_idle(1, Royalties::distribution_step_weight());
+        assert!(Distributions::<Test>::get(0).is_none());
+        assert_eq!(DistributionHead::<Test>::get(), 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "exceeds `DistributionWeightCeiling`")]
+fn integrity_test_rejects_a_distribution_step_over_the_ceiling() {
+    Royalties::integrity_test();
+
+    DistributionWeightCeiling::set(Perbill::from_parts(1));
+    Royalties::integrity_test();
+}
+
+#[test]
+fn distribution_uses_split_in_force_when_paid() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(PAYER),
+            100,
+            report(&[(WORK, 1)])
+        ));
+
+        assert_ok!(propose(&[(ALICE, 100)]));
+        // Still pending, so the 50/50 split applies.
+        Royalties::on_idle(1, Weight::MAX);
+        assert_eq!(Balances::free_balance(ALICE), 150);
+        assert_eq!(Balances::free_balance(BOB), 150);
+    });
+}
+
+fn ledger_on_hold() -> Balance {
+    Balances::balance_on_hold(&HoldReason::StatementLedger.into(), &PAYER)
+}
+
+#[test]
+fn statements_are_numbered_per_payer() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        let distribute =
+            |who| Royalties::distribute(RuntimeOrigin::signed(who), 10, report(&[(RECORDING, 1)]));
+
+        assert_ok!(distribute(PAYER));
+        assert_ok!(distribute(ALICE));
+        assert_ok!(distribute(PAYER));
+        System::assert_last_event(
+            Event::DistributionQueued {
+                id: 2,
+                payer: PAYER,
+                pot: 10,
+                statement: 2,
+            }
+            .into(),
+        );
+        assert_eq!(StatementCount::<Test>::get(PAYER), 2);
+        assert_eq!(StatementCount::<Test>::get(ALICE), 1);
+        assert_eq!(Statements::<Test>::get(ALICE, 1).unwrap().distribution, 1);
+        assert_eq!(Distributions::<Test>::get(2).unwrap().statement, 2);
+        assert_eq!(ledger_on_hold(), 2 * 2);
+    });
+}
+
+#[test]
+fn ledger_records_gross_fees_and_net() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        DistributionFee::set(Perbill::from_percent(10));
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(PAYER),
+            1_000,
+            report(&[(WORK, 3), (RECORDING, 1)])
+        ));
+        Royalties::on_idle(1, Weight::MAX);
+
+        let line = |asset, beneficiary, gross, fees, net| LedgerLine {
+            statement: 1,
+            payer: PAYER,
+            asset,
+            beneficiary,
+            gross,
+            fees,
+            net,
+        };
+        assert_eq!(
+            Royalties::statement_ledger(&PAYER, 1).unwrap(),
+            vec![
+                line(WORK, ALICE, 375, 37, 338),
+                line(WORK, BOB, 375, 37, 338),
+                line(RECORDING, BOB, 250, 25, 225),
+            ]
+        );
+        System::assert_has_event(
+            Event::RoyaltyPaid {
+                id: 0,
+                asset: RECORDING,
+                beneficiary: BOB,
+                amount: 225,
+                fees: 25,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(ALICE), 100 + 338);
+        assert_eq!(Balances::free_balance(BOB), 100 + 338 + 225);
+        assert_eq!(Balances::free_balance(TREASURY), 37 + 37 + 25);
+        // Fees are part of what the pot paid out.
+        System::assert_last_event(
+            Event::DistributionCompleted {
+                id: 0,
+                distributed: 1_000,
+                returned: 0,
+            }
+            .into(),
+        );
+        assert_eq!(TotalDistributed::<Test>::get(), 1_000);
+        assert!(Royalties::statement_ledger(&PAYER, 2).is_none());
+    });
+}
+
+#[test]
+fn payer_prunes_completed_statements() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(PAYER),
+            1_000,
+            report(&[(WORK, 1), (RECORDING, 1)])
+        ));
+        let prune =
+            |who, statement| Royalties::prune_statement(RuntimeOrigin::signed(who), statement);
+
+        assert_noop!(prune(PAYER, 1), Error::<Test>::DistributionPending);
+        Royalties::on_idle(1, Weight::MAX);
+        assert_noop!(prune(ALICE, 1), Error::<Test>::StatementNotFound);
+        assert_noop!(prune(PAYER, 2), Error::<Test>::StatementNotFound);
+        assert_eq!(ledger_on_hold(), 4);
+
+        assert_ok!(prune(PAYER, 1));
+        System::assert_last_event(
+            Event::StatementPruned {
+                payer: PAYER,
+                statement: 1,
+            }
+            .into(),
+        );
+        assert_eq!(ledger_on_hold(), 0);
+        assert!(Royalties::statement_ledger(&PAYER, 1).is_none());
+        assert_eq!(Ledger::<Test>::iter().count(), 0);
+
+        // Numbers are not reused.
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(PAYER),
+            10,
+            report(&[(RECORDING, 1)])
+        ));
+        assert_eq!(Distributions::<Test>::get(1).unwrap().statement, 2);
+    });
+}
+
+#[test]
+fn distribute_usage_follows_finalized_usage() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        let distribute =
+            |pot, period| Royalties::distribute_usage(RuntimeOrigin::signed(PAYER), pot, period);
+
+        assert_noop!(distribute(1_000, 0), Error::<Test>::UsageNotFinalized);
+        // Asset 3 has no split and is left out.
+        MockUsage::finalize(0, vec![(WORK, 30), (3, 50), (RECORDING, 10)]);
+        MockUsage::finalize(1, vec![(3, 50)]);
+        MockUsage::finalize(2, (10..15).map(|asset| (asset, 1)).collect());
+        assert_noop!(distribute(1_000, 1), Error::<Test>::EmptyReport);
+        for asset in 10..15 {
+            MockAssets::set_owner(asset, ALICE);
+            assert_ok!(Royalties::propose_split(
+                RuntimeOrigin::signed(ALICE),
+                asset,
+                shares(&[(ALICE, 100)])
+            ));
+        }
+        assert_noop!(distribute(1_000, 2), Error::<Test>::TooManyReportEntries);
+
+        assert_ok!(distribute(1_000, 0));
+        assert_eq!(
+            Distributions::<Test>::get(0).unwrap().report,
+            report(&[(WORK, 30), (RECORDING, 10)])
+        );
+        Royalties::on_idle(1, Weight::MAX);
+        assert_eq!(Balances::free_balance(ALICE), 100 + 375);
+        assert_eq!(Balances::free_balance(BOB), 100 + 375 + 250);
+    });
+}
+
+#[test]
+fn owners_restrict_who_distributes_their_royalties() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        let allow = |who, asset, payers: Option<Vec<u64>>| {
+            Royalties::set_allowed_payers(
+                RuntimeOrigin::signed(who),
+                asset,
+                payers.map(|payers| payers.try_into().unwrap()),
+            )
+        };
+
+        assert_noop!(
+            allow(BOB, WORK, Some(vec![PAYER])),
+            Error::<Test>::NotAssetOwner
+        );
+        assert_ok!(allow(ALICE, WORK, Some(vec![PAYER])));
+        System::assert_last_event(
+            Event::AllowedPayersSet {
+                asset: WORK,
+                payers: Some(vec![PAYER].try_into().unwrap()),
+            }
+            .into(),
+        );
+
+        // A dust distribution by anyone else is refused, whatever else it pays.
+        assert_noop!(
+            Royalties::distribute(
+                RuntimeOrigin::signed(CHARLIE),
+                1,
+                report(&[(RECORDING, 1), (WORK, 1)])
+            ),
+            Error::<Test>::PayerNotAllowed
+        );
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(CHARLIE),
+            1,
+            report(&[(RECORDING, 1)])
+        ));
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(PAYER),
+            1_000,
+            report(&[(WORK, 1)])
+        ));
+
+        // Open again.
+        assert_ok!(allow(ALICE, WORK, None));
+        assert!(!AllowedPayers::<Test>::contains_key(WORK));
+        assert_ok!(Royalties::distribute(
+            RuntimeOrigin::signed(CHARLIE),
+            1,
+            report(&[(WORK, 1)])
+        ));
+    });
+}
+
+#[test]
+fn distribute_usage_leaves_out_assets_restricted_to_other_payers() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        assert_ok!(Royalties::set_allowed_payers(
+            RuntimeOrigin::signed(BOB),
+            RECORDING,
+            Some(vec![DAVE].try_into().unwrap())
+        ));
+        MockUsage::finalize(0, vec![(WORK, 30), (RECORDING, 10)]);
+
+        assert_ok!(Royalties::distribute_usage(
+            RuntimeOrigin::signed(PAYER),
+            1_000,
+            0
+        ));
+        assert_eq!(
+            Distributions::<Test>::get(0).unwrap().report,
+            report(&[(WORK, 30)])
+        );
+    });
+}
+
+#[test]
+fn simulate_split_recomputes_past_distributions_of_an_asset() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        DistributionFee::set(Perbill::from_percent(10));
+        let distribute_and_pay = |block, pot, entries: &[(u32, u32)]| {
+            System::set_block_number(block);
+            assert_ok!(Royalties::distribute(
+                RuntimeOrigin::signed(PAYER),
+                pot,
+                report(entries)
+            ));
+            Royalties::on_idle(block, Weight::MAX);
+        };
+        // `WORK` is paid 750 then 100 within the range, 400 after it.
+        distribute_and_pay(1, 1_000, &[(WORK, 3), (RECORDING, 1)]);
+        distribute_and_pay(2, 100, &[(WORK, 1)]);
+        distribute_and_pay(3, 400, &[(WORK, 1)]);
+
+        let payout = |beneficiary, paid, gross, fees, net| SimulatedPayout {
+            beneficiary,
+            paid,
+            gross,
+            fees,
+            net,
+        };
+        assert_eq!(
+            Royalties::simulate_split(&WORK, shares(&[(ALICE, 80), (CHARLIE, 20)]).into(), 1, 2),
+            Ok(vec![
+                payout(ALICE, 338 + 45, 600 + 80, 60 + 8, 540 + 72),
+                payout(CHARLIE, 0, 150 + 20, 15 + 2, 135 + 18),
+                payout(BOB, 338 + 45, 0, 0, 0),
+            ])
+        );
+        assert_eq!(
+            Royalties::simulate_split(&WORK, shares(&[(ALICE, 80)]).into(), 1, 2),
+            Err(Error::<Test>::SharesNotWhole.into())
+        );
+        // Pruned statements are no longer simulated.
+        assert_ok!(Royalties::prune_statement(RuntimeOrigin::signed(PAYER), 1));
+        assert_eq!(
+            Royalties::simulate_split(&WORK, shares(&[(BOB, 100)]).into(), 1, 2),
+            Ok(vec![
+                payout(BOB, 45, 100, 10, 90),
+                payout(ALICE, 45, 0, 0, 0)
+            ])
+        );
+    });
+}
+
+#[test]
+fn distributions_are_recorded_as_earnings_per_era() {
+    new_test_ext().execute_with(|| {
+        setup_splits();
+        DistributionFee::set(Perbill::from_percent(10));
+        let distribute = || {
+            assert_ok!(Royalties::distribute(
+                RuntimeOrigin::signed(PAYER),
+                1_000,
+                report(&[(WORK, 3), (RECORDING, 1)])
+            ));
+            Royalties::on_idle(System::block_number(), Weight::MAX);
+        };
+        let paid = |distributions| EraEarnings {
+            distributions,
+            ..Default::default()
+        };
+
+        distribute();
+        System::set_block_number(2 * EARNINGS_ERA);
+        distribute();
+        distribute();
+
+        // Net of fees, in era order.
+        assert_eq!(
+            Royalties::earnings(&ALICE, 0, 10),
+            vec![(0, paid(338)), (2, paid(676))]
+        );
+        assert_eq!(
+            Royalties::earnings(&BOB, 0, 10),
+            vec![(0, paid(338 + 225)), (2, paid(2 * (338 + 225)))]
+        );
+        assert_eq!(
+            Royalties::earnings(&BOB, 1, 2),
+            vec![(2, paid(2 * (338 + 225)))]
+        );
+        assert_eq!(Royalties::earnings(&BOB, 3, 2), vec![]);
+        assert_eq!(Royalties::earnings(&CHARLIE, 0, 10), vec![]);
+    });
+}
+
+#[test]
+fn other_pallets_record_earnings_by_source() {
+    new_test_ext().execute_with(|| {
+        parameter_types! {
+            const Licenses: EarningSource = EarningSource::Licenses;
+            const Sales: EarningSource = EarningSource::Sales;
+        }
+
+        RecordEarning::<Test, Licenses>::on_payout(&ALICE, 40);
+        RecordEarning::<Test, Sales>::on_payout(&ALICE, 25);
+        RecordEarning::<Test, Sales>::on_payout(&ALICE, 5);
+        // Nothing earned, nothing recorded.
+        RecordEarning::<Test, Sales>::on_payout(&BOB, 0);
+
+        assert_eq!(
+            Royalties::earnings(&ALICE, 0, 0),
+            vec![(
+                0,
+                EraEarnings {
+                    licenses: 40,
+                    sales: 30,
+                    ..Default::default()
+                }
+            )]
+        );
+        assert!(Earnings::<Test>::iter_prefix(BOB).next().is_none());
+    });
+}
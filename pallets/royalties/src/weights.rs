@@ -0,0 +1,130 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_royalties.
+pub trait WeightInfo {
+    fn propose_split(c: u32) -> Weight;
+    fn co_sign(c: u32) -> Weight;
+    fn withdraw_proposal() -> Weight;
+    fn lock_split() -> Weight;
+    fn distribute(e: u32) -> Weight;
+    fn pay_entry(c: u32) -> Weight;
+    fn complete_distribution() -> Weight;
+    fn prune_statement(e: u32) -> Weight;
+    fn set_allowed_payers(p: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Assets` registry (r:1 w:0)
+    /// Storage: `Royalties::Splits` (r:1 w:1)
+    /// Storage: `Royalties::Proposals` (r:0 w:1)
+    /// The range of component `c` is `[1, 32]`.
+    fn propose_split(c: u32) -> Weight {
+        Weight::from_parts(25_000_000, 4_000)
+            .saturating_add(Weight::from_parts(500_000, 100).saturating_mul(c.into()))
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Royalties::Proposals` (r:1 w:1)
+    /// Storage: `Royalties::Splits` (r:1 w:1)
+    /// The range of component `c` is `[1, 32]`.
+    fn co_sign(c: u32) -> Weight {
+        Weight::from_parts(20_000_000, 4_000)
+            .saturating_add(Weight::from_parts(600_000, 150).saturating_mul(c.into()))
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Assets` registry (r:1 w:0)
+    /// Storage: `Royalties::Proposals` (r:1 w:1)
+    fn withdraw_proposal() -> Weight {
+        Weight::from_parts(18_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Assets` registry (r:1 w:0)
+    /// Storage: `Royalties::Splits` (r:1 w:1)
+    /// Storage: `Royalties::Proposals` (r:0 w:1)
+    fn lock_split() -> Weight {
+        Weight::from_parts(20_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Royalties::Splits` (r:e w:0)
+    /// Storage: `Royalties::AllowedPayers` (r:e w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Royalties::NextDistributionId` (r:1 w:1)
+    /// Storage: `Royalties::StatementCount` (r:1 w:1)
+    /// Storage: `Royalties::Statements` (r:0 w:1)
+    /// Storage: `Royalties::Distributions` (r:0 w:1)
+    /// The range of component `e` is `[1, 256]`.
+    fn distribute(e: u32) -> Weight {
+        Weight::from_parts(55_000_000, 4_000)
+            .saturating_add(Weight::from_parts(6_500_000, 3_200).saturating_mul(e.into()))
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().reads((2_u64).saturating_mul(e.into())))
+            .saturating_add(ParityDbWeight::get().writes(5_u64))
+    }
+    /// Storage: `Royalties::Splits` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:c w:c)
+    /// Storage: `Royalties::Statements` (r:1 w:1)
+    /// Storage: `Royalties::Ledger` (r:0 w:1)
+    /// Storage: `Royalties::Earnings` (r:c w:c)
+    /// The range of component `c` is `[1, 32]`.
+    fn pay_entry(c: u32) -> Weight {
+        Weight::from_parts(28_000_000, 4_000)
+            // Up to two transfers per collaborator: their net part and the fee.
+            .saturating_add(Weight::from_parts(95_000_000, 5_800).saturating_mul(c.into()))
+            .saturating_add(ParityDbWeight::get().reads(4_u64))
+            .saturating_add(ParityDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+            .saturating_add(ParityDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+    }
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Royalties::Distributions` (r:0 w:1)
+    /// Storage: `Royalties::TotalDistributed` (r:1 w:1)
+    fn complete_distribution() -> Weight {
+        Weight::from_parts(37_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Royalties::Statements` (r:1 w:1)
+    /// Storage: `Royalties::Distributions` (r:1 w:0)
+    /// Storage: `Royalties::Ledger` (r:0 w:e)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `e` is `[1, 256]`.
+    fn prune_statement(e: u32) -> Weight {
+        Weight::from_parts(30_000_000, 4_000)
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(e.into()))
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(e.into()))
+    }
+    /// Storage: `Assets` registry (r:1 w:0)
+    /// Storage: `Royalties::AllowedPayers` (r:0 w:1)
+    /// The range of component `p` is `[0, 16]`.
+    fn set_allowed_payers(p: u32) -> Weight {
+        Weight::from_parts(16_000_000, 3_500)
+            .saturating_add(Weight::from_parts(150_000, 32).saturating_mul(p.into()))
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,145 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+
+fn funded<T: Config>(name: &'static str) -> T::AccountId {
+    let who: T::AccountId = account(name, 0, SEED);
+    T::Currency::set_balance(&who, BalanceOf::<T>::max_value() / 4u32.into());
+    who
+}
+
+fn bench_price<T: Config>() -> BalanceOf<T> {
+    T::MinPrice::get().max(T::Currency::minimum_balance()) * 1_000u32.into()
+}
+
+/// An open listing, with a limited supply, of an asset split among `parts` collaborators.
+fn open_listing<T: Config>(parts: u32) -> T::AccountId {
+    let seller = funded::<T>("seller");
+    let asset = T::BenchmarkHelper::create_asset(&seller, parts);
+    Pallet::<T>::list(
+        RawOrigin::Signed(seller.clone()).into(),
+        asset,
+        bench_price::<T>(),
+        Some(1_000),
+    )
+    .expect("owner lists its asset");
+    seller
+}
+
+/// A purchase of `open_listing`, with its price in escrow.
+fn escrowed_purchase<T: Config>(parts: u32) -> (T::AccountId, T::AccountId) {
+    let seller = open_listing::<T>(parts);
+    let buyer = funded::<T>("buyer");
+    Pallet::<T>::purchase(RawOrigin::Signed(buyer.clone()).into(), 0)
+        .expect("funded buyer purchases");
+    (seller, buyer)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn list() {
+        let seller = funded::<T>("seller");
+        let asset = T::BenchmarkHelper::create_asset(&seller, 1);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(seller),
+            asset,
+            bench_price::<T>(),
+            Some(1_000),
+        );
+
+        assert!(Listings::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn close() {
+        let seller = open_listing::<T>(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(seller), 0);
+
+        assert!(Listings::<T>::get(0).is_some_and(|listing| !listing.open));
+    }
+
+    #[benchmark]
+    fn purchase() {
+        open_listing::<T>(1);
+        let buyer = funded::<T>("buyer");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), 0);
+
+        assert!(Pallet::<T>::has_access(0, &buyer));
+    }
+
+    #[benchmark]
+    fn dispute() {
+        let (_, buyer) = escrowed_purchase::<T>(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), 0);
+
+        assert!(
+            Purchases::<T>::get(0, &buyer)
+                .is_some_and(|bought| bought.state == PurchaseState::Disputed)
+        );
+    }
+
+    #[benchmark]
+    fn refund() {
+        let (seller, buyer) = escrowed_purchase::<T>(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(seller), 0, buyer.clone());
+
+        assert!(!Pallet::<T>::has_access(0, &buyer));
+    }
+
+    /// Settlement along a split of `p` parts, the path `on_idle` takes for each purchase.
+    #[benchmark]
+    fn settle(p: Linear<1, { T::MaxSplitParts::get() }>) {
+        let (_, buyer) = escrowed_purchase::<T>(p);
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::RefundWindow::get())
+                .saturating_add(1u32.into()),
+        );
+        let caller = funded::<T>("caller");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, buyer.clone());
+
+        assert!(
+            Purchases::<T>::get(0, &buyer)
+                .is_some_and(|bought| bought.state == PurchaseState::Settled)
+        );
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
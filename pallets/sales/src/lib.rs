@@ -0,0 +1,708 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Sales
+//!
+//! Primary sales of releases to fans, the proceeds held in escrow until a refund window
+//! passes.
+//!
+//! ## Features
+//! - The owner of an asset (`Config::Assets`), e.g. a release's drop, lists it for sale at a
+//!   price, in a limited number of copies or not. A deposit is held while the listing is open.
+//! - A fan buys a listing once. The price is held from them and they get access right away:
+//!   their purchase is recorded, and `Config::Hooks` lets the runtime e.g. mint them the
+//!   release's NFT.
+//! - Until `Config::RefundWindow` has passed, the buyer may dispute the purchase, which
+//!   `Config::Hooks` hands to the runtime, and the arbitration origin resolves by refunding or
+//!   settling it. The seller may refund a purchase until it is settled.
+//! - Once the window has passed, undisputed purchases are settled in `on_idle`, or by anyone
+//!   with `settle`: the price is paid along the asset's royalty split (`Config::Splits`), or to
+//!   the seller if it has none or a part cannot be paid.
+//! - A refund revokes the buyer's access and puts the copy back on sale.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use alloc::{vec, vec::Vec};
+use frame_support::{
+    pallet_prelude::*,
+    storage::with_storage_layer,
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{Fortitude, Precision, Restriction},
+    },
+};
+use frame_system::pallet_prelude::*;
+use pallet_royalties::{AssetOwnership, OnPayout};
+use sp_runtime::{Saturating, traits::Zero};
+
+pub type ListingId = u32;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type ListingOf<T> =
+    Listing<<T as frame_system::Config>::AccountId, <T as Config>::AssetId, BalanceOf<T>>;
+
+pub type PurchaseOf<T> = Purchase<BalanceOf<T>, BlockNumberFor<T>>;
+
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum PurchaseState {
+    /// The price is held from the buyer until the refund window passes.
+    Escrowed,
+    /// The buyer disputes the purchase; the arbitration origin decides.
+    Disputed,
+    /// The price was paid out. The record stays as the buyer's proof of access.
+    Settled,
+}
+
+/// A release put on sale.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Listing<AccountId, AssetId, Balance> {
+    /// Owner of the asset at listing time, paid the proceeds if the asset has no split.
+    pub seller: AccountId,
+    pub asset: AssetId,
+    pub price: Balance,
+    /// Copies left for sale, `None` if unlimited.
+    pub remaining: Option<u32>,
+    /// Amount held from the seller while the listing is open.
+    pub deposit: Balance,
+    /// Whether fans can still buy. Closed listings are kept until their purchases settle.
+    pub open: bool,
+}
+
+/// A fan's purchase of a listing.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Purchase<Balance, BlockNumber> {
+    /// Held from the buyer until settlement.
+    pub price: Balance,
+    /// Last block at which the buyer may dispute the purchase.
+    pub refundable_until: BlockNumber,
+    pub state: PurchaseState,
+}
+
+/// The royalty split tables sale proceeds are paid along.
+pub trait RevenueSplits<AssetId, AccountId, Balance> {
+    /// `amount` divided among the collaborators of `asset`, the parts adding up to `amount`.
+    /// `None` if the asset has no split.
+    fn apportion(asset: &AssetId, amount: Balance) -> Option<Vec<(AccountId, Balance)>>;
+}
+
+/// No splits: the proceeds always go to the seller.
+impl<AssetId, AccountId, Balance> RevenueSplits<AssetId, AccountId, Balance> for () {
+    fn apportion(_: &AssetId, _: Balance) -> Option<Vec<(AccountId, Balance)>> {
+        None
+    }
+}
+
+/// Lets the runtime follow purchases, e.g. to mint and burn the release's NFT, or to open a
+/// case with an arbitration body.
+pub trait PurchaseHooks<AccountId> {
+    /// `buyer` bought `listing`. An error aborts the purchase.
+    fn on_purchase(listing: ListingId, buyer: &AccountId) -> DispatchResult;
+
+    /// The purchase of `listing` by `buyer` was refunded, revoking their access.
+    fn on_refund(listing: ListingId, buyer: &AccountId);
+
+    /// `buyer` disputed their purchase of `listing`.
+    fn on_dispute(listing: ListingId, buyer: &AccountId);
+}
+
+impl<AccountId> PurchaseHooks<AccountId> for () {
+    fn on_purchase(_: ListingId, _: &AccountId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn on_refund(_: ListingId, _: &AccountId) {}
+
+    fn on_dispute(_: ListingId, _: &AccountId) {}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, AccountId> {
+    /// Create an asset owned by `owner`, whose proceeds are split among `parts` collaborators,
+    /// and return its id.
+    fn create_asset(owner: &AccountId, parts: u32) -> AssetId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Identifier of a release, or of the asset it is sold as.
+        type AssetId: Parameter + Member + MaxEncodedLen;
+
+        /// Resolves who may list an asset.
+        type Assets: AssetOwnership<Self::AssetId, Self::AccountId>;
+
+        /// Splits the proceeds of a sale are paid along.
+        type Splits: RevenueSplits<Self::AssetId, Self::AccountId, BalanceOf<Self>>;
+
+        /// Notified of purchases, refunds and disputes.
+        type Hooks: PurchaseHooks<Self::AccountId>;
+
+        /// Notified of each part of the proceeds paid, with its beneficiary.
+        type OnPayout: OnPayout<Self::AccountId, BalanceOf<Self>>;
+
+        /// Origin allowed to resolve disputed purchases.
+        type ArbitrationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Amount held from the seller for each open listing.
+        #[pallet::constant]
+        type ListingDeposit: Get<BalanceOf<Self>>;
+
+        /// Lowest price a release can be listed at.
+        #[pallet::constant]
+        type MinPrice: Get<BalanceOf<Self>>;
+
+        /// Blocks during which a purchase can be disputed, before its price is paid out.
+        #[pallet::constant]
+        type RefundWindow: Get<BlockNumberFor<Self>>;
+
+        /// Max number of parts `Config::Splits` divides the proceeds of a sale into.
+        #[pallet::constant]
+        type MaxSplitParts: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Self::AccountId>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        ListingDeposit,
+        /// A purchase price awaiting the end of its refund window.
+        Purchase,
+    }
+
+    #[pallet::storage]
+    pub type Listings<T: Config> =
+        StorageMap<_, Twox64Concat, ListingId, ListingOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextListingId<T: Config> = StorageValue<_, ListingId, ValueQuery>;
+
+    /// Purchases of each listing, by buyer.
+    #[pallet::storage]
+    pub type Purchases<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ListingId,
+        Blake2_128Concat,
+        T::AccountId,
+        PurchaseOf<T>,
+        OptionQuery,
+    >;
+
+    /// Purchases to settle, with the end of their refund window. The window has the same
+    /// length for every purchase, so they are queued in the order it ends.
+    #[pallet::storage]
+    pub type SettlementQueue<T: Config> =
+        StorageMap<_, Twox64Concat, u32, (ListingId, T::AccountId, BlockNumberFor<T>), OptionQuery>;
+
+    /// Index of the first entry of `SettlementQueue`.
+    #[pallet::storage]
+    pub type QueueHead<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Index the next purchase is queued at.
+    #[pallet::storage]
+    pub type QueueTail<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        Listed {
+            listing: ListingId,
+            seller: T::AccountId,
+            asset: T::AssetId,
+            price: BalanceOf<T>,
+            supply: Option<u32>,
+        },
+        ListingClosed {
+            listing: ListingId,
+        },
+        Purchased {
+            listing: ListingId,
+            buyer: T::AccountId,
+            price: BalanceOf<T>,
+            refundable_until: BlockNumberFor<T>,
+        },
+        PurchaseDisputed {
+            listing: ListingId,
+            buyer: T::AccountId,
+        },
+        PurchaseRefunded {
+            listing: ListingId,
+            buyer: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A part of a purchase price was paid to a collaborator of the release, or its seller.
+        ProceedsPaid {
+            listing: ListingId,
+            beneficiary: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        PurchaseSettled {
+            listing: ListingId,
+            buyer: T::AccountId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NotAssetOwner,
+        PriceTooLow,
+        ZeroSupply,
+        ListingNotFound,
+        NotSeller,
+        ListingClosed,
+        /// The seller no longer owns the listed asset.
+        ListingStale,
+        SoldOut,
+        /// Sellers cannot buy their own listings.
+        SelfPurchase,
+        AlreadyPurchased,
+        PurchaseNotFound,
+        /// The purchase is not in a state allowing this action.
+        InvalidPurchaseState,
+        RefundWindowElapsed,
+        RefundWindowOpen,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::process_settlements(now, remaining_weight)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// List `asset` for sale at `price`, in `supply` copies or without limit. Asset owner
+        /// only.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::list())]
+        pub fn list(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            price: BalanceOf<T>,
+            supply: Option<u32>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                T::Assets::owner_of(&asset).as_ref() == Some(&who),
+                Error::<T>::NotAssetOwner
+            );
+            ensure!(price >= T::MinPrice::get(), Error::<T>::PriceTooLow);
+            ensure!(supply != Some(0), Error::<T>::ZeroSupply);
+
+            let deposit = T::ListingDeposit::get();
+            T::Currency::hold(&HoldReason::ListingDeposit.into(), &who, deposit)?;
+
+            let listing = NextListingId::<T>::get();
+            Listings::<T>::insert(
+                listing,
+                Listing {
+                    seller: who.clone(),
+                    asset: asset.clone(),
+                    price,
+                    remaining: supply,
+                    deposit,
+                    open: true,
+                },
+            );
+            NextListingId::<T>::put(listing.saturating_add(1));
+
+            Self::deposit_event(Event::Listed {
+                listing,
+                seller: who,
+                asset,
+                price,
+                supply,
+            });
+            Ok(())
+        }
+
+        /// Stop selling a listing and release its deposit. Seller only.
+        ///
+        /// Purchases already made are settled or refunded as usual.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::close())]
+        pub fn close(origin: OriginFor<T>, listing: ListingId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Listings::<T>::try_mutate(listing, |maybe_listing| -> DispatchResult {
+                let info = maybe_listing.as_mut().ok_or(Error::<T>::ListingNotFound)?;
+                ensure!(info.seller == who, Error::<T>::NotSeller);
+                ensure!(info.open, Error::<T>::ListingClosed);
+
+                T::Currency::release(
+                    &HoldReason::ListingDeposit.into(),
+                    &who,
+                    info.deposit,
+                    Precision::BestEffort,
+                )?;
+                info.open = false;
+                info.deposit = Zero::zero();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ListingClosed { listing });
+            Ok(())
+        }
+
+        /// Buy a copy of a listing. The price is held until the refund window passes.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::purchase())]
+        pub fn purchase(origin: OriginFor<T>, listing: ListingId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut info = Listings::<T>::get(listing).ok_or(Error::<T>::ListingNotFound)?;
+            ensure!(info.open, Error::<T>::ListingClosed);
+            ensure!(
+                T::Assets::owner_of(&info.asset).as_ref() == Some(&info.seller),
+                Error::<T>::ListingStale
+            );
+            ensure!(info.seller != who, Error::<T>::SelfPurchase);
+            ensure!(
+                !Purchases::<T>::contains_key(listing, &who),
+                Error::<T>::AlreadyPurchased
+            );
+            if let Some(remaining) = info.remaining.as_mut() {
+                *remaining = remaining.checked_sub(1).ok_or(Error::<T>::SoldOut)?;
+                Listings::<T>::insert(listing, &info);
+            }
+
+            T::Currency::hold(&HoldReason::Purchase.into(), &who, info.price)?;
+            T::Hooks::on_purchase(listing, &who)?;
+
+            let refundable_until =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::RefundWindow::get());
+            Purchases::<T>::insert(
+                listing,
+                &who,
+                Purchase {
+                    price: info.price,
+                    refundable_until,
+                    state: PurchaseState::Escrowed,
+                },
+            );
+            let tail = QueueTail::<T>::get();
+            SettlementQueue::<T>::insert(tail, (listing, who.clone(), refundable_until));
+            QueueTail::<T>::put(tail.saturating_add(1));
+
+            Self::deposit_event(Event::Purchased {
+                listing,
+                buyer: who,
+                price: info.price,
+                refundable_until,
+            });
+            Ok(())
+        }
+
+        /// Dispute a purchase within its refund window, leaving it to the arbitration origin.
+        /// Buyer only.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::dispute())]
+        pub fn dispute(origin: OriginFor<T>, listing: ListingId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Purchases::<T>::try_mutate(listing, &who, |maybe_purchase| -> DispatchResult {
+                let purchase = maybe_purchase
+                    .as_mut()
+                    .ok_or(Error::<T>::PurchaseNotFound)?;
+                ensure!(
+                    purchase.state == PurchaseState::Escrowed,
+                    Error::<T>::InvalidPurchaseState
+                );
+                ensure!(
+                    frame_system::Pallet::<T>::block_number() <= purchase.refundable_until,
+                    Error::<T>::RefundWindowElapsed
+                );
+                purchase.state = PurchaseState::Disputed;
+                Ok(())
+            })?;
+            T::Hooks::on_dispute(listing, &who);
+
+            Self::deposit_event(Event::PurchaseDisputed {
+                listing,
+                buyer: who,
+            });
+            Ok(())
+        }
+
+        /// Refund a purchase not settled yet, disputed or not. Seller only.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::refund())]
+        pub fn refund(
+            origin: OriginFor<T>,
+            listing: ListingId,
+            buyer: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let info = Listings::<T>::get(listing).ok_or(Error::<T>::ListingNotFound)?;
+            ensure!(info.seller == who, Error::<T>::NotSeller);
+            Self::do_refund(listing, info, buyer)
+        }
+
+        /// Resolve a disputed purchase: refund the buyer, or settle it.
+        #[pallet::call_index(5)]
+        #[pallet::weight(
+            T::WeightInfo::refund().max(T::WeightInfo::settle(T::MaxSplitParts::get()))
+        )]
+        pub fn resolve(
+            origin: OriginFor<T>,
+            listing: ListingId,
+            buyer: T::AccountId,
+            refund: bool,
+        ) -> DispatchResult {
+            T::ArbitrationOrigin::ensure_origin(origin)?;
+
+            let info = Listings::<T>::get(listing).ok_or(Error::<T>::ListingNotFound)?;
+            let purchase =
+                Purchases::<T>::get(listing, &buyer).ok_or(Error::<T>::PurchaseNotFound)?;
+            ensure!(
+                purchase.state == PurchaseState::Disputed,
+                Error::<T>::InvalidPurchaseState
+            );
+            if refund {
+                Self::do_refund(listing, info, buyer)
+            } else {
+                Self::do_settle(listing, &info, buyer, purchase)
+            }
+        }
+
+        /// Pay out an undisputed purchase whose refund window has passed, if `on_idle` did not
+        /// already.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::settle(T::MaxSplitParts::get()))]
+        pub fn settle(
+            origin: OriginFor<T>,
+            listing: ListingId,
+            buyer: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let info = Listings::<T>::get(listing).ok_or(Error::<T>::ListingNotFound)?;
+            let purchase =
+                Purchases::<T>::get(listing, &buyer).ok_or(Error::<T>::PurchaseNotFound)?;
+            ensure!(
+                purchase.state == PurchaseState::Escrowed,
+                Error::<T>::InvalidPurchaseState
+            );
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > purchase.refundable_until,
+                Error::<T>::RefundWindowOpen
+            );
+            Self::do_settle(listing, &info, buyer, purchase)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Whether `who` bought `listing`, and was not refunded.
+        pub fn has_access(listing: ListingId, who: &T::AccountId) -> bool {
+            Purchases::<T>::contains_key(listing, who)
+        }
+
+        /// Weight of settling one queued purchase in `on_idle`, whatever the size of its split:
+        /// reading and removing its queue entry, then paying it out like `settle`.
+        pub fn settlement_step_weight() -> Weight {
+            T::DbWeight::get()
+                .reads_writes(1, 1)
+                .saturating_add(T::WeightInfo::settle(T::MaxSplitParts::get()))
+        }
+
+        /// Settle the queued purchases whose refund window has passed, within
+        /// `remaining_weight`, returning the weight used.
+        ///
+        /// Entries of purchases refunded, disputed or already settled are dropped. A purchase
+        /// that cannot be paid out is left to `settle`.
+        pub(crate) fn process_settlements(
+            now: BlockNumberFor<T>,
+            remaining_weight: Weight,
+        ) -> Weight {
+            let step_weight = Self::settlement_step_weight();
+            // The queue bounds, and the entry at its head that may not be due yet.
+            let mut used = T::DbWeight::get().reads(3);
+            if remaining_weight.any_lt(used) {
+                return Weight::zero();
+            }
+
+            let mut head = QueueHead::<T>::get();
+            let tail = QueueTail::<T>::get();
+            while head < tail {
+                if remaining_weight.any_lt(used.saturating_add(step_weight)) {
+                    break;
+                }
+                let Some((listing, buyer, refundable_until)) = SettlementQueue::<T>::get(head)
+                else {
+                    // Cannot happen, see `QueueTail`; skip rather than stall the queue.
+                    head.saturating_inc();
+                    continue;
+                };
+                if refundable_until >= now {
+                    break;
+                }
+                used.saturating_accrue(step_weight);
+
+                // A purchase refunded then made again is queued anew; its earlier entry no
+                // longer matches it.
+                if let (Some(info), Some(purchase)) = (
+                    Listings::<T>::get(listing),
+                    Purchases::<T>::get(listing, &buyer),
+                ) && purchase.state == PurchaseState::Escrowed
+                    && purchase.refundable_until == refundable_until
+                {
+                    let _ = Self::do_settle(listing, &info, buyer, purchase);
+                }
+                SettlementQueue::<T>::remove(head);
+                head.saturating_inc();
+            }
+
+            QueueHead::<T>::put(head);
+            used.saturating_add(T::DbWeight::get().writes(1))
+        }
+
+        /// Give the price of `buyer`'s purchase back, revoke their access and put the copy
+        /// back on sale.
+        fn do_refund(
+            listing: ListingId,
+            info: ListingOf<T>,
+            buyer: T::AccountId,
+        ) -> DispatchResult {
+            let purchase =
+                Purchases::<T>::get(listing, &buyer).ok_or(Error::<T>::PurchaseNotFound)?;
+            ensure!(
+                purchase.state != PurchaseState::Settled,
+                Error::<T>::InvalidPurchaseState
+            );
+
+            T::Currency::release(
+                &HoldReason::Purchase.into(),
+                &buyer,
+                purchase.price,
+                Precision::BestEffort,
+            )?;
+            Purchases::<T>::remove(listing, &buyer);
+            if let Some(remaining) = info.remaining {
+                Listings::<T>::insert(
+                    listing,
+                    Listing {
+                        remaining: Some(remaining.saturating_add(1)),
+                        ..info
+                    },
+                );
+            }
+            T::Hooks::on_refund(listing, &buyer);
+
+            Self::deposit_event(Event::PurchaseRefunded {
+                listing,
+                buyer,
+                amount: purchase.price,
+            });
+            Ok(())
+        }
+
+        /// Pay the price of `buyer`'s purchase along the split of the listed asset, or to the
+        /// seller if it has none or a part cannot be paid.
+        fn do_settle(
+            listing: ListingId,
+            info: &ListingOf<T>,
+            buyer: T::AccountId,
+            mut purchase: PurchaseOf<T>,
+        ) -> DispatchResult {
+            let to_seller = vec![(info.seller.clone(), purchase.price)];
+            let parts = T::Splits::apportion(&info.asset, purchase.price)
+                .unwrap_or_else(|| to_seller.clone());
+            with_storage_layer(|| Self::pay(listing, &buyer, &parts))
+                .or_else(|_| Self::pay(listing, &buyer, &to_seller))?;
+
+            purchase.state = PurchaseState::Settled;
+            Purchases::<T>::insert(listing, &buyer, purchase);
+
+            Self::deposit_event(Event::PurchaseSettled { listing, buyer });
+            Ok(())
+        }
+
+        /// Pay `parts` out of the price held from `buyer`.
+        fn pay(
+            listing: ListingId,
+            buyer: &T::AccountId,
+            parts: &[(T::AccountId, BalanceOf<T>)],
+        ) -> DispatchResult {
+            for (beneficiary, amount) in parts.iter().filter(|(_, amount)| !amount.is_zero()) {
+                T::Currency::transfer_on_hold(
+                    &HoldReason::Purchase.into(),
+                    buyer,
+                    beneficiary,
+                    *amount,
+                    Precision::Exact,
+                    Restriction::Free,
+                    Fortitude::Polite,
+                )?;
+                T::OnPayout::on_payout(beneficiary, *amount);
+                Self::deposit_event(Event::ProceedsPaid {
+                    listing,
+                    beneficiary: beneficiary.clone(),
+                    amount: *amount,
+                });
+            }
+            Ok(())
+        }
+    }
+}
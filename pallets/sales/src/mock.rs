@@ -0,0 +1,189 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_sales;
+use frame_support::{derive_impl, parameter_types, sp_runtime::BuildStorage, storage::unhashed};
+use frame_system::EnsureRoot;
+use parity_scale_codec::Encode;
+use sp_runtime::Perbill;
+
+pub type Balance = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type Sales = pallet_sales;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+    type ExistentialDeposit = ExistentialDeposit;
+}
+
+pub const LISTING_DEPOSIT: Balance = 5;
+pub const REFUND_WINDOW: u64 = 10;
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 2;
+    pub const ListingDeposit: Balance = LISTING_DEPOSIT;
+    pub const MinPrice: Balance = 10;
+    pub const RefundWindow: u64 = REFUND_WINDOW;
+    pub const MaxSplitParts: u32 = 4;
+}
+
+/// Asset owners and splits, kept in the test externalities so that each test starts from
+/// genesis.
+pub struct MockAssets;
+
+impl MockAssets {
+    fn owner_key(asset: u32) -> Vec<u8> {
+        (b"mock/asset-owner", asset).encode()
+    }
+
+    fn split_key(asset: u32) -> Vec<u8> {
+        (b"mock/asset-split", asset).encode()
+    }
+
+    pub fn set_owner(asset: u32, owner: u64) {
+        unhashed::put(&Self::owner_key(asset), &owner);
+    }
+
+    pub fn set_split(asset: u32, shares: Vec<(u64, Perbill)>) {
+        unhashed::put(&Self::split_key(asset), &shares);
+    }
+}
+
+impl pallet_royalties::AssetOwnership<u32, u64> for MockAssets {
+    fn owner_of(asset: &u32) -> Option<u64> {
+        unhashed::get(&Self::owner_key(*asset))
+    }
+}
+
+impl crate::RevenueSplits<u32, u64, Balance> for MockAssets {
+    fn apportion(asset: &u32, amount: Balance) -> Option<Vec<(u64, Balance)>> {
+        let shares: Vec<(u64, Perbill)> = unhashed::get(&Self::split_key(*asset))?;
+        let mut parts: Vec<_> = shares
+            .into_iter()
+            .map(|(who, share)| (who, share.mul_floor(amount)))
+            .collect();
+        let paid: Balance = parts.iter().map(|(_, part)| part).sum();
+        parts[0].1 += amount - paid;
+        Some(parts)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u32, u64> for MockAssets {
+    fn create_asset(owner: &u64, parts: u32) -> u32 {
+        Self::set_owner(0, *owner);
+        Self::set_split(
+            0,
+            (0..parts)
+                .map(|i| (1_000 + u64::from(i), Perbill::from_rational(1, parts)))
+                .collect(),
+        );
+        0
+    }
+}
+
+impl pallet_sales::Config for Test {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetId = u32;
+    type Assets = MockAssets;
+    type Splits = MockAssets;
+    type Hooks = ();
+    type OnPayout = ();
+    type ArbitrationOrigin = EnsureRoot<u64>;
+    type ListingDeposit = ListingDeposit;
+    type MinPrice = MinPrice;
+    type RefundWindow = RefundWindow;
+    type MaxSplitParts = MaxSplitParts;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockAssets;
+}
+
+pub const ARTIST: u64 = 1;
+pub const PRODUCER: u64 = 2;
+pub const FAN: u64 = 3;
+pub const OTHER_FAN: u64 = 4;
+
+/// Owned by `ARTIST`, without a split.
+pub const SINGLE: u32 = 1;
+/// Owned by `ARTIST`, split 70/30 with `PRODUCER`.
+pub const ALBUM: u32 = 2;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ARTIST, 100),
+            (PRODUCER, 100),
+            (FAN, 1_000),
+            (OTHER_FAN, 1_000),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        MockAssets::set_owner(SINGLE, ARTIST);
+        MockAssets::set_owner(ALBUM, ARTIST);
+        MockAssets::set_split(
+            ALBUM,
+            vec![
+                (ARTIST, Perbill::from_percent(70)),
+                (PRODUCER, Perbill::from_percent(30)),
+            ],
+        );
+    });
+    ext
+}
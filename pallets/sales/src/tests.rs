@@ -0,0 +1,343 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Error, Event, HoldReason, Listings, Pallet, PurchaseState, Purchases, QueueHead, mock::*,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Hooks, fungible::InspectHold},
+};
+use sp_runtime::{Perbill, Weight};
+
+const PRICE: Balance = 20;
+
+fn list(asset: u32, supply: Option<u32>) -> sp_runtime::DispatchResult {
+    Sales::list(RuntimeOrigin::signed(ARTIST), asset, PRICE, supply)
+}
+
+fn held(who: u64) -> Balance {
+    Balances::balance_on_hold(&HoldReason::Purchase.into(), &who)
+}
+
+fn state(listing: u32, buyer: u64) -> Option<PurchaseState> {
+    Purchases::<Test>::get(listing, buyer).map(|purchase| purchase.state)
+}
+
+/// Move past the refund window of the purchases made so far and run `on_idle`.
+fn close_refund_window() {
+    let now = System::block_number() + REFUND_WINDOW + 1;
+    System::set_block_number(now);
+    Sales::on_idle(now, Weight::MAX);
+}
+
+#[test]
+fn owners_list_their_assets() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(list(SINGLE, Some(2)));
+
+        let listing = Listings::<Test>::get(0).unwrap();
+        assert_eq!(listing.seller, ARTIST);
+        assert_eq!(listing.remaining, Some(2));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDeposit.into(), &ARTIST),
+            LISTING_DEPOSIT
+        );
+        System::assert_last_event(
+            Event::Listed {
+                listing: 0,
+                seller: ARTIST,
+                asset: SINGLE,
+                price: PRICE,
+                supply: Some(2),
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Sales::list(RuntimeOrigin::signed(FAN), SINGLE, PRICE, None),
+            Error::<Test>::NotAssetOwner
+        );
+        assert_noop!(
+            Sales::list(RuntimeOrigin::signed(ARTIST), SINGLE, 9, None),
+            Error::<Test>::PriceTooLow
+        );
+        assert_noop!(list(SINGLE, Some(0)), Error::<Test>::ZeroSupply);
+    });
+}
+
+#[test]
+fn purchases_hold_the_price_and_grant_access() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(list(SINGLE, Some(1)));
+
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(FAN), 0));
+        assert_eq!(held(FAN), PRICE);
+        assert!(Pallet::<Test>::has_access(0, &FAN));
+        assert_eq!(state(0, FAN), Some(PurchaseState::Escrowed));
+        System::assert_last_event(
+            Event::Purchased {
+                listing: 0,
+                buyer: FAN,
+                price: PRICE,
+                refundable_until: 1 + REFUND_WINDOW,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Sales::purchase(RuntimeOrigin::signed(FAN), 0),
+            Error::<Test>::AlreadyPurchased
+        );
+        assert_noop!(
+            Sales::purchase(RuntimeOrigin::signed(OTHER_FAN), 0),
+            Error::<Test>::SoldOut
+        );
+        assert_noop!(
+            Sales::purchase(RuntimeOrigin::signed(ARTIST), 0),
+            Error::<Test>::SelfPurchase
+        );
+    });
+}
+
+#[test]
+fn closed_or_stale_listings_cannot_be_bought() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(list(SINGLE, None));
+        assert_ok!(list(ALBUM, None));
+
+        assert_noop!(
+            Sales::close(RuntimeOrigin::signed(FAN), 0),
+            Error::<Test>::NotSeller
+        );
+        assert_ok!(Sales::close(RuntimeOrigin::signed(ARTIST), 0));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDeposit.into(), &ARTIST),
+            LISTING_DEPOSIT
+        );
+        assert_noop!(
+            Sales::purchase(RuntimeOrigin::signed(FAN), 0),
+            Error::<Test>::ListingClosed
+        );
+
+        MockAssets::set_owner(ALBUM, PRODUCER);
+        assert_noop!(
+            Sales::purchase(RuntimeOrigin::signed(FAN), 1),
+            Error::<Test>::ListingStale
+        );
+    });
+}
+
+#[test]
+fn proceeds_are_paid_along_the_split_once_the_window_passes() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(list(ALBUM, None));
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(FAN), 0));
+
+        // Still within the window.
+        System::set_block_number(1 + REFUND_WINDOW);
+        Sales::on_idle(1 + REFUND_WINDOW, Weight::MAX);
+        assert_eq!(state(0, FAN), Some(PurchaseState::Escrowed));
+        assert_noop!(
+            Sales::settle(RuntimeOrigin::signed(OTHER_FAN), 0, FAN),
+            Error::<Test>::RefundWindowOpen
+        );
+
+        close_refund_window();
+        assert_eq!(state(0, FAN), Some(PurchaseState::Settled));
+        assert!(Pallet::<Test>::has_access(0, &FAN));
+        assert_eq!(held(FAN), 0);
+        assert_eq!(Balances::free_balance(FAN), 1_000 - PRICE);
+        assert_eq!(Balances::free_balance(ARTIST), 100 - LISTING_DEPOSIT + 14);
+        assert_eq!(Balances::free_balance(PRODUCER), 100 + 6);
+        assert_eq!(QueueHead::<Test>::get(), 1);
+
+        assert_noop!(
+            Sales::settle(RuntimeOrigin::signed(OTHER_FAN), 0, FAN),
+            Error::<Test>::InvalidPurchaseState
+        );
+    });
+}
+
+#[test]
+fn proceeds_go_to_the_seller_without_a_split() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(list(SINGLE, None));
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(FAN), 0));
+
+        // Left to `settle` by a block without idle weight.
+        let now = 2 + REFUND_WINDOW;
+        System::set_block_number(now);
+        Sales::on_idle(now, Weight::zero());
+        assert_ok!(Sales::settle(RuntimeOrigin::signed(OTHER_FAN), 0, FAN));
+
+        assert_eq!(
+            Balances::free_balance(ARTIST),
+            100 - LISTING_DEPOSIT + PRICE
+        );
+        System::assert_last_event(
+            Event::PurchaseSettled {
+                listing: 0,
+                buyer: FAN,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn proceeds_go_to_the_seller_when_a_part_cannot_be_paid() {
+    new_test_ext().execute_with(|| {
+        // 10% of the price is below the existential deposit of a new account.
+        MockAssets::set_split(
+            SINGLE,
+            vec![
+                (ARTIST, Perbill::from_percent(95)),
+                (42, Perbill::from_percent(5)),
+            ],
+        );
+        assert_ok!(list(SINGLE, None));
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(FAN), 0));
+
+        close_refund_window();
+
+        assert_eq!(state(0, FAN), Some(PurchaseState::Settled));
+        assert_eq!(
+            Balances::free_balance(ARTIST),
+            100 - LISTING_DEPOSIT + PRICE
+        );
+        assert_eq!(Balances::free_balance(42), 0);
+    });
+}
+
+#[test]
+fn sellers_refund_unsettled_purchases() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(list(SINGLE, Some(1)));
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(FAN), 0));
+
+        assert_noop!(
+            Sales::refund(RuntimeOrigin::signed(FAN), 0, FAN),
+            Error::<Test>::NotSeller
+        );
+        assert_ok!(Sales::refund(RuntimeOrigin::signed(ARTIST), 0, FAN));
+        assert_eq!(Balances::free_balance(FAN), 1_000);
+        assert!(!Pallet::<Test>::has_access(0, &FAN));
+        // The copy is back on sale.
+        assert_eq!(Listings::<Test>::get(0).unwrap().remaining, Some(1));
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(OTHER_FAN), 0));
+
+        // Settled purchases can no longer be refunded.
+        close_refund_window();
+        assert_noop!(
+            Sales::refund(RuntimeOrigin::signed(ARTIST), 0, OTHER_FAN),
+            Error::<Test>::InvalidPurchaseState
+        );
+    });
+}
+
+#[test]
+fn refunded_then_repurchased_copies_settle_at_their_new_window() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(list(SINGLE, None));
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(FAN), 0));
+        assert_ok!(Sales::refund(RuntimeOrigin::signed(ARTIST), 0, FAN));
+
+        System::set_block_number(5);
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(FAN), 0));
+
+        // The first entry is dropped, the second is not due yet.
+        let now = 2 + REFUND_WINDOW;
+        System::set_block_number(now);
+        Sales::on_idle(now, Weight::MAX);
+        assert_eq!(state(0, FAN), Some(PurchaseState::Escrowed));
+        assert_eq!(QueueHead::<Test>::get(), 1);
+
+        close_refund_window();
+        assert_eq!(state(0, FAN), Some(PurchaseState::Settled));
+        assert_eq!(QueueHead::<Test>::get(), 2);
+    });
+}
+
+#[test]
+fn disputes_are_resolved_by_arbitration() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(list(ALBUM, None));
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(FAN), 0));
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(OTHER_FAN), 0));
+
+        assert_ok!(Sales::dispute(RuntimeOrigin::signed(FAN), 0));
+        assert_ok!(Sales::dispute(RuntimeOrigin::signed(OTHER_FAN), 0));
+        assert_eq!(state(0, FAN), Some(PurchaseState::Disputed));
+        assert_noop!(
+            Sales::dispute(RuntimeOrigin::signed(FAN), 0),
+            Error::<Test>::InvalidPurchaseState
+        );
+
+        // Disputed purchases are not settled when the window passes.
+        close_refund_window();
+        assert_eq!(state(0, FAN), Some(PurchaseState::Disputed));
+
+        assert_noop!(
+            Sales::resolve(RuntimeOrigin::signed(ARTIST), 0, FAN, true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Sales::resolve(RuntimeOrigin::root(), 0, FAN, true));
+        assert_eq!(Balances::free_balance(FAN), 1_000);
+        assert!(!Pallet::<Test>::has_access(0, &FAN));
+
+        assert_ok!(Sales::resolve(RuntimeOrigin::root(), 0, OTHER_FAN, false));
+        assert_eq!(state(0, OTHER_FAN), Some(PurchaseState::Settled));
+        assert_eq!(Balances::free_balance(PRODUCER), 100 + 6);
+    });
+}
+
+#[test]
+fn purchases_cannot_be_disputed_after_the_window() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(list(SINGLE, None));
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(FAN), 0));
+
+        System::set_block_number(2 + REFUND_WINDOW);
+        assert_noop!(
+            Sales::dispute(RuntimeOrigin::signed(FAN), 0),
+            Error::<Test>::RefundWindowElapsed
+        );
+        assert_noop!(
+            Sales::dispute(RuntimeOrigin::signed(OTHER_FAN), 0),
+            Error::<Test>::PurchaseNotFound
+        );
+    });
+}
+
+#[test]
+fn settlement_stops_when_idle_weight_runs_out() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(list(SINGLE, None));
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(FAN), 0));
+        assert_ok!(Sales::purchase(RuntimeOrigin::signed(OTHER_FAN), 0));
+
+        let now = 2 + REFUND_WINDOW;
+        System::set_block_number(now);
+        // Database accesses are free in the mock: room for a single settlement.
+        Sales::on_idle(now, Pallet::<Test>::settlement_step_weight());
+
+        assert_eq!(state(0, FAN), Some(PurchaseState::Settled));
+        assert_eq!(state(0, OTHER_FAN), Some(PurchaseState::Escrowed));
+    });
+}
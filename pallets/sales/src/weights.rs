@@ -0,0 +1,89 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_sales.
+pub trait WeightInfo {
+    fn list() -> Weight;
+    fn close() -> Weight;
+    fn purchase() -> Weight;
+    fn dispute() -> Weight;
+    fn refund() -> Weight;
+    fn settle(p: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Assets` registry (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Sales::NextListingId` (r:1 w:1)
+    /// Storage: `Sales::Listings` (r:0 w:1)
+    fn list() -> Weight {
+        Weight::from_parts(45_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Sales::Listings` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn close() -> Weight {
+        Weight::from_parts(35_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Sales::Listings` (r:1 w:1)
+    /// Storage: `Assets` registry (r:1 w:0)
+    /// Storage: `Sales::Purchases` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Sales::QueueTail` (r:1 w:1)
+    /// Storage: `Sales::SettlementQueue` (r:0 w:1)
+    fn purchase() -> Weight {
+        Weight::from_parts(60_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(5_u64))
+            .saturating_add(ParityDbWeight::get().writes(5_u64))
+    }
+    /// Storage: `Sales::Purchases` (r:1 w:1)
+    fn dispute() -> Weight {
+        Weight::from_parts(20_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Sales::Listings` (r:1 w:1)
+    /// Storage: `Sales::Purchases` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn refund() -> Weight {
+        Weight::from_parts(40_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Sales::Listings` (r:1 w:0)
+    /// Storage: `Sales::Purchases` (r:1 w:1)
+    /// Storage: `Royalties::Splits` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1+p w:1+p)
+    /// The range of component `p` is `[1, 32]`.
+    fn settle(p: u32) -> Weight {
+        Weight::from_parts(45_000_000, 4_500)
+            // A transfer per part, and one to the seller if a part cannot be paid.
+            .saturating_add(Weight::from_parts(60_000_000, 2_600).saturating_mul(p.into()))
+            .saturating_add(ParityDbWeight::get().reads(5_u64))
+            .saturating_add(ParityDbWeight::get().reads(p.into()))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(p.into()))
+    }
+}
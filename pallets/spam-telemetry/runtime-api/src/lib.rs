@@ -0,0 +1,42 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API exposing the failed transaction counts of `pallet-spam-telemetry`, to quantify
+//! spam and attack attempts.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+pub use pallet_spam_telemetry::{EraIndex, FailureCategory};
+
+sp_api::decl_runtime_apis! {
+    pub trait SpamTelemetryApi {
+        /// The era of the current block.
+        fn current_era() -> EraIndex;
+
+        /// The failed transactions counted in `era`, by category.
+        fn failures(era: EraIndex) -> Vec<(FailureCategory, u32)>;
+
+        /// The failed transactions counted from era `from` to era `to` included, summed by
+        /// category. Eras out of the kept history count as empty.
+        fn failures_between(from: EraIndex, to: EraIndex) -> Vec<(FailureCategory, u32)>;
+    }
+}
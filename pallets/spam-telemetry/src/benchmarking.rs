@@ -0,0 +1,74 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use sp_runtime::{ModuleError, TokenError};
+
+/// The `i`-th category: pallet errors first, then the other variants.
+fn category(i: u32) -> DispatchError {
+    match i {
+        0..256 => DispatchError::Module(ModuleError {
+            index: i as u8,
+            error: [0; 4],
+            message: None,
+        }),
+        256 => DispatchError::BadOrigin,
+        257 => DispatchError::CannotLookup,
+        258 => DispatchError::Token(TokenError::FundsUnavailable),
+        259 => DispatchError::NoProviders,
+        260 => DispatchError::Arithmetic(sp_runtime::ArithmeticError::Overflow),
+        261 => DispatchError::Exhausted,
+        _ => DispatchError::Other(""),
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn count_failure() {
+        frame_system::Pallet::<T>::set_block_number(1u32.into());
+        let era = Pallet::<T>::current_era();
+
+        #[block]
+        {
+            Pallet::<T>::note_failure(&DispatchError::BadOrigin);
+        }
+
+        assert_eq!(Failures::<T>::get(era, FailureCategory::BadOrigin), 1);
+    }
+
+    #[benchmark]
+    fn on_initialize_prune(c: Linear<0, MAX_CATEGORIES>) {
+        for i in 0..c {
+            Failures::<T>::insert(0, FailureCategory::from(&category(i)), 1);
+        }
+        let now = T::EraLength::get().saturating_mul(T::HistoryDepth::get().into());
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(now);
+        }
+
+        assert_eq!(Failures::<T>::iter_prefix(0).count(), 0);
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
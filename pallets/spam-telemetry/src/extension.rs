@@ -0,0 +1,83 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Config, Pallet, WeightInfo};
+use core::marker::PhantomData;
+use frame_support::{
+    DefaultNoBound,
+    pallet_prelude::{Decode, DecodeWithMemTracking, Encode, TypeInfo},
+};
+use sp_runtime::{
+    DispatchResult, Weight, impl_tx_ext_default,
+    traits::{DispatchInfoOf, PostDispatchInfoOf, TransactionExtension},
+    transaction_validity::TransactionValidityError,
+};
+
+/// Counts the signed transactions whose call fails, under the category of their error.
+///
+/// Transactions are validated as usual: failures are only counted once included.
+#[derive(Encode, Decode, DecodeWithMemTracking, DefaultNoBound, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CountFailures<T>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> core::fmt::Debug for CountFailures<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CountFailures")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config + Send + Sync> CountFailures<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<T::RuntimeCall> for CountFailures<T> {
+    const IDENTIFIER: &'static str = "CountFailures";
+    type Implicit = ();
+    type Val = ();
+    type Pre = ();
+
+    fn weight(&self, _: &T::RuntimeCall) -> Weight {
+        T::WeightInfo::count_failure()
+    }
+
+    impl_tx_ext_default!(T::RuntimeCall; validate prepare);
+
+    fn post_dispatch_details(
+        _pre: Self::Pre,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+        result: &DispatchResult,
+    ) -> Result<Weight, TransactionValidityError> {
+        match result {
+            Ok(()) => Ok(T::WeightInfo::count_failure()),
+            Err(error) => {
+                Pallet::<T>::note_failure(error);
+                Ok(Weight::zero())
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Spam Telemetry
+//!
+//! Failed transactions counted per era and error category, to quantify spam and attack
+//! attempts and tune the protections against them on data.
+//!
+//! ## Features
+//! - The [`CountFailures`] transaction extension counts each signed transaction whose call
+//!   fails, under the [`FailureCategory`] of its error.
+//! - Eras are `Config::EraLength` blocks long. The counts of the last `Config::HistoryDepth`
+//!   eras are kept, older ones are pruned in `on_initialize`.
+//! - [`Pallet::failures`] and [`Pallet::failures_between`] back the `SpamTelemetryApi` runtime
+//!   API.
+//!
+//! Transactions the pool turns down (bad signature or nonce, unpayable fees...) never reach the
+//! state, and a block including one is invalid: on chain, a rejected transaction is one that was
+//! included and paid its fees but whose call failed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+mod extension;
+pub use extension::CountFailures;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_runtime::{SaturatedConversion, Saturating, traits::Zero};
+
+pub type EraIndex = u32;
+
+/// Categories an era can have counts for: one per pallet index, plus the other variants.
+pub const MAX_CATEGORIES: u32 = 256 + 7;
+
+/// What made a transaction fail, from its [`DispatchError`].
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum FailureCategory {
+    /// The origin may not make the call: `BadOrigin` or `RootNotAllowed`.
+    BadOrigin,
+    /// An account given in the call could not be looked up.
+    CannotLookup,
+    /// An error of the pallet at this index.
+    Module(u8),
+    /// Missing or frozen funds: `Token` errors.
+    Token,
+    /// The reference counts of an account: `ConsumerRemaining`, `NoProviders` or
+    /// `TooManyConsumers`.
+    Account,
+    /// An overflow, underflow or division by zero.
+    Arithmetic,
+    /// A resource or nesting limit: `Exhausted` or `Transactional` errors.
+    Exhausted,
+    /// Any other error.
+    Other,
+}
+
+impl From<&DispatchError> for FailureCategory {
+    fn from(error: &DispatchError) -> Self {
+        match error {
+            DispatchError::BadOrigin | DispatchError::RootNotAllowed => Self::BadOrigin,
+            DispatchError::CannotLookup => Self::CannotLookup,
+            DispatchError::Module(error) => Self::Module(error.index),
+            DispatchError::Token(_) => Self::Token,
+            DispatchError::ConsumerRemaining
+            | DispatchError::NoProviders
+            | DispatchError::TooManyConsumers => Self::Account,
+            DispatchError::Arithmetic(_) => Self::Arithmetic,
+            DispatchError::Exhausted | DispatchError::Transactional(_) => Self::Exhausted,
+            DispatchError::Other(_)
+            | DispatchError::Corruption
+            | DispatchError::Unavailable
+            | DispatchError::Trie(_) => Self::Other,
+        }
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Length of an era, in blocks.
+        #[pallet::constant]
+        type EraLength: Get<BlockNumberFor<Self>>;
+
+        /// Eras whose counts are kept, the current one included.
+        #[pallet::constant]
+        type HistoryDepth: Get<EraIndex>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Failed transactions per era and category.
+    #[pallet::storage]
+    pub type Failures<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, EraIndex, Twox64Concat, FailureCategory, u32, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            if !(now % T::EraLength::get()).is_zero() {
+                return Weight::zero();
+            }
+            // The era starting now pushes the oldest one out of the history.
+            let Some(expired) = Self::era_of(now).checked_sub(T::HistoryDepth::get()) else {
+                return Weight::zero();
+            };
+
+            let removed = Failures::<T>::clear_prefix(expired, MAX_CATEGORIES, None).unique;
+            T::WeightInfo::on_initialize_prune(removed)
+        }
+
+        fn integrity_test() {
+            assert!(
+                !T::EraLength::get().is_zero(),
+                "`EraLength` must not be zero"
+            );
+            assert!(
+                T::HistoryDepth::get() > 0,
+                "`HistoryDepth` must not be zero"
+            );
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The era `block` belongs to.
+        pub fn era_of(block: BlockNumberFor<T>) -> EraIndex {
+            (block / T::EraLength::get()).saturated_into()
+        }
+
+        /// The era of the current block.
+        pub fn current_era() -> EraIndex {
+            Self::era_of(frame_system::Pallet::<T>::block_number())
+        }
+
+        /// Counts a transaction that failed with `error` in the current era.
+        pub fn note_failure(error: &DispatchError) {
+            Failures::<T>::mutate(Self::current_era(), FailureCategory::from(error), |n| {
+                n.saturating_inc()
+            });
+        }
+
+        /// The failures counted in `era`, by category.
+        pub fn failures(era: EraIndex) -> Vec<(FailureCategory, u32)> {
+            Self::failures_between(era, era)
+        }
+
+        /// The failures counted from era `from` to era `to` included, summed by category.
+        ///
+        /// Eras out of the kept history count as empty.
+        pub fn failures_between(from: EraIndex, to: EraIndex) -> Vec<(FailureCategory, u32)> {
+            let current = Self::current_era();
+            let oldest = current.saturating_sub(T::HistoryDepth::get().saturating_sub(1));
+
+            let mut totals = BTreeMap::new();
+            for era in from.max(oldest)..=to.min(current) {
+                for (category, count) in Failures::<T>::iter_prefix(era) {
+                    totals
+                        .entry(category)
+                        .or_insert(0u32)
+                        .saturating_accrue(count);
+                }
+            }
+            totals.into_iter().collect()
+        }
+    }
+}
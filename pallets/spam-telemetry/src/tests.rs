@@ -0,0 +1,175 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CountFailures, FailureCategory, Failures, mock::*, weights::WeightInfo};
+use frame_support::dispatch::{DispatchInfo, PostDispatchInfo};
+use sp_runtime::{
+    ArithmeticError, DispatchError, ModuleError, TokenError, Weight, traits::DispatchTransaction,
+};
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+/// Includes a transaction of `ALICE` whose call ends with `result`.
+fn include(result: Result<(), DispatchError>) -> PostDispatchInfo {
+    let info = DispatchInfo::default();
+    let post_info = CountFailures::<Test>::new()
+        .test_run(Some(ALICE).into(), &remark(), &info, 0, 0, |_| {
+            result
+                .map(|()| PostDispatchInfo::default())
+                .map_err(|error| error.into())
+        })
+        .unwrap();
+    match post_info {
+        Ok(post_info) => post_info,
+        Err(e) => e.post_info,
+    }
+}
+
+fn module_error(index: u8) -> DispatchError {
+    DispatchError::Module(ModuleError {
+        index,
+        error: [1, 0, 0, 0],
+        message: None,
+    })
+}
+
+#[test]
+fn errors_are_grouped_by_category() {
+    let cases = [
+        (DispatchError::BadOrigin, FailureCategory::BadOrigin),
+        (DispatchError::RootNotAllowed, FailureCategory::BadOrigin),
+        (module_error(7), FailureCategory::Module(7)),
+        (
+            DispatchError::Token(TokenError::FundsUnavailable),
+            FailureCategory::Token,
+        ),
+        (DispatchError::ConsumerRemaining, FailureCategory::Account),
+        (
+            DispatchError::Arithmetic(ArithmeticError::Overflow),
+            FailureCategory::Arithmetic,
+        ),
+        (DispatchError::Exhausted, FailureCategory::Exhausted),
+        (DispatchError::Other("oops"), FailureCategory::Other),
+    ];
+    for (error, category) in cases {
+        assert_eq!(FailureCategory::from(&error), category);
+    }
+}
+
+#[test]
+fn only_failed_calls_are_counted() {
+    new_test_ext().execute_with(|| {
+        include(Ok(()));
+        include(Err(DispatchError::BadOrigin));
+        include(Err(module_error(7)));
+        include(Err(module_error(7)));
+        include(Err(module_error(9)));
+
+        assert_eq!(
+            SpamTelemetry::failures(0),
+            vec![
+                (FailureCategory::BadOrigin, 1),
+                (FailureCategory::Module(7), 2),
+                (FailureCategory::Module(9), 1),
+            ]
+        );
+    });
+}
+
+#[test]
+fn successful_calls_are_refunded_the_counting_weight() {
+    new_test_ext().execute_with(|| {
+        let info = DispatchInfo {
+            call_weight: Weight::from_parts(1_000, 0),
+            extension_weight: <() as WeightInfo>::count_failure(),
+            ..Default::default()
+        };
+        let succeeded = CountFailures::<Test>::new()
+            .test_run(Some(ALICE).into(), &remark(), &info, 0, 0, |_| {
+                Ok(PostDispatchInfo::default())
+            })
+            .unwrap()
+            .unwrap();
+        assert_eq!(succeeded.actual_weight, Some(info.call_weight));
+
+        let failed = CountFailures::<Test>::new()
+            .test_run(Some(ALICE).into(), &remark(), &info, 0, 0, |_| {
+                Err(DispatchError::BadOrigin.into())
+            })
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            failed.post_info.actual_weight,
+            Some(info.call_weight + info.extension_weight)
+        );
+    });
+}
+
+#[test]
+fn failures_are_counted_in_the_era_of_their_block() {
+    new_test_ext().execute_with(|| {
+        include(Err(DispatchError::BadOrigin));
+        run_to_block(10);
+        include(Err(DispatchError::BadOrigin));
+        include(Err(DispatchError::Exhausted));
+        run_to_block(20);
+        include(Err(DispatchError::Exhausted));
+
+        assert_eq!(SpamTelemetry::current_era(), 2);
+        assert_eq!(
+            SpamTelemetry::failures(1),
+            vec![
+                (FailureCategory::BadOrigin, 1),
+                (FailureCategory::Exhausted, 1)
+            ]
+        );
+        assert_eq!(
+            SpamTelemetry::failures_between(0, 2),
+            vec![
+                (FailureCategory::BadOrigin, 2),
+                (FailureCategory::Exhausted, 2)
+            ]
+        );
+        assert_eq!(
+            SpamTelemetry::failures_between(2, u32::MAX),
+            vec![(FailureCategory::Exhausted, 1)]
+        );
+    });
+}
+
+#[test]
+fn eras_out_of_the_history_are_pruned() {
+    new_test_ext().execute_with(|| {
+        include(Err(DispatchError::BadOrigin));
+        run_to_block(10);
+        include(Err(DispatchError::BadOrigin));
+
+        // Era 3 starts: era 0 leaves the 3 eras of history.
+        run_to_block(30);
+        assert_eq!(Failures::<Test>::iter_prefix(0).count(), 0);
+        assert_eq!(
+            SpamTelemetry::failures_between(0, 3),
+            vec![(FailureCategory::BadOrigin, 1)]
+        );
+
+        run_to_block(40);
+        assert_eq!(Failures::<Test>::iter().count(), 0);
+    });
+}
@@ -0,0 +1,42 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_spam_telemetry.
+pub trait WeightInfo {
+    fn count_failure() -> Weight;
+    fn on_initialize_prune(c: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `SpamTelemetry::Failures` (r:1 w:1)
+    fn count_failure() -> Weight {
+        Weight::from_parts(6_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `SpamTelemetry::Failures` (r:0 w:`c`)
+    /// The range of component `c` is `[0, 263]`.
+    fn on_initialize_prune(c: u32) -> Weight {
+        Weight::from_parts(3_000_000, 0)
+            .saturating_add(Weight::from_parts(600_000, 0).saturating_mul(c.into()))
+            .saturating_add(ParityDbWeight::get().writes(c.into()))
+    }
+}
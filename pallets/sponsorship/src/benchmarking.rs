@@ -0,0 +1,87 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+
+/// A funded label sponsoring a beneficiary.
+fn sponsorship<T: Config>() -> (T::AccountId, T::AccountId) {
+    let label = funded::<T>("label");
+    let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+    Pallet::<T>::sponsor(
+        RawOrigin::Signed(label.clone()).into(),
+        beneficiary.clone(),
+        T::SponsoredCalls::default(),
+        T::SponsorshipDeposit::get(),
+    )
+    .expect("the label can afford the deposit");
+    (label, beneficiary)
+}
+
+fn funded<T: Config>(name: &'static str) -> T::AccountId {
+    let who: T::AccountId = account(name, 0, SEED);
+    T::Currency::set_balance(&who, BalanceOf::<T>::max_value() / 2u32.into());
+    who
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn sponsor() {
+        let label = funded::<T>("label");
+        let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(label),
+            beneficiary.clone(),
+            T::SponsoredCalls::default(),
+            T::SponsorshipDeposit::get(),
+        );
+
+        assert!(Sponsorships::<T>::contains_key(&beneficiary));
+    }
+
+    #[benchmark]
+    fn revoke() {
+        let (label, beneficiary) = sponsorship::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(label), beneficiary.clone());
+
+        assert!(!Sponsorships::<T>::contains_key(&beneficiary));
+    }
+
+    #[benchmark]
+    fn renounce() {
+        let (_, beneficiary) = sponsorship::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(beneficiary.clone()));
+
+        assert!(!Sponsorships::<T>::contains_key(&beneficiary));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
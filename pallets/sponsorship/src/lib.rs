@@ -0,0 +1,274 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Sponsorship
+//!
+//! Lets a sponsor account pay the transaction fees of other accounts, e.g. a label covering the
+//! onboarding costs of its roster.
+//!
+//! ## Features
+//! - A sponsor covers a beneficiary for one kind of calls (`Config::SponsoredCalls`), up to an
+//!   allowance of fees. A deposit is held from the sponsor for each sponsorship.
+//! - An account has at most one sponsor. The sponsor may change the calls and allowance or
+//!   revoke the sponsorship; the beneficiary may renounce it.
+//! - A beneficiary is given a sufficient reference while sponsored, so that a new account with
+//!   no funds can sign transactions: without it, `frame_system::CheckNonce` rejects them before
+//!   the fee is charged.
+//! - [`ChargeSponsored`] wraps the runtime's `OnChargeTransaction` to charge the fees of covered
+//!   calls to the sponsor. Untipped transactions only: a tip is the signer's own choice, so
+//!   tipped transactions are paid by the signer. Once the allowance can't cover a fee, the
+//!   signer pays it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod payment;
+pub use payment::ChargeSponsored;
+
+pub mod migrations;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        InstanceFilter,
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::Precision,
+    },
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::Zero;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type SponsorshipOf<T> = Sponsorship<
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::SponsoredCalls,
+    BalanceOf<T>,
+>;
+
+/// A sponsor's commitment to pay the fees of a beneficiary.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Sponsorship<AccountId, Calls, Balance> {
+    pub sponsor: AccountId,
+    /// Calls the sponsor pays the fees of.
+    pub calls: Calls,
+    /// Fees the sponsor still agrees to pay.
+    pub allowance: Balance,
+    /// Amount held from the sponsor for this sponsorship.
+    pub deposit: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Kinds of calls a sponsor can cover. The default one is used by benchmarks.
+        type SponsoredCalls: Parameter
+            + Member
+            + MaxEncodedLen
+            + Default
+            + InstanceFilter<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Amount held from the sponsor for each sponsorship.
+        #[pallet::constant]
+        type SponsorshipDeposit: Get<BalanceOf<Self>>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        Sponsorship,
+    }
+
+    /// Sponsorships, keyed by beneficiary.
+    #[pallet::storage]
+    pub type Sponsorships<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SponsorshipOf<T>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `sponsor` covers `beneficiary`, or changed the terms of an existing sponsorship.
+        Sponsored {
+            sponsor: T::AccountId,
+            beneficiary: T::AccountId,
+            calls: T::SponsoredCalls,
+            allowance: BalanceOf<T>,
+        },
+        SponsorshipEnded {
+            sponsor: T::AccountId,
+            beneficiary: T::AccountId,
+        },
+        /// `sponsor` paid `fee` for a transaction of `beneficiary`.
+        FeePaid {
+            sponsor: T::AccountId,
+            beneficiary: T::AccountId,
+            fee: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        CannotSponsorSelf,
+        /// The beneficiary is already covered by another sponsor.
+        AlreadySponsored,
+        NotSponsored,
+        /// The caller isn't the sponsor of the beneficiary.
+        NotSponsor,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Pay the fees of `beneficiary` for `calls`, up to `allowance`.
+        ///
+        /// Called again by the same sponsor, replaces the calls and allowance of the
+        /// sponsorship.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::sponsor())]
+        pub fn sponsor(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+            calls: T::SponsoredCalls,
+            allowance: BalanceOf<T>,
+        ) -> DispatchResult {
+            let sponsor = ensure_signed(origin)?;
+            ensure!(sponsor != beneficiary, Error::<T>::CannotSponsorSelf);
+
+            Sponsorships::<T>::try_mutate(&beneficiary, |maybe_sponsorship| -> DispatchResult {
+                match maybe_sponsorship {
+                    Some(sponsorship) => {
+                        ensure!(sponsorship.sponsor == sponsor, Error::<T>::AlreadySponsored);
+                        sponsorship.calls = calls.clone();
+                        sponsorship.allowance = allowance;
+                    }
+                    None => {
+                        let deposit = T::SponsorshipDeposit::get();
+                        T::Currency::hold(&HoldReason::Sponsorship.into(), &sponsor, deposit)?;
+                        frame_system::Pallet::<T>::inc_sufficients(&beneficiary);
+                        *maybe_sponsorship = Some(Sponsorship {
+                            sponsor: sponsor.clone(),
+                            calls: calls.clone(),
+                            allowance,
+                            deposit,
+                        });
+                    }
+                }
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::Sponsored {
+                sponsor,
+                beneficiary,
+                calls,
+                allowance,
+            });
+            Ok(())
+        }
+
+        /// Stop paying the fees of `beneficiary`. Sponsor only.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::revoke())]
+        pub fn revoke(origin: OriginFor<T>, beneficiary: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let sponsorship =
+                Sponsorships::<T>::get(&beneficiary).ok_or(Error::<T>::NotSponsored)?;
+            ensure!(sponsorship.sponsor == who, Error::<T>::NotSponsor);
+            Self::end(&beneficiary, sponsorship)
+        }
+
+        /// Drop the caller's sponsorship: they pay their own fees again.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::renounce())]
+        pub fn renounce(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let sponsorship = Sponsorships::<T>::get(&who).ok_or(Error::<T>::NotSponsored)?;
+            Self::end(&who, sponsorship)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn end(beneficiary: &T::AccountId, sponsorship: SponsorshipOf<T>) -> DispatchResult {
+            T::Currency::release(
+                &HoldReason::Sponsorship.into(),
+                &sponsorship.sponsor,
+                sponsorship.deposit,
+                Precision::BestEffort,
+            )?;
+            Sponsorships::<T>::remove(beneficiary);
+            frame_system::Pallet::<T>::dec_sufficients(beneficiary);
+
+            Self::deposit_event(Event::SponsorshipEnded {
+                sponsor: sponsorship.sponsor,
+                beneficiary: beneficiary.clone(),
+            });
+            Ok(())
+        }
+
+        /// The sponsor paying `fee` and `tip` for `who` to dispatch `call`, if any.
+        pub fn sponsor_of(
+            who: &T::AccountId,
+            call: &<T as frame_system::Config>::RuntimeCall,
+            fee: BalanceOf<T>,
+            tip: BalanceOf<T>,
+        ) -> Option<T::AccountId> {
+            if !tip.is_zero() {
+                return None;
+            }
+            Sponsorships::<T>::get(who)
+                .filter(|sponsorship| {
+                    sponsorship.allowance >= fee && sponsorship.calls.filter(call)
+                })
+                .map(|sponsorship| sponsorship.sponsor)
+        }
+
+        pub fn sponsorship(beneficiary: &T::AccountId) -> Option<SponsorshipOf<T>> {
+            Sponsorships::<T>::get(beneficiary)
+        }
+    }
+}
@@ -0,0 +1,62 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the pallet.
+
+pub mod v1 {
+    use crate::{Config, Pallet, Sponsorships};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+    };
+    use sp_runtime::Saturating;
+
+    /// Gives the beneficiaries sponsored before v1 the sufficient reference they hold while
+    /// sponsored.
+    pub type ReferenceBeneficiaries<T> = VersionedMigration<
+        0,
+        1,
+        UncheckedReferenceBeneficiaries<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// [`ReferenceBeneficiaries`] without the storage version check.
+    pub struct UncheckedReferenceBeneficiaries<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedReferenceBeneficiaries<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut beneficiaries = 0u64;
+            for beneficiary in Sponsorships::<T>::iter_keys() {
+                frame_system::Pallet::<T>::inc_sufficients(&beneficiary);
+                beneficiaries.saturating_inc();
+            }
+            T::DbWeight::get().reads_writes(beneficiaries.saturating_mul(2), beneficiaries)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_: sp_runtime::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Sponsorships::<T>::iter_keys()
+                    .all(|beneficiary| frame_system::Pallet::<T>::sufficients(&beneficiary) > 0),
+                "every beneficiary holds a sufficient reference"
+            );
+            Ok(())
+        }
+    }
+}
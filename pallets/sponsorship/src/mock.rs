@@ -0,0 +1,145 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_sponsorship;
+use frame_support::{
+    derive_impl,
+    pallet_prelude::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen},
+    parameter_types,
+    sp_runtime::BuildStorage,
+    traits::InstanceFilter,
+    weights::{FixedFee, IdentityFee},
+};
+use pallet_transaction_payment::FungibleAdapter;
+
+pub type Balance = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type TransactionPayment = pallet_transaction_payment;
+
+    #[runtime::pallet_index(3)]
+    pub type Sponsorship = pallet_sponsorship;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+}
+
+#[derive_impl(pallet_transaction_payment::config_preludes::TestDefaultConfig)]
+impl pallet_transaction_payment::Config for Test {
+    type OnChargeTransaction = crate::ChargeSponsored<Test, FungibleAdapter<Balances, ()>>;
+    // Keeps the base fee of an extrinsic within the mock balances.
+    type WeightToFee = FixedFee<1, Balance>;
+    type LengthToFee = IdentityFee<Balance>;
+}
+
+/// Remarks stand in for the calls sponsors want to cover, transfers for any other call.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    Default,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+)]
+pub enum MockCalls {
+    #[default]
+    Any,
+    Remarks,
+}
+
+impl InstanceFilter<RuntimeCall> for MockCalls {
+    fn filter(&self, call: &RuntimeCall) -> bool {
+        match self {
+            MockCalls::Any => true,
+            MockCalls::Remarks => {
+                matches!(call, RuntimeCall::System(frame_system::Call::remark { .. }))
+            }
+        }
+    }
+}
+
+pub const SPONSORSHIP_DEPOSIT: Balance = 10;
+
+parameter_types! {
+    pub const SponsorshipDeposit: Balance = SPONSORSHIP_DEPOSIT;
+}
+
+impl pallet_sponsorship::Config for Test {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type SponsoredCalls = MockCalls;
+    type SponsorshipDeposit = SponsorshipDeposit;
+    type WeightInfo = ();
+}
+
+pub const LABEL: u64 = 1;
+pub const ARTIST: u64 = 2;
+pub const OTHER_LABEL: u64 = 3;
+/// A new account with no funds.
+pub const NEWCOMER: u64 = 4;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(LABEL, 1_000), (ARTIST, 100), (OTHER_LABEL, 1_000)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
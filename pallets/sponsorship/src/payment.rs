@@ -0,0 +1,149 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `OnChargeTransaction` adapter charging sponsored fees to the sponsor.
+
+use core::marker::PhantomData;
+use frame_support::pallet_prelude::TransactionValidityError;
+use pallet_transaction_payment::{OnChargeTransaction, TxCreditHold};
+use sp_runtime::{
+    Saturating,
+    traits::{DispatchInfoOf, PostDispatchInfoOf},
+};
+
+use crate::{BalanceOf, Config, Event, Pallet, Sponsorships};
+
+type CallOf<T> = <T as frame_system::Config>::RuntimeCall;
+
+/// Charges the fees of sponsored transactions to the sponsor, and any other fees to the signer,
+/// through the `Inner` adapter, e.g. `FungibleAdapter`.
+///
+/// The whole fee is taken off the allowance up front; the part refunded after dispatch is
+/// credited back.
+pub struct ChargeSponsored<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T, Inner> OnChargeTransaction<T> for ChargeSponsored<T, Inner>
+where
+    T: Config + pallet_transaction_payment::Config,
+    Inner: OnChargeTransaction<T, Balance = BalanceOf<T>>,
+{
+    type Balance = BalanceOf<T>;
+    /// The wrapped adapter's liquidity info, with the sponsor and the fee charged to them.
+    type LiquidityInfo = (Inner::LiquidityInfo, Option<(T::AccountId, BalanceOf<T>)>);
+
+    fn withdraw_fee(
+        who: &T::AccountId,
+        call: &CallOf<T>,
+        dispatch_info: &DispatchInfoOf<CallOf<T>>,
+        fee_with_tip: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        let Some(sponsor) = Pallet::<T>::sponsor_of(who, call, fee_with_tip, tip) else {
+            let liquidity_info = Inner::withdraw_fee(who, call, dispatch_info, fee_with_tip, tip)?;
+            return Ok((liquidity_info, None));
+        };
+
+        let liquidity_info = Inner::withdraw_fee(&sponsor, call, dispatch_info, fee_with_tip, tip)?;
+        Sponsorships::<T>::mutate(who, |maybe_sponsorship| {
+            if let Some(sponsorship) = maybe_sponsorship {
+                sponsorship.allowance.saturating_reduce(fee_with_tip);
+            }
+        });
+        Ok((liquidity_info, Some((sponsor, fee_with_tip))))
+    }
+
+    fn can_withdraw_fee(
+        who: &T::AccountId,
+        call: &CallOf<T>,
+        dispatch_info: &DispatchInfoOf<CallOf<T>>,
+        fee_with_tip: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<(), TransactionValidityError> {
+        let payer = Pallet::<T>::sponsor_of(who, call, fee_with_tip, tip);
+        Inner::can_withdraw_fee(
+            payer.as_ref().unwrap_or(who),
+            call,
+            dispatch_info,
+            fee_with_tip,
+            tip,
+        )
+    }
+
+    fn correct_and_deposit_fee(
+        who: &T::AccountId,
+        dispatch_info: &DispatchInfoOf<CallOf<T>>,
+        post_info: &PostDispatchInfoOf<CallOf<T>>,
+        corrected_fee_with_tip: Self::Balance,
+        tip: Self::Balance,
+        (liquidity_info, sponsored): Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        let Some((sponsor, charged)) = sponsored else {
+            return Inner::correct_and_deposit_fee(
+                who,
+                dispatch_info,
+                post_info,
+                corrected_fee_with_tip,
+                tip,
+                liquidity_info,
+            );
+        };
+
+        Inner::correct_and_deposit_fee(
+            &sponsor,
+            dispatch_info,
+            post_info,
+            corrected_fee_with_tip,
+            tip,
+            liquidity_info,
+        )?;
+        // The call may have ended the sponsorship, or even let another sponsor take over.
+        Sponsorships::<T>::mutate(who, |maybe_sponsorship| {
+            if let Some(sponsorship) = maybe_sponsorship
+                && sponsorship.sponsor == sponsor
+            {
+                sponsorship
+                    .allowance
+                    .saturating_accrue(charged.saturating_sub(corrected_fee_with_tip));
+            }
+        });
+        Pallet::<T>::deposit_event(Event::FeePaid {
+            sponsor,
+            beneficiary: who.clone(),
+            fee: corrected_fee_with_tip,
+        });
+        Ok(())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn endow_account(who: &T::AccountId, amount: Self::Balance) {
+        Inner::endow_account(who, amount)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn minimum_balance() -> Self::Balance {
+        Inner::minimum_balance()
+    }
+}
+
+impl<T, Inner> TxCreditHold<T> for ChargeSponsored<T, Inner>
+where
+    T: Config + pallet_transaction_payment::Config,
+    Inner: TxCreditHold<T>,
+{
+    type Credit = Inner::Credit;
+}
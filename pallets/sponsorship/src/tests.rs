@@ -0,0 +1,279 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error, Event, HoldReason, Sponsorships, migrations, mock::*};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion, fungible::InspectHold},
+};
+use frame_system::CheckNonce;
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use sp_runtime::{
+    traits::DispatchTransaction,
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+type Adapter = <Test as pallet_transaction_payment::Config>::OnChargeTransaction;
+
+fn held(who: u64) -> Balance {
+    Balances::balance_on_hold(&HoldReason::Sponsorship.into(), &who)
+}
+
+fn allowance(who: u64) -> Balance {
+    Sponsorships::<Test>::get(who).unwrap().allowance
+}
+
+fn remark() -> RuntimeCall {
+    frame_system::Call::remark { remark: vec![] }.into()
+}
+
+fn transfer() -> RuntimeCall {
+    pallet_balances::Call::transfer_allow_death {
+        dest: LABEL,
+        value: 1,
+    }
+    .into()
+}
+
+/// Charge `fee` plus `tip` for `who` to dispatch `call`, refunding down to `corrected` after
+/// dispatch, the way the transaction payment extension does.
+fn pay(who: u64, call: RuntimeCall, fee: Balance, tip: Balance, corrected: Balance) {
+    let info = DispatchInfo::default();
+    let liquidity_info =
+        <Adapter as OnChargeTransaction<Test>>::withdraw_fee(&who, &call, &info, fee + tip, tip)
+            .unwrap();
+    <Adapter as OnChargeTransaction<Test>>::correct_and_deposit_fee(
+        &who,
+        &info,
+        &PostDispatchInfo::default(),
+        corrected + tip,
+        tip,
+        liquidity_info,
+    )
+    .unwrap();
+}
+
+#[test]
+fn sponsor_holds_deposit_and_can_update_terms() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Sponsorship::sponsor(
+            RuntimeOrigin::signed(LABEL),
+            ARTIST,
+            MockCalls::Remarks,
+            100
+        ));
+        assert_eq!(held(LABEL), SPONSORSHIP_DEPOSIT);
+        assert_eq!(allowance(ARTIST), 100);
+
+        assert_ok!(Sponsorship::sponsor(
+            RuntimeOrigin::signed(LABEL),
+            ARTIST,
+            MockCalls::Any,
+            300
+        ));
+        let sponsorship = Sponsorships::<Test>::get(ARTIST).unwrap();
+        assert_eq!(sponsorship.calls, MockCalls::Any);
+        assert_eq!(sponsorship.allowance, 300);
+        assert_eq!(held(LABEL), SPONSORSHIP_DEPOSIT);
+        System::assert_last_event(
+            Event::Sponsored {
+                sponsor: LABEL,
+                beneficiary: ARTIST,
+                calls: MockCalls::Any,
+                allowance: 300,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Sponsorship::sponsor(
+                RuntimeOrigin::signed(OTHER_LABEL),
+                ARTIST,
+                MockCalls::Any,
+                100
+            ),
+            Error::<Test>::AlreadySponsored
+        );
+        assert_noop!(
+            Sponsorship::sponsor(RuntimeOrigin::signed(LABEL), LABEL, MockCalls::Any, 100),
+            Error::<Test>::CannotSponsorSelf
+        );
+    });
+}
+
+#[test]
+fn revoke_and_renounce_release_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Sponsorship::sponsor(
+            RuntimeOrigin::signed(LABEL),
+            ARTIST,
+            MockCalls::Any,
+            100
+        ));
+        assert_noop!(
+            Sponsorship::revoke(RuntimeOrigin::signed(OTHER_LABEL), ARTIST),
+            Error::<Test>::NotSponsor
+        );
+        assert_ok!(Sponsorship::revoke(RuntimeOrigin::signed(LABEL), ARTIST));
+        assert_eq!(held(LABEL), 0);
+        assert!(!Sponsorships::<Test>::contains_key(ARTIST));
+        System::assert_last_event(
+            Event::SponsorshipEnded {
+                sponsor: LABEL,
+                beneficiary: ARTIST,
+            }
+            .into(),
+        );
+
+        assert_ok!(Sponsorship::sponsor(
+            RuntimeOrigin::signed(LABEL),
+            ARTIST,
+            MockCalls::Any,
+            100
+        ));
+        assert_ok!(Sponsorship::renounce(RuntimeOrigin::signed(ARTIST)));
+        assert_eq!(held(LABEL), 0);
+        assert_noop!(
+            Sponsorship::renounce(RuntimeOrigin::signed(ARTIST)),
+            Error::<Test>::NotSponsored
+        );
+    });
+}
+
+#[test]
+fn sponsor_pays_fees_of_covered_calls() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Sponsorship::sponsor(
+            RuntimeOrigin::signed(LABEL),
+            ARTIST,
+            MockCalls::Remarks,
+            100
+        ));
+        let label_balance = Balances::free_balance(LABEL);
+
+        // 40 charged up front, 10 refunded after dispatch.
+        pay(ARTIST, remark(), 40, 0, 30);
+
+        assert_eq!(Balances::free_balance(LABEL), label_balance - 30);
+        assert_eq!(Balances::free_balance(ARTIST), 100);
+        assert_eq!(allowance(ARTIST), 70);
+        System::assert_last_event(
+            Event::FeePaid {
+                sponsor: LABEL,
+                beneficiary: ARTIST,
+                fee: 30,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn signer_pays_what_the_sponsor_does_not_cover() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Sponsorship::sponsor(
+            RuntimeOrigin::signed(LABEL),
+            ARTIST,
+            MockCalls::Remarks,
+            50
+        ));
+        let label_balance = Balances::free_balance(LABEL);
+
+        // A call out of the sponsored kind.
+        pay(ARTIST, transfer(), 10, 0, 10);
+        assert_eq!(Balances::free_balance(ARTIST), 90);
+
+        // A tipped transaction.
+        pay(ARTIST, remark(), 10, 5, 10);
+        assert_eq!(Balances::free_balance(ARTIST), 75);
+
+        // A fee over the allowance.
+        pay(ARTIST, remark(), 60, 0, 60);
+        assert_eq!(Balances::free_balance(ARTIST), 15);
+
+        assert_eq!(Balances::free_balance(LABEL), label_balance);
+        assert_eq!(allowance(ARTIST), 50);
+    });
+}
+
+#[test]
+fn unfunded_beneficiary_can_sign_covered_calls() {
+    new_test_ext().execute_with(|| {
+        let info = DispatchInfo::default();
+        let sign = || {
+            (
+                CheckNonce::<Test>::from(System::account_nonce(NEWCOMER)),
+                ChargeTransactionPayment::<Test>::from(0),
+            )
+                .test_run(
+                    RuntimeOrigin::signed(NEWCOMER),
+                    &remark(),
+                    &info,
+                    10,
+                    0,
+                    |_| Ok(Default::default()),
+                )
+        };
+        assert_eq!(
+            sign().unwrap_err(),
+            TransactionValidityError::Invalid(InvalidTransaction::Payment)
+        );
+
+        assert_ok!(Sponsorship::sponsor(
+            RuntimeOrigin::signed(LABEL),
+            NEWCOMER,
+            MockCalls::Remarks,
+            100
+        ));
+        let label_balance = Balances::free_balance(LABEL);
+
+        assert_ok!(sign().unwrap());
+        assert_eq!(System::account_nonce(NEWCOMER), 1);
+        assert_eq!(
+            Balances::free_balance(LABEL),
+            label_balance - TransactionPayment::compute_fee(10, &info, 0)
+        );
+        assert_eq!(Balances::free_balance(NEWCOMER), 0);
+
+        // The account is reaped with the sponsorship, as it holds nothing else.
+        assert_ok!(Sponsorship::renounce(RuntimeOrigin::signed(NEWCOMER)));
+        assert_eq!(System::sufficients(&NEWCOMER), 0);
+        assert!(!System::account_exists(&NEWCOMER));
+    });
+}
+
+#[test]
+fn migration_references_existing_beneficiaries() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Sponsorship::sponsor(
+            RuntimeOrigin::signed(LABEL),
+            NEWCOMER,
+            MockCalls::Remarks,
+            100
+        ));
+        // As sponsored before v1.
+        System::dec_sufficients(&NEWCOMER);
+        StorageVersion::new(0).put::<Sponsorship>();
+
+        migrations::v1::ReferenceBeneficiaries::<Test>::on_runtime_upgrade();
+
+        assert_eq!(System::sufficients(&NEWCOMER), 1);
+        assert_eq!(Sponsorship::on_chain_storage_version(), 1);
+    });
+}
@@ -0,0 +1,108 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_support::traits::Hooks;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn amount<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance() * 10u32.into()
+}
+
+fn period<T: Config>() -> BlockNumberFor<T> {
+    T::MinPeriod::get().max(1u32.into())
+}
+
+/// Has the `i`-th funded subscriber subscribe to a funded beneficiary.
+fn setup_subscription<T: Config>(i: u32) -> Result<T::AccountId, BenchmarkError> {
+    let subscriber: T::AccountId = account("subscriber", i, SEED);
+    let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+    T::Currency::set_balance(
+        &subscriber,
+        T::SubscriptionDeposit::get() + amount::<T>() * 10u32.into(),
+    );
+    T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+    Pallet::<T>::subscribe(
+        RawOrigin::Signed(subscriber.clone()).into(),
+        beneficiary,
+        amount::<T>(),
+        period::<T>(),
+        period::<T>() * 10u32.into(),
+    )?;
+    Ok(subscriber)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn subscribe() -> Result<(), BenchmarkError> {
+        let subscriber: T::AccountId = account("subscriber", 0, SEED);
+        let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+        T::Currency::set_balance(
+            &subscriber,
+            T::SubscriptionDeposit::get() + amount::<T>() * 10u32.into(),
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(subscriber),
+            beneficiary,
+            amount::<T>(),
+            period::<T>(),
+            period::<T>() * 10u32.into(),
+        );
+
+        assert!(Subscriptions::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel() -> Result<(), BenchmarkError> {
+        let subscriber = setup_subscription::<T>(0)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(subscriber), 0);
+
+        assert!(!Subscriptions::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_initialize(c: Linear<0, { T::MaxChargesPerBlock::get() }>) -> Result<(), BenchmarkError> {
+        for i in 0..c {
+            setup_subscription::<T>(i)?;
+        }
+        let now = frame_system::Pallet::<T>::block_number().saturating_add(period::<T>());
+        frame_system::Pallet::<T>::set_block_number(now);
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(now);
+        }
+
+        assert!((0..c).all(|id| Subscriptions::<T>::get(id).is_some_and(|s| s.next_charge > now)));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
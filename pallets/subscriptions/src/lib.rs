@@ -0,0 +1,379 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Subscriptions
+//!
+//! Recurring payments from fans to artists or platforms, e.g. fan-club memberships, charged by
+//! the chain itself rather than by a custodial billing service.
+//!
+//! ## Features
+//! - A subscriber authorizes a beneficiary to receive `amount` every `period` blocks, for at
+//!   most `max_duration` blocks. The first period is charged on subscription.
+//! - Due charges are executed in `on_initialize`. Each block charges at most
+//!   `Config::MaxChargesPerBlock` subscriptions; a charge falling in a full block is pushed to
+//!   one of the next few blocks.
+//! - A charge failing for lack of funds emits `ChargeFailed` and is retried at the next period.
+//!   After `Config::MaxFailedCharges` consecutive failures the subscription ends.
+//! - Either party can cancel a subscription. A deposit is held from the subscriber for its
+//!   lifetime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{Precision, Preservation},
+    },
+};
+use frame_system::pallet_prelude::*;
+use pallet_royalties::OnPayout;
+use sp_runtime::{Saturating, traits::Zero};
+
+/// Number of blocks after its due block a charge can be pushed to when that block is full.
+pub const SCHEDULE_LOOKAHEAD: u32 = 10;
+
+pub type SubscriptionId = u32;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type SubscriptionOf<T> =
+    Subscription<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Subscription<AccountId, Balance, BlockNumber> {
+    /// Account charged, e.g. a fan.
+    pub subscriber: AccountId,
+    /// Account paid, e.g. an artist or a platform.
+    pub beneficiary: AccountId,
+    /// Charged once per period.
+    pub amount: Balance,
+    /// Blocks between two charges.
+    pub period: BlockNumber,
+    /// No charge is due from this block on.
+    pub ends_at: BlockNumber,
+    /// Block of the next charge.
+    pub next_charge: BlockNumber,
+    /// Consecutive charges that failed, 0 if the subscription is paid up.
+    pub failed_charges: u32,
+    /// Amount held from `subscriber` for this subscription.
+    pub deposit: Balance,
+}
+
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum EndReason {
+    /// `max_duration` was reached.
+    Expired,
+    /// Cancelled by the subscriber or the beneficiary.
+    Cancelled,
+    /// `MaxFailedCharges` consecutive charges failed.
+    ChargesFailed,
+    /// No block around the next charge had room for it.
+    ScheduleFull,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Notified of each charge paid, with its beneficiary.
+        type OnPayout: OnPayout<Self::AccountId, BalanceOf<Self>>;
+
+        /// Amount held from the subscriber for each subscription.
+        #[pallet::constant]
+        type SubscriptionDeposit: Get<BalanceOf<Self>>;
+
+        /// Min number of blocks between two charges.
+        #[pallet::constant]
+        type MinPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Max number of charges executed in a block.
+        #[pallet::constant]
+        type MaxChargesPerBlock: Get<u32>;
+
+        /// Consecutive failed charges ending a subscription.
+        #[pallet::constant]
+        type MaxFailedCharges: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        SubscriptionDeposit,
+    }
+
+    #[pallet::storage]
+    pub type Subscriptions<T: Config> =
+        StorageMap<_, Twox64Concat, SubscriptionId, SubscriptionOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextSubscriptionId<T: Config> = StorageValue<_, SubscriptionId, ValueQuery>;
+
+    /// Subscriptions charged at each block.
+    #[pallet::storage]
+    pub type Agenda<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<SubscriptionId, T::MaxChargesPerBlock>,
+        ValueQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        Subscribed {
+            id: SubscriptionId,
+            subscriber: T::AccountId,
+            beneficiary: T::AccountId,
+            amount: BalanceOf<T>,
+            period: BlockNumberFor<T>,
+            ends_at: BlockNumberFor<T>,
+        },
+        Charged {
+            id: SubscriptionId,
+            amount: BalanceOf<T>,
+        },
+        /// The subscriber could not pay a charge; it is retried at the next period.
+        ChargeFailed {
+            id: SubscriptionId,
+            amount: BalanceOf<T>,
+            error: DispatchError,
+        },
+        SubscriptionEnded {
+            id: SubscriptionId,
+            reason: EndReason,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        SelfSubscription,
+        ZeroAmount,
+        PeriodTooShort,
+        /// `max_duration` does not cover a single period.
+        DurationTooShort,
+        /// No block around the next charge has room for it.
+        ScheduleFull,
+        SubscriptionNotFound,
+        /// Only the subscriber and the beneficiary can cancel a subscription.
+        NotParty,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let due = Agenda::<T>::take(now);
+            for id in due.iter() {
+                Self::charge_due(*id, now);
+            }
+            T::WeightInfo::on_initialize(due.len() as u32)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Pay `beneficiary` `amount` every `period` blocks for at most `max_duration` blocks.
+        /// The first period is charged right away.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::subscribe())]
+        pub fn subscribe(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+            amount: BalanceOf<T>,
+            period: BlockNumberFor<T>,
+            max_duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(who != beneficiary, Error::<T>::SelfSubscription);
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+            // A charge is never scheduled at the block executing it.
+            ensure!(
+                !period.is_zero() && period >= T::MinPeriod::get(),
+                Error::<T>::PeriodTooShort
+            );
+            ensure!(max_duration > period, Error::<T>::DurationTooShort);
+
+            let id = NextSubscriptionId::<T>::get();
+            let now = frame_system::Pallet::<T>::block_number();
+            let next_charge =
+                Self::schedule(id, now.saturating_add(period)).ok_or(Error::<T>::ScheduleFull)?;
+
+            let deposit = T::SubscriptionDeposit::get();
+            T::Currency::hold(&HoldReason::SubscriptionDeposit.into(), &who, deposit)?;
+            T::Currency::transfer(&who, &beneficiary, amount, Preservation::Preserve)?;
+
+            let ends_at = now.saturating_add(max_duration);
+            Subscriptions::<T>::insert(
+                id,
+                Subscription {
+                    subscriber: who.clone(),
+                    beneficiary: beneficiary.clone(),
+                    amount,
+                    period,
+                    ends_at,
+                    next_charge,
+                    failed_charges: 0,
+                    deposit,
+                },
+            );
+            NextSubscriptionId::<T>::put(id.saturating_add(1));
+
+            Self::deposit_event(Event::Subscribed {
+                id,
+                subscriber: who,
+                beneficiary,
+                amount,
+                period,
+                ends_at,
+            });
+            Self::deposit_event(Event::Charged { id, amount });
+            Ok(())
+        }
+
+        /// Stop a subscription and release its deposit. Subscriber or beneficiary only.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::cancel())]
+        pub fn cancel(origin: OriginFor<T>, id: SubscriptionId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let subscription =
+                Subscriptions::<T>::get(id).ok_or(Error::<T>::SubscriptionNotFound)?;
+            ensure!(
+                who == subscription.subscriber || who == subscription.beneficiary,
+                Error::<T>::NotParty
+            );
+            Agenda::<T>::mutate(subscription.next_charge, |ids| ids.retain(|i| *i != id));
+            Self::end(id, &subscription, EndReason::Cancelled);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Charge subscription `id`, due at `now`, and schedule its next charge or end it.
+        fn charge_due(id: SubscriptionId, now: BlockNumberFor<T>) {
+            let Some(mut subscription) = Subscriptions::<T>::get(id) else {
+                return;
+            };
+
+            match T::Currency::transfer(
+                &subscription.subscriber,
+                &subscription.beneficiary,
+                subscription.amount,
+                Preservation::Preserve,
+            ) {
+                Ok(_) => {
+                    subscription.failed_charges = 0;
+                    T::OnPayout::on_payout(&subscription.beneficiary, subscription.amount);
+                    Self::deposit_event(Event::Charged {
+                        id,
+                        amount: subscription.amount,
+                    });
+                }
+                Err(error) => {
+                    subscription.failed_charges.saturating_inc();
+                    Self::deposit_event(Event::ChargeFailed {
+                        id,
+                        amount: subscription.amount,
+                        error,
+                    });
+                }
+            }
+
+            if subscription.failed_charges >= T::MaxFailedCharges::get() {
+                return Self::end(id, &subscription, EndReason::ChargesFailed);
+            }
+            let due = now.saturating_add(subscription.period);
+            if due >= subscription.ends_at {
+                return Self::end(id, &subscription, EndReason::Expired);
+            }
+            match Self::schedule(id, due) {
+                Some(at) => {
+                    subscription.next_charge = at;
+                    Subscriptions::<T>::insert(id, subscription);
+                }
+                None => Self::end(id, &subscription, EndReason::ScheduleFull),
+            }
+        }
+
+        /// Put `id` on the agenda of the first block from `due` with room for it, looking at
+        /// most `SCHEDULE_LOOKAHEAD` blocks ahead. Returns the block it is charged at.
+        fn schedule(id: SubscriptionId, due: BlockNumberFor<T>) -> Option<BlockNumberFor<T>> {
+            (0..=SCHEDULE_LOOKAHEAD)
+                .map(|offset| due.saturating_add(offset.into()))
+                .find(|at| Agenda::<T>::try_append(at, id).is_ok())
+        }
+
+        fn end(id: SubscriptionId, subscription: &SubscriptionOf<T>, reason: EndReason) {
+            // The deposit was held on subscription and nothing else releases it.
+            let _ = T::Currency::release(
+                &HoldReason::SubscriptionDeposit.into(),
+                &subscription.subscriber,
+                subscription.deposit,
+                Precision::BestEffort,
+            );
+            Subscriptions::<T>::remove(id);
+            Self::deposit_event(Event::SubscriptionEnded { id, reason });
+        }
+
+        /// Whether subscription `id` is running and its last charge went through, e.g. to
+        /// check a fan-club membership.
+        pub fn is_paid_up(id: SubscriptionId) -> bool {
+            Subscriptions::<T>::get(id).is_some_and(|s| s.failed_charges == 0)
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as pallet_subscriptions;
+use frame_support::{derive_impl, parameter_types, sp_runtime::BuildStorage};
+
+pub type Balance = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type Subscriptions = pallet_subscriptions;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+}
+
+pub const SUBSCRIPTION_DEPOSIT: Balance = 5;
+pub const MIN_PERIOD: u64 = 5;
+
+parameter_types! {
+    pub const SubscriptionDeposit: Balance = SUBSCRIPTION_DEPOSIT;
+    pub const MinPeriod: u64 = MIN_PERIOD;
+    pub const MaxChargesPerBlock: u32 = 2;
+    pub const MaxFailedCharges: u32 = 2;
+}
+
+impl pallet_subscriptions::Config for Test {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type OnPayout = ();
+    type SubscriptionDeposit = SubscriptionDeposit;
+    type MinPeriod = MinPeriod;
+    type MaxChargesPerBlock = MaxChargesPerBlock;
+    type MaxFailedCharges = MaxFailedCharges;
+    type WeightInfo = ();
+}
+
+pub const FAN: u64 = 1;
+pub const ARTIST: u64 = 2;
+pub const BOB: u64 = 3;
+pub const CHARLIE: u64 = 4;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(FAN, 100), (ARTIST, 100), (BOB, 1_000), (CHARLIE, 1_000)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+pub(crate) fn run_to_block(n: u64) {
+    use frame_support::traits::Hooks;
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        Subscriptions::on_initialize(next);
+    }
+}
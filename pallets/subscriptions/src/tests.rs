@@ -0,0 +1,260 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Agenda, EndReason, Error, Event, HoldReason, SubscriptionOf,
+    Subscriptions as SubscriptionsStorage, mock::*,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::fungible::{Inspect, InspectHold},
+};
+use sp_runtime::TokenError;
+
+const AMOUNT: Balance = 20;
+const PERIOD: u64 = 10;
+
+/// `FAN` pays `ARTIST` 20 every 10 blocks for 45 blocks: charged at blocks 1, 11, 21, 31 and 41.
+fn subscribe() {
+    assert_ok!(Subscriptions::subscribe(
+        RuntimeOrigin::signed(FAN),
+        ARTIST,
+        AMOUNT,
+        PERIOD,
+        45
+    ));
+}
+
+fn subscription(id: u32) -> SubscriptionOf<Test> {
+    SubscriptionsStorage::<Test>::get(id).unwrap()
+}
+
+#[test]
+fn subscribe_charges_the_first_period() {
+    new_test_ext().execute_with(|| {
+        let open = |beneficiary, amount, period, max_duration| {
+            Subscriptions::subscribe(
+                RuntimeOrigin::signed(FAN),
+                beneficiary,
+                amount,
+                period,
+                max_duration,
+            )
+        };
+        assert_noop!(
+            open(FAN, AMOUNT, PERIOD, 45),
+            Error::<Test>::SelfSubscription
+        );
+        assert_noop!(open(ARTIST, 0, PERIOD, 45), Error::<Test>::ZeroAmount);
+        assert_noop!(
+            open(ARTIST, AMOUNT, MIN_PERIOD - 1, 45),
+            Error::<Test>::PeriodTooShort
+        );
+        assert_noop!(
+            open(ARTIST, AMOUNT, PERIOD, PERIOD),
+            Error::<Test>::DurationTooShort
+        );
+        assert_noop!(open(ARTIST, 200, PERIOD, 45), TokenError::FundsUnavailable);
+
+        subscribe();
+
+        assert_eq!(Balances::balance(&ARTIST), 120);
+        assert_eq!(Balances::balance(&FAN), 75);
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::SubscriptionDeposit.into(), &FAN),
+            SUBSCRIPTION_DEPOSIT
+        );
+        assert_eq!(subscription(0).next_charge, 11);
+        assert_eq!(subscription(0).ends_at, 46);
+        assert_eq!(Agenda::<Test>::get(11).into_inner(), vec![0]);
+        System::assert_has_event(
+            Event::Subscribed {
+                id: 0,
+                subscriber: FAN,
+                beneficiary: ARTIST,
+                amount: AMOUNT,
+                period: PERIOD,
+                ends_at: 46,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::Charged {
+                id: 0,
+                amount: AMOUNT,
+            }
+            .into(),
+        );
+        assert!(Subscriptions::is_paid_up(0));
+    });
+}
+
+#[test]
+fn due_charges_run_until_expiry() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            FAN,
+            100
+        ));
+        subscribe();
+
+        run_to_block(10);
+        assert_eq!(Balances::balance(&ARTIST), 120);
+        run_to_block(11);
+        assert_eq!(Balances::balance(&ARTIST), 140);
+        System::assert_last_event(
+            Event::Charged {
+                id: 0,
+                amount: AMOUNT,
+            }
+            .into(),
+        );
+        assert_eq!(subscription(0).next_charge, 21);
+
+        run_to_block(41);
+        assert_eq!(Balances::balance(&ARTIST), 200);
+        System::assert_last_event(
+            Event::SubscriptionEnded {
+                id: 0,
+                reason: EndReason::Expired,
+            }
+            .into(),
+        );
+        assert!(!SubscriptionsStorage::<Test>::contains_key(0));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::SubscriptionDeposit.into(), &FAN),
+            0
+        );
+    });
+}
+
+#[test]
+fn failed_charges_are_retried_then_end_the_subscription() {
+    new_test_ext().execute_with(|| {
+        subscribe();
+        // 75 free: two more charges, then the fan runs dry.
+        run_to_block(21);
+        assert_eq!(Balances::balance(&FAN), 35);
+        assert!(Subscriptions::is_paid_up(0));
+
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(FAN),
+            BOB,
+            30
+        ));
+        run_to_block(31);
+        System::assert_last_event(
+            Event::ChargeFailed {
+                id: 0,
+                amount: AMOUNT,
+                error: TokenError::FundsUnavailable.into(),
+            }
+            .into(),
+        );
+        assert_eq!(subscription(0).failed_charges, 1);
+        assert!(!Subscriptions::is_paid_up(0));
+
+        run_to_block(41);
+        System::assert_last_event(
+            Event::SubscriptionEnded {
+                id: 0,
+                reason: EndReason::ChargesFailed,
+            }
+            .into(),
+        );
+        assert!(!SubscriptionsStorage::<Test>::contains_key(0));
+        assert_eq!(Balances::balance(&ARTIST), 160);
+    });
+}
+
+#[test]
+fn a_successful_charge_resets_failures() {
+    new_test_ext().execute_with(|| {
+        subscribe();
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(FAN),
+            BOB,
+            60
+        ));
+        run_to_block(11);
+        assert_eq!(subscription(0).failed_charges, 1);
+
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            FAN,
+            100
+        ));
+        run_to_block(21);
+        assert_eq!(subscription(0).failed_charges, 0);
+        assert!(Subscriptions::is_paid_up(0));
+    });
+}
+
+#[test]
+fn full_blocks_push_charges_back() {
+    new_test_ext().execute_with(|| {
+        // Two charges per block at most.
+        for who in [FAN, BOB, CHARLIE] {
+            assert_ok!(Subscriptions::subscribe(
+                RuntimeOrigin::signed(who),
+                ARTIST,
+                AMOUNT,
+                PERIOD,
+                45
+            ));
+        }
+        assert_eq!(Agenda::<Test>::get(11).into_inner(), vec![0, 1]);
+        assert_eq!(Agenda::<Test>::get(12).into_inner(), vec![2]);
+        assert_eq!(subscription(2).next_charge, 12);
+
+        run_to_block(12);
+        assert_eq!(Balances::balance(&ARTIST), 100 + 6 * AMOUNT);
+        // Each keeps its period from the block it was charged at.
+        assert_eq!(subscription(2).next_charge, 22);
+    });
+}
+
+#[test]
+fn either_party_cancels() {
+    new_test_ext().execute_with(|| {
+        subscribe();
+        assert_noop!(
+            Subscriptions::cancel(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::NotParty
+        );
+        assert_ok!(Subscriptions::cancel(RuntimeOrigin::signed(ARTIST), 0));
+
+        System::assert_last_event(
+            Event::SubscriptionEnded {
+                id: 0,
+                reason: EndReason::Cancelled,
+            }
+            .into(),
+        );
+        assert!(Agenda::<Test>::get(11).is_empty());
+        assert_eq!(Balances::balance(&FAN), 80);
+        assert_noop!(
+            Subscriptions::cancel(RuntimeOrigin::signed(FAN), 0),
+            Error::<Test>::SubscriptionNotFound
+        );
+
+        run_to_block(11);
+        assert_eq!(Balances::balance(&ARTIST), 120);
+    });
+}
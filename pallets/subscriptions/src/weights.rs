@@ -0,0 +1,60 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_subscriptions.
+pub trait WeightInfo {
+    fn subscribe() -> Weight;
+    fn cancel() -> Weight;
+    fn on_initialize(c: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Subscriptions::NextSubscriptionId` (r:1 w:1)
+    /// Storage: `Subscriptions::Agenda` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `Subscriptions::Subscriptions` (r:0 w:1)
+    fn subscribe() -> Weight {
+        Weight::from_parts(60_000_000, 6_200)
+            .saturating_add(ParityDbWeight::get().reads(5_u64))
+            .saturating_add(ParityDbWeight::get().writes(6_u64))
+    }
+    /// Storage: `Subscriptions::Subscriptions` (r:1 w:1)
+    /// Storage: `Subscriptions::Agenda` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn cancel() -> Weight {
+        Weight::from_parts(35_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Subscriptions::Agenda` (r:1+c w:1+c)
+    /// Storage: `Subscriptions::Subscriptions` (r:c w:c)
+    /// Storage: `System::Account` (r:2c w:2c)
+    /// The range of component `c` is `[0, 100]`.
+    fn on_initialize(c: u32) -> Weight {
+        Weight::from_parts(5_000_000, 1_500)
+            .saturating_add(Weight::from_parts(45_000_000, 6_200).saturating_mul(c.into()))
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().reads((4_u64).saturating_mul(c.into())))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+            .saturating_add(ParityDbWeight::get().writes((4_u64).saturating_mul(c.into())))
+    }
+}
@@ -0,0 +1,178 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn funded<T: Config>(name: &'static str) -> T::AccountId {
+    let who: T::AccountId = account(name, 0, SEED);
+    T::Currency::set_balance(&who, T::Currency::minimum_balance() * 1_000u32.into());
+    who
+}
+
+fn seat_classes<T: Config>(c: u32) -> BoundedVec<(BalanceOf<T>, u32), T::MaxSeatClasses> {
+    BoundedVec::truncate_from(
+        (0..c)
+            .map(|_| (T::Currency::minimum_balance() * 10u32.into(), u32::MAX))
+            .collect(),
+    )
+}
+
+/// Creates an event under `policy`, returning its organizer.
+fn setup_event<T: Config>(policy: TransferPolicy) -> Result<T::AccountId, BenchmarkError> {
+    let origin =
+        T::OrganizerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    let organizer = T::OrganizerOrigin::ensure_origin(origin.clone())
+        .map_err(|_| BenchmarkError::Weightless)?;
+    T::Currency::set_balance(&organizer, T::Currency::minimum_balance() * 1_000u32.into());
+    Pallet::<T>::create_event(origin, seat_classes::<T>(1), policy)?;
+    Ok(organizer)
+}
+
+/// Creates an event under `policy` and sells ticket 0 to a funded fan, returned.
+fn setup_ticket<T: Config>(policy: TransferPolicy) -> Result<T::AccountId, BenchmarkError> {
+    setup_event::<T>(policy)?;
+    let fan = funded::<T>("fan");
+    Pallet::<T>::buy_ticket(RawOrigin::Signed(fan.clone()).into(), 0, 0)?;
+    Ok(fan)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_event(c: Linear<1, { T::MaxSeatClasses::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::OrganizerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            seat_classes::<T>(c),
+            TransferPolicy::Open,
+        );
+
+        assert!(LiveEvents::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_scanner() -> Result<(), BenchmarkError> {
+        let organizer = setup_event::<T>(TransferPolicy::Open)?;
+        let scanner: T::AccountId = account("scanner", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(organizer), 0, scanner.clone());
+
+        assert!(Scanners::<T>::contains_key(0, &scanner));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_scanner() -> Result<(), BenchmarkError> {
+        let organizer = setup_event::<T>(TransferPolicy::Open)?;
+        let scanner: T::AccountId = account("scanner", 0, SEED);
+        Scanners::<T>::insert(0, &scanner, ());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(organizer), 0, scanner.clone());
+
+        assert!(!Scanners::<T>::contains_key(0, &scanner));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn buy_ticket() -> Result<(), BenchmarkError> {
+        setup_event::<T>(TransferPolicy::Open)?;
+        let fan = funded::<T>("fan");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(fan), 0, 0);
+
+        assert!(Tickets::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn redeem() -> Result<(), BenchmarkError> {
+        setup_ticket::<T>(TransferPolicy::Open)?;
+        let scanner: T::AccountId = account("scanner", 0, SEED);
+        Scanners::<T>::insert(0, &scanner, ());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(scanner), 0);
+
+        assert!(Tickets::<T>::get(0).is_some_and(|t| t.redeemed));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_ticket() -> Result<(), BenchmarkError> {
+        let fan = setup_ticket::<T>(TransferPolicy::Open)?;
+        let to: T::AccountId = account("to", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(fan), 0, to.clone());
+
+        assert!(Tickets::<T>::get(0).is_some_and(|t| t.owner == to));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn list_ticket() -> Result<(), BenchmarkError> {
+        let fan = setup_ticket::<T>(TransferPolicy::Open)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(fan), 0, T::Currency::minimum_balance());
+
+        assert!(Listings::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn delist_ticket() -> Result<(), BenchmarkError> {
+        let fan = setup_ticket::<T>(TransferPolicy::Open)?;
+        Listings::<T>::insert(0, T::Currency::minimum_balance());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(fan), 0);
+
+        assert!(!Listings::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn buy_listed() -> Result<(), BenchmarkError> {
+        // The royalty adds a transfer to the organizer.
+        setup_ticket::<T>(TransferPolicy::ResaleRoyalty(Perbill::from_percent(10)))?;
+        Listings::<T>::insert(0, T::Currency::minimum_balance() * 100u32.into());
+        let buyer = funded::<T>("buyer");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), 0);
+
+        assert!(Tickets::<T>::get(0).is_some_and(|t| t.owner == buyer));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
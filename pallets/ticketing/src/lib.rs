@@ -0,0 +1,525 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Ticketing
+//!
+//! Tickets for live events, issued as non-fungible items.
+//!
+//! ## Features
+//! - Artists (`Config::OrganizerOrigin`) create events with seat classes, each with a price and
+//!   a supply cap.
+//! - Fans buy tickets from a seat class until it sells out; the price is paid to the organizer.
+//!   Each ticket is a unique item, owned by one account.
+//! - The organizer approves venue scanners, who mark tickets as redeemed at the door. A redeemed
+//!   ticket can no longer change hands.
+//! - Each event sets its [`TransferPolicy`] for the secondary market: free transfers and
+//!   resales, no transfers at all, or resales only, with a royalty to the organizer on each.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate},
+        tokens::Preservation,
+    },
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{Perbill, Saturating, traits::Zero};
+
+pub type EventId = u32;
+pub type TicketId = u64;
+pub type SeatClassIndex = u32;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type SeatClassesOf<T> = BoundedVec<SeatClass<BalanceOf<T>>, <T as Config>::MaxSeatClasses>;
+
+pub type LiveEventOf<T> = LiveEvent<<T as frame_system::Config>::AccountId, SeatClassesOf<T>>;
+
+pub type TicketOf<T> = Ticket<<T as frame_system::Config>::AccountId>;
+
+/// What ticket holders may do with their tickets.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum TransferPolicy {
+    /// Tickets may be given away and resold freely.
+    Open,
+    /// Tickets stay with their first buyer.
+    NonTransferable,
+    /// Tickets may only change hands through a resale, with this share of the price going to
+    /// the organizer.
+    ResaleRoyalty(Perbill),
+}
+
+/// A class of seats of an event.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct SeatClass<Balance> {
+    pub price: Balance,
+    /// Max number of tickets sold in the class.
+    pub supply: u32,
+    /// Tickets sold so far.
+    pub sold: u32,
+}
+
+/// A live event tickets are sold for.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LiveEvent<AccountId, SeatClasses> {
+    /// Artist who created the event and receives ticket sales.
+    pub organizer: AccountId,
+    pub seat_classes: SeatClasses,
+    pub transfer_policy: TransferPolicy,
+}
+
+/// A ticket to a seat class of an event.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Ticket<AccountId> {
+    pub event: EventId,
+    pub seat_class: SeatClassIndex,
+    pub owner: AccountId,
+    pub redeemed: bool,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: Mutate<Self::AccountId>;
+
+        /// Origin allowed to create events, resolving to the organizer.
+        type OrganizerOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// Max number of seat classes of an event.
+        #[pallet::constant]
+        type MaxSeatClasses: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::storage]
+    pub type LiveEvents<T: Config> =
+        StorageMap<_, Twox64Concat, EventId, LiveEventOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextEventId<T: Config> = StorageValue<_, EventId, ValueQuery>;
+
+    /// Accounts allowed to redeem the tickets of an event.
+    #[pallet::storage]
+    pub type Scanners<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, EventId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    #[pallet::storage]
+    pub type Tickets<T: Config> = StorageMap<_, Twox64Concat, TicketId, TicketOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextTicketId<T: Config> = StorageValue<_, TicketId, ValueQuery>;
+
+    /// Asking price of the tickets listed for resale.
+    #[pallet::storage]
+    pub type Listings<T: Config> = StorageMap<_, Twox64Concat, TicketId, BalanceOf<T>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        EventCreated {
+            event: EventId,
+            organizer: T::AccountId,
+        },
+        ScannerAdded {
+            event: EventId,
+            scanner: T::AccountId,
+        },
+        ScannerRemoved {
+            event: EventId,
+            scanner: T::AccountId,
+        },
+        TicketSold {
+            ticket: TicketId,
+            event: EventId,
+            seat_class: SeatClassIndex,
+            buyer: T::AccountId,
+            price: BalanceOf<T>,
+        },
+        TicketRedeemed {
+            ticket: TicketId,
+            scanner: T::AccountId,
+        },
+        TicketTransferred {
+            ticket: TicketId,
+            from: T::AccountId,
+            to: T::AccountId,
+        },
+        TicketListed {
+            ticket: TicketId,
+            price: BalanceOf<T>,
+        },
+        TicketDelisted {
+            ticket: TicketId,
+        },
+        TicketResold {
+            ticket: TicketId,
+            seller: T::AccountId,
+            buyer: T::AccountId,
+            price: BalanceOf<T>,
+            royalty: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NoSeatClasses,
+        EventNotFound,
+        NotOrganizer,
+        ScannerAlreadyPresent,
+        ScannerNotFound,
+        UnknownSeatClass,
+        SoldOut,
+        TicketNotFound,
+        NotTicketOwner,
+        NotScanner,
+        AlreadyRedeemed,
+        /// The event's transfer policy does not allow this.
+        TransferRestricted,
+        NotListed,
+        /// Listed tickets cannot be transferred until delisted.
+        TicketListed,
+        /// Buying back one's own ticket.
+        AlreadyOwner,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create an event with `seat_classes` on sale, each given as a price and a supply.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_event(seat_classes.len() as u32))]
+        pub fn create_event(
+            origin: OriginFor<T>,
+            seat_classes: BoundedVec<(BalanceOf<T>, u32), T::MaxSeatClasses>,
+            transfer_policy: TransferPolicy,
+        ) -> DispatchResult {
+            let organizer = T::OrganizerOrigin::ensure_origin(origin)?;
+            ensure!(!seat_classes.is_empty(), Error::<T>::NoSeatClasses);
+
+            let seat_classes = BoundedVec::truncate_from(
+                seat_classes
+                    .into_iter()
+                    .map(|(price, supply)| SeatClass {
+                        price,
+                        supply,
+                        sold: 0,
+                    })
+                    .collect(),
+            );
+            let event = NextEventId::<T>::get();
+            LiveEvents::<T>::insert(
+                event,
+                LiveEvent {
+                    organizer: organizer.clone(),
+                    seat_classes,
+                    transfer_policy,
+                },
+            );
+            NextEventId::<T>::put(event.saturating_add(1));
+
+            Self::deposit_event(Event::EventCreated { event, organizer });
+            Ok(())
+        }
+
+        /// Allow `scanner` to redeem the tickets of `event`. Organizer only.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::add_scanner())]
+        pub fn add_scanner(
+            origin: OriginFor<T>,
+            event: EventId,
+            scanner: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_organizer(event, &who)?;
+
+            ensure!(
+                !Scanners::<T>::contains_key(event, &scanner),
+                Error::<T>::ScannerAlreadyPresent
+            );
+            Scanners::<T>::insert(event, &scanner, ());
+            Self::deposit_event(Event::ScannerAdded { event, scanner });
+            Ok(())
+        }
+
+        /// Revoke `scanner` for `event`. Organizer only.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::remove_scanner())]
+        pub fn remove_scanner(
+            origin: OriginFor<T>,
+            event: EventId,
+            scanner: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_organizer(event, &who)?;
+
+            ensure!(
+                Scanners::<T>::take(event, &scanner).is_some(),
+                Error::<T>::ScannerNotFound
+            );
+            Self::deposit_event(Event::ScannerRemoved { event, scanner });
+            Ok(())
+        }
+
+        /// Buy a ticket in `seat_class` of `event`, paying its price to the organizer.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::buy_ticket())]
+        pub fn buy_ticket(
+            origin: OriginFor<T>,
+            event: EventId,
+            seat_class: SeatClassIndex,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+
+            let price = LiveEvents::<T>::try_mutate(event, |maybe_event| {
+                let live_event = maybe_event.as_mut().ok_or(Error::<T>::EventNotFound)?;
+                let class = live_event
+                    .seat_classes
+                    .get_mut(seat_class as usize)
+                    .ok_or(Error::<T>::UnknownSeatClass)?;
+                ensure!(class.sold < class.supply, Error::<T>::SoldOut);
+                class.sold.saturating_inc();
+
+                T::Currency::transfer(
+                    &buyer,
+                    &live_event.organizer,
+                    class.price,
+                    Preservation::Preserve,
+                )?;
+                Ok::<_, DispatchError>(class.price)
+            })?;
+
+            let ticket = NextTicketId::<T>::get();
+            Tickets::<T>::insert(
+                ticket,
+                Ticket {
+                    event,
+                    seat_class,
+                    owner: buyer.clone(),
+                    redeemed: false,
+                },
+            );
+            NextTicketId::<T>::put(ticket.saturating_add(1));
+
+            Self::deposit_event(Event::TicketSold {
+                ticket,
+                event,
+                seat_class,
+                buyer,
+                price,
+            });
+            Ok(())
+        }
+
+        /// Mark `ticket` as redeemed. Scanners of the ticket's event only.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::redeem())]
+        pub fn redeem(origin: OriginFor<T>, ticket: TicketId) -> DispatchResult {
+            let scanner = ensure_signed(origin)?;
+
+            Tickets::<T>::try_mutate(ticket, |maybe_ticket| -> DispatchResult {
+                let ticket = maybe_ticket.as_mut().ok_or(Error::<T>::TicketNotFound)?;
+                ensure!(
+                    Scanners::<T>::contains_key(ticket.event, &scanner),
+                    Error::<T>::NotScanner
+                );
+                ensure!(!ticket.redeemed, Error::<T>::AlreadyRedeemed);
+                ticket.redeemed = true;
+                Ok(())
+            })?;
+            // A redeemed ticket cannot be resold.
+            Listings::<T>::remove(ticket);
+
+            Self::deposit_event(Event::TicketRedeemed { ticket, scanner });
+            Ok(())
+        }
+
+        /// Give `ticket` to `to`, if the event's transfer policy is open. Owner only.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::transfer_ticket())]
+        pub fn transfer_ticket(
+            origin: OriginFor<T>,
+            ticket: TicketId,
+            to: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let policy = Self::ensure_transferable(ticket, &who)?;
+            ensure!(
+                policy == TransferPolicy::Open,
+                Error::<T>::TransferRestricted
+            );
+            ensure!(
+                !Listings::<T>::contains_key(ticket),
+                Error::<T>::TicketListed
+            );
+
+            Tickets::<T>::mutate(ticket, |maybe_ticket| {
+                if let Some(ticket) = maybe_ticket {
+                    ticket.owner = to.clone();
+                }
+            });
+            Self::deposit_event(Event::TicketTransferred {
+                ticket,
+                from: who,
+                to,
+            });
+            Ok(())
+        }
+
+        /// List `ticket` for resale at `price`, replacing any previous listing. Owner only.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::list_ticket())]
+        pub fn list_ticket(
+            origin: OriginFor<T>,
+            ticket: TicketId,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let policy = Self::ensure_transferable(ticket, &who)?;
+            ensure!(
+                policy != TransferPolicy::NonTransferable,
+                Error::<T>::TransferRestricted
+            );
+
+            Listings::<T>::insert(ticket, price);
+            Self::deposit_event(Event::TicketListed { ticket, price });
+            Ok(())
+        }
+
+        /// Withdraw `ticket` from resale. Owner only.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::delist_ticket())]
+        pub fn delist_ticket(origin: OriginFor<T>, ticket: TicketId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let owner = Tickets::<T>::get(ticket)
+                .ok_or(Error::<T>::TicketNotFound)?
+                .owner;
+            ensure!(owner == who, Error::<T>::NotTicketOwner);
+            ensure!(Listings::<T>::take(ticket).is_some(), Error::<T>::NotListed);
+
+            Self::deposit_event(Event::TicketDelisted { ticket });
+            Ok(())
+        }
+
+        /// Buy listed `ticket` at its asking price. The organizer's royalty, if any, is taken
+        /// out of the price and the rest paid to the seller.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::buy_listed())]
+        pub fn buy_listed(origin: OriginFor<T>, ticket: TicketId) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+
+            let price = Listings::<T>::take(ticket).ok_or(Error::<T>::NotListed)?;
+            let mut item = Tickets::<T>::get(ticket).ok_or(Error::<T>::TicketNotFound)?;
+            ensure!(item.owner != buyer, Error::<T>::AlreadyOwner);
+            let live_event = LiveEvents::<T>::get(item.event).ok_or(Error::<T>::EventNotFound)?;
+
+            let royalty = match live_event.transfer_policy {
+                TransferPolicy::ResaleRoyalty(share) => share.mul_floor(price),
+                TransferPolicy::Open => Zero::zero(),
+                TransferPolicy::NonTransferable => {
+                    return Err(Error::<T>::TransferRestricted.into());
+                }
+            };
+            if !royalty.is_zero() {
+                T::Currency::transfer(
+                    &buyer,
+                    &live_event.organizer,
+                    royalty,
+                    Preservation::Preserve,
+                )?;
+            }
+            T::Currency::transfer(
+                &buyer,
+                &item.owner,
+                price.saturating_sub(royalty),
+                Preservation::Preserve,
+            )?;
+
+            let seller = core::mem::replace(&mut item.owner, buyer.clone());
+            Tickets::<T>::insert(ticket, item);
+
+            Self::deposit_event(Event::TicketResold {
+                ticket,
+                seller,
+                buyer,
+                price,
+                royalty,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn ensure_organizer(event: EventId, who: &T::AccountId) -> DispatchResult {
+            let live_event = LiveEvents::<T>::get(event).ok_or(Error::<T>::EventNotFound)?;
+            ensure!(&live_event.organizer == who, Error::<T>::NotOrganizer);
+            Ok(())
+        }
+
+        /// Check that `who` owns `ticket` and that it was not redeemed, returning the transfer
+        /// policy of its event.
+        fn ensure_transferable(
+            ticket: TicketId,
+            who: &T::AccountId,
+        ) -> Result<TransferPolicy, DispatchError> {
+            let ticket = Tickets::<T>::get(ticket).ok_or(Error::<T>::TicketNotFound)?;
+            ensure!(&ticket.owner == who, Error::<T>::NotTicketOwner);
+            ensure!(!ticket.redeemed, Error::<T>::AlreadyRedeemed);
+            let live_event = LiveEvents::<T>::get(ticket.event).ok_or(Error::<T>::EventNotFound)?;
+            Ok(live_event.transfer_policy)
+        }
+    }
+}
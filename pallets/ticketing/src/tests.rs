@@ -0,0 +1,388 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Error, Event, Listings, LiveEvents, Scanners, SeatClass, Ticket, Tickets, TransferPolicy,
+    mock::*,
+};
+use frame_support::{BoundedVec, assert_noop, assert_ok};
+use sp_runtime::{DispatchError, Perbill};
+
+/// Creates an event with a 50-unit class of 2 seats and a 200-unit class of 1 seat.
+fn create_event(policy: TransferPolicy) {
+    assert_ok!(Ticketing::create_event(
+        RuntimeOrigin::signed(ARTIST),
+        BoundedVec::truncate_from(vec![(50, 2), (200, 1)]),
+        policy
+    ));
+}
+
+/// Creates an event and sells ticket 0 of its first class to `FAN`.
+fn sell_ticket(policy: TransferPolicy) {
+    create_event(policy);
+    assert_ok!(Ticketing::buy_ticket(RuntimeOrigin::signed(FAN), 0, 0));
+}
+
+#[test]
+fn create_event_works() {
+    new_test_ext().execute_with(|| {
+        create_event(TransferPolicy::Open);
+
+        let live_event = LiveEvents::<Test>::get(0).unwrap();
+        assert_eq!(live_event.organizer, ARTIST);
+        assert_eq!(
+            live_event.seat_classes.into_inner(),
+            vec![
+                SeatClass {
+                    price: 50,
+                    supply: 2,
+                    sold: 0
+                },
+                SeatClass {
+                    price: 200,
+                    supply: 1,
+                    sold: 0
+                },
+            ]
+        );
+        System::assert_last_event(
+            Event::EventCreated {
+                event: 0,
+                organizer: ARTIST,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn create_event_requires_organizer_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Ticketing::create_event(
+                RuntimeOrigin::signed(FAN),
+                BoundedVec::truncate_from(vec![(50, 2)]),
+                TransferPolicy::Open
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Ticketing::create_event(
+                RuntimeOrigin::signed(ARTIST),
+                BoundedVec::new(),
+                TransferPolicy::Open
+            ),
+            Error::<Test>::NoSeatClasses
+        );
+    });
+}
+
+#[test]
+fn scanners_are_managed_by_organizer() {
+    new_test_ext().execute_with(|| {
+        create_event(TransferPolicy::Open);
+
+        assert_noop!(
+            Ticketing::add_scanner(RuntimeOrigin::signed(FAN), 0, SCANNER),
+            Error::<Test>::NotOrganizer
+        );
+        assert_ok!(Ticketing::add_scanner(
+            RuntimeOrigin::signed(ARTIST),
+            0,
+            SCANNER
+        ));
+        assert!(Scanners::<Test>::contains_key(0, SCANNER));
+        assert_noop!(
+            Ticketing::add_scanner(RuntimeOrigin::signed(ARTIST), 0, SCANNER),
+            Error::<Test>::ScannerAlreadyPresent
+        );
+
+        assert_ok!(Ticketing::remove_scanner(
+            RuntimeOrigin::signed(ARTIST),
+            0,
+            SCANNER
+        ));
+        assert!(!Scanners::<Test>::contains_key(0, SCANNER));
+        System::assert_last_event(
+            Event::ScannerRemoved {
+                event: 0,
+                scanner: SCANNER,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Ticketing::remove_scanner(RuntimeOrigin::signed(ARTIST), 0, SCANNER),
+            Error::<Test>::ScannerNotFound
+        );
+    });
+}
+
+#[test]
+fn buy_ticket_pays_organizer() {
+    new_test_ext().execute_with(|| {
+        create_event(TransferPolicy::Open);
+
+        assert_ok!(Ticketing::buy_ticket(RuntimeOrigin::signed(FAN), 0, 1));
+
+        assert_eq!(Balances::free_balance(FAN), 800);
+        assert_eq!(Balances::free_balance(ARTIST), 300);
+        assert_eq!(
+            Tickets::<Test>::get(0),
+            Some(Ticket {
+                event: 0,
+                seat_class: 1,
+                owner: FAN,
+                redeemed: false,
+            })
+        );
+        assert_eq!(LiveEvents::<Test>::get(0).unwrap().seat_classes[1].sold, 1);
+        System::assert_last_event(
+            Event::TicketSold {
+                ticket: 0,
+                event: 0,
+                seat_class: 1,
+                buyer: FAN,
+                price: 200,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn buy_ticket_respects_supply() {
+    new_test_ext().execute_with(|| {
+        create_event(TransferPolicy::Open);
+
+        assert_ok!(Ticketing::buy_ticket(RuntimeOrigin::signed(FAN), 0, 0));
+        assert_ok!(Ticketing::buy_ticket(
+            RuntimeOrigin::signed(OTHER_FAN),
+            0,
+            0
+        ));
+        assert_noop!(
+            Ticketing::buy_ticket(RuntimeOrigin::signed(FAN), 0, 0),
+            Error::<Test>::SoldOut
+        );
+        assert_noop!(
+            Ticketing::buy_ticket(RuntimeOrigin::signed(FAN), 0, 2),
+            Error::<Test>::UnknownSeatClass
+        );
+        assert_noop!(
+            Ticketing::buy_ticket(RuntimeOrigin::signed(FAN), 1, 0),
+            Error::<Test>::EventNotFound
+        );
+    });
+}
+
+#[test]
+fn buy_ticket_fails_without_funds() {
+    new_test_ext().execute_with(|| {
+        create_event(TransferPolicy::Open);
+
+        assert!(Ticketing::buy_ticket(RuntimeOrigin::signed(SCANNER), 0, 1).is_err());
+        assert_eq!(LiveEvents::<Test>::get(0).unwrap().seat_classes[1].sold, 0);
+        assert!(!Tickets::<Test>::contains_key(0));
+    });
+}
+
+#[test]
+fn redeem_by_scanner_only() {
+    new_test_ext().execute_with(|| {
+        sell_ticket(TransferPolicy::Open);
+        assert_ok!(Ticketing::add_scanner(
+            RuntimeOrigin::signed(ARTIST),
+            0,
+            SCANNER
+        ));
+
+        assert_noop!(
+            Ticketing::redeem(RuntimeOrigin::signed(FAN), 0),
+            Error::<Test>::NotScanner
+        );
+        assert_ok!(Ticketing::redeem(RuntimeOrigin::signed(SCANNER), 0));
+        assert!(Tickets::<Test>::get(0).unwrap().redeemed);
+        System::assert_last_event(
+            Event::TicketRedeemed {
+                ticket: 0,
+                scanner: SCANNER,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Ticketing::redeem(RuntimeOrigin::signed(SCANNER), 0),
+            Error::<Test>::AlreadyRedeemed
+        );
+    });
+}
+
+#[test]
+fn redeemed_ticket_cannot_change_hands() {
+    new_test_ext().execute_with(|| {
+        sell_ticket(TransferPolicy::Open);
+        assert_ok!(Ticketing::add_scanner(
+            RuntimeOrigin::signed(ARTIST),
+            0,
+            SCANNER
+        ));
+        assert_ok!(Ticketing::list_ticket(RuntimeOrigin::signed(FAN), 0, 80));
+
+        assert_ok!(Ticketing::redeem(RuntimeOrigin::signed(SCANNER), 0));
+
+        assert!(!Listings::<Test>::contains_key(0));
+        assert_noop!(
+            Ticketing::transfer_ticket(RuntimeOrigin::signed(FAN), 0, OTHER_FAN),
+            Error::<Test>::AlreadyRedeemed
+        );
+        assert_noop!(
+            Ticketing::list_ticket(RuntimeOrigin::signed(FAN), 0, 80),
+            Error::<Test>::AlreadyRedeemed
+        );
+    });
+}
+
+#[test]
+fn transfer_ticket_under_open_policy() {
+    new_test_ext().execute_with(|| {
+        sell_ticket(TransferPolicy::Open);
+
+        assert_noop!(
+            Ticketing::transfer_ticket(RuntimeOrigin::signed(OTHER_FAN), 0, OTHER_FAN),
+            Error::<Test>::NotTicketOwner
+        );
+        assert_ok!(Ticketing::transfer_ticket(
+            RuntimeOrigin::signed(FAN),
+            0,
+            OTHER_FAN
+        ));
+
+        assert_eq!(Tickets::<Test>::get(0).unwrap().owner, OTHER_FAN);
+        System::assert_last_event(
+            Event::TicketTransferred {
+                ticket: 0,
+                from: FAN,
+                to: OTHER_FAN,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn listed_ticket_cannot_be_transferred() {
+    new_test_ext().execute_with(|| {
+        sell_ticket(TransferPolicy::Open);
+        assert_ok!(Ticketing::list_ticket(RuntimeOrigin::signed(FAN), 0, 80));
+
+        assert_noop!(
+            Ticketing::transfer_ticket(RuntimeOrigin::signed(FAN), 0, OTHER_FAN),
+            Error::<Test>::TicketListed
+        );
+    });
+}
+
+#[test]
+fn non_transferable_tickets_stay_with_buyer() {
+    new_test_ext().execute_with(|| {
+        sell_ticket(TransferPolicy::NonTransferable);
+
+        assert_noop!(
+            Ticketing::transfer_ticket(RuntimeOrigin::signed(FAN), 0, OTHER_FAN),
+            Error::<Test>::TransferRestricted
+        );
+        assert_noop!(
+            Ticketing::list_ticket(RuntimeOrigin::signed(FAN), 0, 80),
+            Error::<Test>::TransferRestricted
+        );
+    });
+}
+
+#[test]
+fn resale_royalty_tickets_only_change_hands_through_resale() {
+    new_test_ext().execute_with(|| {
+        sell_ticket(TransferPolicy::ResaleRoyalty(Perbill::from_percent(10)));
+
+        assert_noop!(
+            Ticketing::transfer_ticket(RuntimeOrigin::signed(FAN), 0, OTHER_FAN),
+            Error::<Test>::TransferRestricted
+        );
+
+        assert_ok!(Ticketing::list_ticket(RuntimeOrigin::signed(FAN), 0, 80));
+        assert_ok!(Ticketing::buy_listed(RuntimeOrigin::signed(OTHER_FAN), 0));
+
+        assert_eq!(Tickets::<Test>::get(0).unwrap().owner, OTHER_FAN);
+        assert_eq!(Balances::free_balance(OTHER_FAN), 920);
+        // 950 after the primary sale, plus the price less the royalty.
+        assert_eq!(Balances::free_balance(FAN), 1_022);
+        // 50 from the primary sale, 8 of royalty.
+        assert_eq!(Balances::free_balance(ARTIST), 158);
+        System::assert_last_event(
+            Event::TicketResold {
+                ticket: 0,
+                seller: FAN,
+                buyer: OTHER_FAN,
+                price: 80,
+                royalty: 8,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn resale_under_open_policy_pays_seller_in_full() {
+    new_test_ext().execute_with(|| {
+        sell_ticket(TransferPolicy::Open);
+        assert_ok!(Ticketing::list_ticket(RuntimeOrigin::signed(FAN), 0, 80));
+
+        assert_noop!(
+            Ticketing::buy_listed(RuntimeOrigin::signed(FAN), 0),
+            Error::<Test>::AlreadyOwner
+        );
+        assert_ok!(Ticketing::buy_listed(RuntimeOrigin::signed(OTHER_FAN), 0));
+
+        assert_eq!(Balances::free_balance(FAN), 1_030);
+        assert_eq!(Balances::free_balance(ARTIST), 150);
+        assert!(!Listings::<Test>::contains_key(0));
+    });
+}
+
+#[test]
+fn delist_ticket_works() {
+    new_test_ext().execute_with(|| {
+        sell_ticket(TransferPolicy::Open);
+
+        assert_noop!(
+            Ticketing::delist_ticket(RuntimeOrigin::signed(FAN), 0),
+            Error::<Test>::NotListed
+        );
+        assert_ok!(Ticketing::list_ticket(RuntimeOrigin::signed(FAN), 0, 80));
+        assert_noop!(
+            Ticketing::delist_ticket(RuntimeOrigin::signed(OTHER_FAN), 0),
+            Error::<Test>::NotTicketOwner
+        );
+        assert_ok!(Ticketing::delist_ticket(RuntimeOrigin::signed(FAN), 0));
+
+        System::assert_last_event(Event::TicketDelisted { ticket: 0 }.into());
+        assert_noop!(
+            Ticketing::buy_listed(RuntimeOrigin::signed(OTHER_FAN), 0),
+            Error::<Test>::NotListed
+        );
+    });
+}
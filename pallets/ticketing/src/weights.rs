@@ -0,0 +1,108 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_ticketing.
+pub trait WeightInfo {
+    fn create_event(c: u32) -> Weight;
+    fn add_scanner() -> Weight;
+    fn remove_scanner() -> Weight;
+    fn buy_ticket() -> Weight;
+    fn redeem() -> Weight;
+    fn transfer_ticket() -> Weight;
+    fn list_ticket() -> Weight;
+    fn delist_ticket() -> Weight;
+    fn buy_listed() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Ticketing::NextEventId` (r:1 w:1)
+    /// Storage: `Ticketing::LiveEvents` (r:0 w:1)
+    /// The range of component `c` is `[1, 16]`.
+    fn create_event(c: u32) -> Weight {
+        Weight::from_parts(18_000_000, 1_500)
+            .saturating_add(Weight::from_parts(300_000, 0).saturating_mul(c.into()))
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Ticketing::LiveEvents` (r:1 w:0)
+    /// Storage: `Ticketing::Scanners` (r:1 w:1)
+    fn add_scanner() -> Weight {
+        Weight::from_parts(16_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Ticketing::LiveEvents` (r:1 w:0)
+    /// Storage: `Ticketing::Scanners` (r:1 w:1)
+    fn remove_scanner() -> Weight {
+        Weight::from_parts(16_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Ticketing::LiveEvents` (r:1 w:1)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `Ticketing::NextTicketId` (r:1 w:1)
+    /// Storage: `Ticketing::Tickets` (r:0 w:1)
+    fn buy_ticket() -> Weight {
+        Weight::from_parts(50_000_000, 6_000)
+            .saturating_add(ParityDbWeight::get().reads(4_u64))
+            .saturating_add(ParityDbWeight::get().writes(5_u64))
+    }
+    /// Storage: `Ticketing::Tickets` (r:1 w:1)
+    /// Storage: `Ticketing::Scanners` (r:1 w:0)
+    /// Storage: `Ticketing::Listings` (r:0 w:1)
+    fn redeem() -> Weight {
+        Weight::from_parts(18_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Ticketing::Tickets` (r:2 w:1)
+    /// Storage: `Ticketing::LiveEvents` (r:1 w:0)
+    /// Storage: `Ticketing::Listings` (r:1 w:0)
+    fn transfer_ticket() -> Weight {
+        Weight::from_parts(20_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(4_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Ticketing::Tickets` (r:1 w:0)
+    /// Storage: `Ticketing::LiveEvents` (r:1 w:0)
+    /// Storage: `Ticketing::Listings` (r:0 w:1)
+    fn list_ticket() -> Weight {
+        Weight::from_parts(17_000_000, 4_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Ticketing::Tickets` (r:1 w:0)
+    /// Storage: `Ticketing::Listings` (r:1 w:1)
+    fn delist_ticket() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Ticketing::Listings` (r:1 w:1)
+    /// Storage: `Ticketing::Tickets` (r:1 w:1)
+    /// Storage: `Ticketing::LiveEvents` (r:1 w:0)
+    /// Storage: `System::Account` (r:3 w:3)
+    fn buy_listed() -> Weight {
+        Weight::from_parts(65_000_000, 8_500)
+            .saturating_add(ParityDbWeight::get().reads(6_u64))
+            .saturating_add(ParityDbWeight::get().writes(5_u64))
+    }
+}
@@ -0,0 +1,36 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API exposing the runtime upgrade announced with `pallet-upgrade-notice`, for nodes
+//! to count down to its activation.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+
+pub use pallet_upgrade_notice::UpgradeNotice;
+
+sp_api::decl_runtime_apis! {
+    pub trait UpgradeNoticeApi<BlockNumber>
+    where
+        BlockNumber: Codec,
+    {
+        /// The upgrade announced and not activated yet, if any.
+        fn pending_upgrade() -> Option<UpgradeNotice<BlockNumber>>;
+    }
+}
@@ -0,0 +1,85 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+fn announce<T: Config>() -> BlockNumberFor<T> {
+    let activation = frame_system::Pallet::<T>::block_number()
+        .saturating_add(T::MinNoticePeriod::get())
+        .saturating_add(1u32.into());
+    PendingUpgrade::<T>::put(UpgradeNotice {
+        code_hash: H256::repeat_byte(1),
+        activation,
+        announced_at: frame_system::Pallet::<T>::block_number(),
+    });
+    activation
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn announce_upgrade() {
+        let activation =
+            frame_system::Pallet::<T>::block_number().saturating_add(T::MinNoticePeriod::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, H256::repeat_byte(1), activation);
+
+        assert!(PendingUpgrade::<T>::exists());
+    }
+
+    #[benchmark]
+    fn cancel_upgrade() {
+        announce::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root);
+
+        assert!(!PendingUpgrade::<T>::exists());
+    }
+
+    #[benchmark]
+    fn on_initialize_noop() {
+        let activation = announce::<T>();
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(activation.saturating_sub(1u32.into()));
+        }
+
+        assert!(PendingUpgrade::<T>::exists());
+    }
+
+    #[benchmark]
+    fn on_initialize_activation() {
+        let activation = announce::<T>();
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(activation);
+        }
+
+        assert!(!PendingUpgrade::<T>::exists());
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
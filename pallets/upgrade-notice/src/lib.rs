@@ -0,0 +1,189 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Upgrade Notice
+//!
+//! Announces upcoming mandatory runtime upgrades, so node operators are not caught by surprise
+//! at enactment.
+//!
+//! ## Features
+//! - Once governance accepts an upgrade, `AnnounceOrigin` records the hash of its code and the
+//!   block at which it activates. The activation must leave at least `Config::MinNoticePeriod`
+//!   blocks of notice.
+//! - One upgrade is announced at a time; it can be cancelled, and the notice is cleared once
+//!   its activation block is reached.
+//! - Nodes read the pending notice through the `pallet-upgrade-notice-runtime-api` crate and
+//!   count down to the activation in their logs, telemetry and RPC.
+//!
+//! The notice does not enact anything: the upgrade itself is still scheduled by governance.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_core::H256;
+use sp_runtime::Saturating;
+
+pub type UpgradeNoticeOf<T> = UpgradeNotice<BlockNumberFor<T>>;
+
+/// An announced runtime upgrade.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct UpgradeNotice<BlockNumber> {
+    /// Hash of the new runtime code.
+    pub code_hash: H256,
+    /// Block at which the upgrade activates.
+    pub activation: BlockNumber,
+    /// Block at which the upgrade was announced.
+    pub announced_at: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Origin allowed to announce and cancel upgrades.
+        type AnnounceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Min number of blocks between an announcement and the activation it announces.
+        #[pallet::constant]
+        type MinNoticePeriod: Get<BlockNumberFor<Self>>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// The upgrade announced and not activated yet, if any.
+    #[pallet::storage]
+    pub type PendingUpgrade<T: Config> = StorageValue<_, UpgradeNoticeOf<T>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        UpgradeAnnounced {
+            code_hash: H256,
+            activation: BlockNumberFor<T>,
+        },
+        UpgradeCancelled {
+            code_hash: H256,
+        },
+        /// The activation block of the announced upgrade was reached.
+        UpgradeActivationReached {
+            code_hash: H256,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The activation does not leave `MinNoticePeriod` blocks of notice.
+        NoticeTooShort,
+        UpgradeAlreadyPending,
+        NoPendingUpgrade,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            match PendingUpgrade::<T>::get() {
+                Some(notice) if notice.activation <= now => {
+                    PendingUpgrade::<T>::kill();
+                    Self::deposit_event(Event::UpgradeActivationReached {
+                        code_hash: notice.code_hash,
+                    });
+                    T::WeightInfo::on_initialize_activation()
+                }
+                _ => T::WeightInfo::on_initialize_noop(),
+            }
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Announce that the runtime with code hash `code_hash` activates at block
+        /// `activation`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::announce_upgrade())]
+        pub fn announce_upgrade(
+            origin: OriginFor<T>,
+            code_hash: H256,
+            activation: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::AnnounceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !PendingUpgrade::<T>::exists(),
+                Error::<T>::UpgradeAlreadyPending
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                activation >= now.saturating_add(T::MinNoticePeriod::get()),
+                Error::<T>::NoticeTooShort
+            );
+
+            PendingUpgrade::<T>::put(UpgradeNotice {
+                code_hash,
+                activation,
+                announced_at: now,
+            });
+            Self::deposit_event(Event::UpgradeAnnounced {
+                code_hash,
+                activation,
+            });
+            Ok(())
+        }
+
+        /// Withdraw the announced upgrade.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::cancel_upgrade())]
+        pub fn cancel_upgrade(origin: OriginFor<T>) -> DispatchResult {
+            T::AnnounceOrigin::ensure_origin(origin)?;
+
+            let notice = PendingUpgrade::<T>::take().ok_or(Error::<T>::NoPendingUpgrade)?;
+            Self::deposit_event(Event::UpgradeCancelled {
+                code_hash: notice.code_hash,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The upgrade announced and not activated yet, if any.
+        pub fn pending_upgrade() -> Option<UpgradeNoticeOf<T>> {
+            PendingUpgrade::<T>::get()
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error, Event, PendingUpgrade, UpgradeNotice as Notice, mock::*};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+fn code() -> H256 {
+    H256::repeat_byte(7)
+}
+
+#[test]
+fn announce_upgrade_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(UpgradeNotice::announce_upgrade(
+            RuntimeOrigin::root(),
+            code(),
+            11
+        ));
+
+        assert_eq!(
+            UpgradeNotice::pending_upgrade(),
+            Some(Notice {
+                code_hash: code(),
+                activation: 11,
+                announced_at: 1,
+            })
+        );
+        System::assert_last_event(
+            Event::UpgradeAnnounced {
+                code_hash: code(),
+                activation: 11,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn announce_upgrade_requires_announce_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            UpgradeNotice::announce_upgrade(RuntimeOrigin::signed(1), code(), 100),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn announce_upgrade_requires_min_notice() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            UpgradeNotice::announce_upgrade(RuntimeOrigin::root(), code(), 10),
+            Error::<Test>::NoticeTooShort
+        );
+    });
+}
+
+#[test]
+fn one_upgrade_pending_at_a_time() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(UpgradeNotice::announce_upgrade(
+            RuntimeOrigin::root(),
+            code(),
+            20
+        ));
+
+        assert_noop!(
+            UpgradeNotice::announce_upgrade(RuntimeOrigin::root(), H256::repeat_byte(8), 30),
+            Error::<Test>::UpgradeAlreadyPending
+        );
+    });
+}
+
+#[test]
+fn cancel_upgrade_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            UpgradeNotice::cancel_upgrade(RuntimeOrigin::root()),
+            Error::<Test>::NoPendingUpgrade
+        );
+        assert_ok!(UpgradeNotice::announce_upgrade(
+            RuntimeOrigin::root(),
+            code(),
+            20
+        ));
+
+        assert_noop!(
+            UpgradeNotice::cancel_upgrade(RuntimeOrigin::signed(1)),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(UpgradeNotice::cancel_upgrade(RuntimeOrigin::root()));
+
+        assert!(!PendingUpgrade::<Test>::exists());
+        System::assert_last_event(Event::UpgradeCancelled { code_hash: code() }.into());
+    });
+}
+
+#[test]
+fn notice_cleared_at_activation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(UpgradeNotice::announce_upgrade(
+            RuntimeOrigin::root(),
+            code(),
+            20
+        ));
+
+        run_to_block(19);
+        assert!(PendingUpgrade::<Test>::exists());
+
+        run_to_block(20);
+        assert!(!PendingUpgrade::<Test>::exists());
+        System::assert_last_event(Event::UpgradeActivationReached { code_hash: code() }.into());
+    });
+}
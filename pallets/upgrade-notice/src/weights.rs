@@ -0,0 +1,53 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_upgrade_notice.
+pub trait WeightInfo {
+    fn announce_upgrade() -> Weight;
+    fn cancel_upgrade() -> Weight;
+    fn on_initialize_noop() -> Weight;
+    fn on_initialize_activation() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `UpgradeNotice::PendingUpgrade` (r:1 w:1)
+    fn announce_upgrade() -> Weight {
+        Weight::from_parts(12_000_000, 1_600)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `UpgradeNotice::PendingUpgrade` (r:1 w:1)
+    fn cancel_upgrade() -> Weight {
+        Weight::from_parts(11_000_000, 1_600)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `UpgradeNotice::PendingUpgrade` (r:1 w:0)
+    fn on_initialize_noop() -> Weight {
+        Weight::from_parts(2_000_000, 1_600).saturating_add(ParityDbWeight::get().reads(1_u64))
+    }
+    /// Storage: `UpgradeNotice::PendingUpgrade` (r:1 w:1)
+    fn on_initialize_activation() -> Weight {
+        Weight::from_parts(8_000_000, 1_600)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,78 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Aggregation of a period's reports into finalized play counts.
+
+use super::*;
+use alloc::collections::BTreeMap;
+
+/// Reports rejected as outliers, as provider and recording pairs.
+pub(crate) type OutliersOf<T> = Vec<(
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::RecordingId,
+)>;
+
+impl<T: Config> Pallet<T> {
+    /// Aggregate `reports` into a period's finalized play counts, highest first, and list the
+    /// `(provider, recording)` pairs rejected as outliers.
+    ///
+    /// Each recording reported by a quorum gets the (lower) median of its reports; reports
+    /// deviating from it by more than `OutlierTolerance` are outliers. A recording left
+    /// without a quorum once outliers are set aside gets no play count. Past
+    /// `MaxReportEntries` recordings, the lowest play counts are dropped.
+    pub(crate) fn aggregate(
+        reports: Vec<(T::AccountId, UsageReportOf<T>)>,
+    ) -> (UsageReportOf<T>, OutliersOf<T>) {
+        let mut by_recording: BTreeMap<T::RecordingId, Vec<(T::AccountId, u32)>> = BTreeMap::new();
+        for (provider, report) in reports {
+            for (recording, plays) in report {
+                by_recording
+                    .entry(recording)
+                    .or_default()
+                    .push((provider.clone(), plays));
+            }
+        }
+
+        let quorum = T::MinQuorum::get().max(1) as usize;
+        let mut aggregate = Vec::new();
+        let mut outliers = Vec::new();
+        for (recording, mut reported) in by_recording {
+            if reported.len() < quorum {
+                continue;
+            }
+            reported.sort_by_key(|(_, plays)| *plays);
+            let median = reported[(reported.len() - 1) / 2].1;
+            let tolerance = T::OutlierTolerance::get().mul_ceil(median);
+
+            let mut accepted = 0;
+            for (provider, plays) in reported {
+                if plays.abs_diff(median) > tolerance {
+                    outliers.push((provider, recording.clone()));
+                } else {
+                    accepted += 1;
+                }
+            }
+            if accepted >= quorum {
+                aggregate.push((recording, median));
+            }
+        }
+
+        // Stable: equal play counts keep the recordings' order.
+        aggregate.sort_by(|(_, a), (_, b)| b.cmp(a));
+        (BoundedVec::truncate_from(aggregate), outliers)
+    }
+}
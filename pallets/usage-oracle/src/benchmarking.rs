@@ -0,0 +1,140 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn funded<T: Config>(name: &'static str, i: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, i, SEED);
+    T::Currency::set_balance(
+        &who,
+        T::ProviderBond::get().saturating_add(T::Currency::minimum_balance()) * 2u32.into(),
+    );
+    who
+}
+
+/// The `i`-th provider, registered.
+fn provider<T: Config>(i: u32) -> T::AccountId {
+    let who = funded::<T>("provider", i);
+    Pallet::<T>::register_provider(RawOrigin::Signed(who.clone()).into())
+        .expect("provider is funded");
+    who
+}
+
+/// A report of `e` recordings, `plays` each.
+fn bench_report<T: Config>(e: u32, plays: u32) -> UsageReportOf<T> {
+    (0..e)
+        .map(|i| (T::BenchmarkHelper::recording(i), plays))
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("e <= MaxReportEntries")
+}
+
+/// Move to the first block of `period`.
+fn go_to_period<T: Config>(period: u32) {
+    let length: u32 = T::PeriodLength::get().unique_saturated_into();
+    frame_system::Pallet::<T>::set_block_number((period * length.max(1)).into());
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn register_provider() {
+        let who = funded::<T>("provider", 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who.clone()));
+
+        assert!(Providers::<T>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn unregister_provider() {
+        let who = provider::<T>(0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who.clone()));
+
+        assert!(Providers::<T>::get(&who).is_some_and(|p| p.unbonding_at.is_some()));
+    }
+
+    #[benchmark]
+    fn withdraw_bond() {
+        let who = provider::<T>(0);
+        Pallet::<T>::unregister_provider(RawOrigin::Signed(who.clone()).into())
+            .expect("provider is registered");
+        frame_system::Pallet::<T>::set_block_number(T::UnbondingPeriod::get() + 1u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who.clone()));
+
+        assert!(!Providers::<T>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn submit_report(e: Linear<1, { T::MaxReportEntries::get() }>) {
+        let who = provider::<T>(0);
+        go_to_period::<T>(1);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(who.clone()),
+            0,
+            bench_report::<T>(e, 1_000),
+        );
+
+        assert!(Submissions::<T>::contains_key(0, &who));
+    }
+
+    /// `p` providers reporting the same `e` recordings, the first one as an outlier.
+    #[benchmark]
+    fn finalize_period(
+        p: Linear<1, { T::MaxProviders::get() }>,
+        e: Linear<1, { T::MaxReportEntries::get() }>,
+    ) {
+        for i in 0..p {
+            let who = provider::<T>(i);
+            let plays = if i == 0 { 1_000_000 } else { 1_000 };
+            Submissions::<T>::insert(0, &who, bench_report::<T>(e, plays));
+        }
+        SubmissionCount::<T>::insert(0, p);
+        go_to_period::<T>(2);
+        let caller: T::AccountId = account("caller", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0);
+
+        assert!(Aggregates::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn slash() {
+        let who = provider::<T>(0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, who.clone(), T::ProviderBond::get());
+
+        assert!(Providers::<T>::get(&who).is_some_and(|p| p.bond.is_zero()));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,521 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! # Pallet Usage Oracle
+//!
+//! Streaming usage reports (plays per recording per period) from bonded data providers,
+//! aggregated on chain into the figures royalty distributions are computed from.
+//!
+//! ## Features
+//! - Any account can become a data provider by bonding `Config::ProviderBond`. Leaving takes
+//!   `Config::UnbondingPeriod`, during which the bond can still be slashed.
+//! - Time is cut into periods of `Config::PeriodLength` blocks. Once a period is over, each
+//!   provider submits one report for it, during the following period.
+//! - After that, the period is finalized: for each recording reported by at least
+//!   `Config::MinQuorum` providers, the median play count is taken, and reports deviating
+//!   from it by more than `Config::OutlierTolerance` are rejected as outliers. Recordings
+//!   still reported by a quorum once outliers are set aside get the median as their
+//!   finalized play count.
+//! - Finalization runs in the idle weight of the blocks following the submission window. A
+//!   period it couldn't fit in before the next window closed is finalized by anyone through
+//!   [`Pallet::finalize_period`].
+//! - Providers whose reports are rejected lose `Config::OutlierSlash` of their bond, once per
+//!   period. `Config::SlashOrigin` can slash further on off-chain proof of a wrong report.
+//! - Finalized aggregates are read through [`Pallet::finalized_usage`], e.g. by the royalty
+//!   distribution engine.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod aggregation;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use alloc::vec::Vec;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{Fortitude, Precision},
+    },
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{
+    Perbill, Saturating,
+    traits::{UniqueSaturatedInto, Zero},
+};
+
+/// Index of a reporting period, counted from genesis.
+pub type UsagePeriod = u32;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type UsageReportOf<T> =
+    BoundedVec<(<T as Config>::RecordingId, u32), <T as Config>::MaxReportEntries>;
+
+pub type ProviderOf<T> = Provider<BalanceOf<T>, BlockNumberFor<T>>;
+
+/// A bonded data provider.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Provider<Balance, BlockNumber> {
+    /// Amount held from the provider, less what was slashed.
+    pub bond: Balance,
+    /// Block from which the bond can be withdrawn, once the provider left.
+    pub unbonding_at: Option<BlockNumber>,
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<RecordingId> {
+    /// The `i`-th recording id.
+    fn recording(i: u32) -> RecordingId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Origin allowed to slash a provider on proof of a wrong report.
+        type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Identifier of a recording usage is reported for.
+        type RecordingId: Parameter + Member + MaxEncodedLen + Ord;
+
+        /// Amount held from each data provider.
+        #[pallet::constant]
+        type ProviderBond: Get<BalanceOf<Self>>;
+
+        /// Blocks a leaving provider's bond stays slashable.
+        #[pallet::constant]
+        type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Length of a reporting period, in blocks.
+        #[pallet::constant]
+        type PeriodLength: Get<BlockNumberFor<Self>>;
+
+        /// Max number of data providers, including leaving ones.
+        #[pallet::constant]
+        type MaxProviders: Get<u32>;
+
+        /// Max number of entries in a report, and in a period's aggregate.
+        #[pallet::constant]
+        type MaxReportEntries: Get<u32>;
+
+        /// Reports a recording needs, outliers set aside, to get a finalized play count.
+        #[pallet::constant]
+        type MinQuorum: Get<u32>;
+
+        /// Max deviation from the median, relative to it, before a report is an outlier.
+        #[pallet::constant]
+        type OutlierTolerance: Get<Perbill>;
+
+        /// Share of its bond a provider loses for a period with outlier reports.
+        #[pallet::constant]
+        type OutlierSlash: Get<Perbill>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::RecordingId>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Bond of a data provider.
+        ProviderBond,
+    }
+
+    #[pallet::storage]
+    pub type Providers<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ProviderOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type ProviderCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Reports of periods not finalized yet, keyed by period and provider.
+    #[pallet::storage]
+    pub type Submissions<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        UsagePeriod,
+        Blake2_128Concat,
+        T::AccountId,
+        UsageReportOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    pub type SubmissionCount<T: Config> = StorageMap<_, Twox64Concat, UsagePeriod, u32, ValueQuery>;
+
+    /// Finalized play counts of each period, highest first.
+    #[pallet::storage]
+    pub type Aggregates<T: Config> =
+        StorageMap<_, Twox64Concat, UsagePeriod, UsageReportOf<T>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        ProviderRegistered {
+            who: T::AccountId,
+            bond: BalanceOf<T>,
+        },
+        ProviderUnbonding {
+            who: T::AccountId,
+            until: BlockNumberFor<T>,
+        },
+        BondWithdrawn {
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        ReportSubmitted {
+            period: UsagePeriod,
+            provider: T::AccountId,
+        },
+        OutlierRejected {
+            period: UsagePeriod,
+            provider: T::AccountId,
+            recording: T::RecordingId,
+        },
+        ProviderSlashed {
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// `recordings` got a finalized play count.
+        PeriodFinalized {
+            period: UsagePeriod,
+            recordings: u32,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        AlreadyProvider,
+        TooManyProviders,
+        NotProvider,
+        /// The provider is leaving.
+        Unbonding,
+        NotUnbonding,
+        StillUnbonding,
+        /// Slashes left the bond below `ProviderBond`.
+        InsufficientBond,
+        /// Reports are only accepted during the period following the reported one.
+        OutsideSubmissionWindow,
+        AlreadySubmitted,
+        EmptyReport,
+        ZeroPlays,
+        DuplicateReportEntry,
+        /// The period's submission window is not over yet.
+        PeriodNotClosed,
+        AlreadyFinalized,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::finalize_closed_period(remaining_weight)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Become a data provider, holding `ProviderBond` from the caller.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::register_provider())]
+        pub fn register_provider(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                !Providers::<T>::contains_key(&who),
+                Error::<T>::AlreadyProvider
+            );
+            let count = ProviderCount::<T>::get();
+            ensure!(count < T::MaxProviders::get(), Error::<T>::TooManyProviders);
+
+            let bond = T::ProviderBond::get();
+            T::Currency::hold(&HoldReason::ProviderBond.into(), &who, bond)?;
+            Providers::<T>::insert(
+                &who,
+                Provider {
+                    bond,
+                    unbonding_at: None,
+                },
+            );
+            ProviderCount::<T>::put(count.saturating_add(1));
+
+            Self::deposit_event(Event::ProviderRegistered { who, bond });
+            Ok(())
+        }
+
+        /// Stop reporting. The bond can be withdrawn after `UnbondingPeriod`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::unregister_provider())]
+        pub fn unregister_provider(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let until = Providers::<T>::try_mutate(&who, |maybe_provider| {
+                let provider = maybe_provider.as_mut().ok_or(Error::<T>::NotProvider)?;
+                ensure!(provider.unbonding_at.is_none(), Error::<T>::Unbonding);
+                let until = frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::UnbondingPeriod::get());
+                provider.unbonding_at = Some(until);
+                Ok::<_, DispatchError>(until)
+            })?;
+
+            Self::deposit_event(Event::ProviderUnbonding { who, until });
+            Ok(())
+        }
+
+        /// Release what is left of the caller's bond, once unbonded.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::withdraw_bond())]
+        pub fn withdraw_bond(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let provider = Providers::<T>::get(&who).ok_or(Error::<T>::NotProvider)?;
+            let until = provider.unbonding_at.ok_or(Error::<T>::NotUnbonding)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= until,
+                Error::<T>::StillUnbonding
+            );
+
+            let amount = T::Currency::release(
+                &HoldReason::ProviderBond.into(),
+                &who,
+                provider.bond,
+                Precision::BestEffort,
+            )?;
+            Providers::<T>::remove(&who);
+            ProviderCount::<T>::mutate(|count| count.saturating_dec());
+
+            Self::deposit_event(Event::BondWithdrawn { who, amount });
+            Ok(())
+        }
+
+        /// Submit the caller's usage report for `period`, which must be the last completed
+        /// period. Providers only, once per period.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::submit_report(report.len() as u32))]
+        pub fn submit_report(
+            origin: OriginFor<T>,
+            period: UsagePeriod,
+            report: UsageReportOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let provider = Providers::<T>::get(&who).ok_or(Error::<T>::NotProvider)?;
+            ensure!(provider.unbonding_at.is_none(), Error::<T>::Unbonding);
+            ensure!(
+                provider.bond >= T::ProviderBond::get(),
+                Error::<T>::InsufficientBond
+            );
+            ensure!(
+                Self::current_period() == period.saturating_add(1),
+                Error::<T>::OutsideSubmissionWindow
+            );
+            ensure!(
+                !Submissions::<T>::contains_key(period, &who),
+                Error::<T>::AlreadySubmitted
+            );
+            ensure!(!report.is_empty(), Error::<T>::EmptyReport);
+            for (i, (recording, plays)) in report.iter().enumerate() {
+                ensure!(*plays > 0, Error::<T>::ZeroPlays);
+                ensure!(
+                    !report[..i].iter().any(|(other, _)| other == recording),
+                    Error::<T>::DuplicateReportEntry
+                );
+            }
+
+            Submissions::<T>::insert(period, &who, report);
+            SubmissionCount::<T>::mutate(period, |count| count.saturating_inc());
+
+            Self::deposit_event(Event::ReportSubmitted {
+                period,
+                provider: who,
+            });
+            Ok(())
+        }
+
+        /// Aggregate the reports submitted for `period` and slash the providers of outliers.
+        ///
+        /// Callable by anyone once the period's submission window is over, for periods not
+        /// finalized on idle.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::finalize_period(
+            T::MaxProviders::get(),
+            T::MaxReportEntries::get(),
+        ))]
+        pub fn finalize_period(
+            origin: OriginFor<T>,
+            period: UsagePeriod,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            ensure!(
+                Self::current_period() > period.saturating_add(1),
+                Error::<T>::PeriodNotClosed
+            );
+            ensure!(
+                !Aggregates::<T>::contains_key(period),
+                Error::<T>::AlreadyFinalized
+            );
+
+            let submissions = Self::do_finalize(period);
+            Ok(Some(T::WeightInfo::finalize_period(
+                submissions,
+                T::MaxReportEntries::get(),
+            ))
+            .into())
+        }
+
+        /// Slash up to `amount` of `who`'s bond, on proof of a wrong report.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::slash())]
+        pub fn slash(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::SlashOrigin::ensure_origin(origin)?;
+
+            ensure!(Providers::<T>::contains_key(&who), Error::<T>::NotProvider);
+            Self::slash_bond(&who, amount);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The period the current block belongs to.
+        pub fn current_period() -> UsagePeriod {
+            let now: u32 = frame_system::Pallet::<T>::block_number().unique_saturated_into();
+            let length: u32 = T::PeriodLength::get().unique_saturated_into();
+            now / length.max(1)
+        }
+
+        /// Finalized play counts of `period`, `None` until it is finalized.
+        pub fn finalized_usage(period: UsagePeriod) -> Option<Vec<(T::RecordingId, u32)>> {
+            Aggregates::<T>::get(period).map(BoundedVec::into_inner)
+        }
+
+        /// Finalize the period whose submission window just closed, if `remaining_weight`
+        /// allows it.
+        ///
+        /// Periods without reports are left alone: there is nothing to aggregate nor slash.
+        pub(crate) fn finalize_closed_period(remaining_weight: Weight) -> Weight {
+            // The period's aggregate and submission count.
+            let used = T::DbWeight::get().reads(2);
+            if remaining_weight.any_lt(used) {
+                return Weight::zero();
+            }
+            let Some(period) = Self::current_period().checked_sub(2) else {
+                return Weight::zero();
+            };
+            if Aggregates::<T>::contains_key(period) {
+                return used;
+            }
+            let submissions = SubmissionCount::<T>::get(period);
+            if submissions.is_zero() {
+                return used;
+            }
+
+            let weight = used.saturating_add(T::WeightInfo::finalize_period(
+                submissions,
+                T::MaxReportEntries::get(),
+            ));
+            if remaining_weight.any_lt(weight) {
+                return used;
+            }
+            Self::do_finalize(period);
+            weight
+        }
+
+        /// Aggregate the reports of `period`, slash the providers of outliers and store the
+        /// aggregate. Returns the number of reports aggregated.
+        fn do_finalize(period: UsagePeriod) -> u32 {
+            let submissions = SubmissionCount::<T>::take(period);
+            let reports: Vec<_> = Submissions::<T>::drain_prefix(period).collect();
+            let (aggregate, outliers) = Self::aggregate(reports);
+
+            let mut slashed: Vec<T::AccountId> = Vec::new();
+            for (provider, recording) in outliers {
+                if !slashed.contains(&provider) {
+                    let amount = Providers::<T>::get(&provider)
+                        .map(|p| T::OutlierSlash::get().mul_floor(p.bond))
+                        .unwrap_or_else(Zero::zero);
+                    Self::slash_bond(&provider, amount);
+                    slashed.push(provider.clone());
+                }
+                Self::deposit_event(Event::OutlierRejected {
+                    period,
+                    provider,
+                    recording,
+                });
+            }
+
+            let recordings = aggregate.len() as u32;
+            Aggregates::<T>::insert(period, aggregate);
+
+            Self::deposit_event(Event::PeriodFinalized { period, recordings });
+            submissions
+        }
+
+        /// Burn up to `amount` of `who`'s bond.
+        fn slash_bond(who: &T::AccountId, amount: BalanceOf<T>) {
+            Providers::<T>::mutate(who, |maybe_provider| {
+                let Some(provider) = maybe_provider else {
+                    return;
+                };
+                let burned = T::Currency::burn_held(
+                    &HoldReason::ProviderBond.into(),
+                    who,
+                    amount.min(provider.bond),
+                    Precision::BestEffort,
+                    Fortitude::Force,
+                )
+                .unwrap_or_else(|_| Zero::zero());
+                provider.bond.saturating_reduce(burned);
+                Self::deposit_event(Event::ProviderSlashed {
+                    who: who.clone(),
+                    amount: burned,
+                });
+            });
+        }
+    }
+}
@@ -0,0 +1,137 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate as pallet_usage_oracle;
+use frame_support::{derive_impl, parameter_types, sp_runtime::BuildStorage};
+use frame_system::EnsureRoot;
+use sp_runtime::Perbill;
+
+pub type Balance = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type UsageOracle = pallet_usage_oracle;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+}
+
+parameter_types! {
+    pub const ProviderBond: Balance = PROVIDER_BOND;
+    pub const UnbondingPeriod: u64 = 20;
+    pub const PeriodLength: u64 = PERIOD_LENGTH;
+    pub const MaxProviders: u32 = 4;
+    pub const MaxReportEntries: u32 = 3;
+    pub const MinQuorum: u32 = 2;
+    pub const OutlierTolerance: Perbill = Perbill::from_percent(10);
+    pub const OutlierSlash: Perbill = Perbill::from_percent(10);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockRecordings;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u32> for MockRecordings {
+    fn recording(i: u32) -> u32 {
+        i
+    }
+}
+
+impl pallet_usage_oracle::Config for Test {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type SlashOrigin = EnsureRoot<Self::AccountId>;
+    type RecordingId = u32;
+    type ProviderBond = ProviderBond;
+    type UnbondingPeriod = UnbondingPeriod;
+    type PeriodLength = PeriodLength;
+    type MaxProviders = MaxProviders;
+    type MaxReportEntries = MaxReportEntries;
+    type MinQuorum = MinQuorum;
+    type OutlierTolerance = OutlierTolerance;
+    type OutlierSlash = OutlierSlash;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockRecordings;
+}
+
+pub const PROVIDER_BOND: Balance = 100;
+pub const PERIOD_LENGTH: u64 = 10;
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+pub const DAVE: u64 = 4;
+pub const EVE: u64 = 5;
+/// Cannot afford the provider bond.
+pub const POOR: u64 = 6;
+
+pub const TRACK_A: u32 = 1;
+pub const TRACK_B: u32 = 2;
+pub const TRACK_C: u32 = 3;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ALICE, 1_000),
+            (BOB, 1_000),
+            (CHARLIE, 1_000),
+            (DAVE, 1_000),
+            (EVE, 1_000),
+            (POOR, 10),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
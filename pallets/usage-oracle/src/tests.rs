@@ -0,0 +1,355 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{
+    Aggregates, Error, Event, HoldReason, ProviderCount, Providers, SubmissionCount, Submissions,
+    UsageReportOf, mock::*,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Hooks, fungible::InspectHold},
+    weights::Weight,
+};
+use sp_runtime::DispatchError;
+
+fn register(who: u64) {
+    assert_ok!(UsageOracle::register_provider(RuntimeOrigin::signed(who)));
+}
+
+fn report(entries: &[(u32, u32)]) -> UsageReportOf<Test> {
+    entries.to_vec().try_into().unwrap()
+}
+
+fn submit(who: u64, period: u32, entries: &[(u32, u32)]) -> sp_runtime::DispatchResult {
+    UsageOracle::submit_report(RuntimeOrigin::signed(who), period, report(entries))
+}
+
+fn finalize(period: u32) -> sp_runtime::DispatchResult {
+    UsageOracle::finalize_period(RuntimeOrigin::signed(EVE), period)
+        .map(|_| ())
+        .map_err(|e| e.error)
+}
+
+fn bond_of(who: u64) -> Balance {
+    Balances::balance_on_hold(&HoldReason::ProviderBond.into(), &who)
+}
+
+/// The first block of `period`.
+fn go_to_period(period: u32) {
+    System::set_block_number(u64::from(period) * PERIOD_LENGTH);
+}
+
+#[test]
+fn providers_bond_to_register() {
+    new_test_ext().execute_with(|| {
+        register(ALICE);
+        System::assert_last_event(
+            Event::ProviderRegistered {
+                who: ALICE,
+                bond: PROVIDER_BOND,
+            }
+            .into(),
+        );
+        assert_eq!(bond_of(ALICE), PROVIDER_BOND);
+        assert_noop!(
+            UsageOracle::register_provider(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::AlreadyProvider
+        );
+        assert!(UsageOracle::register_provider(RuntimeOrigin::signed(POOR)).is_err());
+
+        register(BOB);
+        register(CHARLIE);
+        register(DAVE);
+        assert_eq!(ProviderCount::<Test>::get(), 4);
+        assert_noop!(
+            UsageOracle::register_provider(RuntimeOrigin::signed(EVE)),
+            Error::<Test>::TooManyProviders
+        );
+    });
+}
+
+#[test]
+fn bond_is_withdrawn_after_unbonding() {
+    new_test_ext().execute_with(|| {
+        register(ALICE);
+        let withdraw = || UsageOracle::withdraw_bond(RuntimeOrigin::signed(ALICE));
+
+        assert_noop!(withdraw(), Error::<Test>::NotUnbonding);
+        assert_ok!(UsageOracle::unregister_provider(RuntimeOrigin::signed(
+            ALICE
+        )));
+        System::assert_last_event(
+            Event::ProviderUnbonding {
+                who: ALICE,
+                until: 21,
+            }
+            .into(),
+        );
+        assert_noop!(
+            UsageOracle::unregister_provider(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::Unbonding
+        );
+
+        System::set_block_number(20);
+        assert_noop!(withdraw(), Error::<Test>::StillUnbonding);
+        System::set_block_number(21);
+        assert_ok!(withdraw());
+        System::assert_last_event(
+            Event::BondWithdrawn {
+                who: ALICE,
+                amount: PROVIDER_BOND,
+            }
+            .into(),
+        );
+        assert_eq!(bond_of(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 1_000);
+        assert_eq!(ProviderCount::<Test>::get(), 0);
+        assert_noop!(withdraw(), Error::<Test>::NotProvider);
+    });
+}
+
+#[test]
+fn reports_are_submitted_in_the_following_period() {
+    new_test_ext().execute_with(|| {
+        register(ALICE);
+        register(BOB);
+
+        // Period 0 is not over yet.
+        assert_noop!(
+            submit(ALICE, 0, &[(TRACK_A, 1)]),
+            Error::<Test>::OutsideSubmissionWindow
+        );
+
+        go_to_period(1);
+        assert_ok!(submit(ALICE, 0, &[(TRACK_A, 1)]));
+        System::assert_last_event(
+            Event::ReportSubmitted {
+                period: 0,
+                provider: ALICE,
+            }
+            .into(),
+        );
+        assert_eq!(SubmissionCount::<Test>::get(0), 1);
+        assert_noop!(
+            submit(ALICE, 0, &[(TRACK_A, 1)]),
+            Error::<Test>::AlreadySubmitted
+        );
+        assert_noop!(submit(EVE, 0, &[(TRACK_A, 1)]), Error::<Test>::NotProvider);
+        assert_noop!(submit(BOB, 0, &[]), Error::<Test>::EmptyReport);
+        assert_noop!(submit(BOB, 0, &[(TRACK_A, 0)]), Error::<Test>::ZeroPlays);
+        assert_noop!(
+            submit(BOB, 0, &[(TRACK_A, 1), (TRACK_A, 2)]),
+            Error::<Test>::DuplicateReportEntry
+        );
+
+        assert_ok!(UsageOracle::unregister_provider(RuntimeOrigin::signed(BOB)));
+        assert_noop!(submit(BOB, 0, &[(TRACK_A, 1)]), Error::<Test>::Unbonding);
+
+        go_to_period(2);
+        assert_noop!(
+            submit(ALICE, 0, &[(TRACK_B, 1)]),
+            Error::<Test>::OutsideSubmissionWindow
+        );
+        assert_ok!(submit(ALICE, 1, &[(TRACK_A, 1)]));
+    });
+}
+
+#[test]
+fn finalization_takes_the_median_and_slashes_outliers() {
+    new_test_ext().execute_with(|| {
+        for who in [ALICE, BOB, CHARLIE, DAVE] {
+            register(who);
+        }
+        go_to_period(1);
+        assert_ok!(submit(ALICE, 0, &[(TRACK_A, 100), (TRACK_B, 50)]));
+        assert_ok!(submit(
+            BOB,
+            0,
+            &[(TRACK_A, 105), (TRACK_B, 50), (TRACK_C, 7)]
+        ));
+        assert_ok!(submit(CHARLIE, 0, &[(TRACK_A, 98)]));
+        assert_ok!(submit(DAVE, 0, &[(TRACK_A, 300), (TRACK_B, 52)]));
+
+        assert_noop!(finalize(0), Error::<Test>::PeriodNotClosed);
+        go_to_period(2);
+        assert_ok!(finalize(0));
+
+        // TRACK_C was reported by a single provider, short of the quorum.
+        assert_eq!(
+            UsageOracle::finalized_usage(0),
+            Some(vec![(TRACK_A, 100), (TRACK_B, 50)])
+        );
+        System::assert_has_event(
+            Event::OutlierRejected {
+                period: 0,
+                provider: DAVE,
+                recording: TRACK_A,
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            Event::ProviderSlashed {
+                who: DAVE,
+                amount: 10,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::PeriodFinalized {
+                period: 0,
+                recordings: 2,
+            }
+            .into(),
+        );
+        assert_eq!(bond_of(DAVE), PROVIDER_BOND - 10);
+        assert_eq!(
+            Providers::<Test>::get(DAVE).unwrap().bond,
+            PROVIDER_BOND - 10
+        );
+        assert_eq!(bond_of(ALICE), PROVIDER_BOND);
+        assert_eq!(Submissions::<Test>::iter_prefix(0).count(), 0);
+        assert_eq!(SubmissionCount::<Test>::get(0), 0);
+
+        assert_noop!(finalize(0), Error::<Test>::AlreadyFinalized);
+        assert_eq!(UsageOracle::finalized_usage(1), None);
+        // Slashed below the bond, DAVE no longer reports.
+        assert_noop!(
+            submit(DAVE, 1, &[(TRACK_A, 1)]),
+            Error::<Test>::InsufficientBond
+        );
+    });
+}
+
+#[test]
+fn closed_periods_are_finalized_on_idle() {
+    new_test_ext().execute_with(|| {
+        for who in [ALICE, BOB, CHARLIE] {
+            register(who);
+        }
+        let idle = |weight| UsageOracle::on_idle(System::block_number(), weight);
+        go_to_period(1);
+        assert_ok!(submit(ALICE, 0, &[(TRACK_A, 100)]));
+        assert_ok!(submit(BOB, 0, &[(TRACK_A, 102)]));
+        assert_ok!(submit(CHARLIE, 0, &[(TRACK_A, 150)]));
+
+        // Reports are still accepted.
+        idle(Weight::MAX);
+        assert_eq!(UsageOracle::finalized_usage(0), None);
+
+        go_to_period(2);
+        // No weight left in the block.
+        idle(Weight::zero());
+        assert_eq!(UsageOracle::finalized_usage(0), None);
+
+        assert!(idle(Weight::MAX).all_gt(Weight::zero()));
+        assert_eq!(UsageOracle::finalized_usage(0), Some(vec![(TRACK_A, 102)]));
+        System::assert_has_event(
+            Event::ProviderSlashed {
+                who: CHARLIE,
+                amount: 10,
+            }
+            .into(),
+        );
+        assert_noop!(finalize(0), Error::<Test>::AlreadyFinalized);
+
+        // Period 1 missed its turn, and is left to `finalize_period`.
+        assert_ok!(submit(ALICE, 1, &[(TRACK_A, 10)]));
+        assert_ok!(submit(BOB, 1, &[(TRACK_A, 10)]));
+        go_to_period(4);
+        idle(Weight::MAX);
+        assert_eq!(UsageOracle::finalized_usage(1), None);
+        assert_ok!(finalize(1));
+        assert_eq!(UsageOracle::finalized_usage(1), Some(vec![(TRACK_A, 10)]));
+    });
+}
+
+#[test]
+fn outliers_can_leave_a_recording_without_quorum() {
+    new_test_ext().execute_with(|| {
+        register(ALICE);
+        register(BOB);
+        go_to_period(1);
+        assert_ok!(submit(ALICE, 0, &[(TRACK_A, 100)]));
+        assert_ok!(submit(BOB, 0, &[(TRACK_A, 200)]));
+
+        go_to_period(2);
+        assert_ok!(finalize(0));
+        assert_eq!(UsageOracle::finalized_usage(0), Some(vec![]));
+        assert_eq!(bond_of(BOB), PROVIDER_BOND - 10);
+    });
+}
+
+#[test]
+fn aggregate_keeps_the_highest_play_counts() {
+    new_test_ext().execute_with(|| {
+        for who in [ALICE, BOB, CHARLIE] {
+            register(who);
+        }
+        let track_d = 4;
+        go_to_period(1);
+        assert_ok!(submit(
+            ALICE,
+            0,
+            &[(TRACK_B, 20), (TRACK_C, 30), (track_d, 40)]
+        ));
+        assert_ok!(submit(
+            BOB,
+            0,
+            &[(TRACK_A, 10), (TRACK_C, 30), (track_d, 40)]
+        ));
+        assert_ok!(submit(CHARLIE, 0, &[(TRACK_A, 10), (TRACK_B, 20)]));
+
+        go_to_period(2);
+        assert_ok!(finalize(0));
+        assert_eq!(
+            Aggregates::<Test>::get(0).unwrap().to_vec(),
+            vec![(track_d, 40), (TRACK_C, 30), (TRACK_B, 20)]
+        );
+    });
+}
+
+#[test]
+fn slash_origin_slashes_on_proof() {
+    new_test_ext().execute_with(|| {
+        register(ALICE);
+
+        assert_noop!(
+            UsageOracle::slash(RuntimeOrigin::signed(BOB), ALICE, 30),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            UsageOracle::slash(RuntimeOrigin::root(), BOB, 30),
+            Error::<Test>::NotProvider
+        );
+        assert_ok!(UsageOracle::slash(RuntimeOrigin::root(), ALICE, 30));
+        System::assert_last_event(
+            Event::ProviderSlashed {
+                who: ALICE,
+                amount: 30,
+            }
+            .into(),
+        );
+
+        // A leaving provider's bond stays slashable, up to what is left of it.
+        assert_ok!(UsageOracle::unregister_provider(RuntimeOrigin::signed(
+            ALICE
+        )));
+        assert_ok!(UsageOracle::slash(RuntimeOrigin::root(), ALICE, 1_000));
+        assert_eq!(bond_of(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 1_000 - PROVIDER_BOND);
+    });
+}
@@ -0,0 +1,90 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_usage_oracle.
+pub trait WeightInfo {
+    fn register_provider() -> Weight;
+    fn unregister_provider() -> Weight;
+    fn withdraw_bond() -> Weight;
+    fn submit_report(e: u32) -> Weight;
+    fn finalize_period(p: u32, e: u32) -> Weight;
+    fn slash() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `UsageOracle::Providers` (r:1 w:1)
+    /// Storage: `UsageOracle::ProviderCount` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn register_provider() -> Weight {
+        Weight::from_parts(45_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `UsageOracle::Providers` (r:1 w:1)
+    fn unregister_provider() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `UsageOracle::Providers` (r:1 w:1)
+    /// Storage: `UsageOracle::ProviderCount` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn withdraw_bond() -> Weight {
+        Weight::from_parts(45_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `UsageOracle::Providers` (r:1 w:0)
+    /// Storage: `UsageOracle::Submissions` (r:1 w:1)
+    /// Storage: `UsageOracle::SubmissionCount` (r:1 w:1)
+    /// The range of component `e` is `[1, 256]`.
+    fn submit_report(e: u32) -> Weight {
+        Weight::from_parts(25_000_000, 4_000)
+            .saturating_add(Weight::from_parts(300_000, 20).saturating_mul(e.into()))
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `UsageOracle::Aggregates` (r:1 w:1)
+    /// Storage: `UsageOracle::SubmissionCount` (r:1 w:1)
+    /// Storage: `UsageOracle::Submissions` (r:p w:p)
+    /// Storage: `UsageOracle::Providers` (r:p w:p)
+    /// Storage: `Balances::Holds` (r:p w:p)
+    /// The range of component `p` is `[1, 32]`.
+    /// The range of component `e` is `[1, 256]`.
+    fn finalize_period(p: u32, e: u32) -> Weight {
+        Weight::from_parts(30_000_000, 4_000)
+            .saturating_add(Weight::from_parts(40_000_000, 9_000).saturating_mul(p.into()))
+            .saturating_add(Weight::from_parts(1_500_000, 20).saturating_mul(e.into()))
+            .saturating_add(
+                Weight::from_parts(150_000, 0).saturating_mul(u64::from(p) * u64::from(e)),
+            )
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().reads(3_u64.saturating_mul(p.into())))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64.saturating_mul(p.into())))
+    }
+    /// Storage: `UsageOracle::Providers` (r:2 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn slash() -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+}
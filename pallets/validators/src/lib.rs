@@ -24,6 +24,8 @@
 //! - Add or remove validators via Root or governance origin.
 //! - Integration with `pallet-session` to update the validator set at each new session.
 //! - Compatible with `pallet-session::historical` for tracking validators across sessions.
+//! - Offence handler for `pallet-offences`: reported validators are disabled for the rest of the
+//!   session and removed from the set.
 //!
 //! ## Security
 //! - Configurable maximum number of validators (`MaxValidators`).
@@ -108,6 +110,8 @@ pub mod pallet {
         ValidatorAdded(T::ValidatorId),
         ValidatorRemoved(T::ValidatorId),
         ValidatorSetUpdated,
+        /// A validator was reported for an offence and removed from the set.
+        OffenderRemoved(T::ValidatorId),
     }
 
     #[pallet::error]
@@ -160,8 +164,13 @@ pub mod pallet {
     }
 }
 
-use sp_runtime::Vec;
-use sp_staking::SessionIndex;
+use frame_support::{traits::Get, weights::Weight};
+use pallet_session::historical::IdentificationTuple;
+use sp_runtime::{Perbill, Vec};
+use sp_staking::{
+    SessionIndex,
+    offence::{OffenceDetails, OffenceSeverity, OnOffenceHandler},
+};
 
 impl<T: Config> pallet_session::SessionManager<T::ValidatorId> for Pallet<T> {
     fn new_session(_index: SessionIndex) -> Option<Vec<T::ValidatorId>> {
@@ -196,3 +205,38 @@ impl<T: Config> pallet_session::historical::SessionManager<T::ValidatorId, T::Va
         <Self as pallet_session::SessionManager<_>>::start_session(start_index)
     }
 }
+
+/// There is no stake to slash on a PoA chain: offenders are disabled for the rest of the session
+/// and left out of the next ones, until Root adds them back. The last validator is never removed,
+/// the chain would stop.
+impl<T, Reporter> OnOffenceHandler<Reporter, IdentificationTuple<T>, Weight> for Pallet<T>
+where
+    T: Config + pallet_session::historical::Config,
+{
+    fn on_offence(
+        offenders: &[OffenceDetails<Reporter, IdentificationTuple<T>>],
+        slash_fraction: &[Perbill],
+        _session: SessionIndex,
+    ) -> Weight {
+        let mut validators = Validators::<T>::get();
+        for (details, fraction) in offenders.iter().zip(slash_fraction) {
+            let (offender, _) = &details.offender;
+            log::warn!(target: LOG_TARGET, "Offence reported against validator {offender:?}.");
+
+            pallet_session::Pallet::<T>::report_offence(
+                offender.clone(),
+                OffenceSeverity(*fraction),
+            );
+            if validators.len() > 1 && validators.contains(offender) {
+                validators.retain(|v| v != offender);
+                Self::deposit_event(Event::OffenderRemoved(offender.clone()));
+            }
+        }
+        Validators::<T>::put(validators);
+
+        // Disabling reads the session validators and the disabled ones, and writes the latter.
+        let count = offenders.len() as u64;
+        <T as Config>::WeightInfo::remove_validator()
+            .saturating_add(T::DbWeight::get().reads_writes(2 * count, count))
+    }
+}
@@ -18,7 +18,20 @@
 
 use super::Validators as ValidatorsStorage;
 use crate::mock::*;
-use frame_support::{assert_noop, assert_ok, traits::OnInitialize};
+use frame_support::{assert_noop, assert_ok, traits::OnInitialize, weights::Weight};
+use sp_runtime::Perbill;
+use sp_staking::offence::{OffenceDetails, OnOffenceHandler};
+
+fn report_offence(offender: u64) {
+    let _: Weight = <Validators as OnOffenceHandler<u64, _, _>>::on_offence(
+        &[OffenceDetails {
+            offender: (offender, offender),
+            reporters: vec![],
+        }],
+        &[Perbill::from_percent(10)],
+        Session::current_index(),
+    );
+}
 
 #[test]
 fn genesis_validators_are_set_correctly() {
@@ -140,3 +153,27 @@ fn validator_set_changes_take_effect_in_next_session() {
         assert_eq!(Session::validators(), vec![2, 3, 4]);
     });
 }
+
+#[test]
+fn offenders_are_removed_from_the_set() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        report_offence(2);
+
+        assert_eq!(ValidatorsStorage::<Test>::get(), vec![1, 3]);
+        System::assert_last_event(super::Event::<Test>::OffenderRemoved(2).into());
+    });
+}
+
+#[test]
+fn the_last_validator_is_never_removed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validators::remove_validator(RuntimeOrigin::root(), 2));
+        assert_ok!(Validators::remove_validator(RuntimeOrigin::root(), 3));
+
+        report_offence(1);
+
+        assert_eq!(ValidatorsStorage::<Test>::get(), vec![1]);
+    });
+}
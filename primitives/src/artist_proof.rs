@@ -0,0 +1,243 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Proofs that an account is, or isn't, a verified Allfeat artist, checkable without trusting
+//! the node that served them, e.g. by a website running this crate in wasm.
+//!
+//! An [`ArtistProof`] bundles the header of a block with a compact proof of the artist's
+//! attestations in `pallet-certification` and of the certifiers behind them, both read from the
+//! state of that block. [`ArtistProof::verify`] checks the storage proof against the state root
+//! of the header, and the header against the hash of a block the caller trusts, e.g. the
+//! finalized head of a light client: a node can't forge a proof, but it could serve the proof of
+//! an old block.
+//!
+//! An artist is verified while at least one current certifier attests them.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, DecodeAll, Encode};
+use scale_info::TypeInfo;
+use sp_core::hashing::{blake2_128, twox_128};
+use sp_trie::{CompactProof, LayoutV1, read_trie_value};
+
+use crate::{AccountId, BlockNumber, Hash, Hashing, Header};
+
+/// Name of `pallet-certification` in the runtime.
+pub const CERTIFICATION_PALLET: &[u8] = b"Certification";
+
+/// Storage key of the `Certification::Attestations` of `artist`.
+pub fn attestations_key(artist: &AccountId) -> Vec<u8> {
+    // `CertifiedRecord::Artist(artist)`.
+    certification_map_key(b"Attestations", &(0u8, artist).encode())
+}
+
+/// Storage key of the `Certification::Certifiers` entry of `certifier`.
+pub fn certifier_key(certifier: &AccountId) -> Vec<u8> {
+    certification_map_key(b"Certifiers", &certifier.encode())
+}
+
+/// Key of a `Blake2_128Concat` map of `pallet-certification`.
+fn certification_map_key(storage: &[u8], key: &[u8]) -> Vec<u8> {
+    [
+        &twox_128(CERTIFICATION_PALLET)[..],
+        &twox_128(storage),
+        &blake2_128(key),
+        key,
+    ]
+    .concat()
+}
+
+/// The certifiers that attested an artist, current or not, from the value of their
+/// `Certification::Attestations` entry.
+pub fn attesting_certifiers(attestations: &[u8]) -> Option<Vec<AccountId>> {
+    let attestations = Vec::<(AccountId, BlockNumber)>::decode_all(&mut &attestations[..]).ok()?;
+    Some(
+        attestations
+            .into_iter()
+            .map(|(certifier, _)| certifier)
+            .collect(),
+    )
+}
+
+/// The verification status of an artist at a block, with what it takes to check it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct ArtistProof {
+    /// Header of the block the status was read at.
+    pub header: Header,
+    /// The artist's `Certification::Attestations` entry and the `Certification::Certifiers`
+    /// entries of the certifiers in it, proven from the state root of `header`.
+    pub proof: CompactProof,
+}
+
+/// Why an [`ArtistProof`] couldn't be checked.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProofError {
+    /// The header isn't the one of the trusted block.
+    UnknownBlock,
+    /// The proof isn't rooted in the state of the block, or misses some of the entries.
+    InvalidProof,
+    /// A proven entry isn't a value of the storage it was read from.
+    InvalidValue,
+}
+
+impl ArtistProof {
+    /// The current certifiers attesting `artist` at the block of hash `block_hash`: none if the
+    /// artist isn't verified.
+    pub fn verify(
+        &self,
+        artist: &AccountId,
+        block_hash: &Hash,
+    ) -> Result<Vec<AccountId>, ProofError> {
+        if self.header.hash() != *block_hash {
+            return Err(ProofError::UnknownBlock);
+        }
+        let (db, root) = self
+            .proof
+            .to_memory_db::<Hashing>(Some(&self.header.state_root))
+            .map_err(|_| ProofError::InvalidProof)?;
+        let read = |key: &[u8]| {
+            read_trie_value::<LayoutV1<Hashing>, _>(&db, &root, key, None, None)
+                .map_err(|_| ProofError::InvalidProof)
+        };
+
+        let attesting = match read(&attestations_key(artist))? {
+            Some(value) => attesting_certifiers(&value).ok_or(ProofError::InvalidValue)?,
+            None => Vec::new(),
+        };
+        let mut current = Vec::new();
+        for certifier in attesting {
+            if read(&certifier_key(&certifier))?.is_some() {
+                current.push(certifier);
+            }
+        }
+        Ok(current)
+    }
+
+    /// Whether `artist` is verified at the block of hash `block_hash`.
+    pub fn is_verified(&self, artist: &AccountId, block_hash: &Hash) -> Result<bool, ProofError> {
+        self.verify(artist, block_hash)
+            .map(|certifiers| !certifiers.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::sp_runtime::{Digest, traits::Header as _};
+    use sp_trie::{MemoryDB, TrieDBMutBuilder, TrieMut, recorder::Recorder};
+
+    fn account(n: u8) -> AccountId {
+        AccountId::new([n; 32])
+    }
+
+    /// A block whose state holds `entries`, and the proof of `keys` in it.
+    fn prove(entries: &[(Vec<u8>, Vec<u8>)], keys: &[Vec<u8>]) -> ArtistProof {
+        let mut db = MemoryDB::<Hashing>::default();
+        let mut root = Hash::zero();
+        {
+            let mut trie = TrieDBMutBuilder::<LayoutV1<Hashing>>::new(&mut db, &mut root).build();
+            for (key, value) in entries {
+                trie.insert(key, value).unwrap();
+            }
+        }
+
+        let recorder = Recorder::<Hashing>::default();
+        for key in keys {
+            let mut trie_recorder = recorder.as_trie_recorder(root);
+            read_trie_value::<LayoutV1<Hashing>, _>(
+                &db,
+                &root,
+                key,
+                Some(&mut trie_recorder),
+                None,
+            )
+            .unwrap();
+        }
+
+        ArtistProof {
+            header: Header::new(7, Hash::zero(), root, Hash::zero(), Digest::default()),
+            proof: recorder
+                .drain_storage_proof()
+                .into_compact_proof::<Hashing>(root)
+                .unwrap(),
+        }
+    }
+
+    /// Account 1 is attested by certifiers 10 and 11, of which only 11 is still a certifier.
+    fn state() -> Vec<(Vec<u8>, Vec<u8>)> {
+        vec![
+            (
+                attestations_key(&account(1)),
+                vec![(account(10), 3u32), (account(11), 5u32)].encode(),
+            ),
+            (certifier_key(&account(11)), 0u8.encode()),
+            (certifier_key(&account(12)), 1u8.encode()),
+        ]
+    }
+
+    #[test]
+    fn attested_artists_are_verified_by_their_current_certifiers() {
+        let proof = prove(
+            &state(),
+            &[
+                attestations_key(&account(1)),
+                certifier_key(&account(10)),
+                certifier_key(&account(11)),
+            ],
+        );
+        let block = proof.header.hash();
+
+        assert_eq!(proof.verify(&account(1), &block), Ok(vec![account(11)]));
+        assert_eq!(proof.is_verified(&account(1), &block), Ok(true));
+    }
+
+    #[test]
+    fn unattested_artists_are_proven_unverified() {
+        let proof = prove(&state(), &[attestations_key(&account(2))]);
+
+        assert_eq!(
+            proof.is_verified(&account(2), &proof.header.hash()),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn proofs_only_hold_for_the_trusted_block() {
+        let proof = prove(&state(), &[attestations_key(&account(2))]);
+
+        assert_eq!(
+            proof.verify(&account(2), &Hash::repeat_byte(1)),
+            Err(ProofError::UnknownBlock)
+        );
+    }
+
+    #[test]
+    fn proofs_must_cover_every_certifier() {
+        // Leaving out certifier 11 would hide that the artist is verified.
+        let proof = prove(
+            &state(),
+            &[attestations_key(&account(1)), certifier_key(&account(10))],
+        );
+
+        assert_eq!(
+            proof.verify(&account(1), &proof.header.hash()),
+            Err(ProofError::InvalidProof)
+        );
+    }
+}
@@ -0,0 +1,363 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Human-readable identifiers of the integer-keyed Allfeat entities.
+//!
+//! Escrows, licenses, tickets and the like are keyed by plain integers on chain, so `12` alone
+//! doesn't say which entity it refers to. Their canonical form is a type prefix, the index
+//! padded to at least six digits and an ISO/IEC 7064 MOD 1271-36 check pair, e.g.
+//! `AFE-000123-AC`. The check pair catches any single mistyped digit and any swap of adjacent
+//! digits.
+
+use core::{fmt, str::FromStr};
+
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Min number of digits of the index.
+const MIN_DIGITS: usize = 6;
+const MODULUS: u32 = 1271;
+const RADIX: u32 = 36;
+
+/// The kind of entity an identifier refers to.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum EntityKind {
+    /// A `pallet-import-windows` window.
+    ImportWindow,
+    /// A `pallet-catalog-transfers` transfer.
+    CatalogTransfer,
+    /// A `pallet-crowdfunding` round.
+    CrowdfundingRound,
+    /// A `pallet-royalties` distribution.
+    Distribution,
+    /// A `pallet-deferred-work` task.
+    DeferredTask,
+    /// A `pallet-escrow` escrow.
+    Escrow,
+    /// A `pallet-licensing` offer.
+    LicenseOffer,
+    /// A `pallet-licensing` license.
+    License,
+    /// A `pallet-ticketing` event.
+    TicketedEvent,
+    /// A `pallet-ticketing` ticket.
+    Ticket,
+    /// A `pallet-subscriptions` subscription.
+    Subscription,
+}
+
+impl EntityKind {
+    pub const ALL: [Self; 11] = [
+        Self::ImportWindow,
+        Self::CatalogTransfer,
+        Self::CrowdfundingRound,
+        Self::Distribution,
+        Self::DeferredTask,
+        Self::Escrow,
+        Self::LicenseOffer,
+        Self::License,
+        Self::TicketedEvent,
+        Self::Ticket,
+        Self::Subscription,
+    ];
+
+    /// The prefix of the identifiers of this kind.
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::ImportWindow => "AFI",
+            Self::CatalogTransfer => "AFT",
+            Self::CrowdfundingRound => "AFC",
+            Self::Distribution => "AFD",
+            Self::DeferredTask => "AFQ",
+            Self::Escrow => "AFE",
+            Self::LicenseOffer => "AFO",
+            Self::License => "AFL",
+            Self::TicketedEvent => "AFV",
+            Self::Ticket => "AFK",
+            Self::Subscription => "AFS",
+        }
+    }
+
+    pub fn from_prefix(prefix: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.prefix() == prefix)
+    }
+}
+
+/// Why a string isn't a canonical entity identifier.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EntityIdError {
+    /// Not three dash-separated parts, or an index that isn't canonically padded.
+    Malformed,
+    UnknownKind,
+    /// The index doesn't fit in a `u64`.
+    IndexOverflow,
+    BadChecksum,
+}
+
+impl fmt::Display for EntityIdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Malformed => "malformed entity id",
+            Self::UnknownKind => "unknown entity kind",
+            Self::IndexOverflow => "entity index overflows",
+            Self::BadChecksum => "bad entity id checksum",
+        })
+    }
+}
+
+/// An entity of a given kind, identified by its on-chain index.
+///
+/// Displayed and parsed in its canonical form, e.g. `AFE-000123-AC`.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct EntityId {
+    pub kind: EntityKind,
+    pub index: u64,
+}
+
+impl EntityId {
+    pub fn new(kind: EntityKind, index: impl Into<u64>) -> Self {
+        Self {
+            kind,
+            index: index.into(),
+        }
+    }
+
+    /// The check pair of the identifier.
+    fn checksum(&self) -> [u8; 2] {
+        let mut digits = [0u8; 20];
+        let digits = format_index(self.index, &mut digits);
+        let check = check_value(self.kind.prefix().bytes().chain(digits.iter().copied()));
+        [
+            to_alphanumeric(check / RADIX),
+            to_alphanumeric(check % RADIX),
+        ]
+    }
+}
+
+/// Whether `id` is a canonical entity identifier.
+pub fn validate(id: &str) -> bool {
+    id.parse::<EntityId>().is_ok()
+}
+
+impl fmt::Display for EntityId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [high, low] = self.checksum();
+        write!(
+            f,
+            "{}-{:0width$}-{}{}",
+            self.kind.prefix(),
+            self.index,
+            high as char,
+            low as char,
+            width = MIN_DIGITS,
+        )
+    }
+}
+
+impl FromStr for EntityId {
+    type Err = EntityIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('-');
+        let (Some(prefix), Some(digits), Some(check), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(EntityIdError::Malformed);
+        };
+
+        let canonical_digits = digits.len() >= MIN_DIGITS
+            && digits.bytes().all(|c| c.is_ascii_digit())
+            && (digits.len() == MIN_DIGITS || !digits.starts_with('0'));
+        if !canonical_digits || check.len() != 2 {
+            return Err(EntityIdError::Malformed);
+        }
+        let kind = EntityKind::from_prefix(prefix).ok_or(EntityIdError::UnknownKind)?;
+        let index = digits
+            .parse::<u64>()
+            .map_err(|_| EntityIdError::IndexOverflow)?;
+
+        let id = Self { kind, index };
+        if check.as_bytes() != id.checksum() {
+            return Err(EntityIdError::BadChecksum);
+        }
+        Ok(id)
+    }
+}
+
+/// Write the digits of `index`, padded to [`MIN_DIGITS`], at the end of `buf`.
+fn format_index(mut index: u64, buf: &mut [u8; 20]) -> &[u8] {
+    let mut start = buf.len();
+    while index > 0 || buf.len() - start < MIN_DIGITS {
+        start -= 1;
+        buf[start] = b'0' + (index % 10) as u8;
+        index /= 10;
+    }
+    &buf[start..]
+}
+
+/// The ISO/IEC 7064 MOD 1271-36 check value of `chars`, which are digits or upper-case letters.
+fn check_value(chars: impl Iterator<Item = u8>) -> u32 {
+    let p = chars.fold(0, |p, c| (p * RADIX + from_alphanumeric(c)) % MODULUS);
+    // The check pair `c` makes the whole string, `c` included, worth 1 modulo 1271.
+    (MODULUS + 1 - p * RADIX * RADIX % MODULUS) % MODULUS
+}
+
+fn from_alphanumeric(c: u8) -> u32 {
+    match c {
+        b'0'..=b'9' => (c - b'0') as u32,
+        _ => (c - b'A') as u32 + 10,
+    }
+}
+
+fn to_alphanumeric(v: u32) -> u8 {
+    match v {
+        0..=9 => b'0' + v as u8,
+        _ => b'A' + (v - 10) as u8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_round_trip_through_their_canonical_form() {
+        for kind in EntityKind::ALL {
+            for index in [0, 7, 123, 999_999, 1_000_000, u64::MAX] {
+                let id = EntityId::new(kind, index);
+                let canonical = id.to_string();
+                assert!(canonical.starts_with(kind.prefix()));
+                assert_eq!(canonical.parse(), Ok(id));
+            }
+        }
+        assert_eq!(
+            EntityId::new(EntityKind::Escrow, 123u32).to_string(),
+            "AFE-000123-AC"
+        );
+        assert!(
+            EntityId::new(EntityKind::Ticket, 12_345_678u64)
+                .to_string()
+                .starts_with("AFK-12345678-")
+        );
+    }
+
+    #[test]
+    fn check_pair_follows_iso_7064() {
+        // Worth 1 modulo 1271 once the check pair is appended.
+        for index in [0, 42, 123_456_789] {
+            let canonical = EntityId::new(EntityKind::License, index as u64)
+                .to_string()
+                .replace('-', "");
+            let p = canonical
+                .bytes()
+                .fold(0, |p, c| (p * RADIX + from_alphanumeric(c)) % MODULUS);
+            assert_eq!(p, 1);
+        }
+    }
+
+    #[test]
+    fn typos_are_caught() {
+        let canonical = EntityId::new(EntityKind::Escrow, 123u32).to_string();
+        let (body, check) = canonical.split_at(canonical.len() - 2);
+
+        // Any single substituted digit of the index.
+        for at in 4..10 {
+            for digit in b'0'..=b'9' {
+                let mut typo = canonical.clone().into_bytes();
+                if typo[at] == digit {
+                    continue;
+                }
+                typo[at] = digit;
+                let typo = String::from_utf8(typo).unwrap();
+                assert_eq!(typo.parse::<EntityId>(), Err(EntityIdError::BadChecksum));
+            }
+        }
+        // Swapped adjacent digits.
+        assert_eq!(
+            format!("AFE-000213-{check}").parse::<EntityId>(),
+            Err(EntityIdError::BadChecksum)
+        );
+        // Same index, another kind.
+        assert_eq!(
+            format!("AFL{}{check}", &body[3..]).parse::<EntityId>(),
+            Err(EntityIdError::BadChecksum)
+        );
+    }
+
+    #[test]
+    fn non_canonical_ids_are_refused() {
+        let check = |id: EntityId| {
+            let canonical = id.to_string();
+            canonical[canonical.len() - 2..].to_string()
+        };
+        let c = check(EntityId::new(EntityKind::Escrow, 123u32));
+
+        assert_eq!(
+            format!("AFE-123-{c}").parse::<EntityId>(),
+            Err(EntityIdError::Malformed)
+        );
+        assert_eq!(
+            format!("AFE-0000123-{c}").parse::<EntityId>(),
+            Err(EntityIdError::Malformed)
+        );
+        assert_eq!(
+            format!("afe-000123-{c}").parse::<EntityId>(),
+            Err(EntityIdError::UnknownKind)
+        );
+        assert_eq!(
+            format!("AFE-000123-{c}-1").parse::<EntityId>(),
+            Err(EntityIdError::Malformed)
+        );
+        assert_eq!(
+            format!("AFX-000123-{c}").parse::<EntityId>(),
+            Err(EntityIdError::UnknownKind)
+        );
+        assert_eq!(
+            "AFE-99999999999999999999-00".parse::<EntityId>(),
+            Err(EntityIdError::IndexOverflow)
+        );
+        assert!(!validate("AFE-000123"));
+        assert!(validate(&format!("AFE-000123-{c}")));
+    }
+}
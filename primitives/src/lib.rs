@@ -20,6 +20,19 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+pub mod artist_proof;
+pub use artist_proof::ArtistProof;
+
+pub mod entity_id;
+pub use entity_id::{EntityId, EntityKind};
+
+pub mod properties;
+pub use properties::ChainProperties;
+
+pub mod signing;
+
 use frame_support::sp_runtime::{
     MultiAddress, MultiSignature, OpaqueExtrinsic, generic,
     traits::{BlakeTwo256, IdentifyAccount, Verify},
@@ -59,6 +72,12 @@ pub type Hash = H256;
 /// The hashing algorithm used by the chain.
 pub type Hashing = BlakeTwo256;
 
+/// An event emitted by a contract dry run, SCALE encoded.
+///
+/// Runtimes encode their `frame_system::EventRecord`s in the results of the contracts runtime
+/// API, so that the node serves them without knowing the event type of each runtime.
+pub type OpaqueEventRecord = alloc::vec::Vec<u8>;
+
 /// A timestamp: milliseconds since the unix epoch.
 /// `u64` is enough to represent a duration of half a billion years, when the
 /// time scale is milliseconds.
@@ -0,0 +1,93 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Properties wallets and tools identify an Allfeat network by: the SS58 prefix of its addresses
+//! and its native token.
+//!
+//! They are declared once here and consumed by the runtimes (`SS58Prefix`), the chain specs
+//! (`ss58Format`, `tokenDecimals` and `tokenSymbol`, served by the `system_properties` RPC) and
+//! the node's default address format, so that none of them can drift apart.
+
+use sp_core::crypto::Ss58AddressFormat;
+
+/// Address format and native token of a network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChainProperties {
+    /// SS58 prefix of the network's addresses.
+    pub ss58_prefix: u16,
+    /// Decimals of the native token.
+    pub token_decimals: u8,
+    /// Symbol of the native token.
+    pub token_symbol: &'static str,
+}
+
+impl ChainProperties {
+    /// The SS58 address format of the network.
+    pub fn ss58_format(&self) -> Ss58AddressFormat {
+        Ss58AddressFormat::custom(self.ss58_prefix)
+    }
+
+    /// One unit of the native token, in its smallest denomination.
+    pub const fn unit(&self) -> u128 {
+        10u128.pow(self.token_decimals as u32)
+    }
+}
+
+/// The Allfeat mainnet, registered in the SS58 registry as `allfeat_network`.
+pub const ALLFEAT: ChainProperties = ChainProperties {
+    ss58_prefix: 440,
+    token_decimals: 12,
+    token_symbol: "AFT",
+};
+
+/// The Melodie testnet, using the generic Substrate prefix.
+pub const MELODIE: ChainProperties = ChainProperties {
+    ss58_prefix: 42,
+    token_decimals: 12,
+    token_symbol: "MEL",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::crypto::Ss58AddressFormatRegistry;
+
+    #[test]
+    fn prefixes_match_the_ss58_registry() {
+        assert_eq!(
+            ALLFEAT.ss58_format(),
+            Ss58AddressFormatRegistry::AllfeatNetworkAccount.into()
+        );
+        assert_eq!(
+            MELODIE.ss58_format(),
+            Ss58AddressFormatRegistry::SubstrateAccount.into()
+        );
+    }
+
+    #[test]
+    fn networks_are_told_apart() {
+        assert_ne!(ALLFEAT.ss58_prefix, MELODIE.ss58_prefix);
+        assert_ne!(ALLFEAT.token_symbol, MELODIE.token_symbol);
+    }
+
+    #[test]
+    fn units_follow_the_decimals() {
+        assert_eq!(ALLFEAT.unit(), 1_000_000_000_000);
+        assert_eq!(MELODIE.unit(), ALLFEAT.unit());
+    }
+}
@@ -0,0 +1,102 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API building signed transactions for signers that can't encode them, e.g. hardware
+//! wallets driven by a back office.
+//!
+//! The runtime resolves everything a signature covers besides the call (nonce, era, genesis
+//! hash, spec and transaction versions, ...) and returns the exact bytes to sign. The signature
+//! is then handed back along with the same call and [`TransactionParams`] to get the encoded
+//! extrinsic, ready for submission.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use frame_support::sp_runtime::generic::Era;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+use crate::{AccountId, Balance, Hash, Nonce, Signature};
+
+/// What the caller wants the transaction to look like. Anything left out is resolved by the
+/// runtime.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default, TypeInfo)]
+pub struct SigningParams {
+    /// Nonce to sign with. Defaults to the signer's next nonce.
+    pub nonce: Option<Nonce>,
+    /// Number of blocks the transaction stays valid for, rounded to a power of two between 4
+    /// and 65536. `None` makes it immortal.
+    pub mortality: Option<u64>,
+    /// Tip paid to the block author on top of the fee.
+    pub tip: Balance,
+}
+
+/// The signed transaction parameters, as resolved by the runtime.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct TransactionParams {
+    pub nonce: Nonce,
+    pub era: Era,
+    pub tip: Balance,
+}
+
+/// What a signer signs over.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SigningPayload {
+    /// The bytes to sign: the call, the transaction extensions and their implicit data, hashed
+    /// with blake2-256 when longer than 256 bytes.
+    pub payload: Vec<u8>,
+    /// Parameters to submit the signature with.
+    pub params: TransactionParams,
+    /// Hash of the genesis block.
+    pub genesis_hash: Hash,
+    /// Hash of the block the era starts at, the genesis hash for an immortal transaction.
+    pub checkpoint: Hash,
+    pub spec_version: u32,
+    pub transaction_version: u32,
+}
+
+/// Why no payload or extrinsic could be built.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum SigningError {
+    /// The call isn't a SCALE-encoded call of this runtime.
+    UndecodableCall,
+    /// Data signed over couldn't be resolved, e.g. the hash of the block the era starts at.
+    Unresolved,
+    /// The signature doesn't match the payload and the signer.
+    BadSignature,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait SigningApi {
+        /// The payload `signer` has to sign to dispatch the SCALE-encoded `call`.
+        fn signing_payload(
+            call: Vec<u8>,
+            signer: AccountId,
+            params: SigningParams,
+        ) -> Result<SigningPayload, SigningError>;
+
+        /// The SCALE-encoded extrinsic dispatching `call`, signed by `signer` over the payload
+        /// built from `params`.
+        fn signed_extrinsic(
+            call: Vec<u8>,
+            signer: AccountId,
+            signature: Signature,
+            params: TransactionParams,
+        ) -> Result<Vec<u8>, SigningError>;
+    }
+}
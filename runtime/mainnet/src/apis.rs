@@ -114,9 +114,14 @@ impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
 
         fn generate_key_ownership_proof(
             _set_id: sp_consensus_grandpa::SetId,
-            _authority_id: sp_consensus_grandpa::AuthorityId,
+            authority_id: sp_consensus_grandpa::AuthorityId,
         ) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
-            None
+            use frame_support::traits::KeyOwnerProofSystem;
+            use parity_scale_codec::Encode;
+
+            Historical::prove((sp_consensus_grandpa::KEY_TYPE, authority_id))
+                .map(|p| p.encode())
+                .map(sp_consensus_grandpa::OpaqueKeyOwnershipProof::new)
         }
     }
 
@@ -144,6 +149,37 @@ impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
         }
     }
 
+    impl pallet_upgrade_notice_runtime_api::UpgradeNoticeApi<Block, BlockNumber> for Runtime {
+        fn pending_upgrade() -> Option<pallet_upgrade_notice::UpgradeNoticeOf<Runtime>> {
+            UpgradeNotice::pending_upgrade()
+        }
+    }
+
+    impl allfeat_primitives::signing::SigningApi<Block> for Runtime {
+        fn signing_payload(
+            call: Vec<u8>,
+            signer: AccountId,
+            params: allfeat_primitives::signing::SigningParams,
+        ) -> Result<allfeat_primitives::signing::SigningPayload, allfeat_primitives::signing::SigningError> {
+            shared_runtime::signing::signing_payload::<Runtime, _>(call, signer, params, tx_extension)
+        }
+
+        fn signed_extrinsic(
+            call: Vec<u8>,
+            signer: AccountId,
+            signature: Signature,
+            params: allfeat_primitives::signing::TransactionParams,
+        ) -> Result<Vec<u8>, allfeat_primitives::signing::SigningError> {
+            shared_runtime::signing::signed_extrinsic::<Runtime, _>(
+                call,
+                signer,
+                signature,
+                params,
+                tx_extension,
+            )
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
         for Runtime
     {
@@ -36,4 +36,7 @@ frame_benchmarking::define_benchmarks!(
     [pallet_meta_tx, MetaTx]
     [pallet_verify_signature, VerifySignature]
     [pallet_ats, Ats]
+    [pallet_upgrade_notice, UpgradeNotice]
+    [pallet_parameters, Parameters]
+    [pallet_parameter_timelock, ParameterTimelock]
 );
@@ -19,6 +19,7 @@
 //! The Melodie runtime.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![recursion_limit = "256"]
 
 // Make the WASM binary available.
 #[cfg(feature = "std")]
@@ -72,7 +73,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: alloc::borrow::Cow::Borrowed("allfeat"),
     impl_name: alloc::borrow::Cow::Borrowed("allfeat-allfeat"),
     authoring_version: 1,
-    spec_version: 203,
+    spec_version: 209,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 2,
@@ -136,6 +137,21 @@ pub type MetaTxBareExtension = (
 /// extrinsic level for Signed (v4) transactions.
 pub type TxExtension = TxBareExtension;
 
+/// The extension signed along with a call for `params`, see [`allfeat_primitives::signing`].
+pub fn tx_extension(params: &allfeat_primitives::signing::TransactionParams) -> TxExtension {
+    (
+        frame_system::CheckNonZeroSender::new(),
+        frame_system::CheckSpecVersion::new(),
+        frame_system::CheckTxVersion::new(),
+        frame_system::CheckGenesis::new(),
+        frame_system::CheckEra::from(params.era),
+        frame_system::CheckNonce::from(params.nonce),
+        frame_system::CheckWeight::new(),
+        pallet_transaction_payment::ChargeTransactionPayment::from(params.tip),
+        frame_metadata_hash_extension::CheckMetadataHash::new(false),
+    )
+}
+
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
     generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, TxExtension>;
@@ -226,6 +242,18 @@ mod runtime {
     #[runtime::pallet_index(21)]
     pub type VerifySignature = pallet_verify_signature;
 
+    #[runtime::pallet_index(22)]
+    pub type UpgradeNotice = pallet_upgrade_notice;
+
+    #[runtime::pallet_index(23)]
+    pub type Parameters = pallet_parameters;
+
+    #[runtime::pallet_index(24)]
+    pub type ParameterTimelock = pallet_parameter_timelock;
+
+    #[runtime::pallet_index(25)]
+    pub type Offences = pallet_offences;
+
     #[runtime::pallet_index(105)]
     pub type Ats = pallet_ats;
 }
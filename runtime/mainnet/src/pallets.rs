@@ -18,6 +18,7 @@
 
 mod ats;
 mod multisig;
+mod parameters;
 mod proxy;
 mod scheduler;
 // System stuffs.
@@ -26,6 +27,7 @@ mod authorship;
 mod balances;
 mod grandpa;
 mod meta_tx;
+mod offences;
 mod preimage;
 mod session;
 mod sudo;
@@ -34,12 +36,14 @@ mod timestamp;
 mod token_allocation;
 mod transaction_payment;
 mod treasury;
+mod upgrade_notice;
 mod utility;
 mod validators;
 
 // External required imports
 pub use aura::*;
 pub use balances::*;
+pub use parameters::*;
 pub use session::*;
 pub use system::*;
 pub use transaction_payment::*;
@@ -25,7 +25,11 @@ parameter_types! {
 }
 
 parameter_types! {
-    pub MaxSetIdSessionEntries: u32 = 0;
+    // Equivocations stay reportable, and their set ids resolvable to a session, for a week of
+    // 3-hour sessions.
+    pub const MaxSetIdSessionEntries: u32 = 7 * 8;
+    pub const ReportLongevity: u64 =
+        MaxSetIdSessionEntries::get() as u64 * SessionPeriod::get() as u64;
 }
 
 impl pallet_grandpa::Config for Runtime {
@@ -36,7 +40,8 @@ impl pallet_grandpa::Config for Runtime {
         sp_consensus_grandpa::AuthorityId,
     )>>::Proof;
 
-    type EquivocationReportSystem = ();
+    type EquivocationReportSystem =
+        pallet_grandpa::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
     type MaxNominators = MaxNominatorRewardedPerValidator;
     type WeightInfo = weights::grandpa::AllfeatWeight<Runtime>;
     type MaxAuthorities = MaxAuthorities;
@@ -0,0 +1,178 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    dynamic_params::{dynamic_pallet_params, dynamic_params},
+    parameter_types,
+    sp_runtime::Perbill,
+    traits::{
+        AsEnsureOriginWithArg, ConstU32, Contains, EitherOf, EnsureOrigin, EnsureOriginWithArg,
+    },
+};
+use frame_system::EnsureRoot;
+use pallet_parameter_timelock::EnsureTimelocked;
+use pallet_transaction_payment::Multiplier;
+use shared_runtime::{AdjustmentVariableBounds, MaximumMultiplierBounds, MinimumMultiplierBounds};
+
+/// Runtime parameters changed without a runtime upgrade, either by Root or through
+/// [`ParameterTimelock`], see [`ParametersAdminOrigin`].
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+    use super::*;
+
+    /// Split of transaction fees, tips included, see [`shared_runtime::fee_split`]. Each share
+    /// is weighed against the sum of the three.
+    #[dynamic_pallet_params]
+    #[codec(index = 0)]
+    pub mod fees {
+        #[codec(index = 0)]
+        pub static Burn: Perbill = Perbill::zero();
+
+        #[codec(index = 1)]
+        pub static Treasury: Perbill = Perbill::from_percent(20);
+
+        #[codec(index = 2)]
+        pub static Author: Perbill = Perbill::from_percent(80);
+    }
+
+    /// Fee multiplier settings of [`shared_runtime::SlowAdjustingFeeUpdate`], defaulting to the
+    /// shared constants. Only changed through [`ParameterTimelock`], within the shared
+    /// `*Bounds` ranges.
+    #[dynamic_pallet_params]
+    #[codec(index = 1)]
+    pub mod fee_market {
+        #[codec(index = 0)]
+        pub static AdjustmentVariable: Multiplier = shared_runtime::AdjustmentVariable::get();
+
+        #[codec(index = 1)]
+        pub static MinimumMultiplier: Multiplier = shared_runtime::MinimumMultiplier::get();
+
+        #[codec(index = 2)]
+        pub static MaximumMultiplier: Multiplier = shared_runtime::MaximumMultiplier::get();
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+    fn default() -> Self {
+        RuntimeParameters::Fees(dynamic_params::fees::Parameters::Burn(
+            dynamic_params::fees::Burn,
+            Some(Perbill::zero()),
+        ))
+    }
+}
+
+/// Root sets the fee split directly; fee market settings only change once their timelock
+/// elapsed. Either may be scheduled through [`ParameterTimelock`].
+pub struct ParametersAdminOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for ParametersAdminOrigin {
+    type Success = ();
+
+    fn try_origin(
+        origin: RuntimeOrigin,
+        key: &RuntimeParametersKey,
+    ) -> Result<Self::Success, RuntimeOrigin> {
+        match key {
+            RuntimeParametersKey::Fees(_) => {
+                EitherOf::<EnsureRoot<AccountId>, EnsureTimelocked>::try_origin(origin)
+            }
+            RuntimeParametersKey::FeeMarket(_) => EnsureTimelocked::try_origin(origin),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(_key: &RuntimeParametersKey) -> Result<RuntimeOrigin, ()> {
+        EnsureTimelocked::try_successful_origin()
+    }
+}
+
+/// Hard bounds of the fee market settings.
+pub struct ParameterBounds;
+impl Contains<RuntimeParameters> for ParameterBounds {
+    fn contains(key_value: &RuntimeParameters) -> bool {
+        use dynamic_params::fee_market::Parameters as FeeMarket;
+
+        match key_value {
+            RuntimeParameters::FeeMarket(FeeMarket::AdjustmentVariable(_, Some(value))) => {
+                AdjustmentVariableBounds::get().contains(value)
+            }
+            RuntimeParameters::FeeMarket(FeeMarket::MinimumMultiplier(_, Some(value))) => {
+                MinimumMultiplierBounds::get().contains(value)
+            }
+            RuntimeParameters::FeeMarket(FeeMarket::MaximumMultiplier(_, Some(value))) => {
+                MaximumMultiplierBounds::get().contains(value)
+            }
+            _ => true,
+        }
+    }
+}
+
+impl pallet_parameters::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeParameters = RuntimeParameters;
+    type AdminOrigin = ParametersAdminOrigin;
+    // Not benchmarked on mainnet hardware yet: the pallet's reference weights are used until
+    // `weights/parameters.rs` is generated.
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    // A week for wallets and integrators to see a fee market change coming.
+    pub const ParameterChangeDelay: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_parameter_timelock::Config for Runtime {
+    type ScheduleOrigin = AsEnsureOriginWithArg<EnsureRoot<AccountId>>;
+    type Bounds = ParameterBounds;
+    type Delay = ParameterChangeDelay;
+    // One pending change per parameter.
+    type MaxPending = ConstU32<6>;
+    // Not benchmarked on mainnet hardware yet: the pallet's reference weights are used until
+    // `weights/parameter_timelock.rs` is generated.
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ParameterBounds;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_parameter_timelock::BenchmarkHelper<RuntimeParameters> for ParameterBounds {
+    fn key_value(i: u32) -> RuntimeParameters {
+        use dynamic_params::{fee_market, fees};
+
+        let share = Some(Perbill::from_percent(10));
+        [
+            RuntimeParameters::Fees(fees::Parameters::Burn(fees::Burn, share)),
+            RuntimeParameters::Fees(fees::Parameters::Treasury(fees::Treasury, share)),
+            RuntimeParameters::Fees(fees::Parameters::Author(fees::Author, share)),
+            RuntimeParameters::FeeMarket(fee_market::Parameters::AdjustmentVariable(
+                fee_market::AdjustmentVariable,
+                Some(*AdjustmentVariableBounds::get().end()),
+            )),
+            RuntimeParameters::FeeMarket(fee_market::Parameters::MinimumMultiplier(
+                fee_market::MinimumMultiplier,
+                Some(*MinimumMultiplierBounds::get().end()),
+            )),
+            RuntimeParameters::FeeMarket(fee_market::Parameters::MaximumMultiplier(
+                fee_market::MaximumMultiplier,
+                Some(*MaximumMultiplierBounds::get().start()),
+            )),
+        ][i as usize % 6]
+            .clone()
+    }
+}
@@ -17,16 +17,6 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::*;
-use frame_support::{
-    derive_impl,
-    traits::{ConstU16, ConstU32},
-    weights::{
-        Weight,
-        constants::{ParityDbWeight, WEIGHT_REF_TIME_PER_SECOND},
-    },
-};
-use frame_system::limits::BlockWeights;
-use shared_runtime::{NORMAL_DISPATCH_RATIO, RuntimeBlockLength};
 
 /// All migrations of the runtime, aside from the ones declared in the pallets.
 ///
@@ -34,29 +24,10 @@ use shared_runtime::{NORMAL_DISPATCH_RATIO, RuntimeBlockLength};
 #[allow(unused_parens)]
 type SingleBlockMigrations = ();
 
-frame_support::parameter_types! {
-    pub const Version: sp_version::RuntimeVersion = VERSION;
-    /// We allow for 2 seconds of compute with a 6 second average block time.
-    pub RuntimeBlockWeights: BlockWeights = BlockWeights::with_sensible_defaults(
-        Weight::from_parts(2u64 * WEIGHT_REF_TIME_PER_SECOND, u64::MAX),
-        NORMAL_DISPATCH_RATIO,
-    );
-}
-
-#[derive_impl(frame_system::config_preludes::SolochainDefaultConfig)]
-impl frame_system::Config for Runtime {
-    type BlockWeights = RuntimeBlockWeights;
-    type BlockLength = RuntimeBlockLength;
-    type Nonce = Nonce;
-    type Block = Block;
-    type Hash = allfeat_primitives::Hash;
-    type AccountId = AccountId;
-    type BlockHashCount = shared_runtime::BlockHashCount;
-    type DbWeight = ParityDbWeight;
-    type Version = Version;
-    type AccountData = pallet_balances::AccountData<Balance>;
-    type SystemWeightInfo = weights::system::AllfeatWeight<Runtime>;
-    type SS58Prefix = ConstU16<440>;
-    type MaxConsumers = ConstU32<16>;
-    type SingleBlockMigrations = SingleBlockMigrations;
+shared_runtime::impl_allfeat_common_configs! {
+    system {
+        ss58_prefix: allfeat_primitives::properties::ALLFEAT.ss58_prefix,
+        base_call_filter: frame_support::traits::Everything,
+        single_block_migrations: SingleBlockMigrations,
+    }
 }
@@ -16,15 +16,12 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use super::treasury::TreasuryAccount;
 use crate::*;
 use frame_support::{
     dispatch::DispatchClass,
     parameter_types,
     sp_runtime::Perbill,
-    traits::{
-        Imbalance, OnUnbalanced,
-        fungible::{Balanced, Credit},
-    },
     weights::{
         ConstantMultiplier, WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial,
     },
@@ -32,30 +29,19 @@ use frame_support::{
 use shared_runtime::{
     SlowAdjustingFeeUpdate,
     currency::{MICROAFT, MILLIAFT},
+    fee_split,
 };
 
-pub struct DealWithFees;
-impl OnUnbalanced<Credit<AccountId, Balances>> for DealWithFees {
-    fn on_unbalanceds(mut fees_then_tips: impl Iterator<Item = Credit<AccountId, Balances>>) {
-        if let Some(mut amount) = fees_then_tips.next() {
-            if let Some(tips) = fees_then_tips.next() {
-                tips.merge_into(&mut amount);
-            }
-
-            // Split: 80% to block author, 20% to treasury
-            let treasury_amount = Perbill::from_percent(20) * amount.peek();
-            let (treasury_part, author_part) = amount.split(treasury_amount);
-
-            // Send 20% to treasury
-            let _ = Balances::resolve(&Treasury::account_id(), treasury_part);
-
-            // Send 80% to block author
-            if let Some(author) = Authorship::author() {
-                let _ = Balances::resolve(&author, author_part);
-            }
-        }
-    }
-}
+/// Splits fees and tips between burn, treasury and block author along the `fees` runtime
+/// parameters.
+pub type DealWithFees = fee_split::DealWithFees<
+    Runtime,
+    Balances,
+    dynamic_params::fees::Burn,
+    dynamic_params::fees::Treasury,
+    dynamic_params::fees::Author,
+    TreasuryAccount,
+>;
 
 parameter_types! {
     pub const TransactionByteFee: Balance = 10 * MICROAFT;
@@ -100,7 +86,12 @@ impl pallet_transaction_payment::Config for Runtime {
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
     type WeightToFee = WeightToFee;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
-    type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
+    type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<
+        Self,
+        dynamic_params::fee_market::AdjustmentVariable,
+        dynamic_params::fee_market::MinimumMultiplier,
+        dynamic_params::fee_market::MaximumMultiplier,
+    >;
     type WeightInfo = weights::transaction_payment::AllfeatWeight<Runtime>;
 }
 
@@ -1,7 +1,6 @@
 #[cfg(feature = "runtime-benchmarks")]
 use core::marker::PhantomData;
 
-use crate::weights;
 use allfeat_primitives::{AccountId, Balance};
 use frame_support::{
     PalletId, parameter_types,
@@ -53,7 +52,7 @@ impl pallet_treasury::Config for Runtime {
     type Burn = ();
     type BurnDestination = ();
     type MaxApprovals = ConstU32<100>;
-    type WeightInfo = weights::treasury::AllfeatWeight<Runtime>;
+    type WeightInfo = shared_runtime::weights::treasury::AllfeatWeight<Runtime>;
     type SpendFunds = ();
     type SpendOrigin = EnsureRootWithSuccess<Self::AccountId, MaxBalance>;
     type AssetKind = ();
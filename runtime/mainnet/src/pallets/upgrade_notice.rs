@@ -0,0 +1,34 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::parameter_types;
+use frame_system::EnsureRoot;
+
+parameter_types! {
+    // Leaves operators two days to pick up a node release the upgrade may require.
+    pub const UpgradeMinNoticePeriod: BlockNumber = 2 * DAYS;
+}
+
+impl pallet_upgrade_notice::Config for Runtime {
+    type AnnounceOrigin = EnsureRoot<AccountId>;
+    type MinNoticePeriod = UpgradeMinNoticePeriod;
+    // Not benchmarked on mainnet hardware yet: the pallet's reference weights are used until
+    // `weights/upgrade_notice.rs` is generated.
+    type WeightInfo = ();
+}
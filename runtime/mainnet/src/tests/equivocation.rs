@@ -0,0 +1,169 @@
+use crate::*;
+use frame_support::{
+    assert_ok,
+    traits::{KeyOwnerProofSystem, OnFinalize, OnInitialize},
+};
+use sp_consensus_grandpa::{
+    AuthorityId as GrandpaId, EquivocationProof, KEY_TYPE, RoundNumber, SetId,
+};
+use sp_core::H256;
+use sp_keyring::Ed25519Keyring;
+use sp_runtime::{BuildStorage, transaction_validity::TransactionSource};
+
+const AUTHORITIES: [Ed25519Keyring; 4] = [
+    Ed25519Keyring::Alice,
+    Ed25519Keyring::Bob,
+    Ed25519Keyring::Charlie,
+    Ed25519Keyring::Dave,
+];
+
+fn account(n: u8) -> AccountId {
+    AccountId::new([n; 32])
+}
+
+/// Accounts 1 to 4 validate, with the GRANDPA key of the matching [`AUTHORITIES`].
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_validators::GenesisConfig::<Runtime> {
+        initial_validators: (1..=4).map(account).collect(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    pallet_session::GenesisConfig::<Runtime> {
+        keys: (1..=4)
+            .map(|n| {
+                let keys = SessionKeys {
+                    grandpa: AUTHORITIES[n as usize - 1].public().into(),
+                    aura: sp_core::sr25519::Public::from_raw([n; 32]).into(),
+                };
+                (account(n), account(n), keys)
+            })
+            .collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        Session::on_initialize(1);
+        Grandpa::on_finalize(1);
+    });
+    ext
+}
+
+/// Two prevotes of `offender` for different blocks in the same round, signed by `signer`.
+fn equivocation_proof(
+    set_id: SetId,
+    round: RoundNumber,
+    offender: Ed25519Keyring,
+    signer: Ed25519Keyring,
+) -> EquivocationProof<H256, BlockNumber> {
+    let prevote = |target_hash| {
+        let prevote = finality_grandpa::Prevote {
+            target_hash,
+            target_number: 1,
+        };
+        let message = finality_grandpa::Message::Prevote(prevote.clone());
+        let payload = sp_consensus_grandpa::localized_payload(round, set_id, &message);
+        (prevote, signer.sign(&payload).into())
+    };
+
+    EquivocationProof::new(
+        set_id,
+        sp_consensus_grandpa::Equivocation::Prevote(finality_grandpa::Equivocation {
+            round_number: round,
+            identity: offender.public().into(),
+            first: prevote(H256::repeat_byte(1)),
+            second: prevote(H256::repeat_byte(2)),
+        }),
+    )
+}
+
+fn report_call(offender: Ed25519Keyring, signer: Ed25519Keyring) -> pallet_grandpa::Call<Runtime> {
+    let equivocation_proof = equivocation_proof(Grandpa::current_set_id(), 1, offender, signer);
+    let authority: GrandpaId = offender.public().into();
+    let key_owner_proof = Historical::prove((KEY_TYPE, authority)).unwrap();
+    pallet_grandpa::Call::report_equivocation_unsigned {
+        equivocation_proof: Box::new(equivocation_proof),
+        key_owner_proof,
+    }
+}
+
+#[test]
+fn equivocation_reports_are_only_accepted_from_the_local_node() {
+    new_test_ext().execute_with(|| {
+        let call = report_call(Ed25519Keyring::Bob, Ed25519Keyring::Bob);
+
+        assert_ok!(Grandpa::validate_unsigned(TransactionSource::Local, &call));
+        assert!(Grandpa::validate_unsigned(TransactionSource::External, &call).is_err());
+    });
+}
+
+#[test]
+fn equivocations_need_to_be_signed_by_the_offender() {
+    new_test_ext().execute_with(|| {
+        // Claimed to be from Bob, but signed by Charlie.
+        let pallet_grandpa::Call::report_equivocation_unsigned {
+            equivocation_proof,
+            key_owner_proof,
+        } = report_call(Ed25519Keyring::Bob, Ed25519Keyring::Charlie)
+        else {
+            unreachable!()
+        };
+
+        assert!(
+            Grandpa::report_equivocation_unsigned(
+                RuntimeOrigin::none(),
+                equivocation_proof,
+                key_owner_proof,
+            )
+            .is_err()
+        );
+        assert_eq!(pallet_offences::Reports::<Runtime>::iter().count(), 0);
+        assert_eq!(pallet_validators::Validators::<Runtime>::get().len(), 4);
+    });
+}
+
+#[test]
+fn reported_equivocations_remove_the_offender() {
+    new_test_ext().execute_with(|| {
+        let pallet_grandpa::Call::report_equivocation_unsigned {
+            equivocation_proof,
+            key_owner_proof,
+        } = report_call(Ed25519Keyring::Bob, Ed25519Keyring::Bob)
+        else {
+            unreachable!()
+        };
+
+        assert_ok!(Grandpa::report_equivocation_unsigned(
+            RuntimeOrigin::none(),
+            equivocation_proof.clone(),
+            key_owner_proof.clone(),
+        ));
+
+        assert_eq!(pallet_offences::Reports::<Runtime>::iter().count(), 1);
+        assert_eq!(
+            pallet_validators::Validators::<Runtime>::get().to_vec(),
+            vec![account(1), account(3), account(4)]
+        );
+        let bob = Session::validators()
+            .iter()
+            .position(|validator| *validator == account(2))
+            .unwrap() as u32;
+        assert_eq!(Session::disabled_validators(), vec![bob]);
+
+        // The same equivocation can't be reported twice.
+        assert!(
+            Grandpa::report_equivocation_unsigned(
+                RuntimeOrigin::none(),
+                equivocation_proof,
+                key_owner_proof,
+            )
+            .is_err()
+        );
+    });
+}
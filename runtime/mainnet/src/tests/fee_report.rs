@@ -68,7 +68,7 @@ fn print_fee_report() {
         type ProxyW = weights::proxy::AllfeatWeight<Runtime>;
         type MultisigW = weights::multisig::AllfeatWeight<Runtime>;
         type TokenAllocationW = weights::token_allocation::AllfeatWeight<Runtime>;
-        type TreasuryW = weights::treasury::AllfeatWeight<Runtime>;
+        type TreasuryW = shared_runtime::weights::treasury::AllfeatWeight<Runtime>;
         type SudoW = weights::sudo::AllfeatWeight<Runtime>;
         type ValidatorsW = weights::validators::AllfeatWeight<Runtime>;
         type AtsW = weights::ats::AllfeatWeight<Runtime>;
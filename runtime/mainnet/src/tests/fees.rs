@@ -0,0 +1,102 @@
+use crate::{tests::new_test_ext, *};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Contains, Get, Hooks},
+};
+use pallet_transaction_payment::{Multiplier, MultiplierUpdate};
+use shared_runtime::currency::AFT;
+use sp_runtime::{DispatchError, FixedPointNumber, Perbill, traits::One};
+
+#[test]
+fn fee_split_defaults_to_author_and_treasury() {
+    new_test_ext().execute_with(|| {
+        // 80% to the author, 20% to the treasury, nothing burned.
+        assert_eq!(DealWithFees::split(10 * AFT), (0, 2 * AFT));
+    });
+}
+
+#[test]
+fn root_retunes_fee_split() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Parameters::set_parameter(
+            RuntimeOrigin::root(),
+            RuntimeParameters::Fees(dynamic_params::fees::Parameters::Burn(
+                dynamic_params::fees::Burn,
+                Some(Perbill::from_percent(30)),
+            )),
+        ));
+        assert_ok!(Parameters::set_parameter(
+            RuntimeOrigin::root(),
+            RuntimeParameters::Fees(dynamic_params::fees::Parameters::Author(
+                dynamic_params::fees::Author,
+                Some(Perbill::from_percent(50)),
+            )),
+        ));
+
+        assert_eq!(DealWithFees::split(10 * AFT), (3 * AFT, 2 * AFT));
+    });
+}
+
+fn minimum_multiplier(value: Multiplier) -> RuntimeParameters {
+    RuntimeParameters::FeeMarket(dynamic_params::fee_market::Parameters::MinimumMultiplier(
+        dynamic_params::fee_market::MinimumMultiplier,
+        Some(value),
+    ))
+}
+
+#[test]
+fn fee_market_defaults_are_within_bounds() {
+    use dynamic_params::fee_market::*;
+
+    new_test_ext().execute_with(|| {
+        for default in [
+            RuntimeParameters::FeeMarket(Parameters::AdjustmentVariable(
+                AdjustmentVariable,
+                Some(AdjustmentVariable::get()),
+            )),
+            minimum_multiplier(MinimumMultiplier::get()),
+            RuntimeParameters::FeeMarket(Parameters::MaximumMultiplier(
+                MaximumMultiplier,
+                Some(MaximumMultiplier::get()),
+            )),
+        ] {
+            assert!(ParameterBounds::contains(&default));
+        }
+    });
+}
+
+#[test]
+fn fee_market_is_retuned_through_the_timelock() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let floor = Multiplier::one();
+
+        assert_noop!(
+            Parameters::set_parameter(RuntimeOrigin::root(), minimum_multiplier(floor)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ParameterTimelock::schedule(
+                RuntimeOrigin::root(),
+                minimum_multiplier(Multiplier::saturating_from_rational(1, 100))
+            ),
+            pallet_parameter_timelock::Error::<Runtime>::OutOfBounds
+        );
+        assert_ok!(ParameterTimelock::schedule(
+            RuntimeOrigin::root(),
+            minimum_multiplier(floor)
+        ));
+
+        ParameterTimelock::on_initialize(ParameterChangeDelay::get());
+        assert_eq!(
+            <Runtime as pallet_transaction_payment::Config>::FeeMultiplierUpdate::min(),
+            shared_runtime::MinimumMultiplier::get()
+        );
+
+        ParameterTimelock::on_initialize(1 + ParameterChangeDelay::get());
+        assert_eq!(
+            <Runtime as pallet_transaction_payment::Config>::FeeMultiplierUpdate::min(),
+            floor
+        );
+    });
+}
@@ -2,8 +2,11 @@ use crate::{Runtime, genesis::token::tokenomics};
 use sp_keyring::Sr25519Keyring;
 use sp_runtime::BuildStorage;
 
+pub mod equivocation;
 pub mod fee_report;
+pub mod fees;
 pub mod migration;
+pub mod signing;
 pub mod token;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -0,0 +1,117 @@
+use crate::{tests::new_test_ext, *};
+use allfeat_primitives::signing::{SigningError, SigningParams, SigningPayload};
+use parity_scale_codec::{Decode, Encode};
+use shared_runtime::signing::{signed_extrinsic, signing_payload};
+use sp_core::H256;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{generic::Era, traits::Checkable};
+
+fn remark() -> Vec<u8> {
+    RuntimeCall::System(SystemCall::remark {
+        remark: vec![1, 2, 3],
+    })
+    .encode()
+}
+
+fn payload_at_block_10(params: SigningParams) -> SigningPayload {
+    frame_system::BlockHash::<Runtime>::insert(9, H256::repeat_byte(9));
+    frame_system::Pallet::<Runtime>::set_block_number(10);
+    signing_payload::<Runtime, _>(
+        remark(),
+        Sr25519Keyring::Alice.to_account_id(),
+        params,
+        tx_extension,
+    )
+    .unwrap()
+}
+
+#[test]
+fn signed_payload_makes_a_valid_extrinsic() {
+    new_test_ext().execute_with(|| {
+        let alice = Sr25519Keyring::Alice;
+        let payload = payload_at_block_10(SigningParams {
+            nonce: None,
+            mortality: Some(64),
+            tip: 5,
+        });
+
+        assert_eq!(payload.params.nonce, 0);
+        assert_eq!(payload.params.era, Era::mortal(64, 9));
+        assert_eq!(payload.checkpoint, H256::repeat_byte(9));
+        assert_eq!(
+            payload.genesis_hash,
+            frame_system::Pallet::<Runtime>::block_hash(0)
+        );
+        assert_eq!(payload.spec_version, VERSION.spec_version);
+        assert_eq!(payload.transaction_version, VERSION.transaction_version);
+
+        let signature = alice.sign(&payload.payload).into();
+        let xt = signed_extrinsic::<Runtime, _>(
+            remark(),
+            alice.to_account_id(),
+            signature,
+            payload.params,
+            tx_extension,
+        )
+        .unwrap();
+
+        let xt = UncheckedExtrinsic::decode(&mut &xt[..]).unwrap();
+        assert!(
+            xt.check(&frame_system::ChainContext::<Runtime>::default())
+                .is_ok()
+        );
+    });
+}
+
+#[test]
+fn immortal_payload_is_anchored_at_genesis() {
+    new_test_ext().execute_with(|| {
+        let payload = payload_at_block_10(SigningParams {
+            nonce: Some(7),
+            ..Default::default()
+        });
+
+        assert_eq!(payload.params.nonce, 7);
+        assert_eq!(payload.params.era, Era::Immortal);
+        assert_eq!(payload.checkpoint, payload.genesis_hash);
+    });
+}
+
+#[test]
+fn mismatched_submissions_are_rejected() {
+    new_test_ext().execute_with(|| {
+        let alice = Sr25519Keyring::Alice;
+        let payload = payload_at_block_10(SigningParams::default());
+        let signature: Signature = alice.sign(&payload.payload).into();
+        let submit = |call, signer, signature, params| {
+            signed_extrinsic::<Runtime, _>(call, signer, signature, params, tx_extension)
+        };
+
+        assert_eq!(
+            submit(
+                vec![0xff],
+                alice.to_account_id(),
+                signature.clone(),
+                payload.params.clone()
+            ),
+            Err(SigningError::UndecodableCall)
+        );
+        assert_eq!(
+            submit(
+                remark(),
+                Sr25519Keyring::Bob.to_account_id(),
+                signature.clone(),
+                payload.params.clone()
+            ),
+            Err(SigningError::BadSignature)
+        );
+
+        // The tip is signed over.
+        let mut params = payload.params;
+        params.tip += 1;
+        assert_eq!(
+            submit(remark(), alice.to_account_id(), signature, params),
+            Err(SigningError::BadSignature)
+        );
+    });
+}
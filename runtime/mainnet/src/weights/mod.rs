@@ -30,7 +30,6 @@ pub mod system;
 pub mod timestamp;
 pub mod token_allocation;
 pub mod transaction_payment;
-pub mod treasury;
 pub mod utility;
 pub mod validators;
 pub mod verify_signature;
@@ -16,6 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use allfeat_primitives::Hash;
 use frame_support::pallet_prelude::{TransactionSource, TransactionValidity};
 use sp_api::impl_runtime_apis;
 use sp_inherents::InherentData;
@@ -25,6 +26,28 @@ use sp_session::OpaqueGeneratedSessionKeys;
 use super::*;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 
+type ContractResultOf<R, E> = pallet_contracts::ContractResult<R, Balance, E>;
+
+/// Encodes the events of a contract dry run, for the node to serve without knowing
+/// [`RuntimeEvent`].
+fn opaque_events<R>(
+    result: ContractResultOf<R, frame_system::EventRecord<RuntimeEvent, Hash>>,
+) -> ContractResultOf<R, OpaqueEventRecord> {
+    pallet_contracts::ContractResult {
+        gas_consumed: result.gas_consumed,
+        gas_required: result.gas_required,
+        storage_deposit: result.storage_deposit,
+        debug_message: result.debug_message,
+        result: result.result,
+        events: result.events.map(|events| {
+            events
+                .iter()
+                .map(parity_scale_codec::Encode::encode)
+                .collect()
+        }),
+    }
+}
+
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
         fn version() -> sp_version::RuntimeVersion {
@@ -114,9 +137,14 @@ impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
 
         fn generate_key_ownership_proof(
             _set_id: sp_consensus_grandpa::SetId,
-            _authority_id: sp_consensus_grandpa::AuthorityId,
+            authority_id: sp_consensus_grandpa::AuthorityId,
         ) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
-            None
+            use frame_support::traits::KeyOwnerProofSystem;
+            use parity_scale_codec::Encode;
+
+            Historical::prove((sp_consensus_grandpa::KEY_TYPE, authority_id))
+                .map(|p| p.encode())
+                .map(sp_consensus_grandpa::OpaqueKeyOwnershipProof::new)
         }
     }
 
@@ -342,6 +370,146 @@ impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
         }
     }
 
+    #[api_version(3)]
+    impl pallet_royalties_runtime_api::RoyaltiesApi<Block, AccountId, RoyaltyAsset, Balance, BlockNumber> for Runtime {
+        fn last_statement(payer: AccountId) -> pallet_royalties::StatementNumber {
+            pallet_royalties::StatementCount::<Runtime>::get(payer)
+        }
+
+        fn statement_ledger(
+            payer: AccountId,
+            statement: pallet_royalties::StatementNumber,
+        ) -> Option<Vec<pallet_royalties::LedgerLineOf<Runtime>>> {
+            Royalties::statement_ledger(&payer, statement)
+        }
+
+        fn simulate_split(
+            asset: RoyaltyAsset,
+            shares: Vec<(AccountId, sp_runtime::Perbill)>,
+            from: BlockNumber,
+            to: BlockNumber,
+        ) -> Result<Vec<pallet_royalties::SimulatedPayoutOf<Runtime>>, sp_runtime::DispatchError> {
+            Royalties::simulate_split(&asset, shares, from, to)
+        }
+
+        fn earnings(
+            who: AccountId,
+            from: pallet_royalties::EraIndex,
+            to: pallet_royalties::EraIndex,
+        ) -> Vec<(pallet_royalties::EraIndex, pallet_royalties::EraEarningsOf<Runtime>)> {
+            Royalties::earnings(&who, from, to)
+        }
+    }
+
+    impl pallet_releases_runtime_api::ReleasesApi<Block, pallet_releases::LocalizedTitleOf<Runtime>> for Runtime {
+        fn title_of(
+            ean: pallet_releases::Ean,
+            language: Option<Vec<u8>>,
+        ) -> Option<pallet_releases::LocalizedTitleOf<Runtime>> {
+            CatalogReleases::title_of(&ean, language.as_deref())
+        }
+
+        fn releases_in_language(
+            language: Vec<u8>,
+            start_after: Option<pallet_releases::Ean>,
+            limit: u32,
+        ) -> Vec<pallet_releases::Ean> {
+            CatalogReleases::releases_in_language(&language, start_after, limit)
+        }
+    }
+
+    impl pallet_recordings_runtime_api::RecordingsApi<Block, pallet_recordings::CertificationOf<Runtime>> for Runtime {
+        fn certifications_of(
+            isrc: pallet_recordings::Isrc,
+        ) -> Vec<pallet_recordings::CertificationOf<Runtime>> {
+            SoundRecordings::certifications_of(&isrc)
+        }
+    }
+
+    impl pallet_catalog_transfers_runtime_api::CatalogTransfersApi<Block, AccountId, RoyaltyAsset, Balance, BlockNumber>
+        for Runtime
+    {
+        fn chain_of_title(asset: RoyaltyAsset) -> pallet_catalog_transfers::ChainOfTitleOf<Runtime> {
+            CatalogTransfers::chain_of_title(&asset)
+        }
+    }
+
+    impl pallet_deferred_work_runtime_api::DeferredWorkApi<Block> for Runtime {
+        fn queue_status() -> pallet_deferred_work::QueueStatus {
+            DeferredWork::queue_status()
+        }
+    }
+
+    impl pallet_anchoring_runtime_api::AnchoringApi<Block, AccountId, BlockNumber, Moment> for Runtime {
+        fn anchor_of_content(
+            content_hash: sp_core::H256,
+        ) -> Option<(AccountId, pallet_anchoring::SequenceNumber, pallet_anchoring::AnchorOf<Runtime>)> {
+            Anchoring::anchor_of_content(content_hash)
+        }
+    }
+
+    impl pallet_upgrade_notice_runtime_api::UpgradeNoticeApi<Block, BlockNumber> for Runtime {
+        fn pending_upgrade() -> Option<pallet_upgrade_notice::UpgradeNoticeOf<Runtime>> {
+            UpgradeNotice::pending_upgrade()
+        }
+    }
+
+    impl pallet_spam_telemetry_runtime_api::SpamTelemetryApi<Block> for Runtime {
+        fn current_era() -> pallet_spam_telemetry::EraIndex {
+            SpamTelemetry::current_era()
+        }
+
+        fn failures(
+            era: pallet_spam_telemetry::EraIndex,
+        ) -> Vec<(pallet_spam_telemetry::FailureCategory, u32)> {
+            SpamTelemetry::failures(era)
+        }
+
+        fn failures_between(
+            from: pallet_spam_telemetry::EraIndex,
+            to: pallet_spam_telemetry::EraIndex,
+        ) -> Vec<(pallet_spam_telemetry::FailureCategory, u32)> {
+            SpamTelemetry::failures_between(from, to)
+        }
+    }
+
+    impl allfeat_primitives::signing::SigningApi<Block> for Runtime {
+        fn signing_payload(
+            call: Vec<u8>,
+            signer: AccountId,
+            params: allfeat_primitives::signing::SigningParams,
+        ) -> Result<allfeat_primitives::signing::SigningPayload, allfeat_primitives::signing::SigningError> {
+            shared_runtime::signing::signing_payload::<Runtime, _>(call, signer, params, tx_extension)
+        }
+
+        fn signed_extrinsic(
+            call: Vec<u8>,
+            signer: AccountId,
+            signature: Signature,
+            params: allfeat_primitives::signing::TransactionParams,
+        ) -> Result<Vec<u8>, allfeat_primitives::signing::SigningError> {
+            shared_runtime::signing::signed_extrinsic::<Runtime, _>(
+                call,
+                signer,
+                signature,
+                params,
+                tx_extension,
+            )
+        }
+    }
+
+    impl pallet_badges_runtime_api::BadgesApi<Block, AccountId, BlockNumber, pallet_badges::MetadataOf<Runtime>>
+        for Runtime
+    {
+        fn badges_of(who: AccountId) -> Vec<(pallet_badges::ClassId, BlockNumber)> {
+            Badges::badges_of(&who)
+        }
+
+        fn class(class: pallet_badges::ClassId) -> Option<pallet_badges::BadgeClassOf<Runtime>> {
+            Badges::class(class)
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
         for Runtime
     {
@@ -365,6 +533,68 @@ impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
         }
     }
 
+    impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, OpaqueEventRecord>
+        for Runtime
+    {
+        fn call(
+            origin: AccountId,
+            dest: AccountId,
+            value: Balance,
+            gas_limit: Option<frame_support::weights::Weight>,
+            storage_deposit_limit: Option<Balance>,
+            input_data: Vec<u8>,
+        ) -> pallet_contracts::ContractExecResult<Balance, OpaqueEventRecord> {
+            let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+            opaque_events(Contracts::bare_call(
+                origin,
+                dest,
+                value,
+                gas_limit,
+                storage_deposit_limit,
+                input_data,
+                pallet_contracts::DebugInfo::UnsafeDebug,
+                pallet_contracts::CollectEvents::UnsafeCollect,
+                pallet_contracts::Determinism::Enforced,
+            ))
+        }
+
+        fn instantiate(
+            origin: AccountId,
+            value: Balance,
+            gas_limit: Option<frame_support::weights::Weight>,
+            storage_deposit_limit: Option<Balance>,
+            code: pallet_contracts::Code<Hash>,
+            data: Vec<u8>,
+            salt: Vec<u8>,
+        ) -> pallet_contracts::ContractInstantiateResult<AccountId, Balance, OpaqueEventRecord> {
+            let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+            opaque_events(Contracts::bare_instantiate(
+                origin,
+                value,
+                gas_limit,
+                storage_deposit_limit,
+                code,
+                data,
+                salt,
+                pallet_contracts::DebugInfo::UnsafeDebug,
+                pallet_contracts::CollectEvents::UnsafeCollect,
+            ))
+        }
+
+        fn upload_code(
+            origin: AccountId,
+            code: Vec<u8>,
+            storage_deposit_limit: Option<Balance>,
+            determinism: pallet_contracts::Determinism,
+        ) -> pallet_contracts::CodeUploadResult<Hash, Balance> {
+            Contracts::bare_upload_code(origin, code, storage_deposit_limit, determinism)
+        }
+
+        fn get_storage(address: AccountId, key: Vec<u8>) -> pallet_contracts::GetStorageResult {
+            Contracts::get_storage(address, key)
+        }
+    }
+
     impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
         fn slot_duration() -> sp_consensus_aura::SlotDuration {
             sp_consensus_aura::SlotDuration::from_millis(Aura::slot_duration())
@@ -26,16 +26,60 @@ frame_benchmarking::define_benchmarks!(
     [pallet_multisig, Multisig]
     [pallet_preimage, Preimage]
     [pallet_proxy, Proxy]
+    [pallet_recovery, Recovery]
+    [pallet_identity, Identity]
+    [pallet_contracts, Contracts]
     [pallet_scheduler, Scheduler]
     [pallet_sudo, Sudo]
     [frame_system, SystemBench::<Runtime>]
     [pallet_timestamp, Timestamp]
     [pallet_utility, Utility]
-    [pallet_validators, Validators]
+    [pallet_vesting, Vesting]
+    [pallet_staking, Staking]
+    [pallet_bags_list, VoterList]
+    [pallet_election_provider_multi_phase, ElectionProviderMultiPhase]
+    [pallet_fast_unstake, FastUnstake]
+    [pallet_im_online, ImOnline]
     [pallet_verify_signature, VerifySignature]
     [pallet_transaction_payment, TransactionPayment]
     [pallet_safe_mode, SafeMode]
     [pallet_midds, MusicalWorks]
     [pallet_midds, Recordings]
     [pallet_midds, Releases]
+    [pallet_recordings, SoundRecordings]
+    [pallet_import_windows, ImportWindows]
+    [pallet_spam_telemetry, SpamTelemetry]
+    [pallet_import_staging, ImportStaging]
+    [pallet_releases, CatalogReleases]
+    [pallet_royalties, Royalties]
+    [pallet_licensing, Licensing]
+    [pallet_catalog_transfers, CatalogTransfers]
+    [pallet_certification, Certification]
+    [pallet_usage_oracle, UsageOracle]
+    [pallet_deferred_work, DeferredWork]
+    [pallet_anchoring, Anchoring]
+    [pallet_upgrade_notice, UpgradeNotice]
+    [pallet_ticketing, Ticketing]
+    [pallet_crowdfunding, Crowdfunding]
+    [pallet_escrow, Escrow]
+    [pallet_sales, Sales]
+    [pallet_subscriptions, Subscriptions]
+    [pallet_registry_attestations, RegistryAttestations]
+    [pallet_genres, Genres]
+    [pallet_badges, Badges]
+    [pallet_artists, Artists]
+    [pallet_treasury, Treasury]
+    [pallet_bounties, Bounties]
+    [pallet_child_bounties, ChildBounties]
+    [pallet_sponsorship, Sponsorship]
+    [pallet_catalog_root, CatalogRoot]
+    [pallet_nfts, Nfts]
+    [pallet_asset_conversion, AssetConversion]
+    [pallet_asset_conversion_tx_payment, AssetTxPayment]
+    [pallet_referenda, Referenda]
+    [pallet_conviction_voting, ConvictionVoting]
+    [pallet_collective, TechnicalCommittee]
+    [pallet_membership, TechnicalMembership]
+    [pallet_whitelist, Whitelist]
+    [pallet_tx_pause, TxPause]
 );
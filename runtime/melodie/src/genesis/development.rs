@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use super::genesis;
+use super::{genesis, investor_vesting, team_vesting};
 use alloc::vec;
 use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
 
@@ -27,6 +27,7 @@ pub fn development_config_genesis() -> serde_json::Value {
             Sr25519Keyring::Alice.to_account_id(),
             Ed25519Keyring::Alice.public().into(), // Grandpa
             Sr25519Keyring::Alice.public().into(), // Aura
+            Sr25519Keyring::Alice.public().into(), // ImOnline
         )],
         Sr25519Keyring::Alice.to_account_id(),
         vec![
@@ -39,5 +40,10 @@ pub fn development_config_genesis() -> serde_json::Value {
             Sr25519Keyring::AliceStash.to_account_id(),
             Sr25519Keyring::BobStash.to_account_id(),
         ],
+        // Charlie stands for the team, Dave for an investor.
+        vec![
+            team_vesting(Sr25519Keyring::Charlie.to_account_id()),
+            investor_vesting(Sr25519Keyring::Dave.to_account_id()),
+        ],
     )
 }
@@ -19,23 +19,41 @@
 //! Genesis presets to build the runtime.
 
 extern crate alloc;
-use allfeat_primitives::{AccountId, Balance};
+use allfeat_primitives::{AccountId, Balance, BlockNumber};
 use alloc::{vec, vec::Vec};
 use development::development_config_genesis;
 use frame_support::build_struct_json_patch;
 use local::local_config_genesis;
+use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
+use pallet_staking::StakerStatus;
 use shared_runtime::currency::AFT;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_genesis_builder::PresetId;
 use staging::staging_config_genesis;
 
-use crate::{MiddsDepositBase, MiddsDepositPerByte, RuntimeGenesisConfig, SessionKeys};
+use crate::{MONTHS, MiddsDepositBase, MiddsDepositPerByte, RuntimeGenesisConfig, SessionKeys};
 
 mod development;
 mod local;
 mod staging;
 
+const ENDOWMENT: Balance = 300_000_000 * AFT;
+
+/// Vesting of an endowed account's balance: who, first block of the unlock, its length in
+/// blocks, and the part left liquid from genesis.
+pub type VestingSchedule = (AccountId, BlockNumber, BlockNumber, Balance);
+
+/// A team member's endowment: 10% liquid, the rest unlocking over 3 years after a 1 year cliff.
+pub fn team_vesting(who: AccountId) -> VestingSchedule {
+    (who, 12 * MONTHS, 36 * MONTHS, ENDOWMENT / 10)
+}
+
+/// An investor's endowment: 20% liquid, the rest unlocking over 2 years from genesis.
+pub fn investor_vesting(who: AccountId) -> VestingSchedule {
+    (who, 0, 24 * MONTHS, ENDOWMENT / 5)
+}
+
 // Returns the genesis config template populated with given parameters.
 pub fn genesis(
     initial_authorities: Vec<(
@@ -44,9 +62,11 @@ pub fn genesis(
         // Session Keys
         GrandpaId,
         AuraId,
+        ImOnlineId,
     )>,
     root_key: AccountId,
     mut endowed_accounts: Vec<AccountId>,
+    vesting: Vec<VestingSchedule>,
 ) -> serde_json::Value {
     // endow all authorities and nominators.
     initial_authorities.iter().map(|x| &x.0).for_each(|x| {
@@ -55,7 +75,9 @@ pub fn genesis(
         }
     });
 
-    const ENDOWMENT: Balance = 300_000_000 * AFT;
+    const STASH: Balance = 1_000_000 * AFT;
+    const MIN_VALIDATOR_BOND: Balance = 100_000 * AFT;
+    const MIN_NOMINATOR_BOND: Balance = 100 * AFT;
 
     build_struct_json_patch!(RuntimeGenesisConfig {
         balances: pallet_balances::GenesisConfig {
@@ -65,11 +87,19 @@ pub fn genesis(
                 .map(|x| (x, ENDOWMENT))
                 .collect::<Vec<_>>(),
         },
-        validators: pallet_validators::GenesisConfig {
-            initial_validators: initial_authorities
+        staking: pallet_staking::GenesisConfig {
+            validator_count: initial_authorities.len() as u32,
+            minimum_validator_count: initial_authorities.len() as u32,
+            invulnerables: initial_authorities
                 .iter()
                 .map(|x| x.0.clone())
                 .collect::<Vec<_>>(),
+            stakers: initial_authorities
+                .iter()
+                .map(|x| (x.0.clone(), x.0.clone(), STASH, StakerStatus::Validator))
+                .collect::<Vec<_>>(),
+            min_validator_bond: MIN_VALIDATOR_BOND,
+            min_nominator_bond: MIN_NOMINATOR_BOND,
         },
         session: pallet_session::GenesisConfig {
             keys: initial_authorities
@@ -81,12 +111,14 @@ pub fn genesis(
                         SessionKeys {
                             grandpa: x.1.clone(),
                             aura: x.2.clone(),
+                            im_online: x.3.clone(),
                         },
                     )
                 })
                 .collect::<Vec<_>>(),
             non_authority_keys: Default::default(),
         },
+        vesting: pallet_vesting::GenesisConfig { vesting },
         sudo: pallet_sudo::GenesisConfig {
             key: Some(root_key)
         },
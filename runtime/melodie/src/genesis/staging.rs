@@ -41,6 +41,12 @@ pub fn staging_config_genesis() -> serde_json::Value {
                 )
                 .unwrap()
                 .unchecked_into(),
+                // The Aura key, inserted under `imon` as well.
+                <[u8; 32]>::dehexify(
+                    "f6353bfc949d1d268740e70697514d327b454a2b44976d6b2d19925ceb7c1004",
+                )
+                .unwrap()
+                .unchecked_into(),
             ),
             (
                 AccountId::from_ss58check("5CVU8MfV5P6oKXW4Hx5j2i7FBwX9RtDhXfkRTSq4uPrDZsuj")
@@ -57,6 +63,12 @@ pub fn staging_config_genesis() -> serde_json::Value {
                 )
                 .unwrap()
                 .unchecked_into(),
+                // The Aura key, inserted under `imon` as well.
+                <[u8; 32]>::dehexify(
+                    "e4af3da951c73adce00a8258dc8676ce6d30fce78561f82e75ebd6e8cdbc1730",
+                )
+                .unwrap()
+                .unchecked_into(),
             ),
         ],
         AccountId::from_ss58check("5HDq69cbUxRMHwCDzpFefSeBaLAQmnLKp795zcWNmgGqAix6").unwrap(),
@@ -64,5 +76,6 @@ pub fn staging_config_genesis() -> serde_json::Value {
             // Sudo account
             AccountId::from_ss58check("5HDq69cbUxRMHwCDzpFefSeBaLAQmnLKp795zcWNmgGqAix6").unwrap(),
         ],
+        vec![],
     )
 }
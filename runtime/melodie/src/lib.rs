@@ -29,7 +29,9 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 // allfeat
-pub use allfeat_primitives::{AccountId, Address, Balance, BlockNumber, Moment, Nonce, Signature};
+pub use allfeat_primitives::{
+    AccountId, Address, Balance, BlockNumber, Moment, Nonce, OpaqueEventRecord, Signature,
+};
 
 use apis::RUNTIME_API_VERSIONS;
 use pallet_meta_tx::MetaTxMarker;
@@ -58,6 +60,7 @@ mod pallets;
 pub use pallets::*;
 mod ats;
 mod genesis;
+mod voter_bags;
 pub use ats::*;
 mod weights;
 
@@ -73,9 +76,296 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: alloc::borrow::Cow::Borrowed("allfeat-melodie-3"),
     impl_name: alloc::borrow::Cow::Borrowed("allfeatlabs-melodie-3"),
     authoring_version: 1,
-    spec_version: 204,
+    spec_version: 264,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
+    // 264 — `Artists` checks proof-of-work registrations in the transaction
+    // pool: its `CheckArtistWork` transaction extension takes valid ones
+    // over, so that they pay no fee and need no nonce, and an account
+    // without funds can send them. The 100 registrations cap is replaced by
+    // one more bit of difficulty every 50 registrations within a period, and
+    // solutions are bound to the number of artists their solver created
+    // this way. New transaction extension, hence `transaction_version`
+    // 9 -> 10.
+    // 263 — tips are split with the fee between treasury and block author,
+    // as mainnet's fixed 80/20 split did, instead of going to the author.
+    // 262 — `Artists` refunds the fee of `create_artist_with_work` when the
+    // work is valid and accepts at most 100 such registrations per
+    // adjustment period. The difficulty can rise to 48 bits.
+    // 261 — `Sponsorship` gives a beneficiary a sufficient reference while
+    // sponsored, so that a new account with no funds can sign the calls its
+    // sponsor covers. `ReferenceBeneficiaries` references the existing ones.
+    // 260 — `CatalogRoot` counts the entities of each namespace and
+    // deposits catalog statistics (entity counts, total distributed by
+    // `Royalties`) in the digest once per staking era. `CountEntities`
+    // counts the entities committed before.
+    // 259 — `Royalties` keeps an earnings ledger: what each account is paid
+    // per staking era by royalty distributions, licenses, sales and
+    // subscriptions. `RoyaltiesApi` v3 adds `earnings` for dashboards.
+    // 258 — `Artists` can be created without a deposit by solving a
+    // proof-of-work puzzle (`create_artist_with_work`), one per account at a
+    // time. The difficulty is adjusted every 6 hours towards 50 such
+    // registrations per period.
+    // 257 — `Recordings` anchors can be sealed: `commit_anchor` commits to
+    // the hash of an unreleased master's anchor, `reveal_anchor` discloses
+    // it within a year and dates it from the commitment, and
+    // `withdraw_commitment` releases the deposit of one not revealed.
+    // Additive, `transaction_version` unchanged.
+    // 256 — `UsageOracle` finalizes the period whose submission window just
+    // closed in `on_idle`, slashing the providers of outliers without waiting
+    // for someone to call `finalize_period`. `transaction_version` unchanged.
+    // 255 — added `Contracts` (`pallet_contracts`, pallet index 153): Wasm
+    // (ink!) smart contracts, which anyone can upload and instantiate against
+    // a storage deposit, e.g. royalty wrappers and marketplaces. Contracts
+    // can't dispatch runtime calls. `ContractsApi` exposes dry runs to the
+    // node. Additive, `transaction_version` unchanged.
+    // 254 — added `Identity` (`pallet_identity`, pallet index 152): on-chain
+    // identities with the people chains' fields plus an IPI name number, a
+    // PRO affiliation, a label and a booking contact. Only registrars
+    // certified by `Certification` can be added as judges or judge.
+    // Additive, `transaction_version` unchanged.
+    // 253 — added `Sales` (`pallet_sales`, pallet index 151): primary sales
+    // of releases, the price held from the fan for a 14 days refund window,
+    // during which they can dispute it before the dispute arbiter. Proceeds
+    // are then paid along the release's royalty split. `CatalogManagement`
+    // proxies can list, close and refund. Additive, `transaction_version`
+    // unchanged.
+    // 252 — added `Vesting` (`pallet_vesting`, pallet index 150): balances
+    // unlocking linearly over a number of blocks, set for team and investor
+    // allocations at genesis, or created with `vested_transfer` (e.g. an
+    // artist advance paid in AFT). Additive, `transaction_version` unchanged.
+    // 251 — label proxy types: `ProxyType::CatalogManagement` (catalog,
+    // licensing, transfers and royalty splits), `RoyaltyClaims` (license
+    // payments and escrowed advances) and `Governance` (referenda, preimages
+    // and voting), for the members of a label's multisig or the delegates of
+    // its pure proxy. Additive, `transaction_version` unchanged.
+    // 250 — added `Recovery` (`pallet_recovery`, pallet index 149): an
+    // account, an artist member's included, can be recovered by friends it
+    // designated beforehand, and then acted for with `as_recovered`. The
+    // call filter refuses configurations with a threshold under 2 friends or
+    // a delay under 3 days. Additive, `transaction_version` unchanged.
+    // 249 — validators are elected by `ElectionProviderMultiPhase`
+    // (`pallet_election_provider_multi_phase`, pallet index 148): solutions
+    // are submitted during a signed phase against a deposit, or mined by the
+    // validators' offchain workers, and only checked on-chain. The snapshot
+    // takes the top 3,000 voters of `VoterList`, on-chain phragmén is kept
+    // for genesis and as fallback. Additive, `transaction_version` unchanged.
+    // 248 — added `SpamTelemetry` (`pallet_spam_telemetry`, pallet index
+    // 147): its `CountFailures` transaction extension counts the signed
+    // transactions whose call failed, per staking-era length and error
+    // category, read through the new `SpamTelemetryApi` runtime API. New
+    // transaction extension, hence `transaction_version` 8 -> 9.
+    // 247 — misbehaving validators are slashed: `Offences`
+    // (`pallet_offences`, pallet index 145) hands GRANDPA equivocations and
+    // im-online unresponsiveness reports to `Staking`, which slashes to the
+    // treasury. `ImOnline` (`pallet_im_online`, index 146) adds an
+    // `im_online` session key; `session.set_keys` takes the three keys,
+    // hence `transaction_version` 7 -> 8. GRANDPA key ownership proofs are
+    // served from `Historical`, and Aura skips disabled validators.
+    // 246 — `RoyaltiesApi` v2 gains `simulate_split`: what the distributions
+    // of a block range paid for an asset, next to what they would have paid
+    // under another split, from the statement ledgers. Royalty statements
+    // record the block their distribution was queued in (storage layout of
+    // `Statements` changed).
+    // 245 — `FastUnstake` (`pallet_fast_unstake`, pallet index 143) lets
+    // nominators that were not exposed in the last `BondingDuration` eras
+    // unbond at once, and `DelegatedStaking` (`pallet_delegated_staking`,
+    // index 144) lets agents stake funds held on their delegators' accounts.
+    // The on-chain election no longer reports itself as ongoing, which kept
+    // fast-unstake from checking any stash.
+    // Additive: `transaction_version` unchanged.
+    // 244 — the validator set is elected by nominated proof of stake:
+    // `Staking` (`pallet_staking`, pallet index 11) replaces `Validators`
+    // (index 7) as the session manager, with its `VoterList`
+    // (`pallet_bags_list`, index 12). Eras last a day, elections run
+    // on-chain and each era mints inflation on a reward curve (2.5% to 8% a
+    // year, at most when half the issuance is staked): stakers get their
+    // share, the treasury the rest. Block authors earn era points. The
+    // `Validators` calls are gone, hence `transaction_version` 6 -> 7.
+    // Melodie is reset on deploy, so there is no migration.
+    // 243 — added `ImportStaging` (`pallet_import_staging`, pallet index
+    // 142): approved importers stage batches of catalog registry calls under
+    // a deposit, `DeferredWork` validates them entry by entry into an
+    // on-chain report (new `CatalogTask::ValidateImport`), and the importer
+    // then commits the whole batch or discards it. Additive,
+    // `transaction_version` unchanged.
+    // 242 — `SoundRecordings` stores the sales certifications (gold,
+    // platinum, ...) certification bodies award recordings per territory,
+    // served by the new `RecordingsApi`. Certification bodies are a new
+    // `Certification` organization kind. Additive, `transaction_version`
+    // unchanged.
+    // 241 — the safe-mode and tx-pause whitelists are the shared
+    // `shared_runtime::call_filter` policies: `Grandpa` equivocation reports
+    // keep working in safe mode and can't be paused, `System` remarks are
+    // halted by safe mode and can be paused. No storage change.
+    // 240 — added `TxPause` (`pallet_tx_pause`, pallet index 19), the
+    // `TechnicalCommittee` (`pallet_collective`, index 139) with its
+    // Root-managed `TechnicalMembership` (`pallet_membership`, index 140) and
+    // `Whitelist` (`pallet_whitelist`, index 141). A 2/3 committee motion can
+    // force-enter safe mode, pause calls and whitelist calls, which then go
+    // through the new fast `whitelisted_caller` track. `BaseCallFilter` now
+    // enforces safe mode and paused calls, and the safe-mode whitelist keeps
+    // block production and governance usable. Additive, `transaction_version`
+    // unchanged.
+    // 239 — added `Treasury` (`pallet_treasury`, pallet index 136),
+    // `Bounties` (`pallet_bounties`, index 137) and `ChildBounties`
+    // (`pallet_child_bounties`, index 138) to fund ecosystem grants. 20% of
+    // transaction fees (AFT and asset fees alike, tips excluded), slashed
+    // referendum and curator deposits go to the pot; spends up to 100k AFT
+    // are approved on the new `treasury_spender` track (`Origins`
+    // `TreasurySpender`). Additive, `transaction_version` unchanged.
+    // 238 — added `Artists` (`pallet_artists`, pallet index 135): bands and
+    // other shared artist entities with their own account, acting through
+    // proposals approved by up to 32 member accounts, with separate
+    // thresholds for metadata, payouts and membership. Additive,
+    // `transaction_version` unchanged.
+    // 237 — on-chain governance: `Referenda` (`pallet_referenda`, pallet
+    // index 133) decided by `ConvictionVoting` (`pallet_conviction_voting`,
+    // index 134) over five tracks, four of them dispatching the custom
+    // `Origins` (index 132). Certifiers, genres, badge issuers and import
+    // windows move to `MusicIndustryAdmin`, escrow arbitration and reporter
+    // slashing to `DisputeArbiter`; Root is still accepted by both. Additive,
+    // `transaction_version` unchanged.
+    // 236 — fees can be paid in the USD stablecoin (asset 1) registered by
+    // Root in `Assets` (`pallet_assets`, pallet index 128): it is swapped to
+    // AFT through its `AssetConversion` pool (`pallet_asset_conversion`,
+    // index 130, liquidity tokens in `PoolAssets`, index 129) and the block
+    // author is paid in AFT. `ChargeTransactionPayment` is replaced by
+    // `AssetTxPayment`'s `ChargeAssetTxPayment` (index 131), which carries
+    // the optional fee asset, hence `transaction_version` 5 -> 6.
+    // 235 — added `CatalogRoot` (`pallet_catalog_root`, pallet index 127):
+    // anchored `SoundRecordings` and published `CatalogReleases` are hashed
+    // into the `allfeat/catalog` child trie, whose root is deposited in
+    // every block digest (`DigestItem::Other`, tagged `ctlg`). Existing
+    // entries are not backfilled (melodie is reset on deploy). Additive,
+    // `transaction_version` unchanged.
+    // 234 — `Licensing` sublicensing: `publish_offer` takes the sublicense
+    // rights of the offer (max depth, share passed through to the licensor),
+    // and licensees publish sublicense offers with `offer_sublicense` (call
+    // index 5), up to 64 per original license. Revoking a license revokes
+    // its sublicenses. `publish_offer` arguments changed, hence
+    // `transaction_version` 4 -> 5; existing offers and licenses are not
+    // migrated (melodie is reset on deploy).
+    // 233 — added `Sponsorship` (`pallet_sponsorship`, pallet index 126): a
+    // sponsor holds a deposit and pre-authorizes paying the fees of another
+    // account's catalog calls up to an allowance. Fees are now charged
+    // through `ChargeSponsored`. Additive, `transaction_version` unchanged.
+    // 232 — new `SigningApi` runtime API: resolves nonce, era, genesis hash
+    // and versions into the exact payload an external signer signs, and
+    // turns the signature back into an encoded extrinsic. No storage or call
+    // changes, `transaction_version` unchanged.
+    // 231 — verified artists get 50% of the fee (tips excluded) of their
+    // catalog calls back from the `af/rebat` treasury sub-pot, batches
+    // included only when every batched call is a catalog call. No storage
+    // or call changes, `transaction_version` unchanged.
+    // 230 — added `Badges` (`pallet_badges`, pallet index 125): issuers
+    // approved by Root define badge classes and award non-transferable badges
+    // to accounts, listed by the new `BadgesApi` runtime API. Additive,
+    // `transaction_version` unchanged.
+    // 229 — added `Genres` (`pallet_genres`, pallet index 124): a genre
+    // taxonomy (label hash, parent genre, up to 3 levels below a root) that
+    // Root extends, relabels and deprecates without a runtime upgrade.
+    // Additive, `transaction_version` unchanged.
+    // 228 — `Royalties` gains `set_allowed_payers` (call index 7): asset
+    // owners restrict who may distribute their royalties, up to 16 payers;
+    // open by default. `distribute_usage` skips assets closed to the caller.
+    // Additive, `transaction_version` unchanged.
+    // 227 — `ProxyType::ArtistManager { until }`: an artist delegates catalog
+    // work (MIDDS registrations, recording and release metadata, license
+    // offers and acceptances) to a manager until a given block.
+    // `ProxyDepositFactor` follows the larger proxy type. Additive,
+    // `transaction_version` unchanged.
+    // 226 — added `RegistryAttestations` (`pallet_registry_attestations`,
+    // pallet index 123): certifiers approved in `Certification` register the
+    // key their registry signs with, and anyone relays claims signed with it
+    // ("work registered with society X under number Y"). Additive,
+    // `transaction_version` unchanged.
+    // 225 — `Royalties` exposes `DistributionWeightCeiling` (25%): the
+    // integrity test fails if paying one usage entry to a full split table no
+    // longer fits that share of the block. Metadata-only change,
+    // `transaction_version` unchanged.
+    // 224 — added `Subscriptions` (`pallet_subscriptions`, pallet index 122):
+    // fans authorize recurring charges to artists or platforms, executed in
+    // `on_initialize` with `ChargeFailed` events on missing funds. Additive,
+    // `transaction_version` unchanged.
+    // 223 — `CatalogReleases` gains localized titles (`set_titles`, call index
+    // 4) and a language index of published releases, served by the new
+    // `ReleasesApi` runtime API. `ReleasePublished` carries the titles and
+    // `ReleaseDeposit` covers them. Existing releases have no titles.
+    // `transaction_version` unchanged.
+    // 222 — added the `CatalogTransfersApi` runtime API serving the chain of
+    // title of works and recordings. `CatalogTransfers` keeps the latest 64
+    // links of each provenance chain; older ones are rebuilt from
+    // `TransferCompleted` events. `transaction_version` unchanged.
+    // 221 — added `Escrow` (`pallet_escrow`, pallet index 121): labels hold
+    // advances for artists, released tranche by tranche as milestones are
+    // approved by the label, by timeout or by root arbitration. Additive,
+    // `transaction_version` unchanged.
+    // 220 — added `Crowdfunding` (`pallet_crowdfunding`, pallet index 120):
+    // verified artists raise funds for a recording against a share of its
+    // royalties, moved to the round's backers in `Royalties` once funded;
+    // backers are refunded if the target is missed. `Royalties` gains
+    // `assign_share` for it. Additive, `transaction_version` unchanged.
+    // 219 — added `Nfts` (`pallet_nfts`, pallet index 23) for fan
+    // collectibles: music drop collections can only be created by verified
+    // artists (`MusicDropOrigin`). `RoyaltyAsset` gains a `Drop` variant so a
+    // drop's royalty settings live in the `Royalties` split tables. Additive,
+    // `transaction_version` unchanged.
+    // 218 — added `Ticketing` (`pallet_ticketing`, pallet index 119):
+    // verified artists create live events with priced seat classes; fans buy
+    // tickets, scanners appointed by the organizer redeem them at the door,
+    // and resale follows the event's transfer policy. Additive,
+    // `transaction_version` unchanged.
+    // 217 — added `UpgradeNotice` (`pallet_upgrade_notice`, pallet index 22):
+    // governance announces the activation block of accepted runtime upgrades,
+    // read by nodes through the new `UpgradeNoticeApi` runtime API to count
+    // down to it. Additive, `transaction_version` unchanged.
+    // 216 — added `Anchoring` (`pallet_anchoring`, pallet index 118):
+    // distributors anchor the content hashes of DDEX ERN and CWR delivery
+    // files by sequence number, looked up by hash through the new
+    // `AnchoringApi` runtime API. Additive, `transaction_version` unchanged.
+    // 215 — added `DeferredWork` (`pallet_deferred_work`, pallet index 117):
+    // a shared queue of heavy catalog operations run in `on_idle` within a
+    // tenth of each block, starting with governance bulk reassignments of
+    // works and recordings. Queue depth is exposed through the new
+    // `DeferredWorkApi` runtime API. Additive, `transaction_version` unchanged.
+    // 214 — added `UsageOracle` (`pallet_usage_oracle`, pallet index 116):
+    // bonded data providers report weekly plays per ISRC, aggregated by
+    // median with outlier rejection and slashing. `Royalties` gains
+    // `distribute_usage`, paying pots along finalized aggregates. Additive,
+    // `transaction_version` unchanged.
+    // 213 — added `Certification` (`pallet_certification`, pallet index
+    // 115): governance-managed certifiers (PROs, distributors, registrars)
+    // attest artists and MIDDS works, upgrading them to a verified tier.
+    // Additive, `transaction_version` unchanged.
+    // 212 — `Royalties` numbers each payer's distributions as statements and
+    // keeps their ledgers (gross, fees, net per beneficiary) until pruned,
+    // exported through the new `RoyaltiesApi` runtime API. `distribute` now
+    // holds a ledger deposit; `RoyaltyPaid` and `DistributionQueued` gain
+    // fields. Additive call, `transaction_version` unchanged.
+    // 211 — added `CatalogTransfers` (`pallet_catalog_transfers`, pallet
+    // index 114): offer/accept transfers of works and recordings, singly or
+    // as a catalog, with a provenance chain. `SoundRecordings` gains the
+    // `OwnershipTransferred` event. Additive, `transaction_version` unchanged.
+    // 210 — added `Licensing` (`pallet_licensing`, pallet index 113):
+    // sync and mechanical license offers on royalty assets, paid through
+    // an escrow hold. Additive, `transaction_version` unchanged.
+    // 209 — `Royalties` distributes funded payout pots along usage reports
+    // (`distribute`, processed in `on_idle`); replacing a split now needs
+    // the replaced collaborators' signatures too. Additive call.
+    // 208 — added `Royalties` (`pallet_royalties`, pallet index 112):
+    // co-signed royalty split tables on MIDDS works and anchored
+    // recordings. Additive, `transaction_version` unchanged.
+    // 207 — added `CatalogReleases` (`pallet_releases`, pallet index 111),
+    // grouping anchored `SoundRecordings` into releases keyed by UPC/EAN.
+    // Additive, `transaction_version` unchanged.
+    // 206 — added `ImportWindows` (`pallet_import_windows`, pallet index
+    // 110) and its `PrioritizeCatalogImport` transaction extension. The
+    // extension changes the signed-extension set, hence
+    // `transaction_version` 3 -> 4.
+    // 205 — added `SoundRecordings` (`pallet_recordings`, pallet index
+    // 109), anchoring recording masters by ISRC and content hash against
+    // MIDDS musical works. Additive, `transaction_version` unchanged.
     // 204 — added the `Releases` `pallet_midds<Instance3>` (pallet index
     // 108) and its `ReleaseApi` runtime API, completing the V1 MIDDS type
     // surface (`MusicalWork` / `Recording` / `Release`). Additive (new
@@ -88,7 +378,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     // `remove_own` / `finalize`). Per `../midds-sdk/docs/economics.md`
     // decision #11 no migration is required: melodie testnet is reset on
     // deploy, mainnet doesn't host the pallet.
-    transaction_version: 3,
+    transaction_version: 10,
     system_version: 1,
 };
 
@@ -113,9 +403,12 @@ pub type TxBareExtension = (
     frame_system::CheckTxVersion<Runtime>,
     frame_system::CheckGenesis<Runtime>,
     frame_system::CheckMortality<Runtime>,
+    pallet_artists::CheckArtistWork<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
+    pallet_import_windows::PrioritizeCatalogImport<Runtime>,
+    pallet_spam_telemetry::CountFailures<Runtime>,
     frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 );
 
@@ -149,6 +442,24 @@ pub type MetaTxBareExtension = (
 /// extrinsic level for Signed (v4) transactions.
 pub type TxExtension = TxBareExtension;
 
+/// The extension signed along with a call for `params`, see [`allfeat_primitives::signing`].
+pub fn tx_extension(params: &allfeat_primitives::signing::TransactionParams) -> TxExtension {
+    (
+        frame_system::CheckNonZeroSender::new(),
+        frame_system::CheckSpecVersion::new(),
+        frame_system::CheckTxVersion::new(),
+        frame_system::CheckGenesis::new(),
+        frame_system::CheckMortality::from(params.era),
+        pallet_artists::CheckArtistWork::new(),
+        frame_system::CheckNonce::from(params.nonce),
+        frame_system::CheckWeight::new(),
+        pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::from(params.tip, None),
+        pallet_import_windows::PrioritizeCatalogImport::new(),
+        pallet_spam_telemetry::CountFailures::new(),
+        frame_metadata_hash_extension::CheckMetadataHash::new(false),
+    )
+}
+
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
     generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, TxExtension>;
@@ -199,8 +510,11 @@ mod runtime {
     #[runtime::pallet_index(6)]
     pub type TransactionPayment = pallet_transaction_payment;
 
-    #[runtime::pallet_index(7)]
-    pub type Validators = pallet_validators;
+    #[runtime::pallet_index(11)]
+    pub type Staking = pallet_staking;
+
+    #[runtime::pallet_index(12)]
+    pub type VoterList = pallet_bags_list<Instance1>;
 
     #[runtime::pallet_index(8)]
     pub type Session = pallet_session;
@@ -229,12 +543,21 @@ mod runtime {
     #[runtime::pallet_index(18)]
     pub type SafeMode = pallet_safe_mode;
 
+    #[runtime::pallet_index(19)]
+    pub type TxPause = pallet_tx_pause;
+
     #[runtime::pallet_index(20)]
     pub type MetaTx = pallet_meta_tx;
 
     #[runtime::pallet_index(21)]
     pub type VerifySignature = pallet_verify_signature;
 
+    #[runtime::pallet_index(22)]
+    pub type UpgradeNotice = pallet_upgrade_notice;
+
+    #[runtime::pallet_index(23)]
+    pub type Nfts = pallet_nfts;
+
     // Allfeat related
 
     #[runtime::pallet_index(105)]
@@ -249,4 +572,141 @@ mod runtime {
 
     #[runtime::pallet_index(108)]
     pub type Releases = pallet_midds<Instance3>;
+
+    // Named apart from the MIDDS `Recordings` registry it complements.
+    #[runtime::pallet_index(109)]
+    pub type SoundRecordings = pallet_recordings;
+
+    #[runtime::pallet_index(110)]
+    pub type ImportWindows = pallet_import_windows;
+
+    // Named apart from the MIDDS `Releases` registry it complements.
+    #[runtime::pallet_index(111)]
+    pub type CatalogReleases = pallet_releases;
+
+    #[runtime::pallet_index(112)]
+    pub type Royalties = pallet_royalties;
+
+    #[runtime::pallet_index(113)]
+    pub type Licensing = pallet_licensing;
+
+    #[runtime::pallet_index(114)]
+    pub type CatalogTransfers = pallet_catalog_transfers;
+
+    #[runtime::pallet_index(115)]
+    pub type Certification = pallet_certification;
+
+    #[runtime::pallet_index(116)]
+    pub type UsageOracle = pallet_usage_oracle;
+
+    #[runtime::pallet_index(117)]
+    pub type DeferredWork = pallet_deferred_work;
+
+    #[runtime::pallet_index(118)]
+    pub type Anchoring = pallet_anchoring;
+
+    #[runtime::pallet_index(119)]
+    pub type Ticketing = pallet_ticketing;
+
+    #[runtime::pallet_index(120)]
+    pub type Crowdfunding = pallet_crowdfunding;
+
+    #[runtime::pallet_index(121)]
+    pub type Escrow = pallet_escrow;
+
+    #[runtime::pallet_index(122)]
+    pub type Subscriptions = pallet_subscriptions;
+
+    #[runtime::pallet_index(123)]
+    pub type RegistryAttestations = pallet_registry_attestations;
+
+    #[runtime::pallet_index(124)]
+    pub type Genres = pallet_genres;
+
+    #[runtime::pallet_index(125)]
+    pub type Badges = pallet_badges;
+
+    #[runtime::pallet_index(126)]
+    pub type Sponsorship = pallet_sponsorship;
+
+    #[runtime::pallet_index(127)]
+    pub type CatalogRoot = pallet_catalog_root;
+
+    #[runtime::pallet_index(128)]
+    pub type Assets = pallet_assets<Instance1>;
+
+    #[runtime::pallet_index(129)]
+    pub type PoolAssets = pallet_assets<Instance2>;
+
+    #[runtime::pallet_index(130)]
+    pub type AssetConversion = pallet_asset_conversion;
+
+    #[runtime::pallet_index(131)]
+    pub type AssetTxPayment = pallet_asset_conversion_tx_payment;
+
+    #[runtime::pallet_index(132)]
+    pub type Origins = pallet_custom_origins;
+
+    #[runtime::pallet_index(133)]
+    pub type Referenda = pallet_referenda;
+
+    #[runtime::pallet_index(134)]
+    pub type ConvictionVoting = pallet_conviction_voting;
+
+    #[runtime::pallet_index(135)]
+    pub type Artists = pallet_artists;
+
+    #[runtime::pallet_index(136)]
+    pub type Treasury = pallet_treasury;
+
+    #[runtime::pallet_index(137)]
+    pub type Bounties = pallet_bounties;
+
+    #[runtime::pallet_index(138)]
+    pub type ChildBounties = pallet_child_bounties;
+
+    #[runtime::pallet_index(139)]
+    pub type TechnicalCommittee = pallet_collective<Instance1>;
+
+    #[runtime::pallet_index(140)]
+    pub type TechnicalMembership = pallet_membership<Instance1>;
+
+    #[runtime::pallet_index(141)]
+    pub type Whitelist = pallet_whitelist;
+
+    #[runtime::pallet_index(142)]
+    pub type ImportStaging = pallet_import_staging;
+
+    #[runtime::pallet_index(143)]
+    pub type FastUnstake = pallet_fast_unstake;
+
+    #[runtime::pallet_index(144)]
+    pub type DelegatedStaking = pallet_delegated_staking;
+
+    #[runtime::pallet_index(145)]
+    pub type Offences = pallet_offences;
+
+    #[runtime::pallet_index(146)]
+    pub type ImOnline = pallet_im_online;
+
+    #[runtime::pallet_index(147)]
+    pub type SpamTelemetry = pallet_spam_telemetry;
+
+    #[runtime::pallet_index(148)]
+    pub type ElectionProviderMultiPhase = pallet_election_provider_multi_phase;
+
+    #[runtime::pallet_index(149)]
+    pub type Recovery = pallet_recovery;
+
+    #[runtime::pallet_index(150)]
+    pub type Vesting = pallet_vesting;
+
+    #[runtime::pallet_index(151)]
+    pub type Sales = pallet_sales;
+
+    #[runtime::pallet_index(152)]
+    pub type Identity = pallet_identity;
+
+    #[runtime::pallet_index(153)]
+    pub type Contracts = pallet_contracts;
 }
@@ -16,29 +16,84 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+mod anchoring;
+mod artists;
+mod asset_conversion;
+mod assets;
+mod badges;
+mod bounties;
+mod catalog_root;
+mod catalog_transfers;
+mod certification;
+mod contracts;
+mod conviction_voting;
+mod crowdfunding;
+mod deferred_work;
+mod delegated_staking;
+mod election_provider_multi_phase;
+mod escrow;
+mod fast_unstake;
+mod genres;
+mod governance;
+mod identity;
+mod import_staging;
+mod import_windows;
+mod licensing;
 mod midds;
 mod multisig;
+mod nfts;
 mod proxy;
+mod recordings;
+mod recovery;
+mod referenda;
+mod registry_attestations;
+mod releases;
+mod royalties;
+mod sales;
 mod scheduler;
+mod sponsorship;
+mod subscriptions;
+mod technical_committee;
+mod ticketing;
+mod treasury;
+mod usage_oracle;
+mod vesting;
+mod whitelist;
 // System stuffs.
 mod aura;
 mod authorship;
 mod balances;
 mod grandpa;
+mod im_online;
 mod meta_tx;
+mod offences;
 mod preimage;
 mod safe_mode;
 mod session;
+mod spam_telemetry;
+mod staking;
 mod sudo;
 mod system;
 mod timestamp;
 mod transaction_payment;
+mod tx_pause;
+mod upgrade_notice;
 mod utility;
-mod validators;
 
 // External required imports
+pub use asset_conversion::*;
 pub use balances::*;
+pub use certification::*;
+pub use governance::*;
 pub use midds::*;
+pub use nfts::*;
+pub use proxy::ProxyType;
+pub use recordings::*;
+pub use royalties::*;
 pub use session::*;
+pub use staking::*;
 pub use system::*;
+pub use technical_committee::*;
 pub use transaction_payment::*;
+pub use treasury::*;
+pub use usage_oracle::*;
@@ -17,13 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::*;
-use frame_support::parameter_types;
 
-parameter_types! {
-    pub const MaxValidators: u32 = 5;
-}
-
-impl pallet_validators::Config for Runtime {
-    type MaxValidators = MaxValidators;
-    type WeightInfo = weights::validators::AllfeatWeight<Runtime>;
+impl pallet_anchoring::Config for Runtime {
+    type Time = Timestamp;
+    type WeightInfo = weights::anchoring::AllfeatWeight<Runtime>;
 }
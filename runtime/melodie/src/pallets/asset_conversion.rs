@@ -0,0 +1,80 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    PalletId, ord_parameter_types, parameter_types,
+    traits::{
+        ConstU32, ConstU128,
+        fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
+        tokens::imbalance::ResolveAssetTo,
+    },
+};
+use pallet_asset_conversion::{Ascending, Chain, WithFirstAsset};
+use shared_runtime::currency::AFT;
+use sp_core::U256;
+use sp_runtime::{Permill, traits::AccountIdConversion};
+
+parameter_types! {
+    pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
+    pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
+    pub const PoolSetupFee: Balance = 10 * AFT;
+    pub const LiquidityWithdrawalFee: Permill = Permill::zero();
+}
+
+ord_parameter_types! {
+    pub const AssetConversionOrigin: AccountId =
+        AccountIdConversion::<AccountId>::into_account_truncating(&AssetConversionPalletId::get());
+}
+
+/// AFT and the assets of `Assets`, as one fungibles registry.
+pub type NativeAndAssets =
+    UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<u32>, AccountId>;
+
+pub type PoolIdToAccountId = pallet_asset_conversion::AccountIdConverter<
+    AssetConversionPalletId,
+    (NativeOrWithId<u32>, NativeOrWithId<u32>),
+>;
+
+// AFT/asset pools, whose price is the on-chain rate fees paid in an asset are converted at.
+impl pallet_asset_conversion::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type HigherPrecisionBalance = U256;
+    type AssetKind = NativeOrWithId<u32>;
+    type Assets = NativeAndAssets;
+    type PoolId = (Self::AssetKind, Self::AssetKind);
+    type PoolLocator = Chain<
+        WithFirstAsset<Native, AccountId, NativeOrWithId<u32>, PoolIdToAccountId>,
+        Ascending<AccountId, NativeOrWithId<u32>, PoolIdToAccountId>,
+    >;
+    type PoolAssetId = u32;
+    type PoolAssets = PoolAssets;
+    type PoolSetupFee = PoolSetupFee;
+    type PoolSetupFeeAsset = Native;
+    type PoolSetupFeeTarget = ResolveAssetTo<AssetConversionOrigin, Self::Assets>;
+    type PalletId = AssetConversionPalletId;
+    // 0.3%
+    type LPFee = ConstU32<3>;
+    type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
+    type MaxSwapPathLength = ConstU32<3>;
+    type MintMinLiquidity = ConstU128<100>;
+    type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = pallet_asset_conversion::NativeOrWithIdFactory<u32>;
+}
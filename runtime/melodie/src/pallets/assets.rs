@@ -0,0 +1,88 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    instances::{Instance1, Instance2},
+    parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU32, ConstU128, NeverEnsureOrigin},
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use shared_runtime::currency::deposit;
+
+parameter_types! {
+    pub const AssetDeposit: Balance = deposit(1, 190);
+    pub const AssetAccountDeposit: Balance = deposit(1, 16);
+    pub const AssetsMetadataDepositBase: Balance = deposit(1, 68);
+    pub const AssetsMetadataDepositPerByte: Balance = deposit(0, 1);
+    pub const AssetsApprovalDeposit: Balance = ExistentialDeposit::get();
+    pub const AssetsStringLimit: u32 = 50;
+}
+
+// Fee assets, e.g. a USD stablecoin. Creation is reserved to Root (`force_create`): the registry
+// only holds assets governance vetted, of which `FeeAssets` lists those fees may be paid in.
+impl pallet_assets::Config<Instance1> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type AssetId = u32;
+    type AssetIdParameter = parity_scale_codec::Compact<u32>;
+    type ReserveData = ();
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<NeverEnsureOrigin<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = AssetDeposit;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type MetadataDepositBase = AssetsMetadataDepositBase;
+    type MetadataDepositPerByte = AssetsMetadataDepositPerByte;
+    type ApprovalDeposit = AssetsApprovalDeposit;
+    type StringLimit = AssetsStringLimit;
+    type Freezer = ();
+    type Holder = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+// Liquidity tokens of the `AssetConversion` pools, only minted by the pallet itself.
+impl pallet_assets::Config<Instance2> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type ReserveData = ();
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSignedBy<AssetConversionOrigin, AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = AssetsApprovalDeposit;
+    type StringLimit = AssetsStringLimit;
+    type Freezer = ();
+    type Holder = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
@@ -27,7 +27,8 @@ parameter_types! {
 
 impl pallet_aura::Config for Runtime {
     type AuthorityId = AuraId;
-    type DisabledValidators = ();
+    // Validators disabled for an offence stop authoring until the next era.
+    type DisabledValidators = Session;
     type MaxAuthorities = MaxAuthorities;
     type AllowMultipleBlocksPerSlot = ConstBool<false>;
     type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Runtime>;
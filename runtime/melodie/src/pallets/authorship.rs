@@ -20,5 +20,6 @@ use crate::*;
 
 impl pallet_authorship::Config for Runtime {
     type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
-    type EventHandler = ();
+    // Authoring a block also counts as a heartbeat.
+    type EventHandler = (Staking, ImOnline);
 }
@@ -0,0 +1,32 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::*;
+use frame_support::parameter_types;
+
+parameter_types! {
+    // An IPFS CID of the badge's artwork and description.
+    pub const MaxBadgeMetadataLength: u32 = 64;
+    pub const MaxBadgeClassesPerIssuer: u32 = 256;
+}
+
+impl pallet_badges::Config for Runtime {
+    type AdminOrigin = EnsureMusicIndustryAdmin;
+    type MaxMetadataLength = MaxBadgeMetadataLength;
+    type MaxClassesPerIssuer = MaxBadgeClassesPerIssuer;
+    type WeightInfo = weights::badges::AllfeatWeight<Runtime>;
+}
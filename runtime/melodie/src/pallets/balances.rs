@@ -17,35 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::*;
-use frame_support::parameter_types;
 use shared_runtime::currency::AFT;
-use sp_core::ConstU32;
 
-parameter_types! {
-    pub const ExistentialDeposit: Balance =  AFT / 10; // 0.1 AFT
-
-}
-
-parameter_types! {
-    // For weight estimation, we assume that the most locks on an individual account will be 50.
-    // This number may need to be adjusted in the future if this assumption no longer holds true.
-    pub const MaxLocks: u32 = 50;
-    pub const MaxReserves: u32 = 50;
-}
-
-impl pallet_balances::Config for Runtime {
-    type Balance = Balance;
-    type DustRemoval = ();
-    type RuntimeEvent = RuntimeEvent;
-    type ExistentialDeposit = ExistentialDeposit;
-    type AccountStore = frame_system::Pallet<Runtime>;
-    type WeightInfo = weights::balances::AllfeatWeight<Runtime>;
-    type MaxLocks = MaxLocks;
-    type MaxReserves = MaxReserves;
-    type ReserveIdentifier = [u8; 8];
-    type FreezeIdentifier = RuntimeFreezeReason;
-    type MaxFreezes = ConstU32<50>;
-    type RuntimeHoldReason = RuntimeHoldReason;
-    type RuntimeFreezeReason = RuntimeFreezeReason;
-    type DoneSlashHandler = ();
+shared_runtime::impl_allfeat_common_configs! {
+    balances { existential_deposit: AFT / 10 } // 0.1 AFT
 }
@@ -0,0 +1,67 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use alloc::{vec, vec::Vec};
+use frame_support::{parameter_types, traits::fungible::NativeOrWithId};
+use pallet_bounties::TransferAllFungibles;
+use shared_runtime::currency::{AFT, MILLIAFT};
+use sp_runtime::Permill;
+
+parameter_types! {
+    pub const BountyDepositBase: Balance = AFT;
+    pub const BountyDepositPayoutDelay: BlockNumber = DAYS;
+    pub const BountyUpdatePeriod: BlockNumber = 90 * DAYS;
+    pub const CuratorDepositMultiplier: Permill = Permill::from_percent(50);
+    pub const CuratorDepositMin: Option<Balance> = Some(AFT);
+    pub const CuratorDepositMax: Option<Balance> = Some(100 * AFT);
+    pub const BountyValueMinimum: Balance = 10 * AFT;
+    pub const DataDepositPerByte: Balance = 10 * MILLIAFT;
+    pub const MaximumReasonLength: u32 = 16_384;
+    pub const MaxActiveChildBountyCount: u32 = 16;
+    pub const ChildBountyValueMinimum: Balance = AFT;
+
+    // What a closed bounty's account is swept of, back to the treasury. AFT comes first so the
+    // account can be reaped once the fee asset is gone too.
+    pub BountyAssets: Vec<NativeOrWithId<u32>> =
+        vec![NativeOrWithId::Native, NativeOrWithId::WithId(StableAssetId::get())];
+}
+
+impl pallet_bounties::Config for Runtime {
+    type BountyDepositBase = BountyDepositBase;
+    type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
+    type BountyUpdatePeriod = BountyUpdatePeriod;
+    type CuratorDepositMultiplier = CuratorDepositMultiplier;
+    type CuratorDepositMin = CuratorDepositMin;
+    type CuratorDepositMax = CuratorDepositMax;
+    type BountyValueMinimum = BountyValueMinimum;
+    type DataDepositPerByte = DataDepositPerByte;
+    type RuntimeEvent = RuntimeEvent;
+    type MaximumReasonLength = MaximumReasonLength;
+    type WeightInfo = shared_runtime::weights::bounties::AllfeatWeight<Runtime>;
+    type ChildBountyManager = ChildBounties;
+    type OnSlash = Treasury;
+    type TransferAllAssets = TransferAllFungibles<AccountId, NativeAndAssets, BountyAssets>;
+}
+
+impl pallet_child_bounties::Config for Runtime {
+    type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
+    type ChildBountyValueMinimum = ChildBountyValueMinimum;
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = shared_runtime::weights::child_bounties::AllfeatWeight<Runtime>;
+}
@@ -0,0 +1,39 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::*;
+use frame_support::{parameter_types, traits::Get};
+
+parameter_types! {
+    // Catalog statistics are deposited once per staking era.
+    pub CatalogStatisticsPeriod: BlockNumber = SessionPeriod::get() * SessionsPerEra::get();
+}
+
+/// Everything royalty distributions paid out, reported in the catalog statistics.
+pub struct RoyaltiesDistributed;
+
+impl Get<u128> for RoyaltiesDistributed {
+    fn get() -> u128 {
+        pallet_royalties::TotalDistributed::<Runtime>::get()
+    }
+}
+
+impl pallet_catalog_root::Config for Runtime {
+    type StatisticsPeriod = CatalogStatisticsPeriod;
+    type TotalDistributed = RoyaltiesDistributed;
+    type WeightInfo = weights::catalog_root::AllfeatWeight<Runtime>;
+}
@@ -0,0 +1,79 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{pallet_prelude::DispatchResult, parameter_types};
+use shared_runtime::currency::deposit;
+
+parameter_types! {
+    pub const MaxCatalogTransferBatch: u32 = 100;
+    pub const CatalogTransferDeposit: Balance = deposit(1, 1_400);
+    pub const MaxProvenanceRecords: u32 = 64;
+}
+
+impl pallet_catalog_transfers::AssetTransfer<RoyaltyAsset, AccountId> for RoyaltyAssetOwnership {
+    fn transfer(asset: &RoyaltyAsset, to: &AccountId) -> DispatchResult {
+        match asset {
+            // Only the depositor changes: the MIDDS bond layers stay with the accounts that
+            // paid them, and are refunded to them.
+            RoyaltyAsset::Work(id) => {
+                pallet_midds::DepositInfo::<Runtime, pallet_midds::Instance1>::try_mutate(
+                    id,
+                    |maybe_info| -> DispatchResult {
+                        let info = maybe_info
+                            .as_mut()
+                            .ok_or(pallet_catalog_transfers::Error::<Runtime>::NotAssetOwner)?;
+                        info.depositor = to.clone();
+                        Ok(())
+                    },
+                )
+            }
+            RoyaltyAsset::Recording(isrc) => {
+                pallet_recordings::Pallet::<Runtime>::transfer_ownership(isrc, to)
+            }
+            // The collection deposit moves to the new owner along with the collection.
+            RoyaltyAsset::Drop(collection) => {
+                pallet_nfts::Pallet::<Runtime>::force_collection_owner(
+                    RuntimeOrigin::root(),
+                    *collection,
+                    to.clone().into(),
+                )
+            }
+        }
+    }
+}
+
+impl pallet_catalog_transfers::Config for Runtime {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetId = RoyaltyAsset;
+    type Assets = RoyaltyAssetOwnership;
+    type MaxBatchSize = MaxCatalogTransferBatch;
+    type OfferDeposit = CatalogTransferDeposit;
+    type MaxProvenanceRecords = MaxProvenanceRecords;
+    type WeightInfo = weights::catalog_transfers::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RoyaltyAssetOwnership;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_catalog_transfers::BenchmarkHelper<RoyaltyAsset, AccountId> for RoyaltyAssetOwnership {
+    fn create_asset(i: u32, owner: &AccountId) -> RoyaltyAsset {
+        <Self as pallet_royalties::BenchmarkHelper<_, _>>::create_asset(i, owner)
+    }
+}
@@ -0,0 +1,125 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::*;
+use frame_support::{
+    pallet_prelude::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen},
+    parameter_types,
+    traits::{Contains, EnsureOrigin},
+};
+use midds_traits::MiddsId;
+
+parameter_types! {
+    pub const MaxCertifiers: u32 = 64;
+    pub const MaxRecordAttestations: u32 = 16;
+}
+
+/// A record certifiers can attest.
+#[derive(
+    Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+)]
+pub enum CertifiedRecord {
+    /// An artist, identified by their account.
+    Artist(AccountId),
+    /// A work of the MIDDS `MusicalWorks` registry.
+    Work(MiddsId),
+}
+
+pub struct CertifiableRecords;
+
+impl Contains<CertifiedRecord> for CertifiableRecords {
+    fn contains(record: &CertifiedRecord) -> bool {
+        match record {
+            CertifiedRecord::Artist(who) => System::account_exists(who),
+            CertifiedRecord::Work(id) => MusicalWorksRegistry::contains(id),
+        }
+    }
+}
+
+/// Accounts of artists verified by at least one certifier.
+pub struct VerifiedArtists;
+
+impl Contains<AccountId> for VerifiedArtists {
+    fn contains(who: &AccountId) -> bool {
+        use pallet_certification::Certification as _;
+
+        Certification::is_verified(&CertifiedRecord::Artist(who.clone()))
+    }
+}
+
+/// Signed origin of an artist verified by at least one certifier.
+pub struct EnsureVerifiedArtist;
+
+impl EnsureOrigin<RuntimeOrigin> for EnsureVerifiedArtist {
+    type Success = AccountId;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<AccountId, RuntimeOrigin> {
+        use pallet_certification::Certification as _;
+
+        match o.clone().into() {
+            Ok(frame_system::RawOrigin::Signed(who))
+                if Certification::is_verified(&CertifiedRecord::Artist(who.clone())) =>
+            {
+                Ok(who)
+            }
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        let certifier = AccountId::from([1; 32]);
+        let artist = AccountId::from([2; 32]);
+        pallet_certification::Certifiers::<Runtime>::insert(
+            &certifier,
+            pallet_certification::OrganizationKind::Pro,
+        );
+        pallet_certification::Attestations::<Runtime>::insert(
+            CertifiedRecord::Artist(artist.clone()),
+            frame_support::BoundedVec::truncate_from(alloc::vec![(certifier, 0)]),
+        );
+        Ok(RuntimeOrigin::signed(artist))
+    }
+}
+
+impl pallet_certification::Config for Runtime {
+    type AdminOrigin = EnsureMusicIndustryAdmin;
+    type Record = CertifiedRecord;
+    type Records = CertifiableRecords;
+    type MaxCertifiers = MaxCertifiers;
+    type MaxAttestations = MaxRecordAttestations;
+    type WeightInfo = weights::certification::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CertifiableRecords;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_certification::BenchmarkHelper<CertifiedRecord> for CertifiableRecords {
+    fn create_record(i: u32) -> CertifiedRecord {
+        use pallet_recordings::BenchmarkHelper as _;
+
+        CertifiedRecord::Work(MusicalWorksRegistry::register_work(i))
+    }
+}
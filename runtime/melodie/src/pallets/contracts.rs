@@ -0,0 +1,84 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use allfeat_primitives::Hash;
+use frame_support::{
+    parameter_types,
+    traits::{ConstBool, Nothing, Randomness},
+};
+use frame_system::EnsureSigned;
+use shared_runtime::contracts::{
+    CodeHashLockupDepositPercent, DefaultDepositLimit, DepositPerByte, DepositPerItem, MaxCodeLen,
+    MaxDebugBufferLen, MaxDelegateDependencies, MaxStorageKeyLen, MaxTransientStorageSize,
+};
+
+parameter_types! {
+    // Values and events of at most 8 KiB, half the default: the storage a block full of
+    // 16 KiB writes could add, at ParityDB's write weight, wouldn't fit in validators' memory.
+    pub ContractsSchedule: pallet_contracts::Schedule<Runtime> = pallet_contracts::Schedule {
+        limits: pallet_contracts::Limits {
+            payload_len: 8 * 1024,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+}
+
+/// Randomness for `seal_random`, which contracts can no longer be uploaded with.
+pub struct NoRandomness;
+impl Randomness<Hash, BlockNumber> for NoRandomness {
+    fn random(_subject: &[u8]) -> (Hash, BlockNumber) {
+        (Hash::default(), System::block_number())
+    }
+}
+
+impl pallet_contracts::Config for Runtime {
+    type Time = Timestamp;
+    type Randomness = NoRandomness;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    // Contracts can't dispatch runtime calls: royalty wrappers and marketplaces only move
+    // balances, which they do through `seal_transfer`.
+    type CallFilter = Nothing;
+    type WeightPrice = pallet_transaction_payment::Pallet<Self>;
+    type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
+    type ChainExtension = ();
+    type Schedule = ContractsSchedule;
+    type CallStack = [pallet_contracts::Frame<Self>; 5];
+    type DepositPerByte = DepositPerByte;
+    type DefaultDepositLimit = DefaultDepositLimit;
+    type DepositPerItem = DepositPerItem;
+    type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+    type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
+    type MaxCodeLen = MaxCodeLen;
+    type MaxStorageKeyLen = MaxStorageKeyLen;
+    type MaxTransientStorageSize = MaxTransientStorageSize;
+    type MaxDelegateDependencies = MaxDelegateDependencies;
+    type UnsafeUnstableInterface = ConstBool<false>;
+    type MaxDebugBufferLen = MaxDebugBufferLen;
+    type UploadOrigin = EnsureSigned<AccountId>;
+    type InstantiateOrigin = EnsureSigned<AccountId>;
+    type Migrations = ();
+    type Debug = ();
+    type Environment = ();
+    type ApiVersion = ();
+    type Xcm = ();
+}
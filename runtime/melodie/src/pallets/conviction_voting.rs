@@ -0,0 +1,41 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, tokens::currency::ActiveIssuanceOf},
+};
+
+parameter_types! {
+    // Votes stay locked one week per conviction multiplier, matching the referenda decision
+    // period.
+    pub const VoteLockingPeriod: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_conviction_voting::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Polls = Referenda;
+    type MaxTurnout = ActiveIssuanceOf<Balances, AccountId>;
+    type MaxVotes = ConstU32<512>;
+    type VoteLockingPeriod = VoteLockingPeriod;
+    type BlockNumberProvider = System;
+    type VotingHooks = ();
+    type WeightInfo = pallet_conviction_voting::weights::SubstrateWeight<Runtime>;
+}
@@ -0,0 +1,84 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{PalletId, pallet_prelude::DispatchResult, parameter_types};
+use shared_runtime::currency::AFT;
+use sp_runtime::Perbill;
+
+parameter_types! {
+    pub const CrowdfundingPalletId: PalletId = PalletId(*b"af/crwdf");
+    pub const MaxRoundBackers: u32 = 500;
+    pub const MinRoundContribution: Balance = 10 * AFT;
+    pub const MaxRoundFundingPeriod: BlockNumber = 90 * DAYS;
+}
+
+/// Backers' shares are carved out of the `Royalties` split tables.
+pub struct RoyaltySplitShares;
+
+impl pallet_crowdfunding::RoyaltyShares<RoyaltyAsset, AccountId> for RoyaltySplitShares {
+    fn share_of(asset: &RoyaltyAsset, who: &AccountId) -> Perbill {
+        pallet_royalties::Pallet::<Runtime>::share_of(asset, who)
+    }
+
+    fn assign_share(
+        asset: &RoyaltyAsset,
+        from: &AccountId,
+        to: &AccountId,
+        share: Perbill,
+    ) -> DispatchResult {
+        pallet_royalties::Pallet::<Runtime>::assign_share(asset, from, to, share)
+    }
+}
+
+impl pallet_crowdfunding::Config for Runtime {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type PalletId = CrowdfundingPalletId;
+    type AssetId = RoyaltyAsset;
+    type RoyaltyShares = RoyaltySplitShares;
+    type OpenOrigin = EnsureVerifiedArtist;
+    type MaxBackers = MaxRoundBackers;
+    type MinContribution = MinRoundContribution;
+    type MaxFundingPeriod = MaxRoundFundingPeriod;
+    type WeightInfo = weights::crowdfunding::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RoyaltySplitShares;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_crowdfunding::BenchmarkHelper<RoyaltyAsset, AccountId> for RoyaltySplitShares {
+    fn create_asset(owner: &AccountId) -> RoyaltyAsset {
+        let asset =
+            <RoyaltyAssetOwnership as pallet_royalties::BenchmarkHelper<_, _>>::create_asset(
+                0, owner,
+            );
+        pallet_royalties::Splits::<Runtime>::insert(
+            &asset,
+            pallet_royalties::Split {
+                shares: frame_support::BoundedVec::truncate_from(alloc::vec![(
+                    owner.clone(),
+                    Perbill::one()
+                )]),
+                activated_at: 0,
+                locked: false,
+            },
+        );
+        asset
+    }
+}
@@ -0,0 +1,115 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    BoundedVec,
+    pallet_prelude::{
+        ConstU32, Decode, DecodeWithMemTracking, DispatchError, Encode, MaxEncodedLen, Weight,
+    },
+    parameter_types,
+    traits::Get,
+};
+use frame_system::EnsureRoot;
+use pallet_catalog_transfers::AssetTransfer;
+use sp_runtime::Perbill;
+
+parameter_types! {
+    pub const MaxDeferredTasks: u32 = 64;
+    pub const DeferredWorkCongestionThreshold: u32 = 48;
+    // A tenth of a block, so deferred work never crowds out regular transactions.
+    pub DeferredWorkBlockBudget: Weight =
+        Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
+    // Reassigning a recording moves its anchor and held deposit; a work only its depositor.
+    pub ReassignAssetWeight: Weight = Weight::from_parts(40_000_000, 8_000)
+        .saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads_writes(4, 4));
+}
+
+/// Heavy catalog operations, carried out by `DeferredWork`.
+#[derive(
+    Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+)]
+pub enum CatalogTask {
+    /// Hand `assets` over to `to`, one asset per step, last first. The remaining assets stay
+    /// with their owners if a transfer fails.
+    ReassignAssets {
+        assets: BoundedVec<RoyaltyAsset, ConstU32<1_024>>,
+        to: AccountId,
+    },
+    /// Validate the entries of a staged catalog import, one per step.
+    ValidateImport(pallet_import_staging::ValidationTask),
+}
+
+impl From<pallet_import_staging::ValidationTask> for CatalogTask {
+    fn from(task: pallet_import_staging::ValidationTask) -> Self {
+        CatalogTask::ValidateImport(task)
+    }
+}
+
+impl pallet_deferred_work::DeferredTask for CatalogTask {
+    fn step_weight(&self) -> Weight {
+        match self {
+            CatalogTask::ReassignAssets { .. } => ReassignAssetWeight::get(),
+            CatalogTask::ValidateImport(_) => ImportStaging::validation_step_weight(),
+        }
+    }
+
+    fn step(self) -> Result<Option<Self>, DispatchError> {
+        match self {
+            CatalogTask::ReassignAssets { mut assets, to } => {
+                let Some(asset) = assets.pop() else {
+                    return Ok(None);
+                };
+                RoyaltyAssetOwnership::transfer(&asset, &to)?;
+                Ok((!assets.is_empty()).then_some(CatalogTask::ReassignAssets { assets, to }))
+            }
+            CatalogTask::ValidateImport(task) => {
+                Ok(ImportStaging::validate_step(task)?.map(CatalogTask::ValidateImport))
+            }
+        }
+    }
+}
+
+impl pallet_deferred_work::Config for Runtime {
+    type Task = CatalogTask;
+    type EnqueueOrigin = EnsureRoot<AccountId>;
+    type MaxQueued = MaxDeferredTasks;
+    type CongestionThreshold = DeferredWorkCongestionThreshold;
+    type BlockBudget = DeferredWorkBlockBudget;
+    type WeightInfo = weights::deferred_work::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CatalogTask;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_deferred_work::BenchmarkHelper<CatalogTask> for CatalogTask {
+    fn noop_task() -> CatalogTask {
+        CatalogTask::ReassignAssets {
+            assets: Default::default(),
+            to: AccountId::from([0; 32]),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_election_provider_support::{ElectionDataProvider, SequentialPhragmen};
+use frame_support::{
+    dispatch::DispatchClass,
+    parameter_types,
+    weights::{Weight, constants::BlockExecutionWeight},
+};
+use frame_system::EnsureRoot;
+use pallet_election_provider_multi_phase::{GeometricDepositBase, SolutionAccuracyOf, WeightInfo};
+use shared_runtime::{
+    RuntimeBlockLength,
+    elections::{
+        BetterSignedThreshold, MaxElectingVoters, MinerTxPriority, NposSolution16, OffchainRepeat,
+        SignedDepositByte, SignedDepositIncreaseFactor, SignedFixedDeposit, SignedMaxRefunds,
+        SignedMaxSubmissions, SignedRewardBase,
+    },
+};
+use sp_runtime::Perbill;
+
+use super::{
+    aura::MaxAuthorities,
+    staking::{OnChainExecution, StakingElectionBounds},
+};
+
+parameter_types! {
+    // Both phases end with the last session of the era, when staking asks for its result.
+    pub const SignedPhase: BlockNumber = SessionPeriod::get() / 4;
+    pub const UnsignedPhase: BlockNumber = SessionPeriod::get() / 4;
+
+    // A solution must fit in a normal extrinsic, leaving room for the rest of the block.
+    pub MinerMaxWeight: Weight = RuntimeBlockWeights::get()
+        .get(DispatchClass::Normal)
+        .max_extrinsic
+        .expect("Normal extrinsics have a weight limit configured; qed")
+        .saturating_sub(BlockExecutionWeight::get());
+    pub MinerMaxLength: u32 =
+        Perbill::from_percent(90) * *RuntimeBlockLength::get().max.get(DispatchClass::Normal);
+}
+
+pub struct ElectionProviderBenchmarkConfig;
+impl pallet_election_provider_multi_phase::BenchmarkingConfig for ElectionProviderBenchmarkConfig {
+    const VOTERS: [u32; 2] = [1_000, MaxElectingVoters::get()];
+    const TARGETS: [u32; 2] = [100, 256];
+    const ACTIVE_VOTERS: [u32; 2] = [500, 1_500];
+    const DESIRED_TARGETS: [u32; 2] = [10, 100];
+    const SNAPSHOT_MAXIMUM_VOTERS: u32 = MaxElectingVoters::get();
+    const MINER_MAXIMUM_VOTERS: u32 = MaxElectingVoters::get();
+    const MAXIMUM_TARGETS: u32 = 256;
+}
+
+impl pallet_election_provider_multi_phase::MinerConfig for Runtime {
+    type AccountId = AccountId;
+    type Solution = NposSolution16;
+    type MaxVotesPerVoter =
+        <<Self as pallet_election_provider_multi_phase::Config>::DataProvider as ElectionDataProvider>::MaxVotesPerVoter;
+    type MaxLength = MinerMaxLength;
+    type MaxWeight = MinerMaxWeight;
+    type MaxWinners = MaxAuthorities;
+    type MaxBackersPerWinner = MaxElectingVoters;
+
+    fn solution_weight(voters: u32, targets: u32, active_voters: u32, degree: u32) -> Weight {
+        <Runtime as pallet_election_provider_multi_phase::Config>::WeightInfo::submit_unsigned(
+            voters,
+            targets,
+            active_voters,
+            degree,
+        )
+    }
+}
+
+// Validators elect the next era's set off-chain: solutions are submitted during the signed phase
+// against a deposit, and mined by the validators' offchain workers during the unsigned phase if no
+// good enough signed solution was submitted. The election falls back to on-chain phragmén when no
+// solution was queued at all.
+impl pallet_election_provider_multi_phase::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type EstimateCallFee = TransactionPayment;
+    type SignedPhase = SignedPhase;
+    type UnsignedPhase = UnsignedPhase;
+    type BetterSignedThreshold = BetterSignedThreshold;
+    type OffchainRepeat = OffchainRepeat;
+    type MinerTxPriority = MinerTxPriority;
+    type MinerConfig = Self;
+    type SignedMaxSubmissions = SignedMaxSubmissions;
+    type SignedMaxWeight = MinerMaxWeight;
+    type SignedMaxRefunds = SignedMaxRefunds;
+    type SignedRewardBase = SignedRewardBase;
+    type SignedDepositBase =
+        GeometricDepositBase<Balance, SignedFixedDeposit, SignedDepositIncreaseFactor>;
+    type SignedDepositByte = SignedDepositByte;
+    type SignedDepositWeight = ();
+    type MaxWinners = MaxAuthorities;
+    type MaxBackersPerWinner = MaxElectingVoters;
+    type ElectionBounds = StakingElectionBounds;
+    // Invalid solutions are slashed to the treasury, rewards are minted.
+    type SlashHandler = Treasury;
+    type RewardHandler = ();
+    type DataProvider = Staking;
+    type Fallback = OnChainExecution;
+    type GovernanceFallback = OnChainExecution;
+    type Solver = SequentialPhragmen<AccountId, SolutionAccuracyOf<Self>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type BenchmarkingConfig = ElectionProviderBenchmarkConfig;
+    type WeightInfo = shared_runtime::weights::election_provider_multi_phase::AllfeatWeight<Runtime>;
+}
@@ -0,0 +1,33 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_system::EnsureRoot;
+use shared_runtime::staking::{FastUnstakeBatchSize, FastUnstakeDeposit, MaxErasToCheckPerBlock};
+
+// Checking is off until Root sets the eras checked per block with `FastUnstake::control`.
+impl pallet_fast_unstake::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Deposit = FastUnstakeDeposit;
+    type ControlOrigin = EnsureRoot<AccountId>;
+    type BatchSize = FastUnstakeBatchSize;
+    type Staking = Staking;
+    type MaxErasToCheckPerBlock = MaxErasToCheckPerBlock;
+    type WeightInfo = shared_runtime::weights::fast_unstake::AllfeatWeight<Runtime>;
+}
@@ -0,0 +1,30 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::*;
+use frame_support::parameter_types;
+
+parameter_types! {
+    // Genre, sub-genre, style, and one spare level.
+    pub const MaxGenreDepth: u32 = 3;
+}
+
+impl pallet_genres::Config for Runtime {
+    type AdminOrigin = EnsureMusicIndustryAdmin;
+    type MaxDepth = MaxGenreDepth;
+    type WeightInfo = weights::genres::AllfeatWeight<Runtime>;
+}
@@ -0,0 +1,98 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Origins of the governance tracks besides Root, one per kind of decision the chain delegates
+//! to referenda. See `referenda.rs` for the tracks themselves.
+
+use crate::*;
+use frame_support::traits::EitherOf;
+use frame_system::EnsureRoot;
+
+pub use pallet_custom_origins::{
+    DisputeArbiter, MusicIndustryAdmin, ReferendumCanceller, ReferendumKiller, TreasurySpender,
+    WhitelistedCaller,
+};
+
+/// Root, or a referendum on the music industry admin track.
+pub type EnsureMusicIndustryAdmin = EitherOf<EnsureRoot<AccountId>, MusicIndustryAdmin>;
+
+/// Root, or a referendum on the dispute arbiter track.
+pub type EnsureDisputeArbiter = EitherOf<EnsureRoot<AccountId>, DisputeArbiter>;
+
+impl pallet_custom_origins::Config for Runtime {}
+
+#[frame_support::pallet]
+pub mod pallet_custom_origins {
+    use frame_support::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {}
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[derive(
+        PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, DecodeWithMemTracking, TypeInfo, Debug,
+    )]
+    #[pallet::origin]
+    pub enum Origin {
+        /// Runs the music industry registries: certifiers, identity registrars, genres, badge
+        /// issuers and catalog import windows.
+        MusicIndustryAdmin,
+        /// Settles disputes: escrow arbitration and slashing of usage reporters.
+        DisputeArbiter,
+        /// Cancels an ongoing referendum, refunding its deposits.
+        ReferendumCanceller,
+        /// Kills an ongoing referendum, slashing its deposits.
+        ReferendumKiller,
+        /// Funds ecosystem grants and bounties out of the treasury.
+        TreasurySpender,
+        /// Dispatches, as Root, calls the technical committee whitelisted.
+        WhitelistedCaller,
+    }
+
+    macro_rules! decl_unit_ensures {
+        ( $( $name:ident ),* $(,)? ) => { $(
+            pub struct $name;
+            impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for $name {
+                type Success = ();
+
+                fn try_origin(o: O) -> Result<Self::Success, O> {
+                    o.into().and_then(|o| match o {
+                        Origin::$name => Ok(()),
+                        r => Err(O::from(r)),
+                    })
+                }
+
+                #[cfg(feature = "runtime-benchmarks")]
+                fn try_successful_origin() -> Result<O, ()> {
+                    Ok(O::from(Origin::$name))
+                }
+            }
+        )* };
+    }
+
+    decl_unit_ensures!(
+        MusicIndustryAdmin,
+        DisputeArbiter,
+        ReferendumCanceller,
+        ReferendumKiller,
+        TreasurySpender,
+        WhitelistedCaller,
+    );
+}
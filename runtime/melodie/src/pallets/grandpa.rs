@@ -19,14 +19,17 @@
 use crate::*;
 use frame_support::parameter_types;
 
-use super::aura::MaxAuthorities;
+use super::{
+    aura::MaxAuthorities,
+    staking::{BondingDuration, MaxExposurePageSize, SessionsPerEra},
+};
 
 parameter_types! {
-    pub const MaxNominatorRewardedPerValidator: u32 = 0;
-}
-
-parameter_types! {
-    pub MaxSetIdSessionEntries: u32 = 0;
+    // Equivocations stay reportable, and their set ids resolvable to a session, for as long as
+    // the offender's stake is bonded.
+    pub const MaxSetIdSessionEntries: u32 = BondingDuration::get() * SessionsPerEra::get();
+    pub const ReportLongevity: u64 =
+        MaxSetIdSessionEntries::get() as u64 * SessionPeriod::get() as u64;
 }
 
 impl pallet_grandpa::Config for Runtime {
@@ -37,8 +40,9 @@ impl pallet_grandpa::Config for Runtime {
         sp_consensus_grandpa::AuthorityId,
     )>>::Proof;
 
-    type EquivocationReportSystem = ();
-    type MaxNominators = MaxNominatorRewardedPerValidator;
+    type EquivocationReportSystem =
+        pallet_grandpa::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
+    type MaxNominators = MaxExposurePageSize;
     type WeightInfo = weights::grandpa::AllfeatWeight<Runtime>;
     type MaxAuthorities = MaxAuthorities;
     type MaxSetIdSessionEntries = MaxSetIdSessionEntries;
@@ -0,0 +1,102 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, Contains},
+};
+use frame_system::EnsureRoot;
+use pallet_certification::OrganizationKind;
+use shared_runtime::{currency::deposit, identity::IdentityInfo};
+use sp_runtime::traits::{StaticLookup, Verify};
+
+parameter_types! {
+    // One storage item of 17 bytes, the identity's fields aside: they are charged by the byte.
+    pub const BasicDeposit: Balance = deposit(1, 17);
+    pub const ByteDeposit: Balance = deposit(0, 1);
+    pub const UsernameDeposit: Balance = deposit(0, 32);
+    // One storage item of 53 bytes: the sub-account and its name.
+    pub const SubAccountDeposit: Balance = deposit(1, 53);
+    pub const MaxSubAccounts: u32 = 100;
+    pub const MaxRegistrars: u32 = 20;
+    pub const PendingUsernameExpiration: BlockNumber = 7 * DAYS;
+    pub const UsernameGracePeriod: BlockNumber = 30 * DAYS;
+}
+
+/// Whether `who` is certified as a registrar by `pallet_certification`.
+fn is_certified_registrar(who: &AccountId) -> bool {
+    pallet_certification::Certifiers::<Runtime>::get(who) == Some(OrganizationKind::Registrar)
+}
+
+/// Keeps identity judgements to the registrars `pallet_certification` certified, and rejects
+/// identities whose music industry fields are malformed (see [`IdentityInfo::is_valid`]).
+///
+/// A registrar whose certification is revoked keeps its slot but can't judge anymore, until
+/// it is certified again. Calls dispatched as Root bypass the filter.
+pub struct IdentityJudges;
+impl Contains<RuntimeCall> for IdentityJudges {
+    fn contains(call: &RuntimeCall) -> bool {
+        let certified = |account: &Address| {
+            <Runtime as frame_system::Config>::Lookup::lookup(account.clone())
+                .is_ok_and(|who| is_certified_registrar(&who))
+        };
+
+        match call {
+            RuntimeCall::Identity(pallet_identity::Call::set_identity { info }) => info.is_valid(),
+            RuntimeCall::Identity(pallet_identity::Call::add_registrar { account }) => {
+                certified(account)
+            }
+            RuntimeCall::Identity(pallet_identity::Call::set_account_id { new, .. }) => {
+                certified(new)
+            }
+            RuntimeCall::Identity(pallet_identity::Call::provide_judgement {
+                reg_index, ..
+            }) => pallet_identity::Registrars::<Runtime>::get()
+                .get(*reg_index as usize)
+                .and_then(Option::as_ref)
+                .is_some_and(|registrar| is_certified_registrar(&registrar.account)),
+            _ => true,
+        }
+    }
+}
+
+impl pallet_identity::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BasicDeposit = BasicDeposit;
+    type ByteDeposit = ByteDeposit;
+    type UsernameDeposit = UsernameDeposit;
+    type SubAccountDeposit = SubAccountDeposit;
+    type MaxSubAccounts = MaxSubAccounts;
+    type IdentityInformation = IdentityInfo;
+    type MaxRegistrars = MaxRegistrars;
+    type Slashed = Treasury;
+    type ForceOrigin = EnsureMusicIndustryAdmin;
+    type RegistrarOrigin = EnsureMusicIndustryAdmin;
+    type OffchainSignature = Signature;
+    type SigningPublicKey = <Signature as Verify>::Signer;
+    type UsernameAuthorityOrigin = EnsureRoot<AccountId>;
+    type PendingUsernameExpiration = PendingUsernameExpiration;
+    type UsernameGracePeriod = UsernameGracePeriod;
+    type MaxSuffixLength = ConstU32<7>;
+    type MaxUsernameLength = ConstU32<32>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+    type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
+}
@@ -0,0 +1,45 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{parameter_types, traits::ConstU32};
+use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
+use pallet_session::PeriodicSessions;
+use sp_runtime::transaction_validity::TransactionPriority;
+
+use super::aura::MaxAuthorities;
+
+parameter_types! {
+    // Heartbeats must not be crowded out of the pool: missing one is an offence.
+    pub const ImOnlineUnsignedPriority: TransactionPriority = TransactionPriority::MAX;
+}
+
+// Validators that neither authored a block nor sent a heartbeat during a session are reported
+// to `Offences` as unresponsive when it ends.
+impl pallet_im_online::Config for Runtime {
+    type AuthorityId = ImOnlineId;
+    type MaxKeys = MaxAuthorities;
+    // Heartbeats no longer carry the network state.
+    type MaxPeerInHeartbeats = ConstU32<0>;
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorSet = Historical;
+    type NextSessionRotation = PeriodicSessions<SessionPeriod, SessionOffset>;
+    type ReportUnresponsiveness = Offences;
+    type UnsignedPriority = ImOnlineUnsignedPriority;
+    type WeightInfo = pallet_im_online::weights::SubstrateWeight<Runtime>;
+}
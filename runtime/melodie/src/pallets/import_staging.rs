@@ -0,0 +1,114 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    MAX_EXTRINSIC_DEPTH,
+    dispatch::GetDispatchInfo,
+    pallet_prelude::{DispatchError, DispatchResult, Weight},
+    parameter_types,
+    traits::{Contains, Get},
+};
+use parity_scale_codec::DecodeLimit;
+use shared_runtime::currency::deposit;
+use sp_runtime::{Perbill, traits::Dispatchable};
+
+use super::import_windows::CatalogBatchCalls;
+
+parameter_types! {
+    pub const MaxStagedEntries: u32 = 128;
+    pub const MaxStagedEntryLen: u32 = 4 * 1024;
+    pub const MaxReportedImportFailures: u32 = 32;
+    pub const ImportStagingPeriod: BlockNumber = 7 * DAYS;
+    pub const StagedBatchDepositBase: Balance = deposit(1, 0);
+    pub const StagedEntryDepositPerByte: Balance = deposit(0, 1);
+    // A whole batch of the heaviest entries stays within half a block, and a validation step
+    // well within the deferred work budget.
+    pub ImportEntryWeight: Weight =
+        Perbill::from_rational(1u32, 256) * RuntimeBlockWeights::get().max_block;
+}
+
+/// Approved `ImportWindows` importers may stage catalog imports.
+pub struct ApprovedImporters;
+
+impl Contains<AccountId> for ApprovedImporters {
+    fn contains(who: &AccountId) -> bool {
+        pallet_import_windows::Importers::<Runtime>::contains_key(who)
+    }
+}
+
+/// Staged entries are encoded catalog registry calls, dispatched as signed by the importer.
+pub struct CatalogCalls;
+
+impl pallet_import_staging::CatalogImport<AccountId> for CatalogCalls {
+    fn import(importer: &AccountId, entry: &[u8]) -> DispatchResult {
+        let call = RuntimeCall::decode_all_with_depth_limit(MAX_EXTRINSIC_DEPTH, &mut &entry[..])
+            .map_err(|_| DispatchError::Other("undecodable catalog entry"))?;
+        if !CatalogBatchCalls::is_catalog_call(&call) {
+            return Err(DispatchError::Other("not a catalog registry call"));
+        }
+        if call
+            .get_dispatch_info()
+            .call_weight
+            .any_gt(ImportEntryWeight::get())
+        {
+            return Err(DispatchError::Exhausted);
+        }
+        call.dispatch(RuntimeOrigin::signed(importer.clone()))
+            .map(|_| ())
+            .map_err(|e| e.error)
+    }
+
+    fn entry_weight() -> Weight {
+        ImportEntryWeight::get()
+    }
+}
+
+impl pallet_import_staging::Config for Runtime {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Importers = ApprovedImporters;
+    type Catalog = CatalogCalls;
+    type Validation = DeferredWork;
+    type MaxBatchSize = MaxStagedEntries;
+    type MaxEntryLen = MaxStagedEntryLen;
+    type MaxReportedFailures = MaxReportedImportFailures;
+    type StagingPeriod = ImportStagingPeriod;
+    type DepositBase = StagedBatchDepositBase;
+    type DepositPerByte = StagedEntryDepositPerByte;
+    type WeightInfo = weights::import_staging::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ApprovedImporters;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_import_staging::BenchmarkHelper<AccountId> for ApprovedImporters {
+    fn approve_importer(who: &AccountId) {
+        pallet_import_windows::Importers::<Runtime>::insert(who, ());
+    }
+
+    fn entry(i: u32) -> alloc::vec::Vec<u8> {
+        use pallet_midds::BenchmarkHelper as _;
+        use parity_scale_codec::Encode;
+
+        RuntimeCall::MusicalWorks(pallet_midds::Call::deposit {
+            item: MusicalWorksBenchmarkHelper::bench_instance(i),
+        })
+        .encode()
+    }
+}
@@ -0,0 +1,66 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{parameter_types, traits::Contains};
+use sp_runtime::transaction_validity::TransactionPriority;
+
+parameter_types! {
+    pub const MaxImporters: u32 = 64;
+    pub const MaxScheduledImportWindows: u32 = 16;
+    // Keeps a boosted normal transaction below what the operational class and tipped
+    // transactions can reach, so an import window never starves them.
+    pub const MaxImportPriorityBoost: TransactionPriority = TransactionPriority::MAX / 4;
+}
+
+/// `Utility` batches made only of catalog registry calls.
+pub struct CatalogBatchCalls;
+
+impl CatalogBatchCalls {
+    pub(crate) fn is_catalog_call(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::MusicalWorks(..)
+                | RuntimeCall::Recordings(..)
+                | RuntimeCall::Releases(..)
+                | RuntimeCall::SoundRecordings(..)
+        )
+    }
+}
+
+impl Contains<RuntimeCall> for CatalogBatchCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        match call {
+            RuntimeCall::Utility(
+                pallet_utility::Call::batch { calls }
+                | pallet_utility::Call::batch_all { calls }
+                | pallet_utility::Call::force_batch { calls },
+            ) => !calls.is_empty() && calls.iter().all(Self::is_catalog_call),
+            _ => false,
+        }
+    }
+}
+
+impl pallet_import_windows::Config for Runtime {
+    type AdminOrigin = EnsureMusicIndustryAdmin;
+    type ImportCalls = CatalogBatchCalls;
+    type MaxImporters = MaxImporters;
+    type MaxScheduledWindows = MaxScheduledImportWindows;
+    type MaxPriorityBoost = MaxImportPriorityBoost;
+    type WeightInfo = weights::import_windows::AllfeatWeight<Runtime>;
+}
@@ -0,0 +1,51 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::parameter_types;
+use shared_runtime::currency::deposit;
+
+parameter_types! {
+    pub const MaxLicenseTerritories: u32 = 64;
+    pub const LicenseOfferDeposit: Balance = deposit(1, 250);
+    // Long enough for a licensee to notice a bogus offer on a catalog they don't trust.
+    pub const LicenseEscrowPeriod: BlockNumber = 7 * DAYS;
+    pub const MaxSublicenses: u32 = 64;
+}
+
+impl pallet_licensing::Config for Runtime {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetId = RoyaltyAsset;
+    type Assets = RoyaltyAssetOwnership;
+    type OnPayout = pallet_royalties::RecordEarning<Runtime, LicenseEarnings>;
+    type MaxTerritories = MaxLicenseTerritories;
+    type OfferDeposit = LicenseOfferDeposit;
+    type EscrowPeriod = LicenseEscrowPeriod;
+    type MaxSublicenses = MaxSublicenses;
+    type WeightInfo = weights::licensing::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RoyaltyAssetOwnership;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_licensing::BenchmarkHelper<RoyaltyAsset, AccountId> for RoyaltyAssetOwnership {
+    fn create_asset(i: u32, owner: &AccountId) -> RoyaltyAsset {
+        <Self as pallet_royalties::BenchmarkHelper<_, _>>::create_asset(i, owner)
+    }
+}
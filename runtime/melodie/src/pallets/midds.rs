@@ -17,96 +17,16 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::*;
-use frame_support::{PalletId, parameter_types};
-use frame_system::{EnsureRoot, EnsureSigned};
-use shared_runtime::currency::{MICROAFT, MILLIAFT};
-use sp_runtime::{FixedU128, MultiSigner, traits::AccountIdConversion};
 
-// MIDDS economic model — see `../midds-sdk/docs/economics.md`. Dynamic
-// pricing scaffolding (multipliers, window, finalization queue) follows that
-// doc; the bond calibration below uses a hybrid payload-aware model that
-// supersedes §6's flat 500/10 figures (doc refresh pending).
-parameter_types! {
-    // Bond formula (unmultiplied) — hybrid payload-aware: `DepositBase` pinned
-    // to the ExistentialDeposit (0.1 AFT) for minimal anti-sybil cost, weight
-    // shifted onto `DepositPerByte` so saturated payloads pay materially more
-    // than minimal ones. At $0.02/AFT a typical MusicalWork (~137 B) costs
-    // ~$0.003, a maxed-out Release (~9 KB) ~$0.05 — a 17× ratio creating the
-    // anti-stuffing incentive absent from the prior flat 0.5 AFT calibration.
-    // Dynamic multipliers stack on top via `M_fast × M_slow`. These two are
-    // consumed by the genesis builder (cf. `genesis::genesis`) to seed the
-    // pallet's runtime-mutable `DepositBase` / `DepositPerByte` storage —
-    // governance can recalibrate via `force_set_deposit_*` post-launch
-    // without a runtime upgrade (`../midds-sdk/docs/economics.md` §13.4).
-    pub const MiddsDepositBase: Balance = 100 * MILLIAFT;
-    pub const MiddsDepositPerByte: Balance = 250 * MICROAFT;
-
-    // Refundable commitment window aligned with the IFPI Friday Global
-    // Release Day. Within this window the depositor can `remove_own` (base
-    // refunded, premium → Treasury) or `update`. After: bond → Treasury via
-    // `finalize` (eager hook + permissionless catch-up).
-    pub const MiddsCommitmentWindow: BlockNumber = 7 * DAYS;
-    pub const MiddsMaxFinalizationsPerBlock: u32 = 100;
-    // Sudo-only cleanup cap — bounds `force_remove_many` weight. Set ≥ the
-    // benchmark sweep range (`Linear<1, 64>`) so worst-case weights stay
-    // measurable, with a margin matching `MaxFinalizationsPerBlock`.
-    pub const MiddsMaxRemovalsPerCall: u32 = 100;
-    pub const MiddsBlocksPerDay: BlockNumber = DAYS;
-
-    // M_fast — anti-DoS, per-block reactivity. Target 100 deposits/block
-    // (~17/s at 6 s/block) so a burst stuffing one block sees the multiplier
-    // climb 12.5 % before the next block prices it.
-    pub const MiddsFastTargetPerBlock: u32 = 100;
-    pub MiddsFastAdjustmentRate: FixedU128 = FixedU128::from_rational(125, 1_000);
-    pub MiddsFastMultiplierMin: FixedU128 = FixedU128::from_rational(1, 10);
-    pub MiddsFastMultiplierMax: FixedU128 = FixedU128::from_u32(20);
-
-    // M_slow — anti-flood, 7-day rolling window with day-resolution buckets.
-    // Target 200 000 deposits/week (~30 K/day) — calibrage prudent V1, see
-    // `docs/economics.md` decision #9.
-    pub const MiddsSlowTargetPerWindow: u32 = 200_000;
-    pub MiddsSlowAdjustmentRate: FixedU128 = FixedU128::from_rational(5, 100);
-    pub MiddsSlowMultiplierMin: FixedU128 = FixedU128::from_rational(1, 10);
-    pub MiddsSlowMultiplierMax: FixedU128 = FixedU128::from_u32(50);
-
-    // Foundation Treasury account that receives finalized bonds and
-    // multiplier premiums. Derived from a constant `PalletId` so the address
-    // is deterministic and stable across runtime upgrades — never burned, by
-    // tokenomics design (1 B AFT supply cap, recycled via Treasury
-    // governance — `docs/economics.md` §9).
-    pub const MiddsTreasuryPalletId: PalletId = PalletId(*b"af/midds");
-    pub MiddsTreasuryAccount: AccountId =
-        MiddsTreasuryPalletId::get().into_account_truncating();
-}
-
-impl pallet_midds::Config<pallet_midds::Instance1> for Runtime {
-    type Currency = Balances;
-    type RuntimeHoldReason = RuntimeHoldReason;
-    type Midds = midds_types::MusicalWork;
-    type ProviderOrigin = EnsureSigned<AccountId>;
-    type ForceOrigin = EnsureRoot<AccountId>;
-    // On-behalf flow: owner signs an off-chain `MultiSignature` payload, the
-    // operator submits the runtime extrinsic. `MultiSigner::into_account()`
-    // recovers the on-chain `AccountId`, matching the runtime's `Signature
-    // = MultiSignature` convention from `primitives`.
-    type OffchainSignature = Signature;
-    type Signer = MultiSigner;
-    type TreasuryAccount = MiddsTreasuryAccount;
-    type CommitmentWindow = MiddsCommitmentWindow;
-    type MaxFinalizationsPerBlock = MiddsMaxFinalizationsPerBlock;
-    type MaxRemovalsPerCall = MiddsMaxRemovalsPerCall;
-    type BlocksPerDay = MiddsBlocksPerDay;
-    type FastTargetPerBlock = MiddsFastTargetPerBlock;
-    type FastAdjustmentRate = MiddsFastAdjustmentRate;
-    type FastMultiplierMin = MiddsFastMultiplierMin;
-    type FastMultiplierMax = MiddsFastMultiplierMax;
-    type SlowTargetPerWindow = MiddsSlowTargetPerWindow;
-    type SlowAdjustmentRate = MiddsSlowAdjustmentRate;
-    type SlowMultiplierMin = MiddsSlowMultiplierMin;
-    type SlowMultiplierMax = MiddsSlowMultiplierMax;
-    type WeightInfo = crate::weights::midds_musical_works::AllfeatWeight<Runtime>;
-    #[cfg(feature = "runtime-benchmarks")]
-    type BenchmarkHelper = MusicalWorksBenchmarkHelper;
+// The three MIDDS registries — musical works, recordings and releases — share
+// one economic model, see `shared_runtime::configs`. Only their benchmark
+// helpers, below, are specific to this runtime.
+shared_runtime::impl_allfeat_common_configs! {
+    midds {
+        musical_works: MusicalWorksBenchmarkHelper,
+        recordings: RecordingsBenchmarkHelper,
+        releases: ReleasesBenchmarkHelper,
+    }
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -187,48 +107,17 @@ fn bench_iswc() -> midds_traits::Iswc {
 /// the runtime build `no_std`-clean. `entropy` becomes a SecretUri
 /// derivation path so distinct entropy inputs yield distinct signers.
 #[cfg(feature = "runtime-benchmarks")]
-fn bench_create_signature(entropy: &[u8], msg: &[u8]) -> (Signature, AccountId) {
+pub(crate) fn bench_create_signature(entropy: &[u8], msg: &[u8]) -> (Signature, AccountId) {
     use sp_runtime::traits::IdentifyAccount as _;
     let path = core::str::from_utf8(entropy).unwrap_or("bench");
     let uri = alloc::format!("//{path}");
     let public = sp_io::crypto::sr25519_generate(0.into(), Some(uri.into_bytes()));
-    let account: AccountId = MultiSigner::Sr25519(public).into_account();
+    let account: AccountId = sp_runtime::MultiSigner::Sr25519(public).into_account();
     let sig = sp_io::crypto::sr25519_sign(0.into(), &public, msg)
         .expect("keystore available in benchmark context; qed");
     (Signature::Sr25519(sig), account)
 }
 
-// MIDDS Instance2 — `Recording` (ISRC-keyed). Same economic model as
-// Instance1: V1 deliberately shares one bond/window/multiplier calibration
-// across MIDDS kinds (see `../midds-sdk/docs/economics.md`), so the
-// `parameter_types!` above are reused verbatim. Only the stored payload and
-// the benchmark helper differ.
-impl pallet_midds::Config<pallet_midds::Instance2> for Runtime {
-    type Currency = Balances;
-    type RuntimeHoldReason = RuntimeHoldReason;
-    type Midds = midds_types::Recording;
-    type ProviderOrigin = EnsureSigned<AccountId>;
-    type ForceOrigin = EnsureRoot<AccountId>;
-    type OffchainSignature = Signature;
-    type Signer = MultiSigner;
-    type TreasuryAccount = MiddsTreasuryAccount;
-    type CommitmentWindow = MiddsCommitmentWindow;
-    type MaxFinalizationsPerBlock = MiddsMaxFinalizationsPerBlock;
-    type MaxRemovalsPerCall = MiddsMaxRemovalsPerCall;
-    type BlocksPerDay = MiddsBlocksPerDay;
-    type FastTargetPerBlock = MiddsFastTargetPerBlock;
-    type FastAdjustmentRate = MiddsFastAdjustmentRate;
-    type FastMultiplierMin = MiddsFastMultiplierMin;
-    type FastMultiplierMax = MiddsFastMultiplierMax;
-    type SlowTargetPerWindow = MiddsSlowTargetPerWindow;
-    type SlowAdjustmentRate = MiddsSlowAdjustmentRate;
-    type SlowMultiplierMin = MiddsSlowMultiplierMin;
-    type SlowMultiplierMax = MiddsSlowMultiplierMax;
-    type WeightInfo = crate::weights::midds_recordings::AllfeatWeight<Runtime>;
-    #[cfg(feature = "runtime-benchmarks")]
-    type BenchmarkHelper = RecordingsBenchmarkHelper;
-}
-
 #[cfg(feature = "runtime-benchmarks")]
 pub struct RecordingsBenchmarkHelper;
 
@@ -290,37 +179,6 @@ fn bench_isrc() -> midds_traits::Isrc {
     BoundedVec::try_from(b"USAAA2500001".to_vec()).expect("12-byte literal fits ISRC bound")
 }
 
-// MIDDS Instance3 — `Release` (UPC/EAN-keyed). Completes the V1 MIDDS type
-// surface. Same economic model as Instance1/2: V1 deliberately shares one
-// bond/window/multiplier calibration across MIDDS kinds (see
-// `../midds-sdk/docs/economics.md`), so the `parameter_types!` above are
-// reused verbatim. Only the stored payload and the benchmark helper differ.
-impl pallet_midds::Config<pallet_midds::Instance3> for Runtime {
-    type Currency = Balances;
-    type RuntimeHoldReason = RuntimeHoldReason;
-    type Midds = midds_types::Release;
-    type ProviderOrigin = EnsureSigned<AccountId>;
-    type ForceOrigin = EnsureRoot<AccountId>;
-    type OffchainSignature = Signature;
-    type Signer = MultiSigner;
-    type TreasuryAccount = MiddsTreasuryAccount;
-    type CommitmentWindow = MiddsCommitmentWindow;
-    type MaxFinalizationsPerBlock = MiddsMaxFinalizationsPerBlock;
-    type MaxRemovalsPerCall = MiddsMaxRemovalsPerCall;
-    type BlocksPerDay = MiddsBlocksPerDay;
-    type FastTargetPerBlock = MiddsFastTargetPerBlock;
-    type FastAdjustmentRate = MiddsFastAdjustmentRate;
-    type FastMultiplierMin = MiddsFastMultiplierMin;
-    type FastMultiplierMax = MiddsFastMultiplierMax;
-    type SlowTargetPerWindow = MiddsSlowTargetPerWindow;
-    type SlowAdjustmentRate = MiddsSlowAdjustmentRate;
-    type SlowMultiplierMin = MiddsSlowMultiplierMin;
-    type SlowMultiplierMax = MiddsSlowMultiplierMax;
-    type WeightInfo = crate::weights::midds_releases::AllfeatWeight<Runtime>;
-    #[cfg(feature = "runtime-benchmarks")]
-    type BenchmarkHelper = ReleasesBenchmarkHelper;
-}
-
 #[cfg(feature = "runtime-benchmarks")]
 pub struct ReleasesBenchmarkHelper;
 
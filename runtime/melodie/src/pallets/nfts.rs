@@ -0,0 +1,78 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{parameter_types, traits::AsEnsureOriginWithArg};
+use frame_system::EnsureRoot;
+use pallet_nfts::PalletFeatures;
+use shared_runtime::currency::deposit;
+use sp_runtime::traits::Verify;
+
+/// Origin allowed to create music drop collections.
+///
+/// Only verified artists (see [`EnsureVerifiedArtist`]) can open a drop; the collection is
+/// owned by the signing artist.
+pub type MusicDropOrigin = EnsureVerifiedArtist;
+
+parameter_types! {
+    pub const MusicDropCollectionDeposit: Balance = deposit(1, 130);
+    // Items are the fan collectibles of a drop, kept cheap to mint.
+    pub const MusicDropItemDeposit: Balance = deposit(1, 164) / 40;
+    pub const MusicDropMetadataDepositBase: Balance = deposit(1, 129);
+    pub const MusicDropAttributeDepositBase: Balance = deposit(1, 0);
+    pub const MusicDropDepositPerByte: Balance = deposit(0, 1);
+    pub const MusicDropStringLimit: u32 = 256;
+    pub const MusicDropKeyLimit: u32 = 64;
+    pub const MusicDropValueLimit: u32 = 256;
+    pub const MusicDropApprovalsLimit: u32 = 20;
+    pub const MusicDropItemAttributesApprovalsLimit: u32 = 30;
+    pub const MusicDropMaxTips: u32 = 10;
+    pub const MusicDropMaxDeadlineDuration: BlockNumber = 365 * DAYS;
+    pub const MusicDropMaxAttributesPerCall: u32 = 10;
+    pub MusicDropFeatures: PalletFeatures = PalletFeatures::all_enabled();
+}
+
+impl pallet_nfts::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type CreateOrigin = AsEnsureOriginWithArg<MusicDropOrigin>;
+    type Locker = ();
+    type CollectionDeposit = MusicDropCollectionDeposit;
+    type ItemDeposit = MusicDropItemDeposit;
+    type MetadataDepositBase = MusicDropMetadataDepositBase;
+    type AttributeDepositBase = MusicDropAttributeDepositBase;
+    type DepositPerByte = MusicDropDepositPerByte;
+    type StringLimit = MusicDropStringLimit;
+    type KeyLimit = MusicDropKeyLimit;
+    type ValueLimit = MusicDropValueLimit;
+    type ApprovalsLimit = MusicDropApprovalsLimit;
+    type ItemAttributesApprovalsLimit = MusicDropItemAttributesApprovalsLimit;
+    type MaxTips = MusicDropMaxTips;
+    type MaxDeadlineDuration = MusicDropMaxDeadlineDuration;
+    type MaxAttributesPerCall = MusicDropMaxAttributesPerCall;
+    type Features = MusicDropFeatures;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as Verify>::Signer;
+    type BlockNumberProvider = frame_system::Pallet<Runtime>;
+    type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
@@ -27,8 +27,9 @@ use shared_runtime::currency::deposit;
 parameter_types! {
     // One storage item; key size 32, value size 8; .
     pub const ProxyDepositBase: Balance = deposit(1, 8);
-    // Additional storage item size of 33 bytes.
-    pub const ProxyDepositFactor: Balance = deposit(0, 33);
+    // Additional storage item size of 37 bytes: a 32-byte account and a proxy type of up to
+    // 5 bytes.
+    pub const ProxyDepositFactor: Balance = deposit(0, 37);
     pub const AnnouncementDepositBase: Balance = deposit(1, 8);
     pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
     pub const MaxProxies: u16 = 32;
@@ -53,6 +54,22 @@ parameter_types! {
 pub enum ProxyType {
     Any,
     NonTransfer,
+    /// A manager acting for an artist on their catalog until block `until` included: MIDDS
+    /// registrations and updates, recording and release metadata, and licensing.
+    ///
+    /// Revoked with `remove_proxy`; an expired proxy keeps its deposit until then.
+    ArtistManager {
+        until: BlockNumber,
+    },
+    /// Everyday catalog work of a label: MIDDS, recording and release metadata, licensing,
+    /// release sales, catalog transfers and royalty splits. Distributions and their statements
+    /// stay with the proxied account.
+    CatalogManagement,
+    /// Collecting what a label is owed: license payments and escrowed advances. The funds go
+    /// to the proxied account.
+    RoyaltyClaims,
+    /// Taking part in governance: referenda, their preimages and voting.
+    Governance,
 }
 impl Default for ProxyType {
     fn default() -> Self {
@@ -64,6 +81,60 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
         match self {
             ProxyType::Any => true,
             ProxyType::NonTransfer => !matches!(c, RuntimeCall::Balances(..)),
+            ProxyType::ArtistManager { until } => {
+                System::block_number() <= *until
+                    && matches!(
+                        c,
+                        RuntimeCall::MusicalWorks(..)
+                            | RuntimeCall::Recordings(..)
+                            | RuntimeCall::Releases(..)
+                            | RuntimeCall::SoundRecordings(..)
+                            | RuntimeCall::CatalogReleases(..)
+                            | RuntimeCall::Licensing(
+                                pallet_licensing::Call::publish_offer { .. }
+                                    | pallet_licensing::Call::withdraw_offer { .. }
+                                    | pallet_licensing::Call::accept_offer { .. }
+                                    | pallet_licensing::Call::offer_sublicense { .. }
+                            )
+                            | RuntimeCall::Utility(..)
+                    )
+            }
+            ProxyType::CatalogManagement => matches!(
+                c,
+                RuntimeCall::MusicalWorks(..)
+                    | RuntimeCall::Recordings(..)
+                    | RuntimeCall::Releases(..)
+                    | RuntimeCall::SoundRecordings(..)
+                    | RuntimeCall::CatalogReleases(..)
+                    | RuntimeCall::Licensing(..)
+                    | RuntimeCall::Sales(
+                        pallet_sales::Call::list { .. }
+                            | pallet_sales::Call::close { .. }
+                            | pallet_sales::Call::refund { .. }
+                    )
+                    | RuntimeCall::CatalogTransfers(..)
+                    | RuntimeCall::Royalties(
+                        pallet_royalties::Call::propose_split { .. }
+                            | pallet_royalties::Call::co_sign { .. }
+                            | pallet_royalties::Call::withdraw_proposal { .. }
+                            | pallet_royalties::Call::lock_split { .. }
+                            | pallet_royalties::Call::set_allowed_payers { .. }
+                    )
+                    | RuntimeCall::Utility(..)
+            ),
+            ProxyType::RoyaltyClaims => matches!(
+                c,
+                RuntimeCall::Licensing(pallet_licensing::Call::claim_payment { .. })
+                    | RuntimeCall::Escrow(pallet_escrow::Call::claim { .. })
+                    | RuntimeCall::Utility(..)
+            ),
+            ProxyType::Governance => matches!(
+                c,
+                RuntimeCall::Referenda(..)
+                    | RuntimeCall::ConvictionVoting(..)
+                    | RuntimeCall::Preimage(..)
+                    | RuntimeCall::Utility(..)
+            ),
         }
     }
     fn is_superset(&self, o: &Self) -> bool {
@@ -72,6 +143,14 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             (ProxyType::Any, _) => true,
             (_, ProxyType::Any) => false,
             (ProxyType::NonTransfer, _) => true,
+            (ProxyType::ArtistManager { until }, ProxyType::ArtistManager { until: other }) => {
+                until >= other
+            }
+            (ProxyType::ArtistManager { .. }, _) => false,
+            (ProxyType::CatalogManagement, ProxyType::ArtistManager { .. }) => true,
+            (ProxyType::CatalogManagement, _) => false,
+            (ProxyType::RoyaltyClaims, _) => false,
+            (ProxyType::Governance, _) => false,
         }
     }
 }
@@ -0,0 +1,91 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{parameter_types, traits::Contains};
+use midds_traits::MiddsId;
+use shared_runtime::currency::deposit;
+
+parameter_types! {
+    // Two storage items (the anchor and its content-hash index entry); ~200 bytes together
+    // with `MaxWorksPerRecording` work ids.
+    pub const RecordingAnchorDeposit: Balance = deposit(2, 200);
+    pub const MaxWorksPerRecording: u32 = 16;
+    // A hit single collects a few levels in a dozen territories.
+    pub const MaxCertificationsPerRecording: u32 = 32;
+    // Masters are often finished long before their release date.
+    pub const MaxRevealDelay: BlockNumber = 365 * DAYS;
+}
+
+/// Certification bodies approved in `Certification`.
+pub struct CertificationBodies;
+
+impl Contains<AccountId> for CertificationBodies {
+    fn contains(who: &AccountId) -> bool {
+        pallet_certification::Certifiers::<Runtime>::get(who)
+            == Some(pallet_certification::OrganizationKind::CertificationBody)
+    }
+}
+
+/// Musical works registered in the MIDDS `MusicalWorks` registry (`pallet_midds<Instance1>`).
+pub struct MusicalWorksRegistry;
+
+impl Contains<MiddsId> for MusicalWorksRegistry {
+    fn contains(id: &MiddsId) -> bool {
+        pallet_midds::Items::<Runtime, pallet_midds::Instance1>::contains_key(id)
+    }
+}
+
+impl pallet_recordings::Config for Runtime {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type WorkId = MiddsId;
+    type Works = MusicalWorksRegistry;
+    type MaxWorksPerRecording = MaxWorksPerRecording;
+    type AnchorDeposit = RecordingAnchorDeposit;
+    type Catalog = CatalogRoot;
+    type MaxRevealDelay = MaxRevealDelay;
+    type Certifiers = CertificationBodies;
+    type MaxCertificationsPerRecording = MaxCertificationsPerRecording;
+    type WeightInfo = weights::recordings::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MusicalWorksRegistry;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_recordings::BenchmarkHelper<AccountId, MiddsId> for MusicalWorksRegistry {
+    fn register_work(i: u32) -> MiddsId {
+        use pallet_midds::BenchmarkHelper as _;
+
+        // Written straight into storage: linking only checks that the work exists, so going
+        // through `deposit` (bond, multipliers, finalization queue) would only add noise.
+        let id = MiddsId::from(i);
+        pallet_midds::Items::<Runtime, pallet_midds::Instance1>::insert(
+            id,
+            MusicalWorksBenchmarkHelper::bench_instance(i),
+        );
+        id
+    }
+
+    fn accredit_certifier(who: &AccountId) {
+        pallet_certification::Certifiers::<Runtime>::insert(
+            who,
+            pallet_certification::OrganizationKind::CertificationBody,
+        );
+    }
+}
@@ -0,0 +1,69 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{parameter_types, traits::Contains};
+use shared_runtime::currency::deposit;
+
+parameter_types! {
+    // One storage item; value size is 2 + 4 + 16 = 22 bytes, friends aside.
+    pub const ConfigDepositBase: Balance = deposit(1, 22);
+    // Additional storage item size of 32 bytes.
+    pub const FriendDepositFactor: Balance = deposit(0, 32);
+    pub const MaxFriends: u32 = 9;
+    // One storage item of up to 4 + 16 + 9 * 32 bytes. Its owner gets it if they close the
+    // recovery, so nobody starts recovering an artist's account on a whim.
+    pub const RecoveryDeposit: Balance = deposit(1, 308);
+    /// Fewest friends that must vouch for a recovery.
+    pub const MinRecoveryThreshold: u16 = 2;
+    /// Shortest delay between the start of a recovery and its claim, left to the account's
+    /// owner to close it if they still hold their keys.
+    pub const MinRecoveryDelay: BlockNumber = 3 * DAYS;
+}
+
+/// Rejects recovery configurations a single friend could take over, or leaving the owner less
+/// than [`MinRecoveryDelay`] to react: an account holds an artist's catalog and receives its
+/// royalties.
+pub struct RecoveryConfigs;
+impl Contains<RuntimeCall> for RecoveryConfigs {
+    fn contains(call: &RuntimeCall) -> bool {
+        match call {
+            RuntimeCall::Recovery(pallet_recovery::Call::create_recovery {
+                threshold,
+                delay_period,
+                ..
+            }) => {
+                *threshold >= MinRecoveryThreshold::get()
+                    && *delay_period >= MinRecoveryDelay::get()
+            }
+            _ => true,
+        }
+    }
+}
+
+impl pallet_recovery::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type BlockNumberProvider = frame_system::Pallet<Runtime>;
+    type Currency = Balances;
+    type ConfigDepositBase = ConfigDepositBase;
+    type FriendDepositFactor = FriendDepositFactor;
+    type MaxFriends = MaxFriends;
+    type RecoveryDeposit = RecoveryDeposit;
+    type WeightInfo = pallet_recovery::weights::SubstrateWeight<Runtime>;
+}
@@ -0,0 +1,208 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use alloc::borrow::Cow;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, EitherOf, OriginTrait},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_referenda::{Curve, Track};
+use shared_runtime::currency::AFT;
+use sp_runtime::{FixedI64, str_array as s};
+
+parameter_types! {
+    pub const ReferendumSubmissionDeposit: Balance = 10 * AFT;
+    pub const UndecidingTimeout: BlockNumber = 14 * DAYS;
+}
+
+const fn percent(x: i32) -> FixedI64 {
+    FixedI64::from_rational(x as u128, 100)
+}
+
+// Curves are laid over the 7-day decision period of every track but `dispute_arbiter`.
+const APP_ROOT: Curve = Curve::make_reciprocal(1, 7, percent(80), percent(50), percent(100));
+const SUP_ROOT: Curve = Curve::make_linear(7, 7, percent(0), percent(50));
+const APP_ADMIN: Curve = Curve::make_linear(7, 7, percent(50), percent(100));
+const SUP_ADMIN: Curve = Curve::make_reciprocal(1, 7, percent(10), percent(0), percent(50));
+const APP_ARBITER: Curve = Curve::make_linear(5, 5, percent(50), percent(100));
+const SUP_ARBITER: Curve = Curve::make_reciprocal(1, 5, percent(5), percent(0), percent(50));
+const APP_CANCEL: Curve = Curve::make_linear(7, 7, percent(50), percent(100));
+const SUP_CANCEL: Curve = Curve::make_reciprocal(1, 7, percent(1), percent(0), percent(50));
+const APP_WHITELISTED: Curve = Curve::make_reciprocal(1, 7, percent(96), percent(50), percent(100));
+const SUP_WHITELISTED: Curve = Curve::make_reciprocal(1, 7, percent(1), percent(0), percent(10));
+const APP_SPEND: Curve = Curve::make_linear(7, 7, percent(50), percent(100));
+const SUP_SPEND: Curve = Curve::make_reciprocal(2, 7, percent(5), percent(1), percent(50));
+
+/// Governance tracks, sorted by id.
+///
+/// - `root`: runtime upgrades and anything else needing Root, one referendum at a time.
+/// - `whitelisted_caller`: calls the technical committee whitelisted, dispatched as Root once
+///   approved. The committee's vouching replaces the long confirmation of the `root` track.
+/// - `music_industry_admin`: certifiers, genres, badge issuers and catalog import windows.
+/// - `dispute_arbiter`: escrow arbitration and reporter slashing, decided faster so funds
+///   don't stay frozen for long.
+/// - `referendum_canceller` / `referendum_killer`: stopping a referendum on another track.
+/// - `treasury_spender`: grants and bounties, up to `MaxTreasurySpend` per referendum.
+const TRACKS: [Track<u16, Balance, BlockNumber>; 7] = [
+    Track {
+        id: 0,
+        info: pallet_referenda::TrackInfo {
+            name: s("root"),
+            max_deciding: 1,
+            decision_deposit: 1_000 * AFT,
+            prepare_period: 2 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: DAYS,
+            min_enactment_period: DAYS,
+            min_approval: APP_ROOT,
+            min_support: SUP_ROOT,
+        },
+    },
+    Track {
+        id: 1,
+        info: pallet_referenda::TrackInfo {
+            name: s("whitelisted_caller"),
+            max_deciding: 10,
+            decision_deposit: 1_000 * AFT,
+            prepare_period: 30 * MINUTES,
+            decision_period: 7 * DAYS,
+            confirm_period: 10 * MINUTES,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_WHITELISTED,
+            min_support: SUP_WHITELISTED,
+        },
+    },
+    Track {
+        id: 10,
+        info: pallet_referenda::TrackInfo {
+            name: s("music_industry_admin"),
+            max_deciding: 10,
+            decision_deposit: 100 * AFT,
+            prepare_period: HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_ADMIN,
+            min_support: SUP_ADMIN,
+        },
+    },
+    Track {
+        id: 11,
+        info: pallet_referenda::TrackInfo {
+            name: s("dispute_arbiter"),
+            max_deciding: 20,
+            decision_deposit: 50 * AFT,
+            prepare_period: HOURS,
+            decision_period: 5 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_ARBITER,
+            min_support: SUP_ARBITER,
+        },
+    },
+    Track {
+        id: 20,
+        info: pallet_referenda::TrackInfo {
+            name: s("referendum_canceller"),
+            max_deciding: 10,
+            decision_deposit: 100 * AFT,
+            prepare_period: HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_CANCEL,
+            min_support: SUP_CANCEL,
+        },
+    },
+    Track {
+        id: 21,
+        info: pallet_referenda::TrackInfo {
+            name: s("referendum_killer"),
+            max_deciding: 10,
+            decision_deposit: 500 * AFT,
+            prepare_period: HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_CANCEL,
+            min_support: SUP_CANCEL,
+        },
+    },
+    Track {
+        id: 30,
+        info: pallet_referenda::TrackInfo {
+            name: s("treasury_spender"),
+            max_deciding: 10,
+            decision_deposit: 200 * AFT,
+            prepare_period: 2 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: DAYS,
+            min_enactment_period: DAYS,
+            min_approval: APP_SPEND,
+            min_support: SUP_SPEND,
+        },
+    },
+];
+
+pub struct TracksInfo;
+impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
+    type Id = u16;
+    type RuntimeOrigin = <RuntimeOrigin as OriginTrait>::PalletsOrigin;
+
+    fn tracks() -> impl Iterator<Item = Cow<'static, Track<Self::Id, Balance, BlockNumber>>> {
+        TRACKS.iter().map(Cow::Borrowed)
+    }
+
+    fn track_for(origin: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+        use pallet_custom_origins::Origin;
+
+        match origin {
+            OriginCaller::system(frame_system::RawOrigin::Root) => Ok(0),
+            OriginCaller::Origins(Origin::WhitelistedCaller) => Ok(1),
+            OriginCaller::Origins(Origin::MusicIndustryAdmin) => Ok(10),
+            OriginCaller::Origins(Origin::DisputeArbiter) => Ok(11),
+            OriginCaller::Origins(Origin::ReferendumCanceller) => Ok(20),
+            OriginCaller::Origins(Origin::ReferendumKiller) => Ok(21),
+            OriginCaller::Origins(Origin::TreasurySpender) => Ok(30),
+            _ => Err(()),
+        }
+    }
+}
+
+impl pallet_referenda::Config for Runtime {
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Scheduler = Scheduler;
+    type Currency = Balances;
+    type SubmitOrigin = EnsureSigned<AccountId>;
+    type CancelOrigin = EitherOf<EnsureRoot<AccountId>, ReferendumCanceller>;
+    type KillOrigin = EitherOf<EnsureRoot<AccountId>, ReferendumKiller>;
+    type Slash = Treasury;
+    type Votes = pallet_conviction_voting::VotesOf<Runtime>;
+    type Tally = pallet_conviction_voting::TallyOf<Runtime>;
+    type SubmissionDeposit = ReferendumSubmissionDeposit;
+    type MaxQueued = ConstU32<100>;
+    type UndecidingTimeout = UndecidingTimeout;
+    type AlarmInterval = ConstU32<1>;
+    type Tracks = TracksInfo;
+    type Preimages = Preimage;
+    type BlockNumberProvider = System;
+    type WeightInfo = pallet_referenda::weights::SubstrateWeight<Runtime>;
+}
@@ -0,0 +1,72 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::*;
+use frame_support::{parameter_types, traits::Contains};
+use sp_runtime::MultiSigner;
+
+parameter_types! {
+    // Room for an ISWC, an IPI name number or a society's own work number.
+    pub const MaxRegistryReferenceLength: u32 = 32;
+    pub const MaxRegistryAttestations: u32 = 16;
+}
+
+/// Organizations currently approved in `Certification`.
+pub struct AccreditedCertifiers;
+
+impl Contains<AccountId> for AccreditedCertifiers {
+    fn contains(who: &AccountId) -> bool {
+        pallet_certification::Certifiers::<Runtime>::contains_key(who)
+    }
+}
+
+impl pallet_registry_attestations::Config for Runtime {
+    type Certifiers = AccreditedCertifiers;
+    type Entity = CertifiedRecord;
+    type Entities = CertifiableRecords;
+    type Signature = Signature;
+    type KeyIdentifier = MultiSigner;
+    type SigningKey = AccountId;
+    type MaxReferenceLength = MaxRegistryReferenceLength;
+    type MaxAttestations = MaxRegistryAttestations;
+    type WeightInfo = weights::registry_attestations::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = AccreditedCertifiers;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_registry_attestations::BenchmarkHelper<AccountId, CertifiedRecord, AccountId, Signature>
+    for AccreditedCertifiers
+{
+    fn accredit(who: &AccountId) {
+        pallet_certification::Certifiers::<Runtime>::insert(
+            who,
+            pallet_certification::OrganizationKind::Pro,
+        );
+    }
+
+    fn create_entity(i: u32) -> CertifiedRecord {
+        use pallet_certification::BenchmarkHelper as _;
+
+        CertifiableRecords::create_record(i)
+    }
+
+    fn sign(message: &[u8]) -> (AccountId, Signature) {
+        let (signature, key) = super::midds::bench_create_signature(b"registry", message);
+        (key, signature)
+    }
+}
@@ -0,0 +1,134 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    pallet_prelude::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen},
+    parameter_types,
+};
+use midds_traits::MiddsId;
+use pallet_recordings::Isrc;
+use pallet_royalties::EarningSource;
+use shared_runtime::currency::deposit;
+use sp_runtime::Perbill;
+
+parameter_types! {
+    pub const MaxRoyaltyCollaborators: u32 = 32;
+    // A weekly DSP report for a mid-size catalog; larger reports are split across calls.
+    pub const MaxUsageReportEntries: u32 = 256;
+    // No distribution fee on melodie; fees would go to the MIDDS treasury.
+    pub const RoyaltyDistributionFee: Perbill = Perbill::zero();
+    // One ledger entry paid to a full split table.
+    pub const RoyaltyLedgerDeposit: Balance = deposit(1, 2_600);
+    // A label's DSP aggregators and collecting societies.
+    pub const MaxRoyaltyPayers: u32 = 16;
+    // Leaves most of `on_idle` to the other queues even when a full split table is paid.
+    pub const RoyaltyDistributionWeightCeiling: Perbill = Perbill::from_percent(25);
+    // Earnings are charted per staking era.
+    pub EarningsEra: BlockNumber = SessionPeriod::get() * SessionsPerEra::get();
+    pub const LicenseEarnings: EarningSource = EarningSource::Licenses;
+    pub const SaleEarnings: EarningSource = EarningSource::Sales;
+    pub const SubscriptionEarnings: EarningSource = EarningSource::Subscriptions;
+}
+
+/// An asset royalty splits can be attached to.
+#[derive(
+    Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+)]
+pub enum RoyaltyAsset {
+    /// A work of the MIDDS `MusicalWorks` registry, owned by its depositor.
+    Work(MiddsId),
+    /// A master anchored in `SoundRecordings`, owned by the account that anchored it.
+    Recording(Isrc),
+    /// A music drop collection of `Nfts`, owned by the artist who created it. Its split
+    /// table holds the drop's royalty settings.
+    Drop(u32),
+}
+
+pub struct RoyaltyAssetOwnership;
+
+impl pallet_royalties::AssetOwnership<RoyaltyAsset, AccountId> for RoyaltyAssetOwnership {
+    fn owner_of(asset: &RoyaltyAsset) -> Option<AccountId> {
+        match asset {
+            RoyaltyAsset::Work(id) => {
+                pallet_midds::DepositInfo::<Runtime, pallet_midds::Instance1>::get(id)
+                    .map(|info| info.depositor)
+            }
+            RoyaltyAsset::Recording(isrc) => {
+                pallet_recordings::Recordings::<Runtime>::get(isrc).map(|r| r.owner)
+            }
+            RoyaltyAsset::Drop(collection) => {
+                pallet_nfts::Pallet::<Runtime>::collection_owner(*collection)
+            }
+        }
+    }
+}
+
+impl pallet_royalties::Config for Runtime {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetId = RoyaltyAsset;
+    type Assets = RoyaltyAssetOwnership;
+    type Usage = FinalizedStreamingUsage;
+    type MaxCollaborators = MaxRoyaltyCollaborators;
+    type MaxReportEntries = MaxUsageReportEntries;
+    type DistributionFee = RoyaltyDistributionFee;
+    type FeeDestination = MiddsTreasuryAccount;
+    type LedgerDeposit = RoyaltyLedgerDeposit;
+    type MaxAllowedPayers = MaxRoyaltyPayers;
+    type DistributionWeightCeiling = RoyaltyDistributionWeightCeiling;
+    type EarningsEra = EarningsEra;
+    type WeightInfo = weights::royalties::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RoyaltyAssetOwnership;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_royalties::BenchmarkHelper<RoyaltyAsset, AccountId> for RoyaltyAssetOwnership {
+    fn create_asset(i: u32, owner: &AccountId) -> RoyaltyAsset {
+        // `FRZ04` followed by `i` as a 7-digit designation, e.g. `FRZ040000042`.
+        let mut isrc = b"FRZ040000000".to_vec();
+        let mut n = i;
+        for digit in isrc[5..].iter_mut().rev() {
+            *digit = b'0' + (n % 10) as u8;
+            n /= 10;
+        }
+        let isrc = Isrc::truncate_from(isrc);
+
+        // Written straight into storage: splits only need an owner to resolve.
+        pallet_recordings::Recordings::<Runtime>::insert(
+            &isrc,
+            pallet_recordings::Recording {
+                owner: owner.clone(),
+                content_hash: sp_core::H256::from_low_u64_be(i.into()),
+                works: Default::default(),
+                deposit: 0,
+                anchored_at: 0,
+            },
+        );
+        RoyaltyAsset::Recording(isrc)
+    }
+}
@@ -16,42 +16,17 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::{parameter_types, traits::Contains};
-use frame_system::{EnsureRoot, EnsureRootWithSuccess};
+use frame_support::traits::EitherOf;
+use frame_system::{EnsureRootWithSuccess, EnsureWithSuccess};
 use sp_core::ConstU32;
 
 use crate::*;
 
-/// Calls that can bypass the safe-mode pallet.
-pub struct SafeModeWhitelistedCalls;
-impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
-    fn contains(call: &RuntimeCall) -> bool {
-        matches!(call, RuntimeCall::System(_) | RuntimeCall::SafeMode(_))
+shared_runtime::impl_allfeat_common_configs! {
+    safe_mode {
+        force_enter_origin: EitherOf<
+            EnsureRootWithSuccess<AccountId, ConstU32<9>>,
+            EnsureWithSuccess<EnsureTechnicalCommittee, AccountId, EnterDuration>,
+        >,
     }
 }
-
-parameter_types! {
-    pub const EnterDuration: BlockNumber = 4 * HOURS;
-    pub const EnterDepositAmount: Option<Balance> = None;
-    pub const ExtendDuration: BlockNumber = 2 * HOURS;
-    pub const ExtendDepositAmount: Option<Balance> = None;
-    pub const ReleaseDelay: u32 = 2 * DAYS;
-}
-
-impl pallet_safe_mode::Config for Runtime {
-    type RuntimeEvent = RuntimeEvent;
-    type Currency = Balances;
-    type RuntimeHoldReason = RuntimeHoldReason;
-    type WhitelistedCalls = SafeModeWhitelistedCalls;
-    type EnterDuration = EnterDuration;
-    type ExtendDuration = ExtendDuration;
-    type EnterDepositAmount = EnterDepositAmount;
-    type ExtendDepositAmount = ExtendDepositAmount;
-    type ForceEnterOrigin = EnsureRootWithSuccess<AccountId, ConstU32<9>>;
-    type ForceExtendOrigin = EnsureRootWithSuccess<AccountId, ConstU32<11>>;
-    type ForceExitOrigin = EnsureRoot<AccountId>;
-    type ForceDepositOrigin = EnsureRoot<AccountId>;
-    type Notify = ();
-    type ReleaseDelay = ReleaseDelay;
-    type WeightInfo = weights::safe_mode::AllfeatWeight<Runtime>;
-}
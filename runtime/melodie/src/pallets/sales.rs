@@ -0,0 +1,85 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use alloc::vec::Vec;
+use frame_support::parameter_types;
+use shared_runtime::currency::{AFT, deposit};
+
+parameter_types! {
+    pub const SaleListingDeposit: Balance = deposit(1, 100);
+    pub const MinSalePrice: Balance = AFT;
+    // The withdrawal period of EU consumer law, for fans to report a release that is not what
+    // its listing said.
+    pub const SaleRefundWindow: BlockNumber = 14 * DAYS;
+}
+
+/// Sale proceeds are paid along the `Royalties` split tables.
+pub struct RoyaltySplitRevenue;
+
+impl pallet_sales::RevenueSplits<RoyaltyAsset, AccountId, Balance> for RoyaltySplitRevenue {
+    fn apportion(asset: &RoyaltyAsset, amount: Balance) -> Option<Vec<(AccountId, Balance)>> {
+        pallet_royalties::Pallet::<Runtime>::apportion(asset, amount)
+    }
+}
+
+impl pallet_sales::Config for Runtime {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetId = RoyaltyAsset;
+    type Assets = RoyaltyAssetOwnership;
+    type Splits = RoyaltySplitRevenue;
+    // Access is the purchase record itself.
+    type Hooks = ();
+    type OnPayout = pallet_royalties::RecordEarning<Runtime, SaleEarnings>;
+    type ArbitrationOrigin = EnsureDisputeArbiter;
+    type ListingDeposit = SaleListingDeposit;
+    type MinPrice = MinSalePrice;
+    type RefundWindow = SaleRefundWindow;
+    type MaxSplitParts = MaxRoyaltyCollaborators;
+    type WeightInfo = weights::sales::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RoyaltySplitRevenue;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_sales::BenchmarkHelper<RoyaltyAsset, AccountId> for RoyaltySplitRevenue {
+    fn create_asset(owner: &AccountId, parts: u32) -> RoyaltyAsset {
+        let asset =
+            <RoyaltyAssetOwnership as pallet_royalties::BenchmarkHelper<_, _>>::create_asset(
+                0, owner,
+            );
+        let shares = (0..parts)
+            .map(|i| {
+                (
+                    frame_benchmarking::account("collaborator", i, 0),
+                    sp_runtime::Perbill::from_rational(1, parts),
+                )
+            })
+            .collect::<Vec<_>>();
+        pallet_royalties::Splits::<Runtime>::insert(
+            &asset,
+            pallet_royalties::Split {
+                shares: frame_support::BoundedVec::truncate_from(shares),
+                activated_at: 0,
+                locked: false,
+            },
+        );
+        asset
+    }
+}
@@ -29,6 +29,7 @@ impl_opaque_keys! {
     pub struct SessionKeys {
         pub grandpa: Grandpa,
         pub aura: Aura,
+        pub im_online: ImOnline,
     }
 }
 
@@ -45,7 +46,7 @@ impl pallet_session::Config for Runtime {
     type ValidatorIdOf = ConvertInto;
     type ShouldEndSession = PeriodicSessions<SessionPeriod, SessionOffset>;
     type NextSessionRotation = PeriodicSessions<SessionPeriod, SessionOffset>;
-    type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, Validators>;
+    type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, Staking>;
     type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
     type Keys = SessionKeys;
     type DisablingStrategy = pallet_session::disabling::UpToLimitWithReEnablingDisablingStrategy;
@@ -54,6 +55,6 @@ impl pallet_session::Config for Runtime {
 
 impl pallet_session::historical::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type FullIdentification = Self::ValidatorId;
-    type FullIdentificationOf = Self::ValidatorIdOf;
+    type FullIdentification = ();
+    type FullIdentificationOf = pallet_staking::UnitIdentificationOf<Self>;
 }
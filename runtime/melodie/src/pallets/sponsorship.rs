@@ -0,0 +1,71 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::*;
+use frame_support::{
+    pallet_prelude::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen},
+    parameter_types,
+    traits::{Contains, InstanceFilter},
+};
+use shared_runtime::{currency::deposit, fee_rebate::BatchAware};
+
+parameter_types! {
+    // One sponsorship: beneficiary key, sponsor, calls, allowance and deposit, ~120 bytes.
+    pub const SponsorshipDeposit: Balance = deposit(1, 120);
+}
+
+/// Calls a sponsor can pay the fees of.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+)]
+pub enum SponsoredCalls {
+    Any,
+    /// Catalog calls ([`CatalogCalls`]), batches of them included.
+    #[default]
+    Catalog,
+}
+
+impl InstanceFilter<RuntimeCall> for SponsoredCalls {
+    fn filter(&self, call: &RuntimeCall) -> bool {
+        match self {
+            SponsoredCalls::Any => true,
+            SponsoredCalls::Catalog => BatchAware::<Runtime, CatalogCalls>::contains(call),
+        }
+    }
+
+    fn is_superset(&self, other: &Self) -> bool {
+        self == &SponsoredCalls::Any || self == other
+    }
+}
+
+impl pallet_sponsorship::Config for Runtime {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type SponsoredCalls = SponsoredCalls;
+    type SponsorshipDeposit = SponsorshipDeposit;
+    type WeightInfo = weights::sponsorship::AllfeatWeight<Runtime>;
+}
@@ -0,0 +1,137 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_election_provider_support::{
+    SequentialPhragmen,
+    bounds::{ElectionBounds, ElectionBoundsBuilder},
+    onchain,
+};
+use frame_support::{
+    parameter_types,
+    traits::{ConstBool, ConstU32, Nothing, tokens::imbalance::ResolveTo},
+};
+use frame_system::EnsureRoot;
+use shared_runtime::elections::{MaxElectableTargets, MaxElectingVoters, OnChainAccuracy};
+use sp_runtime::curve::PiecewiseLinear;
+use sp_staking::{EraIndex, SessionIndex};
+
+use super::aura::MaxAuthorities;
+
+// Inflation minted each era, as a yearly rate of the total issuance. Stakers get the maximum
+// when half of the issuance is staked, the treasury the difference to it.
+pallet_staking_reward_curve::build! {
+    const REWARD_CURVE: PiecewiseLinear<'static> = curve!(
+        min_inflation: 0_025_000,
+        max_inflation: 0_080_000,
+        ideal_stake: 0_500_000,
+        falloff: 0_050_000,
+        max_piece_count: 40,
+        test_precision: 0_005_000,
+    );
+}
+
+parameter_types! {
+    pub const RewardCurve: &'static PiecewiseLinear<'static> = &REWARD_CURVE;
+    // 8 sessions of 3 hours: one era a day.
+    pub const SessionsPerEra: SessionIndex = 8;
+    pub const BondingDuration: EraIndex = 28;
+    // Slashes can be cancelled by Root for 27 eras, leaving one era to apply them before the
+    // offender unbonds.
+    pub const SlashDeferDuration: EraIndex = 27;
+    pub const HistoryDepth: u32 = 84;
+    pub const MaxExposurePageSize: u32 = 64;
+    pub const MaxUnlockingChunks: u32 = 32;
+    pub const MaxControllersInDeprecationBatch: u32 = 512;
+
+    // The election snapshot is taken from the top of `VoterList`, the nominators beyond it are
+    // left out of the era's election.
+    pub StakingElectionBounds: ElectionBounds = ElectionBoundsBuilder::default()
+        .voters_count(MaxElectingVoters::get().into())
+        .targets_count(MaxElectableTargets::get().into())
+        .build();
+
+    pub const VoterBagThresholds: &'static [u64] = &crate::voter_bags::THRESHOLDS;
+}
+
+pub struct OnChainSeqPhragmen;
+impl onchain::Config for OnChainSeqPhragmen {
+    type System = Runtime;
+    type Solver = SequentialPhragmen<AccountId, OnChainAccuracy>;
+    type DataProvider = Staking;
+    type WeightInfo = shared_runtime::weights::election_provider_support::AllfeatWeight<Runtime>;
+    type MaxBackersPerWinner = MaxElectingVoters;
+    type MaxWinnersPerPage = MaxAuthorities;
+    type Bounds = StakingElectionBounds;
+    type Sort = ConstBool<true>;
+}
+
+/// Elects within the block asking for it: at genesis, and when no solution was submitted to
+/// `ElectionProviderMultiPhase` in time.
+pub type OnChainExecution = onchain::OnChainExecution<OnChainSeqPhragmen>;
+
+pub struct StakingBenchmarkingConfig;
+impl pallet_staking::BenchmarkingConfig for StakingBenchmarkingConfig {
+    type MaxValidators = ConstU32<1000>;
+    type MaxNominators = ConstU32<1000>;
+}
+
+impl pallet_staking::Config for Runtime {
+    type OldCurrency = Balances;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type CurrencyBalance = Balance;
+    type UnixTime = Timestamp;
+    type CurrencyToVote = sp_staking::currency_to_vote::U128CurrencyToVote;
+    type ElectionProvider = ElectionProviderMultiPhase;
+    type GenesisElectionProvider = OnChainExecution;
+    type NominationsQuota = pallet_staking::FixedNominationsQuota<16>;
+    type HistoryDepth = HistoryDepth;
+    type RewardRemainder = ResolveTo<TreasuryAccount, Balances>;
+    type RuntimeEvent = RuntimeEvent;
+    type Slash = ResolveTo<TreasuryAccount, Balances>;
+    type Reward = ();
+    type SessionsPerEra = SessionsPerEra;
+    type BondingDuration = BondingDuration;
+    type SlashDeferDuration = SlashDeferDuration;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type SessionInterface = Self;
+    type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
+    type NextNewSession = Session;
+    type MaxExposurePageSize = MaxExposurePageSize;
+    type MaxValidatorSet = MaxAuthorities;
+    type VoterList = VoterList;
+    type TargetList = pallet_staking::UseValidatorsMap<Self>;
+    type MaxUnlockingChunks = MaxUnlockingChunks;
+    type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
+    // Delegation agents are told about their slashes, to apply them to their delegators.
+    type EventListeners = DelegatedStaking;
+    type Filter = Nothing;
+    type BenchmarkingConfig = StakingBenchmarkingConfig;
+    type WeightInfo = shared_runtime::weights::staking::AllfeatWeight<Runtime>;
+}
+
+impl pallet_bags_list::Config<pallet_bags_list::Instance1> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = shared_runtime::weights::bags_list::AllfeatWeight<Runtime>;
+    // Staking is the source of truth for voter scores, the bags are only rebagged on demand.
+    type ScoreProvider = Staking;
+    type BagThresholds = VoterBagThresholds;
+    type MaxAutoRebagPerBlock = ();
+    type Score = frame_election_provider_support::VoteWeight;
+}
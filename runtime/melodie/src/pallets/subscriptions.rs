@@ -0,0 +1,40 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::parameter_types;
+use shared_runtime::currency::deposit;
+
+parameter_types! {
+    // One storage item of ~130 bytes plus its agenda entry.
+    pub const SubscriptionDeposit: Balance = deposit(1, 140);
+    pub const MinSubscriptionPeriod: BlockNumber = DAYS;
+    pub const MaxSubscriptionChargesPerBlock: u32 = 100;
+    pub const MaxFailedSubscriptionCharges: u32 = 3;
+}
+
+impl pallet_subscriptions::Config for Runtime {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type OnPayout = pallet_royalties::RecordEarning<Runtime, SubscriptionEarnings>;
+    type SubscriptionDeposit = SubscriptionDeposit;
+    type MinPeriod = MinSubscriptionPeriod;
+    type MaxChargesPerBlock = MaxSubscriptionChargesPerBlock;
+    type MaxFailedCharges = MaxFailedSubscriptionCharges;
+    type WeightInfo = weights::subscriptions::AllfeatWeight<Runtime>;
+}
@@ -0,0 +1,79 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The technical committee: a small collective of trusted members with emergency powers, whose
+//! membership is decided by Root referenda.
+//!
+//! Two thirds of the committee may:
+//! - whitelist a call, fast-tracking its referendum on the `whitelisted_caller` track;
+//! - force safe mode on (see `safe_mode.rs`);
+//! - pause and unpause single calls (see `tx_pause.rs`).
+
+use crate::*;
+use frame_support::{parameter_types, traits::EitherOf};
+use frame_system::{EnsureNever, EnsureRoot};
+use sp_runtime::Perbill;
+
+pub type TechnicalCollective = pallet_collective::Instance1;
+
+/// Two thirds of the technical committee.
+pub type EnsureTechnicalCommittee =
+    pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>;
+
+/// Root, or two thirds of the technical committee.
+pub type EnsureRootOrTechnicalCommittee = EitherOf<EnsureRoot<AccountId>, EnsureTechnicalCommittee>;
+
+parameter_types! {
+    pub const TechnicalMotionDuration: BlockNumber = 3 * DAYS;
+    pub const TechnicalMaxProposals: u32 = 32;
+    pub const TechnicalMaxMembers: u32 = 16;
+    pub TechnicalMaxProposalWeight: Weight =
+        Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_collective::Config<TechnicalCollective> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = TechnicalMotionDuration;
+    type MaxProposals = TechnicalMaxProposals;
+    type MaxMembers = TechnicalMaxMembers;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+    // Members only change through `TechnicalMembership`.
+    type SetMembersOrigin = EnsureNever<()>;
+    type MaxProposalWeight = TechnicalMaxProposalWeight;
+    type DisapproveOrigin = EnsureRoot<AccountId>;
+    type KillOrigin = EnsureRoot<AccountId>;
+    type Consideration = ();
+}
+
+pub type TechnicalMembershipInstance = pallet_membership::Instance1;
+
+impl pallet_membership::Config<TechnicalMembershipInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AddOrigin = EnsureRoot<AccountId>;
+    type RemoveOrigin = EnsureRoot<AccountId>;
+    type SwapOrigin = EnsureRoot<AccountId>;
+    type ResetOrigin = EnsureRoot<AccountId>;
+    type PrimeOrigin = EnsureRoot<AccountId>;
+    type MembershipInitialized = TechnicalCommittee;
+    type MembershipChanged = TechnicalCommittee;
+    type MaxMembers = TechnicalMaxMembers;
+    type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
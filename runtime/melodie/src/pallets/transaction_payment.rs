@@ -18,39 +18,62 @@
 
 use crate::*;
 use frame_support::{
+    PalletId,
     dispatch::DispatchClass,
     parameter_types,
-    sp_runtime::Perbill,
-    traits::{
-        Imbalance, OnUnbalanced,
-        fungible::{Balanced, Credit},
-    },
+    sp_runtime::{Perbill, traits::AccountIdConversion},
+    traits::{Contains, OnUnbalanced, fungible::NativeOrWithId, fungibles},
     weights::{
         ConstantMultiplier, WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial,
     },
 };
+use pallet_asset_conversion_tx_payment::SwapAssetAdapter;
 use shared_runtime::{
     SlowAdjustingFeeUpdate,
     currency::{MICROAFT, MILLIAFT},
+    fee_asset::WhitelistedAssets,
+    fee_rebate::{BatchAware, RebateAdapter},
 };
 
-pub struct DealWithFees;
-impl OnUnbalanced<Credit<AccountId, Balances>> for DealWithFees {
-    fn on_unbalanceds(mut fees_then_tips: impl Iterator<Item = Credit<AccountId, Balances>>) {
-        if let Some(mut amount) = fees_then_tips.next() {
-            // for fees, 100% to author
+/// Fees and tips are split between the treasury and the block author, see
+/// [`FeeTreasuryShare`].
+pub type DealWithFees = shared_runtime::fee_split::DealWithFees<
+    Runtime,
+    Balances,
+    FeeBurnShare,
+    FeeTreasuryShare,
+    FeeAuthorShare,
+    TreasuryAccount,
+>;
+
+/// Fees paid in an asset, once swapped to AFT. Same split as [`DealWithFees`].
+pub struct DealWithAssetFees;
+impl OnUnbalanced<fungibles::Credit<AccountId, NativeAndAssets>> for DealWithAssetFees {
+    fn on_unbalanceds(
+        mut fees_then_tips: impl Iterator<Item = fungibles::Credit<AccountId, NativeAndAssets>>,
+    ) {
+        if let Some(mut fee) = fees_then_tips.next() {
             if let Some(tips) = fees_then_tips.next() {
-                // for tips, if any, 100% to author
-                tips.merge_into(&mut amount);
+                // Both credits are AFT, merging can't fail.
+                fee = match fee.merge(tips) {
+                    Ok(fee) => fee,
+                    Err((fee, _tips)) => fee,
+                };
             }
 
+            let (burn, treasury) = DealWithFees::split(fee.peek());
+            let (to_treasury, rest) = fee.split(treasury);
+            // The burned part is dropped.
+            let (_, amount) = rest.split(burn);
+            let _ = <NativeAndAssets as fungibles::Balanced<AccountId>>::resolve(
+                &TreasuryAccount::get(),
+                to_treasury,
+            );
+
             if let Some(author) = Authorship::author() {
-                match Balances::resolve(&author, amount) {
-                    Ok(_) => (),
-                    Err(_amount) => {
-                        // Resolution failed, the credit is dropped (burned).
-                    }
-                }
+                // A failed resolution drops (burns) the credit.
+                let _ =
+                    <NativeAndAssets as fungibles::Balanced<AccountId>>::resolve(&author, amount);
             }
         }
     }
@@ -66,8 +89,60 @@ parameter_types! {
     pub const TransactionByteFee: Balance = MICROAFT;
     pub const OperationalFeeMultiplier: u8 = 5;
     pub const WeightFeeFactor: Balance = 10 * MILLIAFT;
+
+    // Verified artists get half the fee of their catalog calls back, paid out
+    // of a treasury sub-pot funded by governance. Once the pot is empty, full
+    // fees apply again until it is topped up.
+    pub const ArtistFeeRebate: Perbill = Perbill::from_percent(50);
+    pub const ArtistRebatePotId: PalletId = PalletId(*b"af/rebat");
+    pub ArtistRebatePot: AccountId = ArtistRebatePotId::get().into_account_truncating();
+
+    // The USD stablecoin registered by Root in `Assets`, the only asset fees can be paid in.
+    pub const StableAssetId: u32 = 1;
+
+    // Fee split, tips included. Grants and bounties are funded out of the treasury's part.
+    pub const FeeBurnShare: Perbill = Perbill::zero();
+    pub const FeeTreasuryShare: Perbill = Perbill::from_percent(20);
+    pub const FeeAuthorShare: Perbill = Perbill::from_percent(80);
+}
+
+/// Assets fees may be paid in, swapped to AFT through their `AssetConversion` pool.
+pub struct FeeAssets;
+impl Contains<NativeOrWithId<u32>> for FeeAssets {
+    fn contains(asset: &NativeOrWithId<u32>) -> bool {
+        *asset == NativeOrWithId::WithId(StableAssetId::get())
+    }
 }
 
+/// Calls managing an artist's catalog, the only ones a fee rebate is paid for.
+pub struct CatalogCalls;
+impl Contains<RuntimeCall> for CatalogCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::MusicalWorks(..)
+                | RuntimeCall::Recordings(..)
+                | RuntimeCall::Releases(..)
+                | RuntimeCall::SoundRecordings(..)
+                | RuntimeCall::CatalogReleases(..)
+                | RuntimeCall::Royalties(..)
+                | RuntimeCall::Licensing(..)
+                | RuntimeCall::Anchoring(..)
+        )
+    }
+}
+
+/// Charges fees to the signer, then pays [`ArtistFeeRebate`] of them back to verified artists
+/// for catalog calls, batches of catalog calls included.
+pub type ChargeWithArtistRebate = RebateAdapter<
+    pallet_transaction_payment::FungibleAdapter<Balances, DealWithFees>,
+    Balances,
+    VerifiedArtists,
+    BatchAware<Runtime, CatalogCalls>,
+    ArtistRebatePot,
+    ArtistFeeRebate,
+>;
+
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
 /// node's balance type.
 ///
@@ -101,7 +176,8 @@ impl WeightToFeePolynomial for WeightToFee {
 
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, DealWithFees>;
+    // Sponsorship resolves the payer first, so the rebate goes to whoever actually pays.
+    type OnChargeTransaction = pallet_sponsorship::ChargeSponsored<Runtime, ChargeWithArtistRebate>;
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
     type WeightToFee = WeightToFee;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
@@ -111,3 +187,76 @@ impl pallet_transaction_payment::Config for Runtime {
 
 #[cfg(feature = "runtime-benchmarks")]
 impl pallet_transaction_payment::BenchmarkConfig for Runtime {}
+
+// Fees paid in an asset skip sponsorship and artist rebates: both are settled in AFT by the
+// `OnChargeTransaction` above, which only handles fees paid in AFT.
+impl pallet_asset_conversion_tx_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetId = NativeOrWithId<u32>;
+    type OnChargeAssetTransaction = WhitelistedAssets<
+        SwapAssetAdapter<Native, NativeAndAssets, AssetConversion, DealWithAssetFees>,
+        FeeAssets,
+    >;
+    type WeightInfo = pallet_asset_conversion_tx_payment::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = AssetTxPaymentBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetTxPaymentBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl
+    pallet_asset_conversion_tx_payment::BenchmarkHelperTrait<
+        AccountId,
+        NativeOrWithId<u32>,
+        NativeOrWithId<u32>,
+    > for AssetTxPaymentBenchmarkHelper
+{
+    fn create_asset_id_parameter(_id: u32) -> (NativeOrWithId<u32>, NativeOrWithId<u32>) {
+        // Only the whitelisted asset can pay fees.
+        let asset = NativeOrWithId::WithId(StableAssetId::get());
+        (asset.clone(), asset)
+    }
+
+    fn setup_balances_and_pool(asset_id: NativeOrWithId<u32>, account: AccountId) {
+        use alloc::boxed::Box;
+        use frame_support::{
+            assert_ok,
+            traits::{fungible::Mutate as _, fungibles::Mutate as _},
+        };
+        use shared_runtime::currency::AFT;
+
+        let NativeOrWithId::WithId(asset) = asset_id.clone() else {
+            unreachable!("fees are only benchmarked with an asset")
+        };
+        let lp_provider: AccountId = frame_benchmarking::account("lp_provider", 0, 0);
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            asset.into(),
+            lp_provider.clone().into(),
+            true,
+            1,
+        ));
+        assert_ok!(Balances::mint_into(&lp_provider, 1_000_000 * AFT));
+        assert_ok!(Assets::mint_into(asset, &lp_provider, 1_000_000 * AFT));
+        assert_ok!(AssetConversion::create_pool(
+            RuntimeOrigin::signed(lp_provider.clone()),
+            Box::new(NativeOrWithId::Native),
+            Box::new(asset_id.clone()),
+        ));
+        assert_ok!(AssetConversion::add_liquidity(
+            RuntimeOrigin::signed(lp_provider.clone()),
+            Box::new(NativeOrWithId::Native),
+            Box::new(asset_id),
+            100_000 * AFT,
+            100_000 * AFT,
+            1,
+            1,
+            lp_provider,
+        ));
+
+        assert_ok!(Balances::mint_into(&account, 1_000 * AFT));
+        assert_ok!(Assets::mint_into(asset, &account, 1_000 * AFT));
+    }
+}
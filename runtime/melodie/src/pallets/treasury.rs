@@ -0,0 +1,92 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    PalletId, parameter_types,
+    traits::{
+        EitherOf,
+        tokens::{PayFromAccount, UnityAssetBalanceConversion},
+    },
+};
+use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureWithSuccess};
+use shared_runtime::currency::AFT;
+use sp_core::ConstU32;
+use sp_runtime::traits::IdentityLookup;
+
+#[cfg(feature = "runtime-benchmarks")]
+use core::marker::PhantomData;
+#[cfg(feature = "runtime-benchmarks")]
+use frame_support::traits::fungible::{Inspect, Mutate};
+#[cfg(feature = "runtime-benchmarks")]
+use pallet_treasury::ArgumentsFactory;
+#[cfg(feature = "runtime-benchmarks")]
+use sp_core::crypto::FromEntropy;
+
+parameter_types! {
+    pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+    pub const SpendPeriod: BlockNumber = 6 * DAYS;
+    pub const PayoutSpendPeriod: BlockNumber = 30 * DAYS;
+    pub const MaxBalance: Balance = Balance::MAX;
+    // Largest spend a single `treasury_spender` referendum can approve.
+    pub const MaxTreasurySpend: Balance = 100_000 * AFT;
+
+    pub TreasuryAccount: AccountId = Treasury::account_id();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PalletTreasuryArguments<T>(PhantomData<T>);
+#[cfg(feature = "runtime-benchmarks")]
+impl<T> ArgumentsFactory<(), AccountId> for PalletTreasuryArguments<T>
+where
+    T: Mutate<AccountId> + Inspect<AccountId>,
+{
+    fn create_asset_kind(_seed: u32) {}
+    fn create_beneficiary(seed: [u8; 32]) -> AccountId {
+        let account = AccountId::from_entropy(&mut seed.as_slice()).unwrap();
+        <T as Mutate<_>>::mint_into(&account, <T as Inspect<_>>::minimum_balance()).unwrap();
+        account
+    }
+}
+
+impl pallet_treasury::Config for Runtime {
+    type PalletId = TreasuryPalletId;
+    type Currency = Balances;
+    type RejectOrigin = EitherOf<EnsureRoot<AccountId>, TreasurySpender>;
+    type RuntimeEvent = RuntimeEvent;
+    type SpendPeriod = SpendPeriod;
+    type Burn = ();
+    type BurnDestination = ();
+    type MaxApprovals = ConstU32<100>;
+    type WeightInfo = shared_runtime::weights::treasury::AllfeatWeight<Runtime>;
+    // Approved bounties are funded out of the pot every spend period.
+    type SpendFunds = Bounties;
+    type SpendOrigin = EitherOf<
+        EnsureRootWithSuccess<AccountId, MaxBalance>,
+        EnsureWithSuccess<TreasurySpender, AccountId, MaxTreasurySpend>,
+    >;
+    type AssetKind = ();
+    type Beneficiary = AccountId;
+    type BeneficiaryLookup = IdentityLookup<Self::Beneficiary>;
+    type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
+    type BalanceConverter = UnityAssetBalanceConversion;
+    type PayoutPeriod = PayoutSpendPeriod;
+    type BlockNumberProvider = System;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = PalletTreasuryArguments<Balances>;
+}
@@ -0,0 +1,82 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::*;
+use frame_support::parameter_types;
+use pallet_recordings::Isrc;
+use shared_runtime::currency::AFT;
+use sp_runtime::Perbill;
+
+parameter_types! {
+    pub const UsageProviderBond: Balance = 1_000 * AFT;
+    // Longer than a period and its submission window, so a provider cannot leave before the
+    // reports it submitted are finalized.
+    pub const UsageProviderUnbondingPeriod: BlockNumber = 28 * DAYS;
+    // Weekly reports, matching the DSP reporting cadence.
+    pub const UsagePeriodLength: BlockNumber = 7 * DAYS;
+    pub const MaxUsageProviders: u32 = 32;
+    pub const UsageQuorum: u32 = 3;
+    pub const UsageOutlierTolerance: Perbill = Perbill::from_percent(5);
+    pub const UsageOutlierSlash: Perbill = Perbill::from_percent(10);
+}
+
+impl pallet_usage_oracle::Config for Runtime {
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type SlashOrigin = EnsureDisputeArbiter;
+    type RecordingId = Isrc;
+    type ProviderBond = UsageProviderBond;
+    type UnbondingPeriod = UsageProviderUnbondingPeriod;
+    type PeriodLength = UsagePeriodLength;
+    type MaxProviders = MaxUsageProviders;
+    // Aggregates feed `Royalties::distribute_usage`, so they fit in a usage report.
+    type MaxReportEntries = MaxUsageReportEntries;
+    type MinQuorum = UsageQuorum;
+    type OutlierTolerance = UsageOutlierTolerance;
+    type OutlierSlash = UsageOutlierSlash;
+    type WeightInfo = weights::usage_oracle::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = FinalizedStreamingUsage;
+}
+
+/// Finalized aggregates of `UsageOracle`, as royalty usage reports on recordings.
+pub struct FinalizedStreamingUsage;
+
+impl pallet_royalties::UsageReports<RoyaltyAsset> for FinalizedStreamingUsage {
+    fn finalized_usage(period: u32) -> Option<Vec<(RoyaltyAsset, u32)>> {
+        UsageOracle::finalized_usage(period).map(|usage| {
+            usage
+                .into_iter()
+                .map(|(isrc, plays)| (RoyaltyAsset::Recording(isrc), plays))
+                .collect()
+        })
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_usage_oracle::BenchmarkHelper<Isrc> for FinalizedStreamingUsage {
+    fn recording(i: u32) -> Isrc {
+        // `FRZ05` followed by `i` as a 7-digit designation, e.g. `FRZ050000042`.
+        let mut isrc = b"FRZ050000000".to_vec();
+        let mut n = i;
+        for digit in isrc[5..].iter_mut().rev() {
+            *digit = b'0' + (n % 10) as u8;
+            n /= 10;
+        }
+        Isrc::truncate_from(isrc)
+    }
+}
@@ -18,9 +18,6 @@
 
 use crate::*;
 
-impl pallet_utility::Config for Runtime {
-    type RuntimeEvent = RuntimeEvent;
-    type RuntimeCall = RuntimeCall;
-    type PalletsOrigin = OriginCaller;
-    type WeightInfo = weights::utility::AllfeatWeight<Runtime>;
+shared_runtime::impl_allfeat_common_configs! {
+    utility
 }
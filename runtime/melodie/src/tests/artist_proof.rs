@@ -0,0 +1,56 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The certification storage read by the artist verification proofs of `allfeat-primitives`.
+
+use crate::{AccountId, CertifiedRecord, Runtime};
+use allfeat_primitives::artist_proof;
+use frame_support::storage::unhashed;
+
+#[test]
+fn artist_proofs_read_the_certification_storage() {
+    let artist = AccountId::new([1; 32]);
+
+    assert_eq!(
+        artist_proof::attestations_key(&artist),
+        pallet_certification::Attestations::<Runtime>::hashed_key_for(CertifiedRecord::Artist(
+            artist.clone()
+        )),
+    );
+    assert_eq!(
+        artist_proof::certifier_key(&artist),
+        pallet_certification::Certifiers::<Runtime>::hashed_key_for(&artist),
+    );
+}
+
+#[test]
+fn artist_proofs_decode_the_attestations() {
+    super::new_test_ext().execute_with(|| {
+        let artist = AccountId::new([1; 32]);
+        let certifiers = vec![AccountId::new([10; 32]), AccountId::new([11; 32])];
+        pallet_certification::Attestations::<Runtime>::insert(
+            CertifiedRecord::Artist(artist.clone()),
+            pallet_certification::AttestationsOf::<Runtime>::truncate_from(
+                certifiers.iter().map(|c| (c.clone(), 5)).collect(),
+            ),
+        );
+
+        let value = unhashed::get_raw(&artist_proof::attestations_key(&artist)).unwrap();
+        assert_eq!(artist_proof::attesting_certifiers(&value), Some(certifiers));
+    });
+}
@@ -0,0 +1,103 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Proof-of-work artist registrations sent by accounts without any funds, through the
+//! runtime's transaction extensions.
+
+use crate::{AccountId, Balances, Runtime, RuntimeCall, RuntimeOrigin, System, tx_extension};
+use allfeat_primitives::signing::TransactionParams;
+use frame_support::{assert_ok, dispatch::GetDispatchInfo, traits::fungible::Inspect};
+use pallet_artists::{MembersOf, Pallet as Artists, Thresholds, WorkArtists, leading_zero_bits};
+use parity_scale_codec::Encode;
+use sp_core::H256;
+use sp_runtime::{
+    generic::Era,
+    traits::DispatchTransaction,
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+const THRESHOLDS: Thresholds = Thresholds {
+    metadata: 1,
+    payout: 1,
+    membership: 1,
+};
+
+fn solo(who: &AccountId) -> MembersOf<Runtime> {
+    vec![who.clone()].try_into().unwrap()
+}
+
+/// First nonce solving the puzzle for `who`'s solo artist, or failing it if `enough` is false.
+fn nonce(who: &AccountId, enough: bool) -> u64 {
+    let difficulty = Artists::<Runtime>::work_difficulty();
+    (0..)
+        .find(|nonce| {
+            let hash =
+                Artists::<Runtime>::work_hash(who, &solo(who), &THRESHOLDS, &H256::zero(), *nonce);
+            (leading_zero_bits(hash.as_ref()) >= difficulty) == enough
+        })
+        .unwrap()
+}
+
+/// Applies `create_artist_with_work` signed by `who` with the runtime's transaction
+/// extensions, at nonce 0.
+fn submit(who: &AccountId, nonce: u64) -> Result<(), TransactionValidityError> {
+    let call = RuntimeCall::Artists(pallet_artists::Call::create_artist_with_work {
+        members: solo(who),
+        thresholds: THRESHOLDS,
+        metadata: H256::zero(),
+        nonce,
+    });
+    let info = call.get_dispatch_info();
+    let len = call.encoded_size();
+    let params = TransactionParams {
+        nonce: 0,
+        era: Era::Immortal,
+        tip: 0,
+    };
+    let result = tx_extension(&params).dispatch_transaction(
+        RuntimeOrigin::signed(who.clone()),
+        call,
+        &info,
+        len,
+        0,
+    )?;
+    assert_ok!(result);
+    Ok(())
+}
+
+#[test]
+fn account_without_funds_creates_an_artist_with_work() {
+    super::new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        // Keeps the test fast: the minimum difficulty takes a million hashes.
+        pallet_artists::PowDifficulty::<Runtime>::put(8);
+        let who = AccountId::new([7; 32]);
+        assert_eq!(Balances::total_balance(&who), 0);
+        assert!(!System::account_exists(&who));
+
+        assert_eq!(
+            submit(&who, nonce(&who, false)),
+            Err(InvalidTransaction::BadProof.into())
+        );
+
+        assert_ok!(submit(&who, nonce(&who, true)));
+        assert!(WorkArtists::<Runtime>::contains_key(&who));
+        assert_eq!(Balances::total_balance(&who), 0);
+        assert_eq!(System::account_nonce(&who), 0);
+    });
+}
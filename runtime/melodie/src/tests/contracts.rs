@@ -0,0 +1,137 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Wasm contracts uploaded, instantiated and dry-run the way `ContractsApi` does.
+
+use crate::{
+    AccountId, Balances, Contracts, Runtime, RuntimeBlockWeights, RuntimeEvent, RuntimeOrigin,
+    System,
+};
+use frame_support::{assert_ok, traits::fungible::InspectHold};
+use pallet_contracts::{
+    Code, CollectEvents, ContractAccessError, DebugInfo, Determinism, HoldReason,
+};
+use shared_runtime::currency::AFT;
+use sp_runtime::{
+    BuildStorage,
+    traits::{BlakeTwo256, Hash},
+};
+
+const DEV: u8 = 1;
+const FAN: u8 = 2;
+
+/// `(module (import "env" "memory" (memory 1 1)) (func (export "deploy")) (func (export "call")))`
+const NOOP: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic, version
+    0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type: () -> ()
+    0x02, 0x10, 0x01, 0x03, b'e', b'n', b'v', 0x06, b'm', b'e', b'm', b'o', b'r', b'y', 0x02, 0x01,
+    0x01, 0x01, // import: env.memory
+    0x03, 0x03, 0x02, 0x00, 0x00, // functions
+    0x07, 0x11, 0x02, 0x06, b'd', b'e', b'p', b'l', b'o', b'y', 0x00, 0x00, 0x04, b'c', b'a', b'l',
+    b'l', 0x00, 0x01, // exports
+    0x0a, 0x07, 0x02, 0x02, 0x00, 0x0b, 0x02, 0x00, 0x0b, // empty bodies
+];
+
+fn account(n: u8) -> AccountId {
+    AccountId::new([n; 32])
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: vec![(account(DEV), 1_000 * AFT), (account(FAN), 1_000 * AFT)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+#[test]
+fn uploaded_code_is_held_against_a_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Contracts::upload_code(
+            RuntimeOrigin::signed(account(DEV)),
+            NOOP.to_vec(),
+            None,
+            Determinism::Enforced,
+        ));
+        let held =
+            Balances::balance_on_hold(&HoldReason::CodeUploadDepositReserve.into(), &account(DEV));
+        assert!(held > 0);
+
+        assert_ok!(Contracts::remove_code(
+            RuntimeOrigin::signed(account(DEV)),
+            BlakeTwo256::hash(NOOP),
+        ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::CodeUploadDepositReserve.into(), &account(DEV)),
+            0
+        );
+    });
+}
+
+#[test]
+fn calls_are_dry_run_with_their_events() {
+    new_test_ext().execute_with(|| {
+        let gas_limit = RuntimeBlockWeights::get().max_block;
+        let contract = Contracts::bare_instantiate(
+            account(DEV),
+            0,
+            gas_limit,
+            None,
+            Code::Upload(NOOP.to_vec()),
+            vec![],
+            vec![],
+            DebugInfo::Skip,
+            CollectEvents::Skip,
+        )
+        .result
+        .unwrap()
+        .account_id;
+
+        let result = Contracts::bare_call(
+            account(FAN),
+            contract.clone(),
+            0,
+            gas_limit,
+            None,
+            vec![],
+            DebugInfo::UnsafeDebug,
+            CollectEvents::UnsafeCollect,
+            Determinism::Enforced,
+        );
+        assert!(!result.result.unwrap().did_revert());
+        assert!(result.gas_required.all_lte(gas_limit));
+        assert!(result.events.unwrap().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Contracts(pallet_contracts::Event::Called { .. })
+        )));
+
+        assert_eq!(Contracts::get_storage(contract, vec![0; 32]), Ok(None));
+        assert_eq!(
+            Contracts::get_storage(account(FAN), vec![0; 32]),
+            Err(ContractAccessError::DoesntExist)
+        );
+    });
+}
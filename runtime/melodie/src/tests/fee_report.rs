@@ -48,10 +48,10 @@ fn print_fee_report() {
         use pallet_preimage::WeightInfo as _;
         use pallet_proxy::WeightInfo as _;
         use pallet_scheduler::WeightInfo as _;
+        use pallet_staking::WeightInfo as _;
         use pallet_sudo::WeightInfo as _;
         use pallet_timestamp::WeightInfo as _;
         use pallet_utility::WeightInfo as _;
-        use pallet_validators::WeightInfo as _;
 
         type SystemW = weights::system::AllfeatWeight<Runtime>;
         type BalancesW = weights::balances::AllfeatWeight<Runtime>;
@@ -62,7 +62,7 @@ fn print_fee_report() {
         type ProxyW = weights::proxy::AllfeatWeight<Runtime>;
         type MultisigW = weights::multisig::AllfeatWeight<Runtime>;
         type SudoW = weights::sudo::AllfeatWeight<Runtime>;
-        type ValidatorsW = weights::validators::AllfeatWeight<Runtime>;
+        type StakingW = shared_runtime::weights::staking::AllfeatWeight<Runtime>;
         type AtsW = weights::ats::AllfeatWeight<Runtime>;
 
         let ats_base_deposit = crate::BaseDeposit::get();
@@ -197,18 +197,25 @@ fn print_fee_report() {
                 encoded_len: 200,
                 deposit: 0,
             },
-            // Validators
+            // Staking
             ExtrinsicFeeInfo {
-                pallet: "Validators",
-                extrinsic: "add_validator",
-                weight: ValidatorsW::add_validator(),
+                pallet: "Staking",
+                extrinsic: "bond",
+                weight: StakingW::bond(),
                 encoded_len: 100,
                 deposit: 0,
             },
             ExtrinsicFeeInfo {
-                pallet: "Validators",
-                extrinsic: "remove_validator",
-                weight: ValidatorsW::remove_validator(),
+                pallet: "Staking",
+                extrinsic: "nominate (16 targets)",
+                weight: StakingW::nominate(16),
+                encoded_len: 600,
+                deposit: 0,
+            },
+            ExtrinsicFeeInfo {
+                pallet: "Staking",
+                extrinsic: "payout_stakers (64 nominators)",
+                weight: StakingW::payout_stakers_alive_staked(64),
                 encoded_len: 100,
                 deposit: 0,
             },
@@ -0,0 +1,210 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Identities carrying music industry fields, judged by certified registrars.
+
+use crate::{
+    AccountId, Certification, Identity, Runtime, RuntimeCall, RuntimeOrigin, System,
+    pallet_custom_origins,
+};
+use frame_support::{assert_noop, assert_ok};
+use pallet_certification::OrganizationKind;
+use pallet_identity::{Data, Judgement};
+use shared_runtime::{currency::AFT, identity::IdentityInfo};
+use sp_runtime::{
+    BuildStorage, DispatchResult,
+    traits::{BlakeTwo256, Dispatchable, Hash, StaticLookup},
+};
+
+const ARTIST: u8 = 1;
+const REGISTRAR: u8 = 2;
+const PRO: u8 = 3;
+
+fn account(n: u8) -> AccountId {
+    AccountId::new([n; 32])
+}
+
+fn lookup(n: u8) -> <<Runtime as frame_system::Config>::Lookup as StaticLookup>::Source {
+    <Runtime as frame_system::Config>::Lookup::unlookup(account(n))
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: (1..=3).map(|n| (account(n), 1_000 * AFT)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Certification::add_certifier(
+            RuntimeOrigin::root(),
+            account(REGISTRAR),
+            OrganizationKind::Registrar
+        ));
+        assert_ok!(Certification::add_certifier(
+            RuntimeOrigin::root(),
+            account(PRO),
+            OrganizationKind::Pro
+        ));
+    });
+    ext
+}
+
+fn raw(bytes: &[u8]) -> Data {
+    Data::Raw(bytes.to_vec().try_into().unwrap())
+}
+
+fn artist_identity() -> IdentityInfo {
+    IdentityInfo {
+        display: raw(b"Daft Punk"),
+        ipi: Some(*b"00052210040"),
+        pro: raw(b"SACEM"),
+        label: raw(b"Columbia"),
+        booking: raw(b"booking@example.com"),
+        ..Default::default()
+    }
+}
+
+/// Dispatches `call` from `origin` through the call filter.
+fn dispatch(origin: RuntimeOrigin, call: pallet_identity::Call<Runtime>) -> DispatchResult {
+    RuntimeCall::Identity(call)
+        .dispatch(origin)
+        .map(|_| ())
+        .map_err(|e| e.error)
+}
+
+/// A referendum on the music industry admin track.
+fn music_industry_admin() -> RuntimeOrigin {
+    pallet_custom_origins::Origin::MusicIndustryAdmin.into()
+}
+
+fn set_identity(info: IdentityInfo) -> DispatchResult {
+    dispatch(
+        RuntimeOrigin::signed(account(ARTIST)),
+        pallet_identity::Call::set_identity {
+            info: Box::new(info),
+        },
+    )
+}
+
+fn judge_artist() -> DispatchResult {
+    dispatch(
+        RuntimeOrigin::signed(account(REGISTRAR)),
+        pallet_identity::Call::provide_judgement {
+            reg_index: 0,
+            target: lookup(ARTIST),
+            judgement: Judgement::KnownGood,
+            identity: BlakeTwo256::hash_of(&artist_identity()),
+        },
+    )
+}
+
+#[test]
+fn malformed_music_industry_fields_are_filtered() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            set_identity(IdentityInfo {
+                ipi: Some(*b"IPI-5221004"),
+                ..artist_identity()
+            }),
+            frame_system::Error::<Runtime>::CallFiltered
+        );
+        assert_noop!(
+            set_identity(IdentityInfo {
+                label: Data::BlakeTwo256([0; 32]),
+                ..artist_identity()
+            }),
+            frame_system::Error::<Runtime>::CallFiltered
+        );
+
+        assert_ok!(set_identity(artist_identity()));
+        assert_eq!(
+            pallet_identity::IdentityOf::<Runtime>::get(account(ARTIST))
+                .unwrap()
+                .info,
+            artist_identity()
+        );
+    });
+}
+
+#[test]
+fn only_certified_registrars_judge_identities() {
+    new_test_ext().execute_with(|| {
+        // A certifier of another kind can't become a registrar.
+        assert_noop!(
+            dispatch(
+                music_industry_admin(),
+                pallet_identity::Call::add_registrar {
+                    account: lookup(PRO)
+                },
+            ),
+            frame_system::Error::<Runtime>::CallFiltered
+        );
+        assert_ok!(dispatch(
+            music_industry_admin(),
+            pallet_identity::Call::add_registrar {
+                account: lookup(REGISTRAR)
+            },
+        ));
+        // Nor take over the slot of one.
+        assert_noop!(
+            dispatch(
+                RuntimeOrigin::signed(account(REGISTRAR)),
+                pallet_identity::Call::set_account_id {
+                    index: 0,
+                    new: lookup(PRO)
+                },
+            ),
+            frame_system::Error::<Runtime>::CallFiltered
+        );
+
+        assert_ok!(set_identity(artist_identity()));
+        assert_ok!(Identity::request_judgement(
+            RuntimeOrigin::signed(account(ARTIST)),
+            0,
+            0
+        ));
+
+        // A registrar whose certification is revoked can't judge anymore.
+        assert_ok!(Certification::remove_certifier(
+            RuntimeOrigin::root(),
+            account(REGISTRAR)
+        ));
+        assert_noop!(judge_artist(), frame_system::Error::<Runtime>::CallFiltered);
+
+        // Until it is certified again.
+        assert_ok!(Certification::add_certifier(
+            RuntimeOrigin::root(),
+            account(REGISTRAR),
+            OrganizationKind::Registrar
+        ));
+        assert_ok!(judge_artist());
+        assert!(
+            pallet_identity::IdentityOf::<Runtime>::get(account(ARTIST))
+                .unwrap()
+                .judgements
+                .contains(&(0, Judgement::KnownGood))
+        );
+    });
+}
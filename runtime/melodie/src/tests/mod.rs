@@ -1,8 +1,18 @@
 use crate::Runtime;
 use sp_runtime::BuildStorage;
 
+pub mod artist_proof;
+pub mod artist_work;
+pub mod contracts;
 pub mod fee_report;
+pub mod identity;
 pub mod midds_integration;
+pub mod proxy;
+pub mod recovery;
+pub mod staking;
+pub mod technical_committee;
+pub mod vesting;
+pub mod weights;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
     let t = frame_system::GenesisConfig::<Runtime>::default()
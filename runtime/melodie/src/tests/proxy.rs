@@ -0,0 +1,105 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Calls each proxy type lets a delegate make.
+
+use crate::{AccountId, ProxyType, RuntimeCall};
+use frame_support::traits::InstanceFilter;
+
+fn claim_payment() -> RuntimeCall {
+    RuntimeCall::Licensing(pallet_licensing::Call::claim_payment { license_id: 0 })
+}
+
+fn withdraw_offer() -> RuntimeCall {
+    RuntimeCall::Licensing(pallet_licensing::Call::withdraw_offer { offer_id: 0 })
+}
+
+fn close_listing() -> RuntimeCall {
+    RuntimeCall::Sales(pallet_sales::Call::close { listing: 0 })
+}
+
+fn purchase_release() -> RuntimeCall {
+    RuntimeCall::Sales(pallet_sales::Call::purchase { listing: 0 })
+}
+
+fn claim_advance() -> RuntimeCall {
+    RuntimeCall::Escrow(pallet_escrow::Call::claim {
+        escrow: 0,
+        milestone: 0,
+    })
+}
+
+fn prune_statement() -> RuntimeCall {
+    RuntimeCall::Royalties(pallet_royalties::Call::prune_statement { statement: 1 })
+}
+
+fn remove_vote() -> RuntimeCall {
+    RuntimeCall::ConvictionVoting(pallet_conviction_voting::Call::remove_vote {
+        class: None,
+        index: 0,
+    })
+}
+
+fn transfer() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: AccountId::new([1; 32]).into(),
+        value: 1,
+    })
+}
+
+#[test]
+fn label_proxies_only_make_their_calls() {
+    let cases = [
+        (ProxyType::CatalogManagement, claim_payment(), true),
+        (ProxyType::CatalogManagement, withdraw_offer(), true),
+        (ProxyType::CatalogManagement, close_listing(), true),
+        (ProxyType::CatalogManagement, purchase_release(), false),
+        (ProxyType::CatalogManagement, claim_advance(), false),
+        // Statements belong to the payer of royalties, not to the catalog.
+        (ProxyType::CatalogManagement, prune_statement(), false),
+        (ProxyType::CatalogManagement, remove_vote(), false),
+        (ProxyType::CatalogManagement, transfer(), false),
+        (ProxyType::RoyaltyClaims, claim_payment(), true),
+        (ProxyType::RoyaltyClaims, claim_advance(), true),
+        (ProxyType::RoyaltyClaims, withdraw_offer(), false),
+        (ProxyType::RoyaltyClaims, remove_vote(), false),
+        (ProxyType::RoyaltyClaims, transfer(), false),
+        (ProxyType::Governance, remove_vote(), true),
+        (ProxyType::Governance, claim_payment(), false),
+        (ProxyType::Governance, transfer(), false),
+    ];
+    for (proxy_type, call, allowed) in cases {
+        assert_eq!(
+            proxy_type.filter(&call),
+            allowed,
+            "{proxy_type:?} on {call:?}"
+        );
+    }
+}
+
+#[test]
+fn catalog_management_covers_artist_managers() {
+    assert!(ProxyType::CatalogManagement.is_superset(&ProxyType::ArtistManager { until: 10 }));
+    assert!(!ProxyType::ArtistManager { until: 10 }.is_superset(&ProxyType::CatalogManagement));
+    for other in [ProxyType::RoyaltyClaims, ProxyType::Governance] {
+        assert!(!ProxyType::CatalogManagement.is_superset(&other));
+        assert!(!other.is_superset(&ProxyType::CatalogManagement));
+        assert!(ProxyType::Any.is_superset(&other));
+        assert!(!other.is_superset(&ProxyType::Any));
+    }
+}
@@ -0,0 +1,204 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Recovery of lost accounts, artist members' included, through their friends.
+
+use crate::{AccountId, Artists, DAYS, Recovery, Runtime, RuntimeCall, RuntimeOrigin, System};
+use frame_support::{assert_noop, assert_ok};
+use pallet_artists::{Action, Thresholds};
+use shared_runtime::currency::AFT;
+use sp_core::H256;
+use sp_runtime::{
+    BuildStorage, DispatchResult,
+    traits::{Dispatchable, StaticLookup},
+};
+
+const LOST: u8 = 1;
+const BANDMATE: u8 = 2;
+const FRIENDS: [u8; 3] = [3, 4, 5];
+const RESCUER: u8 = 6;
+
+fn account(n: u8) -> AccountId {
+    AccountId::new([n; 32])
+}
+
+fn lookup(n: u8) -> <<Runtime as frame_system::Config>::Lookup as StaticLookup>::Source {
+    <Runtime as frame_system::Config>::Lookup::unlookup(account(n))
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: (1..=6).map(|n| (account(n), 1_000 * AFT)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Dispatches `create_recovery` for [`LOST`] through the call filter.
+fn create_recovery(threshold: u16, delay_period: u32) -> DispatchResult {
+    RuntimeCall::Recovery(pallet_recovery::Call::create_recovery {
+        friends: FRIENDS.map(account).to_vec(),
+        threshold,
+        delay_period,
+    })
+    .dispatch(RuntimeOrigin::signed(account(LOST)))
+    .map(|_| ())
+    .map_err(|e| e.error)
+}
+
+/// [`RESCUER`] recovers [`LOST`] with the vouches of two of its [`FRIENDS`].
+fn recover_lost_account() {
+    assert_ok!(create_recovery(2, 3 * DAYS));
+    assert_ok!(Recovery::initiate_recovery(
+        RuntimeOrigin::signed(account(RESCUER)),
+        lookup(LOST)
+    ));
+    for friend in &FRIENDS[..2] {
+        assert_ok!(Recovery::vouch_recovery(
+            RuntimeOrigin::signed(account(*friend)),
+            lookup(LOST),
+            lookup(RESCUER)
+        ));
+    }
+
+    assert_noop!(
+        Recovery::claim_recovery(RuntimeOrigin::signed(account(RESCUER)), lookup(LOST)),
+        pallet_recovery::Error::<Runtime>::DelayPeriod
+    );
+    System::set_block_number(System::block_number() + 3 * DAYS);
+    assert_ok!(Recovery::claim_recovery(
+        RuntimeOrigin::signed(account(RESCUER)),
+        lookup(LOST)
+    ));
+}
+
+/// [`RESCUER`] acting as [`LOST`].
+fn as_lost(call: pallet_artists::Call<Runtime>) -> DispatchResult {
+    Recovery::as_recovered(
+        RuntimeOrigin::signed(account(RESCUER)),
+        lookup(LOST),
+        Box::new(RuntimeCall::Artists(call)),
+    )
+}
+
+#[test]
+fn recovery_configs_below_the_minimums_are_filtered() {
+    new_test_ext().execute_with(|| {
+        // A single friend could take the account over.
+        assert_noop!(
+            create_recovery(1, 3 * DAYS),
+            frame_system::Error::<Runtime>::CallFiltered
+        );
+        // Too short for the owner to notice and close a recovery.
+        assert_noop!(
+            create_recovery(2, 3 * DAYS - 1),
+            frame_system::Error::<Runtime>::CallFiltered
+        );
+
+        assert_ok!(create_recovery(2, 3 * DAYS));
+        assert!(pallet_recovery::Recoverable::<Runtime>::contains_key(
+            account(LOST)
+        ));
+    });
+}
+
+#[test]
+fn recovered_member_replaces_their_lost_account() {
+    new_test_ext().execute_with(|| {
+        let thresholds = Thresholds {
+            metadata: 1,
+            payout: 2,
+            membership: 2,
+        };
+        assert_ok!(Artists::create_artist(
+            RuntimeOrigin::signed(account(LOST)),
+            vec![account(LOST), account(BANDMATE)].try_into().unwrap(),
+            thresholds,
+            H256::zero(),
+        ));
+        let add_rescuer = || pallet_artists::Call::propose {
+            artist: 0,
+            action: Action::AddMember(account(RESCUER)),
+        };
+
+        // Only once the account is recovered.
+        assert_noop!(
+            as_lost(add_rescuer()),
+            pallet_recovery::Error::<Runtime>::NotAllowed
+        );
+        recover_lost_account();
+
+        // The recovered account keeps its place among the members.
+        assert_ok!(as_lost(pallet_artists::Call::propose {
+            artist: 0,
+            action: Action::SetMetadata(H256::repeat_byte(1)),
+        }));
+        assert_ok!(Artists::execute(
+            RuntimeOrigin::signed(account(BANDMATE)),
+            0,
+            0
+        ));
+        assert_eq!(
+            pallet_artists::Artists::<Runtime>::get(0).unwrap().metadata,
+            H256::repeat_byte(1)
+        );
+
+        // And hands it over to the rescuer with the consent of the other members.
+        assert_ok!(as_lost(add_rescuer()));
+        assert_ok!(Artists::approve(
+            RuntimeOrigin::signed(account(BANDMATE)),
+            0,
+            1
+        ));
+        assert_ok!(Artists::execute(
+            RuntimeOrigin::signed(account(RESCUER)),
+            0,
+            1
+        ));
+        assert_ok!(Artists::propose(
+            RuntimeOrigin::signed(account(RESCUER)),
+            0,
+            Action::RemoveMember(account(LOST)),
+        ));
+        assert_ok!(Artists::approve(
+            RuntimeOrigin::signed(account(BANDMATE)),
+            0,
+            2
+        ));
+        assert_ok!(Artists::execute(
+            RuntimeOrigin::signed(account(RESCUER)),
+            0,
+            2
+        ));
+        assert_eq!(
+            pallet_artists::Artists::<Runtime>::get(0)
+                .unwrap()
+                .members
+                .to_vec(),
+            vec![account(BANDMATE), account(RESCUER)]
+        );
+    });
+}
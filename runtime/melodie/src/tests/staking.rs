@@ -0,0 +1,453 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Validator elections and era inflation, on the real staking configuration.
+
+use crate::{
+    AccountId, Balance, Balances, ElectionProviderMultiPhase, ExistentialDeposit, FastUnstake,
+    Grandpa, Historical, MILLISECS_PER_BLOCK, Offences, Runtime, RuntimeBlockWeights, RuntimeEvent,
+    RuntimeOrigin, Session, SessionKeys, SessionPeriod, SlashDeferDuration, Staking, System,
+    Timestamp, TreasuryAccount, voter_bags,
+};
+use frame_support::{
+    assert_ok,
+    traits::{KeyOwnerProofSystem, OnFinalize, OnIdle, OnInitialize, fungible::Inspect},
+    weights::Weight,
+};
+use pallet_authorship::EventHandler;
+use pallet_election_provider_multi_phase::{ElectionCompute, Event as ElectionEvent, WeightInfo};
+use pallet_staking::{EraPayout, StakerStatus};
+use shared_runtime::{
+    currency::AFT,
+    elections::{MaxElectableTargets, MaxElectingVoters, SignedFixedDeposit, SignedRewardBase},
+    staking::FastUnstakeDeposit,
+};
+use sp_consensus_grandpa::{AuthorityId as GrandpaId, Equivocation, EquivocationProof};
+use sp_core::{H256, Pair};
+use sp_runtime::{BuildStorage, Perbill, transaction_validity::TransactionSource};
+use sp_staking::{
+    currency_to_vote::{CurrencyToVote, U128CurrencyToVote},
+    offence::ReportOffence,
+};
+
+const STASH: Balance = 1_000_000 * AFT;
+
+fn account(n: u8) -> AccountId {
+    AccountId::new([n; 32])
+}
+
+fn grandpa_pair(n: u8) -> sp_core::ed25519::Pair {
+    sp_core::ed25519::Pair::from_seed(&[n; 32])
+}
+
+fn session_keys(n: u8) -> SessionKeys {
+    SessionKeys {
+        grandpa: grandpa_pair(n).public().into(),
+        aura: sp_core::sr25519::Public::from_raw([n; 32]).into(),
+        im_online: sp_core::sr25519::Public::from_raw([n; 32]).into(),
+    }
+}
+
+/// Accounts 1 and 2 validate with a stash of [`STASH`], account 3 is free to nominate.
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: (1..=3).map(|n| (account(n), 10 * STASH)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    pallet_staking::GenesisConfig::<Runtime> {
+        validator_count: 2,
+        minimum_validator_count: 2,
+        stakers: (1..=2)
+            .map(|n| (account(n), account(n), STASH, StakerStatus::Validator))
+            .collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    pallet_session::GenesisConfig::<Runtime> {
+        keys: (1..=2)
+            .map(|n| (account(n), account(n), session_keys(n)))
+            .collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| run_to_block(1));
+    ext
+}
+
+fn run_to_block(n: u32) {
+    for block in System::block_number() + 1..=n {
+        System::set_block_number(block);
+        Timestamp::set_timestamp(block as u64 * MILLISECS_PER_BLOCK);
+        Session::on_initialize(block);
+        ElectionProviderMultiPhase::on_initialize(block);
+        Staking::on_finalize(block);
+    }
+}
+
+fn election_events() -> Vec<ElectionEvent<Runtime>> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::ElectionProviderMultiPhase(event) => Some(event),
+            _ => None,
+        })
+        .collect()
+}
+
+fn sorted(mut accounts: Vec<AccountId>) -> Vec<AccountId> {
+    accounts.sort();
+    accounts
+}
+
+#[test]
+fn genesis_stakers_are_the_first_validators() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(sorted(Session::validators()), vec![account(1), account(2)]);
+        assert_eq!(
+            pallet_staking::ActiveEra::<Runtime>::get().unwrap().index,
+            0
+        );
+
+        let exposure = pallet_staking::ErasStakersOverview::<Runtime>::get(0, account(1)).unwrap();
+        assert_eq!((exposure.total, exposure.own), (STASH, STASH));
+    });
+}
+
+#[test]
+fn nominations_count_from_the_next_era_which_mints_inflation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Staking::bond(
+            RuntimeOrigin::signed(account(3)),
+            2 * STASH,
+            pallet_staking::RewardDestination::Staked,
+        ));
+        assert_ok!(Staking::nominate(
+            RuntimeOrigin::signed(account(3)),
+            vec![account(1).into()],
+        ));
+        // Eras last a day, the next one is planned at the next session instead.
+        assert_ok!(Staking::force_new_era(RuntimeOrigin::root()));
+
+        run_to_block(2 * SessionPeriod::get() - 1);
+        let era_start = pallet_staking::ActiveEra::<Runtime>::get()
+            .unwrap()
+            .start
+            .unwrap();
+        let issuance = Balances::total_issuance();
+        run_to_block(2 * SessionPeriod::get());
+
+        assert_eq!(
+            pallet_staking::ActiveEra::<Runtime>::get().unwrap().index,
+            1
+        );
+        let exposure = pallet_staking::ErasStakersOverview::<Runtime>::get(1, account(1)).unwrap();
+        assert_eq!((exposure.total, exposure.own), (3 * STASH, STASH));
+        assert_eq!(exposure.nominator_count, 1);
+
+        let now = Timestamp::get();
+        let (to_stakers, to_treasury) = <Runtime as pallet_staking::Config>::EraPayout::era_payout(
+            2 * STASH,
+            issuance,
+            now - era_start,
+        );
+        assert!(to_stakers > 0);
+        assert_eq!(
+            pallet_staking::ErasValidatorReward::<Runtime>::get(0),
+            Some(to_stakers)
+        );
+        assert_eq!(Balances::balance(&TreasuryAccount::get()), to_treasury);
+    });
+}
+
+#[test]
+fn elections_without_solution_fall_back_to_onchain_phragmen() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Staking::force_new_era(RuntimeOrigin::root()));
+
+        run_to_block(SessionPeriod::get());
+
+        assert!(election_events().iter().any(|event| matches!(
+            event,
+            ElectionEvent::ElectionFinalized {
+                compute: ElectionCompute::Fallback,
+                ..
+            }
+        )));
+        assert_eq!(
+            sorted(
+                pallet_staking::ErasStakersOverview::<Runtime>::iter_prefix(1)
+                    .map(|(validator, _)| validator)
+                    .collect()
+            ),
+            vec![account(1), account(2)]
+        );
+    });
+}
+
+#[test]
+fn signed_solutions_are_elected_and_rewarded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Staking::force_new_era(RuntimeOrigin::root()));
+        let signed_phase: u32 =
+            <Runtime as pallet_election_provider_multi_phase::Config>::SignedPhase::get();
+        let unsigned_phase: u32 =
+            <Runtime as pallet_election_provider_multi_phase::Config>::UnsignedPhase::get();
+
+        // The forced era is planned at the next session, both phases end right before.
+        run_to_block(SessionPeriod::get() - signed_phase - unsigned_phase);
+        assert!(ElectionProviderMultiPhase::current_phase().is_signed());
+
+        let (solution, _, _) = ElectionProviderMultiPhase::mine_solution().unwrap();
+        let free = Balances::free_balance(account(3));
+        assert_ok!(ElectionProviderMultiPhase::submit(
+            RuntimeOrigin::signed(account(3)),
+            Box::new(solution),
+        ));
+        assert!(Balances::reserved_balance(account(3)) >= SignedFixedDeposit::get());
+
+        run_to_block(SessionPeriod::get());
+
+        let events = election_events();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            ElectionEvent::ElectionFinalized {
+                compute: ElectionCompute::Signed,
+                ..
+            }
+        )));
+        assert!(events.contains(&ElectionEvent::Rewarded {
+            account: account(3),
+            value: SignedRewardBase::get(),
+        }));
+        // The deposit is returned and the call fee refunded on top of the reward.
+        assert_eq!(Balances::reserved_balance(account(3)), 0);
+        assert!(Balances::free_balance(account(3)) > free + SignedRewardBase::get());
+    });
+}
+
+#[test]
+fn election_snapshot_is_taken_within_a_block() {
+    type ElectionWeights = <Runtime as pallet_election_provider_multi_phase::Config>::WeightInfo;
+    let snapshot = ElectionWeights::create_snapshot_internal(
+        MaxElectingVoters::get(),
+        MaxElectableTargets::get(),
+    );
+    assert!(snapshot.all_lt(RuntimeBlockWeights::get().max_block));
+}
+
+#[test]
+fn unexposed_nominators_unstake_without_waiting_the_bonding_duration() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Staking::bond(
+            RuntimeOrigin::signed(account(3)),
+            2 * STASH,
+            pallet_staking::RewardDestination::Staked,
+        ));
+        assert_ok!(Staking::nominate(
+            RuntimeOrigin::signed(account(3)),
+            vec![account(1).into()],
+        ));
+        assert_ok!(FastUnstake::control(RuntimeOrigin::root(), 1));
+        assert_ok!(FastUnstake::register_fast_unstake(RuntimeOrigin::signed(
+            account(3)
+        )));
+        // The bond is held alongside the deposit.
+        assert_eq!(
+            Balances::reserved_balance(account(3)),
+            2 * STASH + FastUnstakeDeposit::get()
+        );
+
+        // One block checks the only era so far, the next one unbonds.
+        for _ in 0..2 {
+            FastUnstake::on_idle(System::block_number(), Weight::MAX);
+        }
+
+        assert!(pallet_staking::Ledger::<Runtime>::get(account(3)).is_none());
+        assert_eq!(Balances::reserved_balance(account(3)), 0);
+        assert_eq!(Balances::free_balance(account(3)), 10 * STASH);
+    });
+}
+
+#[test]
+fn stakers_get_the_most_inflation_at_half_the_issuance_staked() {
+    const YEAR: u64 = 36_525 * 24 * 60 * 60 * 10;
+    let issuance: Balance = 1_000_000_000 * AFT;
+    let yearly = |staked: Perbill| {
+        let (to_stakers, to_treasury) = <Runtime as pallet_staking::Config>::EraPayout::era_payout(
+            staked * issuance,
+            issuance,
+            YEAR,
+        );
+        (
+            Perbill::from_rational(to_stakers, issuance),
+            Perbill::from_rational(to_stakers + to_treasury, issuance),
+        )
+    };
+    let close_to = |rate: Perbill, percent: u32| {
+        rate.deconstruct()
+            .abs_diff(Perbill::from_rational(percent, 1_000).deconstruct())
+            < Perbill::from_rational(1u32, 1_000).deconstruct()
+    };
+
+    for staked in [0, 25, 50, 75, 100] {
+        let (_, minted) = yearly(Perbill::from_percent(staked));
+        assert!(close_to(minted, 80));
+    }
+    assert!(close_to(yearly(Perbill::zero()).0, 25));
+    assert!(close_to(yearly(Perbill::from_percent(50)).0, 80));
+    assert!(yearly(Perbill::from_percent(75)).0 < yearly(Perbill::from_percent(50)).0);
+}
+
+#[test]
+fn voter_bags_start_at_the_existential_deposit() {
+    let issuance: Balance = 1_000_000_000 * AFT;
+    assert_eq!(
+        U128CurrencyToVote::to_vote(ExistentialDeposit::get(), issuance),
+        voter_bags::EXISTENTIAL_WEIGHT
+    );
+    assert_eq!(voter_bags::THRESHOLDS[0], voter_bags::EXISTENTIAL_WEIGHT);
+    assert_eq!(voter_bags::THRESHOLDS.last(), Some(&u64::MAX));
+    assert!(voter_bags::THRESHOLDS.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn validators_neither_authoring_nor_heartbeating_are_reported_offline() {
+    new_test_ext().execute_with(|| {
+        <Runtime as pallet_authorship::Config>::EventHandler::note_author(account(2));
+        run_to_block(SessionPeriod::get());
+
+        System::assert_has_event(
+            pallet_im_online::Event::SomeOffline {
+                offline: vec![(account(1), ())],
+            }
+            .into(),
+        );
+        // One validator out of two being offline goes unpunished.
+        assert_eq!(
+            pallet_staking::UnappliedSlashes::<Runtime>::iter().count(),
+            0
+        );
+    });
+}
+
+#[test]
+fn grandpa_keys_are_proven_against_the_historical_sessions() {
+    new_test_ext().execute_with(|| {
+        let key = |n| {
+            (
+                sp_consensus_grandpa::KEY_TYPE,
+                GrandpaId::from(grandpa_pair(n).public()),
+            )
+        };
+
+        let proof = Historical::prove(key(1)).unwrap();
+        assert_eq!(
+            Historical::check_proof(key(1), proof),
+            Some((account(1), ()))
+        );
+        let proof = Historical::prove(key(3)).unwrap();
+        assert_eq!(Historical::check_proof(key(3), proof), None);
+    });
+}
+
+#[test]
+fn equivocations_are_slashed_after_the_defer_duration() {
+    new_test_ext().execute_with(|| {
+        let offence = pallet_grandpa::EquivocationOffence {
+            time_slot: pallet_grandpa::TimeSlot {
+                set_id: 0,
+                round: 1,
+            },
+            session_index: Session::current_index(),
+            validator_set_count: 2,
+            offender: (account(1), ()),
+        };
+        assert_ok!(Offences::report_offence(vec![account(2)], offence));
+
+        let slashes =
+            pallet_staking::UnappliedSlashes::<Runtime>::get(SlashDeferDuration::get() + 1);
+        assert_eq!(slashes.len(), 1);
+        assert_eq!(
+            (&slashes[0].validator, slashes[0].own),
+            (&account(1), STASH)
+        );
+        assert_eq!(slashes[0].reporters, vec![account(2)]);
+    });
+}
+
+#[test]
+fn equivocations_reported_by_the_local_node_are_slashed() {
+    new_test_ext().execute_with(|| {
+        let (set_id, round) = (Grandpa::current_set_id(), 1);
+        let signed_prevote = |target_hash| {
+            let prevote = finality_grandpa::Prevote {
+                target_hash,
+                target_number: 1,
+            };
+            let message = finality_grandpa::Message::Prevote(prevote.clone());
+            let payload = sp_consensus_grandpa::localized_payload(round, set_id, &message);
+            (prevote, grandpa_pair(1).sign(&payload).into())
+        };
+        let equivocation_proof = EquivocationProof::new(
+            set_id,
+            Equivocation::Prevote(finality_grandpa::Equivocation {
+                round_number: round,
+                identity: grandpa_pair(1).public().into(),
+                first: signed_prevote(H256::repeat_byte(1)),
+                second: signed_prevote(H256::repeat_byte(2)),
+            }),
+        );
+        let key_owner_proof = Historical::prove((
+            sp_consensus_grandpa::KEY_TYPE,
+            GrandpaId::from(grandpa_pair(1).public()),
+        ))
+        .unwrap();
+
+        // Reports are submitted by the node that saw the equivocation, never gossiped.
+        let call = pallet_grandpa::Call::report_equivocation_unsigned {
+            equivocation_proof: Box::new(equivocation_proof.clone()),
+            key_owner_proof: key_owner_proof.clone(),
+        };
+        assert_ok!(Grandpa::validate_unsigned(TransactionSource::Local, &call));
+        assert!(Grandpa::validate_unsigned(TransactionSource::External, &call).is_err());
+
+        assert_ok!(Grandpa::report_equivocation_unsigned(
+            RuntimeOrigin::none(),
+            Box::new(equivocation_proof),
+            key_owner_proof,
+        ));
+
+        let slashes =
+            pallet_staking::UnappliedSlashes::<Runtime>::get(SlashDeferDuration::get() + 1);
+        assert_eq!(slashes.len(), 1);
+        assert_eq!(
+            (&slashes[0].validator, slashes[0].own),
+            (&account(1), STASH)
+        );
+        assert!(slashes[0].reporters.is_empty());
+    });
+}
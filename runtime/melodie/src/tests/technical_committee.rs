@@ -0,0 +1,280 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Emergency powers of the technical committee, on the real runtime origins.
+
+use crate::{
+    AccountId, Balance, Balances, OriginCaller, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+    SafeMode, System, TechnicalCollective, TechnicalCommittee, TechnicalMembership, TxPause,
+    Whitelist, pallet_custom_origins,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::GetDispatchInfo,
+    traits::{Contains, fungible::Inspect},
+};
+use pallet_referenda::TracksInfo;
+use parity_scale_codec::Encode;
+use shared_runtime::currency::AFT;
+use sp_runtime::{
+    BuildStorage, DispatchError, DispatchResult,
+    traits::{Dispatchable, Hash, StaticLookup},
+};
+
+fn account(n: u8) -> AccountId {
+    AccountId::new([n; 32])
+}
+
+fn lookup(who: AccountId) -> <<Runtime as frame_system::Config>::Lookup as StaticLookup>::Source {
+    <Runtime as frame_system::Config>::Lookup::unlookup(who)
+}
+
+/// Accounts 1 to 3 sit on the committee, account 4 is a regular user.
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: (1..=4).map(|n| (account(n), 1_000 * AFT)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        for n in 1..=3 {
+            assert_ok!(TechnicalMembership::add_member(
+                RuntimeOrigin::root(),
+                lookup(account(n))
+            ));
+        }
+    });
+    ext
+}
+
+/// Proposes `call` to the committee, has every one of `ayes` approve it and returns the result
+/// of its execution.
+fn motion(call: RuntimeCall, ayes: &[AccountId]) -> DispatchResult {
+    let threshold = ayes.len() as u32;
+    let length = call.encoded_size() as u32;
+    let weight = call.get_dispatch_info().call_weight;
+    let hash = <Runtime as frame_system::Config>::Hashing::hash_of(&call);
+    let index = pallet_collective::ProposalCount::<Runtime, TechnicalCollective>::get();
+
+    TechnicalCommittee::propose(
+        RuntimeOrigin::signed(ayes[0].clone()),
+        threshold,
+        Box::new(call),
+        length,
+    )
+    .map_err(|e| e.error)?;
+    // Below two ayes the motion is executed right away.
+    if threshold > 1 {
+        for voter in ayes {
+            TechnicalCommittee::vote(RuntimeOrigin::signed(voter.clone()), hash, index, true)
+                .map_err(|e| e.error)?;
+        }
+        TechnicalCommittee::close(
+            RuntimeOrigin::signed(ayes[0].clone()),
+            hash,
+            index,
+            weight,
+            length,
+        )
+        .map_err(|e| e.error)?;
+    }
+
+    System::events()
+        .into_iter()
+        .rev()
+        .find_map(|record| match record.event {
+            RuntimeEvent::TechnicalCommittee(pallet_collective::Event::Executed {
+                result, ..
+            }) => Some(result),
+            _ => None,
+        })
+        .expect("the motion was executed")
+}
+
+fn transfer(amount: Balance) -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: lookup(account(1)),
+        value: amount,
+    })
+}
+
+#[test]
+fn membership_is_decided_by_root() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            pallet_collective::Members::<Runtime, TechnicalCollective>::get(),
+            vec![account(1), account(2), account(3)]
+        );
+
+        assert_noop!(
+            TechnicalMembership::add_member(RuntimeOrigin::signed(account(1)), lookup(account(4))),
+            DispatchError::BadOrigin
+        );
+        // The collective can't bypass the membership pallet, not even by Root.
+        assert_noop!(
+            TechnicalCommittee::set_members(RuntimeOrigin::root(), vec![account(4)], None, 3),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(TechnicalMembership::remove_member(
+            RuntimeOrigin::root(),
+            lookup(account(3))
+        ));
+        assert_eq!(
+            pallet_collective::Members::<Runtime, TechnicalCollective>::get(),
+            vec![account(1), account(2)]
+        );
+    });
+}
+
+#[test]
+fn two_thirds_of_the_committee_force_safe_mode() {
+    new_test_ext().execute_with(|| {
+        let force_enter = RuntimeCall::SafeMode(pallet_safe_mode::Call::force_enter {});
+
+        assert_eq!(
+            motion(force_enter.clone(), &[account(1)]),
+            Err(DispatchError::BadOrigin)
+        );
+        assert!(!SafeMode::is_entered());
+
+        assert_ok!(motion(force_enter, &[account(1), account(2)]));
+        assert!(SafeMode::is_entered());
+
+        // Transfers are filtered out, block production and governance are not.
+        assert_eq!(
+            transfer(AFT)
+                .dispatch(RuntimeOrigin::signed(account(4)))
+                .map_err(|e| e.error),
+            Err(frame_system::Error::<Runtime>::CallFiltered.into())
+        );
+        type Filter = <Runtime as frame_system::Config>::BaseCallFilter;
+        assert!(Filter::contains(&RuntimeCall::Timestamp(
+            pallet_timestamp::Call::set { now: 0 }
+        )));
+        assert!(Filter::contains(&RuntimeCall::SafeMode(
+            pallet_safe_mode::Call::force_exit {}
+        )));
+    });
+}
+
+#[test]
+fn the_committee_pauses_single_calls() {
+    new_test_ext().execute_with(|| {
+        let full_name = |pallet: &[u8], call: &[u8]| {
+            (
+                pallet.to_vec().try_into().unwrap(),
+                call.to_vec().try_into().unwrap(),
+            )
+        };
+        let pause = |pallet: &[u8], call: &[u8]| {
+            RuntimeCall::TxPause(pallet_tx_pause::Call::pause {
+                full_name: full_name(pallet, call),
+            })
+        };
+        let ayes = [account(1), account(2)];
+
+        assert_ok!(motion(pause(b"Balances", b"transfer_keep_alive"), &ayes));
+        assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(&transfer(AFT)));
+        assert_ok!(
+            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+                dest: lookup(account(1)),
+                value: AFT,
+            })
+            .dispatch(RuntimeOrigin::signed(account(4)))
+        );
+
+        assert_eq!(
+            motion(pause(b"Timestamp", b"set"), &ayes),
+            Err(pallet_tx_pause::Error::<Runtime>::Unpausable.into())
+        );
+
+        assert_ok!(motion(
+            RuntimeCall::TxPause(pallet_tx_pause::Call::unpause {
+                ident: full_name(b"Balances", b"transfer_keep_alive"),
+            }),
+            &ayes
+        ));
+        assert_ok!(transfer(AFT).dispatch(RuntimeOrigin::signed(account(4))));
+        assert!(!TxPause::is_paused(&full_name(
+            b"Balances",
+            b"transfer_keep_alive"
+        )));
+    });
+}
+
+#[test]
+fn whitelisted_calls_are_dispatched_as_root() {
+    new_test_ext().execute_with(|| {
+        let call = RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+            who: lookup(account(4)),
+            new_free: 42 * AFT,
+        });
+        let whitelisted_caller: RuntimeOrigin =
+            pallet_custom_origins::Origin::WhitelistedCaller.into();
+        let dispatch = || {
+            Whitelist::dispatch_whitelisted_call_with_preimage(
+                whitelisted_caller.clone(),
+                Box::new(call.clone()),
+            )
+        };
+
+        assert_noop!(
+            dispatch(),
+            pallet_whitelist::Error::<Runtime>::CallIsNotWhitelisted
+        );
+
+        let call_hash = <Runtime as frame_system::Config>::Hashing::hash_of(&call);
+        assert_eq!(
+            motion(
+                RuntimeCall::Whitelist(pallet_whitelist::Call::whitelist_call { call_hash }),
+                &[account(3)]
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(motion(
+            RuntimeCall::Whitelist(pallet_whitelist::Call::whitelist_call { call_hash }),
+            &[account(2), account(3)]
+        ));
+
+        assert_ok!(dispatch());
+        assert_eq!(Balances::balance(&account(4)), 42 * AFT);
+    });
+}
+
+#[test]
+fn whitelisted_caller_has_its_own_track() {
+    let origin = OriginCaller::Origins(pallet_custom_origins::Origin::WhitelistedCaller);
+    let track = <Runtime as pallet_referenda::Config>::Tracks::track_for(&origin);
+
+    assert_eq!(track, Ok(1));
+    let info = <Runtime as pallet_referenda::Config>::Tracks::info(1).unwrap();
+    assert!(
+        info.confirm_period
+            < <Runtime as pallet_referenda::Config>::Tracks::info(0)
+                .unwrap()
+                .confirm_period
+    );
+}
@@ -0,0 +1,129 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Team and investor allocations vesting from genesis, and artist advances paid vested.
+
+use crate::{AccountId, Balances, Runtime, RuntimeOrigin, System, Vesting};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{VestingSchedule, fungible::Inspect},
+};
+use pallet_vesting::VestingInfo;
+use shared_runtime::currency::AFT;
+use sp_runtime::{BuildStorage, TokenError};
+
+const INVESTOR: u8 = 1;
+const LABEL: u8 = 2;
+const ARTIST: u8 = 3;
+
+fn account(n: u8) -> AccountId {
+    AccountId::new([n; 32])
+}
+
+/// [`INVESTOR`] keeps 100 AFT liquid, the other 900 AFT unlock over 100 blocks from block 10.
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: (1..=3).map(|n| (account(n), 1_000 * AFT)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    pallet_vesting::GenesisConfig::<Runtime> {
+        vesting: vec![(account(INVESTOR), 10, 100, 100 * AFT)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+#[test]
+fn genesis_allocations_unlock_linearly() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            Vesting::vesting_balance(&account(INVESTOR)),
+            Some(900 * AFT)
+        );
+        assert_noop!(
+            Balances::transfer_allow_death(
+                RuntimeOrigin::signed(account(INVESTOR)),
+                account(LABEL).into(),
+                200 * AFT,
+            ),
+            TokenError::Frozen
+        );
+
+        // Half of the schedule has elapsed.
+        System::set_block_number(60);
+        assert_ok!(Vesting::vest(RuntimeOrigin::signed(account(INVESTOR))));
+        assert_eq!(
+            Vesting::vesting_balance(&account(INVESTOR)),
+            Some(450 * AFT)
+        );
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(account(INVESTOR)),
+            account(LABEL).into(),
+            500 * AFT,
+        ));
+
+        System::set_block_number(110);
+        assert_ok!(Vesting::vest(RuntimeOrigin::signed(account(INVESTOR))));
+        assert_eq!(Vesting::vesting_balance(&account(INVESTOR)), None);
+    });
+}
+
+#[test]
+fn artist_advances_are_paid_as_vested_transfers() {
+    new_test_ext().execute_with(|| {
+        // A 120 AFT advance, unlocking at 1 AFT per block.
+        assert_ok!(Vesting::vested_transfer(
+            RuntimeOrigin::signed(account(LABEL)),
+            account(ARTIST).into(),
+            VestingInfo::new(120 * AFT, AFT, 1),
+        ));
+        assert_eq!(Balances::balance(&account(ARTIST)), 1_120 * AFT);
+        assert_eq!(Vesting::vesting_balance(&account(ARTIST)), Some(120 * AFT));
+
+        System::set_block_number(21);
+        assert_ok!(Vesting::vest(RuntimeOrigin::signed(account(ARTIST))));
+        assert_eq!(Vesting::vesting_balance(&account(ARTIST)), Some(100 * AFT));
+
+        assert_noop!(
+            Vesting::vested_transfer(
+                RuntimeOrigin::signed(account(LABEL)),
+                account(ARTIST).into(),
+                VestingInfo::new(AFT / 2, AFT / 100, 1),
+            ),
+            pallet_vesting::Error::<Runtime>::AmountLow
+        );
+    });
+}
+
+#[test]
+fn development_preset_vests_team_and_investor_allocations() {
+    let preset =
+        crate::genesis::get_preset(&sp_genesis_builder::DEV_RUNTIME_PRESET.into()).unwrap();
+    let patch: serde_json::Value = serde_json::from_slice(&preset).unwrap();
+
+    assert_eq!(patch["vesting"]["vesting"].as_array().unwrap().len(), 2);
+}
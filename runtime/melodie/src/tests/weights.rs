@@ -0,0 +1,189 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Runtime;
+use core::any::type_name;
+use frame_support::instances::{Instance1, Instance2, Instance3};
+
+/// Oldest benchmark CLI the runtime's weights may be generated with. Bump it when an SDK
+/// upgrade changes what the pallets benchmark, so that their weights get regenerated.
+const BENCHMARK_CLI_VERSION: u32 = 53;
+
+macro_rules! weight_infos {
+    ($($pallet:ident: $config:path),* $(,)?) => {
+        vec![
+            (
+                "System",
+                type_name::<<Runtime as frame_system::Config>::SystemWeightInfo>(),
+            ),
+            $((
+                stringify!($pallet),
+                type_name::<<Runtime as $config>::WeightInfo>(),
+            )),*
+        ]
+    };
+}
+
+/// `WeightInfo` of each benchmarked pallet of the runtime.
+fn weight_infos() -> Vec<(&'static str, &'static str)> {
+    weight_infos!(
+        Balances: pallet_balances::Config,
+        Grandpa: pallet_grandpa::Config,
+        Ats: pallet_ats::Config,
+        MetaTx: pallet_meta_tx::Config,
+        Multisig: pallet_multisig::Config,
+        Preimage: pallet_preimage::Config,
+        Proxy: pallet_proxy::Config,
+        Recovery: pallet_recovery::Config,
+        Identity: pallet_identity::Config,
+        Contracts: pallet_contracts::Config,
+        Scheduler: pallet_scheduler::Config,
+        Sudo: pallet_sudo::Config,
+        Timestamp: pallet_timestamp::Config,
+        Utility: pallet_utility::Config,
+        Vesting: pallet_vesting::Config,
+        Staking: pallet_staking::Config,
+        VoterList: pallet_bags_list::Config<Instance1>,
+        ElectionProviderMultiPhase: pallet_election_provider_multi_phase::Config,
+        FastUnstake: pallet_fast_unstake::Config,
+        ImOnline: pallet_im_online::Config,
+        VerifySignature: pallet_verify_signature::Config,
+        TransactionPayment: pallet_transaction_payment::Config,
+        SafeMode: pallet_safe_mode::Config,
+        MusicalWorks: pallet_midds::Config<Instance1>,
+        Recordings: pallet_midds::Config<Instance2>,
+        Releases: pallet_midds::Config<Instance3>,
+        SoundRecordings: pallet_recordings::Config,
+        ImportWindows: pallet_import_windows::Config,
+        SpamTelemetry: pallet_spam_telemetry::Config,
+        ImportStaging: pallet_import_staging::Config,
+        CatalogReleases: pallet_releases::Config,
+        Royalties: pallet_royalties::Config,
+        Licensing: pallet_licensing::Config,
+        CatalogTransfers: pallet_catalog_transfers::Config,
+        Certification: pallet_certification::Config,
+        UsageOracle: pallet_usage_oracle::Config,
+        DeferredWork: pallet_deferred_work::Config,
+        Anchoring: pallet_anchoring::Config,
+        UpgradeNotice: pallet_upgrade_notice::Config,
+        Ticketing: pallet_ticketing::Config,
+        Crowdfunding: pallet_crowdfunding::Config,
+        Escrow: pallet_escrow::Config,
+        Sales: pallet_sales::Config,
+        Subscriptions: pallet_subscriptions::Config,
+        RegistryAttestations: pallet_registry_attestations::Config,
+        Genres: pallet_genres::Config,
+        Badges: pallet_badges::Config,
+        Artists: pallet_artists::Config,
+        Treasury: pallet_treasury::Config,
+        Bounties: pallet_bounties::Config,
+        ChildBounties: pallet_child_bounties::Config,
+        Sponsorship: pallet_sponsorship::Config,
+        CatalogRoot: pallet_catalog_root::Config,
+        Nfts: pallet_nfts::Config,
+        AssetConversion: pallet_asset_conversion::Config,
+        AssetTxPayment: pallet_asset_conversion_tx_payment::Config,
+        Referenda: pallet_referenda::Config,
+        ConvictionVoting: pallet_conviction_voting::Config,
+        TechnicalCommittee: pallet_collective::Config<Instance1>,
+        TechnicalMembership: pallet_membership::Config<Instance1>,
+        Whitelist: pallet_whitelist::Config,
+        TxPause: pallet_tx_pause::Config,
+    )
+}
+
+/// Runtime pallets listed in `define_benchmarks!`, but the baseline.
+fn benchmarked_pallets() -> Vec<&'static str> {
+    include_str!("../benchmarks.rs")
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix('[')?.strip_suffix(']'))
+        .filter_map(|entry| entry.split_once(", ").map(|(_, pallet)| pallet))
+        .filter(|pallet| *pallet != "BaselineBench::<Runtime>")
+        .map(|pallet| pallet.strip_suffix("Bench::<Runtime>").unwrap_or(pallet))
+        .collect()
+}
+
+/// What generated the weights of a pallet.
+enum Weights {
+    /// An Allfeat weight file, by the given major version of the benchmark CLI.
+    Cli(u32),
+    /// Zero weights, the pallet authors' weights, or an Allfeat weight file the benchmark CLI
+    /// didn't generate.
+    None,
+}
+
+/// How the weights of `weight_info` were generated.
+fn weights(weight_info: &str) -> Weights {
+    weight_info
+        .split_once("::weights::")
+        .and_then(|(krate, path)| allfeat_weights(krate, path))
+        .map_or(Weights::None, Weights::Cli)
+}
+
+/// Benchmark CLI major version that generated the Allfeat weight file of `path`, in the
+/// `weights` module of `krate`.
+fn allfeat_weights(krate: &str, path: &str) -> Option<u32> {
+    let (module, weight) = path.split_once("::")?;
+    if !weight.starts_with("AllfeatWeight<") {
+        return None;
+    }
+    let dir = match krate {
+        "melodie_runtime" => "src/weights",
+        "shared_runtime" => "../shared/src/weights",
+        _ => return None,
+    };
+    let file = format!("{}/{dir}/{module}.rs", env!("CARGO_MANIFEST_DIR"));
+    let source = std::fs::read_to_string(&file).unwrap_or_else(|_| panic!("{file} not found"));
+    source.lines().find_map(|line| {
+        let (_, version) = line.split_once("SUBSTRATE BENCHMARK CLI VERSION ")?;
+        version.split('.').next()?.parse().ok()
+    })
+}
+
+#[test]
+fn every_benchmarked_pallet_is_checked() {
+    let mut benchmarked = benchmarked_pallets();
+    let mut checked: Vec<_> = weight_infos()
+        .into_iter()
+        .map(|(pallet, _)| pallet)
+        .collect();
+    benchmarked.sort_unstable();
+    checked.sort_unstable();
+
+    assert_eq!(benchmarked, checked);
+}
+
+#[test]
+fn benchmarked_pallets_run_on_fresh_generated_weights() {
+    let mut failures = Vec::new();
+    for (pallet, weight_info) in weight_infos() {
+        match weights(weight_info) {
+            Weights::None => failures.push(format!(
+                "{pallet} runs on `{weight_info}`, which the benchmark CLI didn't generate for \
+                 the runtime: run `just benchmark-weights-testnet`"
+            )),
+            Weights::Cli(version) if version < BENCHMARK_CLI_VERSION => failures.push(format!(
+                "{pallet} weights were generated with benchmark CLI {version}, before \
+                 {BENCHMARK_CLI_VERSION}: regenerate them"
+            )),
+            _ => {}
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,241 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated voter bag thresholds.
+//!
+//! Generated the way `substrate/utils/frame/generate-bags` does, with:
+//! - total issuance: 1_000_000_000 AFT,
+//! - minimum balance: 0.1 AFT (the existential deposit),
+//! - 200 bags.
+//!
+//! Melodie's issuance depends on its genesis preset, the mainnet supply is used as the reference
+//! instead. Regenerate the file if the existential deposit changes.
+
+/// Existential weight for this runtime.
+#[cfg(any(test, feature = "std"))]
+#[allow(unused)]
+pub const EXISTENTIAL_WEIGHT: u64 = 1_851_851_851;
+
+/// Constant ratio between bags for this runtime.
+#[cfg(any(test, feature = "std"))]
+#[allow(unused)]
+pub const CONSTANT_RATIO: f64 = 1.1226458656245017;
+
+/// Upper thresholds delimiting the bag list.
+pub const THRESHOLDS: [u64; 200] = [
+    1_851_851_851,
+    2_078_973_824,
+    2_333_951_368,
+    2_620_200_854,
+    2_941_557_656,
+    3_302_327_541,
+    3_707_344_361,
+    4_162_034_819,
+    4_672_491_182,
+    5_245_552_908,
+    5_888_898_285,
+    6_611_147_313,
+    7_421_977_198,
+    8_332_252_016,
+    9_354_168_277,
+    10_501_418_343,
+    11_789_373_886,
+    13_235_291_851,
+    14_858_545_677,
+    16_680_884_873,
+    18_726_726_438,
+    21_023_482_012,
+    23_601_925_162,
+    26_496_603_704,
+    29_746_302_601,
+    33_394_563_633,
+    37_490_268_797,
+    42_088_295_266,
+    47_250_250_672,
+    53_045_298_567,
+    59_551_085_127,
+    66_854_779_511,
+    75_054_241_815,
+    84_259_334_271,
+    94_593_393_260,
+    106_194_881_859,
+    119_219_245_069,
+    133_840_992_580,
+    150_256_036_971,
+    168_684_318_691,
+    189_372_752_974,
+    212_598_538_188,
+    238_672_869_935,
+    267_945_110_669,
+    300_807_470_707,
+    337_700_263_338,
+    379_117_804_457,
+    425_615_035_758,
+    477_814_960_241,
+    536_416_989_648,
+    602_206_315_679,
+    676_064_430_550,
+    758_980_937_853,
+    852_066_811_968,
+    956_569_283_692,
+    1_073_888_551_520,
+    1_205_596_542_505,
+    1_353_457_974_054,
+    1_519_453_998_868,
+    1_705_808_749_836,
+    1_915_019_140_549,
+    2_149_888_320_729,
+    2_413_563_234_821,
+    2_709_576_786_995,
+    3_041_895_177_512,
+    3_414_971_044_697,
+    3_833_803_124_556,
+    4_304_003_227_401,
+    4_831_871_428_876,
+    5_424_480_482_857,
+    6_089_770_587_240,
+    6_836_655_772_367,
+    7_675_143_337_546,
+    8_616_467_935_971,
+    9_673_242_104_604,
+    10_859_625_255_919,
+    12_191_513_395_789,
+    13_686_752_109_488,
+    15_365_375_669_544,
+    17_249_875_469_181,
+    19_365_501_378_014,
+    21_740_600_057_773,
+    24_406_994_771_055,
+    27_400_411_772_044,
+    30_760_958_992_294,
+    34_533_663_435_344,
+    38_769_074_480_557,
+    43_523_941_179_686,
+    48_861_972_621_058,
+    54_854_691_549_288,
+    61_582_392_677_915,
+    69_135_218_535_126,
+    77_614_367_257_506,
+    87_133_448_514_701,
+    97_820_005_732_634,
+    109_817_225_011_107,
+    123_285_853_633_075,
+    138_406_353_871_159,
+    155_381_320_949_618,
+    174_438_197_559_362,
+    195_832_321_297_008,
+    219_850_345_859_735,
+    246_814_081_835_548,
+    277_084_808_550_585,
+    311_068_114_746_671,
+    349_219_332_947_958,
+    392_049_640_330_171,
+    440_132_907_836_239,
+    494_113_389_307_644,
+    554_714_353_655_936,
+    622_747_775_734_404,
+    699_125_215_755_083,
+    784_870_033_021_282,
+    881_131_097_623_908,
+    989_198_183_820_660,
+    1_110_519_251_349_530,
+    1_246_719_846_223_967,
+    1_399_624_880_955_351,
+    1_571_283_086_029_710,
+    1_763_994_460_256_962,
+    1_980_341_087_792_003,
+    2_223_221_734_736_021,
+    2_495_890_688_867_927,
+    2_802_001_362_908_268,
+    3_145_655_245_543_186,
+    3_531_456_856_089_085,
+    3_964_575_439_119_713,
+    4_450_814_225_684_190,
+    4_996_688_189_127_074,
+    5_609_511_337_338_288,
+    6_297_494_711_036_600,
+    7_069_856_401_137_406,
+    7_936_945_059_295_828,
+    8_910_378_556_507_278,
+    10_003_199_647_612_112,
+    11_230_050_727_408_210,
+    12_607_370_019_878_256,
+    14_153_611_829_214_616,
+    15_889_493_803_721_830,
+    17_838_274_525_614_450,
+    20_026_065_146_055_932,
+    22_482_179_240_946_624,
+    25_239_525_575_077_728,
+    28_335_049_037_184_888,
+    31_810_225_653_863_132,
+    35_711_618_314_891_908,
+    40_091_500_655_973_640,
+    45_008_557_458_110_808,
+    50_528_670_948_070_936,
+    56_725_803_535_352_712,
+    63_682_988_813_191_464,
+    71_493_444_101_740_792,
+    80_261_819_440_075_728,
+    90_105_599_761_901_280,
+    101_156_679_042_314_560,
+    113_563_127_507_159_120,
+    127_491_175_583_300_320,
+    143_127_441_172_199_536,
+    160_681_430_089_383_904,
+    180_388_343_172_479_264,
+    202_512_227_669_437_664,
+    227_349_515_131_502_016,
+    255_232_993_214_115_840,
+    286_536_264_602_793_664,
+    321_678_752_807_814_592,
+    361_131_321_898_939_136,
+    405_422_585_477_355_136,
+    455_145_989_416_948_928,
+    510_967_763_274_510_912,
+    573_635_846_907_528_768,
+    643_989_911_904_746_752,
+    722_972_612_103_751_040,
+    811_642_213_938_022_656,
+    911_186_775_843_838_464,
+    1_022_940_066_712_804_864,
+    1_148_399_436_676_782_336,
+    1_289_245_879_670_696_448,
+    1_447_366_556_585_731_328,
+    1_624_880_080_794_142_720,
+    1_824_164_904_839_150_592,
+    2_047_891_188_634_984_960,
+    2_299_056_576_169_912_576,
+    2_581_026_360_073_974_784,
+    2_897_578_572_204_904_448,
+    3_252_954_604_407_982_592,
+    3_651_916_037_702_808_064,
+    4_099_808_441_334_869_504,
+    4_602_632_996_517_023_744,
+    5_167_126_904_526_748_672,
+    5_800_853_656_524_084_224,
+    6_512_304_374_589_536_256,
+    7_311_011_581_821_299_712,
+    8_207_676_925_864_530_944,
+    9_214_314_567_203_435_520,
+    10_344_412_153_434_556_416,
+    11_613_111_536_369_154_048,
+    13_037_411_653_341_036_544,
+    14_636_396_291_068_014_592,
+    16_431_489_783_809_298_432,
+    18_446_744_073_709_551_615,
+];
@@ -0,0 +1,58 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_anchoring`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_anchoring` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_anchoring::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: `Anchoring::LastSequence` (r:1 w:1)
+	/// Proof: `Anchoring::LastSequence` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Anchoring::ContentIndex` (r:1 w:1)
+	/// Proof: `Anchoring::ContentIndex` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Anchoring::Anchors` (r:0 w:1)
+	/// Proof: `Anchoring::Anchors` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	fn anchor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `36`
+		//  Estimated: `5030`
+		// Minimum execution time: 13_038_000 picoseconds.
+		Weight::from_parts(14_034_000, 5030)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
@@ -0,0 +1,216 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_artists`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_artists` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_artists::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::NextArtistId` (r:1 w:1)
+	/// Proof: `Artists::NextArtistId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::Artists` (r:0 w:1)
+	/// Proof: `Artists::Artists` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 4]`.
+	fn create_artist(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `26`
+		//  Estimated: `5564`
+		// Minimum execution time: 25_089_000 picoseconds.
+		Weight::from_parts(26_224_450, 5564)
+			// Standard Error: 725_238
+			.saturating_add(Weight::from_parts(1_561_070, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Artists::Artists` (r:1 w:1)
+	/// Proof: `Artists::Artists` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::Proposals` (r:0 w:1)
+	/// Proof: `Artists::Proposals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn propose() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `2588`
+		// Minimum execution time: 15_066_000 picoseconds.
+		Weight::from_parts(16_689_000, 2588)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Artists::Artists` (r:1 w:0)
+	/// Proof: `Artists::Artists` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::Proposals` (r:1 w:1)
+	/// Proof: `Artists::Proposals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn approve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `260`
+		//  Estimated: `5161`
+		// Minimum execution time: 15_029_000 picoseconds.
+		Weight::from_parts(19_849_000, 5161)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Artists::Proposals` (r:1 w:1)
+	/// Proof: `Artists::Proposals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::Artists` (r:1 w:1)
+	/// Proof: `Artists::Artists` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn cancel_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `268`
+		//  Estimated: `5161`
+		// Minimum execution time: 20_325_000 picoseconds.
+		Weight::from_parts(21_240_000, 5161)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Artists::Artists` (r:1 w:1)
+	/// Proof: `Artists::Artists` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::Proposals` (r:1 w:1)
+	/// Proof: `Artists::Proposals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn execute_update() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `227`
+		//  Estimated: `5161`
+		// Minimum execution time: 18_833_000 picoseconds.
+		Weight::from_parts(24_857_000, 5161)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Artists::Artists` (r:1 w:1)
+	/// Proof: `Artists::Artists` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::Proposals` (r:1 w:1)
+	/// Proof: `Artists::Proposals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn execute_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `304`
+		//  Estimated: `10271`
+		// Minimum execution time: 57_523_000 picoseconds.
+		Weight::from_parts(63_665_000, 10271)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Artists::Artists` (r:1 w:1)
+	/// Proof: `Artists::Artists` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::Proposals` (r:3 w:3)
+	/// Proof: `Artists::Proposals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::WorkArtists` (r:1 w:0)
+	/// Proof: `Artists::WorkArtists` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 2]`.
+	fn execute_dissolve(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `284 + p * (49 ±0)`
+		//  Estimated: `15284 + p * (2573 ±0)`
+		// Minimum execution time: 57_160_000 picoseconds.
+		Weight::from_parts(61_959_133, 15284)
+			// Standard Error: 218_675
+			.saturating_add(Weight::from_parts(4_230_600, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2573).saturating_mul(p.into()))
+	}
+	/// Storage: `Artists::WorkArtists` (r:1 w:1)
+	/// Proof: `Artists::WorkArtists` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::PowSeed` (r:1 w:0)
+	/// Proof: `Artists::PowSeed` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::WorkCounts` (r:1 w:1)
+	/// Proof: `Artists::WorkCounts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::PowRegistrations` (r:1 w:1)
+	/// Proof: `Artists::PowRegistrations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::PowDifficulty` (r:1 w:0)
+	/// Proof: `Artists::PowDifficulty` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::NextArtistId` (r:1 w:1)
+	/// Proof: `Artists::NextArtistId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::Artists` (r:0 w:1)
+	/// Proof: `Artists::Artists` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 4]`.
+	fn create_artist_with_work(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `7030`
+		// Minimum execution time: 15_875_000 picoseconds.
+		Weight::from_parts(21_253_500, 7030)
+			// Standard Error: 74_780
+			.saturating_add(Weight::from_parts(550_830, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Artists::PowRegistrations` (r:1 w:1)
+	/// Proof: `Artists::PowRegistrations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::PowDifficulty` (r:1 w:1)
+	/// Proof: `Artists::PowDifficulty` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::ParentHash` (r:1 w:0)
+	/// Proof: `System::ParentHash` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::PowSeed` (r:0 w:1)
+	/// Proof: `Artists::PowSeed` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn adjust_pow_difficulty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1525`
+		// Minimum execution time: 7_230_000 picoseconds.
+		Weight::from_parts(7_797_000, 1525)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Artists::WorkArtists` (r:1 w:0)
+	/// Proof: `Artists::WorkArtists` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::PowSeed` (r:1 w:0)
+	/// Proof: `Artists::PowSeed` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::WorkCounts` (r:1 w:0)
+	/// Proof: `Artists::WorkCounts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::PowRegistrations` (r:1 w:0)
+	/// Proof: `Artists::PowRegistrations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Artists::PowDifficulty` (r:1 w:0)
+	/// Proof: `Artists::PowDifficulty` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn check_artist_work() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `6531`
+		// Minimum execution time: 9_103_000 picoseconds.
+		Weight::from_parts(9_539_000, 6531)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+	}
+}
@@ -0,0 +1,121 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_badges`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_badges` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_badges::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: `Badges::Issuers` (r:1 w:1)
+	/// Proof: `Badges::Issuers` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn add_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2503`
+		// Minimum execution time: 6_617_000 picoseconds.
+		Weight::from_parts(7_142_000, 2503)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Badges::Issuers` (r:1 w:1)
+	/// Proof: `Badges::Issuers` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn remove_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `31`
+		//  Estimated: `2503`
+		// Minimum execution time: 8_616_000 picoseconds.
+		Weight::from_parts(9_216_000, 2503)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Badges::Issuers` (r:1 w:1)
+	/// Proof: `Badges::Issuers` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Badges::NextClassId` (r:1 w:1)
+	/// Proof: `Badges::NextClassId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Badges::Classes` (r:0 w:1)
+	/// Proof: `Badges::Classes` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	fn create_class() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `3002`
+		// Minimum execution time: 12_663_000 picoseconds.
+		Weight::from_parts(13_591_000, 3002)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Badges::Issuers` (r:1 w:0)
+	/// Proof: `Badges::Issuers` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Badges::Badges` (r:1 w:1)
+	/// Proof: `Badges::Badges` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Badges::Classes` (r:1 w:1)
+	/// Proof: `Badges::Classes` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	fn award() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `112`
+		//  Estimated: `7530`
+		// Minimum execution time: 16_532_000 picoseconds.
+		Weight::from_parts(17_660_000, 7530)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Badges::Classes` (r:1 w:1)
+	/// Proof: `Badges::Classes` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Badges::Badges` (r:1 w:1)
+	/// Proof: `Badges::Badges` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn revoke() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `162`
+		//  Estimated: `5027`
+		// Minimum execution time: 15_878_000 picoseconds.
+		Weight::from_parts(16_619_000, 5027)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Badges::Badges` (r:1 w:1)
+	/// Proof: `Badges::Badges` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Badges::Classes` (r:1 w:1)
+	/// Proof: `Badges::Classes` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	fn renounce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `162`
+		//  Estimated: `5027`
+		// Minimum execution time: 14_895_000 picoseconds.
+		Weight::from_parts(15_794_000, 5027)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
@@ -0,0 +1,63 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_catalog_root`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_catalog_root` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_catalog_root::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a616c6c666561742f63` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a616c6c666561742f63` (r:1 w:0)
+	fn on_finalize() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `51`
+		//  Estimated: `51`
+		// Minimum execution time: 11_575_000 picoseconds.
+		Weight::from_parts(12_501_000, 51)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `CatalogRoot::EntityCounts` (r:9 w:0)
+	/// Proof: `CatalogRoot::EntityCounts` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn deposit_statistics() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `158`
+		//  Estimated: `22419`
+		// Minimum execution time: 22_170_000 picoseconds.
+		Weight::from_parts(24_425_000, 22419)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+	}
+}
@@ -0,0 +1,128 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_catalog_transfers`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_catalog_transfers` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_catalog_transfers::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:0)
+	/// Storage: `CatalogTransfers::PendingAssets` (r:3 w:3)
+	/// Proof: `CatalogTransfers::PendingAssets` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657201000000` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657201000000` (r:1 w:0)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657202000000` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657202000000` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogTransfers::NextTransferId` (r:1 w:1)
+	/// Proof: `CatalogTransfers::NextTransferId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogTransfers::Offers` (r:0 w:1)
+	/// Proof: `CatalogTransfers::Offers` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[1, 3]`.
+	fn offer_transfer(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `5661 + a * (2499 ±0)`
+		// Minimum execution time: 33_947_000 picoseconds.
+		Weight::from_parts(32_173_166, 5661)
+			// Standard Error: 164_816
+			.saturating_add(Weight::from_parts(3_551_750, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2499).saturating_mul(a.into()))
+	}
+	/// Storage: `CatalogTransfers::Offers` (r:1 w:1)
+	/// Proof: `CatalogTransfers::Offers` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:1)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657201000000` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657201000000` (r:1 w:1)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657202000000` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657202000000` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogTransfers::ProvenanceLength` (r:3 w:3)
+	/// Proof: `CatalogTransfers::ProvenanceLength` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogTransfers::Provenance` (r:0 w:6)
+	/// Proof: `CatalogTransfers::Provenance` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogTransfers::PendingAssets` (r:0 w:3)
+	/// Proof: `CatalogTransfers::PendingAssets` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[1, 3]`.
+	fn accept_transfer(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338 + a * (33 ±0)`
+		//  Estimated: `10490 + a * (2532 ±0)`
+		// Minimum execution time: 61_114_000 picoseconds.
+		Weight::from_parts(53_505_433, 10490)
+			// Standard Error: 198_977
+			.saturating_add(Weight::from_parts(9_709_400, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2532).saturating_mul(a.into()))
+	}
+	/// Storage: `CatalogTransfers::Offers` (r:1 w:1)
+	/// Proof: `CatalogTransfers::Offers` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogTransfers::PendingAssets` (r:0 w:3)
+	/// Proof: `CatalogTransfers::PendingAssets` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[1, 3]`.
+	fn cancel_offer(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187 + a * (4 ±0)`
+		//  Estimated: `7597`
+		// Minimum execution time: 28_110_000 picoseconds.
+		Weight::from_parts(28_254_200, 7597)
+			// Standard Error: 110_522
+			.saturating_add(Weight::from_parts(986_800, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+	}
+}
@@ -0,0 +1,144 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_crowdfunding`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_crowdfunding` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_crowdfunding::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f726f79616c74792d7368617265000000000000000000000000` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f726f79616c74792d7368617265000000000000000000000000` (r:1 w:0)
+	/// Storage: `Crowdfunding::NextRoundId` (r:1 w:1)
+	/// Proof: `Crowdfunding::NextRoundId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdfunding::Rounds` (r:0 w:1)
+	/// Proof: `Crowdfunding::Rounds` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn open_round() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `25`
+		//  Estimated: `524`
+		// Minimum execution time: 8_909_000 picoseconds.
+		Weight::from_parts(9_506_000, 524)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Crowdfunding::Rounds` (r:1 w:1)
+	/// Proof: `Crowdfunding::Rounds` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdfunding::Contributions` (r:1 w:1)
+	/// Proof: `Crowdfunding::Contributions` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	fn contribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `10116`
+		// Minimum execution time: 32_226_000 picoseconds.
+		Weight::from_parts(33_404_000, 10116)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Crowdfunding::Rounds` (r:1 w:1)
+	/// Proof: `Crowdfunding::Rounds` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f726f79616c74792d7368617265000000000000000000000000` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f726f79616c74792d7368617265000000000000000000000000` (r:1 w:1)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f726f79616c74792d7368617265000000006d6f646c70792f63` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f726f79616c74792d7368617265000000006d6f646c70792f63` (r:1 w:1)
+	/// Storage: `Crowdfunding::Contributions` (r:4 w:0)
+	/// Proof: `Crowdfunding::Contributions` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:3 w:3)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:4 w:4)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 3]`.
+	fn settle_funded(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176 + b * (132 ±0)`
+		//  Estimated: `7782 + b * (7716 ±0)`
+		// Minimum execution time: 45_301_000 picoseconds.
+		Weight::from_parts(23_542_333, 7782)
+			// Standard Error: 203_836
+			.saturating_add(Weight::from_parts(25_374_050, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 7716).saturating_mul(b.into()))
+	}
+	/// Storage: `Crowdfunding::Rounds` (r:1 w:1)
+	/// Proof: `Crowdfunding::Rounds` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdfunding::Contributions` (r:4 w:3)
+	/// Proof: `Crowdfunding::Contributions` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:3 w:3)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 3]`.
+	fn settle_failed(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `98 + b * (132 ±0)`
+		//  Estimated: `5051 + b * (7584 ±0)`
+		// Minimum execution time: 34_248_000 picoseconds.
+		Weight::from_parts(12_548_733, 5051)
+			// Standard Error: 168_040
+			.saturating_add(Weight::from_parts(23_588_200, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 7584).saturating_mul(b.into()))
+	}
+	/// Storage: `Crowdfunding::Rounds` (r:1 w:0)
+	/// Proof: `Crowdfunding::Rounds` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdfunding::Contributions` (r:1 w:0)
+	/// Proof: `Crowdfunding::Contributions` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdfunding::TotalClaimed` (r:1 w:1)
+	/// Proof: `Crowdfunding::TotalClaimed` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdfunding::Claimed` (r:1 w:1)
+	/// Proof: `Crowdfunding::Claimed` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `260`
+		//  Estimated: `15175`
+		// Minimum execution time: 42_988_000 picoseconds.
+		Weight::from_parts(45_464_000, 15175)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
@@ -0,0 +1,145 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_import_staging`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_import_staging` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_import_staging::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f696d706f72746572124695bb2d0e7655` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f696d706f72746572124695bb2d0e7655` (r:1 w:0)
+	/// Storage: `DeferredWork::QueueDepth` (r:1 w:1)
+	/// Proof: `DeferredWork::QueueDepth` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `ImportStaging::NextBatchId` (r:1 w:1)
+	/// Proof: `ImportStaging::NextBatchId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DeferredWork::NextTaskId` (r:1 w:1)
+	/// Proof: `DeferredWork::NextTaskId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DeferredWork::Tasks` (r:0 w:1)
+	/// Proof: `DeferredWork::Tasks` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `ImportStaging::Batches` (r:0 w:1)
+	/// Proof: `ImportStaging::Batches` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `ImportStaging::Entries` (r:0 w:4)
+	/// Proof: `ImportStaging::Entries` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// The range of component `e` is `[1, 4]`.
+	fn stage(e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `75`
+		//  Estimated: `6637`
+		// Minimum execution time: 36_715_000 picoseconds.
+		Weight::from_parts(36_951_500, 6637)
+			// Standard Error: 445_765
+			.saturating_add(Weight::from_parts(1_657_610, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(e.into())))
+	}
+	/// Storage: `ImportStaging::Batches` (r:1 w:1)
+	/// Proof: `ImportStaging::Batches` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f696d706f72746572124695bb2d0e7655` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f696d706f72746572124695bb2d0e7655` (r:1 w:0)
+	/// Storage: `ImportStaging::Entries` (r:4 w:4)
+	/// Proof: `ImportStaging::Entries` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f776f726b01000000` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f776f726b01000000` (r:1 w:1)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f776f726b02000000` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f776f726b02000000` (r:1 w:1)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f776f726b03000000` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f776f726b03000000` (r:1 w:1)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f776f726b04000000` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f776f726b04000000` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// The range of component `e` is `[1, 4]`.
+	fn commit(e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `208 + e * (22 ±0)`
+		//  Estimated: `7818 + e * (2530 ±0)`
+		// Minimum execution time: 37_501_000 picoseconds.
+		Weight::from_parts(19_533_300, 7818)
+			// Standard Error: 1_336_043
+			.saturating_add(Weight::from_parts(14_361_350, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2530).saturating_mul(e.into()))
+	}
+	/// Storage: `ImportStaging::Batches` (r:1 w:1)
+	/// Proof: `ImportStaging::Batches` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `ImportStaging::Entries` (r:4 w:4)
+	/// Proof: `ImportStaging::Entries` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// The range of component `e` is `[1, 4]`.
+	fn discard(e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176 + e * (22 ±0)`
+		//  Estimated: `7610 + e * (2508 ±0)`
+		// Minimum execution time: 31_976_000 picoseconds.
+		Weight::from_parts(31_387_700, 7610)
+			// Standard Error: 148_401
+			.saturating_add(Weight::from_parts(1_760_250, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2508).saturating_mul(e.into()))
+	}
+	/// Storage: `ImportStaging::Batches` (r:1 w:1)
+	/// Proof: `ImportStaging::Batches` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `ImportStaging::Entries` (r:1 w:0)
+	/// Proof: `ImportStaging::Entries` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f776f726b01000000` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f776f726b01000000` (r:1 w:0)
+	fn validate_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `5198`
+		// Minimum execution time: 16_409_000 picoseconds.
+		Weight::from_parts(17_236_000, 5198)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
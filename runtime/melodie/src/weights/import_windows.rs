@@ -0,0 +1,139 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_import_windows`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_import_windows` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_import_windows::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: `ImportWindows::Importers` (r:1 w:1)
+	/// Proof: `ImportWindows::Importers` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `ImportWindows::ImporterCount` (r:1 w:1)
+	/// Proof: `ImportWindows::ImporterCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn add_importer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `86`
+		//  Estimated: `2998`
+		// Minimum execution time: 17_241_000 picoseconds.
+		Weight::from_parts(19_258_000, 2998)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ImportWindows::Importers` (r:1 w:1)
+	/// Proof: `ImportWindows::Importers` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `ImportWindows::ImporterCount` (r:1 w:1)
+	/// Proof: `ImportWindows::ImporterCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn remove_importer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115`
+		//  Estimated: `2998`
+		// Minimum execution time: 18_129_000 picoseconds.
+		Weight::from_parts(19_520_000, 2998)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ImportWindows::Windows` (r:1 w:1)
+	/// Proof: `ImportWindows::Windows` (`max_values`: Some(1), `max_size`: Some(65), added: 560, mode: `MaxEncodedLen`)
+	/// Storage: `ImportWindows::NextWindowId` (r:1 w:1)
+	/// Proof: `ImportWindows::NextWindowId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn schedule_window() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `65`
+		//  Estimated: `1059`
+		// Minimum execution time: 16_286_000 picoseconds.
+		Weight::from_parts(18_043_000, 1059)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ImportWindows::Windows` (r:1 w:1)
+	/// Proof: `ImportWindows::Windows` (`max_values`: Some(1), `max_size`: Some(65), added: 560, mode: `MaxEncodedLen`)
+	/// Storage: `ImportWindows::QuotaUsed` (r:3 w:3)
+	/// Proof: `ImportWindows::QuotaUsed` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn cancel_window() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `227`
+		//  Estimated: `8105`
+		// Minimum execution time: 21_520_000 picoseconds.
+		Weight::from_parts(24_848_000, 8105)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ImportWindows::Windows` (r:1 w:0)
+	/// Proof: `ImportWindows::Windows` (`max_values`: Some(1), `max_size`: Some(65), added: 560, mode: `MaxEncodedLen`)
+	fn on_initialize_noop() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `98`
+		//  Estimated: `560`
+		// Minimum execution time: 7_258_000 picoseconds.
+		Weight::from_parts(8_230_000, 560)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ImportWindows::Windows` (r:1 w:1)
+	/// Proof: `ImportWindows::Windows` (`max_values`: Some(1), `max_size`: Some(65), added: 560, mode: `MaxEncodedLen`)
+	/// Storage: `ImportWindows::QuotaUsed` (r:6 w:6)
+	/// Proof: `ImportWindows::QuotaUsed` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `w` is `[1, 2]`.
+	fn on_initialize_close(w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `43 + w * (151 ±0)`
+		//  Estimated: `560 + w * (7545 ±0)`
+		// Minimum execution time: 18_159_000 picoseconds.
+		Weight::from_parts(9_451_900, 560)
+			// Standard Error: 256_654
+			.saturating_add(Weight::from_parts(11_595_400, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(w.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(w.into())))
+			.saturating_add(Weight::from_parts(0, 7545).saturating_mul(w.into()))
+	}
+	/// Storage: `ImportWindows::Windows` (r:1 w:0)
+	/// Proof: `ImportWindows::Windows` (`max_values`: Some(1), `max_size`: Some(65), added: 560, mode: `MaxEncodedLen`)
+	/// Storage: `ImportWindows::Importers` (r:1 w:0)
+	/// Proof: `ImportWindows::Importers` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `ImportWindows::QuotaUsed` (r:1 w:1)
+	/// Proof: `ImportWindows::QuotaUsed` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn prioritize_catalog_import() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `167`
+		//  Estimated: `5574`
+		// Minimum execution time: 16_347_000 picoseconds.
+		Weight::from_parts(18_086_000, 5574)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
@@ -16,9 +16,22 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod anchoring;
+pub mod artists;
 pub mod ats;
+pub mod badges;
 pub mod balances;
+pub mod catalog_root;
+pub mod catalog_transfers;
+pub mod certification;
+pub mod crowdfunding;
+pub mod deferred_work;
+pub mod escrow;
+pub mod genres;
 pub mod grandpa;
+pub mod import_staging;
+pub mod import_windows;
+pub mod licensing;
 pub mod meta_tx;
 pub mod midds_musical_works;
 pub mod midds_recordings;
@@ -26,12 +39,22 @@ pub mod midds_releases;
 pub mod multisig;
 pub mod preimage;
 pub mod proxy;
+pub mod recordings;
+pub mod registry_attestations;
+pub mod releases;
+pub mod royalties;
 pub mod safe_mode;
+pub mod sales;
 pub mod scheduler;
+pub mod spam_telemetry;
+pub mod sponsorship;
+pub mod subscriptions;
 pub mod sudo;
 pub mod system;
+pub mod ticketing;
 pub mod timestamp;
 pub mod transaction_payment;
+pub mod upgrade_notice;
+pub mod usage_oracle;
 pub mod utility;
-pub mod validators;
 pub mod verify_signature;
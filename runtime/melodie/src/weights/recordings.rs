@@ -0,0 +1,182 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_recordings`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_recordings` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_recordings::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: `SoundRecordings::Recordings` (r:1 w:1)
+	/// Proof: `SoundRecordings::Recordings` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `SoundRecordings::ContentIndex` (r:1 w:1)
+	/// Proof: `SoundRecordings::ContentIndex` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogRoot::EntityCounts` (r:1 w:1)
+	/// Proof: `CatalogRoot::EntityCounts` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x737265633046525a303331383030323132` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x737265633046525a303331383030323132` (r:1 w:1)
+	/// The range of component `w` is `[1, 3]`.
+	fn anchor(w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `53`
+		//  Estimated: `12702`
+		// Minimum execution time: 47_516_000 picoseconds.
+		Weight::from_parts(52_981_100, 12702)
+			// Standard Error: 357_491
+			.saturating_add(Weight::from_parts(240_750, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `SoundRecordings::Recordings` (r:1 w:1)
+	/// Proof: `SoundRecordings::Recordings` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x737265633046525a303331383030323132` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x737265633046525a303331383030323132` (r:1 w:1)
+	/// The range of component `w` is `[1, 3]`.
+	fn set_works(w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `236`
+		//  Estimated: `2809`
+		// Minimum execution time: 19_966_000 picoseconds.
+		Weight::from_parts(29_645_033, 2809)
+			// Standard Error: 345_950
+			.saturating_add(Weight::from_parts(5_500, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SoundRecordings::Recordings` (r:1 w:1)
+	/// Proof: `SoundRecordings::Recordings` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogRoot::EntityCounts` (r:1 w:1)
+	/// Proof: `CatalogRoot::EntityCounts` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `SoundRecordings::ContentIndex` (r:0 w:1)
+	/// Proof: `SoundRecordings::ContentIndex` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `SoundRecordings::Certifications` (r:0 w:1)
+	/// Proof: `SoundRecordings::Certifications` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x737265633046525a303331383030323132` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x737265633046525a303331383030323132` (r:1 w:1)
+	fn remove() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `10469`
+		// Minimum execution time: 55_549_000 picoseconds.
+		Weight::from_parts(69_296_000, 10469)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `SoundRecordings::Recordings` (r:1 w:0)
+	/// Proof: `SoundRecordings::Recordings` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `SoundRecordings::Certifications` (r:1 w:1)
+	/// Proof: `SoundRecordings::Certifications` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
+	fn certify() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `324`
+		//  Estimated: `5246`
+		// Minimum execution time: 20_779_000 picoseconds.
+		Weight::from_parts(21_965_000, 5246)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SoundRecordings::Certifications` (r:1 w:1)
+	/// Proof: `SoundRecordings::Certifications` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
+	fn revoke_certification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `276`
+		//  Estimated: `2673`
+		// Minimum execution time: 14_707_000 picoseconds.
+		Weight::from_parts(16_659_000, 2673)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SoundRecordings::SealedAnchors` (r:1 w:1)
+	/// Proof: `SoundRecordings::SealedAnchors` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	fn commit_anchor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `53`
+		//  Estimated: `7620`
+		// Minimum execution time: 41_854_000 picoseconds.
+		Weight::from_parts(46_010_000, 7620)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `SoundRecordings::SealedAnchors` (r:1 w:1)
+	/// Proof: `SoundRecordings::SealedAnchors` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `SoundRecordings::Recordings` (r:1 w:1)
+	/// Proof: `SoundRecordings::Recordings` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `SoundRecordings::ContentIndex` (r:1 w:1)
+	/// Proof: `SoundRecordings::ContentIndex` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogRoot::EntityCounts` (r:1 w:1)
+	/// Proof: `CatalogRoot::EntityCounts` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x737265633046525a303331383030323132` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x737265633046525a303331383030323132` (r:1 w:1)
+	/// The range of component `w` is `[1, 3]`.
+	fn reveal_anchor(w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `84`
+		//  Estimated: `10223`
+		// Minimum execution time: 30_849_000 picoseconds.
+		Weight::from_parts(33_442_100, 10223)
+			// Standard Error: 194_879
+			.saturating_add(Weight::from_parts(801_150, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `SoundRecordings::SealedAnchors` (r:1 w:1)
+	/// Proof: `SoundRecordings::SealedAnchors` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	fn withdraw_commitment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `159`
+		//  Estimated: `7620`
+		// Minimum execution time: 35_334_000 picoseconds.
+		Weight::from_parts(41_683_000, 7620)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
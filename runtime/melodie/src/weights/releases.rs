@@ -0,0 +1,147 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_releases`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_releases` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_releases::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: `CatalogReleases::Releases` (r:1 w:1)
+	/// Proof: `CatalogReleases::Releases` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[1, 3]`.
+	fn create_release(t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `53`
+		//  Estimated: `7620`
+		// Minimum execution time: 24_478_000 picoseconds.
+		Weight::from_parts(25_777_200, 7620)
+			// Standard Error: 109_807
+			.saturating_add(Weight::from_parts(9_550, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CatalogReleases::Releases` (r:1 w:1)
+	/// Proof: `CatalogReleases::Releases` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[1, 3]`.
+	fn set_tracks(t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `70`
+		//  Estimated: `2555`
+		// Minimum execution time: 7_223_000 picoseconds.
+		Weight::from_parts(7_712_100, 2555)
+			// Standard Error: 36_422
+			.saturating_add(Weight::from_parts(92_950, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CatalogReleases::Releases` (r:1 w:1)
+	/// Proof: `CatalogReleases::Releases` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogReleases::Titles` (r:1 w:0)
+	/// Proof: `CatalogReleases::Titles` (`max_values`: None, `max_size`: Some(475), added: 2950, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogRoot::EntityCounts` (r:1 w:1)
+	/// Proof: `CatalogRoot::EntityCounts` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogReleases::LanguageIndex` (r:0 w:3)
+	/// Proof: `CatalogReleases::LanguageIndex` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x72656c733434303036333831333333393331` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x72656c733434303036333831333333393331` (r:1 w:1)
+	/// The range of component `t` is `[1, 3]`.
+	/// The range of component `l` is `[0, 3]`.
+	fn publish(t: u32, l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `65 + t * (11 ±2) + l * (121 ±1)`
+		//  Estimated: `8061 + t * (11 ±2) + l * (121 ±1)`
+		// Minimum execution time: 13_136_000 picoseconds.
+		Weight::from_parts(12_649_161, 8061)
+			// Standard Error: 215_658
+			.saturating_add(Weight::from_parts(811_563, 0).saturating_mul(t.into()))
+			// Standard Error: 142_644
+			.saturating_add(Weight::from_parts(3_130_073, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(l.into())))
+			.saturating_add(Weight::from_parts(0, 11).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 121).saturating_mul(l.into()))
+	}
+	/// Storage: `CatalogReleases::Releases` (r:1 w:1)
+	/// Proof: `CatalogReleases::Releases` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogReleases::Titles` (r:1 w:1)
+	/// Proof: `CatalogReleases::Titles` (`max_values`: None, `max_size`: Some(475), added: 2950, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogRoot::EntityCounts` (r:1 w:1)
+	/// Proof: `CatalogRoot::EntityCounts` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogReleases::LanguageIndex` (r:0 w:3)
+	/// Proof: `CatalogReleases::LanguageIndex` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x72656c733434303036333831333333393331` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x72656c733434303036333831333333393331` (r:1 w:1)
+	/// The range of component `l` is `[0, 3]`.
+	fn remove(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `314 + l * (131 ±3)`
+		//  Estimated: `13374 + l * (132 ±3)`
+		// Minimum execution time: 35_710_000 picoseconds.
+		Weight::from_parts(39_142_020, 13374)
+			// Standard Error: 176_534
+			.saturating_add(Weight::from_parts(3_021_620, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(l.into())))
+			.saturating_add(Weight::from_parts(0, 132).saturating_mul(l.into()))
+	}
+	/// Storage: `CatalogReleases::Releases` (r:1 w:0)
+	/// Proof: `CatalogReleases::Releases` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `CatalogReleases::Titles` (r:0 w:1)
+	/// Proof: `CatalogReleases::Titles` (`max_values`: None, `max_size`: Some(475), added: 2950, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 3]`.
+	fn set_titles(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `70`
+		//  Estimated: `2555`
+		// Minimum execution time: 8_341_000 picoseconds.
+		Weight::from_parts(8_359_800, 2555)
+			// Standard Error: 54_507
+			.saturating_add(Weight::from_parts(683_700, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,218 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_royalties`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_royalties` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_royalties::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:0)
+	/// Storage: `Royalties::Splits` (r:1 w:0)
+	/// Proof: `Royalties::Splits` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::Proposals` (r:0 w:1)
+	/// Proof: `Royalties::Proposals` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 3]`.
+	fn propose_split(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `29`
+		//  Estimated: `2570`
+		// Minimum execution time: 9_450_000 picoseconds.
+		Weight::from_parts(9_419_166, 2570)
+			// Standard Error: 28_606
+			.saturating_add(Weight::from_parts(255_450, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Royalties::Proposals` (r:1 w:1)
+	/// Proof: `Royalties::Proposals` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::Splits` (r:1 w:1)
+	/// Proof: `Royalties::Splits` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 3]`.
+	fn co_sign(_c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `27 + c * (20 ±0)`
+		//  Estimated: `5130`
+		// Minimum execution time: 11_070_000 picoseconds.
+		Weight::from_parts(12_089_166, 5130)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:0)
+	/// Storage: `Royalties::Proposals` (r:1 w:1)
+	/// Proof: `Royalties::Proposals` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	fn withdraw_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `101`
+		//  Estimated: `2690`
+		// Minimum execution time: 10_668_000 picoseconds.
+		Weight::from_parts(11_171_000, 2690)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:0)
+	/// Storage: `Royalties::Splits` (r:1 w:1)
+	/// Proof: `Royalties::Splits` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::Proposals` (r:0 w:1)
+	/// Proof: `Royalties::Proposals` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	fn lock_split() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113`
+		//  Estimated: `2654`
+		// Minimum execution time: 11_342_000 picoseconds.
+		Weight::from_parts(11_845_000, 2654)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Royalties::Splits` (r:4 w:0)
+	/// Proof: `Royalties::Splits` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::AllowedPayers` (r:4 w:0)
+	/// Proof: `Royalties::AllowedPayers` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::NextDistributionId` (r:1 w:1)
+	/// Proof: `Royalties::NextDistributionId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::StatementCount` (r:1 w:1)
+	/// Proof: `Royalties::StatementCount` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::Distributions` (r:0 w:1)
+	/// Proof: `Royalties::Distributions` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::Statements` (r:0 w:1)
+	/// Proof: `Royalties::Statements` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// The range of component `e` is `[1, 4]`.
+	fn distribute(e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `72 + e * (91 ±0)`
+		//  Estimated: `8077 + e * (5053 ±0)`
+		// Minimum execution time: 46_465_000 picoseconds.
+		Weight::from_parts(41_928_850, 8077)
+			// Standard Error: 140_489
+			.saturating_add(Weight::from_parts(6_970_780, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 5053).saturating_mul(e.into()))
+	}
+	/// Storage: `Royalties::Splits` (r:1 w:0)
+	/// Proof: `Royalties::Splits` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:4 w:4)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::Earnings` (r:3 w:3)
+	/// Proof: `Royalties::Earnings` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::Statements` (r:1 w:1)
+	/// Proof: `Royalties::Statements` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::Ledger` (r:0 w:1)
+	/// Proof: `Royalties::Ledger` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 3]`.
+	fn pay_entry(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `177 + c * (32 ±2)`
+		//  Estimated: `10159 + c * (5098 ±0)`
+		// Minimum execution time: 36_175_000 picoseconds.
+		Weight::from_parts(17_441_533, 10159)
+			// Standard Error: 171_150
+			.saturating_add(Weight::from_parts(20_559_250, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 5098).saturating_mul(c.into()))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::TotalDistributed` (r:1 w:1)
+	/// Proof: `Royalties::TotalDistributed` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::Distributions` (r:0 w:1)
+	/// Proof: `Royalties::Distributions` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn complete_distribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `5578`
+		// Minimum execution time: 20_239_000 picoseconds.
+		Weight::from_parts(21_465_000, 5578)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Royalties::Statements` (r:1 w:1)
+	/// Proof: `Royalties::Statements` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::Distributions` (r:1 w:0)
+	/// Proof: `Royalties::Distributions` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Royalties::Ledger` (r:4 w:4)
+	/// Proof: `Royalties::Ledger` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// The range of component `e` is `[1, 4]`.
+	fn prune_statement(e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `205 + e * (49 ±0)`
+		//  Estimated: `10178 + e * (2628 ±0)`
+		// Minimum execution time: 30_656_000 picoseconds.
+		Weight::from_parts(30_413_900, 10178)
+			// Standard Error: 139_583
+			.saturating_add(Weight::from_parts(2_300_680, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2628).saturating_mul(e.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6d6f636b2f61737365742d6f776e657200000000` (r:1 w:0)
+	/// Storage: `Royalties::AllowedPayers` (r:0 w:1)
+	/// Proof: `Royalties::AllowedPayers` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 2]`.
+	fn set_allowed_payers(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `29`
+		//  Estimated: `29`
+		// Minimum execution time: 6_798_000 picoseconds.
+		Weight::from_parts(7_459_866, 29)
+			// Standard Error: 28_434
+			.saturating_add(Weight::from_parts(15_300, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,177 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_ticketing`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_ticketing` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_ticketing::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: `Ticketing::NextEventId` (r:1 w:1)
+	/// Proof: `Ticketing::NextEventId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::LiveEvents` (r:0 w:1)
+	/// Proof: `Ticketing::LiveEvents` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 3]`.
+	fn create_event(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `499`
+		// Minimum execution time: 4_901_000 picoseconds.
+		Weight::from_parts(5_144_866, 499)
+			// Standard Error: 63_365
+			.saturating_add(Weight::from_parts(257_800, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Ticketing::LiveEvents` (r:1 w:0)
+	/// Proof: `Ticketing::LiveEvents` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::Scanners` (r:1 w:1)
+	/// Proof: `Ticketing::Scanners` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn add_scanner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `68`
+		//  Estimated: `5060`
+		// Minimum execution time: 9_943_000 picoseconds.
+		Weight::from_parts(10_759_000, 5060)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Ticketing::LiveEvents` (r:1 w:0)
+	/// Proof: `Ticketing::LiveEvents` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::Scanners` (r:1 w:1)
+	/// Proof: `Ticketing::Scanners` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn remove_scanner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `125`
+		//  Estimated: `5060`
+		// Minimum execution time: 11_156_000 picoseconds.
+		Weight::from_parts(11_719_000, 5060)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Ticketing::LiveEvents` (r:1 w:1)
+	/// Proof: `Ticketing::LiveEvents` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::NextTicketId` (r:1 w:1)
+	/// Proof: `Ticketing::NextTicketId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::Tickets` (r:0 w:1)
+	/// Proof: `Ticketing::Tickets` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	fn buy_ticket() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `211`
+		//  Estimated: `8162`
+		// Minimum execution time: 32_005_000 picoseconds.
+		Weight::from_parts(33_686_000, 8162)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Ticketing::Tickets` (r:1 w:1)
+	/// Proof: `Ticketing::Tickets` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::Scanners` (r:1 w:0)
+	/// Proof: `Ticketing::Scanners` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::Listings` (r:0 w:1)
+	/// Proof: `Ticketing::Listings` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `182`
+		//  Estimated: `5019`
+		// Minimum execution time: 13_869_000 picoseconds.
+		Weight::from_parts(15_101_000, 5019)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Ticketing::Tickets` (r:1 w:1)
+	/// Proof: `Ticketing::Tickets` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::LiveEvents` (r:1 w:0)
+	/// Proof: `Ticketing::LiveEvents` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::Listings` (r:1 w:0)
+	/// Proof: `Ticketing::Listings` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn transfer_ticket() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `150`
+		//  Estimated: `7556`
+		// Minimum execution time: 12_982_000 picoseconds.
+		Weight::from_parts(14_033_000, 7556)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Ticketing::Tickets` (r:1 w:0)
+	/// Proof: `Ticketing::Tickets` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::LiveEvents` (r:1 w:0)
+	/// Proof: `Ticketing::LiveEvents` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::Listings` (r:0 w:1)
+	/// Proof: `Ticketing::Listings` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn list_ticket() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `150`
+		//  Estimated: `5057`
+		// Minimum execution time: 11_535_000 picoseconds.
+		Weight::from_parts(12_379_000, 5057)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Ticketing::Tickets` (r:1 w:0)
+	/// Proof: `Ticketing::Tickets` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::Listings` (r:1 w:1)
+	/// Proof: `Ticketing::Listings` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn delist_ticket() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `170`
+		//  Estimated: `5007`
+		// Minimum execution time: 11_459_000 picoseconds.
+		Weight::from_parts(12_280_000, 5007)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Ticketing::Listings` (r:1 w:1)
+	/// Proof: `Ticketing::Listings` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::Tickets` (r:1 w:1)
+	/// Proof: `Ticketing::Tickets` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Ticketing::LiveEvents` (r:1 w:0)
+	/// Proof: `Ticketing::LiveEvents` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn buy_listed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `431`
+		//  Estimated: `15221`
+		// Minimum execution time: 54_290_000 picoseconds.
+		Weight::from_parts(56_412_000, 15221)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
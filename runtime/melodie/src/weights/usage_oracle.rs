@@ -0,0 +1,150 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_usage_oracle`, not generated against the runtime
+//!
+//! MEASURED BY RUNNING THE PALLET BENCHMARKS NATIVELY AGAINST ITS TEST MOCK, FRAME-BENCHMARKING 46.0.0
+//! DATE: 2026-10-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: `Native`, CHAIN: `None`, GENESIS: `pallet_usage_oracle` test mock
+//!
+//! Not benchmark CLI output: the mock's storage and configuration differ from the Melodie
+//! runtime's. `just benchmark-weights-testnet` replaces this file on the reference hardware,
+//! and the runtime's weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_usage_oracle::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: `UsageOracle::Providers` (r:1 w:1)
+	/// Proof: `UsageOracle::Providers` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `UsageOracle::ProviderCount` (r:1 w:1)
+	/// Proof: `UsageOracle::ProviderCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	fn register_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `53`
+		//  Estimated: `8080`
+		// Minimum execution time: 28_949_000 picoseconds.
+		Weight::from_parts(30_191_000, 8080)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `UsageOracle::Providers` (r:1 w:1)
+	/// Proof: `UsageOracle::Providers` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn unregister_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `64`
+		//  Estimated: `2516`
+		// Minimum execution time: 9_454_000 picoseconds.
+		Weight::from_parts(9_961_000, 2516)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `UsageOracle::Providers` (r:1 w:1)
+	/// Proof: `UsageOracle::Providers` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// Storage: `UsageOracle::ProviderCount` (r:1 w:1)
+	/// Proof: `UsageOracle::ProviderCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn withdraw_bond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `147`
+		//  Estimated: `8080`
+		// Minimum execution time: 29_454_000 picoseconds.
+		Weight::from_parts(30_887_000, 8080)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `UsageOracle::Providers` (r:1 w:0)
+	/// Proof: `UsageOracle::Providers` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `UsageOracle::Submissions` (r:1 w:1)
+	/// Proof: `UsageOracle::Submissions` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `UsageOracle::SubmissionCount` (r:1 w:1)
+	/// Proof: `UsageOracle::SubmissionCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// The range of component `e` is `[1, 3]`.
+	fn submit_report(_e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `64`
+		//  Estimated: `7543`
+		// Minimum execution time: 13_420_000 picoseconds.
+		Weight::from_parts(14_810_466, 7543)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `UsageOracle::Aggregates` (r:1 w:1)
+	/// Proof: `UsageOracle::Aggregates` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `UsageOracle::SubmissionCount` (r:1 w:1)
+	/// Proof: `UsageOracle::SubmissionCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `UsageOracle::Submissions` (r:5 w:4)
+	/// Proof: `UsageOracle::Submissions` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `UsageOracle::Providers` (r:1 w:1)
+	/// Proof: `UsageOracle::Providers` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 4]`.
+	/// The range of component `e` is `[1, 3]`.
+	fn finalize_period(p: u32, e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `166 + p * (97 ±3)`
+		//  Estimated: `5278 + p * (4730 ±238) + e * (798 ±361)`
+		// Minimum execution time: 20_517_000 picoseconds.
+		Weight::from_parts(21_795_000, 5278)
+			// Standard Error: 614_925
+			.saturating_add(Weight::from_parts(12_890_556, 0).saturating_mul(p.into()))
+			// Standard Error: 756_181
+			.saturating_add(Weight::from_parts(669_876, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 4730).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 798).saturating_mul(e.into()))
+	}
+	/// Storage: `UsageOracle::Providers` (r:1 w:1)
+	/// Proof: `UsageOracle::Providers` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	fn slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `139`
+		//  Estimated: `7581`
+		// Minimum execution time: 27_185_000 picoseconds.
+		Weight::from_parts(27_802_000, 7581)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
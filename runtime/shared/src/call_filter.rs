@@ -0,0 +1,182 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Which calls stay available when the chain is put on hold.
+//!
+//! - [`SafeModeWhitelistedCalls`]: calls `pallet_safe_mode` lets through while safe mode is on.
+//! - [`TxPauseWhitelistedCalls`]: calls `pallet_tx_pause` refuses to pause.
+//!
+//! Both keep blocks produced and finalized, and leave governance able to lift the hold: the
+//! emergency origins, referenda and their voting, the technical committee. User activity, remarks
+//! included, is what gets halted.
+//!
+//! Pallets are matched by their name in the runtime, so a runtime lacking one of them simply never
+//! matches it. Calls dispatched as Root bypass the call filter anyway.
+
+use frame_support::{
+    BoundedVec,
+    traits::{Contains, Get, GetCallMetadata},
+};
+
+/// The calls of a pallet a policy covers.
+enum Calls {
+    All,
+    Except(&'static [&'static str]),
+}
+
+type Policy = &'static [(&'static str, Calls)];
+
+/// Calls of the `System` pallet users dispatch for themselves.
+const USER_SYSTEM_CALLS: &[&str] = &["remark", "remark_with_event"];
+
+/// Shared by both policies: block production and finality, the emergency origins and the
+/// governance rescue path.
+const RESCUE_CALLS: Policy = &[
+    ("System", Calls::Except(USER_SYSTEM_CALLS)),
+    ("Timestamp", Calls::All),
+    ("Grandpa", Calls::All),
+    ("Sudo", Calls::All),
+    ("SafeMode", Calls::All),
+    ("TxPause", Calls::All),
+    ("Preimage", Calls::All),
+    ("Referenda", Calls::All),
+    ("ConvictionVoting", Calls::All),
+    ("Whitelist", Calls::All),
+    ("TechnicalCommittee", Calls::All),
+    ("TechnicalMembership", Calls::All),
+];
+
+fn covers(policy: Policy, pallet: &[u8], call: &[u8]) -> bool {
+    policy.iter().any(|(name, calls)| {
+        name.as_bytes() == pallet
+            && match calls {
+                Calls::All => true,
+                Calls::Except(excluded) => !excluded.iter().any(|c| c.as_bytes() == call),
+            }
+    })
+}
+
+/// Calls that keep working while safe mode is on.
+pub struct SafeModeWhitelistedCalls;
+impl<RuntimeCall: GetCallMetadata> Contains<RuntimeCall> for SafeModeWhitelistedCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        let metadata = call.get_call_metadata();
+        covers(
+            RESCUE_CALLS,
+            metadata.pallet_name.as_bytes(),
+            metadata.function_name.as_bytes(),
+        )
+    }
+}
+
+/// Calls that can't be paused, named as `pallet_tx_pause` does: `(pallet, call)`.
+pub struct TxPauseWhitelistedCalls;
+impl<MaxNameLen: Get<u32>> Contains<(BoundedVec<u8, MaxNameLen>, BoundedVec<u8, MaxNameLen>)>
+    for TxPauseWhitelistedCalls
+{
+    fn contains((pallet, call): &(BoundedVec<u8, MaxNameLen>, BoundedVec<u8, MaxNameLen>)) -> bool {
+        covers(RESCUE_CALLS, pallet, call)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::traits::{CallMetadata, ConstU32};
+
+    /// A call known by its names only.
+    struct Call(&'static str, &'static str);
+    impl GetCallMetadata for Call {
+        fn get_module_names() -> &'static [&'static str] {
+            &[]
+        }
+        fn get_call_names(_module: &str) -> &'static [&'static str] {
+            &[]
+        }
+        fn get_call_metadata(&self) -> CallMetadata {
+            CallMetadata {
+                pallet_name: self.0,
+                function_name: self.1,
+            }
+        }
+    }
+
+    const ALLOWED: &[(&str, &str)] = &[
+        ("System", "apply_authorized_upgrade"),
+        ("System", "set_code"),
+        ("Timestamp", "set"),
+        ("Grandpa", "report_equivocation_unsigned"),
+        ("Sudo", "sudo"),
+        ("SafeMode", "force_exit"),
+        ("TxPause", "unpause"),
+        ("Preimage", "note_preimage"),
+        ("Referenda", "submit"),
+        ("Referenda", "place_decision_deposit"),
+        ("ConvictionVoting", "vote"),
+        ("Whitelist", "dispatch_whitelisted_call_with_preimage"),
+        ("TechnicalCommittee", "propose"),
+        ("TechnicalMembership", "add_member"),
+    ];
+
+    const HALTED: &[(&str, &str)] = &[
+        ("System", "remark"),
+        ("System", "remark_with_event"),
+        ("Balances", "transfer_keep_alive"),
+        ("Utility", "batch_all"),
+        ("Proxy", "proxy"),
+        ("Assets", "transfer"),
+        ("Nfts", "mint"),
+        ("Ats", "create"),
+        ("Session", "set_keys"),
+        // Names are matched exactly.
+        ("system", "set_code"),
+        ("Referendum", "submit"),
+    ];
+
+    fn by_safe_mode(pallet: &'static str, call: &'static str) -> bool {
+        SafeModeWhitelistedCalls::contains(&Call(pallet, call))
+    }
+
+    fn by_tx_pause(pallet: &str, call: &str) -> bool {
+        let name = |s: &str| BoundedVec::<u8, ConstU32<64>>::try_from(s.as_bytes().to_vec());
+        TxPauseWhitelistedCalls::contains(&(name(pallet).unwrap(), name(call).unwrap()))
+    }
+
+    #[test]
+    fn safe_mode_lets_rescue_calls_through() {
+        for &(pallet, call) in ALLOWED {
+            assert!(by_safe_mode(pallet, call), "{pallet}::{call} is halted");
+        }
+        for &(pallet, call) in HALTED {
+            assert!(
+                !by_safe_mode(pallet, call),
+                "{pallet}::{call} is let through"
+            );
+        }
+    }
+
+    #[test]
+    fn rescue_calls_can_not_be_paused() {
+        for &(pallet, call) in ALLOWED {
+            assert!(by_tx_pause(pallet, call), "{pallet}::{call} is pausable");
+        }
+        for &(pallet, call) in HALTED {
+            assert!(!by_tx_pause(pallet, call), "{pallet}::{call} is unpausable");
+        }
+    }
+}
@@ -0,0 +1,372 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Pallet configurations common to the Allfeat runtimes.
+//!
+//! [`impl_allfeat_common_configs!`](crate::impl_allfeat_common_configs) expands the `Config`
+//! impls, and the parameter types they use, of the pallets every Allfeat runtime configures the
+//! same way, so that they don't drift apart as runtimes grow. It takes a list of sections, one per
+//! pallet, each carrying the values that legitimately differ between runtimes:
+//!
+//! - `system { ss58_prefix, base_call_filter, single_block_migrations }`: `frame_system`, along
+//!   with the creation of the unsigned transactions the runtime submits itself.
+//! - `balances { existential_deposit }`: `pallet_balances`.
+//! - `timestamp`: `pallet_timestamp`, fed to Aura.
+//! - `utility`: `pallet_utility`.
+//! - `safe_mode { force_enter_origin }`: `pallet_safe_mode`.
+//! - `tx_pause { pause_origin }`: `pallet_tx_pause`.
+//! - `artists`: `pallet_artists`.
+//! - `midds { musical_works, recordings, releases }`: the three `pallet_midds` instances, given
+//!   their benchmark helpers.
+//!
+//! Sections can be expanded all at once, or one by one in the module of each pallet, which then
+//! owns the parameter types of the section. The expansion names the runtime's types as
+//! `construct_runtime` and the `crate::*` prelude of the runtimes define them (`Runtime`,
+//! `RuntimeEvent`, `Balances`, `AccountId`, `weights`...).
+//!
+//! ```ignore
+//! shared_runtime::impl_allfeat_common_configs! {
+//!     balances { existential_deposit: AFT / 10 }
+//!     timestamp
+//!     utility
+//! }
+//! ```
+
+/// Expands the common pallet configurations of the Allfeat runtimes. See [`crate::configs`].
+#[macro_export]
+macro_rules! impl_allfeat_common_configs {
+    () => {};
+
+    (
+        system {
+            ss58_prefix: $ss58_prefix:expr,
+            base_call_filter: $base_call_filter:ty,
+            single_block_migrations: $single_block_migrations:ty $(,)?
+        }
+        $($rest:tt)*
+    ) => {
+        frame_support::parameter_types! {
+            pub const Version: sp_version::RuntimeVersion = VERSION;
+            /// We allow for 2 seconds of compute with a 6 second average block time.
+            pub RuntimeBlockWeights: frame_system::limits::BlockWeights =
+                frame_system::limits::BlockWeights::with_sensible_defaults(
+                    frame_support::weights::Weight::from_parts(
+                        2u64 * frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND,
+                        u64::MAX,
+                    ),
+                    $crate::NORMAL_DISPATCH_RATIO,
+                );
+        }
+
+        // `derive_impl` expands to itself, unqualified.
+        const _: () = {
+            use frame_support::derive_impl;
+
+            #[derive_impl(frame_system::config_preludes::SolochainDefaultConfig)]
+            impl frame_system::Config for Runtime {
+                type BlockWeights = RuntimeBlockWeights;
+                type BlockLength = $crate::RuntimeBlockLength;
+                type Nonce = Nonce;
+                type Block = Block;
+                type Hash = allfeat_primitives::Hash;
+                type AccountId = AccountId;
+                type BlockHashCount = $crate::BlockHashCount;
+                type DbWeight = frame_support::weights::constants::ParityDbWeight;
+                type Version = Version;
+                type AccountData = pallet_balances::AccountData<Balance>;
+                type SystemWeightInfo = weights::system::AllfeatWeight<Runtime>;
+                type SS58Prefix = frame_support::traits::ConstU16<{ $ss58_prefix }>;
+                type MaxConsumers = frame_support::traits::ConstU32<16>;
+                type SingleBlockMigrations = $single_block_migrations;
+                type BaseCallFilter = $base_call_filter;
+            }
+        };
+
+        // Unsigned transactions submitted by the runtime itself, e.g. GRANDPA equivocation
+        // reports.
+        impl<C> frame_system::offchain::CreateTransactionBase<C> for Runtime
+        where
+            RuntimeCall: From<C>,
+        {
+            type Extrinsic = UncheckedExtrinsic;
+            type RuntimeCall = RuntimeCall;
+        }
+
+        impl<C> frame_system::offchain::CreateBare<C> for Runtime
+        where
+            RuntimeCall: From<C>,
+        {
+            fn create_bare(call: RuntimeCall) -> UncheckedExtrinsic {
+                UncheckedExtrinsic::new_bare(call)
+            }
+        }
+
+        $crate::impl_allfeat_common_configs!($($rest)*);
+    };
+
+    (balances { existential_deposit: $existential_deposit:expr $(,)? } $($rest:tt)*) => {
+        frame_support::parameter_types! {
+            pub const ExistentialDeposit: Balance = $existential_deposit;
+            // For weight estimation, we assume that the most locks on an individual account will
+            // be 50. This number may need to be adjusted in the future if this assumption no
+            // longer holds true.
+            pub const MaxLocks: u32 = 50;
+            pub const MaxReserves: u32 = 50;
+        }
+
+        impl pallet_balances::Config for Runtime {
+            type Balance = Balance;
+            type DustRemoval = ();
+            type RuntimeEvent = RuntimeEvent;
+            type ExistentialDeposit = ExistentialDeposit;
+            type AccountStore = frame_system::Pallet<Runtime>;
+            type WeightInfo = weights::balances::AllfeatWeight<Runtime>;
+            type MaxLocks = MaxLocks;
+            type MaxReserves = MaxReserves;
+            type ReserveIdentifier = [u8; 8];
+            type FreezeIdentifier = RuntimeFreezeReason;
+            type MaxFreezes = frame_support::traits::ConstU32<50>;
+            type RuntimeHoldReason = RuntimeHoldReason;
+            type RuntimeFreezeReason = RuntimeFreezeReason;
+            type DoneSlashHandler = ();
+        }
+
+        $crate::impl_allfeat_common_configs!($($rest)*);
+    };
+
+    (timestamp $($rest:tt)*) => {
+        frame_support::parameter_types! {
+            pub const MinimumPeriod: Moment = SLOT_DURATION / 2;
+        }
+
+        impl pallet_timestamp::Config for Runtime {
+            type Moment = Moment;
+            type OnTimestampSet = Aura;
+            type MinimumPeriod = MinimumPeriod;
+            type WeightInfo = weights::timestamp::AllfeatWeight<Runtime>;
+        }
+
+        $crate::impl_allfeat_common_configs!($($rest)*);
+    };
+
+    (utility $($rest:tt)*) => {
+        impl pallet_utility::Config for Runtime {
+            type RuntimeEvent = RuntimeEvent;
+            type RuntimeCall = RuntimeCall;
+            type PalletsOrigin = OriginCaller;
+            type WeightInfo = weights::utility::AllfeatWeight<Runtime>;
+        }
+
+        $crate::impl_allfeat_common_configs!($($rest)*);
+    };
+
+    (safe_mode { force_enter_origin: $force_enter_origin:ty $(,)? } $($rest:tt)*) => {
+        frame_support::parameter_types! {
+            pub const EnterDuration: BlockNumber = 4 * HOURS;
+            pub const EnterDepositAmount: Option<Balance> = None;
+            pub const ExtendDuration: BlockNumber = 2 * HOURS;
+            pub const ExtendDepositAmount: Option<Balance> = None;
+            pub const ReleaseDelay: u32 = 2 * DAYS;
+        }
+
+        impl pallet_safe_mode::Config for Runtime {
+            type RuntimeEvent = RuntimeEvent;
+            type Currency = Balances;
+            type RuntimeHoldReason = RuntimeHoldReason;
+            type WhitelistedCalls = $crate::call_filter::SafeModeWhitelistedCalls;
+            type EnterDuration = EnterDuration;
+            type ExtendDuration = ExtendDuration;
+            type EnterDepositAmount = EnterDepositAmount;
+            type ExtendDepositAmount = ExtendDepositAmount;
+            type ForceEnterOrigin = $force_enter_origin;
+            type ForceExtendOrigin = frame_system::EnsureRootWithSuccess<
+                AccountId,
+                frame_support::traits::ConstU32<11>,
+            >;
+            type ForceExitOrigin = frame_system::EnsureRoot<AccountId>;
+            type ForceDepositOrigin = frame_system::EnsureRoot<AccountId>;
+            type Notify = ();
+            type ReleaseDelay = ReleaseDelay;
+            type WeightInfo = weights::safe_mode::AllfeatWeight<Runtime>;
+        }
+
+        $crate::impl_allfeat_common_configs!($($rest)*);
+    };
+
+    (tx_pause { pause_origin: $pause_origin:ty $(,)? } $($rest:tt)*) => {
+        impl pallet_tx_pause::Config for Runtime {
+            type RuntimeEvent = RuntimeEvent;
+            type RuntimeCall = RuntimeCall;
+            type PauseOrigin = $pause_origin;
+            type UnpauseOrigin = $pause_origin;
+            type WhitelistedCalls = $crate::call_filter::TxPauseWhitelistedCalls;
+            type MaxNameLen = frame_support::traits::ConstU32<256>;
+            type WeightInfo = pallet_tx_pause::weights::SubstrateWeight<Runtime>;
+        }
+
+        $crate::impl_allfeat_common_configs!($($rest)*);
+    };
+
+    (artists $($rest:tt)*) => {
+        frame_support::parameter_types! {
+            pub const ArtistsPalletId: frame_support::PalletId =
+                frame_support::PalletId(*b"af/artst");
+            pub const MaxArtistMembers: u32 = 32;
+            pub const MaxArtistProposals: u32 = 16;
+            pub const ArtistDeposit: Balance = 10 * $crate::currency::AFT;
+            pub const ArtistPowAdjustmentPeriod: BlockNumber = 6 * HOURS;
+            // Past 50 registrations in a period, each 50 more double the work.
+            pub const TargetPowArtists: u32 = 50;
+            // About a million hashes at the minimum: seconds on a phone. At the maximum, a
+            // GPU takes days for each solution.
+            pub const MinArtistPowDifficulty: u32 = 20;
+            pub const MaxArtistPowDifficulty: u32 = 48;
+        }
+
+        impl pallet_artists::Config for Runtime {
+            type Currency = Balances;
+            type RuntimeHoldReason = RuntimeHoldReason;
+            type PalletId = ArtistsPalletId;
+            type MaxMembers = MaxArtistMembers;
+            type MaxProposals = MaxArtistProposals;
+            type ArtistDeposit = ArtistDeposit;
+            type PowAdjustmentPeriod = ArtistPowAdjustmentPeriod;
+            type TargetPowRegistrations = TargetPowArtists;
+            type MinPowDifficulty = MinArtistPowDifficulty;
+            type MaxPowDifficulty = MaxArtistPowDifficulty;
+            type WorkOrigin = pallet_artists::EnsureSolver<AccountId>;
+            type WeightInfo = weights::artists::AllfeatWeight<Runtime>;
+        }
+
+        $crate::impl_allfeat_common_configs!($($rest)*);
+    };
+
+    (
+        midds {
+            musical_works: $musical_works_helper:ty,
+            recordings: $recordings_helper:ty,
+            releases: $releases_helper:ty $(,)?
+        }
+        $($rest:tt)*
+    ) => {
+        // MIDDS economic model — see `../midds-sdk/docs/economics.md`. Dynamic
+        // pricing scaffolding (multipliers, window, finalization queue) follows that
+        // doc; the bond calibration below uses a hybrid payload-aware model that
+        // supersedes §6's flat 500/10 figures (doc refresh pending).
+        frame_support::parameter_types! {
+            // Bond formula (unmultiplied) — hybrid payload-aware: `DepositBase` pinned
+            // to the ExistentialDeposit (0.1 AFT) for minimal anti-sybil cost, weight
+            // shifted onto `DepositPerByte` so saturated payloads pay materially more
+            // than minimal ones. At $0.02/AFT a typical MusicalWork (~137 B) costs
+            // ~$0.003, a maxed-out Release (~9 KB) ~$0.05 — a 17× ratio creating the
+            // anti-stuffing incentive absent from the prior flat 0.5 AFT calibration.
+            // Dynamic multipliers stack on top via `M_fast × M_slow`. These two are
+            // consumed by the genesis builder (cf. `genesis::genesis`) to seed the
+            // pallet's runtime-mutable `DepositBase` / `DepositPerByte` storage —
+            // governance can recalibrate via `force_set_deposit_*` post-launch
+            // without a runtime upgrade (`../midds-sdk/docs/economics.md` §13.4).
+            pub const MiddsDepositBase: Balance = 100 * $crate::currency::MILLIAFT;
+            pub const MiddsDepositPerByte: Balance = 250 * $crate::currency::MICROAFT;
+
+            // Refundable commitment window aligned with the IFPI Friday Global
+            // Release Day. Within this window the depositor can `remove_own` (base
+            // refunded, premium → Treasury) or `update`. After: bond → Treasury via
+            // `finalize` (eager hook + permissionless catch-up).
+            pub const MiddsCommitmentWindow: BlockNumber = 7 * DAYS;
+            pub const MiddsMaxFinalizationsPerBlock: u32 = 100;
+            // Sudo-only cleanup cap — bounds `force_remove_many` weight. Set ≥ the
+            // benchmark sweep range (`Linear<1, 64>`) so worst-case weights stay
+            // measurable, with a margin matching `MaxFinalizationsPerBlock`.
+            pub const MiddsMaxRemovalsPerCall: u32 = 100;
+            pub const MiddsBlocksPerDay: BlockNumber = DAYS;
+
+            // M_fast — anti-DoS, per-block reactivity. Target 100 deposits/block
+            // (~17/s at 6 s/block) so a burst stuffing one block sees the multiplier
+            // climb 12.5 % before the next block prices it.
+            pub const MiddsFastTargetPerBlock: u32 = 100;
+            pub MiddsFastAdjustmentRate: sp_runtime::FixedU128 =
+                sp_runtime::FixedU128::from_rational(125, 1_000);
+            pub MiddsFastMultiplierMin: sp_runtime::FixedU128 =
+                sp_runtime::FixedU128::from_rational(1, 10);
+            pub MiddsFastMultiplierMax: sp_runtime::FixedU128 = sp_runtime::FixedU128::from_u32(20);
+
+            // M_slow — anti-flood, 7-day rolling window with day-resolution buckets.
+            // Target 200 000 deposits/week (~30 K/day) — calibrage prudent V1, see
+            // `docs/economics.md` decision #9.
+            pub const MiddsSlowTargetPerWindow: u32 = 200_000;
+            pub MiddsSlowAdjustmentRate: sp_runtime::FixedU128 =
+                sp_runtime::FixedU128::from_rational(5, 100);
+            pub MiddsSlowMultiplierMin: sp_runtime::FixedU128 =
+                sp_runtime::FixedU128::from_rational(1, 10);
+            pub MiddsSlowMultiplierMax: sp_runtime::FixedU128 = sp_runtime::FixedU128::from_u32(50);
+
+            // Foundation Treasury account that receives finalized bonds and
+            // multiplier premiums. Derived from a constant `PalletId` so the address
+            // is deterministic and stable across runtime upgrades — never burned, by
+            // tokenomics design (1 B AFT supply cap, recycled via Treasury
+            // governance — `docs/economics.md` §9).
+            pub const MiddsTreasuryPalletId: frame_support::PalletId =
+                frame_support::PalletId(*b"af/midds");
+            pub MiddsTreasuryAccount: AccountId =
+                sp_runtime::traits::AccountIdConversion::into_account_truncating(
+                    &MiddsTreasuryPalletId::get(),
+                );
+        }
+
+        $crate::impl_allfeat_common_configs!(@midds Instance1, MusicalWork, midds_musical_works, $musical_works_helper);
+        // V1 deliberately shares one bond/window/multiplier calibration across
+        // MIDDS kinds (see `../midds-sdk/docs/economics.md`): only the stored
+        // payload and the benchmark helper differ between instances.
+        $crate::impl_allfeat_common_configs!(@midds Instance2, Recording, midds_recordings, $recordings_helper);
+        $crate::impl_allfeat_common_configs!(@midds Instance3, Release, midds_releases, $releases_helper);
+
+        $crate::impl_allfeat_common_configs!($($rest)*);
+    };
+
+    (@midds $instance:ident, $midds:ident, $weights:ident, $helper:ty) => {
+        impl pallet_midds::Config<pallet_midds::$instance> for Runtime {
+            type Currency = Balances;
+            type RuntimeHoldReason = RuntimeHoldReason;
+            type Midds = midds_types::$midds;
+            type ProviderOrigin = frame_system::EnsureSigned<AccountId>;
+            type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+            // On-behalf flow: owner signs an off-chain `MultiSignature` payload, the
+            // operator submits the runtime extrinsic. `MultiSigner::into_account()`
+            // recovers the on-chain `AccountId`, matching the runtime's `Signature
+            // = MultiSignature` convention from `primitives`.
+            type OffchainSignature = Signature;
+            type Signer = sp_runtime::MultiSigner;
+            type TreasuryAccount = MiddsTreasuryAccount;
+            type CommitmentWindow = MiddsCommitmentWindow;
+            type MaxFinalizationsPerBlock = MiddsMaxFinalizationsPerBlock;
+            type MaxRemovalsPerCall = MiddsMaxRemovalsPerCall;
+            type BlocksPerDay = MiddsBlocksPerDay;
+            type FastTargetPerBlock = MiddsFastTargetPerBlock;
+            type FastAdjustmentRate = MiddsFastAdjustmentRate;
+            type FastMultiplierMin = MiddsFastMultiplierMin;
+            type FastMultiplierMax = MiddsFastMultiplierMax;
+            type SlowTargetPerWindow = MiddsSlowTargetPerWindow;
+            type SlowAdjustmentRate = MiddsSlowAdjustmentRate;
+            type SlowMultiplierMin = MiddsSlowMultiplierMin;
+            type SlowMultiplierMax = MiddsSlowMultiplierMax;
+            type WeightInfo = weights::$weights::AllfeatWeight<Runtime>;
+            #[cfg(feature = "runtime-benchmarks")]
+            type BenchmarkHelper = $helper;
+        }
+    };
+}
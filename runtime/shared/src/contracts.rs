@@ -0,0 +1,55 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Deposits and limits of the smart contracts deployed on Allfeat runtimes.
+//!
+//! Contracts pay for the storage they use like any other on-chain record (see
+//! [`deposit`](crate::currency::deposit)): a deposit per item and per byte, refunded when the
+//! storage is freed. Their code is bounded so that its deposit stays within
+//! [`DefaultDepositLimit`], the deposit a call may take when the caller sets no limit.
+
+use crate::currency::deposit;
+use allfeat_primitives::Balance;
+use frame_support::{parameter_types, sp_runtime::Perbill};
+
+parameter_types! {
+    pub const DepositPerItem: Balance = deposit(1, 0);
+    pub const DepositPerByte: Balance = deposit(0, 1);
+    pub const DefaultDepositLimit: Balance = deposit(1024, 1024 * 1024);
+    /// Share of the deposit of a code its uploader can't get back while contracts delegate to
+    /// it, so that royalty wrappers can rely on the code of a library staying around.
+    pub const CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
+    pub const MaxCodeLen: u32 = 123 * 1024;
+    pub const MaxStorageKeyLen: u32 = 128;
+    pub const MaxTransientStorageSize: u32 = 1024 * 1024;
+    pub const MaxDelegateDependencies: u32 = 32;
+    pub const MaxDebugBufferLen: u32 = 2 * 1024 * 1024;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn largest_code_fits_the_default_deposit_limit() {
+        let code = DepositPerItem::get() + DepositPerByte::get() * MaxCodeLen::get() as Balance;
+        let lockup = CodeHashLockupDepositPercent::get() * code;
+
+        assert!(code + lockup < DefaultDepositLimit::get());
+    }
+}
@@ -18,9 +18,9 @@
 
 use allfeat_primitives::Balance;
 
-pub const MICROAFT: Balance = 1_000_000;
-pub const MILLIAFT: Balance = 1_000_000_000;
-pub const AFT: Balance = 1_000_000_000_000;
+pub const AFT: Balance = allfeat_primitives::properties::ALLFEAT.unit();
+pub const MILLIAFT: Balance = AFT / 1_000;
+pub const MICROAFT: Balance = AFT / 1_000_000;
 
 pub const fn deposit(items: u32, bytes: u32) -> Balance {
     items as Balance * 10 * AFT + (bytes as Balance) * 100 * MICROAFT
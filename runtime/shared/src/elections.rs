@@ -16,7 +16,59 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::sp_runtime::Perbill;
+//! Election parameters shared by the runtimes electing their validators with
+//! `pallet_election_provider_multi_phase`.
+//!
+//! Solutions are mined off-chain, either by anyone during the signed phase or by the validators'
+//! offchain workers during the unsigned phase, and only checked on-chain. The snapshot is taken
+//! from the top of the bags-list, so the election input stays bounded however many nominators
+//! there are.
+
+use crate::currency::{AFT, deposit};
+use allfeat_primitives::{Balance, BlockNumber};
+use frame_support::{
+    parameter_types,
+    sp_runtime::{PerU16, Perbill, Percent, transaction_validity::TransactionPriority},
+};
 
 /// The accuracy type used for genesis election provider;
 pub type OnChainAccuracy = Perbill;
+
+// Solutions with up to 16 votes per nominator, the most `FixedNominationsQuota<16>` allows.
+frame_election_provider_support::generate_solution_type!(
+    #[compact]
+    pub struct NposSolution16::<
+        VoterIndex = u32,
+        TargetIndex = u16,
+        Accuracy = PerU16,
+        MaxVoters = MaxElectingVoters,
+    >(16)
+);
+
+parameter_types! {
+    /// Voters taken in the election snapshot, by descending stake. Taking the snapshot of this
+    /// many voters has to fit in a block.
+    pub const MaxElectingVoters: u32 = 3_000;
+    /// Validator candidates taken in the election snapshot.
+    pub const MaxElectableTargets: u32 = 256;
+
+    /// Signed solutions kept for the end of the signed phase, only the best one being checked.
+    pub const SignedMaxSubmissions: u32 = 16;
+    /// Submitters of the solutions left unchecked get their call fee back.
+    pub const SignedMaxRefunds: u32 = SignedMaxSubmissions::get() / 4;
+    /// Paid to the submitter of the signed solution elected, on top of their call fee.
+    pub const SignedRewardBase: Balance = AFT;
+    /// Deposit of the first signed solution in the queue, slashed if it turns out invalid.
+    pub const SignedFixedDeposit: Balance = deposit(2, 0);
+    /// Growth of the deposit of each further solution in the queue.
+    pub const SignedDepositIncreaseFactor: Percent = Percent::from_percent(10);
+    pub const SignedDepositByte: Balance = deposit(0, 1);
+    /// A solution only replaces a queued one if its score is at least this much better, so the
+    /// queue can't be churned with marginal improvements.
+    pub BetterSignedThreshold: Perbill = Perbill::from_rational(5u32, 10_000);
+
+    /// Blocks between two attempts of an offchain worker to submit its unsigned solution.
+    pub const OffchainRepeat: BlockNumber = 5;
+    /// Below heartbeats, which must never be crowded out of the pool.
+    pub MinerTxPriority: TransactionPriority = Perbill::from_percent(90) * TransactionPriority::MAX;
+}
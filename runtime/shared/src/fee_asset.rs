@@ -0,0 +1,415 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction fees paid in a whitelisted asset.
+//!
+//! [`WhitelistedAssets`] wraps the runtime's [`OnChargeAssetTransaction`] implementation, e.g.
+//! `SwapAssetAdapter` swapping the fee asset for the native one through an asset conversion
+//! pool. Only assets in the whitelist are accepted, any other asset is an invalid payment even
+//! if a pool exists for it: pools are permissionless, so their existence says nothing about
+//! whether an asset is fit to pay fees with.
+
+use core::marker::PhantomData;
+use frame_support::{
+    ensure,
+    sp_runtime::{
+        traits::{DispatchInfoOf, PostDispatchInfoOf},
+        transaction_validity::{InvalidTransaction, TransactionValidityError},
+    },
+    traits::Contains,
+};
+use pallet_asset_conversion_tx_payment::{Config, OnChargeAssetTransaction};
+use pallet_transaction_payment::OnChargeTransaction;
+
+/// Balance of the native currency fees are calculated in.
+type NativeBalanceOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
+/// An [`OnChargeAssetTransaction`] handler only accepting fees in the assets of `Whitelist`.
+///
+/// - `Inner`: the adapter actually charging the fee, e.g. `SwapAssetAdapter`.
+/// - `Whitelist`: assets fees may be paid in.
+pub struct WhitelistedAssets<Inner, Whitelist>(PhantomData<(Inner, Whitelist)>);
+
+impl<T, Inner, Whitelist> OnChargeAssetTransaction<T> for WhitelistedAssets<Inner, Whitelist>
+where
+    T: Config,
+    Inner: OnChargeAssetTransaction<T>,
+    Whitelist: Contains<Inner::AssetId>,
+{
+    type Balance = Inner::Balance;
+    type AssetId = Inner::AssetId;
+    type LiquidityInfo = Inner::LiquidityInfo;
+
+    fn withdraw_fee(
+        who: &T::AccountId,
+        call: &T::RuntimeCall,
+        dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+        asset_id: Self::AssetId,
+        fee: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        ensure!(Whitelist::contains(&asset_id), InvalidTransaction::Payment);
+        Inner::withdraw_fee(who, call, dispatch_info, asset_id, fee, tip)
+    }
+
+    fn can_withdraw_fee(
+        who: &T::AccountId,
+        asset_id: Self::AssetId,
+        fee: Self::Balance,
+    ) -> Result<(), TransactionValidityError> {
+        ensure!(Whitelist::contains(&asset_id), InvalidTransaction::Payment);
+        Inner::can_withdraw_fee(who, asset_id, fee)
+    }
+
+    fn correct_and_deposit_fee(
+        who: &T::AccountId,
+        dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+        post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+        corrected_fee: Self::Balance,
+        tip: Self::Balance,
+        asset_id: Self::AssetId,
+        already_withdrawn: Self::LiquidityInfo,
+    ) -> Result<NativeBalanceOf<T>, TransactionValidityError> {
+        Inner::correct_and_deposit_fee(
+            who,
+            dispatch_info,
+            post_info,
+            corrected_fee,
+            tip,
+            asset_id,
+            already_withdrawn,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::{
+        PalletId, assert_ok, derive_impl,
+        dispatch::{DispatchInfo, PostDispatchInfo},
+        instances::{Instance1, Instance2},
+        ord_parameter_types, parameter_types,
+        sp_runtime::{BuildStorage, traits::AccountIdConversion},
+        traits::{
+            AsEnsureOriginWithArg, ConstU32, ConstU64,
+            fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
+            fungibles::Mutate,
+            tokens::imbalance::ResolveAssetTo,
+        },
+        weights::IdentityFee,
+    };
+    use frame_system::{EnsureRoot, EnsureSignedBy};
+    use pallet_asset_conversion::{Ascending, Chain, WithFirstAsset};
+    use pallet_asset_conversion_tx_payment::SwapAssetAdapter;
+    use pallet_transaction_payment::FungibleAdapter;
+
+    type Block = frame_system::mocking::MockBlock<Test>;
+
+    const STABLE: u32 = 1;
+    const OTHER: u32 = 2;
+    const FAN: u64 = 1;
+    const LP: u64 = 2;
+    const AUTHOR: u64 = 99;
+
+    #[frame_support::runtime]
+    mod runtime {
+        #[runtime::runtime]
+        #[runtime::derive(
+            RuntimeCall,
+            RuntimeEvent,
+            RuntimeError,
+            RuntimeOrigin,
+            RuntimeFreezeReason,
+            RuntimeHoldReason,
+            RuntimeSlashReason,
+            RuntimeLockId,
+            RuntimeTask,
+            RuntimeViewFunction
+        )]
+        pub struct Test;
+
+        #[runtime::pallet_index(0)]
+        pub type System = frame_system;
+        #[runtime::pallet_index(1)]
+        pub type Balances = pallet_balances;
+        #[runtime::pallet_index(2)]
+        pub type TransactionPayment = pallet_transaction_payment;
+        #[runtime::pallet_index(3)]
+        pub type Assets = pallet_assets<Instance1>;
+        #[runtime::pallet_index(4)]
+        pub type PoolAssets = pallet_assets<Instance2>;
+        #[runtime::pallet_index(5)]
+        pub type AssetConversion = pallet_asset_conversion;
+        #[runtime::pallet_index(6)]
+        pub type AssetTxPayment = pallet_asset_conversion_tx_payment;
+    }
+
+    #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+    impl frame_system::Config for Test {
+        type Block = Block;
+        type AccountData = pallet_balances::AccountData<u64>;
+    }
+
+    #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+    impl pallet_balances::Config for Test {
+        type AccountStore = System;
+    }
+
+    #[derive_impl(pallet_transaction_payment::config_preludes::TestDefaultConfig)]
+    impl pallet_transaction_payment::Config for Test {
+        type OnChargeTransaction = FungibleAdapter<Balances, ()>;
+        type WeightToFee = IdentityFee<u64>;
+        type LengthToFee = IdentityFee<u64>;
+    }
+
+    #[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+    impl pallet_assets::Config<Instance1> for Test {
+        type Currency = Balances;
+        type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+        type ForceOrigin = EnsureRoot<u64>;
+        type Freezer = ();
+    }
+
+    #[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+    impl pallet_assets::Config<Instance2> for Test {
+        type Currency = Balances;
+        type CreateOrigin = AsEnsureOriginWithArg<EnsureSignedBy<AssetConversionOrigin, u64>>;
+        type ForceOrigin = EnsureRoot<u64>;
+        type Freezer = ();
+    }
+
+    parameter_types! {
+        pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
+        pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
+        pub const Author: u64 = AUTHOR;
+    }
+
+    ord_parameter_types! {
+        pub const AssetConversionOrigin: u64 =
+            AccountIdConversion::<u64>::into_account_truncating(&AssetConversionPalletId::get());
+    }
+
+    type NativeAndAssets = UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<u32>, u64>;
+
+    type PoolIdToAccountId = pallet_asset_conversion::AccountIdConverter<
+        AssetConversionPalletId,
+        (NativeOrWithId<u32>, NativeOrWithId<u32>),
+    >;
+
+    impl pallet_asset_conversion::Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type Balance = u64;
+        type HigherPrecisionBalance = u128;
+        type AssetKind = NativeOrWithId<u32>;
+        type Assets = NativeAndAssets;
+        type PoolId = (Self::AssetKind, Self::AssetKind);
+        type PoolLocator = Chain<
+            WithFirstAsset<Native, u64, NativeOrWithId<u32>, PoolIdToAccountId>,
+            Ascending<u64, NativeOrWithId<u32>, PoolIdToAccountId>,
+        >;
+        type PoolAssetId = u32;
+        type PoolAssets = PoolAssets;
+        type PoolSetupFee = ConstU64<0>;
+        type PoolSetupFeeAsset = Native;
+        type PoolSetupFeeTarget = ();
+        type PalletId = AssetConversionPalletId;
+        type LPFee = ConstU32<3>;
+        type LiquidityWithdrawalFee = ();
+        type MaxSwapPathLength = ConstU32<2>;
+        type MintMinLiquidity = ConstU64<100>;
+        type WeightInfo = ();
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper = ();
+    }
+
+    /// Only the stable asset may pay fees, both assets have a pool.
+    pub struct StableOnly;
+    impl Contains<NativeOrWithId<u32>> for StableOnly {
+        fn contains(asset: &NativeOrWithId<u32>) -> bool {
+            *asset == NativeOrWithId::WithId(STABLE)
+        }
+    }
+
+    type Adapter = WhitelistedAssets<
+        SwapAssetAdapter<
+            Native,
+            NativeAndAssets,
+            AssetConversion,
+            ResolveAssetTo<Author, NativeAndAssets>,
+        >,
+        StableOnly,
+    >;
+
+    impl Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type AssetId = NativeOrWithId<u32>;
+        type OnChargeAssetTransaction = Adapter;
+        type WeightInfo = ();
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper = FundedPool;
+    }
+
+    /// Funds benchmark callers with an asset paired with the native currency by `LP`.
+    #[cfg(feature = "runtime-benchmarks")]
+    pub struct FundedPool;
+    #[cfg(feature = "runtime-benchmarks")]
+    impl
+        pallet_asset_conversion_tx_payment::BenchmarkHelperTrait<
+            u64,
+            NativeOrWithId<u32>,
+            NativeOrWithId<u32>,
+        > for FundedPool
+    {
+        fn create_asset_id_parameter(id: u32) -> (NativeOrWithId<u32>, NativeOrWithId<u32>) {
+            (NativeOrWithId::WithId(id), NativeOrWithId::WithId(id))
+        }
+
+        fn setup_balances_and_pool(asset: NativeOrWithId<u32>, account: u64) {
+            let NativeOrWithId::WithId(asset) = asset else {
+                panic!("fees are paid natively without a pool");
+            };
+            if !<Assets as frame_support::traits::fungibles::Inspect<u64>>::asset_exists(asset) {
+                create_pool(asset);
+            }
+            assert_ok!(Balances::force_set_balance(
+                RuntimeOrigin::root(),
+                account,
+                1_000
+            ));
+            assert_ok!(Assets::mint_into(asset, &account, 1_000));
+        }
+    }
+
+    /// Creates `asset` and provides its pool with the native currency, at one native unit for
+    /// two units of asset.
+    fn create_pool(asset: u32) {
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            asset,
+            LP,
+            true,
+            1
+        ));
+        assert_ok!(Assets::mint_into(asset, &LP, 1_000_000));
+        let asset = Box::new(NativeOrWithId::WithId(asset));
+        assert_ok!(AssetConversion::create_pool(
+            RuntimeOrigin::signed(LP),
+            Box::new(NativeOrWithId::Native),
+            asset.clone(),
+        ));
+        assert_ok!(AssetConversion::add_liquidity(
+            RuntimeOrigin::signed(LP),
+            Box::new(NativeOrWithId::Native),
+            asset,
+            100_000,
+            200_000,
+            1,
+            1,
+            LP,
+        ));
+    }
+
+    fn new_test_ext() -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+        pallet_balances::GenesisConfig::<Test> {
+            balances: vec![(FAN, 1), (LP, 1_000_000), (AUTHOR, 1)],
+            ..Default::default()
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+        let mut ext: sp_io::TestExternalities = t.into();
+        ext.execute_with(|| {
+            for asset in [STABLE, OTHER] {
+                create_pool(asset);
+                assert_ok!(Assets::mint_into(asset, &FAN, 1_000));
+            }
+        });
+        ext
+    }
+
+    fn withdraw(asset: u32, fee: u64) -> Result<(), TransactionValidityError> {
+        <Adapter as OnChargeAssetTransaction<Test>>::can_withdraw_fee(
+            &FAN,
+            NativeOrWithId::WithId(asset),
+            fee,
+        )?;
+        let liquidity_info = <Adapter as OnChargeAssetTransaction<Test>>::withdraw_fee(
+            &FAN,
+            &frame_system::Call::remark { remark: vec![] }.into(),
+            &DispatchInfo::default(),
+            NativeOrWithId::WithId(asset),
+            fee,
+            0,
+        )?;
+        <Adapter as OnChargeAssetTransaction<Test>>::correct_and_deposit_fee(
+            &FAN,
+            &DispatchInfo::default(),
+            &PostDispatchInfo::default(),
+            fee,
+            0,
+            NativeOrWithId::WithId(asset),
+            liquidity_info,
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn whitelisted_asset_is_swapped_and_author_paid_natively() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(withdraw(STABLE, 100));
+
+            // The fan paid in the stable asset, at the pool's rate plus the LP fee...
+            let paid = 1_000 - Assets::balance(STABLE, FAN);
+            assert!((200..=210).contains(&paid), "paid {paid}");
+            // ...and the author got the fee in the native currency.
+            assert_eq!(Balances::free_balance(AUTHOR), 101);
+        });
+    }
+
+    #[test]
+    fn other_assets_are_rejected_even_with_a_pool() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(
+                withdraw(OTHER, 100),
+                Err(InvalidTransaction::Payment.into())
+            );
+            assert_eq!(Assets::balance(OTHER, FAN), 1_000);
+            assert_eq!(Balances::free_balance(AUTHOR), 1);
+        });
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    #[test]
+    fn benchmark_helper_funds_callers_through_a_new_pool() {
+        use pallet_asset_conversion_tx_payment::BenchmarkHelperTrait;
+
+        new_test_ext().execute_with(|| {
+            let (asset, _) = FundedPool::create_asset_id_parameter(3);
+            FundedPool::setup_balances_and_pool(asset.clone(), FAN);
+
+            assert_eq!(Assets::balance(3, FAN), 1_000);
+            assert_eq!(
+                AssetConversion::get_reserves(NativeOrWithId::Native, asset).ok(),
+                Some((100_000, 200_000))
+            );
+        });
+    }
+}
@@ -0,0 +1,378 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction fee rebates for certified artists.
+//!
+//! [`RebateAdapter`] wraps the runtime's regular [`OnChargeTransaction`] implementation. The
+//! fee is always charged in full by the wrapped adapter; once the transaction has been applied, a
+//! share of the corrected fee (tips excluded) is paid back to the signer out of a dedicated pot,
+//! provided the signer is certified and the call is eligible.
+//!
+//! Eligibility is decided on the call the signer submitted, so a wrapper call must not be able to
+//! smuggle unrelated calls in. [`BatchAware`] takes care of `pallet_utility`: a batch is eligible
+//! only if every call it contains is, and the other utility calls never are.
+
+use core::marker::PhantomData;
+use frame_support::{
+    sp_runtime::{
+        Perbill,
+        traits::{DispatchInfoOf, PostDispatchInfoOf, Saturating, Zero},
+        transaction_validity::TransactionValidityError,
+    },
+    traits::{
+        Contains, Get, IsSubType, IsType,
+        fungible::{Inspect, Mutate},
+        tokens::Preservation,
+    },
+};
+use pallet_transaction_payment::{Config, OnChargeTransaction, TxCreditHold};
+
+/// An [`OnChargeTransaction`] handler paying back `Rebate` of the fee of eligible transactions.
+///
+/// - `Inner`: the adapter actually charging the fee, e.g. `FungibleAdapter`.
+/// - `Currency`: the currency the fee is paid in.
+/// - `Certified`: accounts entitled to a rebate.
+/// - `Eligible`: calls a rebate is paid for. See [`BatchAware`] for wrapper calls.
+/// - `Pot`: account the rebates are paid from. When it runs dry the full fee is kept.
+/// - `Rebate`: share of the fee, tip excluded, paid back.
+pub struct RebateAdapter<Inner, Currency, Certified, Eligible, Pot, Rebate>(
+    PhantomData<(Inner, Currency, Certified, Eligible, Pot, Rebate)>,
+);
+
+impl<T, Inner, Currency, Certified, Eligible, Pot, Rebate> OnChargeTransaction<T>
+    for RebateAdapter<Inner, Currency, Certified, Eligible, Pot, Rebate>
+where
+    T: Config,
+    Inner: OnChargeTransaction<T, Balance = <Currency as Inspect<T::AccountId>>::Balance>,
+    Currency: Mutate<T::AccountId>,
+    Certified: Contains<T::AccountId>,
+    Eligible: Contains<<T as frame_system::Config>::RuntimeCall>,
+    Pot: Get<T::AccountId>,
+    Rebate: Get<Perbill>,
+{
+    type Balance = Inner::Balance;
+    /// The wrapped adapter's liquidity info, and whether the transaction earns a rebate.
+    type LiquidityInfo = (Inner::LiquidityInfo, bool);
+
+    fn withdraw_fee(
+        who: &T::AccountId,
+        call: &<T as frame_system::Config>::RuntimeCall,
+        dispatch_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        fee_with_tip: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        let liquidity_info = Inner::withdraw_fee(who, call, dispatch_info, fee_with_tip, tip)?;
+        let eligible = Certified::contains(who) && Eligible::contains(call);
+        Ok((liquidity_info, eligible))
+    }
+
+    fn can_withdraw_fee(
+        who: &T::AccountId,
+        call: &<T as frame_system::Config>::RuntimeCall,
+        dispatch_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        fee_with_tip: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<(), TransactionValidityError> {
+        Inner::can_withdraw_fee(who, call, dispatch_info, fee_with_tip, tip)
+    }
+
+    fn correct_and_deposit_fee(
+        who: &T::AccountId,
+        dispatch_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        post_info: &PostDispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        corrected_fee_with_tip: Self::Balance,
+        tip: Self::Balance,
+        (liquidity_info, eligible): Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        Inner::correct_and_deposit_fee(
+            who,
+            dispatch_info,
+            post_info,
+            corrected_fee_with_tip,
+            tip,
+            liquidity_info,
+        )?;
+
+        if eligible {
+            let rebate = Rebate::get().mul_floor(corrected_fee_with_tip.saturating_sub(tip));
+            if !rebate.is_zero() {
+                // Best effort: an empty pot only means no rebate, never a failed transaction.
+                let _ = Currency::transfer(&Pot::get(), who, rebate, Preservation::Preserve);
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn endow_account(who: &T::AccountId, amount: Self::Balance) {
+        Inner::endow_account(who, amount)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn minimum_balance() -> Self::Balance {
+        Inner::minimum_balance()
+    }
+}
+
+impl<T, Inner, Currency, Certified, Eligible, Pot, Rebate> TxCreditHold<T>
+    for RebateAdapter<Inner, Currency, Certified, Eligible, Pot, Rebate>
+where
+    T: Config,
+    Inner: TxCreditHold<T>,
+{
+    type Credit = Inner::Credit;
+}
+
+/// Extends the `Eligible` call filter through `pallet_utility`.
+///
+/// `batch`, `batch_all` and `force_batch` are eligible when all of their calls are, recursively.
+/// Every other utility call is rejected: they dispatch with a different origin or let the caller
+/// pick which call runs.
+pub struct BatchAware<T, Eligible>(PhantomData<(T, Eligible)>);
+
+impl<T, Eligible> Contains<<T as frame_system::Config>::RuntimeCall> for BatchAware<T, Eligible>
+where
+    T: pallet_utility::Config,
+    Eligible: Contains<<T as frame_system::Config>::RuntimeCall>,
+{
+    fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+        use pallet_utility::Call as UtilityCall;
+
+        let utility_call =
+            <<T as pallet_utility::Config>::RuntimeCall as IsType<_>>::from_ref(call);
+        match utility_call.is_sub_type() {
+            None => Eligible::contains(call),
+            Some(
+                UtilityCall::batch { calls }
+                | UtilityCall::batch_all { calls }
+                | UtilityCall::force_batch { calls },
+            ) => calls.iter().all(|call| Self::contains(call.into_ref())),
+            Some(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::{
+        derive_impl,
+        dispatch::{DispatchInfo, PostDispatchInfo},
+        parameter_types,
+        sp_runtime::BuildStorage,
+        weights::IdentityFee,
+    };
+    use pallet_transaction_payment::FungibleAdapter;
+
+    type Block = frame_system::mocking::MockBlock<Test>;
+
+    const ARTIST: u64 = 1;
+    const FAN: u64 = 2;
+    const POT: u64 = 99;
+
+    #[frame_support::runtime]
+    mod runtime {
+        #[runtime::runtime]
+        #[runtime::derive(
+            RuntimeCall,
+            RuntimeEvent,
+            RuntimeError,
+            RuntimeOrigin,
+            RuntimeFreezeReason,
+            RuntimeHoldReason,
+            RuntimeSlashReason,
+            RuntimeLockId,
+            RuntimeTask,
+            RuntimeViewFunction
+        )]
+        pub struct Test;
+
+        #[runtime::pallet_index(0)]
+        pub type System = frame_system;
+        #[runtime::pallet_index(1)]
+        pub type Balances = pallet_balances;
+        #[runtime::pallet_index(2)]
+        pub type TransactionPayment = pallet_transaction_payment;
+        #[runtime::pallet_index(3)]
+        pub type Utility = pallet_utility;
+    }
+
+    #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+    impl frame_system::Config for Test {
+        type Block = Block;
+        type AccountData = pallet_balances::AccountData<u64>;
+    }
+
+    #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+    impl pallet_balances::Config for Test {
+        type AccountStore = System;
+    }
+
+    parameter_types! {
+        pub const RebatePot: u64 = POT;
+        pub const ArtistRebate: Perbill = Perbill::from_percent(50);
+    }
+
+    pub struct Certified;
+    impl Contains<u64> for Certified {
+        fn contains(who: &u64) -> bool {
+            *who == ARTIST
+        }
+    }
+
+    /// Only remarks earn a rebate; transfers stand in for unrelated calls.
+    pub struct Remarks;
+    impl Contains<RuntimeCall> for Remarks {
+        fn contains(call: &RuntimeCall) -> bool {
+            matches!(call, RuntimeCall::System(frame_system::Call::remark { .. }))
+        }
+    }
+
+    type Adapter = RebateAdapter<
+        FungibleAdapter<Balances, ()>,
+        Balances,
+        Certified,
+        BatchAware<Test, Remarks>,
+        RebatePot,
+        ArtistRebate,
+    >;
+
+    #[derive_impl(pallet_transaction_payment::config_preludes::TestDefaultConfig)]
+    impl pallet_transaction_payment::Config for Test {
+        type OnChargeTransaction = Adapter;
+        type WeightToFee = IdentityFee<u64>;
+        type LengthToFee = IdentityFee<u64>;
+    }
+
+    impl pallet_utility::Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type RuntimeCall = RuntimeCall;
+        type PalletsOrigin = OriginCaller;
+        type WeightInfo = ();
+    }
+
+    fn new_test_ext(pot: u64) -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+        pallet_balances::GenesisConfig::<Test> {
+            balances: vec![(ARTIST, 1_000), (FAN, 1_000), (POT, pot)],
+            ..Default::default()
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+        t.into()
+    }
+
+    /// Charge `fee` (plus `tip`) to `who` for `call` the way the transaction payment extension
+    /// does, and return what it actually cost them.
+    fn pay(who: u64, call: RuntimeCall, fee: u64, tip: u64) -> u64 {
+        let before = Balances::free_balance(who);
+        let info = DispatchInfo::default();
+        let liquidity_info = <Adapter as OnChargeTransaction<Test>>::withdraw_fee(
+            &who,
+            &call,
+            &info,
+            fee + tip,
+            tip,
+        )
+        .unwrap();
+        <Adapter as OnChargeTransaction<Test>>::correct_and_deposit_fee(
+            &who,
+            &info,
+            &PostDispatchInfo::default(),
+            fee + tip,
+            tip,
+            liquidity_info,
+        )
+        .unwrap();
+        before - Balances::free_balance(who)
+    }
+
+    fn remark() -> RuntimeCall {
+        frame_system::Call::remark { remark: vec![] }.into()
+    }
+
+    fn transfer() -> RuntimeCall {
+        pallet_balances::Call::transfer_allow_death {
+            dest: FAN,
+            value: 1,
+        }
+        .into()
+    }
+
+    fn batch(calls: Vec<RuntimeCall>) -> RuntimeCall {
+        pallet_utility::Call::batch { calls }.into()
+    }
+
+    #[test]
+    fn certified_artist_gets_rebate_from_pot() {
+        new_test_ext(1_000).execute_with(|| {
+            assert_eq!(pay(ARTIST, remark(), 100, 0), 50);
+            assert_eq!(Balances::free_balance(POT), 950);
+
+            // Neither an uncertified signer nor an ineligible call earn anything.
+            assert_eq!(pay(FAN, remark(), 100, 0), 100);
+            assert_eq!(pay(ARTIST, transfer(), 100, 0), 100);
+            assert_eq!(Balances::free_balance(POT), 950);
+        });
+    }
+
+    #[test]
+    fn tip_is_not_rebated() {
+        new_test_ext(1_000).execute_with(|| {
+            assert_eq!(pay(ARTIST, remark(), 100, 40), 90);
+        });
+    }
+
+    #[test]
+    fn empty_pot_keeps_full_fee() {
+        // Only the existential deposit is left, which the pot must keep.
+        new_test_ext(1).execute_with(|| {
+            assert_eq!(pay(ARTIST, remark(), 100, 0), 100);
+        });
+    }
+
+    #[test]
+    fn batch_wrapping_unrelated_calls_earns_nothing() {
+        new_test_ext(1_000).execute_with(|| {
+            assert_eq!(pay(ARTIST, batch(vec![remark(), remark()]), 100, 0), 50);
+
+            assert_eq!(pay(ARTIST, batch(vec![remark(), transfer()]), 100, 0), 100);
+            let batch_all = pallet_utility::Call::batch_all {
+                calls: vec![remark(), transfer()],
+            };
+            assert_eq!(pay(ARTIST, batch_all.into(), 100, 0), 100);
+            let force_batch = pallet_utility::Call::force_batch {
+                calls: vec![transfer()],
+            };
+            assert_eq!(pay(ARTIST, force_batch.into(), 100, 0), 100);
+
+            // Nesting does not hide the unrelated call either.
+            let nested = batch(vec![remark(), batch(vec![remark(), transfer()])]);
+            assert_eq!(pay(ARTIST, nested, 100, 0), 100);
+
+            // Nor does any other utility wrapper, even around an eligible call.
+            let derivative = pallet_utility::Call::as_derivative {
+                index: 0,
+                call: Box::new(remark()),
+            };
+            assert_eq!(pay(ARTIST, derivative.into(), 100, 0), 100);
+        });
+    }
+}
@@ -0,0 +1,293 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction fee split between burn, treasury and block author.
+//!
+//! [`DealWithFees`] splits each fee into three shares, each a [`Perbill`] read on every call so
+//! that governance can retune them at runtime (e.g. through `pallet_parameters`). The shares are
+//! weighed against their sum, so they need not add up to 100%: `1% / 1% / 2%` splits the same
+//! as `25% / 25% / 50%`. When they are all zero, the block author gets the whole fee.
+//!
+//! Tips are added to the fee before it is split, as with the fixed 80/20 split this replaces.
+//! The burn and treasury portions are rounded down, the rounding dust goes to the author, so
+//! the three portions always add up to the fee and tip.
+
+use core::marker::PhantomData;
+use frame_support::{
+    sp_runtime::{
+        Perbill, Rounding,
+        helpers_128bit::multiply_by_rational_with_rounding,
+        traits::{UniqueSaturatedFrom, UniqueSaturatedInto},
+    },
+    traits::{
+        Get, Imbalance, OnUnbalanced,
+        fungible::{Balanced, Credit},
+    },
+};
+
+/// An [`OnUnbalanced`] handler splitting fees and tips between burn, `TreasuryAccount` and
+/// the block author.
+///
+/// - `R`: the runtime, whose `pallet_authorship` names the block author.
+/// - `Currency`: the currency fees are paid in.
+/// - `Burn`, `Treasury`, `Author`: the share of the fee, tip included, of each destination.
+/// - `TreasuryAccount`: account the treasury portion is paid to.
+///
+/// Portions that can't be paid out, e.g. the author's when there is no known author, are
+/// burned.
+pub struct DealWithFees<R, Currency, Burn, Treasury, Author, TreasuryAccount>(
+    PhantomData<(R, Currency, Burn, Treasury, Author, TreasuryAccount)>,
+);
+
+impl<R, Currency, Burn, Treasury, Author, TreasuryAccount>
+    DealWithFees<R, Currency, Burn, Treasury, Author, TreasuryAccount>
+where
+    Burn: Get<Perbill>,
+    Treasury: Get<Perbill>,
+    Author: Get<Perbill>,
+{
+    /// The parts of the fee burned and paid to the treasury, each rounded down. The author gets
+    /// the rest.
+    pub fn split(fee: Currency::Balance) -> (Currency::Balance, Currency::Balance)
+    where
+        R: frame_system::Config,
+        Currency: Balanced<R::AccountId>,
+    {
+        let burn = Burn::get().deconstruct() as u128;
+        let treasury = Treasury::get().deconstruct() as u128;
+        let total = burn + treasury + Author::get().deconstruct() as u128;
+        // At most the fee, so converting it back is lossless.
+        let portion = |share| {
+            multiply_by_rational_with_rounding(
+                fee.unique_saturated_into(),
+                share,
+                total,
+                Rounding::Down,
+            )
+            .map(Currency::Balance::unique_saturated_from)
+            .unwrap_or_default()
+        };
+        (portion(burn), portion(treasury))
+    }
+}
+
+impl<R, Currency, Burn, Treasury, Author, TreasuryAccount>
+    OnUnbalanced<Credit<R::AccountId, Currency>>
+    for DealWithFees<R, Currency, Burn, Treasury, Author, TreasuryAccount>
+where
+    R: pallet_authorship::Config,
+    Currency: Balanced<R::AccountId>,
+    Burn: Get<Perbill>,
+    Treasury: Get<Perbill>,
+    Author: Get<Perbill>,
+    TreasuryAccount: Get<R::AccountId>,
+{
+    fn on_unbalanceds(mut fees_then_tips: impl Iterator<Item = Credit<R::AccountId, Currency>>) {
+        let Some(mut fees) = fees_then_tips.next() else {
+            return;
+        };
+        if let Some(tips) = fees_then_tips.next() {
+            tips.merge_into(&mut fees);
+        }
+
+        let (burn, treasury) = Self::split(fees.peek());
+        // Dropping the burned part is what burns it.
+        let (_burned, fees) = fees.split(burn);
+        let (to_treasury, to_author) = fees.split(treasury);
+
+        let _ = Currency::resolve(&TreasuryAccount::get(), to_treasury);
+        if let Some(author) = pallet_authorship::Pallet::<R>::author() {
+            let _ = Currency::resolve(&author, to_author);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::{
+        derive_impl, parameter_types,
+        sp_runtime::{BuildStorage, ConsensusEngineId},
+        traits::{
+            FindAuthor,
+            tokens::{Fortitude, Precision, Preservation},
+        },
+    };
+
+    type Block = frame_system::mocking::MockBlock<Test>;
+
+    const AUTHOR: u64 = 1;
+    const TREASURY: u64 = 2;
+    const PAYER: u64 = 3;
+
+    #[frame_support::runtime]
+    mod runtime {
+        #[runtime::runtime]
+        #[runtime::derive(
+            RuntimeCall,
+            RuntimeEvent,
+            RuntimeError,
+            RuntimeOrigin,
+            RuntimeFreezeReason,
+            RuntimeHoldReason,
+            RuntimeSlashReason,
+            RuntimeLockId,
+            RuntimeTask,
+            RuntimeViewFunction
+        )]
+        pub struct Test;
+
+        #[runtime::pallet_index(0)]
+        pub type System = frame_system;
+        #[runtime::pallet_index(1)]
+        pub type Balances = pallet_balances;
+        #[runtime::pallet_index(2)]
+        pub type Authorship = pallet_authorship;
+    }
+
+    #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+    impl frame_system::Config for Test {
+        type Block = Block;
+        type AccountData = pallet_balances::AccountData<u64>;
+    }
+
+    #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+    impl pallet_balances::Config for Test {
+        type AccountStore = System;
+    }
+
+    pub struct Author1;
+    impl FindAuthor<u64> for Author1 {
+        fn find_author<'a, I>(_: I) -> Option<u64>
+        where
+            I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+        {
+            Some(AUTHOR)
+        }
+    }
+
+    impl pallet_authorship::Config for Test {
+        type FindAuthor = Author1;
+        type EventHandler = ();
+    }
+
+    parameter_types! {
+        pub static BurnShare: Perbill = Perbill::from_percent(10);
+        pub static TreasuryShare: Perbill = Perbill::from_percent(20);
+        pub static AuthorShare: Perbill = Perbill::from_percent(70);
+        pub const Treasury: u64 = TREASURY;
+    }
+
+    type Split = DealWithFees<Test, Balances, BurnShare, TreasuryShare, AuthorShare, Treasury>;
+
+    fn new_test_ext() -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+        pallet_balances::GenesisConfig::<Test> {
+            balances: vec![(AUTHOR, 1), (TREASURY, 1), (PAYER, 1_000_000_000_000_000)],
+            ..Default::default()
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+        t.into()
+    }
+
+    /// Charge `fee` and `tip` to the payer and hand them to [`Split`]. Returns what the author
+    /// and the treasury earned and how much was burned.
+    fn deal(fee: u64, tip: u64) -> (u64, u64, u64) {
+        let issuance = Balances::total_issuance();
+        let (author, treasury) = (
+            Balances::free_balance(AUTHOR),
+            Balances::free_balance(TREASURY),
+        );
+
+        let withdraw = |amount| {
+            <Balances as Balanced<u64>>::withdraw(
+                &PAYER,
+                amount,
+                Precision::Exact,
+                Preservation::Preserve,
+                Fortitude::Polite,
+            )
+            .unwrap()
+        };
+        Split::on_unbalanceds([withdraw(fee), withdraw(tip)].into_iter());
+
+        (
+            Balances::free_balance(AUTHOR) - author,
+            Balances::free_balance(TREASURY) - treasury,
+            issuance - Balances::total_issuance(),
+        )
+    }
+
+    #[test]
+    fn fee_and_tip_are_split() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(deal(1_000, 0), (700, 200, 100));
+            // Tips are split with the fee, as the fixed mainnet split did.
+            assert_eq!(deal(1_000, 50), (735, 210, 105));
+            assert_eq!(deal(0, 50), (35, 10, 5));
+        });
+    }
+
+    #[test]
+    fn rounding_dust_goes_to_author() {
+        new_test_ext().execute_with(|| {
+            BurnShare::set(Perbill::from_percent(33));
+            TreasuryShare::set(Perbill::from_percent(33));
+            AuthorShare::set(Perbill::from_percent(34));
+            // 3.3 and 3.3 rounded down, 3.4 plus the dust for the author.
+            assert_eq!(deal(10, 0), (4, 3, 3));
+            assert_eq!(deal(1, 0), (1, 0, 0));
+        });
+    }
+
+    #[test]
+    fn shares_are_relative_to_their_sum() {
+        new_test_ext().execute_with(|| {
+            BurnShare::set(Perbill::from_percent(1));
+            TreasuryShare::set(Perbill::from_percent(1));
+            AuthorShare::set(Perbill::from_percent(2));
+            assert_eq!(deal(1_000, 0), (500, 250, 250));
+
+            // Retuned at runtime, e.g. burn everything.
+            TreasuryShare::set(Perbill::zero());
+            AuthorShare::set(Perbill::zero());
+            assert_eq!(deal(1_000, 10), (0, 0, 1_010));
+
+            BurnShare::set(Perbill::zero());
+            assert_eq!(deal(1_000, 0), (1_000, 0, 0));
+        });
+    }
+
+    #[test]
+    fn large_fees_are_split_exactly() {
+        new_test_ext().execute_with(|| {
+            BurnShare::set(Perbill::from_percent(1));
+            TreasuryShare::set(Perbill::from_percent(1));
+            AuthorShare::set(Perbill::from_percent(1));
+            // A third is 333_333_333 parts per billion: rounding the ratio first would lose
+            // 1_000 on each portion.
+            assert_eq!(
+                deal(3_000_000_000_000, 0),
+                (1_000_000_000_000, 1_000_000_000_000, 1_000_000_000_000)
+            );
+        });
+    }
+}
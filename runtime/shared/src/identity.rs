@@ -0,0 +1,325 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Identity information of Allfeat accounts.
+//!
+//! [`IdentityInfo`] carries the fields of the people chains (display name, legal name, website,
+//! socials...) along with those the music industry knows its actors by:
+//!
+//! - `ipi`: the IPI name number (Interested Party Information) of a songwriter or publisher,
+//!   11 ASCII digits.
+//! - `pro`: the performing rights organization the account is affiliated with, e.g. `SACEM`.
+//! - `label`: the label an artist is signed to.
+//! - `booking`: where to book the artist, an email address or a website.
+//!
+//! `pallet_identity` accepts any encodable information: [`IdentityInfo::is_valid`] is what
+//! runtimes check before letting a `set_identity` through. Registrars judge the information
+//! itself, e.g. that the IPI number is the account owner's.
+
+#[cfg(feature = "runtime-benchmarks")]
+use enumflags2::BitFlag;
+use enumflags2::{BitFlags, bitflags};
+use pallet_identity::{Data, IdentityInformationProvider};
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::{Path, Type, TypeInfo, build::Variants};
+
+/// Length of an IPI name number.
+pub const IPI_LENGTH: usize = 11;
+
+/// The fields of an [`IdentityInfo`], as registrars are asked to judge them.
+#[bitflags]
+#[repr(u64)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IdentityField {
+    Display,
+    Legal,
+    Web,
+    Matrix,
+    Email,
+    PgpFingerprint,
+    Image,
+    Twitter,
+    GitHub,
+    Discord,
+    Ipi,
+    Pro,
+    Label,
+    Booking,
+}
+
+impl TypeInfo for IdentityField {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("IdentityField", module_path!()))
+            .variant(
+                Variants::new()
+                    .variant("Display", |v| v.index(0))
+                    .variant("Legal", |v| v.index(1))
+                    .variant("Web", |v| v.index(2))
+                    .variant("Matrix", |v| v.index(3))
+                    .variant("Email", |v| v.index(4))
+                    .variant("PgpFingerprint", |v| v.index(5))
+                    .variant("Image", |v| v.index(6))
+                    .variant("Twitter", |v| v.index(7))
+                    .variant("GitHub", |v| v.index(8))
+                    .variant("Discord", |v| v.index(9))
+                    .variant("Ipi", |v| v.index(10))
+                    .variant("Pro", |v| v.index(11))
+                    .variant("Label", |v| v.index(12))
+                    .variant("Booking", |v| v.index(13)),
+            )
+    }
+}
+
+/// Information concerning the identity of the controller of an account.
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    MaxEncodedLen,
+    PartialEq,
+    Debug,
+    Default,
+    TypeInfo,
+)]
+pub struct IdentityInfo {
+    /// A reasonable display name for the controller of the account, e.g. an artist's stage name.
+    ///
+    /// Stored as UTF-8.
+    pub display: Data,
+
+    /// The full legal name in the local jurisdiction of the entity.
+    ///
+    /// Stored as UTF-8.
+    pub legal: Data,
+
+    /// A representative website held by the controller of the account.
+    ///
+    /// Stored as UTF-8.
+    pub web: Data,
+
+    /// The Matrix handle held by the controller of the account.
+    ///
+    /// Stored as UTF-8.
+    pub matrix: Data,
+
+    /// The email address of the controller of the account.
+    ///
+    /// Stored as UTF-8.
+    pub email: Data,
+
+    /// The PGP/GPG public key of the controller of the account.
+    pub pgp_fingerprint: Option<[u8; 20]>,
+
+    /// A graphic image representing the controller of the account: a logo, an artist picture...
+    pub image: Data,
+
+    /// The Twitter identity. The leading `@` character may be elided.
+    pub twitter: Data,
+
+    /// The GitHub username of the controller of the account.
+    pub github: Data,
+
+    /// The Discord username of the controller of the account.
+    pub discord: Data,
+
+    /// The IPI name number of the controller of the account, as ASCII digits.
+    pub ipi: Option<[u8; IPI_LENGTH]>,
+
+    /// The performing rights organization the controller of the account is affiliated with.
+    ///
+    /// Stored as UTF-8.
+    pub pro: Data,
+
+    /// The label the controller of the account is signed to.
+    ///
+    /// Stored as UTF-8.
+    pub label: Data,
+
+    /// Where to book the controller of the account: an email address or a website.
+    ///
+    /// Stored as UTF-8.
+    pub booking: Data,
+}
+
+impl IdentityInfo {
+    /// The fields set in this information.
+    pub fn fields(&self) -> BitFlags<IdentityField> {
+        let data = [
+            (&self.display, IdentityField::Display),
+            (&self.legal, IdentityField::Legal),
+            (&self.web, IdentityField::Web),
+            (&self.matrix, IdentityField::Matrix),
+            (&self.email, IdentityField::Email),
+            (&self.image, IdentityField::Image),
+            (&self.twitter, IdentityField::Twitter),
+            (&self.github, IdentityField::GitHub),
+            (&self.discord, IdentityField::Discord),
+            (&self.pro, IdentityField::Pro),
+            (&self.label, IdentityField::Label),
+            (&self.booking, IdentityField::Booking),
+        ];
+        let mut fields = data
+            .into_iter()
+            .filter(|(data, _)| !data.is_none())
+            .map(|(_, field)| field)
+            .collect::<BitFlags<_>>();
+        if self.pgp_fingerprint.is_some() {
+            fields.insert(IdentityField::PgpFingerprint);
+        }
+        if self.ipi.is_some() {
+            fields.insert(IdentityField::Ipi);
+        }
+        fields
+    }
+
+    /// Whether the music industry fields are well-formed: the IPI name number is made of digits,
+    /// and the PRO, label and booking contact are either unset or given in clear, as UTF-8, for
+    /// registrars to check them.
+    pub fn is_valid(&self) -> bool {
+        let ipi = self
+            .ipi
+            .is_none_or(|ipi| ipi.iter().all(u8::is_ascii_digit));
+        let in_clear = |data: &Data| match data {
+            Data::None => true,
+            Data::Raw(raw) => !raw.is_empty() && core::str::from_utf8(raw).is_ok(),
+            _ => false,
+        };
+
+        ipi && in_clear(&self.pro) && in_clear(&self.label) && in_clear(&self.booking)
+    }
+}
+
+impl IdentityInformationProvider for IdentityInfo {
+    type FieldsIdentifier = u64;
+
+    fn has_identity(&self, fields: Self::FieldsIdentifier) -> bool {
+        self.fields().bits() & fields == fields
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_identity_info() -> Self {
+        let data = Data::Raw(alloc::vec![b'a'; 32].try_into().unwrap());
+
+        Self {
+            display: data.clone(),
+            legal: data.clone(),
+            web: data.clone(),
+            matrix: data.clone(),
+            email: data.clone(),
+            pgp_fingerprint: Some([0; 20]),
+            image: data.clone(),
+            twitter: data.clone(),
+            github: data.clone(),
+            discord: data.clone(),
+            ipi: Some([b'0'; IPI_LENGTH]),
+            pro: data.clone(),
+            label: data.clone(),
+            booking: data,
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn all_fields() -> Self::FieldsIdentifier {
+        IdentityField::all().bits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(bytes: &[u8]) -> Data {
+        Data::Raw(bytes.to_vec().try_into().unwrap())
+    }
+
+    fn artist() -> IdentityInfo {
+        IdentityInfo {
+            display: raw(b"Daft Punk"),
+            ipi: Some(*b"00052210040"),
+            pro: raw(b"SACEM"),
+            label: raw(b"Columbia"),
+            booking: raw(b"booking@example.com"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn music_industry_fields_are_identity_fields() {
+        let info = artist();
+        let fields = IdentityField::Display
+            | IdentityField::Ipi
+            | IdentityField::Pro
+            | IdentityField::Label
+            | IdentityField::Booking;
+
+        assert_eq!(info.fields(), fields);
+        assert!(info.has_identity(fields.bits()));
+        assert!(!info.has_identity((fields | IdentityField::Email).bits()));
+    }
+
+    #[test]
+    fn ipi_name_numbers_are_digits() {
+        assert!(artist().is_valid());
+        assert!(IdentityInfo::default().is_valid());
+        assert!(
+            !IdentityInfo {
+                ipi: Some(*b"0005221004X"),
+                ..artist()
+            }
+            .is_valid()
+        );
+    }
+
+    #[test]
+    fn music_industry_fields_are_given_in_clear() {
+        assert!(
+            !IdentityInfo {
+                pro: Data::BlakeTwo256([0; 32]),
+                ..artist()
+            }
+            .is_valid()
+        );
+        assert!(
+            !IdentityInfo {
+                label: raw(&[0xff, 0xfe]),
+                ..artist()
+            }
+            .is_valid()
+        );
+        assert!(
+            !IdentityInfo {
+                booking: raw(b""),
+                ..artist()
+            }
+            .is_valid()
+        );
+        // The other fields are left to registrars.
+        assert!(
+            IdentityInfo {
+                display: Data::Sha256([0; 32]),
+                ..artist()
+            }
+            .is_valid()
+        );
+    }
+}
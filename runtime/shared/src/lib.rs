@@ -20,20 +20,46 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 use allfeat_primitives::{Balance, BlockNumber};
+use core::ops::RangeInclusive;
 use frame_support::{
     dispatch::DispatchClass,
     parameter_types,
-    sp_runtime::{FixedPointNumber, Perbill, Perquintill, traits::Bounded},
+    sp_runtime::{
+        FixedPointNumber, Perbill, Perquintill,
+        traits::{Bounded, One},
+    },
 };
 use frame_system::limits::BlockLength;
 use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 use sp_core::U256;
 
+pub mod call_filter;
+
+pub mod configs;
+
+pub mod contracts;
+
 pub mod elections;
 
 pub mod currency;
 
+pub mod fee_rebate;
+
+pub mod fee_asset;
+
+pub mod fee_split;
+
+pub mod identity;
+
+pub mod signing;
+
+pub mod staking;
+
+pub mod weights;
+
 #[cfg(feature = "std")]
 pub mod fee_estimator;
 
@@ -51,6 +77,19 @@ parameter_types! {
     pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(5, 10u128);
     /// The maximum amount of the multiplier.
     pub MaximumMultiplier: Multiplier = Bounded::max_value();
+    /// Range `AdjustmentVariable` may be retuned within at runtime. Zero would freeze the
+    /// multiplier.
+    pub AdjustmentVariableBounds: RangeInclusive<Multiplier> =
+        Multiplier::saturating_from_rational(1, 1_000_000)..=Multiplier::saturating_from_rational(1, 1_000);
+    /// Range `MinimumMultiplier` may be retuned within at runtime: fees can't drop below a tenth
+    /// of their nominal price, nor be kept above it when blocks are empty.
+    pub MinimumMultiplierBounds: RangeInclusive<Multiplier> =
+        Multiplier::saturating_from_rational(1, 10u128)..=Multiplier::one();
+    /// Range `MaximumMultiplier` may be retuned within at runtime: fees can always grow tenfold
+    /// under congestion. Lying above [`MinimumMultiplierBounds`], the ceiling stays above the
+    /// floor.
+    pub MaximumMultiplierBounds: RangeInclusive<Multiplier> =
+        Multiplier::saturating_from_integer(10)..=Bounded::max_value();
     /// Maximum length of block. Up to 5MB.
     pub RuntimeBlockLength: BlockLength =
         BlockLength::builder().max_length(5 * 1024 * 1024).modify_max_length_for_class(DispatchClass::Normal, |m| *m = NORMAL_DISPATCH_RATIO * *m).build();
@@ -58,13 +97,15 @@ parameter_types! {
 
 /// Parameterized slow adjusting fee updated based on
 /// <https://research.web3.foundation/Polkadot/overview/token-economics#2-slow-adjusting-mechanism>
-pub type SlowAdjustingFeeUpdate<R> = TargetedFeeAdjustment<
+///
+/// The adjustment variable and the multiplier bounds default to the constants above; runtimes
+/// tuning them at runtime pass their own, kept within the `*Bounds` ranges.
+pub type SlowAdjustingFeeUpdate<
     R,
-    TargetBlockFullness,
-    AdjustmentVariable,
-    MinimumMultiplier,
-    MaximumMultiplier,
->;
+    Adjustment = AdjustmentVariable,
+    Minimum = MinimumMultiplier,
+    Maximum = MaximumMultiplier,
+> = TargetedFeeAdjustment<R, TargetBlockFullness, Adjustment, Minimum, Maximum>;
 
 /// We assume that an on-initialize consumes 1% of the weight on average, hence a single extrinsic
 /// will not be allowed to consume more than `AvailableBlockRatio - 1%`.
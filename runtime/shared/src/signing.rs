@@ -0,0 +1,125 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Shared implementation of [`SigningApi`](allfeat_primitives::signing::SigningApi).
+//!
+//! Runtimes only provide the transaction extension built from the [`TransactionParams`], so
+//! that the payload covers exactly what their `UncheckedExtrinsic` will be checked against.
+
+extern crate alloc;
+
+use allfeat_primitives::{
+    AccountId, Address, Hash, Nonce, Signature,
+    signing::{SigningError, SigningParams, SigningPayload, TransactionParams},
+};
+use alloc::vec::Vec;
+use frame_support::{
+    MAX_EXTRINSIC_DEPTH,
+    sp_runtime::{
+        SaturatedConversion,
+        generic::{Era, SignedPayload, UncheckedExtrinsic},
+        traits::{Get, TransactionExtension, Verify, Zero},
+    },
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{DecodeLimit, Encode};
+
+type CallOf<T> = <T as frame_system::Config>::RuntimeCall;
+
+/// Resolve `params` at the current block and build the payload `signer` has to sign to dispatch
+/// `call`.
+///
+/// Mortal eras start at the parent block, the last one whose hash is known to the runtime.
+pub fn signing_payload<T, Extension>(
+    call: Vec<u8>,
+    signer: AccountId,
+    params: SigningParams,
+    extension: impl FnOnce(&TransactionParams) -> Extension,
+) -> Result<SigningPayload, SigningError>
+where
+    T: frame_system::Config<AccountId = AccountId, Nonce = Nonce, Hash = Hash>,
+    Extension: TransactionExtension<CallOf<T>>,
+{
+    let call = decode_call::<T>(&call)?;
+    let current: u64 = frame_system::Pallet::<T>::block_number().saturated_into();
+    let era = match params.mortality {
+        Some(period) => Era::mortal(period, current.saturating_sub(1)),
+        None => Era::Immortal,
+    };
+    let params = TransactionParams {
+        nonce: params
+            .nonce
+            .unwrap_or_else(|| frame_system::Pallet::<T>::account_nonce(&signer)),
+        era,
+        tip: params.tip,
+    };
+
+    let payload = SignedPayload::new(call, extension(&params))
+        .map_err(|_| SigningError::Unresolved)?
+        .using_encoded(|payload| payload.to_vec());
+    let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+    let checkpoint = frame_system::Pallet::<T>::block_hash(
+        era.birth(current).saturated_into::<BlockNumberFor<T>>(),
+    );
+    let version = T::Version::get();
+
+    Ok(SigningPayload {
+        payload,
+        params,
+        genesis_hash,
+        checkpoint,
+        spec_version: version.spec_version,
+        transaction_version: version.transaction_version,
+    })
+}
+
+/// Build the extrinsic dispatching `call`, once `signer` signed the payload resolved as `params`.
+pub fn signed_extrinsic<T, Extension>(
+    call: Vec<u8>,
+    signer: AccountId,
+    signature: Signature,
+    params: TransactionParams,
+    extension: impl FnOnce(&TransactionParams) -> Extension,
+) -> Result<Vec<u8>, SigningError>
+where
+    T: frame_system::Config<AccountId = AccountId>,
+    Extension: TransactionExtension<CallOf<T>>,
+{
+    let call = decode_call::<T>(&call)?;
+    let payload =
+        SignedPayload::new(call, extension(&params)).map_err(|_| SigningError::Unresolved)?;
+    if !payload.using_encoded(|payload| signature.verify(payload, &signer)) {
+        return Err(SigningError::BadSignature);
+    }
+
+    let (call, extension, _) = payload.deconstruct();
+    Ok(
+        UncheckedExtrinsic::<Address, CallOf<T>, Signature, Extension>::new_signed(
+            call,
+            signer.into(),
+            signature,
+            extension,
+        )
+        .encode(),
+    )
+}
+
+fn decode_call<T: frame_system::Config>(call: &[u8]) -> Result<CallOf<T>, SigningError> {
+    CallOf::<T>::decode_all_with_depth_limit(MAX_EXTRINSIC_DEPTH, &mut &call[..])
+        .map_err(|_| SigningError::UndecodableCall)
+}
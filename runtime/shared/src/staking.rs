@@ -0,0 +1,38 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Staking parameters shared by the runtimes securing their validator set with `pallet_staking`.
+
+use crate::currency::AFT;
+use allfeat_primitives::Balance;
+use frame_support::{PalletId, parameter_types, sp_runtime::Perbill};
+
+parameter_types! {
+    /// Reserved by a fast-unstake request. It is returned once the staker is unbonded, and
+    /// slashed if they turn out to have been exposed in one of the last `BondingDuration` eras.
+    pub const FastUnstakeDeposit: Balance = 10 * AFT;
+    /// Unstake requests checked together, sharing the cost of reading the eras' exposures.
+    pub const FastUnstakeBatchSize: u32 = 16;
+    /// Upper bound of the eras checked per block, tuned at runtime with `FastUnstake::control`.
+    pub const MaxErasToCheckPerBlock: u32 = 1;
+
+    /// Derives the accounts holding the funds of nominators migrated to delegation agents.
+    pub const DelegatedStakingPalletId: PalletId = PalletId(*b"py/dlstk");
+    /// Share of a delegator's slash paid to the reporter applying it.
+    pub const DelegatorSlashRewardFraction: Perbill = Perbill::from_percent(1);
+}
@@ -0,0 +1,230 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `pallet_election_provider_multi_phase`, not generated against the runtime
+//!
+//! The reference weights shipped with `pallet_election_provider_multi_phase` 45.0.0 (Substrate
+//! kitchensink runtime, 2025-02-21), not benchmark CLI output. `just benchmark-weights-testnet`
+//! replaces this file on the reference hardware, and the Melodie weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_election_provider_multi_phase::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	/// Storage: `ElectionProviderMultiPhase::CurrentPhase` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::CurrentPhase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_initialize_nothing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1485`
+		// Minimum execution time: 1_355_000 picoseconds.
+		Weight::from_parts(1_403_000, 1485)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ElectionProviderMultiPhase::Round` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::CurrentPhase` (r:1 w:1)
+	/// Proof: `ElectionProviderMultiPhase::CurrentPhase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_initialize_open_signed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1485`
+		// Minimum execution time: 5_350_000 picoseconds.
+		Weight::from_parts(5_507_000, 1485)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ElectionProviderMultiPhase::Round` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::CurrentPhase` (r:1 w:1)
+	/// Proof: `ElectionProviderMultiPhase::CurrentPhase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_initialize_open_unsigned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1485`
+		// Minimum execution time: 6_110_000 picoseconds.
+		Weight::from_parts(6_312_000, 1485)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ElectionProviderMultiPhase::QueuedSolution` (r:0 w:1)
+	/// Proof: `ElectionProviderMultiPhase::QueuedSolution` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn finalize_signed_phase_accept_solution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `52`
+		//  Estimated: `3593`
+		// Minimum execution time: 24_085_000 picoseconds.
+		Weight::from_parts(24_495_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn finalize_signed_phase_reject_solution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `52`
+		//  Estimated: `3593`
+		// Minimum execution time: 16_509_000 picoseconds.
+		Weight::from_parts(17_064_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ElectionProviderMultiPhase::SnapshotMetadata` (r:0 w:1)
+	/// Proof: `ElectionProviderMultiPhase::SnapshotMetadata` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::DesiredTargets` (r:0 w:1)
+	/// Proof: `ElectionProviderMultiPhase::DesiredTargets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::Snapshot` (r:0 w:1)
+	/// Proof: `ElectionProviderMultiPhase::Snapshot` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `v` is `[1000, 2000]`.
+	/// The range of component `t` is `[500, 1000]`.
+	fn create_snapshot_internal(v: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 502_941_000 picoseconds.
+		Weight::from_parts(3_670_497, 0)
+			// Standard Error: 2_331
+			.saturating_add(Weight::from_parts(417_190, 0).saturating_mul(v.into()))
+			// Standard Error: 4_660
+			.saturating_add(Weight::from_parts(123_320, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ElectionProviderMultiPhase::SignedSubmissionIndices` (r:1 w:1)
+	/// Proof: `ElectionProviderMultiPhase::SignedSubmissionIndices` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::SignedSubmissionNextIndex` (r:1 w:1)
+	/// Proof: `ElectionProviderMultiPhase::SignedSubmissionNextIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::SnapshotMetadata` (r:1 w:1)
+	/// Proof: `ElectionProviderMultiPhase::SnapshotMetadata` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::SignedSubmissionsMap` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::SignedSubmissionsMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::QueuedSolution` (r:1 w:1)
+	/// Proof: `ElectionProviderMultiPhase::QueuedSolution` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::Round` (r:1 w:1)
+	/// Proof: `ElectionProviderMultiPhase::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::CurrentPhase` (r:1 w:1)
+	/// Proof: `ElectionProviderMultiPhase::CurrentPhase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::DesiredTargets` (r:0 w:1)
+	/// Proof: `ElectionProviderMultiPhase::DesiredTargets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::Snapshot` (r:0 w:1)
+	/// Proof: `ElectionProviderMultiPhase::Snapshot` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `a` is `[500, 800]`.
+	/// The range of component `d` is `[200, 400]`.
+	fn elect_queued(a: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `204 + a * (768 ±0) + d * (48 ±0)`
+		//  Estimated: `3756 + a * (768 ±0) + d * (49 ±0)`
+		// Minimum execution time: 351_780_000 picoseconds.
+		Weight::from_parts(377_006_000, 3756)
+			// Standard Error: 8_676
+			.saturating_add(Weight::from_parts(497_086, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 768).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 49).saturating_mul(d.into()))
+	}
+	/// Storage: `ElectionProviderMultiPhase::CurrentPhase` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::CurrentPhase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::Round` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::SnapshotMetadata` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::SnapshotMetadata` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::SignedSubmissionIndices` (r:1 w:1)
+	/// Proof: `ElectionProviderMultiPhase::SignedSubmissionIndices` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::SignedSubmissionNextIndex` (r:1 w:1)
+	/// Proof: `ElectionProviderMultiPhase::SignedSubmissionNextIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::SignedSubmissionsMap` (r:0 w:1)
+	/// Proof: `ElectionProviderMultiPhase::SignedSubmissionsMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn submit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `683`
+		//  Estimated: `2168`
+		// Minimum execution time: 40_602_000 picoseconds.
+		Weight::from_parts(41_937_000, 2168)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ElectionProviderMultiPhase::CurrentPhase` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::CurrentPhase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::Round` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::DesiredTargets` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::DesiredTargets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::QueuedSolution` (r:1 w:1)
+	/// Proof: `ElectionProviderMultiPhase::QueuedSolution` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::SnapshotMetadata` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::SnapshotMetadata` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::Snapshot` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::Snapshot` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::MinimumUntrustedScore` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::MinimumUntrustedScore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `v` is `[1000, 2000]`.
+	/// The range of component `t` is `[500, 1000]`.
+	/// The range of component `a` is `[500, 800]`.
+	/// The range of component `d` is `[200, 400]`.
+	fn submit_unsigned(v: u32, t: u32, a: u32, _d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `89 + t * (32 ±0) + v * (553 ±0)`
+		//  Estimated: `1574 + t * (32 ±0) + v * (553 ±0)`
+		// Minimum execution time: 5_664_045_000 picoseconds.
+		Weight::from_parts(5_771_637_000, 1574)
+			// Standard Error: 18_838
+			.saturating_add(Weight::from_parts(202_471, 0).saturating_mul(v.into()))
+			// Standard Error: 55_824
+			.saturating_add(Weight::from_parts(4_436_597, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 553).saturating_mul(v.into()))
+	}
+	/// Storage: `ElectionProviderMultiPhase::DesiredTargets` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::DesiredTargets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::Snapshot` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::Snapshot` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::Round` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ElectionProviderMultiPhase::MinimumUntrustedScore` (r:1 w:0)
+	/// Proof: `ElectionProviderMultiPhase::MinimumUntrustedScore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `v` is `[1000, 2000]`.
+	/// The range of component `t` is `[500, 1000]`.
+	/// The range of component `a` is `[500, 800]`.
+	/// The range of component `d` is `[200, 400]`.
+	fn feasibility_check(v: u32, t: u32, a: u32, _d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `64 + t * (32 ±0) + v * (553 ±0)`
+		//  Estimated: `1549 + t * (32 ±0) + v * (553 ±0)`
+		// Minimum execution time: 4_805_475_000 picoseconds.
+		Weight::from_parts(4_914_697_000, 1549)
+			// Standard Error: 18_827
+			.saturating_add(Weight::from_parts(384_743, 0).saturating_mul(v.into()))
+			// Standard Error: 55_792
+			.saturating_add(Weight::from_parts(2_733_267, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 553).saturating_mul(v.into()))
+	}
+}
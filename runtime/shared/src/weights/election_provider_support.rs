@@ -0,0 +1,55 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Provisional weights for `frame_election_provider_support`, not generated against the runtime
+//!
+//! The reference weights shipped with `frame_election_provider_support` 46.0.0 (Substrate node,
+//! 2022-04-23), not benchmark CLI output. `just benchmark-weights-testnet` replaces this file on
+//! the reference hardware, and the Melodie weights test fails until it does.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use frame_election_provider_support::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	fn phragmen(v: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+			// Standard Error: 667_000
+			.saturating_add(Weight::from_parts(32_973_000, 0).saturating_mul(v.into()))
+			// Standard Error: 1_334_000
+			.saturating_add(Weight::from_parts(1_334_000, 0).saturating_mul(t.into()))
+			// Standard Error: 60_644_000
+			.saturating_add(Weight::from_parts(2_636_364_000, 0).saturating_mul(d.into()))
+	}
+	fn phragmms(v: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+			// Standard Error: 73_000
+			.saturating_add(Weight::from_parts(21_073_000, 0).saturating_mul(v.into()))
+			// Standard Error: 146_000
+			.saturating_add(Weight::from_parts(65_000, 0).saturating_mul(t.into()))
+			// Standard Error: 6_649_000
+			.saturating_add(Weight::from_parts(1_711_424_000, 0).saturating_mul(d.into()))
+	}
+}
@@ -27,6 +27,8 @@ LOG_DIR="${ROOT_DIR}/target/weight-logs"
 LOG_FILE="${LOG_DIR}/testnet_weights_${RUN_ID}.log"
 GENERATED_DIR="${LOG_DIR}/generated/testnet/${RUN_ID}"
 RUNTIME_WEIGHTS_DIR="${ROOT_DIR}/runtime/melodie/src/weights"
+# Weights the Melodie configuration reads from `shared_runtime::weights`.
+SHARED_WEIGHTS_DIR="${ROOT_DIR}/runtime/shared/src/weights"
 
 timestamp() {
   date +"%Y-%m-%d %H:%M:%S"
@@ -81,6 +83,14 @@ output_path_for_pallet() {
   case "${pallet}" in
   frame_system) echo "${RUNTIME_WEIGHTS_DIR}/system.rs" ;;
   frame_benchmarking) echo "${RUNTIME_WEIGHTS_DIR}/benchmarking.rs" ;;
+  pallet_bags_list) echo "${SHARED_WEIGHTS_DIR}/bags_list.rs" ;;
+  pallet_bounties) echo "${SHARED_WEIGHTS_DIR}/bounties.rs" ;;
+  pallet_child_bounties) echo "${SHARED_WEIGHTS_DIR}/child_bounties.rs" ;;
+  pallet_election_provider_multi_phase) echo "${SHARED_WEIGHTS_DIR}/election_provider_multi_phase.rs" ;;
+  pallet_election_provider_support_benchmarking) echo "${SHARED_WEIGHTS_DIR}/election_provider_support.rs" ;;
+  pallet_fast_unstake) echo "${SHARED_WEIGHTS_DIR}/fast_unstake.rs" ;;
+  pallet_staking) echo "${SHARED_WEIGHTS_DIR}/staking.rs" ;;
+  pallet_treasury) echo "${SHARED_WEIGHTS_DIR}/treasury.rs" ;;
   *)
     normalized="${pallet#pallet_}"
     normalized="${normalized#frame_}"
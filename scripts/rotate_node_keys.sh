@@ -66,20 +66,26 @@ if [[ -z "$KEYS" || -z "$PROOF" ]]; then
   exit 1
 fi
 
-# SessionKeys = (grandpa: ed25519, aura: sr25519): 32 bytes (64 hex) each.
+# SessionKeys = (grandpa: ed25519, aura: sr25519), plus im_online: sr25519 on Melodie:
+# 32 bytes (64 hex) each.
 HEX=${KEYS:2}
-if [[ ${#HEX} -ne 128 ]]; then
-  echo "Warning: expected 2 session keys (128 hex chars), got ${#HEX}. Printing raw blob only." >&2
+if [[ ${#HEX} -ne 128 && ${#HEX} -ne 192 ]]; then
+  echo "Warning: expected 2 or 3 session keys (128 or 192 hex chars), got ${#HEX}. Printing raw blob only." >&2
   echo "keys:  $KEYS"
   echo "proof: $PROOF"
   exit 0
 fi
+IM_ONLINE_LINE=""
+if [[ ${#HEX} -eq 192 ]]; then
+  IM_ONLINE_LINE="ImOnline Public Key: 0x${HEX:128:64}"
+fi
 
 cat <<EOF
 Owner account (must sign setKeys): $OWNER_HEX
 
 Grandpa Public Key: 0x${HEX:0:64}
 Aura Public Key:    0x${HEX:64:64}
+${IM_ONLINE_LINE}
 
 ==> Submit session.setKeys SIGNED BY THE OWNER ACCOUNT ABOVE, with:
   keys:  $KEYS
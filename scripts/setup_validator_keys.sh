@@ -12,7 +12,8 @@
 # Since pallet-session v46 (proof-of-possession), each session key must sign the
 # statement `b"POP_" || owner`, where `owner` is the 32-byte account id that will
 # submit `session.setKeys`. The proof is the SCALE tuple of those signatures, in
-# the same order as the runtime `SessionKeys` struct: (grandpa, aura).
+# the same order as the runtime `SessionKeys` struct: (grandpa, aura), followed by
+# im_online on Melodie.
 #
 # Usage:
 #   ./setup_validator_keys.sh [VALIDATOR_ACCOUNT]
@@ -25,11 +26,23 @@
 #   NODE_PATH    Base path of the node whose keystore receives the keys. If unset,
 #                key insertion is skipped (keys are only derived/printed).
 #   ALLFEAT_BIN  Node binary (default: ./target/release/allfeat).
+#   NETWORK      `allfeat` (default) or `melodie`. Melodie validators also hold an
+#                im-online key, used to send heartbeats.
 
 set -euo pipefail
 
 BIN="${ALLFEAT_BIN:-./target/release/allfeat}"
 OWNER_INPUT="${1:-}"
+NETWORK="${NETWORK:-allfeat}"
+
+case "$NETWORK" in
+  allfeat) IM_ONLINE=false ;;
+  melodie) IM_ONLINE=true ;;
+  *)
+    echo "Error: unknown NETWORK '$NETWORK' (expected allfeat or melodie)." >&2
+    exit 1
+    ;;
+esac
 
 if [[ ! -x "$BIN" ]] && ! command -v "$BIN" >/dev/null 2>&1; then
   echo "Error: node binary '$BIN' not found." >&2
@@ -46,24 +59,39 @@ printf '%s\n' "$RANDOM_SECRET"
 printf '=======================================================================================\n\n'
 
 # --- 2. Derive the public keys (order MUST match the runtime SessionKeys) -------
-#        runtime SessionKeys = { grandpa: ed25519, aura: sr25519 }
+#        runtime SessionKeys = { grandpa: ed25519, aura: sr25519[, im_online: sr25519] }
 GRANDPA_PUB=$("$BIN" key inspect --scheme ed25519 "$RANDOM_SECRET//grandpa" | grep "Account ID" | awk '{print $3}')
 AURA_PUB=$("$BIN" key inspect --scheme sr25519 "$RANDOM_SECRET//aura" | grep "Account ID" | awk '{print $3}')
+if $IM_ONLINE; then
+  IM_ONLINE_PUB=$("$BIN" key inspect --scheme sr25519 "$RANDOM_SECRET//im_online" | grep "Account ID" | awk '{print $3}')
+fi
 
 # --- 3. Insert the private keys into the node keystore --------------------------
 if [[ -n "${NODE_PATH:-}" ]]; then
   "$BIN" key insert --base-path "$NODE_PATH" --scheme Ed25519 --suri "$RANDOM_SECRET//grandpa" --key-type gran
   "$BIN" key insert --base-path "$NODE_PATH" --scheme Sr25519 --suri "$RANDOM_SECRET//aura"    --key-type aura
-  printf 'Inserted grandpa (gran) + aura keys into the keystore at %s\n\n' "$NODE_PATH"
+  if $IM_ONLINE; then
+    "$BIN" key insert --base-path "$NODE_PATH" --scheme Sr25519 --suri "$RANDOM_SECRET//im_online" --key-type imon
+    printf 'Inserted grandpa (gran) + aura + im_online (imon) keys into the keystore at %s\n\n' "$NODE_PATH"
+  else
+    printf 'Inserted grandpa (gran) + aura keys into the keystore at %s\n\n' "$NODE_PATH"
+  fi
 else
   printf 'NODE_PATH not set — skipped keystore insertion (keys only derived).\n\n'
 fi
 
 printf 'Grandpa Public Key: %s\n' "$GRANDPA_PUB"
-printf 'Aura Public Key:    %s\n\n' "$AURA_PUB"
+printf 'Aura Public Key:    %s\n' "$AURA_PUB"
+if $IM_ONLINE; then
+  printf 'ImOnline Public Key: %s\n' "$IM_ONLINE_PUB"
+fi
+printf '\n'
 
 # Concatenated public session keys = the `keys` argument of session.setKeys.
 KEYS="0x${GRANDPA_PUB:2}${AURA_PUB:2}"
+if $IM_ONLINE; then
+  KEYS="${KEYS}${IM_ONLINE_PUB:2}"
+fi
 
 # --- 4. Optionally emit the ownership proof for the given owner account ---------
 if [[ -z "$OWNER_INPUT" ]]; then
@@ -87,11 +115,15 @@ fi
 # Proof-of-possession statement = b"POP_" || owner   (0x504f505f == "POP_").
 STATEMENT="0x504f505f${OWNER_HEX:2}"
 
-# Each key signs the statement; proof = SCALE tuple (grandpa_sig, aura_sig),
-# i.e. the two fixed 64-byte signatures concatenated, same order as SessionKeys.
+# Each key signs the statement; proof = SCALE tuple (grandpa_sig, aura_sig[, im_online_sig]),
+# i.e. the fixed 64-byte signatures concatenated, same order as SessionKeys.
 GRANDPA_SIG=$("$BIN" key sign --hex --message "$STATEMENT" --scheme ed25519 --suri "$RANDOM_SECRET//grandpa")
 AURA_SIG=$("$BIN" key sign --hex --message "$STATEMENT" --scheme sr25519 --suri "$RANDOM_SECRET//aura")
 PROOF="0x${GRANDPA_SIG:2}${AURA_SIG:2}"
+if $IM_ONLINE; then
+  IM_ONLINE_SIG=$("$BIN" key sign --hex --message "$STATEMENT" --scheme sr25519 --suri "$RANDOM_SECRET//im_online")
+  PROOF="${PROOF}${IM_ONLINE_SIG:2}"
+fi
 
 printf '\nOwner account (must sign setKeys): %s\n\n' "$OWNER_HEX"
 printf '==> Submit session.setKeys SIGNED BY THE OWNER ACCOUNT ABOVE, with:\n'